@@ -0,0 +1,85 @@
+//! Select the version module re-exported at the crate root from the
+//! `LINUX_RAW_SYS_VERSION` environment variable, overriding the default
+//! version for the target architecture, and mark whether there is a root
+//! version module at all, and whether it has `openat2`.
+
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=LINUX_RAW_SYS_VERSION");
+
+    let mut values = "none()".to_owned();
+    for version in VERSIONS {
+        values.push_str(&format!(", \"{}\"", version));
+    }
+    println!(
+        "cargo:rustc-check-cfg=cfg(linux_raw_sys_version, values({}))",
+        values
+    );
+    println!("cargo:rustc-check-cfg=cfg(linux_raw_sys_root)");
+    println!("cargo:rustc-check-cfg=cfg(linux_raw_sys_openat2)");
+
+    let root = match env::var("LINUX_RAW_SYS_VERSION") {
+        Ok(version) if !version.is_empty() => {
+            // Only versions generated for all architectures can be selected,
+            // since the others only have modules for the architectures which
+            // default to them.
+            if !VERSIONS.contains(&version.as_str()) {
+                panic!(
+                    "LINUX_RAW_SYS_VERSION is {:?}, but it must be one of: {}",
+                    version,
+                    VERSIONS.join(", ")
+                );
+            }
+            println!("cargo:rustc-cfg=linux_raw_sys_version");
+            println!("cargo:rustc-cfg=linux_raw_sys_version=\"{}\"", version);
+            version
+        }
+        _ => {
+            let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+            match DEFAULT_VERSIONS.iter().find(|(default, _)| *default == arch) {
+                Some((_, version)) => version.to_string(),
+                None => return,
+            }
+        }
+    };
+
+    // The helper modules use the version module re-exported at the crate
+    // root, which some architectures only have if one is selected.
+    println!("cargo:rustc-cfg=linux_raw_sys_root");
+    // <linux/openat2.h> is new in Linux 5.6.
+    if version_number(&root) >= (5, 6) {
+        println!("cargo:rustc-cfg=linux_raw_sys_openat2");
+    }
+}
+
+/// The major and minor numbers of a version module name, such as `v5_11`.
+fn version_number(version: &str) -> (u32, u32) {
+    let mut parts = version[1..].split('_').map(|part| part.parse().unwrap());
+    (parts.next().unwrap(), parts.next().unwrap())
+}
+
+// The rest of this file is auto-generated!
+
+/// The versions which are generated for all architectures.
+const VERSIONS: &[&str] = &[
+    "v5_4",
+    "v5_11",
+];
+
+/// The architectures which have a default version, and their versions.
+const DEFAULT_VERSIONS: &[(&str, &str)] = &[
+    ("x86", "v2_6_32"),
+    ("x86_64", "v2_6_32"),
+    ("aarch64", "v4_2"),
+    ("mips", "v4_4"),
+    ("mips64", "v4_4"),
+    ("arm", "v3_2"),
+    ("powerpc", "v2_6_32"),
+    ("powerpc64", "v3_10"),
+    ("riscv64", "v4_20"),
+    ("riscv32", "v5_4"),
+    ("s390x", "v5_4"),
+    ("sparc64", "v5_4"),
+];
@@ -6,10 +6,12 @@
 // Selected Linux headers.
 
 #include <linux/auxvec.h>
+#include <linux/capability.h>
 #include <linux/eventpoll.h>
 #include <linux/fadvise.h>
 #include <linux/falloc.h>
 #include <linux/fcntl.h>
+#include <linux/filter.h>
 #include <linux/fs.h>
 #include <linux/futex.h>
 #include <linux/in.h>
@@ -73,6 +75,10 @@ struct __kernel_timespec {
     __kernel_time64_t tv_sec;
     long long         tv_nsec;
 };
+struct __kernel_itimerspec {
+    struct __kernel_timespec it_interval;
+    struct __kernel_timespec it_value;
+};
 #endif
 
 #define DT_UNKNOWN 0
@@ -91,6 +97,11 @@ struct __kernel_timespec {
 #define SHUT_WR   1
 #define SHUT_RDWR 2
 
+// x86, arm, and s390x only define `_NSIG` for the kernel itself.
+#ifndef _NSIG
+#define _NSIG 64
+#endif
+
 struct linux_dirent64 {
     __UINT64_TYPE__ d_ino;
     __INT64_TYPE__  d_off;
@@ -0,0 +1,471 @@
+//! Post-processing of bindgen's output to add optional, feature-gated
+//! derives to the generated structs.
+//!
+//! bindgen doesn't know which structs are eligible for traits like
+//! zerocopy's `IntoBytes` or bytemuck's `Pod`, which depend on the absence
+//! of padding, or serde's `Serialize`, which only supports small arrays, so
+//! this computes the layout of each struct for the target architecture from
+//! the generated Rust source.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::ops::Range;
+
+/// A struct or union definition parsed from the generated bindings.
+struct Item {
+    is_union: bool,
+    packed: Option<usize>,
+    align: Option<usize>,
+    fields: Vec<String>,
+}
+
+/// The layout of a plain-old-data type: one made up only of integers and
+/// floating-point values, for which every bit pattern is valid.
+#[derive(Copy, Clone)]
+struct Info {
+    size: usize,
+    align: usize,
+    /// There are no padding bytes.
+    no_padding: bool,
+    /// All arrays have at most 32 elements, which is as many as serde
+    /// supports.
+    serde: bool,
+}
+
+struct Analysis {
+    ptr_width: usize,
+    u64_align: usize,
+    aliases: HashMap<String, String>,
+    items: HashMap<String, Item>,
+    enums: HashSet<String>,
+    memo: HashMap<String, Option<Info>>,
+    eq_memo: HashMap<String, Option<bool>>,
+}
+
+impl Analysis {
+    fn new(contents: &str, rust_arch: &str) -> Self {
+        let mut aliases = HashMap::new();
+        let mut items = HashMap::new();
+        let mut enums = HashSet::new();
+        let mut reprs = Vec::new();
+        let mut current: Option<(String, Item)> = None;
+
+        for line in contents.lines() {
+            if let Some((name, item)) = &mut current {
+                if line == "}" {
+                    let (name, item) = (name.clone(), current.take().unwrap().1);
+                    items.insert(name, item);
+                } else if let Some(ty) = field_type(line) {
+                    item.fields.push(ty.to_owned());
+                }
+                continue;
+            }
+
+            if let Some(repr) = line
+                .strip_prefix("#[repr(")
+                .and_then(|rest| rest.strip_suffix(")]"))
+            {
+                reprs.push(repr.to_owned());
+            } else if line.starts_with("#[") {
+                // Other attributes, such as derives, don't affect layout.
+            } else if let Some(rest) = line.strip_prefix("pub type ") {
+                let alias = rest.strip_suffix(';').and_then(|r| r.split_once(" = "));
+                if let Some((name, ty)) = alias {
+                    aliases.insert(name.to_owned(), ty.to_owned());
+                }
+                reprs.clear();
+            } else if let Some(name) = line
+                .strip_prefix("pub enum ")
+                .and_then(|rest| rest.strip_suffix(" {"))
+            {
+                enums.insert(name.to_owned());
+                reprs.clear();
+            } else if let Some((name, is_union)) = item_start(line) {
+                let mut item = Item {
+                    is_union,
+                    packed: None,
+                    align: None,
+                    fields: Vec::new(),
+                };
+                for repr in reprs.drain(..) {
+                    for part in repr.split(", ") {
+                        if part == "packed" {
+                            item.packed = Some(1);
+                        } else if let Some(n) = part
+                            .strip_prefix("packed(")
+                            .and_then(|n| n.strip_suffix(')'))
+                        {
+                            item.packed = Some(n.parse().unwrap());
+                        } else if let Some(n) = part
+                            .strip_prefix("align(")
+                            .and_then(|n| n.strip_suffix(')'))
+                        {
+                            item.align = Some(n.parse().unwrap());
+                        }
+                    }
+                }
+                current = Some((name.to_owned(), item));
+            } else {
+                reprs.clear();
+            }
+        }
+
+        let ptr_width = match rust_arch {
+            "x86" | "arm" | "mips" | "powerpc" | "riscv32" | "sparc" => 4,
+            _ => 8,
+        };
+        // i386's ABI only aligns 64-bit integers to 4 bytes.
+        let u64_align = match rust_arch {
+            "x86" => 4,
+            _ => 8,
+        };
+
+        Self {
+            ptr_width,
+            u64_align,
+            aliases,
+            items,
+            enums,
+            memo: HashMap::new(),
+            eq_memo: HashMap::new(),
+        }
+    }
+
+    /// Compute the layout of the type named by `ty`, or `None` if it isn't
+    /// plain old data, such as a pointer, an enum, a union, or a struct
+    /// containing one of those.
+    fn info(&mut self, ty: &str) -> Option<Info> {
+        if let Some(inner) = ty.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+            let (elem, len) = inner.rsplit_once("; ")?;
+            let len: usize = len.trim_end_matches("usize").parse().ok()?;
+            let elem = self.info(elem)?;
+            return Some(Info {
+                size: elem.size * len,
+                serde: elem.serde && len <= 32,
+                ..elem
+            });
+        }
+
+        let name = ty.strip_prefix("crate::ctypes::").unwrap_or(ty);
+        let prim = |size, align| {
+            Some(Info {
+                size,
+                align,
+                no_padding: true,
+                serde: true,
+            })
+        };
+        match name {
+            "u8" | "i8" | "c_char" | "c_schar" | "c_uchar" => return prim(1, 1),
+            "u16" | "i16" | "c_short" | "c_ushort" => return prim(2, 2),
+            "u32" | "i32" | "f32" | "c_int" | "c_uint" | "c_float" => return prim(4, 4),
+            "u64" | "i64" | "f64" | "c_longlong" | "c_ulonglong" | "c_double" => {
+                return prim(8, self.u64_align)
+            }
+            "usize" | "isize" | "c_long" | "c_ulong" => {
+                return prim(self.ptr_width, self.ptr_width)
+            }
+            _ => {}
+        }
+        if name.contains(|c: char| !(c.is_ascii_alphanumeric() || c == '_')) {
+            // Pointers, generics, and paths outside the bindings.
+            return None;
+        }
+
+        if let Some(info) = self.memo.get(name) {
+            return *info;
+        }
+        // Guard against recursion through pointers to the type itself.
+        self.memo.insert(name.to_owned(), None);
+        let info = if let Some(alias) = self.aliases.get(name).cloned() {
+            self.info(&alias)
+        } else {
+            self.item_info(name)
+        };
+        self.memo.insert(name.to_owned(), info);
+        info
+    }
+
+    /// Determine whether the type named by `ty` can derive `PartialEq`,
+    /// returning `Some(true)` if it can derive `Eq` too, and `Some(false)` if
+    /// it contains floating-point values and so can't.
+    fn eq(&mut self, ty: &str) -> Option<bool> {
+        if let Some(inner) = ty.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+            let (elem, _len) = inner.rsplit_once("; ")?;
+            return self.eq(elem);
+        }
+        if ty.starts_with("*mut ") || ty.starts_with("*const ") {
+            return Some(true);
+        }
+
+        let name = ty.strip_prefix("crate::ctypes::").unwrap_or(ty);
+        match name {
+            "f32" | "f64" | "c_float" | "c_double" => return Some(false),
+            "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "usize" | "isize"
+            | "c_char" | "c_schar" | "c_uchar" | "c_short" | "c_ushort" | "c_int" | "c_uint"
+            | "c_long" | "c_ulong" | "c_longlong" | "c_ulonglong" => return Some(true),
+            _ => {}
+        }
+        if self.enums.contains(name) {
+            return Some(true);
+        }
+        if name.contains(|c: char| !(c.is_ascii_alphanumeric() || c == '_')) {
+            // Function pointers, generics, and paths outside the bindings.
+            return None;
+        }
+
+        if let Some(eq) = self.eq_memo.get(name) {
+            return *eq;
+        }
+        // Guard against recursion through pointers to the type itself.
+        self.eq_memo.insert(name.to_owned(), None);
+        let eq = if let Some(alias) = self.aliases.get(name).cloned() {
+            self.eq(&alias)
+        } else {
+            let fields = match self.items.get(name) {
+                Some(item) if !item.is_union => item.fields.clone(),
+                _ => return None,
+            };
+            let mut eq = Some(true);
+            for field in &fields {
+                match self.eq(field) {
+                    Some(field_eq) => eq = eq.map(|eq| eq && field_eq),
+                    None => {
+                        eq = None;
+                        break;
+                    }
+                }
+            }
+            eq
+        };
+        self.eq_memo.insert(name.to_owned(), eq);
+        eq
+    }
+
+    fn item_info(&mut self, name: &str) -> Option<Info> {
+        let (is_union, packed, align, fields) = {
+            let item = self.items.get(name)?;
+            (item.is_union, item.packed, item.align, item.fields.clone())
+        };
+        if is_union {
+            return None;
+        }
+
+        let mut offset = 0;
+        let mut max_align = 1;
+        let mut field_bytes = 0;
+        let mut no_padding = true;
+        let mut serde = true;
+        for field in &fields {
+            let info = self.info(field)?;
+            let field_align = match packed {
+                Some(packed) => info.align.min(packed),
+                None => info.align,
+            };
+            offset = round_up(offset, field_align) + info.size;
+            max_align = max_align.max(field_align);
+            field_bytes += info.size;
+            no_padding &= info.no_padding;
+            serde &= info.serde;
+        }
+        if let Some(align) = align {
+            max_align = max_align.max(align);
+        }
+        let size = round_up(offset, max_align);
+
+        Some(Info {
+            size,
+            align: max_align,
+            no_padding: no_padding && field_bytes == size,
+            serde,
+        })
+    }
+
+    /// Append the ranges of padding bytes in a value of the type named by
+    /// `ty` at offset `base` to `padding`, including the padding in its
+    /// fields. Return `None` if its layout can't be computed.
+    fn padding_in(&mut self, ty: &str, base: usize, padding: &mut Vec<Range<usize>>) -> Option<()> {
+        let info = self.info(ty)?;
+        if info.no_padding {
+            return Some(());
+        }
+        if let Some(inner) = ty.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+            let (elem, len) = inner.rsplit_once("; ")?;
+            let len: usize = len.trim_end_matches("usize").parse().ok()?;
+            let size = self.info(elem)?.size;
+            for i in 0..len {
+                self.padding_in(elem, base + i * size, padding)?;
+            }
+            return Some(());
+        }
+        if let Some(alias) = self.aliases.get(ty).cloned() {
+            return self.padding_in(&alias, base, padding);
+        }
+
+        let (packed, fields) = {
+            let item = self.items.get(ty)?;
+            (item.packed, item.fields.clone())
+        };
+        let mut offset = 0;
+        for field in &fields {
+            let field_info = self.info(field)?;
+            let align = match packed {
+                Some(packed) => field_info.align.min(packed),
+                None => field_info.align,
+            };
+            let start = round_up(offset, align);
+            if start > offset {
+                padding.push(base + offset..base + start);
+            }
+            self.padding_in(field, base + start, padding)?;
+            offset = start + field_info.size;
+        }
+        if info.size > offset {
+            padding.push(base + offset..base + info.size);
+        }
+        Some(())
+    }
+}
+
+/// If `line` starts a struct or union definition with named fields, return
+/// its name and whether it's a union.
+fn item_start(line: &str) -> Option<(&str, bool)> {
+    let (rest, is_union) = if let Some(rest) = line.strip_prefix("pub struct ") {
+        (rest, false)
+    } else if let Some(rest) = line.strip_prefix("pub union ") {
+        (rest, true)
+    } else {
+        return None;
+    };
+    let name = rest.strip_suffix(" {")?;
+    if name.contains(|c: char| !(c.is_ascii_alphanumeric() || c == '_')) {
+        return None;
+    }
+    Some((name, is_union))
+}
+
+/// If `line` is a field declaration, return its type.
+fn field_type(line: &str) -> Option<&str> {
+    let line = line.strip_prefix("pub ").unwrap_or(line);
+    let (_name, ty) = line.split_once(": ")?;
+    ty.strip_suffix(',')
+}
+
+fn round_up(n: usize, align: usize) -> usize {
+    n.div_ceil(align) * align
+}
+
+/// Find the ranges of padding bytes in the struct `name` defined in the
+/// bindings `contents` for `rust_arch`, or `None` if it isn't defined there
+/// or its layout can't be computed.
+pub(crate) fn padding(contents: &str, rust_arch: &str, name: &str) -> Option<Vec<Range<usize>>> {
+    let mut analysis = Analysis::new(contents, rust_arch);
+    let mut padding = Vec::new();
+    analysis.padding_in(name, 0, &mut padding)?;
+    Some(padding)
+}
+
+/// Add the feature-gated derives to the structs in `mod_rs` which are
+/// eligible for them on `rust_arch`.
+pub(crate) fn add_derives(mod_rs: &str, rust_arch: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+    let mut analysis = Analysis::new(&contents, rust_arch);
+
+    let mut out = String::with_capacity(contents.len());
+    let mut has_debug = false;
+    let mut union = None;
+    for line in contents.lines() {
+        // bindgen can't derive `Debug` for unions, or for the structs which
+        // contain them, so implement it for unions by printing their raw
+        // bytes, which lets the structs derive it.
+        if let Some(derives) = line
+            .strip_prefix("#[derive(")
+            .and_then(|rest| rest.strip_suffix(")]"))
+        {
+            has_debug = derives.split(", ").any(|derive| derive == "Debug");
+            if has_debug {
+                // Let users who don't need `Debug` skip compiling it.
+                let derives = derives
+                    .split(", ")
+                    .filter(|derive| *derive != "Debug")
+                    .collect::<Vec<_>>();
+                if !derives.is_empty() {
+                    out.push_str(&format!("#[derive({})]\n", derives.join(", ")));
+                }
+                out.push_str("#[cfg_attr(feature = \"derive-debug\", derive(Debug))]\n");
+                continue;
+            }
+        } else if !line.starts_with("#[") {
+            match item_start(line) {
+                Some((_, false)) if !has_debug => {
+                    out.push_str("#[cfg_attr(feature = \"union-debug\", derive(Debug))]\n");
+                }
+                Some((name, true)) => union = Some(name),
+                _ => {}
+            }
+            has_debug = false;
+        }
+
+        if let Some((name, false)) = item_start(line) {
+            match analysis.eq(name) {
+                Some(true) => {
+                    out.push_str("#[cfg_attr(feature = \"derive-eq\", derive(PartialEq, Eq))]\n")
+                }
+                Some(false) => {
+                    out.push_str("#[cfg_attr(feature = \"derive-eq\", derive(PartialEq))]\n")
+                }
+                None => {}
+            }
+
+            if let Some(info) = analysis.info(name) {
+                let mut zerocopy = vec![
+                    "zerocopy::FromBytes",
+                    "zerocopy::Immutable",
+                    "zerocopy::KnownLayout",
+                ];
+                // `IntoBytes` would expose the contents of padding bytes.
+                if info.no_padding {
+                    zerocopy.insert(1, "zerocopy::IntoBytes");
+                }
+                out.push_str(&format!(
+                    "#[cfg_attr(feature = \"zerocopy\", derive({}))]\n",
+                    zerocopy.join(", ")
+                ));
+
+                // Likewise, `Pod` requires that there be no padding, while
+                // `Zeroable` only requires that all-zeros be a valid value.
+                let mut bytemuck = vec!["bytemuck::Zeroable"];
+                if info.no_padding {
+                    bytemuck.push("bytemuck::Pod");
+                }
+                out.push_str(&format!(
+                    "#[cfg_attr(feature = \"bytemuck\", derive({}))]\n",
+                    bytemuck.join(", ")
+                ));
+
+                if info.serde {
+                    out.push_str(
+                        "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]\n",
+                    );
+                }
+            }
+        }
+        out.push_str(line);
+        out.push('\n');
+
+        if line == "}" {
+            if let Some(name) = union.take() {
+                out.push_str(&format!(
+                    "#[cfg(feature = \"union-debug\")]\n\
+                     impl ::core::fmt::Debug for {0} {{\n\
+                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {{\n\
+                     f.debug_struct(\"{0}\").finish_non_exhaustive()\n\
+                     }}\n\
+                     }}\n",
+                    name
+                ));
+            }
+        }
+    }
+    fs::write(mod_rs, out).unwrap();
+}
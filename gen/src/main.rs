@@ -2,8 +2,10 @@
 //! over each public header, for each supported architecture, for a selection
 //! of Linux kernel versions.
 
+mod derives;
+
 use bindgen::{builder, EnumVariation};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::fs::File;
@@ -30,7 +32,7 @@ const LINUX_VERSIONS: [&str; 8] = [
 
 /// Base supported revisions for various architectures.
 /// <https://doc.rust-lang.org/nightly/rustc/platform-support.html>
-const DEFAULT_LINUX_VERSIONS: [(&str, &str); 9] = [
+const DEFAULT_LINUX_VERSIONS: [(&str, &str); 12] = [
     ("x86", "v2.6.32"),
     ("x86_64", "v2.6.32"),
     ("aarch64", "v4.2"),
@@ -40,16 +42,139 @@ const DEFAULT_LINUX_VERSIONS: [(&str, &str); 9] = [
     ("powerpc", "v2.6.32"),
     ("powerpc64", "v3.10"), // powerpc64 has 2.6.32, but powerpc64le has 3.10; go with the later for now.
     ("riscv64", "v4.20"),
+    ("riscv32", "v5.4"),
+    // s390x and sparc64 support 3.2, but only the versions generated for
+    // all architectures have them so far.
+    ("s390x", "v5.4"),
+    ("sparc64", "v5.4"),
 ];
 
+/// Versions which are generated for all architectures and available behind
+/// cargo features, even if they're also the default for some architectures.
+const FEATURE_LINUX_VERSIONS: [&str; 2] = ["v5.4", "v5.11"];
+
 /// Some commonly used features.
-const DEFAULT_FEATURES: &str = "\"general\", \"errno\"";
+const DEFAULT_FEATURES: &str = "\"general\", \"errno\", \"derive-debug\"";
+
+/// Flag families in `general` which get typed wrappers with the
+/// `typed-flags` feature: the wrapper name, its integer type, and the
+/// prefix of the constants it contains.
+const TYPED_FLAGS: [(&str, &str, &str); 5] = [
+    ("OFlags", "u32", "O_"),
+    ("MapFlags", "u32", "MAP_"),
+    ("EpollFlags", "u32", "EPOLL"),
+    ("CloneFlags", "u64", "CLONE_"),
+    ("MfdFlags", "u32", "MFD_"),
+];
+
+/// Request structs for which the kernel expects unused fields and padding to
+/// be zeroed, which get a `zeroed()` constructor and a `Default` impl that
+/// zero them.
+const ZEROED_STRUCTS: [&str; 5] = [
+    "clone_args",
+    "open_how",
+    "sched_attr",
+    "io_uring_params",
+    "uffdio_api",
+];
+
+/// Structs the kernel fills in, which contain only integers, so any bytes it
+/// wrote are a valid value, and which get helpers for reading them out of
+/// byte buffers and `MaybeUninit`s.
+const KERNEL_FILLED_STRUCTS: [&str; 6] =
+    ["stat", "stat64", "statfs", "statfs64", "statx", "rusage"];
+
+/// The equivalents of the `si_*` macros from <asm-generic/siginfo.h>, which
+/// name fields inside `siginfo`'s unions, and which bindgen doesn't
+/// translate: their names, return types, the `_sifields` member they're in,
+/// if any, their field names, and their docs.
+const SIGINFO_ACCESSORS: [(&str, &str, Option<&str>, &str, &str); 9] = [
+    ("si_signo", "crate::ctypes::c_int", None, "si_signo", "The signal number."),
+    (
+        "si_errno",
+        "crate::ctypes::c_int",
+        None,
+        "si_errno",
+        "The errno value associated with the signal, which is usually 0.",
+    ),
+    (
+        "si_code",
+        "crate::ctypes::c_int",
+        None,
+        "si_code",
+        "The signal code, such as `SI_USER` or `CLD_EXITED`, which determines which of the other fields are valid.",
+    ),
+    (
+        "si_pid",
+        "__kernel_pid_t",
+        Some("_kill"),
+        "_pid",
+        "The PID of the sending process, for signals sent by `kill`, `sigqueue`, and for `SIGCHLD`.",
+    ),
+    (
+        "si_uid",
+        "__kernel_uid32_t",
+        Some("_kill"),
+        "_uid",
+        "The real UID of the sending process, for signals sent by `kill`, `sigqueue`, and for `SIGCHLD`.",
+    ),
+    (
+        "si_status",
+        "crate::ctypes::c_int",
+        Some("_sigchld"),
+        "_status",
+        "The exit status or signal of the child, for `SIGCHLD`.",
+    ),
+    (
+        "si_addr",
+        "*mut crate::ctypes::c_void",
+        Some("_sigfault"),
+        "_addr",
+        "The faulting address, for `SIGSEGV`, `SIGBUS`, `SIGILL`, `SIGFPE`, and `SIGTRAP`.",
+    ),
+    (
+        "si_fd",
+        "crate::ctypes::c_int",
+        Some("_sigpoll"),
+        "_fd",
+        "The file descriptor, for `SIGPOLL`.",
+    ),
+    (
+        "si_value",
+        "sigval",
+        Some("_rt"),
+        "_sigval",
+        "The value passed to `sigqueue`, or with a POSIX timer.",
+    ),
+];
+
+/// Extensible structs whose size is part of the ABI, and the prefix of their
+/// `*_SIZE_VER*` constants, which get static assertions that the latest
+/// size matches the generated struct.
+const SIZE_VER_STRUCTS: [(&str, &str); 4] = [
+    ("clone_args", "CLONE_ARGS"),
+    ("sched_attr", "SCHED_ATTR"),
+    ("perf_event_attr", "PERF_ATTR"),
+    ("mount_attr", "MOUNT_ATTR"),
+];
+
+/// Structs whose size is fixed by the ABI, with reserved space for future
+/// fields, which get static assertions that the generated struct has it.
+const FIXED_SIZE_STRUCTS: [(&str, usize); 2] = [("statx", 256), ("statx_timestamp", 16)];
 
 fn main() {
     let mut args = env::args();
     let _exe = args.next().unwrap();
     let cmd = args.next();
 
+    // Report generated files which are stale or have been edited, without
+    // regenerating anything.
+    if cmd.as_deref() == Some("--check-manifest") {
+        assert!(args.next().is_none());
+        check_manifest("../src/MANIFEST");
+        return;
+    }
+
     // This is the main invocation path.
     assert!(cmd.is_none());
     assert!(args.next().is_none());
@@ -127,27 +252,79 @@ fn main() {
             .filter(|default| &default.1 == linux_version)
             .map(|default| default.0)
             .collect::<Vec<_>>();
+        let cfg_selected = format!("linux_raw_sys_version = \"{}\"", linux_version_mod);
         if !default_arch_versions.is_empty() {
             let mut cfg_versions = vec![];
             for arch in default_arch_versions {
                 cfg_versions.push(format!("target_arch = \"{}\"", arch));
             }
-            writeln!(src_lib_rs, "{}", gen_cfg_any(&cfg_versions)).unwrap();
+            let is_feature_version = FEATURE_LINUX_VERSIONS.contains(linux_version);
+            if is_feature_version {
+                let mut cfg_mod = cfg_versions.clone();
+                cfg_mod.push(format!("feature = \"{}\"", linux_version_mod));
+                cfg_mod.push(cfg_selected.clone());
+                writeln!(src_lib_rs, "{}", gen_cfg_any(&cfg_mod)).unwrap();
+                writeln!(
+                    src_lib_rs,
+                    "#[cfg_attr(docsrs, doc(cfg({})))]",
+                    cfg_any(&cfg_mod[..cfg_mod.len() - 1])
+                )
+                .unwrap();
+            } else {
+                writeln!(src_lib_rs, "{}", gen_cfg_any(&cfg_versions)).unwrap();
+                // The contents are documented at the top level, below, so
+                // don't document them twice.
+                writeln!(src_lib_rs, "#[doc(hidden)]").unwrap();
+            }
             writeln!(src_lib_rs, "pub mod {};", linux_version_mod).unwrap();
 
             // If this is the default version for an architecture, make the
-            // contents available in the top-level namespace.
-            writeln!(src_lib_rs, "{}", gen_cfg_any(&cfg_versions)).unwrap();
+            // contents available in the top-level namespace, unless
+            // `LINUX_RAW_SYS_VERSION` selects another version.
+            writeln!(
+                src_lib_rs,
+                "#[cfg(all({}, not(linux_raw_sys_version)))]",
+                cfg_any(&cfg_versions)
+            )
+            .unwrap();
+            if !is_feature_version {
+                writeln!(src_lib_rs, "#[doc(inline)]").unwrap();
+            }
             writeln!(src_lib_rs, "pub use {}::*;", linux_version_mod).unwrap();
         } else {
-            let cfg_version = format!("#[cfg(feature = \"{}\")]", linux_version_mod);
-            writeln!(src_lib_rs, "{}", cfg_version).unwrap();
+            writeln!(
+                src_lib_rs,
+                "#[cfg(any(feature = \"{}\", {}))]",
+                linux_version_mod, cfg_selected
+            )
+            .unwrap();
+            writeln!(
+                src_lib_rs,
+                "#[cfg_attr(docsrs, doc(cfg(feature = \"{}\")))]",
+                linux_version_mod
+            )
+            .unwrap();
             writeln!(src_lib_rs, "pub mod {};", linux_version_mod).unwrap();
         }
+        if is_all_arch_version(linux_version) {
+            writeln!(src_lib_rs, "#[cfg({})]", cfg_selected).unwrap();
+            writeln!(src_lib_rs, "pub use {}::*;", linux_version_mod).unwrap();
+        }
 
         let src_vers = format!("../src/{}", linux_version_mod);
         fs::create_dir_all(&src_vers).unwrap();
         let mut src_vers_mod_rs = File::create(&format!("{}/mod.rs", src_vers)).unwrap();
+        writeln!(
+            src_vers_mod_rs,
+            "/// The `LINUX_VERSION_CODE` of the headers these bindings were generated from."
+        )
+        .unwrap();
+        writeln!(
+            src_vers_mod_rs,
+            "pub const LINUX_VERSION_CODE: u32 = {:#08x};",
+            linux_version_code(linux_version)
+        )
+        .unwrap();
 
         // Checkout a specific version of Linux.
         git_checkout(linux_version);
@@ -174,13 +351,14 @@ fn main() {
             let mut headers_made = false;
             for rust_arch in rust_arches {
                 // Only build the default versions on their associated
-                // architectures.
+                // architectures, unless they're also feature versions.
                 if !DEFAULT_LINUX_VERSIONS
                     .iter()
                     .any(|default| rust_arch == &default.0 && linux_version == &default.1)
                     && DEFAULT_LINUX_VERSIONS
                         .iter()
                         .any(|default| linux_version == &default.1)
+                    && !FEATURE_LINUX_VERSIONS.contains(linux_version)
                 {
                     continue;
                 }
@@ -227,21 +405,78 @@ fn main() {
 
                     writeln!(src_arch_mod_rs, "/// {}", header_name.to_str().unwrap()).unwrap();
                     writeln!(src_arch_mod_rs, "#[cfg(feature = \"{}\")]", mod_name).unwrap();
+                    writeln!(
+                        src_arch_mod_rs,
+                        "#[cfg_attr(docsrs, doc(cfg(feature = \"{}\")))]",
+                        mod_name
+                    )
+                    .unwrap();
                     writeln!(src_arch_mod_rs, "pub mod r#{};", mod_name).unwrap();
                     // Collect all unique feature names across all architectures.
                     if features.insert(mod_name.to_owned()) {
                         writeln!(cargo_toml, "{} = []", mod_name).unwrap();
                     }
+
+                    write_consts(&mod_rs, &format!("{}/{}_consts.rs", src_arch, mod_name));
+                    writeln!(
+                        src_arch_mod_rs,
+                        "/// The constants from {}, without its types.",
+                        header_name.to_str().unwrap()
+                    )
+                    .unwrap();
+                    writeln!(
+                        src_arch_mod_rs,
+                        "#[cfg(all(feature = \"const-only\", feature = \"{}\"))]",
+                        mod_name
+                    )
+                    .unwrap();
+                    writeln!(
+                        src_arch_mod_rs,
+                        "#[cfg_attr(docsrs, doc(cfg(all(feature = \"const-only\", feature = \"{}\"))))]",
+                        mod_name
+                    )
+                    .unwrap();
+                    writeln!(src_arch_mod_rs, "pub mod {}_consts;", mod_name).unwrap();
                 }
+
+                write_kernel_types(&mut src_arch_mod_rs, &format!("{}/general.rs", src_arch));
             }
 
             fs::remove_dir_all(&linux_headers).unwrap();
         }
+
+        share_common_items(&src_vers);
     }
 
+    write_since_tables("../src/since.rs");
+    write_arch_constants_test(
+        FEATURE_LINUX_VERSIONS.last().unwrap(),
+        "../tests/arch_constants.rs",
+    );
+    write_build_rs_versions("../build.rs");
+    write_manifest("../src/MANIFEST");
+
+    // Make the newest version available as `latest`.
+    let latest_version_mod = LINUX_VERSIONS.last().unwrap().replace('.', "_");
+    writeln!(src_lib_rs, "#[cfg(feature = \"latest\")]").unwrap();
+    writeln!(
+        src_lib_rs,
+        "#[cfg_attr(docsrs, doc(cfg(feature = \"latest\")))]"
+    )
+    .unwrap();
+    writeln!(src_lib_rs, "pub use {} as latest;", latest_version_mod).unwrap();
+
     writeln!(cargo_toml, "default = [\"std\", {}]", DEFAULT_FEATURES).unwrap();
     writeln!(cargo_toml, "std = []").unwrap();
     writeln!(cargo_toml, "no_std = []").unwrap();
+    writeln!(cargo_toml, "typed-flags = [\"general\"]").unwrap();
+    writeln!(cargo_toml, "derive-debug = []").unwrap();
+    writeln!(cargo_toml, "union-debug = [\"derive-debug\"]").unwrap();
+    writeln!(cargo_toml, "derive-eq = []").unwrap();
+    writeln!(cargo_toml, "openat2 = [\"general\"]").unwrap();
+    writeln!(cargo_toml, "latest = [\"{}\"]", latest_version_mod).unwrap();
+    writeln!(cargo_toml, "since = []").unwrap();
+    writeln!(cargo_toml, "const-only = []").unwrap();
     writeln!(
         cargo_toml,
         "rustc-dep-of-std = [\"core\", \"compiler_builtins\", \"no_std\"]"
@@ -371,8 +606,6 @@ fn run_bindgen(
     rust_arch: &str,
     linux_version: &str,
 ) {
-    let clang_arch = compute_clang_arch(rust_arch);
-
     eprintln!(
         "Generating bindings for {} on Linux {} architecture {}",
         mod_name, linux_version, rust_arch
@@ -384,18 +617,15 @@ fn run_bindgen(
         .rustfmt_configuration_file(Some(Path::new("bindgen-rustfmt.toml").to_owned()))
         .layout_tests(false)
         .generate_comments(false)
+        // Rust enums can't hold values the kernel adds later, so they must
+        // not be used for fields the kernel writes; tests/enum_fields.rs
+        // checks that no struct or union field has an enum type.
         .default_enum_style(EnumVariation::Rust {
             non_exhaustive: true,
         })
         .array_pointers_in_arguments(true)
         .derive_debug(true)
-        .clang_arg(&format!("--target={}-unknown-linux", clang_arch))
-        .clang_arg("-DBITS_PER_LONG=(__SIZEOF_LONG__*__CHAR_BIT__)")
-        .clang_arg("-nostdinc")
-        .clang_arg("-I")
-        .clang_arg(linux_include)
-        .clang_arg("-I")
-        .clang_arg("include")
+        .clang_args(clang_args(linux_include, rust_arch))
         .blocklist_item("NULL");
 
     let bindings = builder
@@ -407,6 +637,1357 @@ fn run_bindgen(
     bindings
         .write_to_file(mod_rs)
         .expect(&format!("write_to_file for {}", mod_name));
+
+    let names = ioctl_names(linux_include, header_name, rust_arch);
+    let ioctls = ioctl_values(linux_include, header_name, rust_arch, names);
+    rewrite_ioctls(mod_rs, &ioctls);
+
+    derives::add_derives(mod_rs, rust_arch);
+
+    if mod_name == "general" {
+        append_syscall_names(mod_rs);
+        append_typed_flags(mod_rs);
+        append_siginfo_accessors(mod_rs);
+    }
+    if mod_name == "errno" {
+        append_errno_names(mod_rs);
+    }
+    append_zeroed_defaults(mod_rs, rust_arch);
+    append_kernel_filled(mod_rs);
+    append_packed_accessors(mod_rs);
+    append_size_assertions(mod_rs);
+    if mod_name == "general" {
+        append_statx_mask(mod_rs);
+    }
+}
+
+/// The arguments to clang for compiling the headers in `linux_include` for
+/// `rust_arch`.
+fn clang_args(linux_include: &str, rust_arch: &str) -> Vec<String> {
+    vec![
+        format!("--target={}-unknown-linux", compute_clang_arch(rust_arch)),
+        "-DBITS_PER_LONG=(__SIZEOF_LONG__*__CHAR_BIT__)".to_owned(),
+        "-nostdinc".to_owned(),
+        "-I".to_owned(),
+        linux_include.to_owned(),
+        "-I".to_owned(),
+        "include".to_owned(),
+    ]
+}
+
+/// The clang binary to run for the things bindgen doesn't do, which can be
+/// overridden with `CLANG`.
+fn clang() -> Command {
+    Command::new(env::var("CLANG").unwrap_or_else(|_| "clang".to_owned()))
+}
+
+/// The macros in <asm-generic/ioctl.h> and the architectures' <asm/ioctl.h>
+/// which ioctl request codes are made with.
+const IOCTL_MACROS: [&str; 5] = ["_IO", "_IOR", "_IOW", "_IOWR", "_IOC"];
+
+/// Find the names of the ioctl request codes defined by `header_name`: the
+/// macros made with `IOCTL_MACROS`, directly or through other macros, and the
+/// plain numbers in <asm/ioctls.h> and <asm/sockios.h> and their generic and
+/// `linux/` versions, which predate `_IOC`.
+fn ioctl_names(linux_include: &str, header_name: &str, rust_arch: &str) -> Vec<String> {
+    let output = clang()
+        .args(clang_args(linux_include, rust_arch))
+        .args(["-E", "-dD", header_name])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "clang -E {}: {}",
+        header_name,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let output = String::from_utf8(output.stdout).unwrap();
+
+    // Collect the name, parameter list and body of each `#define`, and the
+    // file it's in, from the line markers.
+    let mut file = "";
+    let mut defines = Vec::new();
+    for line in output.lines() {
+        if let Some(marker) = line.strip_prefix("# ") {
+            if let Some(path) = marker.split('"').nth(1) {
+                file = path;
+            }
+        } else if let Some(define) = line.strip_prefix("#define ") {
+            let end = define
+                .find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(define.len());
+            let (name, rest) = define.split_at(end);
+            match rest.strip_prefix('(') {
+                Some(rest) => defines.push((name, true, rest.split_once(')').unwrap().1, file)),
+                None => defines.push((name, false, rest.trim(), file)),
+            }
+        }
+    }
+
+    let mut makers = IOCTL_MACROS.iter().copied().collect::<HashSet<_>>();
+    let mut names = HashSet::new();
+    for (name, function, body, file) in &defines {
+        let plain = !function
+            && (file.ends_with("/ioctls.h") || file.ends_with("/sockios.h"))
+            && parse_c_int(body).is_some_and(|value| (value >> 8) & 0xff != 0);
+        if plain {
+            names.insert(*name);
+        }
+    }
+    // Follow uses of the macros until there are no new ones, since macros
+    // can be defined in terms of macros defined after them.
+    loop {
+        let count = makers.len() + names.len();
+        for (name, function, body, _) in &defines {
+            if c_tokens(body).any(|token| makers.contains(token) || names.contains(token)) {
+                if *function {
+                    makers.insert(*name);
+                } else {
+                    names.insert(*name);
+                }
+            }
+        }
+        if makers.len() + names.len() == count {
+            break;
+        }
+    }
+
+    let mut names = names.into_iter().map(str::to_owned).collect::<Vec<_>>();
+    names.sort_unstable();
+    names
+}
+
+/// Split `body` into identifiers and numbers, with some empty strings.
+fn c_tokens(body: &str) -> impl Iterator<Item = &str> {
+    body.split(|c: char| !c.is_alphanumeric() && c != '_')
+}
+
+/// Parse a C integer literal, such as `0x5401` or `0x89E0U`.
+fn parse_c_int(literal: &str) -> Option<u64> {
+    let literal = literal.trim_end_matches(&['u', 'U', 'l', 'L'][..]);
+    if let Some(hex) = literal
+        .strip_prefix("0x")
+        .or_else(|| literal.strip_prefix("0X"))
+    {
+        u64::from_str_radix(hex, 16).ok()
+    } else if literal.len() > 1 && literal.starts_with('0') {
+        u64::from_str_radix(&literal[1..], 8).ok()
+    } else {
+        literal.parse().ok()
+    }
+}
+
+/// Evaluate the ioctl request codes `names` from `header_name` as `unsigned
+/// int`, the type the `ioctl` syscall takes them as, with bindgen.
+fn ioctl_values(
+    linux_include: &str,
+    header_name: &str,
+    rust_arch: &str,
+    mut names: Vec<String>,
+) -> BTreeMap<String, u32> {
+    let dir = tempdir::TempDir::new("linux-raw-sys-ioctls").unwrap();
+    let header = dir.path().join("ioctls.h");
+    let include = fs::canonicalize(header_name).unwrap();
+
+    // Some codes can't be evaluated on their own, such as ones using the size
+    // of a struct from a header that isn't included, and bindgen gives up on
+    // any errors, so leave out the lines clang reports errors on until there
+    // are none.
+    loop {
+        let mut contents = format!("#include \"{}\"\n", include.display());
+        for name in &names {
+            contents.push_str(&format!(
+                "static const unsigned int linux_raw_sys_ioctl_{0} = {0};\n",
+                name
+            ));
+        }
+        fs::write(&header, contents).unwrap();
+
+        let output = clang()
+            .args(clang_args(linux_include, rust_arch))
+            .args(["-fsyntax-only", "-ferror-limit=0"])
+            .arg(&header)
+            .output()
+            .unwrap();
+        if output.status.success() {
+            break;
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let prefix = format!("{}:", header.display());
+        let errors = stderr
+            .lines()
+            .filter(|line| line.contains(": error: "))
+            .filter_map(|line| line.strip_prefix(&prefix)?.split(':').next()?.parse().ok())
+            .collect::<HashSet<usize>>();
+        assert!(!errors.is_empty(), "clang {}: {}", header_name, stderr);
+        // Line 1 is the `#include`.
+        names = names
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| !errors.contains(&(index + 2)))
+            .map(|(_, name)| name)
+            .collect();
+    }
+
+    let bindings = builder()
+        .clang_args(clang_args(linux_include, rust_arch))
+        .allowlist_var("linux_raw_sys_ioctl_.*")
+        .use_core()
+        .ctypes_prefix("crate::ctypes")
+        .header(header.to_str().unwrap())
+        .generate()
+        .unwrap_or_else(|_| panic!("generate ioctl values for {}", header_name))
+        .to_string();
+
+    // The items look like `pub const linux_raw_sys_ioctl_TCGETS:
+    // crate::ctypes::c_uint = 21505;`, possibly split over several lines.
+    let mut ioctls = BTreeMap::new();
+    for item in bindings.split(';') {
+        let item = item.split_whitespace().collect::<Vec<_>>().join(" ");
+        if let Some((name, value)) = item
+            .strip_prefix("pub const linux_raw_sys_ioctl_")
+            .and_then(|rest| rest.split_once(": crate::ctypes::c_uint = "))
+        {
+            ioctls.insert(name.to_owned(), value.parse().unwrap());
+        }
+    }
+    ioctls
+}
+
+/// Give the ioctl request codes in the bindings in `mod_rs` the type
+/// `crate::Ioctl`, in place of the `u32` or `i32` bindgen picks from their
+/// values, and add the ones bindgen can't evaluate itself, such as the ones
+/// made with `_IOC`.
+fn rewrite_ioctls(mod_rs: &str, ioctls: &BTreeMap<String, u32>) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+
+    let mut missing = ioctls.clone();
+    let mut out = File::create(mod_rs).unwrap();
+    for line in contents.lines() {
+        let name = line
+            .strip_prefix("pub const ")
+            .and_then(|rest| rest.split_once(": "))
+            .map(|(name, _)| name);
+        match name.and_then(|name| missing.remove_entry(name)) {
+            Some((name, value)) => {
+                writeln!(out, "pub const {}: crate::Ioctl = {};", name, value).unwrap()
+            }
+            None => writeln!(out, "{}", line).unwrap(),
+        }
+    }
+    for (name, value) in missing {
+        writeln!(out, "pub const {}: crate::Ioctl = {};", name, value).unwrap();
+    }
+}
+
+/// Append a `SYSCALL_NAMES` table, mapping syscall numbers back to their
+/// names, to the bindings in `mod_rs`, using its `__NR_*` constants.
+fn append_syscall_names(mod_rs: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+
+    let mut syscalls = Vec::new();
+    for line in contents.lines() {
+        let rest = match line.strip_prefix("pub const __NR_") {
+            Some(rest) => rest,
+            None => continue,
+        };
+        let (name, value) = match rest.split_once(": u32 = ") {
+            Some(pair) => pair,
+            None => continue,
+        };
+        // Skip things like `__NR_syscalls` and `__NR_Linux` which aren't
+        // syscalls themselves, asm-generic's `__NR_arch_specific_syscall`,
+        // which is the base of the architecture's own numbers, and mips'
+        // placeholders for unused numbers, such as `__NR_unused18` and
+        // `__NR_reserved82`.
+        if name == "syscalls"
+            || name == "arch_specific_syscall"
+            || name.chars().any(|c| c.is_ascii_uppercase())
+            || ["unused", "reserved"].iter().any(|prefix| {
+                matches!(name.strip_prefix(prefix),
+                    Some(n) if !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+            })
+        {
+            continue;
+        }
+        let nr: u32 = value.trim_end_matches(';').parse().unwrap();
+        syscalls.push((nr, name));
+    }
+    if syscalls.is_empty() {
+        return;
+    }
+
+    // Some architectures, such as mips, number their syscalls from a
+    // non-zero base, so index the table relative to that.
+    let base = syscalls.iter().map(|(nr, _)| *nr).min().unwrap();
+    let end = syscalls.iter().map(|(nr, _)| *nr).max().unwrap() + 1;
+    let mut names = vec![None; (end - base) as usize];
+    for (nr, name) in syscalls {
+        let slot = &mut names[(nr - base) as usize];
+        if slot.is_none() {
+            *slot = Some(name);
+        }
+    }
+
+    let mut out = fs::OpenOptions::new().append(true).open(mod_rs).unwrap();
+    writeln!(
+        out,
+        "/// The syscall number corresponding to index 0 of `SYSCALL_NAMES`."
+    )
+    .unwrap();
+    writeln!(out, "pub const SYSCALL_NAMES_BASE: u32 = {};", base).unwrap();
+    writeln!(
+        out,
+        "/// The names of the syscalls, indexed by syscall number minus `SYSCALL_NAMES_BASE`."
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub static SYSCALL_NAMES: &[::core::option::Option<&str>] = &["
+    )
+    .unwrap();
+    for name in names {
+        match name {
+            Some(name) => writeln!(out, "Some(\"{}\"),", name).unwrap(),
+            None => writeln!(out, "None,").unwrap(),
+        }
+    }
+    writeln!(out, "];").unwrap();
+}
+
+/// Append an `ERRNO_NAMES` table, pairing each errno value in the bindings in
+/// `mod_rs` with its name, to `mod_rs`.
+fn append_errno_names(mod_rs: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+
+    let mut errnos = Vec::new();
+    for line in contents.lines() {
+        let (name, value) = match line
+            .strip_prefix("pub const ")
+            .and_then(|rest| rest.split_once(": u32 = "))
+        {
+            Some(pair) => pair,
+            None => continue,
+        };
+        let value: u32 = value.trim_end_matches(';').parse().unwrap();
+        errnos.push((value, name));
+    }
+
+    // Sort by value, keeping aliases such as `EWOULDBLOCK` after the names
+    // the headers define first.
+    errnos.sort_by_key(|(value, _)| *value);
+
+    let mut out = fs::OpenOptions::new().append(true).open(mod_rs).unwrap();
+    writeln!(
+        out,
+        "/// The errno values and their names, sorted by value, with aliases after the names they alias."
+    )
+    .unwrap();
+    writeln!(out, "pub static ERRNO_NAMES: &[(u32, &str)] = &[").unwrap();
+    for (value, name) in errnos {
+        writeln!(out, "({}, \"{}\"),", value, name).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+/// Append an invocation of the `typed_flags!` macro, declaring a wrapper type
+/// for each of the `TYPED_FLAGS` families, to the bindings in `mod_rs`.
+fn append_typed_flags(mod_rs: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+
+    let mut families = Vec::new();
+    for (type_name, int_type, prefix) in &TYPED_FLAGS {
+        let mut flags = Vec::new();
+        for line in contents.lines() {
+            let name = match line
+                .strip_prefix("pub const ")
+                .and_then(|rest| rest.split_once(':'))
+            {
+                Some((name, _)) => name,
+                None => continue,
+            };
+            let rest = match name.strip_prefix(prefix) {
+                Some(rest) if !rest.is_empty() => rest,
+                _ => continue,
+            };
+            // Skip field encodings and sizes, which aren't flags, and, for
+            // `EPOLL`, the `EPOLL_CTL_*` ops and `EPOLL_CLOEXEC`.
+            if name.ends_with("_MASK")
+                || name.ends_with("_SHIFT")
+                || name.contains("_SIZE_")
+                || (*prefix == "EPOLL" && rest.contains('_'))
+            {
+                continue;
+            }
+            flags.push(name);
+        }
+        if !flags.is_empty() {
+            families.push((type_name, int_type, flags));
+        }
+    }
+    if families.is_empty() {
+        return;
+    }
+
+    let mut out = fs::OpenOptions::new().append(true).open(mod_rs).unwrap();
+    writeln!(out, "#[cfg(feature = \"typed-flags\")]").unwrap();
+    writeln!(out, "typed_flags! {{").unwrap();
+    for (type_name, int_type, flags) in families {
+        writeln!(out, "{}: {} {{", type_name, int_type).unwrap();
+        for flag in flags {
+            writeln!(out, "{},", flag).unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+}
+
+/// Append the `SIGINFO_ACCESSORS` to `siginfo` in the bindings in `mod_rs`.
+///
+/// Older headers declare `_sifields` directly in `siginfo`, which bindgen
+/// translates with `__BindgenUnionField`, while newer ones wrap everything
+/// in a union padded to 128 bytes, so the paths to the fields differ.
+fn append_siginfo_accessors(mod_rs: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+
+    let wrapped = if contents.contains("\npub struct siginfo {\npub __bindgen_anon_1: ") {
+        true
+    } else if contents.contains("\npub struct siginfo {\n") {
+        false
+    } else {
+        return;
+    };
+
+    let mut out = fs::OpenOptions::new().append(true).open(mod_rs).unwrap();
+    writeln!(out, "impl siginfo {{").unwrap();
+    for (name, ty, member, field, doc) in &SIGINFO_ACCESSORS {
+        writeln!(out, "/// {}", doc).unwrap();
+        let path = match (member, wrapped) {
+            // The header overlaps the padding, so it's always initialized.
+            (None, true) => format!(
+                "unsafe {{ self.__bindgen_anon_1.__bindgen_anon_1.{} }}",
+                field
+            ),
+            (None, false) => format!("self.{}", field),
+            (Some(member), true) => format!(
+                "self.__bindgen_anon_1.__bindgen_anon_1._sifields.{}.{}",
+                member, field
+            ),
+            (Some(member), false) => format!("self._sifields.{}.as_ref().{}", member, field),
+        };
+        if member.is_some() {
+            writeln!(out, "///").unwrap();
+            writeln!(out, "/// # Safety").unwrap();
+            writeln!(out, "///").unwrap();
+            writeln!(
+                out,
+                "/// The signal must be one for which the kernel fills in this field."
+            )
+            .unwrap();
+            writeln!(out, "#[inline]").unwrap();
+            writeln!(out, "pub unsafe fn {}(&self) -> {} {{", name, ty).unwrap();
+        } else {
+            writeln!(out, "#[inline]").unwrap();
+            writeln!(out, "pub fn {}(&self) -> {} {{", name, ty).unwrap();
+        }
+        writeln!(out, "{}", path).unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+}
+
+/// Append `zeroed()` and `zero_in_place` constructors, and `Default` impls
+/// which use `zeroed()`, for each of the `ZEROED_STRUCTS` defined in the
+/// bindings in `mod_rs`. Their docs list the struct's padding on
+/// `rust_arch`, which only `zero_in_place` is sure to zero.
+fn append_zeroed_defaults(mod_rs: &str, rust_arch: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+
+    let mut out = fs::OpenOptions::new().append(true).open(mod_rs).unwrap();
+    for name in &ZEROED_STRUCTS {
+        if !contents.contains(&format!("\npub struct {} {{\n", name)) {
+            continue;
+        }
+        // The layout of structs with bitfields, such as `perf_event_attr`,
+        // isn't computed, so their docs don't list their padding.
+        let padding = derives::padding(&contents, rust_arch, name).unwrap_or_default();
+
+        let article = if name.starts_with(['a', 'e', 'i', 'o', 'u']) {
+            "an"
+        } else {
+            "a"
+        };
+
+        writeln!(out, "impl {} {{", name).unwrap();
+        writeln!(
+            out,
+            "/// Return {} `{}` with all of its fields set to zero.",
+            article, name
+        )
+        .unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(
+            out,
+            "/// The kernel fails with `EINVAL` if fields it doesn't use are nonzero, so start from this and then set the fields which are needed."
+        )
+        .unwrap();
+        if !padding.is_empty() {
+            let ranges = padding
+                .iter()
+                .map(|range| format!("{}..{}", range.start, range.end))
+                .collect::<Vec<_>>();
+            writeln!(out, "///").unwrap();
+            writeln!(
+                out,
+                "/// Its padding is at bytes {}, which struct literals leave uninitialized, and which a value that's returned or moved needn't keep, so use `zero_in_place` for one to pass to the kernel.",
+                ranges.join(", ")
+            )
+            .unwrap();
+        }
+        writeln!(out, "#[inline]").unwrap();
+        writeln!(out, "pub fn zeroed() -> Self {{").unwrap();
+        writeln!(out, "unsafe {{ ::core::mem::zeroed() }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(
+            out,
+            "/// Set all of the bytes of `uninit`, including any padding, to zero, and return a reference to the `{}` it then holds.",
+            name
+        )
+        .unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(
+            out,
+            "/// The padding stays zero as long as only fields are assigned through the reference, so pass that to the kernel rather than a copy."
+        )
+        .unwrap();
+        writeln!(out, "#[inline]").unwrap();
+        writeln!(
+            out,
+            "pub fn zero_in_place(uninit: &mut ::core::mem::MaybeUninit<Self>) -> &mut Self {{"
+        )
+        .unwrap();
+        writeln!(out, "unsafe {{").unwrap();
+        writeln!(out, "uninit.as_mut_ptr().write_bytes(0, 1);").unwrap();
+        writeln!(out, "&mut *uninit.as_mut_ptr()").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out, "impl Default for {} {{", name).unwrap();
+        writeln!(out, "#[inline]").unwrap();
+        writeln!(out, "fn default() -> Self {{").unwrap();
+        writeln!(out, "Self::zeroed()").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+}
+
+/// Append `from_bytes_unchecked` and `assume_init_ref` helpers to each of
+/// the `KERNEL_FILLED_STRUCTS` defined in the bindings in `mod_rs`.
+fn append_kernel_filled(mod_rs: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+
+    let mut out = fs::OpenOptions::new().append(true).open(mod_rs).unwrap();
+    for name in &KERNEL_FILLED_STRUCTS {
+        if !contents.contains(&format!("\npub struct {} {{\n", name)) {
+            continue;
+        }
+
+        writeln!(out, "impl {} {{", name).unwrap();
+        writeln!(
+            out,
+            "/// Read a `{}` from `bytes`, which need not be aligned, such as a buffer the kernel wrote it into.",
+            name
+        )
+        .unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(out, "/// # Safety").unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(
+            out,
+            "/// `bytes` must be at least `size_of::<Self>()` bytes long, and those bytes must be initialized."
+        )
+        .unwrap();
+        writeln!(out, "#[inline]").unwrap();
+        writeln!(
+            out,
+            "pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> Self {{"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "debug_assert!(bytes.len() >= ::core::mem::size_of::<Self>());"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "::core::ptr::read_unaligned(bytes.as_ptr().cast::<Self>())"
+        )
+        .unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(
+            out,
+            "/// Get a reference to a `{}` the kernel wrote into `uninit`, such as by passing its `as_mut_ptr()` to a syscall.",
+            name
+        )
+        .unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(out, "/// # Safety").unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(
+            out,
+            "/// All of `uninit` must have been initialized, such as by a successful syscall."
+        )
+        .unwrap();
+        writeln!(out, "#[inline]").unwrap();
+        writeln!(
+            out,
+            "pub unsafe fn assume_init_ref(uninit: &::core::mem::MaybeUninit<Self>) -> &Self {{"
+        )
+        .unwrap();
+        writeln!(out, "&*uninit.as_ptr()").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+}
+
+/// Append getters and setters for the fields of the packed structs in the
+/// bindings in `mod_rs`, which read and write them with `read_unaligned`
+/// and `write_unaligned`, since references to them may be misaligned.
+fn append_packed_accessors(mod_rs: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+
+    let mut out = fs::OpenOptions::new().append(true).open(mod_rs).unwrap();
+    let mut packed = false;
+    let mut current: Option<(&str, Vec<(&str, &str)>)> = None;
+    for line in contents.lines() {
+        if let Some((name, fields)) = &mut current {
+            if line != "}" {
+                let field = line.strip_prefix("pub ").and_then(|f| f.strip_suffix(','));
+                // Skip bitfield storage and padding, which are named `_*`.
+                if let Some((field, ty)) = field.and_then(|f| f.split_once(": ")) {
+                    if !field.starts_with('_') {
+                        fields.push((field, ty));
+                    }
+                }
+                continue;
+            }
+
+            writeln!(out, "impl {} {{", name).unwrap();
+            for (field, ty) in fields.iter() {
+                writeln!(out, "#[inline]").unwrap();
+                writeln!(out, "pub fn {}(&self) -> {} {{", field, ty).unwrap();
+                writeln!(
+                    out,
+                    "unsafe {{ ::core::ptr::addr_of!(self.{}).read_unaligned() }}",
+                    field
+                )
+                .unwrap();
+                writeln!(out, "}}").unwrap();
+                writeln!(out, "#[inline]").unwrap();
+                writeln!(out, "pub fn set_{}(&mut self, val: {}) {{", field, ty).unwrap();
+                writeln!(
+                    out,
+                    "unsafe {{ ::core::ptr::addr_of_mut!(self.{}).write_unaligned(val) }}",
+                    field
+                )
+                .unwrap();
+                writeln!(out, "}}").unwrap();
+            }
+            writeln!(out, "}}").unwrap();
+            current = None;
+        } else if line.starts_with("#[repr(C, packed") {
+            packed = true;
+        } else if line.starts_with("#[") {
+            // Derives and other attributes come between the repr and the
+            // struct.
+        } else {
+            if packed {
+                current = line
+                    .strip_prefix("pub struct ")
+                    .and_then(|rest| rest.strip_suffix(" {"))
+                    .map(|name| (name, Vec::new()));
+            }
+            packed = false;
+        }
+    }
+}
+
+/// Append static assertions that the size of each of the `SIZE_VER_STRUCTS`
+/// defined in the bindings in `mod_rs` matches its latest `*_SIZE_VER*`
+/// constant, and that each of the `FIXED_SIZE_STRUCTS` has its fixed size.
+fn append_size_assertions(mod_rs: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+
+    let mut out = fs::OpenOptions::new().append(true).open(mod_rs).unwrap();
+    for (name, prefix) in &SIZE_VER_STRUCTS {
+        if !contents.contains(&format!("\npub struct {} {{\n", name)) {
+            continue;
+        }
+        let latest = contents
+            .lines()
+            .filter_map(|line| line.strip_prefix("pub const "))
+            .filter_map(|rest| rest.strip_prefix(prefix))
+            .filter_map(|rest| rest.strip_prefix("_SIZE_VER"))
+            .filter_map(|rest| rest.split(':').next().unwrap().parse::<u32>().ok())
+            .max();
+        if let Some(latest) = latest {
+            writeln!(
+                out,
+                "const _: () = assert!(::core::mem::size_of::<{}>() == {}_SIZE_VER{} as usize);",
+                name, prefix, latest
+            )
+            .unwrap();
+        }
+    }
+    for (name, size) in &FIXED_SIZE_STRUCTS {
+        if !contents.contains(&format!("\npub struct {} {{\n", name)) {
+            continue;
+        }
+        writeln!(
+            out,
+            "const _: () = assert!(::core::mem::size_of::<{}>() == {});",
+            name, size
+        )
+        .unwrap();
+    }
+}
+
+/// Append a `STATX_KNOWN_MASK` constant, combining all the `STATX_*` field
+/// mask bits in the bindings in `mod_rs`, if they have `statx`.
+fn append_statx_mask(mod_rs: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+    if !contents.contains("\npub struct statx {\n") {
+        return;
+    }
+
+    let mut bits = Vec::new();
+    for line in contents.lines() {
+        let rest = match line.strip_prefix("pub const STATX_") {
+            Some(rest) => rest,
+            None => continue,
+        };
+        let (name, value) = match rest.split_once(": u32 = ") {
+            Some(pair) => pair,
+            None => continue,
+        };
+        // Skip the attribute flags, the reserved bit, and the aggregates.
+        if name.starts_with("ATTR_")
+            || name.starts_with('_')
+            || name == "ALL"
+            || name == "BASIC_STATS"
+        {
+            continue;
+        }
+        let value = value.trim_end_matches(';').parse::<u32>().unwrap();
+        if value.is_power_of_two() {
+            bits.push(format!("STATX_{}", name));
+        }
+    }
+
+    let mut out = fs::OpenOptions::new().append(true).open(mod_rs).unwrap();
+    writeln!(
+        out,
+        "/// All the `STATX_*` field mask bits these bindings define. Unlike `STATX_ALL`, this includes the bits added after it was frozen."
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub const STATX_KNOWN_MASK: u32 = {};",
+        bits.join(" | ")
+    )
+    .unwrap();
+}
+
+/// Move the integer and byte string constants in the bindings in `mod_rs`
+/// to `consts_rs`, and `include!` them back, so that they can also be
+/// compiled on their own, for users who need only constants and not the time
+/// it takes to compile all the types.
+fn write_consts(mod_rs: &str, consts_rs: &str) {
+    let contents = fs::read_to_string(mod_rs).unwrap();
+
+    let mut out = File::create(consts_rs).unwrap();
+    let mut rest = File::create(mod_rs).unwrap();
+    writeln!(
+        rest,
+        "include!(\"{}\");",
+        Path::new(consts_rs).file_name().unwrap().to_str().unwrap()
+    )
+    .unwrap();
+    // Doc comments and attributes go wherever the item after them goes.
+    let mut pending = String::new();
+    for line in contents.lines() {
+        if line.starts_with("///") || line.starts_with("#[") {
+            pending.push_str(line);
+            pending.push('\n');
+            continue;
+        }
+        let ty = line
+            .strip_prefix("pub const ")
+            .and_then(|rest| rest.split_once(": "))
+            .filter(|(name, _)| name.chars().all(|c| c.is_alphanumeric() || c == '_'))
+            .and_then(|(_, rest)| Some(rest.split_once(" = ")?.0));
+        // Leave constants of enum types, which need the enums, and
+        // everything else, in place.
+        let dest = match ty {
+            Some("u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" | "crate::Ioctl") => {
+                &mut out
+            }
+            Some(ty) if ty.starts_with("&'static [u8; ") => &mut out,
+            _ => &mut rest,
+        };
+        dest.write_all(std::mem::take(&mut pending).as_bytes())
+            .unwrap();
+        writeln!(dest, "{}", line).unwrap();
+    }
+    rest.write_all(pending.as_bytes()).unwrap();
+}
+
+/// Write a `kernel_types` module to `mod_rs`, re-exporting all the
+/// `__kernel_*` types in the `general` bindings in `general_rs`.
+fn write_kernel_types(mod_rs: &mut File, general_rs: &str) {
+    let contents = fs::read_to_string(general_rs).unwrap();
+
+    let mut names = contents
+        .lines()
+        .filter_map(|line| {
+            line.strip_prefix("pub type ")
+                .or_else(|| line.strip_prefix("pub struct "))
+                .or_else(|| line.strip_prefix("pub union "))
+        })
+        .map(|rest| {
+            rest.split(|c: char| !c.is_alphanumeric() && c != '_')
+                .next()
+                .unwrap()
+        })
+        .filter(|name| name.starts_with("__kernel_") && !name.contains("__bindgen"))
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+
+    writeln!(
+        mod_rs,
+        "/// The `__kernel_*` types, such as `__kernel_size_t` and `__kernel_loff_t`."
+    )
+    .unwrap();
+    writeln!(mod_rs, "#[cfg(feature = \"general\")]").unwrap();
+    writeln!(
+        mod_rs,
+        "#[cfg_attr(docsrs, doc(cfg(feature = \"general\")))]"
+    )
+    .unwrap();
+    writeln!(mod_rs, "pub mod kernel_types {{").unwrap();
+    for name in names {
+        writeln!(mod_rs, "pub use super::general::{};", name).unwrap();
+    }
+    writeln!(mod_rs, "}}").unwrap();
+}
+
+/// Whether `linux_version` is generated for all architectures, and so may be
+/// selected with `LINUX_RAW_SYS_VERSION`.
+fn is_all_arch_version(linux_version: &str) -> bool {
+    FEATURE_LINUX_VERSIONS.contains(&linux_version)
+        || !DEFAULT_LINUX_VERSIONS
+            .iter()
+            .any(|default| linux_version == default.1)
+}
+
+/// Rewrite the generated part of `build_rs` with the list of versions which
+/// `LINUX_RAW_SYS_VERSION` may select, and of the default version of each
+/// architecture which has one.
+fn write_build_rs_versions(build_rs: &str) {
+    let contents = fs::read_to_string(build_rs).unwrap();
+    let marker = "// The rest of this file is auto-generated!\n";
+    let edit_at = contents.find(marker).unwrap();
+    let mut out = File::create(build_rs).unwrap();
+    out.write_all(&contents.as_bytes()[..edit_at]).unwrap();
+    out.write_all(marker.as_bytes()).unwrap();
+    writeln!(
+        out,
+        "\n/// The versions which are generated for all architectures."
+    )
+    .unwrap();
+    writeln!(out, "const VERSIONS: &[&str] = &[").unwrap();
+    for linux_version in LINUX_VERSIONS.iter().filter(|v| is_all_arch_version(v)) {
+        writeln!(out, "    \"{}\",", linux_version.replace('.', "_")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(
+        out,
+        "\n/// The architectures which have a default version, and their versions."
+    )
+    .unwrap();
+    writeln!(out, "const DEFAULT_VERSIONS: &[(&str, &str)] = &[").unwrap();
+    for (arch, linux_version) in DEFAULT_LINUX_VERSIONS {
+        writeln!(
+            out,
+            "    (\"{}\", \"{}\"),",
+            arch,
+            linux_version.replace('.', "_")
+        )
+        .unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+/// Split `contents`, the generated bindings for one module, into its
+/// top-level items, each with its preceding attributes and comments.
+fn split_items(contents: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut item = String::new();
+    let mut depth = 0_i32;
+    for line in contents.lines() {
+        item.push_str(line);
+        item.push('\n');
+
+        let mut in_str = false;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if in_str {
+                match c {
+                    '\\' => {
+                        chars.next();
+                    }
+                    '"' => in_str = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match c {
+                '"' => in_str = true,
+                '/' if chars.peek() == Some(&'/') => break,
+                '{' | '(' | '[' => depth += 1,
+                '}' | ')' | ']' => depth -= 1,
+                _ => {}
+            }
+        }
+
+        let line = line.trim();
+        if depth == 0 && !line.starts_with("#[") && (line.ends_with(';') || line.ends_with('}')) {
+            items.push(std::mem::take(&mut item));
+        }
+    }
+    // Keep trailing comments, such as bindgen's header in a file with no
+    // items left after moving its constants out.
+    assert!(
+        item.lines()
+            .all(|line| line.trim().is_empty() || line.starts_with("//") || line.starts_with("/*")),
+        "unterminated item: {}",
+        item
+    );
+    if !item.trim().is_empty() {
+        items.push(item);
+    }
+    items
+}
+
+/// Move the items which are identical in all the architectures' bindings for
+/// each module in `src_vers` into `src_vers/shared`, and `include!` them
+/// from each architecture's bindings, to avoid shipping many copies of them.
+///
+/// The items are included rather than put in a module of their own so that
+/// they're resolved in each architecture's context, as types like
+/// `crate::ctypes::c_long` and `__kernel_long_t` differ between them.
+fn share_common_items(src_vers: &str) {
+    let mut arches = fs::read_dir(src_vers)
+        .unwrap()
+        .map(|entry| entry.unwrap())
+        .filter(|entry| entry.file_type().unwrap().is_dir())
+        .map(|entry| entry.file_name().to_str().unwrap().to_owned())
+        .filter(|name| name != "shared")
+        .collect::<Vec<_>>();
+    arches.sort();
+    if arches.len() < 2 {
+        return;
+    }
+
+    let mut mod_names = fs::read_dir(format!("{}/{}", src_vers, arches[0]))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_str().unwrap().to_owned())
+        .filter(|name| name != "mod.rs")
+        .collect::<Vec<_>>();
+    mod_names.sort();
+
+    let shared_dir = format!("{}/shared", src_vers);
+    for mod_name in mod_names {
+        let arch_items = arches
+            .iter()
+            .map(|arch| {
+                split_items(
+                    &fs::read_to_string(format!("{}/{}/{}", src_vers, arch, mod_name)).unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Count how many times each item occurs in all the architectures.
+        // Leave `include!`s in place, as their paths are relative.
+        let mut common: HashMap<&str, usize> = HashMap::new();
+        for item in &arch_items[0] {
+            if !item.starts_with("include!(") {
+                *common.entry(item).or_default() += 1;
+            }
+        }
+        for items in &arch_items[1..] {
+            let mut counts: HashMap<&str, usize> = HashMap::new();
+            for item in items {
+                *counts.entry(item).or_default() += 1;
+            }
+            for (item, count) in common.iter_mut() {
+                *count = (*count).min(counts.get(item).copied().unwrap_or(0));
+            }
+        }
+        if common.values().all(|count| *count == 0) {
+            continue;
+        }
+
+        fs::create_dir_all(&shared_dir).unwrap();
+        let mut shared = common.clone();
+        let mut out = File::create(format!("{}/{}", shared_dir, mod_name)).unwrap();
+        for item in &arch_items[0] {
+            if let Some(count) = shared.get_mut(item.as_str()).filter(|count| **count > 0) {
+                *count -= 1;
+                out.write_all(item.as_bytes()).unwrap();
+            }
+        }
+
+        for (arch, items) in arches.iter().zip(&arch_items) {
+            let mut shared = common.clone();
+            let mut out = File::create(format!("{}/{}/{}", src_vers, arch, mod_name)).unwrap();
+            writeln!(out, "include!(\"../shared/{}\");", mod_name).unwrap();
+            for item in items {
+                match shared.get_mut(item.as_str()) {
+                    Some(count) if *count > 0 => *count -= 1,
+                    _ => out.write_all(item.as_bytes()).unwrap(),
+                }
+            }
+        }
+    }
+}
+
+/// Write `test_rs`, a test which checks the values of the constants in the
+/// `linux_version` bindings which every architecture defines, but not all
+/// with the same value, so that an architecture picking up another's values,
+/// such as the asm-generic ones, is caught. Syscall numbers are left out,
+/// since they differ almost everywhere.
+fn write_arch_constants_test(linux_version: &str, test_rs: &str) {
+    let vers_mod = linux_version.replace('.', "_");
+    let src_vers = format!("../src/{}", vers_mod);
+
+    let mut arches = fs::read_dir(&src_vers)
+        .unwrap()
+        .map(|entry| entry.unwrap())
+        .filter(|entry| entry.file_type().unwrap().is_dir() && entry.file_name() != "shared")
+        .map(|entry| entry.file_name().to_str().unwrap().to_owned())
+        .collect::<Vec<_>>();
+    arches.sort_unstable();
+
+    // Map each module to each architecture's constants.
+    let mut modules: BTreeMap<String, BTreeMap<&str, BTreeMap<String, String>>> = BTreeMap::new();
+    for arch in &arches {
+        for entry in fs::read_dir(format!("{}/{}", src_vers, arch)).unwrap() {
+            let path = entry.unwrap().path();
+            let mod_name = match path
+                .file_name()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .strip_suffix("_consts.rs")
+            {
+                Some(mod_name) => mod_name.to_owned(),
+                None => continue,
+            };
+            let consts = modules
+                .entry(mod_name)
+                .or_default()
+                .entry(arch.as_str())
+                .or_default();
+            for line in fs::read_to_string(&path).unwrap().lines() {
+                let (name, value) = match line
+                    .strip_prefix("pub const ")
+                    .and_then(|rest| rest.strip_suffix(';'))
+                    .and_then(|rest| rest.split_once(": "))
+                    .and_then(|(name, rest)| Some((name, rest.split_once(" = ")?)))
+                {
+                    Some((name, (_ty, value))) => (name, value),
+                    None => continue,
+                };
+                if !name.starts_with("__NR_") && value.parse::<i128>().is_ok() {
+                    consts.insert(name.to_owned(), value.to_owned());
+                }
+            }
+        }
+    }
+
+    let mut out = File::create(test_rs).unwrap();
+    writeln!(
+        out,
+        "//! Check the constants in the `{}` bindings whose values differ between",
+        vers_mod
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "//! architectures, so that an architecture picking up another's values, such"
+    )
+    .unwrap();
+    writeln!(out, "//! as the asm-generic ones, is caught.").unwrap();
+    writeln!(out, "//!").unwrap();
+    writeln!(out, "//! This file is auto-generated!").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "#![cfg(feature = \"{}\")]", vers_mod).unwrap();
+
+    for (mod_name, by_arch) in &modules {
+        if by_arch.len() != arches.len() {
+            continue;
+        }
+        let first = by_arch.values().next().unwrap();
+        let divergent = first
+            .keys()
+            .filter(|name| {
+                let values = by_arch
+                    .values()
+                    .map(|consts| consts.get(*name))
+                    .collect::<Vec<_>>();
+                values.iter().all(Option::is_some) && values.iter().any(|value| *value != values[0])
+            })
+            .collect::<Vec<_>>();
+        if divergent.is_empty() {
+            continue;
+        }
+
+        writeln!(out).unwrap();
+        writeln!(out, "#[cfg(feature = \"{}\")]", mod_name).unwrap();
+        writeln!(out, "#[test]").unwrap();
+        writeln!(out, "fn {}() {{", mod_name).unwrap();
+        writeln!(out, "    use linux_raw_sys::{}::{}::*;", vers_mod, mod_name).unwrap();
+        for (arch, consts) in by_arch {
+            writeln!(out).unwrap();
+            writeln!(out, "    #[cfg(target_arch = \"{}\")]", arch).unwrap();
+            writeln!(out, "    {{").unwrap();
+            for name in &divergent {
+                writeln!(out, "        assert_eq!({}, {});", name, consts[*name]).unwrap();
+            }
+            writeln!(out, "    }}").unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+}
+
+/// Rewrite the generated part of `since_rs` with a table for each generated
+/// module, mapping each item name to the `LINUX_VERSION_CODE` of the oldest
+/// version module which defines it on any architecture.
+fn write_since_tables(since_rs: &str) {
+    let mut tables: BTreeMap<String, BTreeMap<String, u32>> = BTreeMap::new();
+    for vers_entry in fs::read_dir("../src").unwrap() {
+        let vers_entry = vers_entry.unwrap();
+        if !vers_entry.file_type().unwrap().is_dir() {
+            continue;
+        }
+        let vers_dir = vers_entry.file_name().to_str().unwrap().to_owned();
+        let version_code = linux_version_code(&vers_dir.replace('_', "."));
+        for arch_entry in fs::read_dir(vers_entry.path()).unwrap() {
+            let arch_entry = arch_entry.unwrap();
+            if !arch_entry.file_type().unwrap().is_dir() {
+                continue;
+            }
+            for mod_entry in fs::read_dir(arch_entry.path()).unwrap() {
+                let mod_path = mod_entry.unwrap().path();
+                let mod_name = mod_path.file_stem().unwrap().to_str().unwrap().to_owned();
+                if mod_name == "mod" {
+                    continue;
+                }
+                // Count the constants under the module they're included in.
+                let mod_name = mod_name.trim_end_matches("_consts").to_owned();
+                let table = tables.entry(mod_name).or_default();
+                let contents = fs::read_to_string(&mod_path).unwrap();
+                for line in contents.lines() {
+                    let rest = match [
+                        "pub const ",
+                        "pub struct ",
+                        "pub union ",
+                        "pub type ",
+                        "pub enum ",
+                    ]
+                    .iter()
+                    .find_map(|prefix| line.strip_prefix(prefix))
+                    {
+                        Some(rest) => rest,
+                        None => continue,
+                    };
+                    let name = rest
+                        .split(|c: char| !c.is_alphanumeric() && c != '_')
+                        .next()
+                        .unwrap();
+                    if name.contains("__bindgen") {
+                        continue;
+                    }
+                    let since = table.entry(name.to_owned()).or_insert(version_code);
+                    *since = (*since).min(version_code);
+                }
+            }
+        }
+    }
+
+    let contents = fs::read_to_string(since_rs).unwrap();
+    let marker = "// The rest of this file is auto-generated!\n";
+    let edit_at = contents.find(marker).unwrap();
+    let mut out = File::create(since_rs).unwrap();
+    out.write_all(&contents.as_bytes()[..edit_at]).unwrap();
+    out.write_all(marker.as_bytes()).unwrap();
+    for (mod_name, table) in tables {
+        writeln!(
+            out,
+            "\n/// The oldest generated version defining each item in `{}`.",
+            mod_name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "pub static {}: &[(&str, u32)] = &[",
+            mod_name.to_uppercase()
+        )
+        .unwrap();
+        for (name, since) in table {
+            writeln!(out, "    (\"{}\", {:#08x}),", name, since).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+    }
+}
+
+/// The FNV-1a offset basis, the hash of no bytes.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Continue the 64-bit FNV-1a hash `hash` with `bytes`.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// List the files in `dir` and its subdirectories, sorted.
+fn files_in(dir: &Path) -> Vec<String> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files.extend(files_in(&path));
+        } else {
+            files.push(path.to_str().unwrap().to_owned());
+        }
+    }
+    files.sort_unstable();
+    files
+}
+
+/// Compute the manifest entries for the generated files under `src`: each
+/// file's path relative to `src`, the hash of its contents, and the hash of
+/// the inputs it was generated from. Those are the Linux version and
+/// architecture, the module's header in `modules`, or all of them for
+/// `mod.rs`, and the supplementary headers in `include`.
+fn manifest_entries(src: &str) -> Vec<(String, u64, u64)> {
+    let includes = files_in(Path::new("include"));
+    let modules = files_in(Path::new("modules"));
+
+    let mut entries = Vec::new();
+    for path in files_in(Path::new(src)) {
+        let rel = path[src.len()..].trim_start_matches('/');
+        // The version directories contain a `mod.rs` and a directory for
+        // each architecture, or `shared`.
+        let (vers, arch, file) = match rel.split('/').collect::<Vec<_>>()[..] {
+            [vers, file] if vers.starts_with('v') => (vers, "", file),
+            [vers, arch, file] if vers.starts_with('v') => (vers, arch, file),
+            _ => continue,
+        };
+
+        let mod_name = file.trim_end_matches(".rs").trim_end_matches("_consts");
+        let inputs = if mod_name == "mod" {
+            modules.clone()
+        } else {
+            vec![format!("modules/{}.h", mod_name)]
+        };
+        let mut input_hash = fnv1a(FNV_OFFSET_BASIS, format!("{}\0{}\0", vers, arch).as_bytes());
+        for input in inputs.iter().chain(&includes) {
+            input_hash = fnv1a(input_hash, input.as_bytes());
+            input_hash = fnv1a(input_hash, &fs::read(input).unwrap());
+        }
+
+        let output_hash = fnv1a(FNV_OFFSET_BASIS, &fs::read(&path).unwrap());
+        entries.push((rel.to_owned(), output_hash, input_hash));
+    }
+    entries
+}
+
+/// Write the manifest of the generated files under `../src` to `manifest`.
+fn write_manifest(manifest: &str) {
+    let mut out = File::create(manifest).unwrap();
+    writeln!(
+        out,
+        "# The generated files, with FNV-1a hashes of their contents and of the"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "# inputs they were generated from. This file is auto-generated!"
+    )
+    .unwrap();
+    for (path, output_hash, input_hash) in manifest_entries("../src") {
+        writeln!(out, "{:016x} {:016x} {}", output_hash, input_hash, path).unwrap();
+    }
+}
+
+/// Report the generated files under `../src` which differ from `manifest`:
+/// those which have been edited, whose inputs have changed since they were
+/// generated, or which are missing or not listed.
+fn check_manifest(manifest: &str) {
+    let mut recorded = HashMap::new();
+    for line in fs::read_to_string(manifest).unwrap().lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.splitn(3, ' ');
+        let output_hash = fields.next().unwrap().to_owned();
+        let input_hash = fields.next().unwrap().to_owned();
+        let path = fields.next().unwrap().to_owned();
+        recorded.insert(path, (output_hash, input_hash));
+    }
+
+    let mut problems = 0;
+    for (path, output_hash, input_hash) in manifest_entries("../src") {
+        let problem = match recorded.remove(&path) {
+            None => "not in the manifest",
+            Some((recorded_output, _)) if recorded_output != format!("{:016x}", output_hash) => {
+                "edited since it was generated"
+            }
+            Some((_, recorded_input)) if recorded_input != format!("{:016x}", input_hash) => {
+                "stale; its inputs have changed"
+            }
+            Some(_) => continue,
+        };
+        eprintln!("{}: {}", path, problem);
+        problems += 1;
+    }
+    let mut missing = recorded.keys().collect::<Vec<_>>();
+    missing.sort_unstable();
+    for path in missing {
+        eprintln!("{}: missing", path);
+        problems += 1;
+    }
+
+    if problems != 0 {
+        eprintln!("{} generated files differ from the manifest", problems);
+        std::process::exit(1);
+    }
+}
+
+/// Compute the `LINUX_VERSION_CODE` for a version tag like "v5.11".
+fn linux_version_code(linux_version: &str) -> u32 {
+    let mut parts = linux_version
+        .trim_start_matches('v')
+        .split('.')
+        .map(|part| part.parse::<u32>().unwrap());
+    let major = parts.next().unwrap();
+    let minor = parts.next().unwrap_or(0);
+    let patch = parts.next().unwrap_or(0);
+    (major << 16) + (minor << 8) + patch
 }
 
 fn compute_clang_arch(rust_arch: &str) -> &str {
@@ -420,7 +2001,14 @@ fn compute_clang_arch(rust_arch: &str) -> &str {
 fn gen_cfg_any(cfgs: &[String]) -> String {
     match &cfgs[..] {
         [] => String::new(),
-        [cfg] => format!("#[cfg({})]", cfg),
-        cfgs => format!("#[cfg(any({}))]", cfgs.join(", ")),
+        cfgs => format!("#[cfg({})]", cfg_any(cfgs)),
+    }
+}
+
+/// The cfg predicate which is true if any of `cfgs` is.
+fn cfg_any(cfgs: &[String]) -> String {
+    match cfgs {
+        [cfg] => cfg.clone(),
+        cfgs => format!("any({})", cfgs.join(", ")),
     }
 }
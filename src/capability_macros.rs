@@ -0,0 +1,39 @@
+//! Equivalents of the `CAP_TO_INDEX` and `CAP_TO_MASK` macros from
+//! <linux/capability.h>, which bindgen doesn't translate, for manipulating
+//! the 32-bit words of capability sets in `__user_cap_data_struct`.
+
+/// The highest capability number in this version's <linux/capability.h>.
+pub use crate::general::CAP_LAST_CAP;
+
+/// The index of the 32-bit word containing capability `cap`.
+#[inline]
+pub const fn CAP_TO_INDEX(cap: u32) -> u32 {
+    cap >> 5
+}
+
+/// The bit for capability `cap` within its 32-bit word.
+#[inline]
+pub const fn CAP_TO_MASK(cap: u32) -> u32 {
+    1 << (cap & 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::general::{CAP_CHOWN, CAP_SETFCAP, CAP_SYS_ADMIN, _LINUX_CAPABILITY_U32S_3};
+
+    #[test]
+    fn cap_to_index_and_mask() {
+        assert_eq!(CAP_TO_INDEX(CAP_CHOWN), 0);
+        assert_eq!(CAP_TO_MASK(CAP_CHOWN), 1);
+        assert_eq!(CAP_TO_INDEX(CAP_SYS_ADMIN), 0);
+        assert_eq!(CAP_TO_MASK(CAP_SYS_ADMIN), 1 << 21);
+        assert_eq!(CAP_TO_INDEX(CAP_SETFCAP), 0);
+        assert_eq!(CAP_TO_MASK(CAP_SETFCAP), 1 << 31);
+        assert_eq!(CAP_TO_INDEX(32), 1);
+        assert_eq!(CAP_TO_MASK(32), 1);
+
+        // Every capability fits in a version 3 capability set.
+        assert!(CAP_TO_INDEX(CAP_LAST_CAP) < _LINUX_CAPABILITY_U32S_3);
+    }
+}
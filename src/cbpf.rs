@@ -0,0 +1,97 @@
+//! Classic BPF, as used by socket filters and seccomp: equivalents of the
+//! `BPF_STMT`, `BPF_JUMP`, and opcode field macros from <linux/filter.h> and
+//! <linux/bpf_common.h>.
+//!
+//! The instruction format, `sock_filter`, and the opcode constants, such as
+//! `BPF_LD`, are generated from those headers into the `general` module.
+
+pub use crate::general::{sock_filter, sock_fprog};
+
+/// The instruction class of `code`, one of `BPF_LD`, `BPF_ALU`, etc.
+#[inline]
+pub const fn BPF_CLASS(code: u32) -> u32 {
+    code & 0x07
+}
+
+/// The load or store size of `code`, one of `BPF_W`, `BPF_H`, or `BPF_B`.
+#[inline]
+pub const fn BPF_SIZE(code: u32) -> u32 {
+    code & 0x18
+}
+
+/// The load or store mode of `code`, one of `BPF_IMM`, `BPF_ABS`, etc.
+#[inline]
+pub const fn BPF_MODE(code: u32) -> u32 {
+    code & 0xe0
+}
+
+/// The ALU or jump operation of `code`, one of `BPF_ADD`, `BPF_JEQ`, etc.
+#[inline]
+pub const fn BPF_OP(code: u32) -> u32 {
+    code & 0xf0
+}
+
+/// The operand source of `code`, either `BPF_K` or `BPF_X`.
+#[inline]
+pub const fn BPF_SRC(code: u32) -> u32 {
+    code & 0x08
+}
+
+/// The return value source of a `BPF_RET` `code`, one of `BPF_K`, `BPF_X`,
+/// or `BPF_A`.
+#[inline]
+pub const fn BPF_RVAL(code: u32) -> u32 {
+    code & 0x18
+}
+
+/// The operation of a `BPF_MISC` `code`, either `BPF_TAX` or `BPF_TXA`.
+#[inline]
+pub const fn BPF_MISCOP(code: u32) -> u32 {
+    code & 0xf8
+}
+
+/// A non-jump instruction.
+#[inline]
+pub const fn BPF_STMT(code: u32, k: u32) -> sock_filter {
+    sock_filter {
+        code: code as u16,
+        jt: 0,
+        jf: 0,
+        k,
+    }
+}
+
+/// A jump instruction, which skips `jt` instructions if its condition is
+/// true and `jf` instructions if it's false.
+#[inline]
+pub const fn BPF_JUMP(code: u32, k: u32, jt: u8, jf: u8) -> sock_filter {
+    sock_filter {
+        code: code as u16,
+        jt,
+        jf,
+        k,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::general::{BPF_ABS, BPF_JEQ, BPF_JMP, BPF_K, BPF_LD, BPF_W};
+
+    #[test]
+    fn instructions() {
+        // `BPF_STMT(BPF_LD | BPF_W | BPF_ABS, 4)` and
+        // `BPF_JUMP(BPF_JMP | BPF_JEQ | BPF_K, 0xc000003e, 0, 1)` from C.
+        let ld = BPF_STMT(BPF_LD | BPF_W | BPF_ABS, 4);
+        assert_eq!((ld.code, ld.jt, ld.jf, ld.k), (0x20, 0, 0, 4));
+        let jeq = BPF_JUMP(BPF_JMP | BPF_JEQ | BPF_K, 0xc000003e, 0, 1);
+        assert_eq!((jeq.code, jeq.jt, jeq.jf, jeq.k), (0x15, 0, 1, 0xc000003e));
+
+        let code = u32::from(jeq.code);
+        assert_eq!(BPF_CLASS(code), BPF_JMP);
+        assert_eq!(BPF_OP(code), BPF_JEQ);
+        assert_eq!(BPF_SRC(code), BPF_K);
+        assert_eq!(BPF_MODE(u32::from(ld.code)), BPF_ABS);
+        assert_eq!(BPF_SIZE(u32::from(ld.code)), BPF_W);
+    }
+}
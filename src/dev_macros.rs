@@ -0,0 +1,45 @@
+//! Equivalents of the `major`, `minor`, and `makedev` macros for encoding
+//! and decoding device numbers, which bindgen doesn't translate.
+
+/// The major number of the 64-bit device number `dev`.
+#[inline]
+pub const fn major(dev: u64) -> u32 {
+    (((dev >> 32) & 0xffff_f000) | ((dev >> 8) & 0x0000_0fff)) as u32
+}
+
+/// The minor number of the 64-bit device number `dev`.
+#[inline]
+pub const fn minor(dev: u64) -> u32 {
+    (((dev >> 12) & 0xffff_ff00) | (dev & 0x0000_00ff)) as u32
+}
+
+/// Form a 64-bit device number from a major and minor number.
+#[inline]
+pub const fn makedev(major: u32, minor: u32) -> u64 {
+    let major = major as u64;
+    let minor = minor as u64;
+    ((major & 0xffff_f000) << 32)
+        | ((major & 0x0000_0fff) << 8)
+        | ((minor & 0xffff_ff00) << 12)
+        | (minor & 0x0000_00ff)
+}
+
+/// The major number of the old 16-bit device number `dev`, as used by
+/// `__kernel_old_dev_t` in some ABIs.
+#[inline]
+pub const fn old_major(dev: u16) -> u32 {
+    ((dev >> 8) & 0xff) as u32
+}
+
+/// The minor number of the old 16-bit device number `dev`.
+#[inline]
+pub const fn old_minor(dev: u16) -> u32 {
+    (dev & 0xff) as u32
+}
+
+/// Form an old 16-bit device number from a major and minor number, each of
+/// which must be less than 256.
+#[inline]
+pub const fn old_makedev(major: u32, minor: u32) -> u16 {
+    (((major & 0xff) << 8) | (minor & 0xff)) as u16
+}
@@ -0,0 +1,16 @@
+//! Equivalents of the `FUTEX_OP` macro from <linux/futex.h>, which bindgen
+//! doesn't translate, along with the bits of a PI or robust futex word.
+
+pub use crate::general::{FUTEX_OWNER_DIED, FUTEX_TID_MASK, FUTEX_WAITERS};
+
+/// Encode the operation for `FUTEX_WAKE_OP`: atomically apply `op`, one of
+/// the `FUTEX_OP_*` operations, with `oparg` to the second futex word, and
+/// compare its old value with `cmparg` using `cmp`, one of the
+/// `FUTEX_OP_CMP_*` comparisons, to decide whether to wake its waiters.
+///
+/// `op` may be combined with `FUTEX_OP_OPARG_SHIFT`, to use `1 << oparg`
+/// as the argument.
+#[inline]
+pub const fn FUTEX_OP(op: u32, oparg: u32, cmp: u32, cmparg: u32) -> u32 {
+    ((op & 0xf) << 28) | ((cmp & 0xf) << 24) | ((oparg & 0xfff) << 12) | (cmparg & 0xfff)
+}
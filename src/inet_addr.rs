@@ -0,0 +1,191 @@
+//! Conversions between `in_addr` and `in6_addr` and their octets, and
+//! equivalents of the `IN6ADDR_*_INIT` macros, so that addresses can be
+//! handled without going through `in6_addr`'s anonymous union.
+
+use crate::general::{in6_addr, in6_addr__bindgen_ty_1, in_addr};
+
+impl in_addr {
+    /// Construct an address from its octets, in network byte order.
+    #[inline]
+    pub const fn from_octets(octets: [u8; 4]) -> Self {
+        Self {
+            s_addr: u32::from_ne_bytes(octets),
+        }
+    }
+
+    /// The octets of the address, in network byte order.
+    #[inline]
+    pub const fn octets(&self) -> [u8; 4] {
+        self.s_addr.to_ne_bytes()
+    }
+}
+
+impl in6_addr {
+    /// Construct an address from its octets, in network byte order.
+    #[inline]
+    pub const fn from_octets(octets: [u8; 16]) -> Self {
+        Self {
+            in6_u: in6_addr__bindgen_ty_1 { u6_addr8: octets },
+        }
+    }
+
+    /// The octets of the address, in network byte order.
+    #[inline]
+    pub const fn octets(&self) -> [u8; 16] {
+        // Every variant of the union is plain bytes, so any of them may be
+        // read as bytes.
+        unsafe { self.in6_u.u6_addr8 }
+    }
+
+    /// The address as an array of octets, like the `s6_addr` macro.
+    #[inline]
+    pub fn s6_addr(&self) -> &[u8; 16] {
+        unsafe { &self.in6_u.u6_addr8 }
+    }
+
+    /// The address as a mutable array of octets, like the `s6_addr` macro.
+    #[inline]
+    pub fn s6_addr_mut(&mut self) -> &mut [u8; 16] {
+        unsafe { &mut self.in6_u.u6_addr8 }
+    }
+}
+
+impl From<[u8; 4]> for in_addr {
+    #[inline]
+    fn from(octets: [u8; 4]) -> Self {
+        Self::from_octets(octets)
+    }
+}
+
+impl From<in_addr> for [u8; 4] {
+    #[inline]
+    fn from(addr: in_addr) -> Self {
+        addr.octets()
+    }
+}
+
+impl From<[u8; 16]> for in6_addr {
+    #[inline]
+    fn from(octets: [u8; 16]) -> Self {
+        Self::from_octets(octets)
+    }
+}
+
+impl From<in6_addr> for [u8; 16] {
+    #[inline]
+    fn from(addr: in6_addr) -> Self {
+        addr.octets()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::net::Ipv4Addr> for in_addr {
+    #[inline]
+    fn from(addr: std::net::Ipv4Addr) -> Self {
+        Self::from_octets(addr.octets())
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<in_addr> for std::net::Ipv4Addr {
+    #[inline]
+    fn from(addr: in_addr) -> Self {
+        addr.octets().into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::net::Ipv6Addr> for in6_addr {
+    #[inline]
+    fn from(addr: std::net::Ipv6Addr) -> Self {
+        Self::from_octets(addr.octets())
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<in6_addr> for std::net::Ipv6Addr {
+    #[inline]
+    fn from(addr: in6_addr) -> Self {
+        addr.octets().into()
+    }
+}
+
+/// The unspecified address, `::`.
+pub const IN6ADDR_ANY_INIT: in6_addr = in6_addr::from_octets([0; 16]);
+/// The loopback address, `::1`.
+pub const IN6ADDR_LOOPBACK_INIT: in6_addr =
+    in6_addr::from_octets([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+/// The link-local all-nodes multicast address, `ff02::1`.
+pub const IN6ADDR_LINKLOCAL_ALLNODES_INIT: in6_addr =
+    in6_addr::from_octets([0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+/// The link-local all-routers multicast address, `ff02::2`.
+pub const IN6ADDR_LINKLOCAL_ALLROUTERS_INIT: in6_addr =
+    in6_addr::from_octets([0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+/// The interface-local all-nodes multicast address, `ff01::1`.
+pub const IN6ADDR_INTERFACELOCAL_ALLNODES_INIT: in6_addr =
+    in6_addr::from_octets([0xff, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+/// The interface-local all-routers multicast address, `ff01::2`.
+pub const IN6ADDR_INTERFACELOCAL_ALLROUTERS_INIT: in6_addr =
+    in6_addr::from_octets([0xff, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+/// The site-local all-routers multicast address, `ff05::2`.
+pub const IN6ADDR_SITELOCAL_ALLROUTERS_INIT: in6_addr =
+    in6_addr::from_octets([0xff, 0x05, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::general::INADDR_LOOPBACK;
+
+    #[test]
+    fn in_addr_octets() {
+        // `htonl(INADDR_LOOPBACK)`, as `inet_addr("127.0.0.1")` returns.
+        let loopback = in_addr {
+            s_addr: INADDR_LOOPBACK.to_be(),
+        };
+        assert_eq!(loopback.octets(), [127, 0, 0, 1]);
+        assert_eq!(in_addr::from_octets([127, 0, 0, 1]).s_addr, loopback.s_addr);
+
+        let octets = [192, 0, 2, 1];
+        assert_eq!(in_addr::from(octets).octets(), octets);
+        assert_eq!(<[u8; 4]>::from(in_addr::from(octets)), octets);
+    }
+
+    #[test]
+    fn in6_addr_octets() {
+        let octets = [
+            0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x12, 0x34,
+        ];
+        let addr = in6_addr::from(octets);
+        assert_eq!(addr.octets(), octets);
+        assert_eq!(addr.s6_addr(), &octets);
+        assert_eq!(<[u8; 16]>::from(addr), octets);
+        // The `s6_addr16` view of the same address, as in C.
+        assert_eq!(unsafe { addr.in6_u.u6_addr16[0] }, 0x2001_u16.to_be());
+
+        let mut addr = IN6ADDR_ANY_INIT;
+        addr.s6_addr_mut()[15] = 1;
+        assert_eq!(addr.octets(), IN6ADDR_LOOPBACK_INIT.octets());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_net() {
+        use std::net::{Ipv4Addr, Ipv6Addr};
+
+        assert_eq!(in_addr::from(Ipv4Addr::LOCALHOST).octets(), [127, 0, 0, 1]);
+        assert_eq!(
+            Ipv4Addr::from(in_addr::from_octets([192, 0, 2, 1])),
+            Ipv4Addr::new(192, 0, 2, 1)
+        );
+        assert_eq!(Ipv6Addr::from(IN6ADDR_ANY_INIT), Ipv6Addr::UNSPECIFIED);
+        assert_eq!(Ipv6Addr::from(IN6ADDR_LOOPBACK_INIT), Ipv6Addr::LOCALHOST);
+        assert_eq!(
+            Ipv6Addr::from(IN6ADDR_LINKLOCAL_ALLNODES_INIT),
+            "ff02::1".parse::<Ipv6Addr>().unwrap()
+        );
+        assert_eq!(
+            Ipv6Addr::from(IN6ADDR_SITELOCAL_ALLROUTERS_INIT),
+            "ff05::2".parse::<Ipv6Addr>().unwrap()
+        );
+    }
+}
@@ -1,16 +1,35 @@
 #![allow(non_camel_case_types, non_upper_case_globals, non_snake_case)]
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 
 #[cfg(feature = "std")]
 pub use std::os::raw as ctypes;
 
 #[cfg(all(not(feature = "std"), feature = "no_std"))]
 pub mod ctypes {
-    // The signedness of `char` is platform-specific, however a consequence
-    // of it being platform-specific is that any code which depends on the
-    // signedness of `char` is already non-portable. So we can just use `u8`
-    // here and no portable code will notice.
+    // The signedness of `char` is platform-specific, so match the C ABI of
+    // each architecture, as `std::os::raw::c_char` does, so that fields of
+    // type `char` have the same type with `std` and `no_std`.
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "riscv32",
+        target_arch = "riscv64",
+        target_arch = "s390x",
+    ))]
     pub type c_char = u8;
+    #[cfg(not(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "riscv32",
+        target_arch = "riscv64",
+        target_arch = "s390x",
+    )))]
+    pub type c_char = i8;
 
     // The following assumes that Linux is always either ILP32 or LP64,
     // and char is always 8-bit.
@@ -41,32 +60,131 @@ pub mod ctypes {
     pub use core::ffi::c_void;
 }
 
+/// The type of the ioctl request codes, such as `TCGETS` and `FIONREAD`.
+///
+/// The `ioctl` syscall takes its request as an `unsigned int`. Most of the
+/// request codes which are plain numbers have this type, so they can be
+/// passed on without casts.
+pub type Ioctl = ctypes::c_uint;
+
+// `openat2` is only in the version modules for Linux 5.6 and later, none of
+// which are the default on any architecture, so it needs one selected with
+// `LINUX_RAW_SYS_VERSION` or the `v5_11` feature.
+#[cfg(all(
+    feature = "openat2",
+    not(any(linux_raw_sys_openat2, feature = "v5_11"))
+))]
+compile_error!("The `openat2` feature requires a Linux 5.6 or later version, such as `v5_11`, to be selected with `LINUX_RAW_SYS_VERSION`, or the `v5_11` feature.");
+
+// This must come before the generated modules, which use its macro.
+#[cfg(feature = "typed-flags")]
+#[macro_use]
+mod typed_flags;
+
+// The helpers which use the version module re-exported at the crate root are
+// only built where there is one, which `build.rs` marks with
+// `linux_raw_sys_root`.
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod capability_macros;
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod cbpf;
+#[cfg(feature = "general")]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod dev_macros;
+#[cfg(all(feature = "errno", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "errno")))]
+pub mod errno_names;
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod futex_macros;
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod inet_addr;
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod mman_macros;
+#[cfg(all(feature = "netlink", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "netlink")))]
+pub mod netlink_macros;
+#[cfg(all(feature = "openat2", any(linux_raw_sys_openat2, feature = "v5_11")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "openat2")))]
+pub mod openat2;
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod select_macros;
+#[cfg(feature = "since")]
+#[cfg_attr(docsrs, doc(cfg(feature = "since")))]
+pub mod since;
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod signal_macros;
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod signal_names;
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod sockaddr_any;
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod termios_speed;
+#[cfg(all(feature = "general", linux_raw_sys_root))]
+mod time_conversions;
+pub mod version_macros;
+#[cfg(feature = "general")]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod wait_macros;
+
 // The rest of this file is auto-generated!
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "powerpc"))]
+#[doc(hidden)]
 pub mod v2_6_32;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "powerpc"))]
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64", target_arch = "powerpc"), not(linux_raw_sys_version)))]
+#[doc(inline)]
 pub use v2_6_32::*;
 #[cfg(target_arch = "arm")]
+#[doc(hidden)]
 pub mod v3_2;
-#[cfg(target_arch = "arm")]
+#[cfg(all(target_arch = "arm", not(linux_raw_sys_version)))]
+#[doc(inline)]
 pub use v3_2::*;
 #[cfg(target_arch = "powerpc64")]
+#[doc(hidden)]
 pub mod v3_10;
-#[cfg(target_arch = "powerpc64")]
+#[cfg(all(target_arch = "powerpc64", not(linux_raw_sys_version)))]
+#[doc(inline)]
 pub use v3_10::*;
 #[cfg(target_arch = "aarch64")]
+#[doc(hidden)]
 pub mod v4_2;
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", not(linux_raw_sys_version)))]
+#[doc(inline)]
 pub use v4_2::*;
 #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+#[doc(hidden)]
 pub mod v4_4;
-#[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+#[cfg(all(any(target_arch = "mips", target_arch = "mips64"), not(linux_raw_sys_version)))]
+#[doc(inline)]
 pub use v4_4::*;
 #[cfg(target_arch = "riscv64")]
+#[doc(hidden)]
 pub mod v4_20;
-#[cfg(target_arch = "riscv64")]
+#[cfg(all(target_arch = "riscv64", not(linux_raw_sys_version)))]
+#[doc(inline)]
 pub use v4_20::*;
-#[cfg(feature = "v5_4")]
+#[cfg(any(target_arch = "riscv32", target_arch = "s390x", target_arch = "sparc64", feature = "v5_4", linux_raw_sys_version = "v5_4"))]
+#[cfg_attr(docsrs, doc(cfg(any(target_arch = "riscv32", target_arch = "s390x", target_arch = "sparc64", feature = "v5_4"))))]
 pub mod v5_4;
-#[cfg(feature = "v5_11")]
+#[cfg(all(any(target_arch = "riscv32", target_arch = "s390x", target_arch = "sparc64"), not(linux_raw_sys_version)))]
+pub use v5_4::*;
+#[cfg(linux_raw_sys_version = "v5_4")]
+pub use v5_4::*;
+#[cfg(any(feature = "v5_11", linux_raw_sys_version = "v5_11"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "v5_11")))]
 pub mod v5_11;
+#[cfg(linux_raw_sys_version = "v5_11")]
+pub use v5_11::*;
+#[cfg(feature = "latest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "latest")))]
+pub use v5_11 as latest;
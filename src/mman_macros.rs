@@ -0,0 +1,40 @@
+//! Encoding of hugepage sizes in `mmap` flags, as with the `MAP_HUGE_*`
+//! macros from <linux/mman.h>, for the sizes which don't have a constant.
+
+use crate::ctypes::c_int;
+
+// These are from Linux 3.8, which is after the default versions of some
+// architectures, so they're defined here rather than taken from `general`.
+// The encoding is the same on every architecture.
+const MAP_HUGE_SHIFT: u32 = 26;
+const MAP_HUGE_MASK: u32 = 0x3f;
+
+/// The `mmap` flags bits selecting a hugepage size of `1 << log2` bytes,
+/// to be combined with `MAP_HUGETLB`.
+#[inline]
+pub const fn map_hugetlb_size(log2: u32) -> c_int {
+    ((log2 & MAP_HUGE_MASK) << MAP_HUGE_SHIFT) as c_int
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn huge_sizes() {
+        // `HUGETLB_FLAG_ENCODE_2MB` and `HUGETLB_FLAG_ENCODE_1GB` from C.
+        assert_eq!(map_hugetlb_size(21), 21 << 26);
+        assert_eq!(map_hugetlb_size(30), 30 << 26);
+        assert_eq!(
+            (map_hugetlb_size(30) as u32 >> MAP_HUGE_SHIFT) & MAP_HUGE_MASK,
+            30
+        );
+    }
+
+    #[cfg(feature = "v5_11")]
+    #[test]
+    fn matches_generated() {
+        assert_eq!(MAP_HUGE_SHIFT, crate::v5_11::general::MAP_HUGE_SHIFT);
+        assert_eq!(MAP_HUGE_MASK, crate::v5_11::general::MAP_HUGE_MASK);
+    }
+}
@@ -0,0 +1,36 @@
+//! Equivalents of the `NLMSG_*` and `NLA_*` macros from
+//! <linux/netlink.h>, which bindgen doesn't translate.
+
+use crate::netlink::{nlattr, nlmsghdr, NLA_ALIGNTO, NLMSG_ALIGNTO};
+use core::mem::size_of;
+
+/// Round `len` up to the alignment of netlink messages.
+#[inline]
+pub const fn NLMSG_ALIGN(len: u32) -> u32 {
+    (len + NLMSG_ALIGNTO - 1) & !(NLMSG_ALIGNTO - 1)
+}
+
+/// The aligned size of a `nlmsghdr`.
+pub const NLMSG_HDRLEN: u32 = NLMSG_ALIGN(size_of::<nlmsghdr>() as u32);
+
+/// The value of `nlmsg_len` for a message with a payload of `len` bytes.
+#[inline]
+pub const fn NLMSG_LENGTH(len: u32) -> u32 {
+    len + NLMSG_HDRLEN
+}
+
+/// The number of bytes a message with a payload of `len` bytes occupies in
+/// a buffer, including trailing padding.
+#[inline]
+pub const fn NLMSG_SPACE(len: u32) -> u32 {
+    NLMSG_ALIGN(NLMSG_LENGTH(len))
+}
+
+/// Round `len` up to the alignment of netlink attributes.
+#[inline]
+pub const fn NLA_ALIGN(len: u32) -> u32 {
+    (len + NLA_ALIGNTO - 1) & !(NLA_ALIGNTO - 1)
+}
+
+/// The aligned size of a `nlattr`.
+pub const NLA_HDRLEN: u32 = NLA_ALIGN(size_of::<nlattr>() as u32);
@@ -0,0 +1,21 @@
+//! The definitions from <linux/openat2.h>, which is new in Linux 5.6, from
+//! the version module re-exported at the crate root if it has them, and
+//! from `v5_11` otherwise.
+
+#[cfg(linux_raw_sys_openat2)]
+pub use crate::general::{
+    open_how, RESOLVE_BENEATH, RESOLVE_IN_ROOT, RESOLVE_NO_MAGICLINKS, RESOLVE_NO_SYMLINKS,
+    RESOLVE_NO_XDEV,
+};
+#[cfg(not(linux_raw_sys_openat2))]
+pub use crate::v5_11::general::{
+    open_how, RESOLVE_BENEATH, RESOLVE_IN_ROOT, RESOLVE_NO_MAGICLINKS, RESOLVE_NO_SYMLINKS,
+    RESOLVE_NO_XDEV,
+};
+
+/// Only resolve the path if it can be done entirely from the dcache, without
+/// I/O, and fail with `EAGAIN` otherwise.
+///
+/// This is new in Linux 5.12, after the newest generated version, so it's
+/// defined here. Older kernels reject it with `EINVAL`.
+pub const RESOLVE_CACHED: u32 = 0x20;
@@ -0,0 +1,39 @@
+//! Equivalents of the `FD_*` macros for manipulating the kernel's
+//! `__kernel_fd_set`, which bindgen doesn't translate.
+//!
+//! The kernel's `fd_set` holds `__FD_SETSIZE` bits; `fd` arguments must be
+//! non-negative and less than that, or these functions panic.
+
+use crate::ctypes::{c_int, c_ulong};
+use crate::general::__kernel_fd_set;
+
+const BITS: usize = c_ulong::BITS as usize;
+
+/// Add `fd` to `set`.
+#[inline]
+pub fn FD_SET(fd: c_int, set: &mut __kernel_fd_set) {
+    let fd = fd as usize;
+    set.fds_bits[fd / BITS] |= 1 << (fd % BITS);
+}
+
+/// Remove `fd` from `set`.
+#[inline]
+pub fn FD_CLR(fd: c_int, set: &mut __kernel_fd_set) {
+    let fd = fd as usize;
+    set.fds_bits[fd / BITS] &= !(1 << (fd % BITS));
+}
+
+/// Test whether `fd` is in `set`.
+#[inline]
+pub const fn FD_ISSET(fd: c_int, set: &__kernel_fd_set) -> bool {
+    let fd = fd as usize;
+    (set.fds_bits[fd / BITS] & (1 << (fd % BITS))) != 0
+}
+
+/// Remove all file descriptors from `set`.
+#[inline]
+pub fn FD_ZERO(set: &mut __kernel_fd_set) {
+    for word in set.fds_bits.iter_mut() {
+        *word = 0;
+    }
+}
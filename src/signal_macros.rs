@@ -0,0 +1,125 @@
+//! Definitions for manipulating the kernel's signal sets, as used by
+//! `rt_sigprocmask`, `rt_sigaction`, and friends.
+//!
+//! The kernel's `sigset_t` is `_NSIG` bits wide, which is smaller than the
+//! 1024-bit `sigset_t` used by glibc, and the size passed to the `rt_sig*`
+//! system calls must match it exactly.
+
+use crate::ctypes::{c_int, c_ulong};
+
+pub use crate::general::SIGRTMIN;
+
+/// The number of signals supported by the kernel.
+#[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+pub use crate::general::_NSIG;
+/// The number of signals supported by the kernel.
+///
+/// sparc's `_NSIG` is the size of its old 32-bit `sigset_t`; the `rt_sig*`
+/// system calls use `__NEW_NSIG`.
+#[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+pub use crate::general::__NEW_NSIG as _NSIG;
+
+/// The number of signal bits in each word of a `kernel_sigset_t`.
+pub const _NSIG_BPW: usize = c_ulong::BITS as usize;
+
+/// The number of words in a `kernel_sigset_t`.
+pub const _NSIG_WORDS: usize = _NSIG as usize / _NSIG_BPW;
+
+/// The kernel's `sigset_t`.
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct kernel_sigset_t {
+    pub sig: [c_ulong; _NSIG_WORDS],
+}
+
+/// A signal set with no signals in it.
+#[inline]
+pub const fn sigemptyset() -> kernel_sigset_t {
+    kernel_sigset_t {
+        sig: [0; _NSIG_WORDS],
+    }
+}
+
+/// A signal set with every signal in it.
+#[inline]
+pub const fn sigfillset() -> kernel_sigset_t {
+    kernel_sigset_t {
+        sig: [!0; _NSIG_WORDS],
+    }
+}
+
+/// Return `set` with `sig` added.
+///
+/// # Panics
+///
+/// Panics if `sig` is not in `1..=_NSIG`.
+#[inline]
+pub const fn sigaddset(mut set: kernel_sigset_t, sig: c_int) -> kernel_sigset_t {
+    let bit = sig_bit(sig);
+    set.sig[bit / _NSIG_BPW] |= 1 << (bit % _NSIG_BPW);
+    set
+}
+
+/// Return `set` with `sig` removed.
+///
+/// # Panics
+///
+/// Panics if `sig` is not in `1..=_NSIG`.
+#[inline]
+pub const fn sigdelset(mut set: kernel_sigset_t, sig: c_int) -> kernel_sigset_t {
+    let bit = sig_bit(sig);
+    set.sig[bit / _NSIG_BPW] &= !(1 << (bit % _NSIG_BPW));
+    set
+}
+
+/// Test whether `sig` is in `set`.
+///
+/// # Panics
+///
+/// Panics if `sig` is not in `1..=_NSIG`.
+#[inline]
+pub const fn sigismember(set: &kernel_sigset_t, sig: c_int) -> bool {
+    let bit = sig_bit(sig);
+    (set.sig[bit / _NSIG_BPW] & (1 << (bit % _NSIG_BPW))) != 0
+}
+
+/// The bit index of `sig` in a `kernel_sigset_t`. Signal numbers start at 1.
+#[inline]
+const fn sig_bit(sig: c_int) -> usize {
+    debug_assert!(sig >= 1 && sig as u32 <= _NSIG, "signal number out of range");
+    (sig - 1) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::general::{SIGHUP, SIGKILL};
+
+    #[test]
+    fn add_del_member() {
+        let set = sigaddset(sigemptyset(), SIGKILL as c_int);
+        assert!(sigismember(&set, SIGKILL as c_int));
+        assert!(!sigismember(&set, SIGHUP as c_int));
+        let set = sigdelset(set, SIGKILL as c_int);
+        assert!(!sigismember(&set, SIGKILL as c_int));
+
+        // The highest signal number is the last bit of the set.
+        let set = sigaddset(sigemptyset(), _NSIG as c_int);
+        assert!(sigismember(&set, _NSIG as c_int));
+        assert_eq!(set.sig[_NSIG_WORDS - 1], 1 << (_NSIG_BPW - 1));
+        assert!(sigismember(&sigfillset(), _NSIG as c_int));
+    }
+
+    #[test]
+    #[should_panic]
+    fn signal_zero() {
+        sigaddset(sigemptyset(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn signal_past_nsig() {
+        sigismember(&sigfillset(), _NSIG as c_int + 1);
+    }
+}
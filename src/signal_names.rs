@@ -0,0 +1,86 @@
+//! Symbolic names for signal numbers, for printing readable signal
+//! information without depending on libc.
+//!
+//! Signal numbers differ between architectures, so the table is built from
+//! the constants in the `general` module.
+
+use crate::general;
+
+/// The number of entries in `SIGNAL_NAMES`; all the non-realtime signals
+/// are less than this.
+pub const SIGNAL_NAMES_LEN: usize = 32;
+
+/// A table mapping signal numbers to their names, such as `"SIGKILL"`.
+///
+/// Where several names share a number, such as `SIGIO` and `SIGPOLL`, the
+/// more common name is used. Realtime signals aren't included.
+pub static SIGNAL_NAMES: [Option<&str>; SIGNAL_NAMES_LEN] = NAMES;
+
+// `signal_name` reads this rather than the static, since const fns can't
+// refer to statics before Rust 1.83.
+const NAMES: [Option<&str>; SIGNAL_NAMES_LEN] = signal_names();
+
+/// Return the name of `sig`, such as `"SIGKILL"`, or `None` if it isn't a
+/// known non-realtime signal.
+#[inline]
+pub const fn signal_name(sig: i32) -> Option<&'static str> {
+    if sig < 0 || sig as usize >= SIGNAL_NAMES_LEN {
+        return None;
+    }
+    NAMES[sig as usize]
+}
+
+const fn signal_names() -> [Option<&'static str>; SIGNAL_NAMES_LEN] {
+    let mut names = [None; SIGNAL_NAMES_LEN];
+    names[general::SIGHUP as usize] = Some("SIGHUP");
+    names[general::SIGINT as usize] = Some("SIGINT");
+    names[general::SIGQUIT as usize] = Some("SIGQUIT");
+    names[general::SIGILL as usize] = Some("SIGILL");
+    names[general::SIGTRAP as usize] = Some("SIGTRAP");
+    names[general::SIGABRT as usize] = Some("SIGABRT");
+    names[general::SIGBUS as usize] = Some("SIGBUS");
+    names[general::SIGFPE as usize] = Some("SIGFPE");
+    names[general::SIGKILL as usize] = Some("SIGKILL");
+    names[general::SIGUSR1 as usize] = Some("SIGUSR1");
+    names[general::SIGSEGV as usize] = Some("SIGSEGV");
+    names[general::SIGUSR2 as usize] = Some("SIGUSR2");
+    names[general::SIGPIPE as usize] = Some("SIGPIPE");
+    names[general::SIGALRM as usize] = Some("SIGALRM");
+    names[general::SIGTERM as usize] = Some("SIGTERM");
+    names[general::SIGCHLD as usize] = Some("SIGCHLD");
+    names[general::SIGCONT as usize] = Some("SIGCONT");
+    names[general::SIGSTOP as usize] = Some("SIGSTOP");
+    names[general::SIGTSTP as usize] = Some("SIGTSTP");
+    names[general::SIGTTIN as usize] = Some("SIGTTIN");
+    names[general::SIGTTOU as usize] = Some("SIGTTOU");
+    names[general::SIGURG as usize] = Some("SIGURG");
+    names[general::SIGXCPU as usize] = Some("SIGXCPU");
+    names[general::SIGXFSZ as usize] = Some("SIGXFSZ");
+    names[general::SIGVTALRM as usize] = Some("SIGVTALRM");
+    names[general::SIGPROF as usize] = Some("SIGPROF");
+    names[general::SIGWINCH as usize] = Some("SIGWINCH");
+    names[general::SIGIO as usize] = Some("SIGIO");
+    names[general::SIGPWR as usize] = Some("SIGPWR");
+    names[general::SIGSYS as usize] = Some("SIGSYS");
+
+    // mips and sparc have `SIGEMT` in place of `SIGSTKFLT`.
+    #[cfg(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    ))]
+    {
+        names[general::SIGEMT as usize] = Some("SIGEMT");
+    }
+    #[cfg(not(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
+    {
+        names[general::SIGSTKFLT as usize] = Some("SIGSTKFLT");
+    }
+    names
+}
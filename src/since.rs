@@ -0,0 +1,4486 @@
+//! The kernel version in which each generated item first appears, for code
+//! which needs to know whether an item, such as `P_PIDFD` or
+//! `CLONE_INTO_CGROUP`, exists on its minimum supported kernel.
+//!
+//! Each table maps item names in one of the generated modules to the
+//! `LINUX_VERSION_CODE` of the oldest generated version which defines the
+//! item on any architecture. Only some versions are generated, so an item
+//! may have been added in any release after the previous generated version.
+//! And the oldest versions are only generated for the architectures which
+//! default to them, so architecture-specific items may be older than listed.
+
+/// Look up `name` in one of the tables in this module, returning the
+/// `LINUX_VERSION_CODE` of the oldest generated version which defines it.
+///
+/// ```
+/// use linux_raw_sys::since::{since, GENERAL};
+/// use linux_raw_sys::version_macros::KERNEL_VERSION;
+///
+/// assert!(since(GENERAL, "CLONE_INTO_CGROUP").unwrap() > KERNEL_VERSION(5, 4, 0));
+/// assert_eq!(since(GENERAL, "not_an_item"), None);
+/// ```
+pub fn since(table: &[(&str, u32)], name: &str) -> Option<u32> {
+    table
+        .binary_search_by(|(item, _)| (*item).cmp(name))
+        .ok()
+        .map(|index| table[index].1)
+}
+
+// The rest of this file is auto-generated!
+
+/// The oldest generated version defining each item in `errno`.
+pub static ERRNO: &[(&str, u32)] = &[
+    ("E2BIG", 0x020620),
+    ("EACCES", 0x020620),
+    ("EADDRINUSE", 0x020620),
+    ("EADDRNOTAVAIL", 0x020620),
+    ("EADV", 0x020620),
+    ("EAFNOSUPPORT", 0x020620),
+    ("EAGAIN", 0x020620),
+    ("EALREADY", 0x020620),
+    ("EBADE", 0x020620),
+    ("EBADF", 0x020620),
+    ("EBADFD", 0x020620),
+    ("EBADMSG", 0x020620),
+    ("EBADR", 0x020620),
+    ("EBADRQC", 0x020620),
+    ("EBADSLT", 0x020620),
+    ("EBFONT", 0x020620),
+    ("EBUSY", 0x020620),
+    ("ECANCELED", 0x020620),
+    ("ECHILD", 0x020620),
+    ("ECHRNG", 0x020620),
+    ("ECOMM", 0x020620),
+    ("ECONNABORTED", 0x020620),
+    ("ECONNREFUSED", 0x020620),
+    ("ECONNRESET", 0x020620),
+    ("EDEADLK", 0x020620),
+    ("EDEADLOCK", 0x020620),
+    ("EDESTADDRREQ", 0x020620),
+    ("EDOM", 0x020620),
+    ("EDOTDOT", 0x020620),
+    ("EDQUOT", 0x020620),
+    ("EEXIST", 0x020620),
+    ("EFAULT", 0x020620),
+    ("EFBIG", 0x020620),
+    ("EHOSTDOWN", 0x020620),
+    ("EHOSTUNREACH", 0x020620),
+    ("EHWPOISON", 0x030200),
+    ("EIDRM", 0x020620),
+    ("EILSEQ", 0x020620),
+    ("EINIT", 0x040400),
+    ("EINPROGRESS", 0x020620),
+    ("EINTR", 0x020620),
+    ("EINVAL", 0x020620),
+    ("EIO", 0x020620),
+    ("EISCONN", 0x020620),
+    ("EISDIR", 0x020620),
+    ("EISNAM", 0x020620),
+    ("EKEYEXPIRED", 0x020620),
+    ("EKEYREJECTED", 0x020620),
+    ("EKEYREVOKED", 0x020620),
+    ("EL2HLT", 0x020620),
+    ("EL2NSYNC", 0x020620),
+    ("EL3HLT", 0x020620),
+    ("EL3RST", 0x020620),
+    ("ELIBACC", 0x020620),
+    ("ELIBBAD", 0x020620),
+    ("ELIBEXEC", 0x020620),
+    ("ELIBMAX", 0x020620),
+    ("ELIBSCN", 0x020620),
+    ("ELNRNG", 0x020620),
+    ("ELOOP", 0x020620),
+    ("EMEDIUMTYPE", 0x020620),
+    ("EMFILE", 0x020620),
+    ("EMLINK", 0x020620),
+    ("EMSGSIZE", 0x020620),
+    ("EMULTIHOP", 0x020620),
+    ("ENAMETOOLONG", 0x020620),
+    ("ENAVAIL", 0x020620),
+    ("ENETDOWN", 0x020620),
+    ("ENETRESET", 0x020620),
+    ("ENETUNREACH", 0x020620),
+    ("ENFILE", 0x020620),
+    ("ENOANO", 0x020620),
+    ("ENOBUFS", 0x020620),
+    ("ENOCSI", 0x020620),
+    ("ENODATA", 0x020620),
+    ("ENODEV", 0x020620),
+    ("ENOENT", 0x020620),
+    ("ENOEXEC", 0x020620),
+    ("ENOKEY", 0x020620),
+    ("ENOLCK", 0x020620),
+    ("ENOLINK", 0x020620),
+    ("ENOMEDIUM", 0x020620),
+    ("ENOMEM", 0x020620),
+    ("ENOMSG", 0x020620),
+    ("ENONET", 0x020620),
+    ("ENOPKG", 0x020620),
+    ("ENOPROTOOPT", 0x020620),
+    ("ENOSPC", 0x020620),
+    ("ENOSR", 0x020620),
+    ("ENOSTR", 0x020620),
+    ("ENOSYS", 0x020620),
+    ("ENOTBLK", 0x020620),
+    ("ENOTCONN", 0x020620),
+    ("ENOTDIR", 0x020620),
+    ("ENOTEMPTY", 0x020620),
+    ("ENOTNAM", 0x020620),
+    ("ENOTRECOVERABLE", 0x020620),
+    ("ENOTSOCK", 0x020620),
+    ("ENOTTY", 0x020620),
+    ("ENOTUNIQ", 0x020620),
+    ("ENXIO", 0x020620),
+    ("EOPNOTSUPP", 0x020620),
+    ("EOVERFLOW", 0x020620),
+    ("EOWNERDEAD", 0x020620),
+    ("EPERM", 0x020620),
+    ("EPFNOSUPPORT", 0x020620),
+    ("EPIPE", 0x020620),
+    ("EPROCLIM", 0x050400),
+    ("EPROTO", 0x020620),
+    ("EPROTONOSUPPORT", 0x020620),
+    ("EPROTOTYPE", 0x020620),
+    ("ERANGE", 0x020620),
+    ("EREMCHG", 0x020620),
+    ("EREMDEV", 0x040400),
+    ("EREMOTE", 0x020620),
+    ("EREMOTEIO", 0x020620),
+    ("ERESTART", 0x020620),
+    ("ERFKILL", 0x020620),
+    ("EROFS", 0x020620),
+    ("ERREMOTE", 0x050400),
+    ("ESHUTDOWN", 0x020620),
+    ("ESOCKTNOSUPPORT", 0x020620),
+    ("ESPIPE", 0x020620),
+    ("ESRCH", 0x020620),
+    ("ESRMNT", 0x020620),
+    ("ESTALE", 0x020620),
+    ("ESTRPIPE", 0x020620),
+    ("ETIME", 0x020620),
+    ("ETIMEDOUT", 0x020620),
+    ("ETOOMANYREFS", 0x020620),
+    ("ETXTBSY", 0x020620),
+    ("EUCLEAN", 0x020620),
+    ("EUNATCH", 0x020620),
+    ("EUSERS", 0x020620),
+    ("EWOULDBLOCK", 0x020620),
+    ("EXDEV", 0x020620),
+    ("EXFULL", 0x020620),
+    ("_LAST_ERRNO", 0x020620),
+];
+
+/// The oldest generated version defining each item in `general`.
+pub static GENERAL: &[(&str, u32)] = &[
+    ("AAFS_MAGIC", 0x041400),
+    ("ADFS_SUPER_MAGIC", 0x020620),
+    ("AFFS_SUPER_MAGIC", 0x020620),
+    ("AFS_FS_MAGIC", 0x041400),
+    ("AFS_SUPER_MAGIC", 0x020620),
+    ("AF_APPLETALK", 0x020620),
+    ("AF_ASH", 0x020620),
+    ("AF_ATMPVC", 0x020620),
+    ("AF_ATMSVC", 0x020620),
+    ("AF_AX25", 0x020620),
+    ("AF_BLUETOOTH", 0x020620),
+    ("AF_BRIDGE", 0x020620),
+    ("AF_CAN", 0x020620),
+    ("AF_DECnet", 0x020620),
+    ("AF_ECONET", 0x020620),
+    ("AF_IEEE802154", 0x020620),
+    ("AF_INET", 0x020620),
+    ("AF_INET6", 0x020620),
+    ("AF_IPX", 0x020620),
+    ("AF_IRDA", 0x020620),
+    ("AF_ISDN", 0x020620),
+    ("AF_IUCV", 0x020620),
+    ("AF_KEY", 0x020620),
+    ("AF_LLC", 0x020620),
+    ("AF_MAX", 0x020620),
+    ("AF_NETBEUI", 0x020620),
+    ("AF_NETLINK", 0x020620),
+    ("AF_NETROM", 0x020620),
+    ("AF_PACKET", 0x020620),
+    ("AF_PHONET", 0x020620),
+    ("AF_PPPOX", 0x020620),
+    ("AF_RDS", 0x020620),
+    ("AF_ROSE", 0x020620),
+    ("AF_RXRPC", 0x020620),
+    ("AF_SECURITY", 0x020620),
+    ("AF_SNA", 0x020620),
+    ("AF_TIPC", 0x020620),
+    ("AF_UNIX", 0x020620),
+    ("AF_UNSPEC", 0x020620),
+    ("AF_WANPIPE", 0x020620),
+    ("AF_X25", 0x020620),
+    ("ANON_INODE_FS_MAGIC", 0x020620),
+    ("ARCH_SET_FS", 0x020620),
+    ("ARG_MAX", 0x020620),
+    ("ASI_AFAR", 0x050400),
+    ("ASI_AFSR", 0x050400),
+    ("ASI_AIUP", 0x050400),
+    ("ASI_AIUPL", 0x050400),
+    ("ASI_AIUS", 0x050400),
+    ("ASI_AIUSL", 0x050400),
+    ("ASI_BLK_AIUP", 0x050400),
+    ("ASI_BLK_AIUPL", 0x050400),
+    ("ASI_BLK_AIUP_4V", 0x050400),
+    ("ASI_BLK_AIUP_L_4V", 0x050400),
+    ("ASI_BLK_AIUS", 0x050400),
+    ("ASI_BLK_AIUSL", 0x050400),
+    ("ASI_BLK_AIUS_4V", 0x050400),
+    ("ASI_BLK_AIUS_L_4V", 0x050400),
+    ("ASI_BLK_COMMIT_P", 0x050400),
+    ("ASI_BLK_COMMIT_S", 0x050400),
+    ("ASI_BLK_INIT_QUAD_LDD_AIUS", 0x050400),
+    ("ASI_BLK_INIT_QUAD_LDD_P", 0x050400),
+    ("ASI_BLK_INIT_QUAD_LDD_S", 0x050400),
+    ("ASI_BLK_P", 0x050400),
+    ("ASI_BLK_PL", 0x050400),
+    ("ASI_BLK_S", 0x050400),
+    ("ASI_BLK_SL", 0x050400),
+    ("ASI_BRPRED_ARRAY", 0x050400),
+    ("ASI_CESR_ID", 0x050400),
+    ("ASI_CMT_ERROR_STEERING", 0x050400),
+    ("ASI_CONTROL", 0x050400),
+    ("ASI_CORE_AVAILABLE", 0x050400),
+    ("ASI_CORE_ENABLE", 0x050400),
+    ("ASI_CORE_ENABLE_STAT", 0x050400),
+    ("ASI_CORE_ID", 0x050400),
+    ("ASI_CORE_RUNNING_RW", 0x050400),
+    ("ASI_CORE_RUNNING_STAT", 0x050400),
+    ("ASI_CORE_RUNNING_W1C", 0x050400),
+    ("ASI_CORE_RUNNING_W1S", 0x050400),
+    ("ASI_DCACHE_DATA", 0x050400),
+    ("ASI_DCACHE_INVALIDATE", 0x050400),
+    ("ASI_DCACHE_SNOOP_TAG", 0x050400),
+    ("ASI_DCACHE_TAG", 0x050400),
+    ("ASI_DCACHE_UTAG", 0x050400),
+    ("ASI_DCU_CONTROL_REG", 0x050400),
+    ("ASI_DMMU", 0x050400),
+    ("ASI_DMMU_DEMAP", 0x050400),
+    ("ASI_DMMU_TSB_64KB_PTR", 0x050400),
+    ("ASI_DMMU_TSB_8KB_PTR", 0x050400),
+    ("ASI_DMMU_TSB_DIRECT_PTR", 0x050400),
+    ("ASI_DTLB_DATA_ACCESS", 0x050400),
+    ("ASI_DTLB_DATA_IN", 0x050400),
+    ("ASI_DTLB_TAG_READ", 0x050400),
+    ("ASI_EC_CTRL", 0x050400),
+    ("ASI_EC_DATA", 0x050400),
+    ("ASI_EC_R", 0x050400),
+    ("ASI_EC_TAG_DATA", 0x050400),
+    ("ASI_EC_W", 0x050400),
+    ("ASI_ESTATE_ERROR_EN", 0x050400),
+    ("ASI_FL16_P", 0x050400),
+    ("ASI_FL16_PL", 0x050400),
+    ("ASI_FL16_S", 0x050400),
+    ("ASI_FL16_SL", 0x050400),
+    ("ASI_FL8_P", 0x050400),
+    ("ASI_FL8_PL", 0x050400),
+    ("ASI_FL8_S", 0x050400),
+    ("ASI_FL8_SL", 0x050400),
+    ("ASI_FLUSHCTX", 0x050400),
+    ("ASI_FLUSHPG", 0x050400),
+    ("ASI_FLUSHSEG", 0x050400),
+    ("ASI_HWFLUSHCONTEXT", 0x050400),
+    ("ASI_HWFLUSHPAGE", 0x050400),
+    ("ASI_HWFLUSHSEG", 0x050400),
+    ("ASI_IC_INSTR", 0x050400),
+    ("ASI_IC_NEXT_FIELD", 0x050400),
+    ("ASI_IC_PRE_DECODE", 0x050400),
+    ("ASI_IC_STAG", 0x050400),
+    ("ASI_IC_TAG", 0x050400),
+    ("ASI_IIU_INST_TRAP", 0x050400),
+    ("ASI_IMMU", 0x050400),
+    ("ASI_IMMU_DEMAP", 0x050400),
+    ("ASI_IMMU_TSB_64KB_PTR", 0x050400),
+    ("ASI_IMMU_TSB_8KB_PTR", 0x050400),
+    ("ASI_INTR_DATAN_R", 0x050400),
+    ("ASI_INTR_DATAN_W", 0x050400),
+    ("ASI_INTR_DISPATCH_STAT", 0x050400),
+    ("ASI_INTR_DISPATCH_W", 0x050400),
+    ("ASI_INTR_ID", 0x050400),
+    ("ASI_INTR_R", 0x050400),
+    ("ASI_INTR_RECEIVE", 0x050400),
+    ("ASI_INTR_W", 0x050400),
+    ("ASI_ITLB_DATA_ACCESS", 0x050400),
+    ("ASI_ITLB_DATA_IN", 0x050400),
+    ("ASI_ITLB_TAG_READ", 0x050400),
+    ("ASI_JBUS_CONFIG", 0x050400),
+    ("ASI_KERNELDATA", 0x050400),
+    ("ASI_KERNELTXT", 0x050400),
+    ("ASI_LEON_BYPASS", 0x050400),
+    ("ASI_LEON_CACHEREGS", 0x050400),
+    ("ASI_LEON_DCACHE_MISS", 0x050400),
+    ("ASI_LEON_DFLUSH", 0x050400),
+    ("ASI_LEON_FLUSH_PAGE", 0x050400),
+    ("ASI_LEON_IFLUSH", 0x050400),
+    ("ASI_LEON_MMUFLUSH", 0x050400),
+    ("ASI_LEON_MMUREGS", 0x050400),
+    ("ASI_LEON_NOCACHE", 0x050400),
+    ("ASI_LSU_CONTROL", 0x050400),
+    ("ASI_MCD_PRIMARY", 0x050400),
+    ("ASI_MCD_PRIV_PRIMARY", 0x050400),
+    ("ASI_MCD_REAL", 0x050400),
+    ("ASI_MCD_ST_BLKINIT_PRIMARY", 0x050400),
+    ("ASI_MCU_CTRL_REG", 0x050400),
+    ("ASI_MMU", 0x050400),
+    ("ASI_M_ACTION", 0x050400),
+    ("ASI_M_BCOPY", 0x050400),
+    ("ASI_M_BFILL", 0x050400),
+    ("ASI_M_BYPASS", 0x050400),
+    ("ASI_M_CTL", 0x050400),
+    ("ASI_M_DATAC_DATA", 0x050400),
+    ("ASI_M_DATAC_TAG", 0x050400),
+    ("ASI_M_DCDR", 0x050400),
+    ("ASI_M_DC_FLCLEAR", 0x050400),
+    ("ASI_M_DIAGS", 0x050400),
+    ("ASI_M_FBMEM", 0x050400),
+    ("ASI_M_FLUSH_CTX", 0x050400),
+    ("ASI_M_FLUSH_IWHOLE", 0x050400),
+    ("ASI_M_FLUSH_PAGE", 0x050400),
+    ("ASI_M_FLUSH_PROBE", 0x050400),
+    ("ASI_M_FLUSH_REGION", 0x050400),
+    ("ASI_M_FLUSH_SEG", 0x050400),
+    ("ASI_M_FLUSH_USER", 0x050400),
+    ("ASI_M_IC_FLCLEAR", 0x050400),
+    ("ASI_M_IFLUSH_CTX", 0x050400),
+    ("ASI_M_IFLUSH_PAGE", 0x050400),
+    ("ASI_M_IFLUSH_REGION", 0x050400),
+    ("ASI_M_IFLUSH_SEG", 0x050400),
+    ("ASI_M_IFLUSH_USER", 0x050400),
+    ("ASI_M_IODIAG", 0x050400),
+    ("ASI_M_KERNELDATA", 0x050400),
+    ("ASI_M_KERNELTXT", 0x050400),
+    ("ASI_M_MMUREGS", 0x050400),
+    ("ASI_M_MXCC", 0x050400),
+    ("ASI_M_RES00", 0x050400),
+    ("ASI_M_SBUS", 0x050400),
+    ("ASI_M_TLBDIAG", 0x050400),
+    ("ASI_M_TXTC_DATA", 0x050400),
+    ("ASI_M_TXTC_TAG", 0x050400),
+    ("ASI_M_UNA01", 0x050400),
+    ("ASI_M_USERDATA", 0x050400),
+    ("ASI_M_USERTXT", 0x050400),
+    ("ASI_M_VIKING_TMP1", 0x050400),
+    ("ASI_M_VMEPS", 0x050400),
+    ("ASI_M_VMEPT", 0x050400),
+    ("ASI_M_VMEUS", 0x050400),
+    ("ASI_M_VMEUT", 0x050400),
+    ("ASI_N", 0x050400),
+    ("ASI_NL", 0x050400),
+    ("ASI_NUCLEUS_QUAD_LDD", 0x050400),
+    ("ASI_NUCLEUS_QUAD_LDD_L", 0x050400),
+    ("ASI_NULL1", 0x050400),
+    ("ASI_NULL2", 0x050400),
+    ("ASI_P", 0x050400),
+    ("ASI_PCACHE_DATA", 0x050400),
+    ("ASI_PCACHE_DATA_STATUS", 0x050400),
+    ("ASI_PCACHE_SNOOP_TAG", 0x050400),
+    ("ASI_PCACHE_TAG", 0x050400),
+    ("ASI_PHYS_BYPASS_EC_E", 0x050400),
+    ("ASI_PHYS_BYPASS_EC_E_L", 0x050400),
+    ("ASI_PHYS_USE_EC", 0x050400),
+    ("ASI_PHYS_USE_EC_L", 0x050400),
+    ("ASI_PIC", 0x050400),
+    ("ASI_PL", 0x050400),
+    ("ASI_PNF", 0x050400),
+    ("ASI_PNFL", 0x050400),
+    ("ASI_PST16_P", 0x050400),
+    ("ASI_PST16_PL", 0x050400),
+    ("ASI_PST16_S", 0x050400),
+    ("ASI_PST16_SL", 0x050400),
+    ("ASI_PST32_P", 0x050400),
+    ("ASI_PST32_PL", 0x050400),
+    ("ASI_PST32_S", 0x050400),
+    ("ASI_PST32_SL", 0x050400),
+    ("ASI_PST8_P", 0x050400),
+    ("ASI_PST8_PL", 0x050400),
+    ("ASI_PST8_S", 0x050400),
+    ("ASI_PST8_SL", 0x050400),
+    ("ASI_PTE", 0x050400),
+    ("ASI_QUAD_LDD_PHYS", 0x050400),
+    ("ASI_QUAD_LDD_PHYS_4V", 0x050400),
+    ("ASI_QUAD_LDD_PHYS_L", 0x050400),
+    ("ASI_QUAD_LDD_PHYS_L_4V", 0x050400),
+    ("ASI_QUEUE", 0x050400),
+    ("ASI_REGMAP", 0x050400),
+    ("ASI_S", 0x050400),
+    ("ASI_SAFARI_ADDRESS", 0x050400),
+    ("ASI_SAFARI_CONFIG", 0x050400),
+    ("ASI_SCRATCHPAD", 0x050400),
+    ("ASI_SEGMAP", 0x050400),
+    ("ASI_SL", 0x050400),
+    ("ASI_SNF", 0x050400),
+    ("ASI_SNFL", 0x050400),
+    ("ASI_SRAM_FAST_INIT", 0x050400),
+    ("ASI_ST_BLKINIT_MRU_P", 0x050400),
+    ("ASI_ST_BLKINIT_MRU_PL", 0x050400),
+    ("ASI_ST_BLKINIT_MRU_S", 0x050400),
+    ("ASI_ST_BLKINIT_MRU_SL", 0x050400),
+    ("ASI_UDBH_CONTROL_R", 0x050400),
+    ("ASI_UDBH_ERROR_R", 0x050400),
+    ("ASI_UDBL_CONTROL_R", 0x050400),
+    ("ASI_UDBL_ERROR_R", 0x050400),
+    ("ASI_UDB_CONTROL_W", 0x050400),
+    ("ASI_UDB_ERROR_W", 0x050400),
+    ("ASI_UPA_CONFIG", 0x050400),
+    ("ASI_USERDATA", 0x050400),
+    ("ASI_USERTXT", 0x050400),
+    ("ASI_WCACHE_DATA", 0x050400),
+    ("ASI_WCACHE_SNOOP_TAG", 0x050400),
+    ("ASI_WCACHE_TAG", 0x050400),
+    ("ASI_WCACHE_VALID_BITS", 0x050400),
+    ("ASI_XIR_STEERING", 0x050400),
+    ("AT_ADI_BLKSZ", 0x050400),
+    ("AT_ADI_NBITS", 0x050400),
+    ("AT_ADI_UEONADI", 0x050400),
+    ("AT_BASE", 0x020620),
+    ("AT_BASE_PLATFORM", 0x020620),
+    ("AT_CLKTCK", 0x020620),
+    ("AT_DCACHEBSIZE", 0x020620),
+    ("AT_EACCESS", 0x050b00),
+    ("AT_EGID", 0x020620),
+    ("AT_EMPTY_PATH", 0x030200),
+    ("AT_ENTRY", 0x020620),
+    ("AT_EUID", 0x020620),
+    ("AT_EXECFD", 0x020620),
+    ("AT_EXECFN", 0x020620),
+    ("AT_FDCWD", 0x020620),
+    ("AT_FLAGS", 0x020620),
+    ("AT_GID", 0x020620),
+    ("AT_HWCAP", 0x020620),
+    ("AT_HWCAP2", 0x030a00),
+    ("AT_ICACHEBSIZE", 0x020620),
+    ("AT_IGNORE", 0x020620),
+    ("AT_IGNOREPPC", 0x020620),
+    ("AT_L1D_CACHEGEOMETRY", 0x050400),
+    ("AT_L1D_CACHESIZE", 0x050400),
+    ("AT_L1I_CACHEGEOMETRY", 0x050400),
+    ("AT_L1I_CACHESIZE", 0x050400),
+    ("AT_L2_CACHEGEOMETRY", 0x050400),
+    ("AT_L2_CACHESIZE", 0x050400),
+    ("AT_L3_CACHEGEOMETRY", 0x050400),
+    ("AT_L3_CACHESIZE", 0x050400),
+    ("AT_MINSIGSTKSZ", 0x050400),
+    ("AT_NOTELF", 0x020620),
+    ("AT_NO_AUTOMOUNT", 0x030200),
+    ("AT_NULL", 0x020620),
+    ("AT_PAGESZ", 0x020620),
+    ("AT_PHDR", 0x020620),
+    ("AT_PHENT", 0x020620),
+    ("AT_PHNUM", 0x020620),
+    ("AT_PLATFORM", 0x020620),
+    ("AT_RANDOM", 0x020620),
+    ("AT_RECURSIVE", 0x050400),
+    ("AT_REMOVEDIR", 0x020620),
+    ("AT_SECURE", 0x020620),
+    ("AT_STATX_DONT_SYNC", 0x041400),
+    ("AT_STATX_FORCE_SYNC", 0x041400),
+    ("AT_STATX_SYNC_AS_STAT", 0x041400),
+    ("AT_STATX_SYNC_TYPE", 0x041400),
+    ("AT_SYMLINK_FOLLOW", 0x020620),
+    ("AT_SYMLINK_NOFOLLOW", 0x020620),
+    ("AT_SYSINFO", 0x020620),
+    ("AT_SYSINFO_EHDR", 0x020620),
+    ("AT_UCACHEBSIZE", 0x020620),
+    ("AT_UID", 0x020620),
+    ("AT_VECTOR_SIZE_ARCH", 0x030a00),
+    ("AUTOFS_SUPER_MAGIC", 0x020620),
+    ("B0", 0x020620),
+    ("B1000000", 0x020620),
+    ("B110", 0x020620),
+    ("B115200", 0x020620),
+    ("B1152000", 0x020620),
+    ("B1200", 0x020620),
+    ("B134", 0x020620),
+    ("B150", 0x020620),
+    ("B1500000", 0x020620),
+    ("B153600", 0x050400),
+    ("B1800", 0x020620),
+    ("B19200", 0x020620),
+    ("B200", 0x020620),
+    ("B2000000", 0x020620),
+    ("B230400", 0x020620),
+    ("B2400", 0x020620),
+    ("B2500000", 0x020620),
+    ("B300", 0x020620),
+    ("B3000000", 0x020620),
+    ("B307200", 0x050400),
+    ("B3500000", 0x020620),
+    ("B38400", 0x020620),
+    ("B4000000", 0x020620),
+    ("B460800", 0x020620),
+    ("B4800", 0x020620),
+    ("B50", 0x020620),
+    ("B500000", 0x020620),
+    ("B57600", 0x020620),
+    ("B576000", 0x020620),
+    ("B600", 0x020620),
+    ("B614400", 0x050400),
+    ("B75", 0x020620),
+    ("B76800", 0x050400),
+    ("B921600", 0x020620),
+    ("B9600", 0x020620),
+    ("BALLOON_KVM_MAGIC", 0x041400),
+    ("BDEVFS_MAGIC", 0x030a00),
+    ("BINDERFS_SUPER_MAGIC", 0x050400),
+    ("BINFMTFS_MAGIC", 0x030a00),
+    ("BLKPBSZGET", 0x020620),
+    ("BLKSSZGET", 0x020620),
+    ("BLOCK_SIZE", 0x020620),
+    ("BLOCK_SIZE_BITS", 0x020620),
+    ("BMAP_IOCTL", 0x020620),
+    ("BOTHER", 0x020620),
+    ("BPF_A", 0x020620),
+    ("BPF_ABS", 0x020620),
+    ("BPF_ADD", 0x020620),
+    ("BPF_ALU", 0x020620),
+    ("BPF_AND", 0x020620),
+    ("BPF_B", 0x020620),
+    ("BPF_DIV", 0x020620),
+    ("BPF_FS_MAGIC", 0x040400),
+    ("BPF_H", 0x020620),
+    ("BPF_IMM", 0x020620),
+    ("BPF_IND", 0x020620),
+    ("BPF_JA", 0x020620),
+    ("BPF_JEQ", 0x020620),
+    ("BPF_JGE", 0x020620),
+    ("BPF_JGT", 0x020620),
+    ("BPF_JMP", 0x020620),
+    ("BPF_JSET", 0x020620),
+    ("BPF_K", 0x020620),
+    ("BPF_LD", 0x020620),
+    ("BPF_LDX", 0x020620),
+    ("BPF_LEN", 0x020620),
+    ("BPF_LL_OFF", 0x040200),
+    ("BPF_LSH", 0x020620),
+    ("BPF_MAJOR_VERSION", 0x020620),
+    ("BPF_MAXINSNS", 0x020620),
+    ("BPF_MEM", 0x020620),
+    ("BPF_MEMWORDS", 0x020620),
+    ("BPF_MINOR_VERSION", 0x020620),
+    ("BPF_MISC", 0x020620),
+    ("BPF_MOD", 0x030a00),
+    ("BPF_MSH", 0x020620),
+    ("BPF_MUL", 0x020620),
+    ("BPF_NEG", 0x020620),
+    ("BPF_NET_OFF", 0x040200),
+    ("BPF_OR", 0x020620),
+    ("BPF_RET", 0x020620),
+    ("BPF_RSH", 0x020620),
+    ("BPF_ST", 0x020620),
+    ("BPF_STX", 0x020620),
+    ("BPF_SUB", 0x020620),
+    ("BPF_TAX", 0x020620),
+    ("BPF_TXA", 0x020620),
+    ("BPF_W", 0x020620),
+    ("BPF_X", 0x020620),
+    ("BPF_XOR", 0x030a00),
+    ("BRKINT", 0x020620),
+    ("BS0", 0x020620),
+    ("BS1", 0x020620),
+    ("BSDLY", 0x020620),
+    ("BTRFS_SUPER_MAGIC", 0x020620),
+    ("BTRFS_TEST_MAGIC", 0x040200),
+    ("BUS_ADRALN", 0x020620),
+    ("BUS_ADRERR", 0x020620),
+    ("BUS_MCEERR_AO", 0x020620),
+    ("BUS_MCEERR_AR", 0x020620),
+    ("BUS_OBJERR", 0x020620),
+    ("CAP_AUDIT_CONTROL", 0x020620),
+    ("CAP_AUDIT_READ", 0x040200),
+    ("CAP_AUDIT_WRITE", 0x020620),
+    ("CAP_BLOCK_SUSPEND", 0x030a00),
+    ("CAP_BPF", 0x050b00),
+    ("CAP_CHECKPOINT_RESTORE", 0x050b00),
+    ("CAP_CHOWN", 0x020620),
+    ("CAP_DAC_OVERRIDE", 0x020620),
+    ("CAP_DAC_READ_SEARCH", 0x020620),
+    ("CAP_FOWNER", 0x020620),
+    ("CAP_FSETID", 0x020620),
+    ("CAP_IPC_LOCK", 0x020620),
+    ("CAP_IPC_OWNER", 0x020620),
+    ("CAP_KILL", 0x020620),
+    ("CAP_LAST_CAP", 0x020620),
+    ("CAP_LEASE", 0x020620),
+    ("CAP_LINUX_IMMUTABLE", 0x020620),
+    ("CAP_MAC_ADMIN", 0x020620),
+    ("CAP_MAC_OVERRIDE", 0x020620),
+    ("CAP_MKNOD", 0x020620),
+    ("CAP_NET_ADMIN", 0x020620),
+    ("CAP_NET_BIND_SERVICE", 0x020620),
+    ("CAP_NET_BROADCAST", 0x020620),
+    ("CAP_NET_RAW", 0x020620),
+    ("CAP_PERFMON", 0x050b00),
+    ("CAP_SETFCAP", 0x020620),
+    ("CAP_SETGID", 0x020620),
+    ("CAP_SETPCAP", 0x020620),
+    ("CAP_SETUID", 0x020620),
+    ("CAP_SYSLOG", 0x030200),
+    ("CAP_SYS_ADMIN", 0x020620),
+    ("CAP_SYS_BOOT", 0x020620),
+    ("CAP_SYS_CHROOT", 0x020620),
+    ("CAP_SYS_MODULE", 0x020620),
+    ("CAP_SYS_NICE", 0x020620),
+    ("CAP_SYS_PACCT", 0x020620),
+    ("CAP_SYS_PTRACE", 0x020620),
+    ("CAP_SYS_RAWIO", 0x020620),
+    ("CAP_SYS_RESOURCE", 0x020620),
+    ("CAP_SYS_TIME", 0x020620),
+    ("CAP_SYS_TTY_CONFIG", 0x020620),
+    ("CAP_WAKE_ALARM", 0x030200),
+    ("CBAUD", 0x020620),
+    ("CBAUDEX", 0x020620),
+    ("CGROUP2_SUPER_MAGIC", 0x041400),
+    ("CGROUP_SUPER_MAGIC", 0x020620),
+    ("CIBAUD", 0x020620),
+    ("CLD_CONTINUED", 0x020620),
+    ("CLD_DUMPED", 0x020620),
+    ("CLD_EXITED", 0x020620),
+    ("CLD_KILLED", 0x020620),
+    ("CLD_STOPPED", 0x020620),
+    ("CLD_TRAPPED", 0x020620),
+    ("CLOCAL", 0x020620),
+    ("CLOCKS_MASK", 0x020620),
+    ("CLOCKS_MONO", 0x020620),
+    ("CLOCK_BOOTTIME", 0x030200),
+    ("CLOCK_BOOTTIME_ALARM", 0x030200),
+    ("CLOCK_MONOTONIC", 0x020620),
+    ("CLOCK_MONOTONIC_COARSE", 0x020620),
+    ("CLOCK_MONOTONIC_RAW", 0x020620),
+    ("CLOCK_PROCESS_CPUTIME_ID", 0x020620),
+    ("CLOCK_REALTIME", 0x020620),
+    ("CLOCK_REALTIME_ALARM", 0x030200),
+    ("CLOCK_REALTIME_COARSE", 0x020620),
+    ("CLOCK_SGI_CYCLE", 0x020620),
+    ("CLOCK_TAI", 0x030a00),
+    ("CLOCK_THREAD_CPUTIME_ID", 0x020620),
+    ("CLONE_ARGS_SIZE_VER0", 0x050400),
+    ("CLONE_ARGS_SIZE_VER1", 0x050b00),
+    ("CLONE_ARGS_SIZE_VER2", 0x050b00),
+    ("CLONE_CHILD_CLEARTID", 0x020620),
+    ("CLONE_CHILD_SETTID", 0x020620),
+    ("CLONE_CLEAR_SIGHAND", 0x050b00),
+    ("CLONE_DETACHED", 0x020620),
+    ("CLONE_FILES", 0x020620),
+    ("CLONE_FS", 0x020620),
+    ("CLONE_INTO_CGROUP", 0x050b00),
+    ("CLONE_IO", 0x020620),
+    ("CLONE_NEWCGROUP", 0x041400),
+    ("CLONE_NEWIPC", 0x020620),
+    ("CLONE_NEWNET", 0x020620),
+    ("CLONE_NEWNS", 0x020620),
+    ("CLONE_NEWPID", 0x020620),
+    ("CLONE_NEWTIME", 0x050b00),
+    ("CLONE_NEWUSER", 0x020620),
+    ("CLONE_NEWUTS", 0x020620),
+    ("CLONE_PARENT", 0x020620),
+    ("CLONE_PARENT_SETTID", 0x020620),
+    ("CLONE_PIDFD", 0x050400),
+    ("CLONE_PTRACE", 0x020620),
+    ("CLONE_SETTLS", 0x020620),
+    ("CLONE_SIGHAND", 0x020620),
+    ("CLONE_STOPPED", 0x020620),
+    ("CLONE_SYSVSEM", 0x020620),
+    ("CLONE_THREAD", 0x020620),
+    ("CLONE_UNTRACED", 0x020620),
+    ("CLONE_VFORK", 0x020620),
+    ("CLONE_VM", 0x020620),
+    ("CMSPAR", 0x020620),
+    ("CODA_SUPER_MAGIC", 0x020620),
+    ("CR0", 0x020620),
+    ("CR1", 0x020620),
+    ("CR2", 0x020620),
+    ("CR3", 0x020620),
+    ("CRAMFS_MAGIC", 0x020620),
+    ("CRAMFS_MAGIC_WEND", 0x020620),
+    ("CRDLY", 0x020620),
+    ("CREAD", 0x020620),
+    ("CRTSCTS", 0x020620),
+    ("CS5", 0x020620),
+    ("CS6", 0x020620),
+    ("CS7", 0x020620),
+    ("CS8", 0x020620),
+    ("CSIGNAL", 0x020620),
+    ("CSIZE", 0x020620),
+    ("CSTOPB", 0x020620),
+    ("CTSXON", 0x020620),
+    ("DAXFS_MAGIC", 0x041400),
+    ("DEBUGFS_MAGIC", 0x020620),
+    ("DEFECHO", 0x050400),
+    ("DEVMEM_MAGIC", 0x050b00),
+    ("DEVPTS_SUPER_MAGIC", 0x020620),
+    ("DMA_BUF_MAGIC", 0x050400),
+    ("DN_ACCESS", 0x020620),
+    ("DN_ATTRIB", 0x020620),
+    ("DN_CREATE", 0x020620),
+    ("DN_DELETE", 0x020620),
+    ("DN_MODIFY", 0x020620),
+    ("DN_MULTISHOT", 0x020620),
+    ("DN_RENAME", 0x020620),
+    ("DSRXON", 0x020620),
+    ("DTRXOFF", 0x020620),
+    ("DT_BLK", 0x020620),
+    ("DT_CHR", 0x020620),
+    ("DT_DIR", 0x020620),
+    ("DT_FIFO", 0x020620),
+    ("DT_LNK", 0x020620),
+    ("DT_REG", 0x020620),
+    ("DT_SOCK", 0x020620),
+    ("DT_UNKNOWN", 0x020620),
+    ("E2BIG", 0x040400),
+    ("EACCES", 0x040400),
+    ("EADDRINUSE", 0x040400),
+    ("EADDRNOTAVAIL", 0x040400),
+    ("EADV", 0x040400),
+    ("EAFNOSUPPORT", 0x040400),
+    ("EAGAIN", 0x040400),
+    ("EALREADY", 0x040400),
+    ("EBADE", 0x040400),
+    ("EBADF", 0x040400),
+    ("EBADFD", 0x040400),
+    ("EBADMSG", 0x040400),
+    ("EBADR", 0x040400),
+    ("EBADRQC", 0x040400),
+    ("EBADSLT", 0x040400),
+    ("EBFONT", 0x040400),
+    ("EBUSY", 0x040400),
+    ("ECANCELED", 0x040400),
+    ("ECHILD", 0x040400),
+    ("ECHO", 0x020620),
+    ("ECHOCTL", 0x020620),
+    ("ECHOE", 0x020620),
+    ("ECHOK", 0x020620),
+    ("ECHOKE", 0x020620),
+    ("ECHONL", 0x020620),
+    ("ECHOPRT", 0x020620),
+    ("ECHRNG", 0x040400),
+    ("ECOMM", 0x040400),
+    ("ECONNABORTED", 0x040400),
+    ("ECONNREFUSED", 0x040400),
+    ("ECONNRESET", 0x040400),
+    ("ECRYPTFS_SUPER_MAGIC", 0x030200),
+    ("EDEADLK", 0x040400),
+    ("EDEADLOCK", 0x040400),
+    ("EDESTADDRREQ", 0x040400),
+    ("EDOM", 0x040400),
+    ("EDOTDOT", 0x040400),
+    ("EDQUOT", 0x040400),
+    ("EEXIST", 0x040400),
+    ("EFAULT", 0x040400),
+    ("EFBIG", 0x040400),
+    ("EFD_CLOEXEC", 0x020620),
+    ("EFD_NONBLOCK", 0x020620),
+    ("EFD_SEMAPHORE", 0x020620),
+    ("EFIVARFS_MAGIC", 0x030a00),
+    ("EFS_SUPER_MAGIC", 0x020620),
+    ("EHOSTDOWN", 0x040400),
+    ("EHOSTUNREACH", 0x040400),
+    ("EHWPOISON", 0x040400),
+    ("EIDRM", 0x040400),
+    ("EILSEQ", 0x040400),
+    ("EINIT", 0x040400),
+    ("EINPROGRESS", 0x040400),
+    ("EINTR", 0x040400),
+    ("EINVAL", 0x040400),
+    ("EIO", 0x040400),
+    ("EISCONN", 0x040400),
+    ("EISDIR", 0x040400),
+    ("EISNAM", 0x040400),
+    ("EKEYEXPIRED", 0x040400),
+    ("EKEYREJECTED", 0x040400),
+    ("EKEYREVOKED", 0x040400),
+    ("EL2HLT", 0x040400),
+    ("EL2NSYNC", 0x040400),
+    ("EL3HLT", 0x040400),
+    ("EL3RST", 0x040400),
+    ("ELIBACC", 0x040400),
+    ("ELIBBAD", 0x040400),
+    ("ELIBEXEC", 0x040400),
+    ("ELIBMAX", 0x040400),
+    ("ELIBSCN", 0x040400),
+    ("ELNRNG", 0x040400),
+    ("ELOOP", 0x040400),
+    ("EMEDIUMTYPE", 0x040400),
+    ("EMFILE", 0x040400),
+    ("EMLINK", 0x040400),
+    ("EMSGSIZE", 0x040400),
+    ("EMT_TAGOVF", 0x041400),
+    ("EMULTIHOP", 0x040400),
+    ("ENAMETOOLONG", 0x040400),
+    ("ENAVAIL", 0x040400),
+    ("ENETDOWN", 0x040400),
+    ("ENETRESET", 0x040400),
+    ("ENETUNREACH", 0x040400),
+    ("ENFILE", 0x040400),
+    ("ENOANO", 0x040400),
+    ("ENOBUFS", 0x040400),
+    ("ENOCSI", 0x040400),
+    ("ENODATA", 0x040400),
+    ("ENODEV", 0x040400),
+    ("ENOENT", 0x040400),
+    ("ENOEXEC", 0x040400),
+    ("ENOKEY", 0x040400),
+    ("ENOLCK", 0x040400),
+    ("ENOLINK", 0x040400),
+    ("ENOMEDIUM", 0x040400),
+    ("ENOMEM", 0x040400),
+    ("ENOMSG", 0x040400),
+    ("ENONET", 0x040400),
+    ("ENOPKG", 0x040400),
+    ("ENOPROTOOPT", 0x040400),
+    ("ENOSPC", 0x040400),
+    ("ENOSR", 0x040400),
+    ("ENOSTR", 0x040400),
+    ("ENOSYS", 0x040400),
+    ("ENOTBLK", 0x040400),
+    ("ENOTCONN", 0x040400),
+    ("ENOTDIR", 0x040400),
+    ("ENOTEMPTY", 0x040400),
+    ("ENOTNAM", 0x040400),
+    ("ENOTRECOVERABLE", 0x040400),
+    ("ENOTSOCK", 0x040400),
+    ("ENOTTY", 0x040400),
+    ("ENOTUNIQ", 0x040400),
+    ("ENXIO", 0x040400),
+    ("EOPNOTSUPP", 0x040400),
+    ("EOVERFLOW", 0x040400),
+    ("EOWNERDEAD", 0x040400),
+    ("EPERM", 0x040400),
+    ("EPFNOSUPPORT", 0x040400),
+    ("EPIPE", 0x040400),
+    ("EPOLLERR", 0x020620),
+    ("EPOLLET", 0x020620),
+    ("EPOLLEXCLUSIVE", 0x020620),
+    ("EPOLLHUP", 0x020620),
+    ("EPOLLIN", 0x020620),
+    ("EPOLLMSG", 0x020620),
+    ("EPOLLNVAL", 0x020620),
+    ("EPOLLONESHOT", 0x020620),
+    ("EPOLLOUT", 0x020620),
+    ("EPOLLPRI", 0x020620),
+    ("EPOLLRDBAND", 0x020620),
+    ("EPOLLRDHUP", 0x020620),
+    ("EPOLLRDNORM", 0x020620),
+    ("EPOLLWAKEUP", 0x020620),
+    ("EPOLLWRBAND", 0x020620),
+    ("EPOLLWRNORM", 0x020620),
+    ("EPOLL_CLOEXEC", 0x020620),
+    ("EPOLL_CTL_ADD", 0x020620),
+    ("EPOLL_CTL_DEL", 0x020620),
+    ("EPOLL_CTL_MOD", 0x020620),
+    ("EPROTO", 0x040400),
+    ("EPROTONOSUPPORT", 0x040400),
+    ("EPROTOTYPE", 0x040400),
+    ("ERANGE", 0x040400),
+    ("EREMCHG", 0x040400),
+    ("EREMDEV", 0x040400),
+    ("EREMOTE", 0x040400),
+    ("EREMOTEIO", 0x040400),
+    ("ERESTART", 0x040400),
+    ("ERFKILL", 0x040400),
+    ("EROFS", 0x040400),
+    ("EROFS_SUPER_MAGIC_V1", 0x050400),
+    ("ESHUTDOWN", 0x040400),
+    ("ESOCKTNOSUPPORT", 0x040400),
+    ("ESPIPE", 0x040400),
+    ("ESRCH", 0x040400),
+    ("ESRMNT", 0x040400),
+    ("ESTALE", 0x040400),
+    ("ESTRPIPE", 0x040400),
+    ("ETIME", 0x040400),
+    ("ETIMEDOUT", 0x040400),
+    ("ETOOMANYREFS", 0x040400),
+    ("ETXTBSY", 0x040400),
+    ("EUCLEAN", 0x040400),
+    ("EUNATCH", 0x040400),
+    ("EUSERS", 0x040400),
+    ("EWOULDBLOCK", 0x040400),
+    ("EXDEV", 0x040400),
+    ("EXFULL", 0x040400),
+    ("EXT2_SUPER_MAGIC", 0x020620),
+    ("EXT3_SUPER_MAGIC", 0x020620),
+    ("EXT4_SUPER_MAGIC", 0x020620),
+    ("EXTA", 0x020620),
+    ("EXTB", 0x020620),
+    ("EXTPROC", 0x030200),
+    ("F2FS_SUPER_MAGIC", 0x030a00),
+    ("FALLOC_FL_COLLAPSE_RANGE", 0x040200),
+    ("FALLOC_FL_INSERT_RANGE", 0x040200),
+    ("FALLOC_FL_KEEP_SIZE", 0x020620),
+    ("FALLOC_FL_NO_HIDE_STALE", 0x030a00),
+    ("FALLOC_FL_PUNCH_HOLE", 0x030200),
+    ("FALLOC_FL_UNSHARE_RANGE", 0x041400),
+    ("FALLOC_FL_ZERO_RANGE", 0x040200),
+    ("FASYNC", 0x020620),
+    ("FD_CLOEXEC", 0x020620),
+    ("FD_SETSIZE", 0x020620),
+    ("FF0", 0x020620),
+    ("FF1", 0x020620),
+    ("FFDLY", 0x020620),
+    ("FILE_DEDUPE_RANGE_DIFFERS", 0x041400),
+    ("FILE_DEDUPE_RANGE_SAME", 0x041400),
+    ("FIOASYNC", 0x020620),
+    ("FIOCLEX", 0x020620),
+    ("FIOGETOWN", 0x020620),
+    ("FIONBIO", 0x020620),
+    ("FIONCLEX", 0x020620),
+    ("FIONREAD", 0x020620),
+    ("FIOQSIZE", 0x020620),
+    ("FIOSETOWN", 0x020620),
+    ("FLUSHO", 0x020620),
+    ("FPE_CONDTRAP", 0x041400),
+    ("FPE_FLTDIV", 0x020620),
+    ("FPE_FLTINV", 0x020620),
+    ("FPE_FLTOVF", 0x020620),
+    ("FPE_FLTRES", 0x020620),
+    ("FPE_FLTSUB", 0x020620),
+    ("FPE_FLTUND", 0x020620),
+    ("FPE_FLTUNK", 0x041400),
+    ("FPE_INTDIV", 0x020620),
+    ("FPE_INTOVF", 0x020620),
+    ("FSCRYPT_KEY_DESCRIPTOR_SIZE", 0x050400),
+    ("FSCRYPT_KEY_DESC_PREFIX", 0x050400),
+    ("FSCRYPT_KEY_DESC_PREFIX_SIZE", 0x050400),
+    ("FSCRYPT_KEY_IDENTIFIER_SIZE", 0x050400),
+    ("FSCRYPT_KEY_REMOVAL_STATUS_FLAG_FILES_BUSY", 0x050400),
+    ("FSCRYPT_KEY_REMOVAL_STATUS_FLAG_OTHER_USERS", 0x050400),
+    ("FSCRYPT_KEY_SPEC_TYPE_DESCRIPTOR", 0x050400),
+    ("FSCRYPT_KEY_SPEC_TYPE_IDENTIFIER", 0x050400),
+    ("FSCRYPT_KEY_STATUS_ABSENT", 0x050400),
+    ("FSCRYPT_KEY_STATUS_FLAG_ADDED_BY_SELF", 0x050400),
+    ("FSCRYPT_KEY_STATUS_INCOMPLETELY_REMOVED", 0x050400),
+    ("FSCRYPT_KEY_STATUS_PRESENT", 0x050400),
+    ("FSCRYPT_MAX_KEY_SIZE", 0x050400),
+    ("FSCRYPT_MODE_ADIANTUM", 0x050400),
+    ("FSCRYPT_MODE_AES_128_CBC", 0x050400),
+    ("FSCRYPT_MODE_AES_128_CTS", 0x050400),
+    ("FSCRYPT_MODE_AES_256_CTS", 0x050400),
+    ("FSCRYPT_MODE_AES_256_XTS", 0x050400),
+    ("FSCRYPT_POLICY_FLAGS_PAD_16", 0x050400),
+    ("FSCRYPT_POLICY_FLAGS_PAD_32", 0x050400),
+    ("FSCRYPT_POLICY_FLAGS_PAD_4", 0x050400),
+    ("FSCRYPT_POLICY_FLAGS_PAD_8", 0x050400),
+    ("FSCRYPT_POLICY_FLAGS_PAD_MASK", 0x050400),
+    ("FSCRYPT_POLICY_FLAGS_VALID", 0x050400),
+    ("FSCRYPT_POLICY_FLAG_DIRECT_KEY", 0x050400),
+    ("FSCRYPT_POLICY_FLAG_IV_INO_LBLK_32", 0x050b00),
+    ("FSCRYPT_POLICY_FLAG_IV_INO_LBLK_64", 0x050b00),
+    ("FSCRYPT_POLICY_V1", 0x050400),
+    ("FSCRYPT_POLICY_V2", 0x050400),
+    ("FSLABEL_MAX", 0x041400),
+    ("FSMOUNT_CLOEXEC", 0x050400),
+    ("FSOPEN_CLOEXEC", 0x050400),
+    ("FSPICK_CLOEXEC", 0x050400),
+    ("FSPICK_EMPTY_PATH", 0x050400),
+    ("FSPICK_NO_AUTOMOUNT", 0x050400),
+    ("FSPICK_SYMLINK_NOFOLLOW", 0x050400),
+    ("FS_APPEND_FL", 0x020620),
+    ("FS_BINARY_MOUNTDATA", 0x020620),
+    ("FS_BTREE_FL", 0x020620),
+    ("FS_CASEFOLD_FL", 0x050400),
+    ("FS_COMPRBLK_FL", 0x020620),
+    ("FS_COMPR_FL", 0x020620),
+    ("FS_DAX_FL", 0x050b00),
+    ("FS_DIRECTIO_FL", 0x020620),
+    ("FS_DIRSYNC_FL", 0x020620),
+    ("FS_DIRTY_FL", 0x020620),
+    ("FS_EA_INODE_FL", 0x041400),
+    ("FS_ECOMPR_FL", 0x020620),
+    ("FS_ENCRYPTION_MODE_ADIANTUM", 0x050400),
+    ("FS_ENCRYPTION_MODE_AES_128_CBC", 0x041400),
+    ("FS_ENCRYPTION_MODE_AES_128_CTS", 0x041400),
+    ("FS_ENCRYPTION_MODE_AES_256_CBC", 0x041400),
+    ("FS_ENCRYPTION_MODE_AES_256_CTS", 0x041400),
+    ("FS_ENCRYPTION_MODE_AES_256_GCM", 0x041400),
+    ("FS_ENCRYPTION_MODE_AES_256_XTS", 0x041400),
+    ("FS_ENCRYPTION_MODE_INVALID", 0x041400),
+    ("FS_ENCRYPTION_MODE_SPECK128_256_CTS", 0x041400),
+    ("FS_ENCRYPTION_MODE_SPECK128_256_XTS", 0x041400),
+    ("FS_ENCRYPT_FL", 0x041400),
+    ("FS_EOFBLOCKS_FL", 0x041400),
+    ("FS_EXTENT_FL", 0x020620),
+    ("FS_FL_USER_MODIFIABLE", 0x020620),
+    ("FS_FL_USER_VISIBLE", 0x020620),
+    ("FS_HAS_SUBTYPE", 0x020620),
+    ("FS_HUGE_FILE_FL", 0x041400),
+    ("FS_IMAGIC_FL", 0x020620),
+    ("FS_IMMUTABLE_FL", 0x020620),
+    ("FS_INDEX_FL", 0x020620),
+    ("FS_INLINE_DATA_FL", 0x041400),
+    ("FS_JOURNAL_DATA_FL", 0x020620),
+    ("FS_KEY_DESCRIPTOR_SIZE", 0x041400),
+    ("FS_KEY_DESC_PREFIX", 0x041400),
+    ("FS_KEY_DESC_PREFIX_SIZE", 0x041400),
+    ("FS_MAX_KEY_SIZE", 0x041400),
+    ("FS_NOATIME_FL", 0x020620),
+    ("FS_NOCOMP_FL", 0x020620),
+    ("FS_NOCOW_FL", 0x030200),
+    ("FS_NODUMP_FL", 0x020620),
+    ("FS_NOTAIL_FL", 0x020620),
+    ("FS_POLICY_FLAGS_PAD_16", 0x041400),
+    ("FS_POLICY_FLAGS_PAD_32", 0x041400),
+    ("FS_POLICY_FLAGS_PAD_4", 0x041400),
+    ("FS_POLICY_FLAGS_PAD_8", 0x041400),
+    ("FS_POLICY_FLAGS_PAD_MASK", 0x041400),
+    ("FS_POLICY_FLAGS_VALID", 0x041400),
+    ("FS_POLICY_FLAG_DIRECT_KEY", 0x050400),
+    ("FS_PROJINHERIT_FL", 0x040400),
+    ("FS_RENAME_DOES_D_MOVE", 0x020620),
+    ("FS_REQUIRES_DEV", 0x020620),
+    ("FS_RESERVED_FL", 0x020620),
+    ("FS_REVAL_DOT", 0x020620),
+    ("FS_SECRM_FL", 0x020620),
+    ("FS_SYNC_FL", 0x020620),
+    ("FS_TOPDIR_FL", 0x020620),
+    ("FS_UNRM_FL", 0x020620),
+    ("FS_VERITY_FL", 0x050400),
+    ("FS_XFLAG_APPEND", 0x041400),
+    ("FS_XFLAG_COWEXTSIZE", 0x041400),
+    ("FS_XFLAG_DAX", 0x041400),
+    ("FS_XFLAG_EXTSIZE", 0x041400),
+    ("FS_XFLAG_EXTSZINHERIT", 0x041400),
+    ("FS_XFLAG_FILESTREAM", 0x041400),
+    ("FS_XFLAG_HASATTR", 0x041400),
+    ("FS_XFLAG_IMMUTABLE", 0x041400),
+    ("FS_XFLAG_NOATIME", 0x041400),
+    ("FS_XFLAG_NODEFRAG", 0x041400),
+    ("FS_XFLAG_NODUMP", 0x041400),
+    ("FS_XFLAG_NOSYMLINKS", 0x041400),
+    ("FS_XFLAG_PREALLOC", 0x041400),
+    ("FS_XFLAG_PROJINHERIT", 0x041400),
+    ("FS_XFLAG_REALTIME", 0x041400),
+    ("FS_XFLAG_RTINHERIT", 0x041400),
+    ("FS_XFLAG_SYNC", 0x041400),
+    ("FUTEXFS_SUPER_MAGIC", 0x020620),
+    ("FUTEX_BITSET_MATCH_ANY", 0x020620),
+    ("FUTEX_CLOCK_REALTIME", 0x020620),
+    ("FUTEX_CMD_MASK", 0x020620),
+    ("FUTEX_CMP_REQUEUE", 0x020620),
+    ("FUTEX_CMP_REQUEUE_PI", 0x020620),
+    ("FUTEX_CMP_REQUEUE_PI_PRIVATE", 0x020620),
+    ("FUTEX_CMP_REQUEUE_PRIVATE", 0x020620),
+    ("FUTEX_FD", 0x020620),
+    ("FUTEX_LOCK_PI", 0x020620),
+    ("FUTEX_LOCK_PI_PRIVATE", 0x020620),
+    ("FUTEX_OP_ADD", 0x020620),
+    ("FUTEX_OP_ANDN", 0x020620),
+    ("FUTEX_OP_CMP_EQ", 0x020620),
+    ("FUTEX_OP_CMP_GE", 0x020620),
+    ("FUTEX_OP_CMP_GT", 0x020620),
+    ("FUTEX_OP_CMP_LE", 0x020620),
+    ("FUTEX_OP_CMP_LT", 0x020620),
+    ("FUTEX_OP_CMP_NE", 0x020620),
+    ("FUTEX_OP_OPARG_SHIFT", 0x020620),
+    ("FUTEX_OP_OR", 0x020620),
+    ("FUTEX_OP_SET", 0x020620),
+    ("FUTEX_OP_XOR", 0x020620),
+    ("FUTEX_OWNER_DIED", 0x020620),
+    ("FUTEX_PRIVATE_FLAG", 0x020620),
+    ("FUTEX_REQUEUE", 0x020620),
+    ("FUTEX_REQUEUE_PRIVATE", 0x020620),
+    ("FUTEX_TID_MASK", 0x020620),
+    ("FUTEX_TRYLOCK_PI", 0x020620),
+    ("FUTEX_TRYLOCK_PI_PRIVATE", 0x020620),
+    ("FUTEX_UNLOCK_PI", 0x020620),
+    ("FUTEX_UNLOCK_PI_PRIVATE", 0x020620),
+    ("FUTEX_WAIT", 0x020620),
+    ("FUTEX_WAITERS", 0x020620),
+    ("FUTEX_WAIT_BITSET", 0x020620),
+    ("FUTEX_WAIT_BITSET_PRIVATE", 0x020620),
+    ("FUTEX_WAIT_PRIVATE", 0x020620),
+    ("FUTEX_WAIT_REQUEUE_PI", 0x020620),
+    ("FUTEX_WAIT_REQUEUE_PI_PRIVATE", 0x020620),
+    ("FUTEX_WAKE", 0x020620),
+    ("FUTEX_WAKE_BITSET", 0x020620),
+    ("FUTEX_WAKE_BITSET_PRIVATE", 0x020620),
+    ("FUTEX_WAKE_OP", 0x020620),
+    ("FUTEX_WAKE_OP_PRIVATE", 0x020620),
+    ("FUTEX_WAKE_PRIVATE", 0x020620),
+    ("F_ADD_SEALS", 0x040200),
+    ("F_CANCELLK", 0x020620),
+    ("F_DUPFD", 0x020620),
+    ("F_DUPFD_CLOEXEC", 0x020620),
+    ("F_EXLCK", 0x020620),
+    ("F_GETFD", 0x020620),
+    ("F_GETFL", 0x020620),
+    ("F_GETLEASE", 0x020620),
+    ("F_GETLK", 0x020620),
+    ("F_GETLK64", 0x020620),
+    ("F_GETOWN", 0x020620),
+    ("F_GETOWNER_UIDS", 0x030a00),
+    ("F_GETOWN_EX", 0x020620),
+    ("F_GETPIPE_SZ", 0x030200),
+    ("F_GETSIG", 0x020620),
+    ("F_GET_FILE_RW_HINT", 0x041400),
+    ("F_GET_RW_HINT", 0x041400),
+    ("F_GET_SEALS", 0x040200),
+    ("F_INPROGRESS", 0x020620),
+    ("F_LINUX_SPECIFIC_BASE", 0x020620),
+    ("F_NOTIFY", 0x020620),
+    ("F_OFD_GETLK", 0x040200),
+    ("F_OFD_SETLK", 0x040200),
+    ("F_OFD_SETLKW", 0x040200),
+    ("F_OK", 0x020620),
+    ("F_OWNER_PGRP", 0x020620),
+    ("F_OWNER_PID", 0x020620),
+    ("F_OWNER_TID", 0x020620),
+    ("F_RDLCK", 0x020620),
+    ("F_SEAL_FUTURE_WRITE", 0x050400),
+    ("F_SEAL_GROW", 0x040200),
+    ("F_SEAL_SEAL", 0x040200),
+    ("F_SEAL_SHRINK", 0x040200),
+    ("F_SEAL_WRITE", 0x040200),
+    ("F_SETFD", 0x020620),
+    ("F_SETFL", 0x020620),
+    ("F_SETLEASE", 0x020620),
+    ("F_SETLK", 0x020620),
+    ("F_SETLK64", 0x020620),
+    ("F_SETLKW", 0x020620),
+    ("F_SETLKW64", 0x020620),
+    ("F_SETOWN", 0x020620),
+    ("F_SETOWN_EX", 0x020620),
+    ("F_SETPIPE_SZ", 0x030200),
+    ("F_SETSIG", 0x020620),
+    ("F_SET_FILE_RW_HINT", 0x041400),
+    ("F_SET_RW_HINT", 0x041400),
+    ("F_SHLCK", 0x020620),
+    ("F_UNLCK", 0x020620),
+    ("F_WRLCK", 0x020620),
+    ("GRND_INSECURE", 0x050b00),
+    ("GRND_NONBLOCK", 0x040200),
+    ("GRND_RANDOM", 0x040200),
+    ("HOSTFS_SUPER_MAGIC", 0x030a00),
+    ("HPFS_SUPER_MAGIC", 0x020620),
+    ("HUGETLBFS_MAGIC", 0x020620),
+    ("HUGETLB_FLAG_ENCODE_16GB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_16KB", 0x050b00),
+    ("HUGETLB_FLAG_ENCODE_16MB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_1GB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_1MB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_256MB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_2GB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_2MB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_32MB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_512KB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_512MB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_64KB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_8MB", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_MASK", 0x041400),
+    ("HUGETLB_FLAG_ENCODE_SHIFT", 0x041400),
+    ("HUPCL", 0x020620),
+    ("IBSHIFT", 0x020620),
+    ("ICANON", 0x020620),
+    ("ICRNL", 0x020620),
+    ("IEXTEN", 0x020620),
+    ("IGNBRK", 0x020620),
+    ("IGNCR", 0x020620),
+    ("IGNPAR", 0x020620),
+    ("ILL_BADIADDR", 0x041400),
+    ("ILL_BADSTK", 0x020620),
+    ("ILL_COPROC", 0x020620),
+    ("ILL_ILLADR", 0x020620),
+    ("ILL_ILLOPC", 0x020620),
+    ("ILL_ILLOPN", 0x020620),
+    ("ILL_ILLTRP", 0x020620),
+    ("ILL_PRVOPC", 0x020620),
+    ("ILL_PRVREG", 0x020620),
+    ("IMAXBEL", 0x020620),
+    ("INADDR_ALLHOSTS_GROUP", 0x020620),
+    ("INADDR_ALLRTRS_GROUP", 0x020620),
+    ("INADDR_ALLSNOOPERS_GROUP", 0x050400),
+    ("INADDR_LOOPBACK", 0x020620),
+    ("INADDR_MAX_LOCAL_GROUP", 0x020620),
+    ("INADDR_UNSPEC_GROUP", 0x020620),
+    ("INLCR", 0x020620),
+    ("INOTIFYFS_SUPER_MAGIC", 0x020620),
+    ("INPCK", 0x020620),
+    ("INR_OPEN", 0x020620),
+    ("INR_OPEN_CUR", 0x030200),
+    ("INR_OPEN_MAX", 0x030200),
+    ("IN_CLASSA_HOST", 0x020620),
+    ("IN_CLASSA_MAX", 0x020620),
+    ("IN_CLASSA_NET", 0x020620),
+    ("IN_CLASSA_NSHIFT", 0x020620),
+    ("IN_CLASSB_HOST", 0x020620),
+    ("IN_CLASSB_MAX", 0x020620),
+    ("IN_CLASSB_NET", 0x020620),
+    ("IN_CLASSB_NSHIFT", 0x020620),
+    ("IN_CLASSC_HOST", 0x020620),
+    ("IN_CLASSC_NET", 0x020620),
+    ("IN_CLASSC_NSHIFT", 0x020620),
+    ("IN_CLASSE_NET", 0x041400),
+    ("IN_CLASSE_NSHIFT", 0x041400),
+    ("IN_LOOPBACKNET", 0x020620),
+    ("IN_MULTICAST_NET", 0x020620),
+    ("IOCSIZE_MASK", 0x020620),
+    ("IOCSIZE_SHIFT", 0x020620),
+    ("IOC_IN", 0x020620),
+    ("IOC_INOUT", 0x020620),
+    ("IOC_OUT", 0x020620),
+    ("IPPROTO_AH", 0x020620),
+    ("IPPROTO_BEETPH", 0x020620),
+    ("IPPROTO_COMP", 0x020620),
+    ("IPPROTO_DCCP", 0x020620),
+    ("IPPROTO_DSTOPTS", 0x020620),
+    ("IPPROTO_EGP", 0x020620),
+    ("IPPROTO_ENCAP", 0x040200),
+    ("IPPROTO_ESP", 0x020620),
+    ("IPPROTO_ETHERNET", 0x050b00),
+    ("IPPROTO_FRAGMENT", 0x020620),
+    ("IPPROTO_GRE", 0x020620),
+    ("IPPROTO_HOPOPTS", 0x020620),
+    ("IPPROTO_ICMP", 0x020620),
+    ("IPPROTO_ICMPV6", 0x020620),
+    ("IPPROTO_IDP", 0x020620),
+    ("IPPROTO_IGMP", 0x020620),
+    ("IPPROTO_IP", 0x020620),
+    ("IPPROTO_IPIP", 0x020620),
+    ("IPPROTO_IPV6", 0x020620),
+    ("IPPROTO_MAX", 0x020620),
+    ("IPPROTO_MH", 0x020620),
+    ("IPPROTO_MPLS", 0x040200),
+    ("IPPROTO_MPTCP", 0x050b00),
+    ("IPPROTO_MTP", 0x040200),
+    ("IPPROTO_NONE", 0x020620),
+    ("IPPROTO_PIM", 0x020620),
+    ("IPPROTO_PUP", 0x020620),
+    ("IPPROTO_RAW", 0x020620),
+    ("IPPROTO_ROUTING", 0x020620),
+    ("IPPROTO_RSVP", 0x020620),
+    ("IPPROTO_SCTP", 0x020620),
+    ("IPPROTO_TCP", 0x020620),
+    ("IPPROTO_TP", 0x040200),
+    ("IPPROTO_UDP", 0x020620),
+    ("IPPROTO_UDPLITE", 0x020620),
+    ("IPV6_2292DSTOPTS", 0x020620),
+    ("IPV6_2292HOPLIMIT", 0x020620),
+    ("IPV6_2292HOPOPTS", 0x020620),
+    ("IPV6_2292PKTINFO", 0x020620),
+    ("IPV6_2292PKTOPTIONS", 0x020620),
+    ("IPV6_2292RTHDR", 0x020620),
+    ("IPV6_ADDRFORM", 0x020620),
+    ("IPV6_ADDR_PREFERENCES", 0x020620),
+    ("IPV6_ADD_MEMBERSHIP", 0x020620),
+    ("IPV6_AUTHHDR", 0x020620),
+    ("IPV6_AUTOFLOWLABEL", 0x040200),
+    ("IPV6_CHECKSUM", 0x020620),
+    ("IPV6_DONTFRAG", 0x030200),
+    ("IPV6_DROP_MEMBERSHIP", 0x020620),
+    ("IPV6_DSTOPTS", 0x020620),
+    ("IPV6_FLOWINFO", 0x020620),
+    ("IPV6_FLOWINFO_FLOWLABEL", 0x020620),
+    ("IPV6_FLOWINFO_PRIORITY", 0x020620),
+    ("IPV6_FLOWINFO_SEND", 0x020620),
+    ("IPV6_FLOWLABEL_MGR", 0x020620),
+    ("IPV6_FL_A_GET", 0x020620),
+    ("IPV6_FL_A_PUT", 0x020620),
+    ("IPV6_FL_A_RENEW", 0x020620),
+    ("IPV6_FL_F_CREATE", 0x020620),
+    ("IPV6_FL_F_EXCL", 0x020620),
+    ("IPV6_FL_F_REFLECT", 0x040200),
+    ("IPV6_FL_F_REMOTE", 0x040200),
+    ("IPV6_FL_S_ANY", 0x020620),
+    ("IPV6_FL_S_EXCL", 0x020620),
+    ("IPV6_FL_S_NONE", 0x020620),
+    ("IPV6_FL_S_PROCESS", 0x020620),
+    ("IPV6_FL_S_USER", 0x020620),
+    ("IPV6_FREEBIND", 0x041400),
+    ("IPV6_HDRINCL", 0x041400),
+    ("IPV6_HOPLIMIT", 0x020620),
+    ("IPV6_HOPOPTS", 0x020620),
+    ("IPV6_IPSEC_POLICY", 0x020620),
+    ("IPV6_JOIN_ANYCAST", 0x020620),
+    ("IPV6_LEAVE_ANYCAST", 0x020620),
+    ("IPV6_MINHOPCOUNT", 0x030200),
+    ("IPV6_MTU", 0x020620),
+    ("IPV6_MTU_DISCOVER", 0x020620),
+    ("IPV6_MULTICAST_ALL", 0x041400),
+    ("IPV6_MULTICAST_HOPS", 0x020620),
+    ("IPV6_MULTICAST_IF", 0x020620),
+    ("IPV6_MULTICAST_LOOP", 0x020620),
+    ("IPV6_NEXTHOP", 0x020620),
+    ("IPV6_ORIGDSTADDR", 0x030200),
+    ("IPV6_PATHMTU", 0x030200),
+    ("IPV6_PKTINFO", 0x020620),
+    ("IPV6_PMTUDISC_DO", 0x020620),
+    ("IPV6_PMTUDISC_DONT", 0x020620),
+    ("IPV6_PMTUDISC_INTERFACE", 0x040200),
+    ("IPV6_PMTUDISC_OMIT", 0x040200),
+    ("IPV6_PMTUDISC_PROBE", 0x020620),
+    ("IPV6_PMTUDISC_WANT", 0x020620),
+    ("IPV6_PREFER_SRC_CGA", 0x020620),
+    ("IPV6_PREFER_SRC_COA", 0x020620),
+    ("IPV6_PREFER_SRC_HOME", 0x020620),
+    ("IPV6_PREFER_SRC_NONCGA", 0x020620),
+    ("IPV6_PREFER_SRC_PUBLIC", 0x020620),
+    ("IPV6_PREFER_SRC_PUBTMP_DEFAULT", 0x020620),
+    ("IPV6_PREFER_SRC_TMP", 0x020620),
+    ("IPV6_PRIORITY_10", 0x020620),
+    ("IPV6_PRIORITY_11", 0x020620),
+    ("IPV6_PRIORITY_12", 0x020620),
+    ("IPV6_PRIORITY_13", 0x020620),
+    ("IPV6_PRIORITY_14", 0x020620),
+    ("IPV6_PRIORITY_15", 0x020620),
+    ("IPV6_PRIORITY_8", 0x020620),
+    ("IPV6_PRIORITY_9", 0x020620),
+    ("IPV6_PRIORITY_BULK", 0x020620),
+    ("IPV6_PRIORITY_CONTROL", 0x020620),
+    ("IPV6_PRIORITY_FILLER", 0x020620),
+    ("IPV6_PRIORITY_INTERACTIVE", 0x020620),
+    ("IPV6_PRIORITY_RESERVED1", 0x020620),
+    ("IPV6_PRIORITY_RESERVED2", 0x020620),
+    ("IPV6_PRIORITY_UNATTENDED", 0x020620),
+    ("IPV6_PRIORITY_UNCHARACTERIZED", 0x020620),
+    ("IPV6_RECVDSTOPTS", 0x020620),
+    ("IPV6_RECVERR", 0x020620),
+    ("IPV6_RECVERR_RFC4884", 0x050b00),
+    ("IPV6_RECVFRAGSIZE", 0x041400),
+    ("IPV6_RECVHOPLIMIT", 0x020620),
+    ("IPV6_RECVHOPOPTS", 0x020620),
+    ("IPV6_RECVORIGDSTADDR", 0x030200),
+    ("IPV6_RECVPATHMTU", 0x030200),
+    ("IPV6_RECVPKTINFO", 0x020620),
+    ("IPV6_RECVRTHDR", 0x020620),
+    ("IPV6_RECVTCLASS", 0x020620),
+    ("IPV6_ROUTER_ALERT", 0x020620),
+    ("IPV6_ROUTER_ALERT_ISOLATE", 0x050400),
+    ("IPV6_RTHDR", 0x020620),
+    ("IPV6_RTHDRDSTOPTS", 0x020620),
+    ("IPV6_TCLASS", 0x020620),
+    ("IPV6_TLV_CALIPSO", 0x041400),
+    ("IPV6_TLV_HAO", 0x020620),
+    ("IPV6_TLV_JUMBO", 0x020620),
+    ("IPV6_TLV_PAD0", 0x020620),
+    ("IPV6_TLV_PAD1", 0x030a00),
+    ("IPV6_TLV_PADN", 0x020620),
+    ("IPV6_TLV_ROUTERALERT", 0x020620),
+    ("IPV6_TRANSPARENT", 0x030200),
+    ("IPV6_UNICAST_HOPS", 0x020620),
+    ("IPV6_UNICAST_IF", 0x030a00),
+    ("IPV6_V6ONLY", 0x020620),
+    ("IPV6_XFRM_POLICY", 0x020620),
+    ("IP_ADD_MEMBERSHIP", 0x020620),
+    ("IP_ADD_SOURCE_MEMBERSHIP", 0x020620),
+    ("IP_BIND_ADDRESS_NO_PORT", 0x040200),
+    ("IP_BLOCK_SOURCE", 0x020620),
+    ("IP_CHECKSUM", 0x040200),
+    ("IP_DEFAULT_MULTICAST_LOOP", 0x020620),
+    ("IP_DEFAULT_MULTICAST_TTL", 0x020620),
+    ("IP_DROP_MEMBERSHIP", 0x020620),
+    ("IP_DROP_SOURCE_MEMBERSHIP", 0x020620),
+    ("IP_FREEBIND", 0x020620),
+    ("IP_HDRINCL", 0x020620),
+    ("IP_IPSEC_POLICY", 0x020620),
+    ("IP_MINTTL", 0x030200),
+    ("IP_MSFILTER", 0x020620),
+    ("IP_MTU", 0x020620),
+    ("IP_MTU_DISCOVER", 0x020620),
+    ("IP_MULTICAST_ALL", 0x020620),
+    ("IP_MULTICAST_IF", 0x020620),
+    ("IP_MULTICAST_LOOP", 0x020620),
+    ("IP_MULTICAST_TTL", 0x020620),
+    ("IP_NODEFRAG", 0x030200),
+    ("IP_OPTIONS", 0x020620),
+    ("IP_ORIGDSTADDR", 0x020620),
+    ("IP_PASSSEC", 0x020620),
+    ("IP_PKTINFO", 0x020620),
+    ("IP_PKTOPTIONS", 0x020620),
+    ("IP_PMTUDISC_DO", 0x020620),
+    ("IP_PMTUDISC_DONT", 0x020620),
+    ("IP_PMTUDISC_INTERFACE", 0x040200),
+    ("IP_PMTUDISC_OMIT", 0x040200),
+    ("IP_PMTUDISC_PROBE", 0x020620),
+    ("IP_PMTUDISC_WANT", 0x020620),
+    ("IP_RECVERR", 0x020620),
+    ("IP_RECVERR_RFC4884", 0x050b00),
+    ("IP_RECVFRAGSIZE", 0x041400),
+    ("IP_RECVOPTS", 0x020620),
+    ("IP_RECVORIGDSTADDR", 0x020620),
+    ("IP_RECVRETOPTS", 0x020620),
+    ("IP_RECVTOS", 0x020620),
+    ("IP_RECVTTL", 0x020620),
+    ("IP_RETOPTS", 0x020620),
+    ("IP_ROUTER_ALERT", 0x020620),
+    ("IP_TOS", 0x020620),
+    ("IP_TRANSPARENT", 0x020620),
+    ("IP_TTL", 0x020620),
+    ("IP_UNBLOCK_SOURCE", 0x020620),
+    ("IP_UNICAST_IF", 0x030a00),
+    ("IP_XFRM_POLICY", 0x020620),
+    ("ISIG", 0x020620),
+    ("ISOFS_SUPER_MAGIC", 0x020620),
+    ("ISTRIP", 0x020620),
+    ("ITIMER_PROF", 0x020620),
+    ("ITIMER_REAL", 0x020620),
+    ("ITIMER_VIRTUAL", 0x020620),
+    ("ITOSTOP", 0x040400),
+    ("IUCLC", 0x020620),
+    ("IUTF8", 0x020620),
+    ("IXANY", 0x020620),
+    ("IXOFF", 0x020620),
+    ("IXON", 0x020620),
+    ("JFFS2_SUPER_MAGIC", 0x020620),
+    ("KERN_FEATURE_MIXED_MODE_STACK", 0x050400),
+    ("LINK_MAX", 0x020620),
+    ("LINUX_VERSION_CODE", 0x020620),
+    ("LOCK_EX", 0x020620),
+    ("LOCK_MAND", 0x020620),
+    ("LOCK_NB", 0x020620),
+    ("LOCK_READ", 0x020620),
+    ("LOCK_RW", 0x020620),
+    ("LOCK_SH", 0x020620),
+    ("LOCK_UN", 0x020620),
+    ("LOCK_WRITE", 0x020620),
+    ("MADV_COLD", 0x050400),
+    ("MADV_DODUMP", 0x030a00),
+    ("MADV_DOFORK", 0x020620),
+    ("MADV_DONTDUMP", 0x030a00),
+    ("MADV_DONTFORK", 0x020620),
+    ("MADV_DONTNEED", 0x020620),
+    ("MADV_FREE", 0x041400),
+    ("MADV_HUGEPAGE", 0x030200),
+    ("MADV_HWPOISON", 0x020620),
+    ("MADV_KEEPONFORK", 0x041400),
+    ("MADV_MERGEABLE", 0x020620),
+    ("MADV_NOHUGEPAGE", 0x030200),
+    ("MADV_NORMAL", 0x020620),
+    ("MADV_PAGEOUT", 0x050400),
+    ("MADV_RANDOM", 0x020620),
+    ("MADV_REMOVE", 0x020620),
+    ("MADV_SEQUENTIAL", 0x020620),
+    ("MADV_SOFT_OFFLINE", 0x030200),
+    ("MADV_UNMERGEABLE", 0x020620),
+    ("MADV_WILLNEED", 0x020620),
+    ("MADV_WIPEONFORK", 0x041400),
+    ("MAP_32BIT", 0x020620),
+    ("MAP_ANONYMOUS", 0x020620),
+    ("MAP_AUTOGROW", 0x040400),
+    ("MAP_AUTORSRV", 0x040400),
+    ("MAP_DENYWRITE", 0x020620),
+    ("MAP_EXECUTABLE", 0x020620),
+    ("MAP_FILE", 0x020620),
+    ("MAP_FIXED", 0x020620),
+    ("MAP_FIXED_NOREPLACE", 0x041400),
+    ("MAP_GROWSDOWN", 0x020620),
+    ("MAP_HUGETLB", 0x020620),
+    ("MAP_HUGE_16GB", 0x041400),
+    ("MAP_HUGE_16KB", 0x050b00),
+    ("MAP_HUGE_16MB", 0x041400),
+    ("MAP_HUGE_1GB", 0x041400),
+    ("MAP_HUGE_1MB", 0x041400),
+    ("MAP_HUGE_256MB", 0x041400),
+    ("MAP_HUGE_2GB", 0x041400),
+    ("MAP_HUGE_2MB", 0x041400),
+    ("MAP_HUGE_32MB", 0x041400),
+    ("MAP_HUGE_512KB", 0x041400),
+    ("MAP_HUGE_512MB", 0x041400),
+    ("MAP_HUGE_64KB", 0x041400),
+    ("MAP_HUGE_8MB", 0x041400),
+    ("MAP_HUGE_MASK", 0x030a00),
+    ("MAP_HUGE_SHIFT", 0x030a00),
+    ("MAP_INHERIT", 0x050400),
+    ("MAP_LOCAL", 0x040400),
+    ("MAP_LOCKED", 0x020620),
+    ("MAP_NONBLOCK", 0x020620),
+    ("MAP_NORESERVE", 0x020620),
+    ("MAP_POPULATE", 0x020620),
+    ("MAP_PRIVATE", 0x020620),
+    ("MAP_RENAME", 0x020620),
+    ("MAP_SHARED", 0x020620),
+    ("MAP_SHARED_VALIDATE", 0x041400),
+    ("MAP_STACK", 0x020620),
+    ("MAP_SYNC", 0x041400),
+    ("MAP_TYPE", 0x020620),
+    ("MAP_UNINITIALIZED", 0x030200),
+    ("MAX_CANON", 0x020620),
+    ("MAX_CLOCKS", 0x020620),
+    ("MAX_INPUT", 0x020620),
+    ("MAY_ACCESS", 0x020620),
+    ("MAY_APPEND", 0x020620),
+    ("MAY_CHDIR", 0x030200),
+    ("MAY_EXEC", 0x020620),
+    ("MAY_NOT_BLOCK", 0x030200),
+    ("MAY_OPEN", 0x020620),
+    ("MAY_READ", 0x020620),
+    ("MAY_WRITE", 0x020620),
+    ("MCAST_BLOCK_SOURCE", 0x020620),
+    ("MCAST_EXCLUDE", 0x020620),
+    ("MCAST_INCLUDE", 0x020620),
+    ("MCAST_JOIN_GROUP", 0x020620),
+    ("MCAST_JOIN_SOURCE_GROUP", 0x020620),
+    ("MCAST_LEAVE_GROUP", 0x020620),
+    ("MCAST_LEAVE_SOURCE_GROUP", 0x020620),
+    ("MCAST_MSFILTER", 0x020620),
+    ("MCAST_UNBLOCK_SOURCE", 0x020620),
+    ("MCL_CURRENT", 0x020620),
+    ("MCL_FUTURE", 0x020620),
+    ("MCL_ONFAULT", 0x040400),
+    ("MEMBARRIER_CMD_SHARED", 0x041400),
+    ("MFD_ALLOW_SEALING", 0x040200),
+    ("MFD_CLOEXEC", 0x040200),
+    ("MFD_HUGETLB", 0x041400),
+    ("MFD_HUGE_16GB", 0x041400),
+    ("MFD_HUGE_16MB", 0x041400),
+    ("MFD_HUGE_1GB", 0x041400),
+    ("MFD_HUGE_1MB", 0x041400),
+    ("MFD_HUGE_256MB", 0x041400),
+    ("MFD_HUGE_2GB", 0x041400),
+    ("MFD_HUGE_2MB", 0x041400),
+    ("MFD_HUGE_32MB", 0x041400),
+    ("MFD_HUGE_512KB", 0x041400),
+    ("MFD_HUGE_512MB", 0x041400),
+    ("MFD_HUGE_64KB", 0x041400),
+    ("MFD_HUGE_8MB", 0x041400),
+    ("MFD_HUGE_MASK", 0x041400),
+    ("MFD_HUGE_SHIFT", 0x041400),
+    ("MINIX2_SUPER_MAGIC", 0x020620),
+    ("MINIX2_SUPER_MAGIC2", 0x020620),
+    ("MINIX3_SUPER_MAGIC", 0x020620),
+    ("MINIX_SUPER_MAGIC", 0x020620),
+    ("MINIX_SUPER_MAGIC2", 0x020620),
+    ("MINSIGSTKSZ", 0x020620),
+    ("MLOCK_ONFAULT", 0x040400),
+    ("MOUNT_ATTR_NOATIME", 0x050400),
+    ("MOUNT_ATTR_NODEV", 0x050400),
+    ("MOUNT_ATTR_NODIRATIME", 0x050400),
+    ("MOUNT_ATTR_NOEXEC", 0x050400),
+    ("MOUNT_ATTR_NOSUID", 0x050400),
+    ("MOUNT_ATTR_RDONLY", 0x050400),
+    ("MOUNT_ATTR_RELATIME", 0x050400),
+    ("MOUNT_ATTR_STRICTATIME", 0x050400),
+    ("MOUNT_ATTR__ATIME", 0x050400),
+    ("MOVE_MOUNT_F_AUTOMOUNTS", 0x050400),
+    ("MOVE_MOUNT_F_EMPTY_PATH", 0x050400),
+    ("MOVE_MOUNT_F_SYMLINKS", 0x050400),
+    ("MOVE_MOUNT_T_AUTOMOUNTS", 0x050400),
+    ("MOVE_MOUNT_T_EMPTY_PATH", 0x050400),
+    ("MOVE_MOUNT_T_SYMLINKS", 0x050400),
+    ("MOVE_MOUNT__MASK", 0x050400),
+    ("MREMAP_DONTUNMAP", 0x050b00),
+    ("MREMAP_FIXED", 0x020620),
+    ("MREMAP_MAYMOVE", 0x020620),
+    ("MSDOS_SUPER_MAGIC", 0x020620),
+    ("MSG_CMSG_CLOEXEC", 0x020620),
+    ("MSG_CONFIRM", 0x020620),
+    ("MSG_CTRUNC", 0x020620),
+    ("MSG_DONTROUTE", 0x020620),
+    ("MSG_DONTWAIT", 0x020620),
+    ("MSG_EOR", 0x020620),
+    ("MSG_ERRQUEUE", 0x020620),
+    ("MSG_FIN", 0x020620),
+    ("MSG_MORE", 0x020620),
+    ("MSG_NOSIGNAL", 0x020620),
+    ("MSG_OOB", 0x020620),
+    ("MSG_PEEK", 0x020620),
+    ("MSG_PROBE", 0x020620),
+    ("MSG_RST", 0x020620),
+    ("MSG_SYN", 0x020620),
+    ("MSG_TRUNC", 0x020620),
+    ("MSG_WAITALL", 0x020620),
+    ("MS_ACTIVE", 0x020620),
+    ("MS_ASYNC", 0x020620),
+    ("MS_BIND", 0x020620),
+    ("MS_BORN", 0x030200),
+    ("MS_DIRSYNC", 0x020620),
+    ("MS_INVALIDATE", 0x020620),
+    ("MS_I_VERSION", 0x020620),
+    ("MS_KERNMOUNT", 0x020620),
+    ("MS_LAZYTIME", 0x040200),
+    ("MS_MANDLOCK", 0x020620),
+    ("MS_MGC_MSK", 0x020620),
+    ("MS_MGC_VAL", 0x020620),
+    ("MS_MOVE", 0x020620),
+    ("MS_NOATIME", 0x020620),
+    ("MS_NODEV", 0x020620),
+    ("MS_NODIRATIME", 0x020620),
+    ("MS_NOEXEC", 0x020620),
+    ("MS_NOREMOTELOCK", 0x041400),
+    ("MS_NOSEC", 0x030200),
+    ("MS_NOSUID", 0x020620),
+    ("MS_NOSYMFOLLOW", 0x050b00),
+    ("MS_NOUSER", 0x020620),
+    ("MS_POSIXACL", 0x020620),
+    ("MS_PRIVATE", 0x020620),
+    ("MS_RDONLY", 0x020620),
+    ("MS_REC", 0x020620),
+    ("MS_RELATIME", 0x020620),
+    ("MS_REMOUNT", 0x020620),
+    ("MS_RMT_MASK", 0x020620),
+    ("MS_SHARED", 0x020620),
+    ("MS_SILENT", 0x020620),
+    ("MS_SLAVE", 0x020620),
+    ("MS_STRICTATIME", 0x020620),
+    ("MS_SUBMOUNT", 0x041400),
+    ("MS_SYNC", 0x020620),
+    ("MS_SYNCHRONOUS", 0x020620),
+    ("MS_UNBINDABLE", 0x020620),
+    ("MS_VERBOSE", 0x020620),
+    ("MTD_INODE_FS_MAGIC", 0x030a00),
+    ("NAME_MAX", 0x020620),
+    ("NCC", 0x020620),
+    ("NCCS", 0x020620),
+    ("NCP_SUPER_MAGIC", 0x020620),
+    ("NFF", 0x020620),
+    ("NFS_SUPER_MAGIC", 0x020620),
+    ("NGROUPS_MAX", 0x020620),
+    ("NILFS_SUPER_MAGIC", 0x030200),
+    ("NL0", 0x020620),
+    ("NL1", 0x020620),
+    ("NL2", 0x020620),
+    ("NL3", 0x020620),
+    ("NLDLY", 0x020620),
+    ("NOFLSH", 0x020620),
+    ("NR_FILE", 0x020620),
+    ("NR_OPEN", 0x020620),
+    ("NSFS_MAGIC", 0x040200),
+    ("NSIG", 0x020620),
+    ("NSIGBUS", 0x020620),
+    ("NSIGCHLD", 0x020620),
+    ("NSIGEMT", 0x041400),
+    ("NSIGFPE", 0x020620),
+    ("NSIGILL", 0x020620),
+    ("NSIGPOLL", 0x020620),
+    ("NSIGSEGV", 0x020620),
+    ("NSIGSYS", 0x030a00),
+    ("NSIGTRAP", 0x020620),
+    ("OCFS2_SUPER_MAGIC", 0x041400),
+    ("OCRNL", 0x020620),
+    ("OFDEL", 0x020620),
+    ("OFILL", 0x020620),
+    ("OLCUC", 0x020620),
+    ("ONLCR", 0x020620),
+    ("ONLRET", 0x020620),
+    ("ONOCR", 0x020620),
+    ("OPENPROM_SUPER_MAGIC", 0x020620),
+    ("OPEN_TREE_CLOEXEC", 0x050400),
+    ("OPEN_TREE_CLONE", 0x050400),
+    ("OPOST", 0x020620),
+    ("OVERCOMMIT_ALWAYS", 0x020620),
+    ("OVERCOMMIT_GUESS", 0x020620),
+    ("OVERCOMMIT_NEVER", 0x020620),
+    ("OVERLAYFS_SUPER_MAGIC", 0x041400),
+    ("O_ACCMODE", 0x020620),
+    ("O_APPEND", 0x020620),
+    ("O_CLOEXEC", 0x020620),
+    ("O_CREAT", 0x020620),
+    ("O_DIRECT", 0x020620),
+    ("O_DIRECTORY", 0x020620),
+    ("O_DSYNC", 0x030200),
+    ("O_EXCL", 0x020620),
+    ("O_LARGEFILE", 0x020620),
+    ("O_NDELAY", 0x020620),
+    ("O_NOATIME", 0x020620),
+    ("O_NOCTTY", 0x020620),
+    ("O_NOFOLLOW", 0x020620),
+    ("O_NONBLOCK", 0x020620),
+    ("O_PATH", 0x030200),
+    ("O_RDONLY", 0x020620),
+    ("O_RDWR", 0x020620),
+    ("O_SYNC", 0x020620),
+    ("O_TMPFILE", 0x040200),
+    ("O_TMPFILE_MASK", 0x040200),
+    ("O_TRUNC", 0x020620),
+    ("O_WRONLY", 0x020620),
+    ("PAGEOUT", 0x050400),
+    ("PARENB", 0x020620),
+    ("PARMRK", 0x020620),
+    ("PARODD", 0x020620),
+    ("PATH_MAX", 0x020620),
+    ("PENDIN", 0x020620),
+    ("PIPEFS_MAGIC", 0x030a00),
+    ("PIPE_BUF", 0x020620),
+    ("PKEY_ACCESS_MASK", 0x041400),
+    ("PKEY_DISABLE_ACCESS", 0x041400),
+    ("PKEY_DISABLE_EXECUTE", 0x050400),
+    ("PKEY_DISABLE_WRITE", 0x041400),
+    ("POLLERR", 0x020620),
+    ("POLLFREE", 0x030a00),
+    ("POLLHUP", 0x020620),
+    ("POLLIN", 0x020620),
+    ("POLLMSG", 0x020620),
+    ("POLLNVAL", 0x020620),
+    ("POLLOUT", 0x020620),
+    ("POLLPRI", 0x020620),
+    ("POLLRDBAND", 0x020620),
+    ("POLLRDHUP", 0x020620),
+    ("POLLRDNORM", 0x020620),
+    ("POLLREMOVE", 0x020620),
+    ("POLLWRBAND", 0x020620),
+    ("POLLWRNORM", 0x020620),
+    ("POLL_BUSY_LOOP", 0x040200),
+    ("POLL_ERR", 0x020620),
+    ("POLL_HUP", 0x020620),
+    ("POLL_IN", 0x020620),
+    ("POLL_MSG", 0x020620),
+    ("POLL_OUT", 0x020620),
+    ("POLL_PRI", 0x020620),
+    ("POSIX_FADV_DONTNEED", 0x020620),
+    ("POSIX_FADV_NOREUSE", 0x020620),
+    ("POSIX_FADV_NORMAL", 0x020620),
+    ("POSIX_FADV_RANDOM", 0x020620),
+    ("POSIX_FADV_SEQUENTIAL", 0x020620),
+    ("POSIX_FADV_WILLNEED", 0x020620),
+    ("PPC_CMM_MAGIC", 0x050b00),
+    ("PRIO_MAX", 0x020620),
+    ("PRIO_MIN", 0x020620),
+    ("PRIO_PGRP", 0x020620),
+    ("PRIO_PROCESS", 0x020620),
+    ("PRIO_USER", 0x020620),
+    ("PROC_SUPER_MAGIC", 0x020620),
+    ("PROT_ADI", 0x050400),
+    ("PROT_BTI", 0x050b00),
+    ("PROT_EXEC", 0x020620),
+    ("PROT_GROWSDOWN", 0x020620),
+    ("PROT_GROWSUP", 0x020620),
+    ("PROT_MTE", 0x050b00),
+    ("PROT_NONE", 0x020620),
+    ("PROT_READ", 0x020620),
+    ("PROT_SAO", 0x020620),
+    ("PROT_SEM", 0x020620),
+    ("PROT_WRITE", 0x020620),
+    ("PR_CAPBSET_DROP", 0x020620),
+    ("PR_CAPBSET_READ", 0x020620),
+    ("PR_CAP_AMBIENT", 0x040400),
+    ("PR_CAP_AMBIENT_CLEAR_ALL", 0x040400),
+    ("PR_CAP_AMBIENT_IS_SET", 0x040400),
+    ("PR_CAP_AMBIENT_LOWER", 0x040400),
+    ("PR_CAP_AMBIENT_RAISE", 0x040400),
+    ("PR_ENDIAN_BIG", 0x020620),
+    ("PR_ENDIAN_LITTLE", 0x020620),
+    ("PR_ENDIAN_PPC_LITTLE", 0x020620),
+    ("PR_FPEMU_NOPRINT", 0x020620),
+    ("PR_FPEMU_SIGFPE", 0x020620),
+    ("PR_FP_EXC_ASYNC", 0x020620),
+    ("PR_FP_EXC_DISABLED", 0x020620),
+    ("PR_FP_EXC_DIV", 0x020620),
+    ("PR_FP_EXC_INV", 0x020620),
+    ("PR_FP_EXC_NONRECOV", 0x020620),
+    ("PR_FP_EXC_OVF", 0x020620),
+    ("PR_FP_EXC_PRECISE", 0x020620),
+    ("PR_FP_EXC_RES", 0x020620),
+    ("PR_FP_EXC_SW_ENABLE", 0x020620),
+    ("PR_FP_EXC_UND", 0x020620),
+    ("PR_FP_MODE_FR", 0x040200),
+    ("PR_FP_MODE_FRE", 0x040200),
+    ("PR_GET_CHILD_SUBREAPER", 0x030a00),
+    ("PR_GET_DUMPABLE", 0x020620),
+    ("PR_GET_ENDIAN", 0x020620),
+    ("PR_GET_FPEMU", 0x020620),
+    ("PR_GET_FPEXC", 0x020620),
+    ("PR_GET_FP_MODE", 0x040200),
+    ("PR_GET_IO_FLUSHER", 0x050b00),
+    ("PR_GET_KEEPCAPS", 0x020620),
+    ("PR_GET_NAME", 0x020620),
+    ("PR_GET_NO_NEW_PRIVS", 0x030a00),
+    ("PR_GET_PDEATHSIG", 0x020620),
+    ("PR_GET_SECCOMP", 0x020620),
+    ("PR_GET_SECUREBITS", 0x020620),
+    ("PR_GET_SPECULATION_CTRL", 0x041400),
+    ("PR_GET_TAGGED_ADDR_CTRL", 0x050400),
+    ("PR_GET_THP_DISABLE", 0x040200),
+    ("PR_GET_TID_ADDRESS", 0x030a00),
+    ("PR_GET_TIMERSLACK", 0x020620),
+    ("PR_GET_TIMING", 0x020620),
+    ("PR_GET_TSC", 0x020620),
+    ("PR_GET_UNALIGN", 0x020620),
+    ("PR_MCE_KILL", 0x020620),
+    ("PR_MCE_KILL_CLEAR", 0x020620),
+    ("PR_MCE_KILL_DEFAULT", 0x020620),
+    ("PR_MCE_KILL_EARLY", 0x020620),
+    ("PR_MCE_KILL_GET", 0x020620),
+    ("PR_MCE_KILL_LATE", 0x020620),
+    ("PR_MCE_KILL_SET", 0x020620),
+    ("PR_MPX_DISABLE_MANAGEMENT", 0x040200),
+    ("PR_MPX_ENABLE_MANAGEMENT", 0x040200),
+    ("PR_MTE_TAG_MASK", 0x050b00),
+    ("PR_MTE_TAG_SHIFT", 0x050b00),
+    ("PR_MTE_TCF_ASYNC", 0x050b00),
+    ("PR_MTE_TCF_MASK", 0x050b00),
+    ("PR_MTE_TCF_NONE", 0x050b00),
+    ("PR_MTE_TCF_SHIFT", 0x050b00),
+    ("PR_MTE_TCF_SYNC", 0x050b00),
+    ("PR_PAC_APDAKEY", 0x050400),
+    ("PR_PAC_APDBKEY", 0x050400),
+    ("PR_PAC_APGAKEY", 0x050400),
+    ("PR_PAC_APIAKEY", 0x050400),
+    ("PR_PAC_APIBKEY", 0x050400),
+    ("PR_PAC_RESET_KEYS", 0x050400),
+    ("PR_SET_CHILD_SUBREAPER", 0x030a00),
+    ("PR_SET_DUMPABLE", 0x020620),
+    ("PR_SET_ENDIAN", 0x020620),
+    ("PR_SET_FPEMU", 0x020620),
+    ("PR_SET_FPEXC", 0x020620),
+    ("PR_SET_FP_MODE", 0x040200),
+    ("PR_SET_IO_FLUSHER", 0x050b00),
+    ("PR_SET_KEEPCAPS", 0x020620),
+    ("PR_SET_MM", 0x030a00),
+    ("PR_SET_MM_ARG_END", 0x030a00),
+    ("PR_SET_MM_ARG_START", 0x030a00),
+    ("PR_SET_MM_AUXV", 0x030a00),
+    ("PR_SET_MM_BRK", 0x030a00),
+    ("PR_SET_MM_END_CODE", 0x030a00),
+    ("PR_SET_MM_END_DATA", 0x030a00),
+    ("PR_SET_MM_ENV_END", 0x030a00),
+    ("PR_SET_MM_ENV_START", 0x030a00),
+    ("PR_SET_MM_EXE_FILE", 0x030a00),
+    ("PR_SET_MM_MAP", 0x040200),
+    ("PR_SET_MM_MAP_SIZE", 0x040200),
+    ("PR_SET_MM_START_BRK", 0x030a00),
+    ("PR_SET_MM_START_CODE", 0x030a00),
+    ("PR_SET_MM_START_DATA", 0x030a00),
+    ("PR_SET_MM_START_STACK", 0x030a00),
+    ("PR_SET_NAME", 0x020620),
+    ("PR_SET_NO_NEW_PRIVS", 0x030a00),
+    ("PR_SET_PDEATHSIG", 0x020620),
+    ("PR_SET_PTRACER", 0x030a00),
+    ("PR_SET_SECCOMP", 0x020620),
+    ("PR_SET_SECUREBITS", 0x020620),
+    ("PR_SET_SPECULATION_CTRL", 0x041400),
+    ("PR_SET_SYSCALL_USER_DISPATCH", 0x050b00),
+    ("PR_SET_TAGGED_ADDR_CTRL", 0x050400),
+    ("PR_SET_THP_DISABLE", 0x040200),
+    ("PR_SET_TIMERSLACK", 0x020620),
+    ("PR_SET_TIMING", 0x020620),
+    ("PR_SET_TSC", 0x020620),
+    ("PR_SET_UNALIGN", 0x020620),
+    ("PR_SPEC_DISABLE", 0x041400),
+    ("PR_SPEC_DISABLE_NOEXEC", 0x050400),
+    ("PR_SPEC_ENABLE", 0x041400),
+    ("PR_SPEC_FORCE_DISABLE", 0x041400),
+    ("PR_SPEC_INDIRECT_BRANCH", 0x041400),
+    ("PR_SPEC_NOT_AFFECTED", 0x041400),
+    ("PR_SPEC_PRCTL", 0x041400),
+    ("PR_SPEC_STORE_BYPASS", 0x041400),
+    ("PR_SVE_GET_VL", 0x041400),
+    ("PR_SVE_SET_VL", 0x041400),
+    ("PR_SVE_SET_VL_ONEXEC", 0x041400),
+    ("PR_SVE_VL_INHERIT", 0x041400),
+    ("PR_SVE_VL_LEN_MASK", 0x041400),
+    ("PR_SYS_DISPATCH_OFF", 0x050b00),
+    ("PR_SYS_DISPATCH_ON", 0x050b00),
+    ("PR_TAGGED_ADDR_ENABLE", 0x050400),
+    ("PR_TASK_PERF_EVENTS_DISABLE", 0x020620),
+    ("PR_TASK_PERF_EVENTS_ENABLE", 0x020620),
+    ("PR_TIMING_STATISTICAL", 0x020620),
+    ("PR_TIMING_TIMESTAMP", 0x020620),
+    ("PR_TSC_ENABLE", 0x020620),
+    ("PR_TSC_SIGSEGV", 0x020620),
+    ("PR_UNALIGN_NOPRINT", 0x020620),
+    ("PR_UNALIGN_SIGBUS", 0x020620),
+    ("PSTOREFS_MAGIC", 0x030200),
+    ("P_ALL", 0x020620),
+    ("P_PGID", 0x020620),
+    ("P_PID", 0x020620),
+    ("P_PIDFD", 0x050400),
+    ("QNX4_SUPER_MAGIC", 0x020620),
+    ("QNX6_SUPER_MAGIC", 0x030a00),
+    ("RAMFS_MAGIC", 0x020620),
+    ("RDTGROUP_SUPER_MAGIC", 0x041400),
+    ("READ", 0x020620),
+    ("READA", 0x020620),
+    ("REISER2FS_JR_SUPER_MAGIC_STRING", 0x020620),
+    ("REISER2FS_SUPER_MAGIC_STRING", 0x020620),
+    ("REISERFS_SUPER_MAGIC", 0x020620),
+    ("REISERFS_SUPER_MAGIC_STRING", 0x020620),
+    ("RENAME_EXCHANGE", 0x040200),
+    ("RENAME_NOREPLACE", 0x040200),
+    ("RENAME_WHITEOUT", 0x040200),
+    ("RESOLVE_BENEATH", 0x050b00),
+    ("RESOLVE_IN_ROOT", 0x050b00),
+    ("RESOLVE_NO_MAGICLINKS", 0x050b00),
+    ("RESOLVE_NO_SYMLINKS", 0x050b00),
+    ("RESOLVE_NO_XDEV", 0x050b00),
+    ("RLIM64_INFINITY", 0x030200),
+    ("RLIMIT_AS", 0x020620),
+    ("RLIMIT_CORE", 0x020620),
+    ("RLIMIT_CPU", 0x020620),
+    ("RLIMIT_DATA", 0x020620),
+    ("RLIMIT_FSIZE", 0x020620),
+    ("RLIMIT_LOCKS", 0x020620),
+    ("RLIMIT_MEMLOCK", 0x020620),
+    ("RLIMIT_MSGQUEUE", 0x020620),
+    ("RLIMIT_NICE", 0x020620),
+    ("RLIMIT_NOFILE", 0x020620),
+    ("RLIMIT_NPROC", 0x020620),
+    ("RLIMIT_RSS", 0x020620),
+    ("RLIMIT_RTPRIO", 0x020620),
+    ("RLIMIT_RTTIME", 0x020620),
+    ("RLIMIT_SIGPENDING", 0x020620),
+    ("RLIMIT_STACK", 0x020620),
+    ("RLIM_INFINITY", 0x020620),
+    ("RLIM_NLIMITS", 0x020620),
+    ("ROBUST_LIST_LIMIT", 0x020620),
+    ("RTSIG_MAX", 0x020620),
+    ("RTSXOFF", 0x020620),
+    ("RUSAGE_BOTH", 0x020620),
+    ("RUSAGE_CHILDREN", 0x020620),
+    ("RUSAGE_SELF", 0x020620),
+    ("RUSAGE_THREAD", 0x020620),
+    ("RWA_MASK", 0x020620),
+    ("RWF_APPEND", 0x020620),
+    ("RWF_DSYNC", 0x020620),
+    ("RWF_HIPRI", 0x020620),
+    ("RWF_NOWAIT", 0x020620),
+    ("RWF_SYNC", 0x020620),
+    ("RWF_WRITE_LIFE_NOT_SET", 0x041400),
+    ("RWH_WRITE_LIFE_EXTREME", 0x041400),
+    ("RWH_WRITE_LIFE_LONG", 0x041400),
+    ("RWH_WRITE_LIFE_MEDIUM", 0x041400),
+    ("RWH_WRITE_LIFE_NONE", 0x041400),
+    ("RWH_WRITE_LIFE_NOT_SET", 0x050b00),
+    ("RWH_WRITE_LIFE_SHORT", 0x041400),
+    ("RW_MASK", 0x020620),
+    ("R_OK", 0x020620),
+    ("SA_EXPOSE_TAGBITS", 0x050b00),
+    ("SA_NOCLDSTOP", 0x020620),
+    ("SA_NOCLDWAIT", 0x020620),
+    ("SA_NODEFER", 0x020620),
+    ("SA_NOMASK", 0x020620),
+    ("SA_ONESHOT", 0x020620),
+    ("SA_ONSTACK", 0x020620),
+    ("SA_RESETHAND", 0x020620),
+    ("SA_RESTART", 0x020620),
+    ("SA_RESTORER", 0x020620),
+    ("SA_SIGINFO", 0x020620),
+    ("SA_STACK", 0x050400),
+    ("SA_THIRTYTWO", 0x030200),
+    ("SA_UNSUPPORTED", 0x050b00),
+    ("SCHED_BATCH", 0x020620),
+    ("SCHED_DEADLINE", 0x040200),
+    ("SCHED_FIFO", 0x020620),
+    ("SCHED_FLAG_ALL", 0x041400),
+    ("SCHED_FLAG_DL_OVERRUN", 0x041400),
+    ("SCHED_FLAG_KEEP_ALL", 0x050400),
+    ("SCHED_FLAG_KEEP_PARAMS", 0x050400),
+    ("SCHED_FLAG_KEEP_POLICY", 0x050400),
+    ("SCHED_FLAG_RECLAIM", 0x041400),
+    ("SCHED_FLAG_RESET_ON_FORK", 0x040200),
+    ("SCHED_FLAG_UTIL_CLAMP", 0x050400),
+    ("SCHED_FLAG_UTIL_CLAMP_MAX", 0x050400),
+    ("SCHED_FLAG_UTIL_CLAMP_MIN", 0x050400),
+    ("SCHED_IDLE", 0x020620),
+    ("SCHED_NORMAL", 0x020620),
+    ("SCHED_RESET_ON_FORK", 0x020620),
+    ("SCHED_RR", 0x020620),
+    ("SCM_TIMESTAMP", 0x020620),
+    ("SCM_TIMESTAMPING", 0x020620),
+    ("SCM_TIMESTAMPING_OPT_STATS", 0x041400),
+    ("SCM_TIMESTAMPING_PKTINFO", 0x041400),
+    ("SCM_TIMESTAMPNS", 0x020620),
+    ("SCM_TXTIME", 0x041400),
+    ("SCM_WIFI_STATUS", 0x030a00),
+    ("SECURITYFS_MAGIC", 0x020620),
+    ("SEEK_CUR", 0x020620),
+    ("SEEK_DATA", 0x030200),
+    ("SEEK_END", 0x020620),
+    ("SEEK_HOLE", 0x030200),
+    ("SEEK_MAX", 0x020620),
+    ("SEEK_SET", 0x020620),
+    ("SEGV_ACCADI", 0x041400),
+    ("SEGV_ACCERR", 0x020620),
+    ("SEGV_ADIDERR", 0x041400),
+    ("SEGV_ADIPERR", 0x041400),
+    ("SEGV_BNDERR", 0x040200),
+    ("SEGV_MAPERR", 0x020620),
+    ("SEGV_MTEAERR", 0x050b00),
+    ("SEGV_MTESERR", 0x050b00),
+    ("SEGV_PKUERR", 0x041400),
+    ("SELINUX_MAGIC", 0x020620),
+    ("SEL_EX", 0x020620),
+    ("SEL_IN", 0x020620),
+    ("SEL_OUT", 0x020620),
+    ("SHUT_RD", 0x020620),
+    ("SHUT_RDWR", 0x020620),
+    ("SHUT_WR", 0x020620),
+    ("SIGABRT", 0x020620),
+    ("SIGALRM", 0x020620),
+    ("SIGBUS", 0x020620),
+    ("SIGCHLD", 0x020620),
+    ("SIGCLD", 0x040400),
+    ("SIGCONT", 0x020620),
+    ("SIGEMT", 0x040400),
+    ("SIGEV_MAX_SIZE", 0x020620),
+    ("SIGEV_NONE", 0x020620),
+    ("SIGEV_SIGNAL", 0x020620),
+    ("SIGEV_THREAD", 0x020620),
+    ("SIGEV_THREAD_ID", 0x020620),
+    ("SIGFPE", 0x020620),
+    ("SIGHUP", 0x020620),
+    ("SIGILL", 0x020620),
+    ("SIGINT", 0x020620),
+    ("SIGIO", 0x020620),
+    ("SIGIOT", 0x020620),
+    ("SIGKILL", 0x020620),
+    ("SIGLOST", 0x050400),
+    ("SIGPIPE", 0x020620),
+    ("SIGPOLL", 0x020620),
+    ("SIGPROF", 0x020620),
+    ("SIGPWR", 0x020620),
+    ("SIGQUIT", 0x020620),
+    ("SIGRTMAX", 0x020620),
+    ("SIGRTMIN", 0x020620),
+    ("SIGSEGV", 0x020620),
+    ("SIGSTKFLT", 0x020620),
+    ("SIGSTKSZ", 0x020620),
+    ("SIGSTOP", 0x020620),
+    ("SIGSWI", 0x030200),
+    ("SIGSYS", 0x020620),
+    ("SIGTERM", 0x020620),
+    ("SIGTRAP", 0x020620),
+    ("SIGTSTP", 0x020620),
+    ("SIGTTIN", 0x020620),
+    ("SIGTTOU", 0x020620),
+    ("SIGUNUSED", 0x020620),
+    ("SIGURG", 0x020620),
+    ("SIGUSR1", 0x020620),
+    ("SIGUSR2", 0x020620),
+    ("SIGVTALRM", 0x020620),
+    ("SIGWINCH", 0x020620),
+    ("SIGXCPU", 0x020620),
+    ("SIGXFSZ", 0x020620),
+    ("SIG_BLOCK", 0x020620),
+    ("SIG_DBG_BRANCH_TRACING", 0x020620),
+    ("SIG_DBG_SINGLE_STEPPING", 0x020620),
+    ("SIG_SETMASK", 0x020620),
+    ("SIG_UNBLOCK", 0x020620),
+    ("SIOCATMARK", 0x020620),
+    ("SIOCGPGRP", 0x020620),
+    ("SIOCGSTAMP", 0x020620),
+    ("SIOCGSTAMPNS", 0x020620),
+    ("SIOCGSTAMPNS_OLD", 0x050400),
+    ("SIOCGSTAMP_OLD", 0x050400),
+    ("SIOCSPGRP", 0x020620),
+    ("SI_ASYNCIO", 0x020620),
+    ("SI_ASYNCNL", 0x041400),
+    ("SI_DETHREAD", 0x020620),
+    ("SI_KERNEL", 0x020620),
+    ("SI_MAX_SIZE", 0x020620),
+    ("SI_MESGQ", 0x041400),
+    ("SI_NOINFO", 0x050400),
+    ("SI_QUEUE", 0x020620),
+    ("SI_SIGIO", 0x020620),
+    ("SI_TIMER", 0x041400),
+    ("SI_TKILL", 0x020620),
+    ("SI_USER", 0x020620),
+    ("SKF_AD_ALU_XOR_X", 0x030a00),
+    ("SKF_AD_CPU", 0x030200),
+    ("SKF_AD_HATYPE", 0x030200),
+    ("SKF_AD_IFINDEX", 0x020620),
+    ("SKF_AD_MARK", 0x020620),
+    ("SKF_AD_MAX", 0x020620),
+    ("SKF_AD_NLATTR", 0x020620),
+    ("SKF_AD_NLATTR_NEST", 0x020620),
+    ("SKF_AD_OFF", 0x020620),
+    ("SKF_AD_PAY_OFFSET", 0x040200),
+    ("SKF_AD_PKTTYPE", 0x020620),
+    ("SKF_AD_PROTOCOL", 0x020620),
+    ("SKF_AD_QUEUE", 0x030200),
+    ("SKF_AD_RANDOM", 0x040200),
+    ("SKF_AD_RXHASH", 0x030200),
+    ("SKF_AD_VLAN_TAG", 0x030a00),
+    ("SKF_AD_VLAN_TAG_PRESENT", 0x030a00),
+    ("SKF_AD_VLAN_TPID", 0x040200),
+    ("SKF_LL_OFF", 0x020620),
+    ("SKF_NET_OFF", 0x020620),
+    ("SMACK_MAGIC", 0x030a00),
+    ("SMB_SUPER_MAGIC", 0x020620),
+    ("SOCKFS_MAGIC", 0x020620),
+    ("SOCK_DGRAM", 0x020620),
+    ("SOCK_RAW", 0x020620),
+    ("SOCK_RDM", 0x020620),
+    ("SOCK_SEQPACKET", 0x020620),
+    ("SOCK_STREAM", 0x020620),
+    ("SOL_SOCKET", 0x020620),
+    ("SO_ACCEPTCONN", 0x020620),
+    ("SO_ATTACH_BPF", 0x040200),
+    ("SO_ATTACH_FILTER", 0x020620),
+    ("SO_ATTACH_REUSEPORT_CBPF", 0x041400),
+    ("SO_ATTACH_REUSEPORT_EBPF", 0x041400),
+    ("SO_BINDTODEVICE", 0x020620),
+    ("SO_BINDTOIFINDEX", 0x050400),
+    ("SO_BPF_EXTENSIONS", 0x040200),
+    ("SO_BROADCAST", 0x020620),
+    ("SO_BSDCOMPAT", 0x020620),
+    ("SO_BUSY_POLL", 0x040200),
+    ("SO_BUSY_POLL_BUDGET", 0x050b00),
+    ("SO_CNX_ADVICE", 0x041400),
+    ("SO_COOKIE", 0x041400),
+    ("SO_DEBUG", 0x020620),
+    ("SO_DETACH_BPF", 0x040200),
+    ("SO_DETACH_FILTER", 0x020620),
+    ("SO_DETACH_REUSEPORT_BPF", 0x050400),
+    ("SO_DOMAIN", 0x020620),
+    ("SO_DONTROUTE", 0x020620),
+    ("SO_ERROR", 0x020620),
+    ("SO_GET_FILTER", 0x030a00),
+    ("SO_INCOMING_CPU", 0x040200),
+    ("SO_INCOMING_NAPI_ID", 0x041400),
+    ("SO_KEEPALIVE", 0x020620),
+    ("SO_LINGER", 0x020620),
+    ("SO_LOCK_FILTER", 0x030a00),
+    ("SO_MARK", 0x020620),
+    ("SO_MAX_PACING_RATE", 0x040200),
+    ("SO_MEMINFO", 0x041400),
+    ("SO_NOFCS", 0x030a00),
+    ("SO_NO_CHECK", 0x020620),
+    ("SO_OOBINLINE", 0x020620),
+    ("SO_PASSCRED", 0x020620),
+    ("SO_PASSSEC", 0x020620),
+    ("SO_PEEK_OFF", 0x030a00),
+    ("SO_PEERCRED", 0x020620),
+    ("SO_PEERGROUPS", 0x041400),
+    ("SO_PEERNAME", 0x020620),
+    ("SO_PEERSEC", 0x020620),
+    ("SO_PREFER_BUSY_POLL", 0x050b00),
+    ("SO_PRIORITY", 0x020620),
+    ("SO_PROTOCOL", 0x020620),
+    ("SO_RCVBUF", 0x020620),
+    ("SO_RCVBUFFORCE", 0x020620),
+    ("SO_RCVLOWAT", 0x020620),
+    ("SO_RCVTIMEO", 0x020620),
+    ("SO_RCVTIMEO_NEW", 0x050400),
+    ("SO_RCVTIMEO_OLD", 0x050400),
+    ("SO_REUSEADDR", 0x020620),
+    ("SO_REUSEPORT", 0x030a00),
+    ("SO_RXQ_OVFL", 0x030200),
+    ("SO_SECURITY_AUTHENTICATION", 0x020620),
+    ("SO_SECURITY_ENCRYPTION_NETWORK", 0x020620),
+    ("SO_SECURITY_ENCRYPTION_TRANSPORT", 0x020620),
+    ("SO_SELECT_ERR_QUEUE", 0x030a00),
+    ("SO_SNDBUF", 0x020620),
+    ("SO_SNDBUFFORCE", 0x020620),
+    ("SO_SNDLOWAT", 0x020620),
+    ("SO_SNDTIMEO", 0x020620),
+    ("SO_SNDTIMEO_NEW", 0x050400),
+    ("SO_SNDTIMEO_OLD", 0x050400),
+    ("SO_STYLE", 0x040400),
+    ("SO_TIMESTAMP", 0x020620),
+    ("SO_TIMESTAMPING", 0x020620),
+    ("SO_TIMESTAMPING_NEW", 0x050400),
+    ("SO_TIMESTAMPING_OLD", 0x050400),
+    ("SO_TIMESTAMPNS", 0x020620),
+    ("SO_TIMESTAMPNS_NEW", 0x050400),
+    ("SO_TIMESTAMPNS_OLD", 0x050400),
+    ("SO_TIMESTAMP_NEW", 0x050400),
+    ("SO_TIMESTAMP_OLD", 0x050400),
+    ("SO_TXTIME", 0x041400),
+    ("SO_TYPE", 0x020620),
+    ("SO_WIFI_STATUS", 0x030a00),
+    ("SO_ZEROCOPY", 0x041400),
+    ("SQUASHFS_MAGIC", 0x020620),
+    ("SS_AUTODISARM", 0x041400),
+    ("SS_DISABLE", 0x020620),
+    ("SS_FLAG_BITS", 0x041400),
+    ("SS_ONSTACK", 0x020620),
+    ("STACK_END_MAGIC", 0x020620),
+    ("STAT64_HAS_BROKEN_ST_INO", 0x020620),
+    ("STATX_ALL", 0x041400),
+    ("STATX_ATIME", 0x041400),
+    ("STATX_ATTR_APPEND", 0x041400),
+    ("STATX_ATTR_AUTOMOUNT", 0x041400),
+    ("STATX_ATTR_COMPRESSED", 0x041400),
+    ("STATX_ATTR_DAX", 0x050b00),
+    ("STATX_ATTR_ENCRYPTED", 0x041400),
+    ("STATX_ATTR_IMMUTABLE", 0x041400),
+    ("STATX_ATTR_MOUNT_ROOT", 0x050b00),
+    ("STATX_ATTR_NODUMP", 0x041400),
+    ("STATX_ATTR_VERITY", 0x050b00),
+    ("STATX_BASIC_STATS", 0x041400),
+    ("STATX_BLOCKS", 0x041400),
+    ("STATX_BTIME", 0x041400),
+    ("STATX_CTIME", 0x041400),
+    ("STATX_GID", 0x041400),
+    ("STATX_INO", 0x041400),
+    ("STATX_KNOWN_MASK", 0x041400),
+    ("STATX_MNT_ID", 0x050b00),
+    ("STATX_MODE", 0x041400),
+    ("STATX_MTIME", 0x041400),
+    ("STATX_NLINK", 0x041400),
+    ("STATX_SIZE", 0x041400),
+    ("STATX_TYPE", 0x041400),
+    ("STATX_UID", 0x041400),
+    ("STATX__RESERVED", 0x041400),
+    ("STAT_HAVE_NSEC", 0x020620),
+    ("STDERR_FILENO", 0x020620),
+    ("STDIN_FILENO", 0x020620),
+    ("STDOUT_FILENO", 0x020620),
+    ("SUBSIG_ALIGNMENT", 0x050400),
+    ("SUBSIG_BUSTIMEOUT", 0x050400),
+    ("SUBSIG_FPDISABLED", 0x050400),
+    ("SUBSIG_FPDIVZERO", 0x050400),
+    ("SUBSIG_FPERROR", 0x050400),
+    ("SUBSIG_FPINEXACT", 0x050400),
+    ("SUBSIG_FPINTOVFL", 0x050400),
+    ("SUBSIG_FPOPERROR", 0x050400),
+    ("SUBSIG_FPOVFLOW", 0x050400),
+    ("SUBSIG_FPSTSIG", 0x050400),
+    ("SUBSIG_FPUNFLOW", 0x050400),
+    ("SUBSIG_IDIVZERO", 0x050400),
+    ("SUBSIG_ILLINST", 0x050400),
+    ("SUBSIG_MISCERROR", 0x050400),
+    ("SUBSIG_NOMAPPING", 0x050400),
+    ("SUBSIG_PRIVINST", 0x050400),
+    ("SUBSIG_PROTECTION", 0x050400),
+    ("SUBSIG_SEGERROR", 0x050400),
+    ("SUBSIG_STACK", 0x050400),
+    ("SUBSIG_TAG", 0x050400),
+    ("SWRITE", 0x020620),
+    ("SYNC_FILE_RANGE_WAIT_AFTER", 0x020620),
+    ("SYNC_FILE_RANGE_WAIT_BEFORE", 0x020620),
+    ("SYNC_FILE_RANGE_WRITE", 0x020620),
+    ("SYNC_FILE_RANGE_WRITE_AND_WAIT", 0x050400),
+    ("SYSCALL_DISPATCH_FILTER_ALLOW", 0x050b00),
+    ("SYSCALL_DISPATCH_FILTER_BLOCK", 0x050b00),
+    ("SYSCALL_NAMES_BASE", 0x020620),
+    ("SYSFS_MAGIC", 0x020620),
+    ("SYS_ACCEPT", 0x020620),
+    ("SYS_ACCEPT4", 0x020620),
+    ("SYS_BIND", 0x020620),
+    ("SYS_CONNECT", 0x020620),
+    ("SYS_GETPEERNAME", 0x020620),
+    ("SYS_GETSOCKNAME", 0x020620),
+    ("SYS_GETSOCKOPT", 0x020620),
+    ("SYS_LISTEN", 0x020620),
+    ("SYS_RECV", 0x020620),
+    ("SYS_RECVFROM", 0x020620),
+    ("SYS_RECVMMSG", 0x030200),
+    ("SYS_RECVMSG", 0x020620),
+    ("SYS_SECCOMP", 0x030a00),
+    ("SYS_SEND", 0x020620),
+    ("SYS_SENDMMSG", 0x030200),
+    ("SYS_SENDMSG", 0x020620),
+    ("SYS_SENDTO", 0x020620),
+    ("SYS_SETSOCKOPT", 0x020620),
+    ("SYS_SHUTDOWN", 0x020620),
+    ("SYS_SOCKET", 0x020620),
+    ("SYS_SOCKETPAIR", 0x020620),
+    ("SYS_USER_DISPATCH", 0x050b00),
+    ("S_APPEND", 0x020620),
+    ("S_AUTOMOUNT", 0x030200),
+    ("S_DEAD", 0x020620),
+    ("S_DIRSYNC", 0x020620),
+    ("S_IFBLK", 0x020620),
+    ("S_IFCHR", 0x020620),
+    ("S_IFDIR", 0x020620),
+    ("S_IFIFO", 0x020620),
+    ("S_IFLNK", 0x020620),
+    ("S_IFMT", 0x020620),
+    ("S_IFREG", 0x020620),
+    ("S_IFSOCK", 0x020620),
+    ("S_IMA", 0x030200),
+    ("S_IMMUTABLE", 0x020620),
+    ("S_IRGRP", 0x020620),
+    ("S_IROTH", 0x020620),
+    ("S_IRUSR", 0x020620),
+    ("S_IRWXG", 0x020620),
+    ("S_IRWXO", 0x020620),
+    ("S_IRWXU", 0x020620),
+    ("S_ISGID", 0x020620),
+    ("S_ISUID", 0x020620),
+    ("S_ISVTX", 0x020620),
+    ("S_IWGRP", 0x020620),
+    ("S_IWOTH", 0x020620),
+    ("S_IWUSR", 0x020620),
+    ("S_IXGRP", 0x020620),
+    ("S_IXOTH", 0x020620),
+    ("S_IXUSR", 0x020620),
+    ("S_NOATIME", 0x020620),
+    ("S_NOCMTIME", 0x020620),
+    ("S_NOQUOTA", 0x020620),
+    ("S_NOSEC", 0x030200),
+    ("S_PRIVATE", 0x020620),
+    ("S_SWAPFILE", 0x020620),
+    ("S_SYNC", 0x020620),
+    ("TAB0", 0x020620),
+    ("TAB1", 0x020620),
+    ("TAB2", 0x020620),
+    ("TAB3", 0x020620),
+    ("TABDLY", 0x020620),
+    ("TCFLSH", 0x020620),
+    ("TCGETA", 0x020620),
+    ("TCGETS", 0x020620),
+    ("TCGETX", 0x020620),
+    ("TCIFLUSH", 0x020620),
+    ("TCIOFF", 0x020620),
+    ("TCIOFLUSH", 0x020620),
+    ("TCION", 0x020620),
+    ("TCOFLUSH", 0x020620),
+    ("TCOOFF", 0x020620),
+    ("TCOON", 0x020620),
+    ("TCPI_OPT_ECN", 0x020620),
+    ("TCPI_OPT_ECN_SEEN", 0x030200),
+    ("TCPI_OPT_SACK", 0x020620),
+    ("TCPI_OPT_SYN_DATA", 0x030a00),
+    ("TCPI_OPT_TIMESTAMPS", 0x020620),
+    ("TCPI_OPT_WSCALE", 0x020620),
+    ("TCP_CC_INFO", 0x040200),
+    ("TCP_CM_INQ", 0x041400),
+    ("TCP_CONGESTION", 0x020620),
+    ("TCP_COOKIE_IN_ALWAYS", 0x030200),
+    ("TCP_COOKIE_MAX", 0x030200),
+    ("TCP_COOKIE_MIN", 0x030200),
+    ("TCP_COOKIE_OUT_NEVER", 0x030200),
+    ("TCP_COOKIE_PAIR_SIZE", 0x030200),
+    ("TCP_COOKIE_TRANSACTIONS", 0x030200),
+    ("TCP_CORK", 0x020620),
+    ("TCP_DATA_OFFSET", 0x020620),
+    ("TCP_DEFER_ACCEPT", 0x020620),
+    ("TCP_FASTOPEN", 0x030a00),
+    ("TCP_FASTOPEN_CONNECT", 0x041400),
+    ("TCP_FASTOPEN_KEY", 0x041400),
+    ("TCP_FASTOPEN_NO_COOKIE", 0x041400),
+    ("TCP_FLAG_ACK", 0x020620),
+    ("TCP_FLAG_CWR", 0x020620),
+    ("TCP_FLAG_ECE", 0x020620),
+    ("TCP_FLAG_FIN", 0x020620),
+    ("TCP_FLAG_PSH", 0x020620),
+    ("TCP_FLAG_RST", 0x020620),
+    ("TCP_FLAG_SYN", 0x020620),
+    ("TCP_FLAG_URG", 0x020620),
+    ("TCP_INFO", 0x020620),
+    ("TCP_INQ", 0x041400),
+    ("TCP_KEEPCNT", 0x020620),
+    ("TCP_KEEPIDLE", 0x020620),
+    ("TCP_KEEPINTVL", 0x020620),
+    ("TCP_LINGER2", 0x020620),
+    ("TCP_MAXSEG", 0x020620),
+    ("TCP_MD5SIG", 0x020620),
+    ("TCP_MD5SIG_EXT", 0x041400),
+    ("TCP_MD5SIG_FLAG_IFINDEX", 0x050b00),
+    ("TCP_MD5SIG_FLAG_PREFIX", 0x041400),
+    ("TCP_MD5SIG_MAXKEYLEN", 0x020620),
+    ("TCP_MSS_DEFAULT", 0x030200),
+    ("TCP_MSS_DESIRED", 0x030200),
+    ("TCP_NLA_BUSY", 0x041400),
+    ("TCP_NLA_BYTES_NOTSENT", 0x050b00),
+    ("TCP_NLA_BYTES_RETRANS", 0x041400),
+    ("TCP_NLA_BYTES_SENT", 0x041400),
+    ("TCP_NLA_CA_STATE", 0x041400),
+    ("TCP_NLA_DATA_SEGS_OUT", 0x041400),
+    ("TCP_NLA_DELIVERED", 0x041400),
+    ("TCP_NLA_DELIVERED_CE", 0x041400),
+    ("TCP_NLA_DELIVERY_RATE", 0x041400),
+    ("TCP_NLA_DELIVERY_RATE_APP_LMT", 0x041400),
+    ("TCP_NLA_DSACK_DUPS", 0x041400),
+    ("TCP_NLA_EDT", 0x050b00),
+    ("TCP_NLA_MIN_RTT", 0x041400),
+    ("TCP_NLA_PACING_RATE", 0x041400),
+    ("TCP_NLA_PAD", 0x041400),
+    ("TCP_NLA_RECUR_RETRANS", 0x041400),
+    ("TCP_NLA_REORDERING", 0x041400),
+    ("TCP_NLA_REORD_SEEN", 0x041400),
+    ("TCP_NLA_RWND_LIMITED", 0x041400),
+    ("TCP_NLA_SNDBUF_LIMITED", 0x041400),
+    ("TCP_NLA_SNDQ_SIZE", 0x041400),
+    ("TCP_NLA_SND_CWND", 0x041400),
+    ("TCP_NLA_SND_SSTHRESH", 0x041400),
+    ("TCP_NLA_SRTT", 0x050400),
+    ("TCP_NLA_TIMEOUT_REHASH", 0x050b00),
+    ("TCP_NLA_TOTAL_RETRANS", 0x041400),
+    ("TCP_NODELAY", 0x020620),
+    ("TCP_NOTSENT_LOWAT", 0x040200),
+    ("TCP_NO_QUEUE", 0x030a00),
+    ("TCP_QUEUES_NR", 0x030a00),
+    ("TCP_QUEUE_SEQ", 0x030a00),
+    ("TCP_QUICKACK", 0x020620),
+    ("TCP_RECEIVE_ZEROCOPY_FLAG_TLB_CLEAN_HINT", 0x050b00),
+    ("TCP_RECV_QUEUE", 0x030a00),
+    ("TCP_REPAIR", 0x030a00),
+    ("TCP_REPAIR_OFF", 0x041400),
+    ("TCP_REPAIR_OFF_NO_WP", 0x041400),
+    ("TCP_REPAIR_ON", 0x041400),
+    ("TCP_REPAIR_OPTIONS", 0x030a00),
+    ("TCP_REPAIR_QUEUE", 0x030a00),
+    ("TCP_REPAIR_WINDOW", 0x041400),
+    ("TCP_RESERVED_BITS", 0x020620),
+    ("TCP_SAVED_SYN", 0x040200),
+    ("TCP_SAVE_SYN", 0x040200),
+    ("TCP_SEND_QUEUE", 0x030a00),
+    ("TCP_SYNCNT", 0x020620),
+    ("TCP_S_DATA_IN", 0x030200),
+    ("TCP_S_DATA_OUT", 0x030200),
+    ("TCP_THIN_DUPACK", 0x030200),
+    ("TCP_THIN_LINEAR_TIMEOUTS", 0x030200),
+    ("TCP_TIMESTAMP", 0x030a00),
+    ("TCP_TX_DELAY", 0x050400),
+    ("TCP_ULP", 0x041400),
+    ("TCP_USER_TIMEOUT", 0x030200),
+    ("TCP_WINDOW_CLAMP", 0x020620),
+    ("TCP_ZEROCOPY_RECEIVE", 0x041400),
+    ("TCSADRAIN", 0x020620),
+    ("TCSAFLUSH", 0x020620),
+    ("TCSANOW", 0x020620),
+    ("TCSBRK", 0x020620),
+    ("TCSBRKP", 0x020620),
+    ("TCSETA", 0x020620),
+    ("TCSETAF", 0x020620),
+    ("TCSETAW", 0x020620),
+    ("TCSETS", 0x020620),
+    ("TCSETSF", 0x020620),
+    ("TCSETSW", 0x020620),
+    ("TCSETX", 0x020620),
+    ("TCSETXF", 0x020620),
+    ("TCSETXW", 0x020620),
+    ("TCXONC", 0x020620),
+    ("TFD_CLOEXEC", 0x020620),
+    ("TFD_CREATE_FLAGS", 0x020620),
+    ("TFD_NONBLOCK", 0x020620),
+    ("TFD_SETTIME_FLAGS", 0x020620),
+    ("TFD_SHARED_FCNTL_FLAGS", 0x020620),
+    ("TFD_TIMER_ABSTIME", 0x020620),
+    ("TFD_TIMER_CANCEL_ON_SET", 0x041400),
+    ("TIMER_ABSTIME", 0x020620),
+    ("TIOCCBRK", 0x020620),
+    ("TIOCCONS", 0x020620),
+    ("TIOCEXCL", 0x020620),
+    ("TIOCGETD", 0x020620),
+    ("TIOCGETP", 0x040400),
+    ("TIOCGHAYESESP", 0x020620),
+    ("TIOCGICOUNT", 0x020620),
+    ("TIOCGLCKTRMIOS", 0x020620),
+    ("TIOCGLTC", 0x040400),
+    ("TIOCGPGRP", 0x020620),
+    ("TIOCGRS485", 0x020620),
+    ("TIOCGSERIAL", 0x020620),
+    ("TIOCGSID", 0x020620),
+    ("TIOCGSOFTCAR", 0x020620),
+    ("TIOCGWINSZ", 0x020620),
+    ("TIOCINQ", 0x020620),
+    ("TIOCLINUX", 0x020620),
+    ("TIOCMBIC", 0x020620),
+    ("TIOCMBIS", 0x020620),
+    ("TIOCMGET", 0x020620),
+    ("TIOCMIWAIT", 0x020620),
+    ("TIOCMSET", 0x020620),
+    ("TIOCM_CAR", 0x020620),
+    ("TIOCM_CD", 0x020620),
+    ("TIOCM_CTS", 0x020620),
+    ("TIOCM_DSR", 0x020620),
+    ("TIOCM_DTR", 0x020620),
+    ("TIOCM_LE", 0x020620),
+    ("TIOCM_LOOP", 0x020620),
+    ("TIOCM_OUT1", 0x020620),
+    ("TIOCM_OUT2", 0x020620),
+    ("TIOCM_RI", 0x020620),
+    ("TIOCM_RNG", 0x020620),
+    ("TIOCM_RTS", 0x020620),
+    ("TIOCM_SR", 0x020620),
+    ("TIOCM_ST", 0x020620),
+    ("TIOCNOTTY", 0x020620),
+    ("TIOCNXCL", 0x020620),
+    ("TIOCOUTQ", 0x020620),
+    ("TIOCPKT", 0x020620),
+    ("TIOCPKT_DATA", 0x020620),
+    ("TIOCPKT_DOSTOP", 0x020620),
+    ("TIOCPKT_FLUSHREAD", 0x020620),
+    ("TIOCPKT_FLUSHWRITE", 0x020620),
+    ("TIOCPKT_IOCTL", 0x030200),
+    ("TIOCPKT_NOSTOP", 0x020620),
+    ("TIOCPKT_START", 0x020620),
+    ("TIOCPKT_STOP", 0x020620),
+    ("TIOCSBRK", 0x020620),
+    ("TIOCSCTTY", 0x020620),
+    ("TIOCSERCONFIG", 0x020620),
+    ("TIOCSERGETLSR", 0x020620),
+    ("TIOCSERGETMULTI", 0x020620),
+    ("TIOCSERGSTRUCT", 0x020620),
+    ("TIOCSERGWILD", 0x020620),
+    ("TIOCSERSETMULTI", 0x020620),
+    ("TIOCSERSWILD", 0x020620),
+    ("TIOCSER_TEMT", 0x020620),
+    ("TIOCSETD", 0x020620),
+    ("TIOCSETN", 0x040400),
+    ("TIOCSETP", 0x040400),
+    ("TIOCSHAYESESP", 0x020620),
+    ("TIOCSLCKTRMIOS", 0x020620),
+    ("TIOCSLTC", 0x040400),
+    ("TIOCSPGRP", 0x020620),
+    ("TIOCSRS485", 0x020620),
+    ("TIOCSSERIAL", 0x020620),
+    ("TIOCSSOFTCAR", 0x020620),
+    ("TIOCSTI", 0x020620),
+    ("TIOCSWINSZ", 0x020620),
+    ("TIOCVHANGUP", 0x030200),
+    ("TMPFS_MAGIC", 0x020620),
+    ("TOSTOP", 0x020620),
+    ("TRACEFS_MAGIC", 0x040200),
+    ("TRAP_BRANCH", 0x020620),
+    ("TRAP_BRKPT", 0x020620),
+    ("TRAP_HWBKPT", 0x020620),
+    ("TRAP_TRACE", 0x020620),
+    ("TRAP_UNK", 0x041400),
+    ("UDF_SUPER_MAGIC", 0x041400),
+    ("UIO_FASTIOV", 0x020620),
+    ("UIO_MAXIOV", 0x020620),
+    ("UNIX_PATH_MAX", 0x020620),
+    ("USBDEVICE_SUPER_MAGIC", 0x020620),
+    ("UTIME_NOW", 0x020620),
+    ("UTIME_OMIT", 0x020620),
+    ("V9FS_MAGIC", 0x030200),
+    ("VDISCARD", 0x020620),
+    ("VDSUSP", 0x050400),
+    ("VEOF", 0x020620),
+    ("VEOL", 0x020620),
+    ("VEOL2", 0x020620),
+    ("VERASE", 0x020620),
+    ("VFS_CAP_FLAGS_EFFECTIVE", 0x020620),
+    ("VFS_CAP_FLAGS_MASK", 0x020620),
+    ("VFS_CAP_REVISION", 0x020620),
+    ("VFS_CAP_REVISION_1", 0x020620),
+    ("VFS_CAP_REVISION_2", 0x020620),
+    ("VFS_CAP_REVISION_3", 0x041400),
+    ("VFS_CAP_REVISION_MASK", 0x020620),
+    ("VFS_CAP_REVISION_SHIFT", 0x020620),
+    ("VFS_CAP_U32", 0x020620),
+    ("VFS_CAP_U32_1", 0x020620),
+    ("VFS_CAP_U32_2", 0x020620),
+    ("VFS_CAP_U32_3", 0x041400),
+    ("VINTR", 0x020620),
+    ("VKILL", 0x020620),
+    ("VLNEXT", 0x020620),
+    ("VMIN", 0x020620),
+    ("VQUIT", 0x020620),
+    ("VREPRINT", 0x020620),
+    ("VSTART", 0x020620),
+    ("VSTOP", 0x020620),
+    ("VSUSP", 0x020620),
+    ("VSWTC", 0x020620),
+    ("VSWTCH", 0x040400),
+    ("VT0", 0x020620),
+    ("VT1", 0x020620),
+    ("VTDLY", 0x020620),
+    ("VTIME", 0x020620),
+    ("VWERASE", 0x020620),
+    ("WCONTINUED", 0x020620),
+    ("WEXITED", 0x020620),
+    ("WNOHANG", 0x020620),
+    ("WNOWAIT", 0x020620),
+    ("WRAP", 0x050400),
+    ("WRITE", 0x020620),
+    ("WSTOPPED", 0x020620),
+    ("WUNTRACED", 0x020620),
+    ("W_OK", 0x020620),
+    ("XATTR_LIST_MAX", 0x020620),
+    ("XATTR_NAME_MAX", 0x020620),
+    ("XATTR_SIZE_MAX", 0x020620),
+    ("XCASE", 0x020620),
+    ("XENFS_SUPER_MAGIC", 0x020620),
+    ("XFS_SUPER_MAGIC", 0x041400),
+    ("XTABS", 0x020620),
+    ("X_OK", 0x020620),
+    ("Z3FOLD_MAGIC", 0x050400),
+    ("ZONEFS_MAGIC", 0x050b00),
+    ("ZSMALLOC_MAGIC", 0x041400),
+    ("_ASM_ARM_UNISTD_COMMON_H", 0x050400),
+    ("_ASM_ARM_UNISTD_EABI_H", 0x050400),
+    ("_ASM_X86_UNISTD_32_H", 0x050400),
+    ("_ASM_X86_UNISTD_64_H", 0x050400),
+    ("_IOC_DIRBITS", 0x020620),
+    ("_IOC_DIRMASK", 0x020620),
+    ("_IOC_DIRSHIFT", 0x020620),
+    ("_IOC_NONE", 0x020620),
+    ("_IOC_NRBITS", 0x020620),
+    ("_IOC_NRMASK", 0x020620),
+    ("_IOC_NRSHIFT", 0x020620),
+    ("_IOC_READ", 0x020620),
+    ("_IOC_SIZEBITS", 0x020620),
+    ("_IOC_SIZEMASK", 0x020620),
+    ("_IOC_SIZESHIFT", 0x020620),
+    ("_IOC_TYPEBITS", 0x020620),
+    ("_IOC_TYPEMASK", 0x020620),
+    ("_IOC_TYPESHIFT", 0x020620),
+    ("_IOC_WRITE", 0x020620),
+    ("_IOC_XSIZEMASK", 0x050400),
+    ("_K_SS_MAXSIZE", 0x020620),
+    ("_LINUX_CAPABILITY_U32S", 0x020620),
+    ("_LINUX_CAPABILITY_U32S_1", 0x020620),
+    ("_LINUX_CAPABILITY_U32S_2", 0x020620),
+    ("_LINUX_CAPABILITY_U32S_3", 0x020620),
+    ("_LINUX_CAPABILITY_VERSION", 0x020620),
+    ("_LINUX_CAPABILITY_VERSION_1", 0x020620),
+    ("_LINUX_CAPABILITY_VERSION_2", 0x020620),
+    ("_LINUX_CAPABILITY_VERSION_3", 0x020620),
+    ("_MAP_NEW", 0x050400),
+    ("_MIPS_ISA_MIPS1", 0x040400),
+    ("_MIPS_ISA_MIPS2", 0x040400),
+    ("_MIPS_ISA_MIPS3", 0x040400),
+    ("_MIPS_ISA_MIPS32", 0x040400),
+    ("_MIPS_ISA_MIPS4", 0x040400),
+    ("_MIPS_ISA_MIPS5", 0x040400),
+    ("_MIPS_ISA_MIPS64", 0x040400),
+    ("_MIPS_SIM_ABI32", 0x040400),
+    ("_MIPS_SIM_ABI64", 0x040400),
+    ("_MIPS_SIM_NABI32", 0x040400),
+    ("_NSIG", 0x020620),
+    ("_NSIG_BPW", 0x020620),
+    ("_NSIG_WORDS", 0x020620),
+    ("_STK_LIM", 0x020620),
+    ("_STK_LIM_MAX", 0x020620),
+    ("_SV_IGNCHILD", 0x050400),
+    ("_SV_INTR", 0x050400),
+    ("_SV_RESET", 0x050400),
+    ("_SV_SSTACK", 0x050400),
+    ("_VEOF", 0x020620),
+    ("_VEOL", 0x020620),
+    ("_VEOL2", 0x020620),
+    ("_VERASE", 0x020620),
+    ("_VINTR", 0x020620),
+    ("_VKILL", 0x020620),
+    ("_VMIN", 0x020620),
+    ("_VQUIT", 0x020620),
+    ("_VSWTC", 0x020620),
+    ("_VTIME", 0x020620),
+    ("__ARM_NR_BASE", 0x030200),
+    ("__ARM_NR_breakpoint", 0x030200),
+    ("__ARM_NR_cacheflush", 0x030200),
+    ("__ARM_NR_get_tls", 0x050400),
+    ("__ARM_NR_set_tls", 0x030200),
+    ("__ARM_NR_usr26", 0x030200),
+    ("__ARM_NR_usr32", 0x030200),
+    ("__BIG_ENDIAN", 0x020620),
+    ("__BITS_PER_LONG", 0x020620),
+    ("__BindgenBitfieldUnit", 0x020620),
+    ("__BindgenUnionField", 0x020620),
+    ("__FD_SETSIZE", 0x020620),
+    ("__FPE_DECDIV", 0x041400),
+    ("__FPE_DECERR", 0x041400),
+    ("__FPE_DECOVF", 0x041400),
+    ("__FPE_INVASC", 0x041400),
+    ("__FPE_INVDEC", 0x041400),
+    ("__FSCRYPT_MODE_MAX", 0x050400),
+    ("__ILL_BNDMOD", 0x041400),
+    ("__ILL_BREAK", 0x041400),
+    ("__IncompleteArrayField", 0x020620),
+    ("__LITTLE_ENDIAN", 0x020620),
+    ("__NEW_NSIG", 0x050400),
+    ("__NEW_UTS_LEN", 0x020620),
+    ("__NR3264_fadvise64", 0x040200),
+    ("__NR3264_fcntl", 0x040200),
+    ("__NR3264_fstat", 0x040200),
+    ("__NR3264_fstatat", 0x040200),
+    ("__NR3264_fstatfs", 0x040200),
+    ("__NR3264_ftruncate", 0x040200),
+    ("__NR3264_lseek", 0x040200),
+    ("__NR3264_mmap", 0x040200),
+    ("__NR3264_sendfile", 0x040200),
+    ("__NR3264_statfs", 0x040200),
+    ("__NR3264_truncate", 0x040200),
+    ("__NR_64_Linux", 0x040400),
+    ("__NR_64_Linux_syscalls", 0x040400),
+    ("__NR_Linux", 0x040400),
+    ("__NR_Linux_syscalls", 0x040400),
+    ("__NR_N32_Linux", 0x040400),
+    ("__NR_N32_Linux_syscalls", 0x040400),
+    ("__NR_O32_Linux", 0x040400),
+    ("__NR_O32_Linux_syscalls", 0x040400),
+    ("__NR_OABI_SYSCALL_BASE", 0x030200),
+    ("__NR_SYSCALL_BASE", 0x030200),
+    ("__NR__llseek", 0x020620),
+    ("__NR__newselect", 0x020620),
+    ("__NR__sysctl", 0x020620),
+    ("__NR_accept", 0x020620),
+    ("__NR_accept4", 0x020620),
+    ("__NR_access", 0x020620),
+    ("__NR_acct", 0x020620),
+    ("__NR_add_key", 0x020620),
+    ("__NR_adjtimex", 0x020620),
+    ("__NR_afs_syscall", 0x020620),
+    ("__NR_alarm", 0x020620),
+    ("__NR_arch_prctl", 0x020620),
+    ("__NR_arch_specific_syscall", 0x040200),
+    ("__NR_arm_fadvise64_64", 0x030200),
+    ("__NR_arm_sync_file_range", 0x030200),
+    ("__NR_bdflush", 0x020620),
+    ("__NR_bind", 0x020620),
+    ("__NR_bpf", 0x040200),
+    ("__NR_break", 0x020620),
+    ("__NR_brk", 0x020620),
+    ("__NR_cachectl", 0x040400),
+    ("__NR_cacheflush", 0x040400),
+    ("__NR_capget", 0x020620),
+    ("__NR_capset", 0x020620),
+    ("__NR_chdir", 0x020620),
+    ("__NR_chmod", 0x020620),
+    ("__NR_chown", 0x020620),
+    ("__NR_chown32", 0x020620),
+    ("__NR_chroot", 0x020620),
+    ("__NR_clock_adjtime", 0x030200),
+    ("__NR_clock_adjtime64", 0x050400),
+    ("__NR_clock_getres", 0x020620),
+    ("__NR_clock_getres_time64", 0x050400),
+    ("__NR_clock_gettime", 0x020620),
+    ("__NR_clock_gettime64", 0x050400),
+    ("__NR_clock_nanosleep", 0x020620),
+    ("__NR_clock_nanosleep_time64", 0x050400),
+    ("__NR_clock_settime", 0x020620),
+    ("__NR_clock_settime64", 0x050400),
+    ("__NR_clone", 0x020620),
+    ("__NR_clone3", 0x050400),
+    ("__NR_close", 0x020620),
+    ("__NR_close_range", 0x050b00),
+    ("__NR_connect", 0x020620),
+    ("__NR_copy_file_range", 0x041400),
+    ("__NR_creat", 0x020620),
+    ("__NR_create_module", 0x020620),
+    ("__NR_delete_module", 0x020620),
+    ("__NR_dup", 0x020620),
+    ("__NR_dup2", 0x020620),
+    ("__NR_dup3", 0x020620),
+    ("__NR_epoll_create", 0x020620),
+    ("__NR_epoll_create1", 0x020620),
+    ("__NR_epoll_ctl", 0x020620),
+    ("__NR_epoll_ctl_old", 0x020620),
+    ("__NR_epoll_pwait", 0x020620),
+    ("__NR_epoll_pwait2", 0x050b00),
+    ("__NR_epoll_wait", 0x020620),
+    ("__NR_epoll_wait_old", 0x020620),
+    ("__NR_eventfd", 0x020620),
+    ("__NR_eventfd2", 0x020620),
+    ("__NR_execv", 0x050400),
+    ("__NR_execve", 0x020620),
+    ("__NR_execveat", 0x040200),
+    ("__NR_exit", 0x020620),
+    ("__NR_exit_group", 0x020620),
+    ("__NR_faccessat", 0x020620),
+    ("__NR_faccessat2", 0x050b00),
+    ("__NR_fadvise64", 0x020620),
+    ("__NR_fadvise64_64", 0x020620),
+    ("__NR_fallocate", 0x020620),
+    ("__NR_fanotify_init", 0x030200),
+    ("__NR_fanotify_mark", 0x030200),
+    ("__NR_fchdir", 0x020620),
+    ("__NR_fchmod", 0x020620),
+    ("__NR_fchmodat", 0x020620),
+    ("__NR_fchown", 0x020620),
+    ("__NR_fchown32", 0x020620),
+    ("__NR_fchownat", 0x020620),
+    ("__NR_fcntl", 0x020620),
+    ("__NR_fcntl64", 0x020620),
+    ("__NR_fdatasync", 0x020620),
+    ("__NR_fgetxattr", 0x020620),
+    ("__NR_finit_module", 0x030a00),
+    ("__NR_flistxattr", 0x020620),
+    ("__NR_flock", 0x020620),
+    ("__NR_fork", 0x020620),
+    ("__NR_fremovexattr", 0x020620),
+    ("__NR_fsconfig", 0x050400),
+    ("__NR_fsetxattr", 0x020620),
+    ("__NR_fsmount", 0x050400),
+    ("__NR_fsopen", 0x050400),
+    ("__NR_fspick", 0x050400),
+    ("__NR_fstat", 0x020620),
+    ("__NR_fstat64", 0x020620),
+    ("__NR_fstatat64", 0x020620),
+    ("__NR_fstatfs", 0x020620),
+    ("__NR_fstatfs64", 0x020620),
+    ("__NR_fsync", 0x020620),
+    ("__NR_ftime", 0x020620),
+    ("__NR_ftruncate", 0x020620),
+    ("__NR_ftruncate64", 0x020620),
+    ("__NR_futex", 0x020620),
+    ("__NR_futex_time64", 0x050400),
+    ("__NR_futimesat", 0x020620),
+    ("__NR_get_kernel_syms", 0x020620),
+    ("__NR_get_mempolicy", 0x020620),
+    ("__NR_get_robust_list", 0x020620),
+    ("__NR_get_thread_area", 0x020620),
+    ("__NR_getcpu", 0x020620),
+    ("__NR_getcwd", 0x020620),
+    ("__NR_getdents", 0x020620),
+    ("__NR_getdents64", 0x020620),
+    ("__NR_getdomainname", 0x050400),
+    ("__NR_getegid", 0x020620),
+    ("__NR_getegid32", 0x020620),
+    ("__NR_geteuid", 0x020620),
+    ("__NR_geteuid32", 0x020620),
+    ("__NR_getgid", 0x020620),
+    ("__NR_getgid32", 0x020620),
+    ("__NR_getgroups", 0x020620),
+    ("__NR_getgroups32", 0x020620),
+    ("__NR_getitimer", 0x020620),
+    ("__NR_getpagesize", 0x050400),
+    ("__NR_getpeername", 0x020620),
+    ("__NR_getpgid", 0x020620),
+    ("__NR_getpgrp", 0x020620),
+    ("__NR_getpid", 0x020620),
+    ("__NR_getpmsg", 0x020620),
+    ("__NR_getppid", 0x020620),
+    ("__NR_getpriority", 0x020620),
+    ("__NR_getrandom", 0x040200),
+    ("__NR_getresgid", 0x020620),
+    ("__NR_getresgid32", 0x020620),
+    ("__NR_getresuid", 0x020620),
+    ("__NR_getresuid32", 0x020620),
+    ("__NR_getrlimit", 0x020620),
+    ("__NR_getrusage", 0x020620),
+    ("__NR_getsid", 0x020620),
+    ("__NR_getsockname", 0x020620),
+    ("__NR_getsockopt", 0x020620),
+    ("__NR_gettid", 0x020620),
+    ("__NR_gettimeofday", 0x020620),
+    ("__NR_getuid", 0x020620),
+    ("__NR_getuid32", 0x020620),
+    ("__NR_getxattr", 0x020620),
+    ("__NR_gtty", 0x020620),
+    ("__NR_idle", 0x020620),
+    ("__NR_init_module", 0x020620),
+    ("__NR_inotify_add_watch", 0x020620),
+    ("__NR_inotify_init", 0x020620),
+    ("__NR_inotify_init1", 0x020620),
+    ("__NR_inotify_rm_watch", 0x020620),
+    ("__NR_io_cancel", 0x020620),
+    ("__NR_io_destroy", 0x020620),
+    ("__NR_io_getevents", 0x020620),
+    ("__NR_io_pgetevents", 0x041400),
+    ("__NR_io_pgetevents_time64", 0x050400),
+    ("__NR_io_setup", 0x020620),
+    ("__NR_io_submit", 0x020620),
+    ("__NR_io_uring_enter", 0x050400),
+    ("__NR_io_uring_register", 0x050400),
+    ("__NR_io_uring_setup", 0x050400),
+    ("__NR_ioctl", 0x020620),
+    ("__NR_ioperm", 0x020620),
+    ("__NR_iopl", 0x020620),
+    ("__NR_ioprio_get", 0x020620),
+    ("__NR_ioprio_set", 0x020620),
+    ("__NR_ipc", 0x020620),
+    ("__NR_kcmp", 0x030a00),
+    ("__NR_kern_features", 0x050400),
+    ("__NR_kexec_file_load", 0x050400),
+    ("__NR_kexec_load", 0x020620),
+    ("__NR_keyctl", 0x020620),
+    ("__NR_kill", 0x020620),
+    ("__NR_lchown", 0x020620),
+    ("__NR_lchown32", 0x020620),
+    ("__NR_lgetxattr", 0x020620),
+    ("__NR_link", 0x020620),
+    ("__NR_linkat", 0x020620),
+    ("__NR_listen", 0x020620),
+    ("__NR_listxattr", 0x020620),
+    ("__NR_llistxattr", 0x020620),
+    ("__NR_llseek", 0x050400),
+    ("__NR_lock", 0x020620),
+    ("__NR_lookup_dcookie", 0x020620),
+    ("__NR_lremovexattr", 0x020620),
+    ("__NR_lseek", 0x020620),
+    ("__NR_lsetxattr", 0x020620),
+    ("__NR_lstat", 0x020620),
+    ("__NR_lstat64", 0x020620),
+    ("__NR_madvise", 0x020620),
+    ("__NR_madvise1", 0x020620),
+    ("__NR_mbind", 0x020620),
+    ("__NR_membarrier", 0x040400),
+    ("__NR_memfd_create", 0x040200),
+    ("__NR_memory_ordering", 0x050400),
+    ("__NR_migrate_pages", 0x020620),
+    ("__NR_mincore", 0x020620),
+    ("__NR_mkdir", 0x020620),
+    ("__NR_mkdirat", 0x020620),
+    ("__NR_mknod", 0x020620),
+    ("__NR_mknodat", 0x020620),
+    ("__NR_mlock", 0x020620),
+    ("__NR_mlock2", 0x040400),
+    ("__NR_mlockall", 0x020620),
+    ("__NR_mmap", 0x020620),
+    ("__NR_mmap2", 0x020620),
+    ("__NR_modify_ldt", 0x020620),
+    ("__NR_mount", 0x020620),
+    ("__NR_move_mount", 0x050400),
+    ("__NR_move_pages", 0x020620),
+    ("__NR_mprotect", 0x020620),
+    ("__NR_mpx", 0x020620),
+    ("__NR_mq_getsetattr", 0x020620),
+    ("__NR_mq_notify", 0x020620),
+    ("__NR_mq_open", 0x020620),
+    ("__NR_mq_timedreceive", 0x020620),
+    ("__NR_mq_timedreceive_time64", 0x050400),
+    ("__NR_mq_timedsend", 0x020620),
+    ("__NR_mq_timedsend_time64", 0x050400),
+    ("__NR_mq_unlink", 0x020620),
+    ("__NR_mremap", 0x020620),
+    ("__NR_msgctl", 0x020620),
+    ("__NR_msgget", 0x020620),
+    ("__NR_msgrcv", 0x020620),
+    ("__NR_msgsnd", 0x020620),
+    ("__NR_msync", 0x020620),
+    ("__NR_multiplexer", 0x020620),
+    ("__NR_munlock", 0x020620),
+    ("__NR_munlockall", 0x020620),
+    ("__NR_munmap", 0x020620),
+    ("__NR_name_to_handle_at", 0x030200),
+    ("__NR_nanosleep", 0x020620),
+    ("__NR_newfstatat", 0x020620),
+    ("__NR_nfsservctl", 0x020620),
+    ("__NR_nice", 0x020620),
+    ("__NR_oldfstat", 0x020620),
+    ("__NR_oldlstat", 0x020620),
+    ("__NR_oldolduname", 0x020620),
+    ("__NR_oldstat", 0x020620),
+    ("__NR_olduname", 0x020620),
+    ("__NR_open", 0x020620),
+    ("__NR_open_by_handle_at", 0x030200),
+    ("__NR_open_tree", 0x050400),
+    ("__NR_openat", 0x020620),
+    ("__NR_openat2", 0x050b00),
+    ("__NR_pause", 0x020620),
+    ("__NR_pciconfig_iobase", 0x020620),
+    ("__NR_pciconfig_read", 0x020620),
+    ("__NR_pciconfig_write", 0x020620),
+    ("__NR_perf_event_open", 0x020620),
+    ("__NR_perfctr", 0x050400),
+    ("__NR_personality", 0x020620),
+    ("__NR_pidfd_getfd", 0x050b00),
+    ("__NR_pidfd_open", 0x050400),
+    ("__NR_pidfd_send_signal", 0x050400),
+    ("__NR_pipe", 0x020620),
+    ("__NR_pipe2", 0x020620),
+    ("__NR_pivot_root", 0x020620),
+    ("__NR_pkey_alloc", 0x041400),
+    ("__NR_pkey_free", 0x041400),
+    ("__NR_pkey_mprotect", 0x041400),
+    ("__NR_poll", 0x020620),
+    ("__NR_ppoll", 0x020620),
+    ("__NR_ppoll_time64", 0x050400),
+    ("__NR_prctl", 0x020620),
+    ("__NR_pread64", 0x020620),
+    ("__NR_preadv", 0x020620),
+    ("__NR_preadv2", 0x041400),
+    ("__NR_prlimit64", 0x030200),
+    ("__NR_process_madvise", 0x050b00),
+    ("__NR_process_vm_readv", 0x030200),
+    ("__NR_process_vm_writev", 0x030200),
+    ("__NR_prof", 0x020620),
+    ("__NR_profil", 0x020620),
+    ("__NR_pselect6", 0x020620),
+    ("__NR_pselect6_time64", 0x050400),
+    ("__NR_ptrace", 0x020620),
+    ("__NR_putpmsg", 0x020620),
+    ("__NR_pwrite64", 0x020620),
+    ("__NR_pwritev", 0x020620),
+    ("__NR_pwritev2", 0x041400),
+    ("__NR_query_module", 0x020620),
+    ("__NR_quotactl", 0x020620),
+    ("__NR_read", 0x020620),
+    ("__NR_readahead", 0x020620),
+    ("__NR_readdir", 0x020620),
+    ("__NR_readlink", 0x020620),
+    ("__NR_readlinkat", 0x020620),
+    ("__NR_readv", 0x020620),
+    ("__NR_reboot", 0x020620),
+    ("__NR_recv", 0x030200),
+    ("__NR_recvfrom", 0x020620),
+    ("__NR_recvmmsg", 0x030200),
+    ("__NR_recvmmsg_time64", 0x050400),
+    ("__NR_recvmsg", 0x020620),
+    ("__NR_remap_file_pages", 0x020620),
+    ("__NR_removexattr", 0x020620),
+    ("__NR_rename", 0x020620),
+    ("__NR_renameat", 0x020620),
+    ("__NR_renameat2", 0x040200),
+    ("__NR_request_key", 0x020620),
+    ("__NR_reserved177", 0x040400),
+    ("__NR_reserved193", 0x040400),
+    ("__NR_reserved221", 0x040400),
+    ("__NR_reserved82", 0x040400),
+    ("__NR_restart_syscall", 0x020620),
+    ("__NR_riscv_flush_icache", 0x041400),
+    ("__NR_rmdir", 0x020620),
+    ("__NR_rseq", 0x041400),
+    ("__NR_rt_sigaction", 0x020620),
+    ("__NR_rt_sigpending", 0x020620),
+    ("__NR_rt_sigprocmask", 0x020620),
+    ("__NR_rt_sigqueueinfo", 0x020620),
+    ("__NR_rt_sigreturn", 0x020620),
+    ("__NR_rt_sigsuspend", 0x020620),
+    ("__NR_rt_sigtimedwait", 0x020620),
+    ("__NR_rt_sigtimedwait_time64", 0x050400),
+    ("__NR_rt_tgsigqueueinfo", 0x020620),
+    ("__NR_rtas", 0x020620),
+    ("__NR_s390_guarded_storage", 0x050400),
+    ("__NR_s390_pci_mmio_read", 0x050400),
+    ("__NR_s390_pci_mmio_write", 0x050400),
+    ("__NR_s390_runtime_instr", 0x050400),
+    ("__NR_s390_sthyi", 0x050400),
+    ("__NR_sched_get_affinity", 0x050400),
+    ("__NR_sched_get_priority_max", 0x020620),
+    ("__NR_sched_get_priority_min", 0x020620),
+    ("__NR_sched_getaffinity", 0x020620),
+    ("__NR_sched_getattr", 0x040200),
+    ("__NR_sched_getparam", 0x020620),
+    ("__NR_sched_getscheduler", 0x020620),
+    ("__NR_sched_rr_get_interval", 0x020620),
+    ("__NR_sched_rr_get_interval_time64", 0x050400),
+    ("__NR_sched_set_affinity", 0x050400),
+    ("__NR_sched_setaffinity", 0x020620),
+    ("__NR_sched_setattr", 0x040200),
+    ("__NR_sched_setparam", 0x020620),
+    ("__NR_sched_setscheduler", 0x020620),
+    ("__NR_sched_yield", 0x020620),
+    ("__NR_seccomp", 0x040200),
+    ("__NR_security", 0x020620),
+    ("__NR_select", 0x020620),
+    ("__NR_semctl", 0x020620),
+    ("__NR_semget", 0x020620),
+    ("__NR_semop", 0x020620),
+    ("__NR_semtimedop", 0x020620),
+    ("__NR_semtimedop_time64", 0x050400),
+    ("__NR_send", 0x030200),
+    ("__NR_sendfile", 0x020620),
+    ("__NR_sendfile64", 0x020620),
+    ("__NR_sendmmsg", 0x030200),
+    ("__NR_sendmsg", 0x020620),
+    ("__NR_sendto", 0x020620),
+    ("__NR_set_mempolicy", 0x020620),
+    ("__NR_set_robust_list", 0x020620),
+    ("__NR_set_thread_area", 0x020620),
+    ("__NR_set_tid_address", 0x020620),
+    ("__NR_setdomainname", 0x020620),
+    ("__NR_setfsgid", 0x020620),
+    ("__NR_setfsgid32", 0x020620),
+    ("__NR_setfsuid", 0x020620),
+    ("__NR_setfsuid32", 0x020620),
+    ("__NR_setgid", 0x020620),
+    ("__NR_setgid32", 0x020620),
+    ("__NR_setgroups", 0x020620),
+    ("__NR_setgroups32", 0x020620),
+    ("__NR_sethostname", 0x020620),
+    ("__NR_setitimer", 0x020620),
+    ("__NR_setns", 0x030200),
+    ("__NR_setpgid", 0x020620),
+    ("__NR_setpriority", 0x020620),
+    ("__NR_setregid", 0x020620),
+    ("__NR_setregid32", 0x020620),
+    ("__NR_setresgid", 0x020620),
+    ("__NR_setresgid32", 0x020620),
+    ("__NR_setresuid", 0x020620),
+    ("__NR_setresuid32", 0x020620),
+    ("__NR_setreuid", 0x020620),
+    ("__NR_setreuid32", 0x020620),
+    ("__NR_setrlimit", 0x020620),
+    ("__NR_setsid", 0x020620),
+    ("__NR_setsockopt", 0x020620),
+    ("__NR_settimeofday", 0x020620),
+    ("__NR_setuid", 0x020620),
+    ("__NR_setuid32", 0x020620),
+    ("__NR_setxattr", 0x020620),
+    ("__NR_sgetmask", 0x020620),
+    ("__NR_shmat", 0x020620),
+    ("__NR_shmctl", 0x020620),
+    ("__NR_shmdt", 0x020620),
+    ("__NR_shmget", 0x020620),
+    ("__NR_shutdown", 0x020620),
+    ("__NR_sigaction", 0x020620),
+    ("__NR_sigaltstack", 0x020620),
+    ("__NR_signal", 0x020620),
+    ("__NR_signalfd", 0x020620),
+    ("__NR_signalfd4", 0x020620),
+    ("__NR_sigpending", 0x020620),
+    ("__NR_sigprocmask", 0x020620),
+    ("__NR_sigreturn", 0x020620),
+    ("__NR_sigsuspend", 0x020620),
+    ("__NR_socket", 0x020620),
+    ("__NR_socketcall", 0x020620),
+    ("__NR_socketpair", 0x020620),
+    ("__NR_splice", 0x020620),
+    ("__NR_spu_create", 0x020620),
+    ("__NR_spu_run", 0x020620),
+    ("__NR_ssetmask", 0x020620),
+    ("__NR_stat", 0x020620),
+    ("__NR_stat64", 0x020620),
+    ("__NR_statfs", 0x020620),
+    ("__NR_statfs64", 0x020620),
+    ("__NR_statx", 0x041400),
+    ("__NR_stime", 0x020620),
+    ("__NR_stty", 0x020620),
+    ("__NR_subpage_prot", 0x020620),
+    ("__NR_swapcontext", 0x020620),
+    ("__NR_swapoff", 0x020620),
+    ("__NR_swapon", 0x020620),
+    ("__NR_switch_endian", 0x050400),
+    ("__NR_symlink", 0x020620),
+    ("__NR_symlinkat", 0x020620),
+    ("__NR_sync", 0x020620),
+    ("__NR_sync_file_range", 0x020620),
+    ("__NR_sync_file_range2", 0x020620),
+    ("__NR_syncfs", 0x030200),
+    ("__NR_sys_debug_setcontext", 0x020620),
+    ("__NR_syscall", 0x030200),
+    ("__NR_syscalls", 0x040200),
+    ("__NR_sysfs", 0x020620),
+    ("__NR_sysinfo", 0x020620),
+    ("__NR_syslog", 0x020620),
+    ("__NR_sysmips", 0x040400),
+    ("__NR_tee", 0x020620),
+    ("__NR_tgkill", 0x020620),
+    ("__NR_time", 0x020620),
+    ("__NR_timer_create", 0x020620),
+    ("__NR_timer_delete", 0x020620),
+    ("__NR_timer_getoverrun", 0x020620),
+    ("__NR_timer_gettime", 0x020620),
+    ("__NR_timer_gettime64", 0x050400),
+    ("__NR_timer_settime", 0x020620),
+    ("__NR_timer_settime64", 0x050400),
+    ("__NR_timerfd", 0x040400),
+    ("__NR_timerfd_create", 0x020620),
+    ("__NR_timerfd_gettime", 0x020620),
+    ("__NR_timerfd_gettime64", 0x050400),
+    ("__NR_timerfd_settime", 0x020620),
+    ("__NR_timerfd_settime64", 0x050400),
+    ("__NR_times", 0x020620),
+    ("__NR_tkill", 0x020620),
+    ("__NR_truncate", 0x020620),
+    ("__NR_truncate64", 0x020620),
+    ("__NR_tuxcall", 0x020620),
+    ("__NR_ugetrlimit", 0x020620),
+    ("__NR_ulimit", 0x020620),
+    ("__NR_umask", 0x020620),
+    ("__NR_umount", 0x020620),
+    ("__NR_umount2", 0x020620),
+    ("__NR_uname", 0x020620),
+    ("__NR_unlink", 0x020620),
+    ("__NR_unlinkat", 0x020620),
+    ("__NR_unshare", 0x020620),
+    ("__NR_unused109", 0x040400),
+    ("__NR_unused150", 0x040400),
+    ("__NR_unused18", 0x040400),
+    ("__NR_unused28", 0x040400),
+    ("__NR_unused59", 0x040400),
+    ("__NR_unused84", 0x040400),
+    ("__NR_uselib", 0x020620),
+    ("__NR_userfaultfd", 0x040400),
+    ("__NR_ustat", 0x020620),
+    ("__NR_utime", 0x020620),
+    ("__NR_utimensat", 0x020620),
+    ("__NR_utimensat_time64", 0x050400),
+    ("__NR_utimes", 0x020620),
+    ("__NR_utrap_install", 0x050400),
+    ("__NR_vfork", 0x020620),
+    ("__NR_vhangup", 0x020620),
+    ("__NR_vm86", 0x020620),
+    ("__NR_vm86old", 0x020620),
+    ("__NR_vmsplice", 0x020620),
+    ("__NR_vserver", 0x020620),
+    ("__NR_wait4", 0x020620),
+    ("__NR_waitid", 0x020620),
+    ("__NR_waitpid", 0x020620),
+    ("__NR_write", 0x020620),
+    ("__NR_writev", 0x020620),
+    ("__OLD_NSIG", 0x050400),
+    ("__OLD_UTS_LEN", 0x020620),
+    ("__O_SYNC", 0x030200),
+    ("__O_TMPFILE", 0x040200),
+    ("__SI_CHLD", 0x020620),
+    ("__SI_FAULT", 0x020620),
+    ("__SI_KILL", 0x020620),
+    ("__SI_MESGQ", 0x020620),
+    ("__SI_POLL", 0x020620),
+    ("__SI_RT", 0x020620),
+    ("__SI_SYS", 0x030a00),
+    ("__SI_TIMER", 0x020620),
+    ("__SOCK_SIZE__", 0x020620),
+    ("__SO_ACCEPTCON", 0x020620),
+    ("__UAPI_DEF_IF_IFCONF", 0x041400),
+    ("__UAPI_DEF_IF_IFMAP", 0x041400),
+    ("__UAPI_DEF_IF_IFNAMSIZ", 0x041400),
+    ("__UAPI_DEF_IF_IFREQ", 0x041400),
+    ("__UAPI_DEF_IF_NET_DEVICE_FLAGS", 0x041400),
+    ("__UAPI_DEF_IF_NET_DEVICE_FLAGS_LOWER_UP_DORMANT_ECHO", 0x041400),
+    ("__UAPI_DEF_IN6_ADDR", 0x040200),
+    ("__UAPI_DEF_IN6_ADDR_ALT", 0x040200),
+    ("__UAPI_DEF_IN6_PKTINFO", 0x040200),
+    ("__UAPI_DEF_IN_ADDR", 0x040200),
+    ("__UAPI_DEF_IN_CLASS", 0x040200),
+    ("__UAPI_DEF_IN_IPPROTO", 0x040200),
+    ("__UAPI_DEF_IN_PKTINFO", 0x040200),
+    ("__UAPI_DEF_IP6_MTUINFO", 0x040200),
+    ("__UAPI_DEF_IPPROTO_V6", 0x040200),
+    ("__UAPI_DEF_IPV6_MREQ", 0x040200),
+    ("__UAPI_DEF_IPV6_OPTIONS", 0x040200),
+    ("__UAPI_DEF_IPX_CONFIG_DATA", 0x041400),
+    ("__UAPI_DEF_IPX_INTERFACE_DEFINITION", 0x041400),
+    ("__UAPI_DEF_IPX_ROUTE_DEF", 0x041400),
+    ("__UAPI_DEF_IPX_ROUTE_DEFINITION", 0x041400),
+    ("__UAPI_DEF_IP_MREQ", 0x040200),
+    ("__UAPI_DEF_SOCKADDR_IN", 0x040200),
+    ("__UAPI_DEF_SOCKADDR_IN6", 0x040200),
+    ("__UAPI_DEF_SOCKADDR_IPX", 0x041400),
+    ("__UAPI_DEF_XATTR", 0x040200),
+    ("__WALL", 0x020620),
+    ("__WCLONE", 0x020620),
+    ("__WNOTHREAD", 0x020620),
+    ("__X32_SYSCALL_BIT", 0x050400),
+    ("__be16", 0x020620),
+    ("__be32", 0x020620),
+    ("__be64", 0x020620),
+    ("__fsword_t", 0x020620),
+    ("__kernel_caddr_t", 0x020620),
+    ("__kernel_clock_t", 0x020620),
+    ("__kernel_clockid_t", 0x020620),
+    ("__kernel_daddr_t", 0x020620),
+    ("__kernel_fd_set", 0x020620),
+    ("__kernel_fsid_t", 0x020620),
+    ("__kernel_gid16_t", 0x020620),
+    ("__kernel_gid32_t", 0x020620),
+    ("__kernel_gid_t", 0x020620),
+    ("__kernel_ino_t", 0x020620),
+    ("__kernel_ipc_pid_t", 0x020620),
+    ("__kernel_itimerspec", 0x020620),
+    ("__kernel_key_t", 0x020620),
+    ("__kernel_loff_t", 0x020620),
+    ("__kernel_long_t", 0x030a00),
+    ("__kernel_mode_t", 0x020620),
+    ("__kernel_mqd_t", 0x020620),
+    ("__kernel_nlink_t", 0x020620),
+    ("__kernel_off_t", 0x020620),
+    ("__kernel_old_dev_t", 0x020620),
+    ("__kernel_old_gid_t", 0x020620),
+    ("__kernel_old_itimerval", 0x050b00),
+    ("__kernel_old_time_t", 0x050b00),
+    ("__kernel_old_timespec", 0x050b00),
+    ("__kernel_old_timeval", 0x041400),
+    ("__kernel_old_uid_t", 0x020620),
+    ("__kernel_pid_t", 0x020620),
+    ("__kernel_ptrdiff_t", 0x020620),
+    ("__kernel_rwf_t", 0x041400),
+    ("__kernel_sa_family_t", 0x020620),
+    ("__kernel_sighandler_t", 0x020620),
+    ("__kernel_sigset_t", 0x050400),
+    ("__kernel_size_t", 0x020620),
+    ("__kernel_sock_timeval", 0x050400),
+    ("__kernel_sockaddr_storage", 0x020620),
+    ("__kernel_ssize_t", 0x020620),
+    ("__kernel_suseconds_t", 0x020620),
+    ("__kernel_time64_t", 0x020620),
+    ("__kernel_time_t", 0x020620),
+    ("__kernel_timer_t", 0x020620),
+    ("__kernel_timespec", 0x020620),
+    ("__kernel_uid16_t", 0x020620),
+    ("__kernel_uid32_t", 0x020620),
+    ("__kernel_uid_t", 0x020620),
+    ("__kernel_ulong_t", 0x030a00),
+    ("__le16", 0x020620),
+    ("__le32", 0x020620),
+    ("__le64", 0x020620),
+    ("__new_sigaction", 0x050400),
+    ("__new_sigset_t", 0x050400),
+    ("__old_kernel_stat", 0x020620),
+    ("__poll_t", 0x041400),
+    ("__restorefn_t", 0x020620),
+    ("__s16", 0x020620),
+    ("__s32", 0x020620),
+    ("__s64", 0x020620),
+    ("__s8", 0x020620),
+    ("__sifields", 0x041400),
+    ("__sighandler_t", 0x020620),
+    ("__signalfn_t", 0x020620),
+    ("__sigrestore_t", 0x020620),
+    ("__sum16", 0x020620),
+    ("__u16", 0x020620),
+    ("__u32", 0x020620),
+    ("__u64", 0x020620),
+    ("__u8", 0x020620),
+    ("__user_cap_data_struct", 0x020620),
+    ("__user_cap_header_struct", 0x020620),
+    ("__vector128", 0x020620),
+    ("__wsum", 0x020620),
+    ("_bindgen_ty_1", 0x020620),
+    ("_bindgen_ty_2", 0x020620),
+    ("_bindgen_ty_3", 0x030a00),
+    ("_bindgen_ty_4", 0x041400),
+    ("addr_t", 0x050400),
+    ("cap_user_data_t", 0x020620),
+    ("cap_user_header_t", 0x020620),
+    ("cc_t", 0x020620),
+    ("clone_args", 0x050400),
+    ("compat_statfs64", 0x020620),
+    ("epoll_event", 0x020620),
+    ("f_owner_ex", 0x020620),
+    ("file_clone_range", 0x041400),
+    ("file_dedupe_range", 0x041400),
+    ("file_dedupe_range_info", 0x041400),
+    ("files_stat_struct", 0x020620),
+    ("flock", 0x020620),
+    ("flock64", 0x020620),
+    ("fn", 0x020620),
+    ("fsconfig_command", 0x050400),
+    ("fscrypt_add_key_arg", 0x050400),
+    ("fscrypt_get_key_status_arg", 0x050400),
+    ("fscrypt_get_policy_ex_arg", 0x050400),
+    ("fscrypt_key", 0x041400),
+    ("fscrypt_key_specifier", 0x050400),
+    ("fscrypt_policy", 0x041400),
+    ("fscrypt_policy_v1", 0x050400),
+    ("fscrypt_policy_v2", 0x050400),
+    ("fscrypt_provisioning_key_payload", 0x050b00),
+    ("fscrypt_remove_key_arg", 0x050400),
+    ("fsid_t", 0x040400),
+    ("fstrim_range", 0x030200),
+    ("fsxattr", 0x041400),
+    ("group_filter", 0x020620),
+    ("group_req", 0x020620),
+    ("group_source_req", 0x020620),
+    ("in6_addr", 0x020620),
+    ("in6_flowlabel_req", 0x020620),
+    ("in_addr", 0x020620),
+    ("in_pktinfo", 0x020620),
+    ("inodes_stat_t", 0x020620),
+    ("iovec", 0x020620),
+    ("ip_mreq", 0x020620),
+    ("ip_mreq_source", 0x020620),
+    ("ip_mreqn", 0x020620),
+    ("ip_msfilter", 0x020620),
+    ("ipv6_mreq", 0x020620),
+    ("itimerspec", 0x020620),
+    ("itimerval", 0x020620),
+    ("k_sigaction", 0x020620),
+    ("ktermios", 0x020620),
+    ("linger", 0x020620),
+    ("linux_dirent64", 0x020620),
+    ("ltchars", 0x020620),
+    ("membarrier_cmd", 0x040400),
+    ("membarrier_cmd_flag", 0x050b00),
+    ("new_utsname", 0x020620),
+    ("old_sigaction", 0x020620),
+    ("old_sigset_t", 0x020620),
+    ("old_utsname", 0x020620),
+    ("oldold_utsname", 0x020620),
+    ("open_how", 0x050b00),
+    ("pollfd", 0x020620),
+    ("prctl_mm_map", 0x040200),
+    ("pt_regs", 0x050400),
+    ("rand_pool_info", 0x020620),
+    ("rlimit", 0x020620),
+    ("rlimit64", 0x030200),
+    ("rnd_state", 0x030200),
+    ("robust_list", 0x020620),
+    ("robust_list_head", 0x020620),
+    ("rq_flag_bits", 0x030200),
+    ("rusage", 0x020620),
+    ("saddr_t", 0x050400),
+    ("sgttyb", 0x020620),
+    ("sig_dbg_op", 0x020620),
+    ("sigaction", 0x020620),
+    ("sigaltstack", 0x020620),
+    ("sigevent", 0x020620),
+    ("sigevent_t", 0x020620),
+    ("siginfo", 0x020620),
+    ("siginfo_t", 0x020620),
+    ("sigset_t", 0x020620),
+    ("sigstack", 0x050400),
+    ("sigval", 0x020620),
+    ("sigval_t", 0x020620),
+    ("size_t", 0x020620),
+    ("sock_filter", 0x020620),
+    ("sock_fprog", 0x020620),
+    ("sockaddr", 0x020620),
+    ("sockaddr_in", 0x020620),
+    ("sockaddr_in6", 0x020620),
+    ("sockaddr_un", 0x020620),
+    ("socket_state", 0x020620),
+    ("socklen_t", 0x020620),
+    ("speed_t", 0x020620),
+    ("ssize_t", 0x020620),
+    ("stack_t", 0x020620),
+    ("stat", 0x020620),
+    ("stat64", 0x020620),
+    ("statfs", 0x020620),
+    ("statfs64", 0x020620),
+    ("statx", 0x041400),
+    ("statx_timestamp", 0x041400),
+    ("task_struct", 0x020620),
+    ("tcflag_t", 0x020620),
+    ("tchars", 0x020620),
+    ("tcp_ca_state", 0x020620),
+    ("tcp_cookie_transactions", 0x030200),
+    ("tcp_diag_md5sig", 0x041400),
+    ("tcp_fastopen_client_fail", 0x050b00),
+    ("tcp_info", 0x020620),
+    ("tcp_md5sig", 0x020620),
+    ("tcp_repair_opt", 0x030a00),
+    ("tcp_repair_window", 0x041400),
+    ("tcp_word_hdr", 0x020620),
+    ("tcp_zerocopy_receive", 0x041400),
+    ("tcphdr", 0x020620),
+    ("termio", 0x020620),
+    ("termios", 0x020620),
+    ("termios2", 0x020620),
+    ("termiox", 0x020620),
+    ("timespec", 0x020620),
+    ("timeval", 0x020620),
+    ("timezone", 0x020620),
+    ("umode_t", 0x020620),
+    ("user_desc", 0x020620),
+    ("vfs_cap_data", 0x020620),
+    ("vfs_ns_cap_data", 0x041400),
+    ("winsize", 0x020620),
+];
+
+/// The oldest generated version defining each item in `netlink`.
+pub static NETLINK: &[(&str, u32)] = &[
+    ("BRIDGE_MODE_HAIRPIN", 0x030a00),
+    ("BRIDGE_MODE_UNSPEC", 0x030a00),
+    ("FDB_NOTIFY_BIT", 0x050b00),
+    ("FDB_NOTIFY_INACTIVE_BIT", 0x050b00),
+    ("GENEVE_DF_MAX", 0x050400),
+    ("HSR_PROTOCOL_HSR", 0x050b00),
+    ("HSR_PROTOCOL_MAX", 0x050b00),
+    ("HSR_PROTOCOL_PRP", 0x050b00),
+    ("IFA_ADDRESS", 0x020620),
+    ("IFA_ANYCAST", 0x020620),
+    ("IFA_BROADCAST", 0x020620),
+    ("IFA_CACHEINFO", 0x020620),
+    ("IFA_FLAGS", 0x040200),
+    ("IFA_F_DADFAILED", 0x020620),
+    ("IFA_F_DEPRECATED", 0x020620),
+    ("IFA_F_HOMEADDRESS", 0x020620),
+    ("IFA_F_MANAGETEMPADDR", 0x040200),
+    ("IFA_F_MCAUTOJOIN", 0x040200),
+    ("IFA_F_NODAD", 0x020620),
+    ("IFA_F_NOPREFIXROUTE", 0x040200),
+    ("IFA_F_OPTIMISTIC", 0x020620),
+    ("IFA_F_PERMANENT", 0x020620),
+    ("IFA_F_SECONDARY", 0x020620),
+    ("IFA_F_STABLE_PRIVACY", 0x040200),
+    ("IFA_F_TEMPORARY", 0x020620),
+    ("IFA_F_TENTATIVE", 0x020620),
+    ("IFA_LABEL", 0x020620),
+    ("IFA_LOCAL", 0x020620),
+    ("IFA_MULTICAST", 0x020620),
+    ("IFA_RT_PRIORITY", 0x041400),
+    ("IFA_TARGET_NETNSID", 0x041400),
+    ("IFA_UNSPEC", 0x020620),
+    ("IFLA_ADDRESS", 0x020620),
+    ("IFLA_AF_SPEC", 0x030200),
+    ("IFLA_ALT_IFNAME", 0x050b00),
+    ("IFLA_BAREUDP_ETHERTYPE", 0x050b00),
+    ("IFLA_BAREUDP_MULTIPROTO_MODE", 0x050b00),
+    ("IFLA_BAREUDP_PORT", 0x050b00),
+    ("IFLA_BAREUDP_SRCPORT_MIN", 0x050b00),
+    ("IFLA_BAREUDP_UNSPEC", 0x050b00),
+    ("IFLA_BOND_ACTIVE_SLAVE", 0x040200),
+    ("IFLA_BOND_AD_ACTOR_SYSTEM", 0x040200),
+    ("IFLA_BOND_AD_ACTOR_SYS_PRIO", 0x040200),
+    ("IFLA_BOND_AD_INFO", 0x040200),
+    ("IFLA_BOND_AD_INFO_ACTOR_KEY", 0x040200),
+    ("IFLA_BOND_AD_INFO_AGGREGATOR", 0x040200),
+    ("IFLA_BOND_AD_INFO_NUM_PORTS", 0x040200),
+    ("IFLA_BOND_AD_INFO_PARTNER_KEY", 0x040200),
+    ("IFLA_BOND_AD_INFO_PARTNER_MAC", 0x040200),
+    ("IFLA_BOND_AD_INFO_UNSPEC", 0x040200),
+    ("IFLA_BOND_AD_LACP_RATE", 0x040200),
+    ("IFLA_BOND_AD_SELECT", 0x040200),
+    ("IFLA_BOND_AD_USER_PORT_KEY", 0x040200),
+    ("IFLA_BOND_ALL_SLAVES_ACTIVE", 0x040200),
+    ("IFLA_BOND_ARP_ALL_TARGETS", 0x040200),
+    ("IFLA_BOND_ARP_INTERVAL", 0x040200),
+    ("IFLA_BOND_ARP_IP_TARGET", 0x040200),
+    ("IFLA_BOND_ARP_VALIDATE", 0x040200),
+    ("IFLA_BOND_DOWNDELAY", 0x040200),
+    ("IFLA_BOND_FAIL_OVER_MAC", 0x040200),
+    ("IFLA_BOND_LP_INTERVAL", 0x040200),
+    ("IFLA_BOND_MIIMON", 0x040200),
+    ("IFLA_BOND_MIN_LINKS", 0x040200),
+    ("IFLA_BOND_MODE", 0x040200),
+    ("IFLA_BOND_NUM_PEER_NOTIF", 0x040200),
+    ("IFLA_BOND_PACKETS_PER_SLAVE", 0x040200),
+    ("IFLA_BOND_PEER_NOTIF_DELAY", 0x050400),
+    ("IFLA_BOND_PRIMARY", 0x040200),
+    ("IFLA_BOND_PRIMARY_RESELECT", 0x040200),
+    ("IFLA_BOND_RESEND_IGMP", 0x040200),
+    ("IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE", 0x040200),
+    ("IFLA_BOND_SLAVE_AD_AGGREGATOR_ID", 0x040200),
+    ("IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE", 0x040200),
+    ("IFLA_BOND_SLAVE_LINK_FAILURE_COUNT", 0x040200),
+    ("IFLA_BOND_SLAVE_MII_STATUS", 0x040200),
+    ("IFLA_BOND_SLAVE_PERM_HWADDR", 0x040200),
+    ("IFLA_BOND_SLAVE_QUEUE_ID", 0x040200),
+    ("IFLA_BOND_SLAVE_STATE", 0x040200),
+    ("IFLA_BOND_SLAVE_UNSPEC", 0x040200),
+    ("IFLA_BOND_TLB_DYNAMIC_LB", 0x040400),
+    ("IFLA_BOND_UNSPEC", 0x040200),
+    ("IFLA_BOND_UPDELAY", 0x040200),
+    ("IFLA_BOND_USE_CARRIER", 0x040200),
+    ("IFLA_BOND_XMIT_HASH_POLICY", 0x040200),
+    ("IFLA_BROADCAST", 0x020620),
+    ("IFLA_BRPORT_BACKUP_PORT", 0x041400),
+    ("IFLA_BRPORT_BCAST_FLOOD", 0x041400),
+    ("IFLA_BRPORT_BRIDGE_ID", 0x040400),
+    ("IFLA_BRPORT_CONFIG_PENDING", 0x040400),
+    ("IFLA_BRPORT_COST", 0x030a00),
+    ("IFLA_BRPORT_DESIGNATED_COST", 0x040400),
+    ("IFLA_BRPORT_DESIGNATED_PORT", 0x040400),
+    ("IFLA_BRPORT_FAST_LEAVE", 0x030a00),
+    ("IFLA_BRPORT_FLUSH", 0x040400),
+    ("IFLA_BRPORT_FORWARD_DELAY_TIMER", 0x040400),
+    ("IFLA_BRPORT_GROUP_FWD_MASK", 0x041400),
+    ("IFLA_BRPORT_GUARD", 0x030a00),
+    ("IFLA_BRPORT_HOLD_TIMER", 0x040400),
+    ("IFLA_BRPORT_ID", 0x040400),
+    ("IFLA_BRPORT_ISOLATED", 0x041400),
+    ("IFLA_BRPORT_LEARNING", 0x040200),
+    ("IFLA_BRPORT_LEARNING_SYNC", 0x040200),
+    ("IFLA_BRPORT_MCAST_FLOOD", 0x041400),
+    ("IFLA_BRPORT_MCAST_TO_UCAST", 0x041400),
+    ("IFLA_BRPORT_MESSAGE_AGE_TIMER", 0x040400),
+    ("IFLA_BRPORT_MODE", 0x030a00),
+    ("IFLA_BRPORT_MRP_IN_OPEN", 0x050b00),
+    ("IFLA_BRPORT_MRP_RING_OPEN", 0x050b00),
+    ("IFLA_BRPORT_MULTICAST_ROUTER", 0x040400),
+    ("IFLA_BRPORT_NEIGH_SUPPRESS", 0x041400),
+    ("IFLA_BRPORT_NO", 0x040400),
+    ("IFLA_BRPORT_PAD", 0x041400),
+    ("IFLA_BRPORT_PRIORITY", 0x030a00),
+    ("IFLA_BRPORT_PROTECT", 0x030a00),
+    ("IFLA_BRPORT_PROXYARP", 0x040200),
+    ("IFLA_BRPORT_PROXYARP_WIFI", 0x040200),
+    ("IFLA_BRPORT_ROOT_ID", 0x040400),
+    ("IFLA_BRPORT_STATE", 0x030a00),
+    ("IFLA_BRPORT_TOPOLOGY_CHANGE_ACK", 0x040400),
+    ("IFLA_BRPORT_UNICAST_FLOOD", 0x040200),
+    ("IFLA_BRPORT_UNSPEC", 0x030a00),
+    ("IFLA_BRPORT_VLAN_TUNNEL", 0x041400),
+    ("IFLA_BR_AGEING_TIME", 0x040200),
+    ("IFLA_BR_BRIDGE_ID", 0x040400),
+    ("IFLA_BR_FDB_FLUSH", 0x040400),
+    ("IFLA_BR_FORWARD_DELAY", 0x040200),
+    ("IFLA_BR_GC_TIMER", 0x040400),
+    ("IFLA_BR_GROUP_ADDR", 0x040400),
+    ("IFLA_BR_GROUP_FWD_MASK", 0x040400),
+    ("IFLA_BR_HELLO_TIME", 0x040200),
+    ("IFLA_BR_HELLO_TIMER", 0x040400),
+    ("IFLA_BR_MAX_AGE", 0x040200),
+    ("IFLA_BR_MCAST_HASH_ELASTICITY", 0x040400),
+    ("IFLA_BR_MCAST_HASH_MAX", 0x040400),
+    ("IFLA_BR_MCAST_IGMP_VERSION", 0x041400),
+    ("IFLA_BR_MCAST_LAST_MEMBER_CNT", 0x040400),
+    ("IFLA_BR_MCAST_LAST_MEMBER_INTVL", 0x040400),
+    ("IFLA_BR_MCAST_MEMBERSHIP_INTVL", 0x040400),
+    ("IFLA_BR_MCAST_MLD_VERSION", 0x041400),
+    ("IFLA_BR_MCAST_QUERIER", 0x040400),
+    ("IFLA_BR_MCAST_QUERIER_INTVL", 0x040400),
+    ("IFLA_BR_MCAST_QUERY_INTVL", 0x040400),
+    ("IFLA_BR_MCAST_QUERY_RESPONSE_INTVL", 0x040400),
+    ("IFLA_BR_MCAST_QUERY_USE_IFADDR", 0x040400),
+    ("IFLA_BR_MCAST_ROUTER", 0x040400),
+    ("IFLA_BR_MCAST_SNOOPING", 0x040400),
+    ("IFLA_BR_MCAST_STARTUP_QUERY_CNT", 0x040400),
+    ("IFLA_BR_MCAST_STARTUP_QUERY_INTVL", 0x040400),
+    ("IFLA_BR_MCAST_STATS_ENABLED", 0x041400),
+    ("IFLA_BR_MULTI_BOOLOPT", 0x050400),
+    ("IFLA_BR_NF_CALL_ARPTABLES", 0x040400),
+    ("IFLA_BR_NF_CALL_IP6TABLES", 0x040400),
+    ("IFLA_BR_NF_CALL_IPTABLES", 0x040400),
+    ("IFLA_BR_PAD", 0x041400),
+    ("IFLA_BR_PRIORITY", 0x040200),
+    ("IFLA_BR_ROOT_ID", 0x040400),
+    ("IFLA_BR_ROOT_PATH_COST", 0x040400),
+    ("IFLA_BR_ROOT_PORT", 0x040400),
+    ("IFLA_BR_STP_STATE", 0x040200),
+    ("IFLA_BR_TCN_TIMER", 0x040400),
+    ("IFLA_BR_TOPOLOGY_CHANGE", 0x040400),
+    ("IFLA_BR_TOPOLOGY_CHANGE_DETECTED", 0x040400),
+    ("IFLA_BR_TOPOLOGY_CHANGE_TIMER", 0x040400),
+    ("IFLA_BR_UNSPEC", 0x040200),
+    ("IFLA_BR_VLAN_DEFAULT_PVID", 0x040400),
+    ("IFLA_BR_VLAN_FILTERING", 0x040400),
+    ("IFLA_BR_VLAN_PROTOCOL", 0x040400),
+    ("IFLA_BR_VLAN_STATS_ENABLED", 0x041400),
+    ("IFLA_BR_VLAN_STATS_PER_PORT", 0x041400),
+    ("IFLA_CARRIER", 0x030a00),
+    ("IFLA_CARRIER_CHANGES", 0x040200),
+    ("IFLA_CARRIER_DOWN_COUNT", 0x041400),
+    ("IFLA_CARRIER_UP_COUNT", 0x041400),
+    ("IFLA_COST", 0x020620),
+    ("IFLA_EVENT", 0x041400),
+    ("IFLA_EVENT_BONDING_FAILOVER", 0x041400),
+    ("IFLA_EVENT_BONDING_OPTIONS", 0x041400),
+    ("IFLA_EVENT_FEATURES", 0x041400),
+    ("IFLA_EVENT_IGMP_RESEND", 0x041400),
+    ("IFLA_EVENT_NONE", 0x041400),
+    ("IFLA_EVENT_NOTIFY_PEERS", 0x041400),
+    ("IFLA_EVENT_REBOOT", 0x041400),
+    ("IFLA_EXT_MASK", 0x030a00),
+    ("IFLA_GENEVE_COLLECT_METADATA", 0x040400),
+    ("IFLA_GENEVE_DF", 0x050400),
+    ("IFLA_GENEVE_ID", 0x040200),
+    ("IFLA_GENEVE_LABEL", 0x041400),
+    ("IFLA_GENEVE_PORT", 0x040400),
+    ("IFLA_GENEVE_REMOTE", 0x040200),
+    ("IFLA_GENEVE_REMOTE6", 0x040400),
+    ("IFLA_GENEVE_TOS", 0x040200),
+    ("IFLA_GENEVE_TTL", 0x040200),
+    ("IFLA_GENEVE_TTL_INHERIT", 0x041400),
+    ("IFLA_GENEVE_UDP_CSUM", 0x041400),
+    ("IFLA_GENEVE_UDP_ZERO_CSUM6_RX", 0x041400),
+    ("IFLA_GENEVE_UDP_ZERO_CSUM6_TX", 0x041400),
+    ("IFLA_GENEVE_UNSPEC", 0x040200),
+    ("IFLA_GROUP", 0x030200),
+    ("IFLA_GSO_MAX_SEGS", 0x041400),
+    ("IFLA_GSO_MAX_SIZE", 0x041400),
+    ("IFLA_GTP_FD0", 0x041400),
+    ("IFLA_GTP_FD1", 0x041400),
+    ("IFLA_GTP_PDP_HASHSIZE", 0x041400),
+    ("IFLA_GTP_ROLE", 0x041400),
+    ("IFLA_GTP_UNSPEC", 0x041400),
+    ("IFLA_HSR_MULTICAST_SPEC", 0x040200),
+    ("IFLA_HSR_PROTOCOL", 0x050b00),
+    ("IFLA_HSR_SEQ_NR", 0x040200),
+    ("IFLA_HSR_SLAVE1", 0x040200),
+    ("IFLA_HSR_SLAVE2", 0x040200),
+    ("IFLA_HSR_SUPERVISION_ADDR", 0x040200),
+    ("IFLA_HSR_UNSPEC", 0x040200),
+    ("IFLA_HSR_VERSION", 0x041400),
+    ("IFLA_IFALIAS", 0x020620),
+    ("IFLA_IFNAME", 0x020620),
+    ("IFLA_IF_NETNSID", 0x041400),
+    ("IFLA_INET6_ADDR_GEN_MODE", 0x040200),
+    ("IFLA_INET6_CACHEINFO", 0x020620),
+    ("IFLA_INET6_CONF", 0x020620),
+    ("IFLA_INET6_FLAGS", 0x020620),
+    ("IFLA_INET6_ICMP6STATS", 0x020620),
+    ("IFLA_INET6_MCAST", 0x020620),
+    ("IFLA_INET6_STATS", 0x020620),
+    ("IFLA_INET6_TOKEN", 0x030a00),
+    ("IFLA_INET6_UNSPEC", 0x020620),
+    ("IFLA_INET_CONF", 0x030200),
+    ("IFLA_INET_UNSPEC", 0x030200),
+    ("IFLA_INFO_DATA", 0x020620),
+    ("IFLA_INFO_KIND", 0x020620),
+    ("IFLA_INFO_SLAVE_DATA", 0x040200),
+    ("IFLA_INFO_SLAVE_KIND", 0x040200),
+    ("IFLA_INFO_UNSPEC", 0x020620),
+    ("IFLA_INFO_XSTATS", 0x020620),
+    ("IFLA_IPOIB_MODE", 0x030a00),
+    ("IFLA_IPOIB_PKEY", 0x030a00),
+    ("IFLA_IPOIB_UMCAST", 0x030a00),
+    ("IFLA_IPOIB_UNSPEC", 0x030a00),
+    ("IFLA_IPVLAN_FLAGS", 0x041400),
+    ("IFLA_IPVLAN_MODE", 0x040200),
+    ("IFLA_IPVLAN_UNSPEC", 0x040200),
+    ("IFLA_LINK", 0x020620),
+    ("IFLA_LINKINFO", 0x020620),
+    ("IFLA_LINKMODE", 0x020620),
+    ("IFLA_LINK_NETNSID", 0x040200),
+    ("IFLA_MACSEC_CIPHER_SUITE", 0x041400),
+    ("IFLA_MACSEC_ENCODING_SA", 0x041400),
+    ("IFLA_MACSEC_ENCRYPT", 0x041400),
+    ("IFLA_MACSEC_ES", 0x041400),
+    ("IFLA_MACSEC_ICV_LEN", 0x041400),
+    ("IFLA_MACSEC_INC_SCI", 0x041400),
+    ("IFLA_MACSEC_OFFLOAD", 0x050b00),
+    ("IFLA_MACSEC_PAD", 0x041400),
+    ("IFLA_MACSEC_PORT", 0x041400),
+    ("IFLA_MACSEC_PROTECT", 0x041400),
+    ("IFLA_MACSEC_REPLAY_PROTECT", 0x041400),
+    ("IFLA_MACSEC_SCB", 0x041400),
+    ("IFLA_MACSEC_SCI", 0x041400),
+    ("IFLA_MACSEC_UNSPEC", 0x041400),
+    ("IFLA_MACSEC_VALIDATION", 0x041400),
+    ("IFLA_MACSEC_WINDOW", 0x041400),
+    ("IFLA_MACVLAN_BC_QUEUE_LEN", 0x050b00),
+    ("IFLA_MACVLAN_BC_QUEUE_LEN_USED", 0x050b00),
+    ("IFLA_MACVLAN_FLAGS", 0x030a00),
+    ("IFLA_MACVLAN_MACADDR", 0x040200),
+    ("IFLA_MACVLAN_MACADDR_COUNT", 0x040200),
+    ("IFLA_MACVLAN_MACADDR_DATA", 0x040200),
+    ("IFLA_MACVLAN_MACADDR_MODE", 0x040200),
+    ("IFLA_MACVLAN_MODE", 0x030200),
+    ("IFLA_MACVLAN_UNSPEC", 0x030200),
+    ("IFLA_MAP", 0x020620),
+    ("IFLA_MASTER", 0x020620),
+    ("IFLA_MAX_MTU", 0x041400),
+    ("IFLA_MIN_MTU", 0x041400),
+    ("IFLA_MTU", 0x020620),
+    ("IFLA_NET_NS_FD", 0x030200),
+    ("IFLA_NET_NS_PID", 0x020620),
+    ("IFLA_NEW_IFINDEX", 0x041400),
+    ("IFLA_NEW_NETNSID", 0x041400),
+    ("IFLA_NUM_RX_QUEUES", 0x030a00),
+    ("IFLA_NUM_TX_QUEUES", 0x030a00),
+    ("IFLA_NUM_VF", 0x030200),
+    ("IFLA_OFFLOAD_XSTATS_CPU_HIT", 0x041400),
+    ("IFLA_OFFLOAD_XSTATS_UNSPEC", 0x041400),
+    ("IFLA_OPERSTATE", 0x020620),
+    ("IFLA_PAD", 0x041400),
+    ("IFLA_PERM_ADDRESS", 0x050b00),
+    ("IFLA_PHYS_PORT_ID", 0x040200),
+    ("IFLA_PHYS_PORT_NAME", 0x040200),
+    ("IFLA_PHYS_SWITCH_ID", 0x040200),
+    ("IFLA_PORT_HOST_UUID", 0x030200),
+    ("IFLA_PORT_INSTANCE_UUID", 0x030200),
+    ("IFLA_PORT_PROFILE", 0x030200),
+    ("IFLA_PORT_REQUEST", 0x030200),
+    ("IFLA_PORT_RESPONSE", 0x030200),
+    ("IFLA_PORT_SELF", 0x030200),
+    ("IFLA_PORT_UNSPEC", 0x030200),
+    ("IFLA_PORT_VF", 0x030200),
+    ("IFLA_PORT_VSI_TYPE", 0x030200),
+    ("IFLA_PPP_DEV_FD", 0x041400),
+    ("IFLA_PPP_UNSPEC", 0x041400),
+    ("IFLA_PRIORITY", 0x020620),
+    ("IFLA_PROMISCUITY", 0x030a00),
+    ("IFLA_PROP_LIST", 0x050b00),
+    ("IFLA_PROTINFO", 0x020620),
+    ("IFLA_PROTO_DOWN", 0x040400),
+    ("IFLA_PROTO_DOWN_REASON", 0x050b00),
+    ("IFLA_PROTO_DOWN_REASON_MASK", 0x050b00),
+    ("IFLA_PROTO_DOWN_REASON_MAX", 0x050b00),
+    ("IFLA_PROTO_DOWN_REASON_UNSPEC", 0x050b00),
+    ("IFLA_PROTO_DOWN_REASON_VALUE", 0x050b00),
+    ("IFLA_QDISC", 0x020620),
+    ("IFLA_RMNET_FLAGS", 0x041400),
+    ("IFLA_RMNET_MUX_ID", 0x041400),
+    ("IFLA_RMNET_UNSPEC", 0x041400),
+    ("IFLA_STATS", 0x020620),
+    ("IFLA_STATS64", 0x030200),
+    ("IFLA_STATS_AF_SPEC", 0x041400),
+    ("IFLA_STATS_LINK_64", 0x041400),
+    ("IFLA_STATS_LINK_OFFLOAD_XSTATS", 0x041400),
+    ("IFLA_STATS_LINK_XSTATS", 0x041400),
+    ("IFLA_STATS_LINK_XSTATS_SLAVE", 0x041400),
+    ("IFLA_STATS_UNSPEC", 0x041400),
+    ("IFLA_TARGET_NETNSID", 0x041400),
+    ("IFLA_TUN_GROUP", 0x041400),
+    ("IFLA_TUN_MULTI_QUEUE", 0x041400),
+    ("IFLA_TUN_NUM_DISABLED_QUEUES", 0x041400),
+    ("IFLA_TUN_NUM_QUEUES", 0x041400),
+    ("IFLA_TUN_OWNER", 0x041400),
+    ("IFLA_TUN_PERSIST", 0x041400),
+    ("IFLA_TUN_PI", 0x041400),
+    ("IFLA_TUN_TYPE", 0x041400),
+    ("IFLA_TUN_UNSPEC", 0x041400),
+    ("IFLA_TUN_VNET_HDR", 0x041400),
+    ("IFLA_TXQLEN", 0x020620),
+    ("IFLA_UNSPEC", 0x020620),
+    ("IFLA_VFINFO_LIST", 0x030200),
+    ("IFLA_VF_BROADCAST", 0x050400),
+    ("IFLA_VF_IB_NODE_GUID", 0x041400),
+    ("IFLA_VF_IB_PORT_GUID", 0x041400),
+    ("IFLA_VF_INFO", 0x030200),
+    ("IFLA_VF_INFO_UNSPEC", 0x030200),
+    ("IFLA_VF_LINK_STATE", 0x040200),
+    ("IFLA_VF_LINK_STATE_AUTO", 0x040200),
+    ("IFLA_VF_LINK_STATE_DISABLE", 0x040200),
+    ("IFLA_VF_LINK_STATE_ENABLE", 0x040200),
+    ("IFLA_VF_MAC", 0x030200),
+    ("IFLA_VF_PORT", 0x030200),
+    ("IFLA_VF_PORTS", 0x030200),
+    ("IFLA_VF_PORT_UNSPEC", 0x030200),
+    ("IFLA_VF_RATE", 0x040200),
+    ("IFLA_VF_RSS_QUERY_EN", 0x040200),
+    ("IFLA_VF_SPOOFCHK", 0x030200),
+    ("IFLA_VF_STATS", 0x040200),
+    ("IFLA_VF_STATS_BROADCAST", 0x040200),
+    ("IFLA_VF_STATS_MULTICAST", 0x040200),
+    ("IFLA_VF_STATS_PAD", 0x041400),
+    ("IFLA_VF_STATS_RX_BYTES", 0x040200),
+    ("IFLA_VF_STATS_RX_DROPPED", 0x041400),
+    ("IFLA_VF_STATS_RX_PACKETS", 0x040200),
+    ("IFLA_VF_STATS_TX_BYTES", 0x040200),
+    ("IFLA_VF_STATS_TX_DROPPED", 0x041400),
+    ("IFLA_VF_STATS_TX_PACKETS", 0x040200),
+    ("IFLA_VF_TRUST", 0x040400),
+    ("IFLA_VF_TX_RATE", 0x030200),
+    ("IFLA_VF_UNSPEC", 0x030200),
+    ("IFLA_VF_VLAN", 0x030200),
+    ("IFLA_VF_VLAN_INFO", 0x041400),
+    ("IFLA_VF_VLAN_INFO_UNSPEC", 0x041400),
+    ("IFLA_VF_VLAN_LIST", 0x041400),
+    ("IFLA_VLAN_EGRESS_QOS", 0x020620),
+    ("IFLA_VLAN_FLAGS", 0x020620),
+    ("IFLA_VLAN_ID", 0x020620),
+    ("IFLA_VLAN_INGRESS_QOS", 0x020620),
+    ("IFLA_VLAN_PROTOCOL", 0x030a00),
+    ("IFLA_VLAN_QOS_MAPPING", 0x020620),
+    ("IFLA_VLAN_QOS_UNSPEC", 0x020620),
+    ("IFLA_VLAN_UNSPEC", 0x020620),
+    ("IFLA_VRF_PORT_TABLE", 0x041400),
+    ("IFLA_VRF_PORT_UNSPEC", 0x041400),
+    ("IFLA_VRF_TABLE", 0x040400),
+    ("IFLA_VRF_UNSPEC", 0x040400),
+    ("IFLA_VXLAN_AGEING", 0x030a00),
+    ("IFLA_VXLAN_COLLECT_METADATA", 0x040400),
+    ("IFLA_VXLAN_DF", 0x050400),
+    ("IFLA_VXLAN_GBP", 0x040200),
+    ("IFLA_VXLAN_GPE", 0x041400),
+    ("IFLA_VXLAN_GROUP", 0x030a00),
+    ("IFLA_VXLAN_GROUP6", 0x040200),
+    ("IFLA_VXLAN_ID", 0x030a00),
+    ("IFLA_VXLAN_L2MISS", 0x030a00),
+    ("IFLA_VXLAN_L3MISS", 0x030a00),
+    ("IFLA_VXLAN_LABEL", 0x041400),
+    ("IFLA_VXLAN_LEARNING", 0x030a00),
+    ("IFLA_VXLAN_LIMIT", 0x030a00),
+    ("IFLA_VXLAN_LINK", 0x030a00),
+    ("IFLA_VXLAN_LOCAL", 0x030a00),
+    ("IFLA_VXLAN_LOCAL6", 0x040200),
+    ("IFLA_VXLAN_PORT", 0x030a00),
+    ("IFLA_VXLAN_PORT_RANGE", 0x030a00),
+    ("IFLA_VXLAN_PROXY", 0x030a00),
+    ("IFLA_VXLAN_REMCSUM_NOPARTIAL", 0x040200),
+    ("IFLA_VXLAN_REMCSUM_RX", 0x040200),
+    ("IFLA_VXLAN_REMCSUM_TX", 0x040200),
+    ("IFLA_VXLAN_RSC", 0x030a00),
+    ("IFLA_VXLAN_TOS", 0x030a00),
+    ("IFLA_VXLAN_TTL", 0x030a00),
+    ("IFLA_VXLAN_TTL_INHERIT", 0x041400),
+    ("IFLA_VXLAN_UDP_CSUM", 0x040200),
+    ("IFLA_VXLAN_UDP_ZERO_CSUM6_RX", 0x040200),
+    ("IFLA_VXLAN_UDP_ZERO_CSUM6_TX", 0x040200),
+    ("IFLA_VXLAN_UNSPEC", 0x030a00),
+    ("IFLA_WEIGHT", 0x020620),
+    ("IFLA_WIRELESS", 0x020620),
+    ("IFLA_XDP", 0x041400),
+    ("IFLA_XDP_ATTACHED", 0x041400),
+    ("IFLA_XDP_DRV_PROG_ID", 0x041400),
+    ("IFLA_XDP_EXPECTED_FD", 0x050b00),
+    ("IFLA_XDP_FD", 0x041400),
+    ("IFLA_XDP_FLAGS", 0x041400),
+    ("IFLA_XDP_HW_PROG_ID", 0x041400),
+    ("IFLA_XDP_PROG_ID", 0x041400),
+    ("IFLA_XDP_SKB_PROG_ID", 0x041400),
+    ("IFLA_XDP_UNSPEC", 0x041400),
+    ("IFLA_XFRM_IF_ID", 0x041400),
+    ("IFLA_XFRM_LINK", 0x041400),
+    ("IFLA_XFRM_UNSPEC", 0x041400),
+    ("IPOIB_MODE_CONNECTED", 0x030a00),
+    ("IPOIB_MODE_DATAGRAM", 0x030a00),
+    ("IPVLAN_F_PRIVATE", 0x041400),
+    ("IPVLAN_F_VEPA", 0x041400),
+    ("LINK_XSTATS_TYPE_BOND", 0x050400),
+    ("LINK_XSTATS_TYPE_BRIDGE", 0x041400),
+    ("LINK_XSTATS_TYPE_UNSPEC", 0x041400),
+    ("LINUX_VERSION_CODE", 0x020620),
+    ("MACSEC_OFFLOAD_MAX", 0x050b00),
+    ("MACSEC_VALIDATE_MAX", 0x041400),
+    ("MACVLAN_FLAG_NOPROMISC", 0x030a00),
+    ("MAX_LINKS", 0x020620),
+    ("MAX_VLAN_LIST_LEN", 0x041400),
+    ("NDA_CACHEINFO", 0x020620),
+    ("NDA_DST", 0x020620),
+    ("NDA_FDB_EXT_ATTRS", 0x050b00),
+    ("NDA_IFINDEX", 0x030a00),
+    ("NDA_LINK_NETNSID", 0x040200),
+    ("NDA_LLADDR", 0x020620),
+    ("NDA_MASTER", 0x040200),
+    ("NDA_NH_ID", 0x050b00),
+    ("NDA_PORT", 0x030a00),
+    ("NDA_PROBES", 0x020620),
+    ("NDA_PROTOCOL", 0x050400),
+    ("NDA_SRC_VNI", 0x041400),
+    ("NDA_UNSPEC", 0x020620),
+    ("NDA_VLAN", 0x030a00),
+    ("NDA_VNI", 0x030a00),
+    ("NDTA_CONFIG", 0x020620),
+    ("NDTA_GC_INTERVAL", 0x020620),
+    ("NDTA_NAME", 0x020620),
+    ("NDTA_PAD", 0x041400),
+    ("NDTA_PARMS", 0x020620),
+    ("NDTA_STATS", 0x020620),
+    ("NDTA_THRESH1", 0x020620),
+    ("NDTA_THRESH2", 0x020620),
+    ("NDTA_THRESH3", 0x020620),
+    ("NDTA_UNSPEC", 0x020620),
+    ("NDTPA_ANYCAST_DELAY", 0x020620),
+    ("NDTPA_APP_PROBES", 0x020620),
+    ("NDTPA_BASE_REACHABLE_TIME", 0x020620),
+    ("NDTPA_DELAY_PROBE_TIME", 0x020620),
+    ("NDTPA_GC_STALETIME", 0x020620),
+    ("NDTPA_IFINDEX", 0x020620),
+    ("NDTPA_LOCKTIME", 0x020620),
+    ("NDTPA_MCAST_PROBES", 0x020620),
+    ("NDTPA_MCAST_REPROBES", 0x040200),
+    ("NDTPA_PAD", 0x041400),
+    ("NDTPA_PROXY_DELAY", 0x020620),
+    ("NDTPA_PROXY_QLEN", 0x020620),
+    ("NDTPA_QUEUE_LEN", 0x020620),
+    ("NDTPA_QUEUE_LENBYTES", 0x030a00),
+    ("NDTPA_REACHABLE_TIME", 0x020620),
+    ("NDTPA_REFCNT", 0x020620),
+    ("NDTPA_RETRANS_TIME", 0x020620),
+    ("NDTPA_UCAST_PROBES", 0x020620),
+    ("NDTPA_UNSPEC", 0x020620),
+    ("NDUSEROPT_SRCADDR", 0x020620),
+    ("NDUSEROPT_UNSPEC", 0x020620),
+    ("NETLINK_ADD_MEMBERSHIP", 0x020620),
+    ("NETLINK_AUDIT", 0x020620),
+    ("NETLINK_BROADCAST_ERROR", 0x020620),
+    ("NETLINK_CAP_ACK", 0x040400),
+    ("NETLINK_CONNECTED", 0x020620),
+    ("NETLINK_CONNECTOR", 0x020620),
+    ("NETLINK_CRYPTO", 0x030200),
+    ("NETLINK_DNRTMSG", 0x020620),
+    ("NETLINK_DROP_MEMBERSHIP", 0x020620),
+    ("NETLINK_ECRYPTFS", 0x020620),
+    ("NETLINK_EXT_ACK", 0x041400),
+    ("NETLINK_FIB_LOOKUP", 0x020620),
+    ("NETLINK_FIREWALL", 0x020620),
+    ("NETLINK_GENERIC", 0x020620),
+    ("NETLINK_GET_STRICT_CHK", 0x041400),
+    ("NETLINK_INET_DIAG", 0x020620),
+    ("NETLINK_IP6_FW", 0x020620),
+    ("NETLINK_ISCSI", 0x020620),
+    ("NETLINK_KOBJECT_UEVENT", 0x020620),
+    ("NETLINK_LISTEN_ALL_NSID", 0x040200),
+    ("NETLINK_LIST_MEMBERSHIPS", 0x040200),
+    ("NETLINK_NETFILTER", 0x020620),
+    ("NETLINK_NFLOG", 0x020620),
+    ("NETLINK_NO_ENOBUFS", 0x020620),
+    ("NETLINK_PKTINFO", 0x020620),
+    ("NETLINK_RDMA", 0x030200),
+    ("NETLINK_ROUTE", 0x020620),
+    ("NETLINK_RX_RING", 0x030a00),
+    ("NETLINK_SCSITRANSPORT", 0x020620),
+    ("NETLINK_SELINUX", 0x020620),
+    ("NETLINK_SMC", 0x041400),
+    ("NETLINK_SOCK_DIAG", 0x030a00),
+    ("NETLINK_TX_RING", 0x030a00),
+    ("NETLINK_UNCONNECTED", 0x020620),
+    ("NETLINK_UNUSED", 0x020620),
+    ("NETLINK_USERSOCK", 0x020620),
+    ("NETLINK_XFRM", 0x020620),
+    ("NET_MAJOR", 0x020620),
+    ("NFEA_ACTIVITY_NOTIFY", 0x050b00),
+    ("NFEA_DONT_REFRESH", 0x050b00),
+    ("NFEA_UNSPEC", 0x050b00),
+    ("NLA_ALIGNTO", 0x020620),
+    ("NLA_F_NESTED", 0x020620),
+    ("NLA_F_NET_BYTEORDER", 0x020620),
+    ("NLA_TYPE_MASK", 0x020620),
+    ("NLMSGERR_ATTR_MAX", 0x041400),
+    ("NLMSG_ALIGNTO", 0x020620),
+    ("NLMSG_DONE", 0x020620),
+    ("NLMSG_ERROR", 0x020620),
+    ("NLMSG_MIN_TYPE", 0x020620),
+    ("NLMSG_NOOP", 0x020620),
+    ("NLMSG_OVERRUN", 0x020620),
+    ("NLM_F_ACK", 0x020620),
+    ("NLM_F_ACK_TLVS", 0x041400),
+    ("NLM_F_APPEND", 0x020620),
+    ("NLM_F_ATOMIC", 0x020620),
+    ("NLM_F_CAPPED", 0x041400),
+    ("NLM_F_CREATE", 0x020620),
+    ("NLM_F_DUMP", 0x020620),
+    ("NLM_F_DUMP_FILTERED", 0x040400),
+    ("NLM_F_DUMP_INTR", 0x030200),
+    ("NLM_F_ECHO", 0x020620),
+    ("NLM_F_EXCL", 0x020620),
+    ("NLM_F_MATCH", 0x020620),
+    ("NLM_F_MULTI", 0x020620),
+    ("NLM_F_NONREC", 0x041400),
+    ("NLM_F_REPLACE", 0x020620),
+    ("NLM_F_REQUEST", 0x020620),
+    ("NLM_F_ROOT", 0x020620),
+    ("NL_MMAP_MSG_ALIGNMENT", 0x030a00),
+    ("NL_POLICY_TYPE_ATTR_MAX", 0x050b00),
+    ("NTF_EXT_LEARNED", 0x040200),
+    ("NTF_MASTER", 0x030a00),
+    ("NTF_OFFLOADED", 0x041400),
+    ("NTF_PROXY", 0x020620),
+    ("NTF_ROUTER", 0x020620),
+    ("NTF_SELF", 0x030a00),
+    ("NTF_STICKY", 0x041400),
+    ("NTF_USE", 0x020620),
+    ("NUD_DELAY", 0x020620),
+    ("NUD_FAILED", 0x020620),
+    ("NUD_INCOMPLETE", 0x020620),
+    ("NUD_NOARP", 0x020620),
+    ("NUD_NONE", 0x020620),
+    ("NUD_PERMANENT", 0x020620),
+    ("NUD_PROBE", 0x020620),
+    ("NUD_REACHABLE", 0x020620),
+    ("NUD_STALE", 0x020620),
+    ("PORT_PROFILE_MAX", 0x030200),
+    ("PORT_PROFILE_RESPONSE_BADSTATE", 0x030200),
+    ("PORT_PROFILE_RESPONSE_ERROR", 0x030200),
+    ("PORT_PROFILE_RESPONSE_INPROGRESS", 0x030200),
+    ("PORT_PROFILE_RESPONSE_INSUFFICIENT_RESOURCES", 0x030200),
+    ("PORT_PROFILE_RESPONSE_INVALID", 0x030200),
+    ("PORT_PROFILE_RESPONSE_SUCCESS", 0x030200),
+    ("PORT_REQUEST_ASSOCIATE", 0x030200),
+    ("PORT_REQUEST_DISASSOCIATE", 0x030200),
+    ("PORT_REQUEST_PREASSOCIATE", 0x030200),
+    ("PORT_REQUEST_PREASSOCIATE_RR", 0x030200),
+    ("PORT_SELF_VF", 0x030200),
+    ("PORT_UUID_MAX", 0x030200),
+    ("PORT_VDP_RESPONSE_INSUFFICIENT_RESOURCES", 0x030200),
+    ("PORT_VDP_RESPONSE_INVALID_FORMAT", 0x030200),
+    ("PORT_VDP_RESPONSE_OUT_OF_SYNC", 0x030200),
+    ("PORT_VDP_RESPONSE_SUCCESS", 0x030200),
+    ("PORT_VDP_RESPONSE_UNUSED_VTID", 0x030200),
+    ("PORT_VDP_RESPONSE_VTID_VERSION_VIOALTION", 0x030200),
+    ("PORT_VDP_RESPONSE_VTID_VIOLATION", 0x030200),
+    ("PREFIX_ADDRESS", 0x020620),
+    ("PREFIX_CACHEINFO", 0x020620),
+    ("PREFIX_UNSPEC", 0x020620),
+    ("RMNET_FLAGS_EGRESS_MAP_CKSUMV4", 0x041400),
+    ("RMNET_FLAGS_INGRESS_DEAGGREGATION", 0x041400),
+    ("RMNET_FLAGS_INGRESS_MAP_CKSUMV4", 0x041400),
+    ("RMNET_FLAGS_INGRESS_MAP_COMMANDS", 0x041400),
+    ("RTAX_ADVMSS", 0x020620),
+    ("RTAX_CC_ALGO", 0x040200),
+    ("RTAX_CWND", 0x020620),
+    ("RTAX_FASTOPEN_NO_COOKIE", 0x041400),
+    ("RTAX_FEATURES", 0x020620),
+    ("RTAX_FEATURE_ALLFRAG", 0x020620),
+    ("RTAX_FEATURE_ECN", 0x020620),
+    ("RTAX_FEATURE_MASK", 0x040400),
+    ("RTAX_FEATURE_SACK", 0x020620),
+    ("RTAX_FEATURE_TIMESTAMP", 0x020620),
+    ("RTAX_HOPLIMIT", 0x020620),
+    ("RTAX_INITCWND", 0x020620),
+    ("RTAX_INITRWND", 0x030200),
+    ("RTAX_LOCK", 0x020620),
+    ("RTAX_MTU", 0x020620),
+    ("RTAX_QUICKACK", 0x040200),
+    ("RTAX_REORDERING", 0x020620),
+    ("RTAX_RTO_MIN", 0x020620),
+    ("RTAX_RTT", 0x020620),
+    ("RTAX_RTTVAR", 0x020620),
+    ("RTAX_SSTHRESH", 0x020620),
+    ("RTAX_UNSPEC", 0x020620),
+    ("RTAX_WINDOW", 0x020620),
+    ("RTA_ALIGNTO", 0x020620),
+    ("RTEXT_FILTER_BRVLAN", 0x030a00),
+    ("RTEXT_FILTER_BRVLAN_COMPRESSED", 0x040200),
+    ("RTEXT_FILTER_CFM_CONFIG", 0x050b00),
+    ("RTEXT_FILTER_CFM_STATUS", 0x050b00),
+    ("RTEXT_FILTER_MRP", 0x050b00),
+    ("RTEXT_FILTER_SKIP_STATS", 0x040400),
+    ("RTEXT_FILTER_VF", 0x030a00),
+    ("RTMGRP_DECnet_IFADDR", 0x020620),
+    ("RTMGRP_DECnet_ROUTE", 0x020620),
+    ("RTMGRP_IPV4_IFADDR", 0x020620),
+    ("RTMGRP_IPV4_MROUTE", 0x020620),
+    ("RTMGRP_IPV4_ROUTE", 0x020620),
+    ("RTMGRP_IPV4_RULE", 0x020620),
+    ("RTMGRP_IPV6_IFADDR", 0x020620),
+    ("RTMGRP_IPV6_IFINFO", 0x020620),
+    ("RTMGRP_IPV6_MROUTE", 0x020620),
+    ("RTMGRP_IPV6_PREFIX", 0x020620),
+    ("RTMGRP_IPV6_ROUTE", 0x020620),
+    ("RTMGRP_LINK", 0x020620),
+    ("RTMGRP_NEIGH", 0x020620),
+    ("RTMGRP_NOTIFY", 0x020620),
+    ("RTMGRP_TC", 0x020620),
+    ("RTM_BASE", 0x020620),
+    ("RTM_DELACTION", 0x020620),
+    ("RTM_DELADDR", 0x020620),
+    ("RTM_DELADDRLABEL", 0x020620),
+    ("RTM_DELCHAIN", 0x041400),
+    ("RTM_DELLINK", 0x020620),
+    ("RTM_DELLINKPROP", 0x050b00),
+    ("RTM_DELMDB", 0x030a00),
+    ("RTM_DELNEIGH", 0x020620),
+    ("RTM_DELNETCONF", 0x041400),
+    ("RTM_DELNEXTHOP", 0x050400),
+    ("RTM_DELNSID", 0x040200),
+    ("RTM_DELQDISC", 0x020620),
+    ("RTM_DELROUTE", 0x020620),
+    ("RTM_DELRULE", 0x020620),
+    ("RTM_DELTCLASS", 0x020620),
+    ("RTM_DELTFILTER", 0x020620),
+    ("RTM_DELVLAN", 0x050b00),
+    ("RTM_F_CLONED", 0x020620),
+    ("RTM_F_EQUALIZE", 0x020620),
+    ("RTM_F_FIB_MATCH", 0x041400),
+    ("RTM_F_LOOKUP_TABLE", 0x040400),
+    ("RTM_F_NOTIFY", 0x020620),
+    ("RTM_F_OFFLOAD", 0x050b00),
+    ("RTM_F_PREFIX", 0x020620),
+    ("RTM_F_TRAP", 0x050b00),
+    ("RTM_GETACTION", 0x020620),
+    ("RTM_GETADDR", 0x020620),
+    ("RTM_GETADDRLABEL", 0x020620),
+    ("RTM_GETANYCAST", 0x020620),
+    ("RTM_GETCHAIN", 0x041400),
+    ("RTM_GETDCB", 0x020620),
+    ("RTM_GETLINK", 0x020620),
+    ("RTM_GETLINKPROP", 0x050b00),
+    ("RTM_GETMDB", 0x030a00),
+    ("RTM_GETMULTICAST", 0x020620),
+    ("RTM_GETNEIGH", 0x020620),
+    ("RTM_GETNEIGHTBL", 0x020620),
+    ("RTM_GETNETCONF", 0x030a00),
+    ("RTM_GETNEXTHOP", 0x050400),
+    ("RTM_GETNSID", 0x040200),
+    ("RTM_GETQDISC", 0x020620),
+    ("RTM_GETROUTE", 0x020620),
+    ("RTM_GETRULE", 0x020620),
+    ("RTM_GETSTATS", 0x041400),
+    ("RTM_GETTCLASS", 0x020620),
+    ("RTM_GETTFILTER", 0x020620),
+    ("RTM_GETVLAN", 0x050b00),
+    ("RTM_NEWACTION", 0x020620),
+    ("RTM_NEWADDR", 0x020620),
+    ("RTM_NEWADDRLABEL", 0x020620),
+    ("RTM_NEWCACHEREPORT", 0x041400),
+    ("RTM_NEWCHAIN", 0x041400),
+    ("RTM_NEWLINK", 0x020620),
+    ("RTM_NEWLINKPROP", 0x050b00),
+    ("RTM_NEWMDB", 0x030a00),
+    ("RTM_NEWNDUSEROPT", 0x020620),
+    ("RTM_NEWNEIGH", 0x020620),
+    ("RTM_NEWNEIGHTBL", 0x020620),
+    ("RTM_NEWNETCONF", 0x030a00),
+    ("RTM_NEWNEXTHOP", 0x050400),
+    ("RTM_NEWNSID", 0x040200),
+    ("RTM_NEWPREFIX", 0x020620),
+    ("RTM_NEWQDISC", 0x020620),
+    ("RTM_NEWROUTE", 0x020620),
+    ("RTM_NEWRULE", 0x020620),
+    ("RTM_NEWSTATS", 0x041400),
+    ("RTM_NEWTCLASS", 0x020620),
+    ("RTM_NEWTFILTER", 0x020620),
+    ("RTM_NEWVLAN", 0x050b00),
+    ("RTM_SETDCB", 0x020620),
+    ("RTM_SETLINK", 0x020620),
+    ("RTM_SETNEIGHTBL", 0x020620),
+    ("RTNETLINK_HAVE_PEERINFO", 0x020620),
+    ("RTNH_ALIGNTO", 0x020620),
+    ("RTNH_COMPARE_MASK", 0x040200),
+    ("RTNH_F_DEAD", 0x020620),
+    ("RTNH_F_LINKDOWN", 0x040200),
+    ("RTNH_F_OFFLOAD", 0x040200),
+    ("RTNH_F_ONLINK", 0x020620),
+    ("RTNH_F_PERVASIVE", 0x020620),
+    ("RTNH_F_TRAP", 0x050b00),
+    ("RTNH_F_UNRESOLVED", 0x041400),
+    ("RTNL_FAMILY_IP6MR", 0x030200),
+    ("RTNL_FAMILY_IPMR", 0x030200),
+    ("RTNL_FAMILY_MAX", 0x030200),
+    ("RTN_ANYCAST", 0x020620),
+    ("RTN_BLACKHOLE", 0x020620),
+    ("RTN_BROADCAST", 0x020620),
+    ("RTN_LOCAL", 0x020620),
+    ("RTN_MULTICAST", 0x020620),
+    ("RTN_NAT", 0x020620),
+    ("RTN_PROHIBIT", 0x020620),
+    ("RTN_THROW", 0x020620),
+    ("RTN_UNICAST", 0x020620),
+    ("RTN_UNREACHABLE", 0x020620),
+    ("RTN_UNSPEC", 0x020620),
+    ("RTN_XRESOLVE", 0x020620),
+    ("RTPROT_BABEL", 0x040200),
+    ("RTPROT_BGP", 0x041400),
+    ("RTPROT_BIRD", 0x020620),
+    ("RTPROT_BOOT", 0x020620),
+    ("RTPROT_DHCP", 0x020620),
+    ("RTPROT_DNROUTED", 0x020620),
+    ("RTPROT_EIGRP", 0x041400),
+    ("RTPROT_GATED", 0x020620),
+    ("RTPROT_ISIS", 0x041400),
+    ("RTPROT_KEEPALIVED", 0x050b00),
+    ("RTPROT_KERNEL", 0x020620),
+    ("RTPROT_MROUTED", 0x030a00),
+    ("RTPROT_MRT", 0x020620),
+    ("RTPROT_NTK", 0x020620),
+    ("RTPROT_OSPF", 0x041400),
+    ("RTPROT_RA", 0x020620),
+    ("RTPROT_REDIRECT", 0x020620),
+    ("RTPROT_RIP", 0x041400),
+    ("RTPROT_STATIC", 0x020620),
+    ("RTPROT_UNSPEC", 0x020620),
+    ("RTPROT_XORP", 0x020620),
+    ("RTPROT_ZEBRA", 0x020620),
+    ("SI_LOAD_SHIFT", 0x030a00),
+    ("TCAA_MAX", 0x020620),
+    ("TCA_ACT_FLAG_LARGE_DUMP_ON", 0x050b00),
+    ("TCA_ACT_FLAG_TERSE_DUMP", 0x050b00),
+    ("TCA_ACT_TAB", 0x020620),
+    ("TCA_CHAIN", 0x041400),
+    ("TCA_DUMP_FLAGS", 0x050b00),
+    ("TCA_DUMP_FLAGS_TERSE", 0x050b00),
+    ("TCA_DUMP_INVISIBLE", 0x041400),
+    ("TCA_EGRESS_BLOCK", 0x041400),
+    ("TCA_FCNT", 0x020620),
+    ("TCA_FLAG_LARGE_DUMP_ON", 0x041400),
+    ("TCA_HW_OFFLOAD", 0x041400),
+    ("TCA_INGRESS_BLOCK", 0x041400),
+    ("TCA_KIND", 0x020620),
+    ("TCA_OPTIONS", 0x020620),
+    ("TCA_PAD", 0x041400),
+    ("TCA_RATE", 0x020620),
+    ("TCA_ROOT_COUNT", 0x041400),
+    ("TCA_ROOT_FLAGS", 0x041400),
+    ("TCA_ROOT_TAB", 0x041400),
+    ("TCA_ROOT_TIME_DELTA", 0x041400),
+    ("TCA_ROOT_UNSPEC", 0x041400),
+    ("TCA_STAB", 0x020620),
+    ("TCA_STATS", 0x020620),
+    ("TCA_STATS2", 0x020620),
+    ("TCA_UNSPEC", 0x020620),
+    ("TCA_XSTATS", 0x020620),
+    ("TCM_IFINDEX_MAGIC_BLOCK", 0x041400),
+    ("VXLAN_DF_MAX", 0x050400),
+    ("XDP_ATTACHED_DRV", 0x041400),
+    ("XDP_ATTACHED_HW", 0x041400),
+    ("XDP_ATTACHED_MULTI", 0x041400),
+    ("XDP_ATTACHED_NONE", 0x041400),
+    ("XDP_ATTACHED_SKB", 0x041400),
+    ("XDP_FLAGS_DRV_MODE", 0x041400),
+    ("XDP_FLAGS_HW_MODE", 0x041400),
+    ("XDP_FLAGS_MASK", 0x041400),
+    ("XDP_FLAGS_MODES", 0x041400),
+    ("XDP_FLAGS_REPLACE", 0x050b00),
+    ("XDP_FLAGS_SKB_MODE", 0x041400),
+    ("XDP_FLAGS_UPDATE_IF_NOEXIST", 0x041400),
+    ("_K_SS_MAXSIZE", 0x020620),
+    ("_MIPS_ISA_MIPS1", 0x040400),
+    ("_MIPS_ISA_MIPS2", 0x040400),
+    ("_MIPS_ISA_MIPS3", 0x040400),
+    ("_MIPS_ISA_MIPS32", 0x040400),
+    ("_MIPS_ISA_MIPS4", 0x040400),
+    ("_MIPS_ISA_MIPS5", 0x040400),
+    ("_MIPS_ISA_MIPS64", 0x040400),
+    ("_MIPS_SIM_ABI32", 0x040400),
+    ("_MIPS_SIM_ABI64", 0x040400),
+    ("_MIPS_SIM_NABI32", 0x040400),
+    ("__BITS_PER_LONG", 0x020620),
+    ("__FD_SETSIZE", 0x020620),
+    ("__IFA_MAX", 0x020620),
+    ("__IFLA_BAREUDP_MAX", 0x050b00),
+    ("__IFLA_BOND_AD_INFO_MAX", 0x040200),
+    ("__IFLA_BOND_MAX", 0x040200),
+    ("__IFLA_BOND_SLAVE_MAX", 0x040200),
+    ("__IFLA_BRPORT_MAX", 0x030a00),
+    ("__IFLA_BR_MAX", 0x040200),
+    ("__IFLA_GENEVE_MAX", 0x040200),
+    ("__IFLA_GTP_MAX", 0x041400),
+    ("__IFLA_HSR_MAX", 0x040200),
+    ("__IFLA_INET6_MAX", 0x020620),
+    ("__IFLA_INET_MAX", 0x030200),
+    ("__IFLA_INFO_MAX", 0x020620),
+    ("__IFLA_IPOIB_MAX", 0x030a00),
+    ("__IFLA_IPVLAN_MAX", 0x040200),
+    ("__IFLA_MACSEC_MAX", 0x041400),
+    ("__IFLA_MACVLAN_MAX", 0x030200),
+    ("__IFLA_MAX", 0x020620),
+    ("__IFLA_OFFLOAD_XSTATS_MAX", 0x041400),
+    ("__IFLA_PORT_MAX", 0x030200),
+    ("__IFLA_PPP_MAX", 0x041400),
+    ("__IFLA_PROTO_DOWN_REASON_CNT", 0x050b00),
+    ("__IFLA_RMNET_MAX", 0x041400),
+    ("__IFLA_STATS_MAX", 0x041400),
+    ("__IFLA_TUN_MAX", 0x041400),
+    ("__IFLA_VF_INFO_MAX", 0x030200),
+    ("__IFLA_VF_LINK_STATE_MAX", 0x040200),
+    ("__IFLA_VF_MAX", 0x030200),
+    ("__IFLA_VF_PORT_MAX", 0x030200),
+    ("__IFLA_VF_STATS_MAX", 0x040200),
+    ("__IFLA_VF_VLAN_INFO_MAX", 0x041400),
+    ("__IFLA_VLAN_MAX", 0x020620),
+    ("__IFLA_VLAN_QOS_MAX", 0x020620),
+    ("__IFLA_VRF_MAX", 0x040400),
+    ("__IFLA_VRF_PORT_MAX", 0x041400),
+    ("__IFLA_VXLAN_MAX", 0x030a00),
+    ("__IFLA_XDP_MAX", 0x041400),
+    ("__IFLA_XFRM_MAX", 0x041400),
+    ("__IncompleteArrayField", 0x030a00),
+    ("__LINK_XSTATS_TYPE_MAX", 0x041400),
+    ("__NDA_MAX", 0x020620),
+    ("__NDTA_MAX", 0x020620),
+    ("__NDTPA_MAX", 0x020620),
+    ("__NDUSEROPT_MAX", 0x020620),
+    ("__NFEA_MAX", 0x050b00),
+    ("__PREFIX_MAX", 0x020620),
+    ("__RTAX_MAX", 0x020620),
+    ("__RTM_MAX", 0x020620),
+    ("__RTN_MAX", 0x020620),
+    ("__TCA_MAX", 0x020620),
+    ("__TCA_ROOT_MAX", 0x041400),
+    ("__be16", 0x020620),
+    ("__be32", 0x020620),
+    ("__be64", 0x020620),
+    ("__kernel_caddr_t", 0x020620),
+    ("__kernel_clock_t", 0x020620),
+    ("__kernel_clockid_t", 0x020620),
+    ("__kernel_daddr_t", 0x020620),
+    ("__kernel_fd_set", 0x020620),
+    ("__kernel_fsid_t", 0x020620),
+    ("__kernel_gid16_t", 0x020620),
+    ("__kernel_gid32_t", 0x020620),
+    ("__kernel_gid_t", 0x020620),
+    ("__kernel_ino_t", 0x020620),
+    ("__kernel_ipc_pid_t", 0x020620),
+    ("__kernel_key_t", 0x020620),
+    ("__kernel_loff_t", 0x020620),
+    ("__kernel_long_t", 0x030a00),
+    ("__kernel_mode_t", 0x020620),
+    ("__kernel_mqd_t", 0x020620),
+    ("__kernel_nlink_t", 0x020620),
+    ("__kernel_off_t", 0x020620),
+    ("__kernel_old_dev_t", 0x020620),
+    ("__kernel_old_gid_t", 0x020620),
+    ("__kernel_old_time_t", 0x050b00),
+    ("__kernel_old_timeval", 0x050400),
+    ("__kernel_old_uid_t", 0x020620),
+    ("__kernel_pid_t", 0x020620),
+    ("__kernel_ptrdiff_t", 0x020620),
+    ("__kernel_sa_family_t", 0x030200),
+    ("__kernel_sighandler_t", 0x020620),
+    ("__kernel_sigset_t", 0x050400),
+    ("__kernel_size_t", 0x020620),
+    ("__kernel_sockaddr_storage", 0x020620),
+    ("__kernel_ssize_t", 0x020620),
+    ("__kernel_suseconds_t", 0x020620),
+    ("__kernel_time64_t", 0x041400),
+    ("__kernel_time_t", 0x020620),
+    ("__kernel_timer_t", 0x020620),
+    ("__kernel_uid16_t", 0x020620),
+    ("__kernel_uid32_t", 0x020620),
+    ("__kernel_uid_t", 0x020620),
+    ("__kernel_ulong_t", 0x030a00),
+    ("__le16", 0x020620),
+    ("__le32", 0x020620),
+    ("__le64", 0x020620),
+    ("__poll_t", 0x041400),
+    ("__s16", 0x020620),
+    ("__s32", 0x020620),
+    ("__s64", 0x020620),
+    ("__s8", 0x020620),
+    ("__sum16", 0x020620),
+    ("__u16", 0x020620),
+    ("__u32", 0x020620),
+    ("__u64", 0x020620),
+    ("__u8", 0x020620),
+    ("__vector128", 0x020620),
+    ("__wsum", 0x020620),
+    ("_bindgen_ty_1", 0x020620),
+    ("_bindgen_ty_10", 0x020620),
+    ("_bindgen_ty_11", 0x020620),
+    ("_bindgen_ty_12", 0x020620),
+    ("_bindgen_ty_13", 0x020620),
+    ("_bindgen_ty_14", 0x020620),
+    ("_bindgen_ty_15", 0x020620),
+    ("_bindgen_ty_16", 0x020620),
+    ("_bindgen_ty_17", 0x030200),
+    ("_bindgen_ty_18", 0x030200),
+    ("_bindgen_ty_19", 0x030200),
+    ("_bindgen_ty_2", 0x020620),
+    ("_bindgen_ty_20", 0x030200),
+    ("_bindgen_ty_21", 0x030200),
+    ("_bindgen_ty_22", 0x030200),
+    ("_bindgen_ty_23", 0x030200),
+    ("_bindgen_ty_24", 0x030200),
+    ("_bindgen_ty_25", 0x030a00),
+    ("_bindgen_ty_26", 0x030a00),
+    ("_bindgen_ty_27", 0x030a00),
+    ("_bindgen_ty_28", 0x030a00),
+    ("_bindgen_ty_29", 0x030a00),
+    ("_bindgen_ty_3", 0x020620),
+    ("_bindgen_ty_30", 0x040200),
+    ("_bindgen_ty_31", 0x040200),
+    ("_bindgen_ty_32", 0x040200),
+    ("_bindgen_ty_33", 0x040200),
+    ("_bindgen_ty_34", 0x040200),
+    ("_bindgen_ty_35", 0x040200),
+    ("_bindgen_ty_36", 0x040200),
+    ("_bindgen_ty_37", 0x040200),
+    ("_bindgen_ty_38", 0x040200),
+    ("_bindgen_ty_39", 0x040400),
+    ("_bindgen_ty_4", 0x020620),
+    ("_bindgen_ty_40", 0x041400),
+    ("_bindgen_ty_41", 0x041400),
+    ("_bindgen_ty_42", 0x041400),
+    ("_bindgen_ty_43", 0x041400),
+    ("_bindgen_ty_44", 0x041400),
+    ("_bindgen_ty_45", 0x041400),
+    ("_bindgen_ty_46", 0x041400),
+    ("_bindgen_ty_47", 0x041400),
+    ("_bindgen_ty_48", 0x041400),
+    ("_bindgen_ty_49", 0x041400),
+    ("_bindgen_ty_5", 0x020620),
+    ("_bindgen_ty_50", 0x041400),
+    ("_bindgen_ty_51", 0x041400),
+    ("_bindgen_ty_52", 0x041400),
+    ("_bindgen_ty_53", 0x041400),
+    ("_bindgen_ty_54", 0x041400),
+    ("_bindgen_ty_55", 0x050b00),
+    ("_bindgen_ty_56", 0x050b00),
+    ("_bindgen_ty_57", 0x050b00),
+    ("_bindgen_ty_58", 0x050b00),
+    ("_bindgen_ty_59", 0x050b00),
+    ("_bindgen_ty_6", 0x020620),
+    ("_bindgen_ty_7", 0x020620),
+    ("_bindgen_ty_8", 0x020620),
+    ("_bindgen_ty_9", 0x020620),
+    ("addr_t", 0x050400),
+    ("fn", 0x030a00),
+    ("if_stats_msg", 0x041400),
+    ("ifa_cacheinfo", 0x020620),
+    ("ifaddrmsg", 0x020620),
+    ("ifinfomsg", 0x020620),
+    ("ifla_bridge_id", 0x040400),
+    ("ifla_cacheinfo", 0x020620),
+    ("ifla_geneve_df", 0x050400),
+    ("ifla_gtp_role", 0x041400),
+    ("ifla_port_vsi", 0x030200),
+    ("ifla_rmnet_flags", 0x041400),
+    ("ifla_vf_broadcast", 0x050400),
+    ("ifla_vf_guid", 0x041400),
+    ("ifla_vf_link_state", 0x040200),
+    ("ifla_vf_mac", 0x030200),
+    ("ifla_vf_rate", 0x040200),
+    ("ifla_vf_rss_query_en", 0x040200),
+    ("ifla_vf_spoofchk", 0x030200),
+    ("ifla_vf_trust", 0x040400),
+    ("ifla_vf_tx_rate", 0x030200),
+    ("ifla_vf_vlan", 0x030200),
+    ("ifla_vf_vlan_info", 0x041400),
+    ("ifla_vlan_flags", 0x020620),
+    ("ifla_vlan_qos_mapping", 0x020620),
+    ("ifla_vxlan_df", 0x050400),
+    ("ifla_vxlan_port_range", 0x030a00),
+    ("in6_addr_gen_mode", 0x040200),
+    ("ipvlan_mode", 0x040200),
+    ("macsec_offload", 0x050b00),
+    ("macsec_validation_type", 0x041400),
+    ("macvlan_macaddr_mode", 0x040200),
+    ("macvlan_mode", 0x030200),
+    ("nda_cacheinfo", 0x020620),
+    ("ndmsg", 0x020620),
+    ("ndt_config", 0x020620),
+    ("ndt_stats", 0x020620),
+    ("ndtmsg", 0x020620),
+    ("nduseroptmsg", 0x020620),
+    ("net", 0x020620),
+    ("netlink_attribute_type", 0x050b00),
+    ("netlink_policy_type_attr", 0x050b00),
+    ("nl_mmap_hdr", 0x030a00),
+    ("nl_mmap_req", 0x030a00),
+    ("nl_mmap_status", 0x030a00),
+    ("nl_pktinfo", 0x020620),
+    ("nla_bitfield32", 0x041400),
+    ("nlattr", 0x020620),
+    ("nlmsgerr", 0x020620),
+    ("nlmsgerr_attrs", 0x041400),
+    ("nlmsghdr", 0x020620),
+    ("prefix_cacheinfo", 0x020620),
+    ("prefixmsg", 0x020620),
+    ("rt_class_t", 0x020620),
+    ("rt_scope_t", 0x020620),
+    ("rta_cacheinfo", 0x020620),
+    ("rta_mfc_stats", 0x030a00),
+    ("rta_session", 0x020620),
+    ("rtattr", 0x020620),
+    ("rtattr_type_t", 0x020620),
+    ("rtgenmsg", 0x020620),
+    ("rtmsg", 0x020620),
+    ("rtnetlink_groups", 0x020620),
+    ("rtnexthop", 0x020620),
+    ("rtnl_link_ifmap", 0x020620),
+    ("rtnl_link_stats", 0x020620),
+    ("rtnl_link_stats64", 0x030200),
+    ("rtvia", 0x040200),
+    ("saddr_t", 0x050400),
+    ("size_t", 0x020620),
+    ("sockaddr_nl", 0x020620),
+    ("ssize_t", 0x020620),
+    ("sysinfo", 0x030a00),
+    ("tcamsg", 0x020620),
+    ("tcmsg", 0x020620),
+    ("umode_t", 0x020620),
+];
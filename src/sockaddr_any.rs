@@ -0,0 +1,159 @@
+//! A socket address buffer which can hold an address of any family, for use
+//! with `accept`, `recvfrom`, `getsockname`, and similar syscalls.
+
+use crate::general::{
+    __kernel_sa_family_t, __kernel_sockaddr_storage, sockaddr, sockaddr_in, sockaddr_in6,
+    sockaddr_un, AF_INET, AF_INET6, AF_UNIX,
+};
+#[cfg(feature = "netlink")]
+use crate::{general::AF_NETLINK, netlink::sockaddr_nl};
+use core::mem::zeroed;
+
+/// A union of the socket address types, with the size and alignment of
+/// `__kernel_sockaddr_storage`.
+///
+/// The fields are private, so a `sockaddr_any` can only be made with
+/// `Default` or `From`, which initialize the family. Every socket address
+/// type begins with its family, so `family` is always valid to read, and
+/// `as_*` check it before returning a specific type.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union sockaddr_any {
+    family: __kernel_sa_family_t,
+    storage: __kernel_sockaddr_storage,
+    inet: sockaddr_in,
+    inet6: sockaddr_in6,
+    unix: sockaddr_un,
+    #[cfg(feature = "netlink")]
+    netlink: sockaddr_nl,
+}
+
+impl sockaddr_any {
+    /// The address family, one of the `AF_*` constants.
+    #[inline]
+    pub fn family(&self) -> u32 {
+        // Every variant begins with its family, and `Default` and the
+        // `From` impls initialize the whole buffer.
+        u32::from(unsafe { self.family })
+    }
+
+    /// A pointer to pass to syscalls which take a `sockaddr *`.
+    #[inline]
+    pub fn as_ptr(&self) -> *const sockaddr {
+        (self as *const Self).cast()
+    }
+
+    /// A mutable pointer to pass to syscalls which take a `sockaddr *`.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut sockaddr {
+        (self as *mut Self).cast()
+    }
+
+    /// The address as a `sockaddr_in`, if it's an `AF_INET` address.
+    #[inline]
+    pub fn as_inet(&self) -> Option<&sockaddr_in> {
+        if self.family() == AF_INET {
+            Some(unsafe { &self.inet })
+        } else {
+            None
+        }
+    }
+
+    /// The address as a `sockaddr_in6`, if it's an `AF_INET6` address.
+    #[inline]
+    pub fn as_inet6(&self) -> Option<&sockaddr_in6> {
+        if self.family() == AF_INET6 {
+            Some(unsafe { &self.inet6 })
+        } else {
+            None
+        }
+    }
+
+    /// The address as a `sockaddr_un`, if it's an `AF_UNIX` address.
+    #[inline]
+    pub fn as_unix(&self) -> Option<&sockaddr_un> {
+        if self.family() == AF_UNIX {
+            Some(unsafe { &self.unix })
+        } else {
+            None
+        }
+    }
+
+    /// The address as a `sockaddr_nl`, if it's an `AF_NETLINK` address.
+    #[cfg(feature = "netlink")]
+    #[inline]
+    pub fn as_netlink(&self) -> Option<&sockaddr_nl> {
+        if self.family() == AF_NETLINK {
+            Some(unsafe { &self.netlink })
+        } else {
+            None
+        }
+    }
+}
+
+/// An all-zeros buffer, with a family of `AF_UNSPEC`.
+impl Default for sockaddr_any {
+    #[inline]
+    fn default() -> Self {
+        unsafe { zeroed() }
+    }
+}
+
+impl From<sockaddr_in> for sockaddr_any {
+    #[inline]
+    fn from(addr: sockaddr_in) -> Self {
+        let mut any = Self::default();
+        any.inet = addr;
+        any
+    }
+}
+
+impl From<sockaddr_in6> for sockaddr_any {
+    #[inline]
+    fn from(addr: sockaddr_in6) -> Self {
+        let mut any = Self::default();
+        any.inet6 = addr;
+        any
+    }
+}
+
+impl From<sockaddr_un> for sockaddr_any {
+    #[inline]
+    fn from(addr: sockaddr_un) -> Self {
+        let mut any = Self::default();
+        any.unix = addr;
+        any
+    }
+}
+
+#[cfg(feature = "netlink")]
+impl From<sockaddr_nl> for sockaddr_any {
+    #[inline]
+    fn from(addr: sockaddr_nl) -> Self {
+        let mut any = Self::default();
+        any.netlink = addr;
+        any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::general::AF_UNSPEC;
+
+    #[test]
+    fn family_checked() {
+        let any = sockaddr_any::default();
+        assert_eq!(any.family(), AF_UNSPEC);
+        assert!(any.as_inet().is_none());
+        assert!(any.as_unix().is_none());
+
+        let mut inet: sockaddr_in = unsafe { zeroed() };
+        inet.sin_family = AF_INET as _;
+        inet.sin_port = 80_u16.to_be();
+        let any = sockaddr_any::from(inet);
+        assert_eq!(any.family(), AF_INET);
+        assert_eq!(any.as_inet().unwrap().sin_port, 80_u16.to_be());
+        assert!(any.as_inet6().is_none());
+    }
+}
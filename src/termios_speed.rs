@@ -0,0 +1,166 @@
+//! Encoding and decoding of the baud rate bits in `c_cflag`, for use with
+//! `termios2` (or, on powerpc, `termios`) and `BOTHER`.
+//!
+//! The layout of these bits differs between architectures, so these use the
+//! architecture's `CBAUD`, `CIBAUD`, and `IBSHIFT` rather than fixed masks.
+
+use crate::general::{
+    speed_t, tcflag_t, B0, B110, B115200, B1200, B134, B150, B1500000, B1800, B19200, B200,
+    B2000000, B230400, B2400, B300, B38400, B460800, B4800, B50, B500000, B57600, B576000, B600,
+    B75, B921600, B9600, BOTHER, CBAUD, CIBAUD, IBSHIFT,
+};
+#[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+use crate::general::{B1000000, B1152000, B2500000, B3000000, B3500000, B4000000};
+#[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+use crate::general::{B153600, B307200, B614400, B76800};
+
+/// The baud rates which have `B*` constants, and their constants.
+const SPEEDS: &[(u32, tcflag_t)] = &[
+    (0, B0),
+    (50, B50),
+    (75, B75),
+    (110, B110),
+    (134, B134),
+    (150, B150),
+    (200, B200),
+    (300, B300),
+    (600, B600),
+    (1200, B1200),
+    (1800, B1800),
+    (2400, B2400),
+    (4800, B4800),
+    (9600, B9600),
+    (19200, B19200),
+    (38400, B38400),
+    (57600, B57600),
+    #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+    (76800, B76800),
+    (115200, B115200),
+    #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+    (153600, B153600),
+    (230400, B230400),
+    #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+    (307200, B307200),
+    (460800, B460800),
+    (500000, B500000),
+    (576000, B576000),
+    #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+    (614400, B614400),
+    (921600, B921600),
+    #[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+    (1000000, B1000000),
+    #[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+    (1152000, B1152000),
+    (1500000, B1500000),
+    (2000000, B2000000),
+    #[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+    (2500000, B2500000),
+    #[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+    (3000000, B3000000),
+    #[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+    (3500000, B3500000),
+    #[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+    (4000000, B4000000),
+];
+
+/// The `B*` constant for `baud`, if there is one.
+///
+/// For other rates, use `BOTHER` and set `c_ospeed` or `c_ispeed` to the
+/// rate.
+pub fn baud_to_speed(baud: u32) -> Option<tcflag_t> {
+    SPEEDS
+        .iter()
+        .find(|(rate, _)| *rate == baud)
+        .map(|(_, speed)| *speed)
+}
+
+/// The baud rate of the `B*` constant `speed`, or `None` if it's `BOTHER`
+/// or unknown.
+pub fn speed_to_baud(speed: tcflag_t) -> Option<u32> {
+    SPEEDS
+        .iter()
+        .find(|(_, constant)| *constant == speed)
+        .map(|(rate, _)| *rate)
+}
+
+/// The output speed bits of `c_cflag`: a `B*` constant or `BOTHER`.
+#[inline]
+pub const fn cflag_ospeed(c_cflag: tcflag_t) -> tcflag_t {
+    c_cflag & CBAUD
+}
+
+/// Replace the output speed bits of `c_cflag` with `speed`, a `B*`
+/// constant or `BOTHER`.
+#[inline]
+pub const fn cflag_set_ospeed(c_cflag: tcflag_t, speed: tcflag_t) -> tcflag_t {
+    (c_cflag & !CBAUD) | (speed & CBAUD)
+}
+
+/// The input speed bits of `c_cflag`: a `B*` constant or `BOTHER`. `B0`
+/// means that the input speed is the same as the output speed.
+#[inline]
+pub const fn cflag_ispeed(c_cflag: tcflag_t) -> tcflag_t {
+    (c_cflag & CIBAUD) >> IBSHIFT
+}
+
+/// Replace the input speed bits of `c_cflag` with `speed`, a `B*` constant
+/// or `BOTHER`. `B0` means that the input speed is the same as the output
+/// speed.
+#[inline]
+pub const fn cflag_set_ispeed(c_cflag: tcflag_t, speed: tcflag_t) -> tcflag_t {
+    (c_cflag & !CIBAUD) | ((speed << IBSHIFT) & CIBAUD)
+}
+
+/// Set both speeds in `c_cflag` to `baud`, and return the new `c_cflag`
+/// along with the value for `c_ispeed` and `c_ospeed`, which is `baud`
+/// itself, for use with `BOTHER` when there's no `B*` constant for it.
+#[inline]
+pub fn cflag_set_baud(c_cflag: tcflag_t, baud: u32) -> (tcflag_t, speed_t) {
+    let speed = baud_to_speed(baud).unwrap_or(BOTHER);
+    let c_cflag = cflag_set_ospeed(c_cflag, speed);
+    (cflag_set_ispeed(c_cflag, speed), baud)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speeds_round_trip() {
+        for &(baud, speed) in SPEEDS {
+            assert_eq!(baud_to_speed(baud), Some(speed));
+            assert_eq!(speed_to_baud(speed), Some(baud));
+            assert_eq!(cflag_ospeed(cflag_set_ospeed(0, speed)), speed);
+            assert_eq!(cflag_ispeed(cflag_set_ispeed(0, speed)), speed);
+        }
+        assert_eq!(baud_to_speed(12345), None);
+        assert_eq!(speed_to_baud(BOTHER), None);
+    }
+
+    #[test]
+    fn cflag_set_baud_keeps_other_bits() {
+        let other = !(CBAUD | CIBAUD);
+        let (c_cflag, ispeed) = cflag_set_baud(other, 115200);
+        assert_eq!(c_cflag & other, other);
+        assert_eq!(cflag_ospeed(c_cflag), B115200);
+        assert_eq!(cflag_ispeed(c_cflag), B115200);
+        assert_eq!(ispeed, 115200);
+
+        let (c_cflag, ispeed) = cflag_set_baud(0, 12345);
+        assert_eq!(cflag_ospeed(c_cflag), BOTHER);
+        assert_eq!(cflag_ispeed(c_cflag), BOTHER);
+        assert_eq!(ispeed, 12345);
+    }
+
+    /// Compare with the values from x86's `<asm/termbits.h>`, where `CS8` is
+    /// 0o60, `B9600` is 0o15, `B115200` is 0o10002, and `IBSHIFT` is 16.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn c_values() {
+        let c_cflag = 0o000060 | 0o010002 | (0o000015 << 16);
+        assert_eq!(cflag_ospeed(c_cflag), B115200);
+        assert_eq!(cflag_ispeed(c_cflag), B9600);
+        assert_eq!(cflag_set_ospeed(0o000060, B115200), 0o010062);
+        assert_eq!(cflag_set_ispeed(0o000060, B9600), 0o000060 | (0o15 << 16));
+    }
+}
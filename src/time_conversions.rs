@@ -0,0 +1,69 @@
+//! Conversions between the legacy `timespec`, `timeval`, and `itimerspec`
+//! types, whose `tv_sec` is a `long` and so overflows in 2038 on 32-bit
+//! platforms, and the y2038-safe `__kernel_timespec` and
+//! `__kernel_itimerspec`.
+
+// On 64-bit platforms, the legacy and 64-bit field types are the same.
+#![allow(clippy::useless_conversion)]
+
+use crate::general::{__kernel_itimerspec, __kernel_timespec, itimerspec, timespec, timeval};
+use core::convert::TryFrom;
+use core::num::TryFromIntError;
+
+impl From<timespec> for __kernel_timespec {
+    #[inline]
+    fn from(ts: timespec) -> Self {
+        Self {
+            tv_sec: ts.tv_sec.into(),
+            tv_nsec: ts.tv_nsec.into(),
+        }
+    }
+}
+
+impl From<timeval> for __kernel_timespec {
+    #[inline]
+    fn from(tv: timeval) -> Self {
+        Self {
+            tv_sec: tv.tv_sec.into(),
+            tv_nsec: i64::from(tv.tv_usec) * 1000,
+        }
+    }
+}
+
+/// Fails if the time doesn't fit in a `timespec`, such as times after 2038
+/// on 32-bit platforms.
+impl TryFrom<__kernel_timespec> for timespec {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(ts: __kernel_timespec) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tv_sec: TryFrom::try_from(ts.tv_sec)?,
+            tv_nsec: TryFrom::try_from(ts.tv_nsec)?,
+        })
+    }
+}
+
+impl From<itimerspec> for __kernel_itimerspec {
+    #[inline]
+    fn from(its: itimerspec) -> Self {
+        Self {
+            it_interval: its.it_interval.into(),
+            it_value: its.it_value.into(),
+        }
+    }
+}
+
+/// Fails if either time doesn't fit in a `timespec`, such as times after
+/// 2038 on 32-bit platforms.
+impl TryFrom<__kernel_itimerspec> for itimerspec {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(its: __kernel_itimerspec) -> Result<Self, Self::Error> {
+        Ok(Self {
+            it_interval: TryFrom::try_from(its.it_interval)?,
+            it_value: TryFrom::try_from(its.it_value)?,
+        })
+    }
+}
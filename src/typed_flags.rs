@@ -0,0 +1,128 @@
+//! The `typed_flags!` macro, which the generated bindings use to declare
+//! typed wrappers for families of flag constants when the `typed-flags`
+//! feature is enabled.
+
+macro_rules! typed_flags {
+    ($($name:ident: $ty:ty { $($flag:ident,)* })*) => {
+        $(
+            #[doc = concat!("A typed set of `", stringify!($ty), "` flags.")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "typed-flags")))]
+            #[repr(transparent)]
+            #[cfg_attr(feature = "derive-debug", derive(Debug))]
+            #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+            pub struct $name(pub $ty);
+
+            impl $name {
+                $(
+                    #[doc = concat!("`", stringify!($flag), "`")]
+                    pub const $flag: Self = Self($flag as $ty);
+                )*
+
+                /// The set with no flags.
+                #[inline]
+                pub const fn empty() -> Self {
+                    Self(0)
+                }
+
+                /// Wrap raw flag bits, including any unknown bits.
+                #[inline]
+                pub const fn from_bits(bits: $ty) -> Self {
+                    Self(bits)
+                }
+
+                /// Return the raw flag bits.
+                #[inline]
+                pub const fn bits(self) -> $ty {
+                    self.0
+                }
+
+                /// Test whether no flags are set.
+                #[inline]
+                pub const fn is_empty(self) -> bool {
+                    self.0 == 0
+                }
+
+                /// Test whether all the flags in `other` are set.
+                #[inline]
+                pub const fn contains(self, other: Self) -> bool {
+                    self.0 & other.0 == other.0
+                }
+
+                /// Test whether any of the flags in `other` are set.
+                #[inline]
+                pub const fn intersects(self, other: Self) -> bool {
+                    self.0 & other.0 != 0
+                }
+
+                /// The flags in either `self` or `other`.
+                #[inline]
+                pub const fn union(self, other: Self) -> Self {
+                    Self(self.0 | other.0)
+                }
+
+                /// The flags in `self` but not in `other`.
+                #[inline]
+                pub const fn difference(self, other: Self) -> Self {
+                    Self(self.0 & !other.0)
+                }
+            }
+
+            impl ::core::ops::BitOr for $name {
+                type Output = Self;
+
+                #[inline]
+                fn bitor(self, other: Self) -> Self {
+                    Self(self.0 | other.0)
+                }
+            }
+
+            impl ::core::ops::BitOrAssign for $name {
+                #[inline]
+                fn bitor_assign(&mut self, other: Self) {
+                    self.0 |= other.0;
+                }
+            }
+
+            impl ::core::ops::BitAnd for $name {
+                type Output = Self;
+
+                #[inline]
+                fn bitand(self, other: Self) -> Self {
+                    Self(self.0 & other.0)
+                }
+            }
+
+            impl ::core::ops::BitAndAssign for $name {
+                #[inline]
+                fn bitand_assign(&mut self, other: Self) {
+                    self.0 &= other.0;
+                }
+            }
+
+            impl ::core::ops::BitXor for $name {
+                type Output = Self;
+
+                #[inline]
+                fn bitxor(self, other: Self) -> Self {
+                    Self(self.0 ^ other.0)
+                }
+            }
+
+            impl ::core::ops::BitXorAssign for $name {
+                #[inline]
+                fn bitxor_assign(&mut self, other: Self) {
+                    self.0 ^= other.0;
+                }
+            }
+
+            impl ::core::ops::Not for $name {
+                type Output = Self;
+
+                #[inline]
+                fn not(self) -> Self {
+                    Self(!self.0)
+                }
+            }
+        )*
+    };
+}
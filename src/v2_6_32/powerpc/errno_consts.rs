@@ -0,0 +1,2 @@
+include!("../shared/errno_consts.rs");
+pub const _LAST_ERRNO: u32 = 516;
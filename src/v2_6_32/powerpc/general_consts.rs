@@ -0,0 +1,448 @@
+include!("../shared/general_consts.rs");
+pub const AT_DCACHEBSIZE: u32 = 19;
+pub const AT_ICACHEBSIZE: u32 = 20;
+pub const AT_UCACHEBSIZE: u32 = 21;
+pub const AT_IGNOREPPC: u32 = 22;
+pub const O_DIRECTORY: u32 = 16384;
+pub const O_NOFOLLOW: u32 = 32768;
+pub const O_LARGEFILE: u32 = 65536;
+pub const O_DIRECT: u32 = 131072;
+pub const __BITS_PER_LONG: u32 = 32;
+pub const _IOC_SIZEBITS: u32 = 13;
+pub const _IOC_DIRBITS: u32 = 3;
+pub const _IOC_NONE: u32 = 1;
+pub const _IOC_WRITE: u32 = 4;
+pub const _IOC_SIZEMASK: u32 = 8191;
+pub const _IOC_DIRMASK: u32 = 7;
+pub const _IOC_DIRSHIFT: u32 = 29;
+pub const IOC_IN: u32 = 2147483648;
+pub const IOC_OUT: u32 = 1073741824;
+pub const IOCSIZE_MASK: u32 = 536805376;
+pub const __BIG_ENDIAN: u32 = 4321;
+pub const PROT_SAO: u32 = 16;
+pub const MAP_RENAME: u32 = 32;
+pub const MAP_NORESERVE: u32 = 64;
+pub const MAP_LOCKED: u32 = 128;
+pub const MCL_CURRENT: u32 = 8192;
+pub const MCL_FUTURE: u32 = 16384;
+pub const SO_RCVLOWAT: u32 = 16;
+pub const SO_SNDLOWAT: u32 = 17;
+pub const SO_RCVTIMEO: u32 = 18;
+pub const SO_SNDTIMEO: u32 = 19;
+pub const SO_PASSCRED: u32 = 20;
+pub const SO_PEERCRED: u32 = 21;
+pub const _NSIG_BPW: u32 = 32;
+pub const _NSIG_WORDS: u32 = 2;
+pub const SIGRTMAX: u32 = 64;
+pub const SIG_DBG_SINGLE_STEPPING: u32 = 1;
+pub const SIG_DBG_BRANCH_TRACING: u32 = 2;
+pub const VMIN: u32 = 5;
+pub const VEOL: u32 = 6;
+pub const VTIME: u32 = 7;
+pub const VEOL2: u32 = 8;
+pub const VSWTC: u32 = 9;
+pub const VWERASE: u32 = 10;
+pub const VREPRINT: u32 = 11;
+pub const VSUSP: u32 = 12;
+pub const VSTART: u32 = 13;
+pub const VSTOP: u32 = 14;
+pub const VDISCARD: u32 = 16;
+pub const IXON: u32 = 512;
+pub const IXOFF: u32 = 1024;
+pub const IUCLC: u32 = 4096;
+pub const ONLCR: u32 = 2;
+pub const OLCUC: u32 = 4;
+pub const NLDLY: u32 = 768;
+pub const NL2: u32 = 512;
+pub const NL3: u32 = 768;
+pub const TABDLY: u32 = 3072;
+pub const TAB1: u32 = 1024;
+pub const TAB2: u32 = 2048;
+pub const TAB3: u32 = 3072;
+pub const XTABS: u32 = 3072;
+pub const CRDLY: u32 = 12288;
+pub const CR1: u32 = 4096;
+pub const CR2: u32 = 8192;
+pub const CR3: u32 = 12288;
+pub const FFDLY: u32 = 16384;
+pub const FF1: u32 = 16384;
+pub const BSDLY: u32 = 32768;
+pub const BS1: u32 = 32768;
+pub const VTDLY: u32 = 65536;
+pub const VT1: u32 = 65536;
+pub const CBAUD: u32 = 255;
+pub const CBAUDEX: u32 = 0;
+pub const B57600: u32 = 16;
+pub const B115200: u32 = 17;
+pub const B230400: u32 = 18;
+pub const B460800: u32 = 19;
+pub const B500000: u32 = 20;
+pub const B576000: u32 = 21;
+pub const B921600: u32 = 22;
+pub const B1000000: u32 = 23;
+pub const B1152000: u32 = 24;
+pub const B1500000: u32 = 25;
+pub const B2000000: u32 = 26;
+pub const B2500000: u32 = 27;
+pub const B3000000: u32 = 28;
+pub const B3500000: u32 = 29;
+pub const B4000000: u32 = 30;
+pub const BOTHER: u32 = 31;
+pub const CIBAUD: u32 = 16711680;
+pub const CSIZE: u32 = 768;
+pub const CS6: u32 = 256;
+pub const CS7: u32 = 512;
+pub const CS8: u32 = 768;
+pub const CSTOPB: u32 = 1024;
+pub const CREAD: u32 = 2048;
+pub const PARENB: u32 = 4096;
+pub const PARODD: u32 = 8192;
+pub const HUPCL: u32 = 16384;
+pub const CLOCAL: u32 = 32768;
+pub const ISIG: u32 = 128;
+pub const ICANON: u32 = 256;
+pub const XCASE: u32 = 16384;
+pub const ECHOE: u32 = 2;
+pub const ECHOK: u32 = 4;
+pub const ECHONL: u32 = 16;
+pub const NOFLSH: u32 = 2147483648;
+pub const TOSTOP: u32 = 4194304;
+pub const ECHOCTL: u32 = 64;
+pub const ECHOPRT: u32 = 32;
+pub const ECHOKE: u32 = 1;
+pub const FLUSHO: u32 = 8388608;
+pub const PENDIN: u32 = 536870912;
+pub const IEXTEN: u32 = 1024;
+pub const NCC: u32 = 10;
+pub const _VINTR: u32 = 0;
+pub const _VQUIT: u32 = 1;
+pub const _VERASE: u32 = 2;
+pub const _VKILL: u32 = 3;
+pub const _VEOF: u32 = 4;
+pub const _VMIN: u32 = 5;
+pub const _VEOL: u32 = 6;
+pub const _VTIME: u32 = 7;
+pub const _VEOL2: u32 = 8;
+pub const _VSWTC: u32 = 9;
+pub const __NR_restart_syscall: u32 = 0;
+pub const __NR_exit: u32 = 1;
+pub const __NR_fork: u32 = 2;
+pub const __NR_read: u32 = 3;
+pub const __NR_write: u32 = 4;
+pub const __NR_open: u32 = 5;
+pub const __NR_close: u32 = 6;
+pub const __NR_waitpid: u32 = 7;
+pub const __NR_creat: u32 = 8;
+pub const __NR_link: u32 = 9;
+pub const __NR_unlink: u32 = 10;
+pub const __NR_execve: u32 = 11;
+pub const __NR_chdir: u32 = 12;
+pub const __NR_time: u32 = 13;
+pub const __NR_mknod: u32 = 14;
+pub const __NR_chmod: u32 = 15;
+pub const __NR_lchown: u32 = 16;
+pub const __NR_break: u32 = 17;
+pub const __NR_oldstat: u32 = 18;
+pub const __NR_lseek: u32 = 19;
+pub const __NR_getpid: u32 = 20;
+pub const __NR_mount: u32 = 21;
+pub const __NR_umount: u32 = 22;
+pub const __NR_setuid: u32 = 23;
+pub const __NR_getuid: u32 = 24;
+pub const __NR_stime: u32 = 25;
+pub const __NR_ptrace: u32 = 26;
+pub const __NR_alarm: u32 = 27;
+pub const __NR_oldfstat: u32 = 28;
+pub const __NR_pause: u32 = 29;
+pub const __NR_utime: u32 = 30;
+pub const __NR_stty: u32 = 31;
+pub const __NR_gtty: u32 = 32;
+pub const __NR_access: u32 = 33;
+pub const __NR_nice: u32 = 34;
+pub const __NR_ftime: u32 = 35;
+pub const __NR_sync: u32 = 36;
+pub const __NR_kill: u32 = 37;
+pub const __NR_rename: u32 = 38;
+pub const __NR_mkdir: u32 = 39;
+pub const __NR_rmdir: u32 = 40;
+pub const __NR_dup: u32 = 41;
+pub const __NR_pipe: u32 = 42;
+pub const __NR_times: u32 = 43;
+pub const __NR_prof: u32 = 44;
+pub const __NR_brk: u32 = 45;
+pub const __NR_setgid: u32 = 46;
+pub const __NR_getgid: u32 = 47;
+pub const __NR_signal: u32 = 48;
+pub const __NR_geteuid: u32 = 49;
+pub const __NR_getegid: u32 = 50;
+pub const __NR_acct: u32 = 51;
+pub const __NR_umount2: u32 = 52;
+pub const __NR_lock: u32 = 53;
+pub const __NR_ioctl: u32 = 54;
+pub const __NR_fcntl: u32 = 55;
+pub const __NR_mpx: u32 = 56;
+pub const __NR_setpgid: u32 = 57;
+pub const __NR_ulimit: u32 = 58;
+pub const __NR_oldolduname: u32 = 59;
+pub const __NR_umask: u32 = 60;
+pub const __NR_chroot: u32 = 61;
+pub const __NR_ustat: u32 = 62;
+pub const __NR_dup2: u32 = 63;
+pub const __NR_getppid: u32 = 64;
+pub const __NR_getpgrp: u32 = 65;
+pub const __NR_setsid: u32 = 66;
+pub const __NR_sigaction: u32 = 67;
+pub const __NR_sgetmask: u32 = 68;
+pub const __NR_ssetmask: u32 = 69;
+pub const __NR_setreuid: u32 = 70;
+pub const __NR_setregid: u32 = 71;
+pub const __NR_sigsuspend: u32 = 72;
+pub const __NR_sigpending: u32 = 73;
+pub const __NR_sethostname: u32 = 74;
+pub const __NR_setrlimit: u32 = 75;
+pub const __NR_getrlimit: u32 = 76;
+pub const __NR_getrusage: u32 = 77;
+pub const __NR_gettimeofday: u32 = 78;
+pub const __NR_settimeofday: u32 = 79;
+pub const __NR_getgroups: u32 = 80;
+pub const __NR_setgroups: u32 = 81;
+pub const __NR_select: u32 = 82;
+pub const __NR_symlink: u32 = 83;
+pub const __NR_oldlstat: u32 = 84;
+pub const __NR_readlink: u32 = 85;
+pub const __NR_uselib: u32 = 86;
+pub const __NR_swapon: u32 = 87;
+pub const __NR_reboot: u32 = 88;
+pub const __NR_readdir: u32 = 89;
+pub const __NR_mmap: u32 = 90;
+pub const __NR_munmap: u32 = 91;
+pub const __NR_truncate: u32 = 92;
+pub const __NR_ftruncate: u32 = 93;
+pub const __NR_fchmod: u32 = 94;
+pub const __NR_fchown: u32 = 95;
+pub const __NR_getpriority: u32 = 96;
+pub const __NR_setpriority: u32 = 97;
+pub const __NR_profil: u32 = 98;
+pub const __NR_statfs: u32 = 99;
+pub const __NR_fstatfs: u32 = 100;
+pub const __NR_ioperm: u32 = 101;
+pub const __NR_socketcall: u32 = 102;
+pub const __NR_setitimer: u32 = 104;
+pub const __NR_getitimer: u32 = 105;
+pub const __NR_stat: u32 = 106;
+pub const __NR_lstat: u32 = 107;
+pub const __NR_fstat: u32 = 108;
+pub const __NR_olduname: u32 = 109;
+pub const __NR_iopl: u32 = 110;
+pub const __NR_vhangup: u32 = 111;
+pub const __NR_idle: u32 = 112;
+pub const __NR_vm86: u32 = 113;
+pub const __NR_wait4: u32 = 114;
+pub const __NR_swapoff: u32 = 115;
+pub const __NR_sysinfo: u32 = 116;
+pub const __NR_ipc: u32 = 117;
+pub const __NR_fsync: u32 = 118;
+pub const __NR_sigreturn: u32 = 119;
+pub const __NR_clone: u32 = 120;
+pub const __NR_setdomainname: u32 = 121;
+pub const __NR_uname: u32 = 122;
+pub const __NR_modify_ldt: u32 = 123;
+pub const __NR_adjtimex: u32 = 124;
+pub const __NR_mprotect: u32 = 125;
+pub const __NR_sigprocmask: u32 = 126;
+pub const __NR_create_module: u32 = 127;
+pub const __NR_init_module: u32 = 128;
+pub const __NR_delete_module: u32 = 129;
+pub const __NR_get_kernel_syms: u32 = 130;
+pub const __NR_quotactl: u32 = 131;
+pub const __NR_getpgid: u32 = 132;
+pub const __NR_fchdir: u32 = 133;
+pub const __NR_bdflush: u32 = 134;
+pub const __NR_sysfs: u32 = 135;
+pub const __NR_personality: u32 = 136;
+pub const __NR_afs_syscall: u32 = 137;
+pub const __NR_setfsuid: u32 = 138;
+pub const __NR_setfsgid: u32 = 139;
+pub const __NR__llseek: u32 = 140;
+pub const __NR_getdents: u32 = 141;
+pub const __NR__newselect: u32 = 142;
+pub const __NR_flock: u32 = 143;
+pub const __NR_msync: u32 = 144;
+pub const __NR_readv: u32 = 145;
+pub const __NR_writev: u32 = 146;
+pub const __NR_getsid: u32 = 147;
+pub const __NR_fdatasync: u32 = 148;
+pub const __NR__sysctl: u32 = 149;
+pub const __NR_mlock: u32 = 150;
+pub const __NR_munlock: u32 = 151;
+pub const __NR_mlockall: u32 = 152;
+pub const __NR_munlockall: u32 = 153;
+pub const __NR_sched_setparam: u32 = 154;
+pub const __NR_sched_getparam: u32 = 155;
+pub const __NR_sched_setscheduler: u32 = 156;
+pub const __NR_sched_getscheduler: u32 = 157;
+pub const __NR_sched_yield: u32 = 158;
+pub const __NR_sched_get_priority_max: u32 = 159;
+pub const __NR_sched_get_priority_min: u32 = 160;
+pub const __NR_sched_rr_get_interval: u32 = 161;
+pub const __NR_nanosleep: u32 = 162;
+pub const __NR_mremap: u32 = 163;
+pub const __NR_setresuid: u32 = 164;
+pub const __NR_getresuid: u32 = 165;
+pub const __NR_query_module: u32 = 166;
+pub const __NR_poll: u32 = 167;
+pub const __NR_nfsservctl: u32 = 168;
+pub const __NR_setresgid: u32 = 169;
+pub const __NR_getresgid: u32 = 170;
+pub const __NR_prctl: u32 = 171;
+pub const __NR_rt_sigreturn: u32 = 172;
+pub const __NR_rt_sigaction: u32 = 173;
+pub const __NR_rt_sigprocmask: u32 = 174;
+pub const __NR_rt_sigpending: u32 = 175;
+pub const __NR_rt_sigtimedwait: u32 = 176;
+pub const __NR_rt_sigqueueinfo: u32 = 177;
+pub const __NR_rt_sigsuspend: u32 = 178;
+pub const __NR_pread64: u32 = 179;
+pub const __NR_pwrite64: u32 = 180;
+pub const __NR_chown: u32 = 181;
+pub const __NR_getcwd: u32 = 182;
+pub const __NR_capget: u32 = 183;
+pub const __NR_capset: u32 = 184;
+pub const __NR_sigaltstack: u32 = 185;
+pub const __NR_sendfile: u32 = 186;
+pub const __NR_getpmsg: u32 = 187;
+pub const __NR_putpmsg: u32 = 188;
+pub const __NR_vfork: u32 = 189;
+pub const __NR_ugetrlimit: u32 = 190;
+pub const __NR_readahead: u32 = 191;
+pub const __NR_mmap2: u32 = 192;
+pub const __NR_truncate64: u32 = 193;
+pub const __NR_ftruncate64: u32 = 194;
+pub const __NR_stat64: u32 = 195;
+pub const __NR_lstat64: u32 = 196;
+pub const __NR_fstat64: u32 = 197;
+pub const __NR_pciconfig_read: u32 = 198;
+pub const __NR_pciconfig_write: u32 = 199;
+pub const __NR_pciconfig_iobase: u32 = 200;
+pub const __NR_multiplexer: u32 = 201;
+pub const __NR_getdents64: u32 = 202;
+pub const __NR_pivot_root: u32 = 203;
+pub const __NR_fcntl64: u32 = 204;
+pub const __NR_madvise: u32 = 205;
+pub const __NR_mincore: u32 = 206;
+pub const __NR_gettid: u32 = 207;
+pub const __NR_tkill: u32 = 208;
+pub const __NR_setxattr: u32 = 209;
+pub const __NR_lsetxattr: u32 = 210;
+pub const __NR_fsetxattr: u32 = 211;
+pub const __NR_getxattr: u32 = 212;
+pub const __NR_lgetxattr: u32 = 213;
+pub const __NR_fgetxattr: u32 = 214;
+pub const __NR_listxattr: u32 = 215;
+pub const __NR_llistxattr: u32 = 216;
+pub const __NR_flistxattr: u32 = 217;
+pub const __NR_removexattr: u32 = 218;
+pub const __NR_lremovexattr: u32 = 219;
+pub const __NR_fremovexattr: u32 = 220;
+pub const __NR_futex: u32 = 221;
+pub const __NR_sched_setaffinity: u32 = 222;
+pub const __NR_sched_getaffinity: u32 = 223;
+pub const __NR_tuxcall: u32 = 225;
+pub const __NR_sendfile64: u32 = 226;
+pub const __NR_io_setup: u32 = 227;
+pub const __NR_io_destroy: u32 = 228;
+pub const __NR_io_getevents: u32 = 229;
+pub const __NR_io_submit: u32 = 230;
+pub const __NR_io_cancel: u32 = 231;
+pub const __NR_set_tid_address: u32 = 232;
+pub const __NR_fadvise64: u32 = 233;
+pub const __NR_exit_group: u32 = 234;
+pub const __NR_lookup_dcookie: u32 = 235;
+pub const __NR_epoll_create: u32 = 236;
+pub const __NR_epoll_ctl: u32 = 237;
+pub const __NR_epoll_wait: u32 = 238;
+pub const __NR_remap_file_pages: u32 = 239;
+pub const __NR_timer_create: u32 = 240;
+pub const __NR_timer_settime: u32 = 241;
+pub const __NR_timer_gettime: u32 = 242;
+pub const __NR_timer_getoverrun: u32 = 243;
+pub const __NR_timer_delete: u32 = 244;
+pub const __NR_clock_settime: u32 = 245;
+pub const __NR_clock_gettime: u32 = 246;
+pub const __NR_clock_getres: u32 = 247;
+pub const __NR_clock_nanosleep: u32 = 248;
+pub const __NR_swapcontext: u32 = 249;
+pub const __NR_tgkill: u32 = 250;
+pub const __NR_utimes: u32 = 251;
+pub const __NR_statfs64: u32 = 252;
+pub const __NR_fstatfs64: u32 = 253;
+pub const __NR_fadvise64_64: u32 = 254;
+pub const __NR_rtas: u32 = 255;
+pub const __NR_sys_debug_setcontext: u32 = 256;
+pub const __NR_migrate_pages: u32 = 258;
+pub const __NR_mbind: u32 = 259;
+pub const __NR_get_mempolicy: u32 = 260;
+pub const __NR_set_mempolicy: u32 = 261;
+pub const __NR_mq_open: u32 = 262;
+pub const __NR_mq_unlink: u32 = 263;
+pub const __NR_mq_timedsend: u32 = 264;
+pub const __NR_mq_timedreceive: u32 = 265;
+pub const __NR_mq_notify: u32 = 266;
+pub const __NR_mq_getsetattr: u32 = 267;
+pub const __NR_kexec_load: u32 = 268;
+pub const __NR_add_key: u32 = 269;
+pub const __NR_request_key: u32 = 270;
+pub const __NR_keyctl: u32 = 271;
+pub const __NR_waitid: u32 = 272;
+pub const __NR_ioprio_set: u32 = 273;
+pub const __NR_ioprio_get: u32 = 274;
+pub const __NR_inotify_init: u32 = 275;
+pub const __NR_inotify_add_watch: u32 = 276;
+pub const __NR_inotify_rm_watch: u32 = 277;
+pub const __NR_spu_run: u32 = 278;
+pub const __NR_spu_create: u32 = 279;
+pub const __NR_pselect6: u32 = 280;
+pub const __NR_ppoll: u32 = 281;
+pub const __NR_unshare: u32 = 282;
+pub const __NR_splice: u32 = 283;
+pub const __NR_tee: u32 = 284;
+pub const __NR_vmsplice: u32 = 285;
+pub const __NR_openat: u32 = 286;
+pub const __NR_mkdirat: u32 = 287;
+pub const __NR_mknodat: u32 = 288;
+pub const __NR_fchownat: u32 = 289;
+pub const __NR_futimesat: u32 = 290;
+pub const __NR_fstatat64: u32 = 291;
+pub const __NR_unlinkat: u32 = 292;
+pub const __NR_renameat: u32 = 293;
+pub const __NR_linkat: u32 = 294;
+pub const __NR_symlinkat: u32 = 295;
+pub const __NR_readlinkat: u32 = 296;
+pub const __NR_fchmodat: u32 = 297;
+pub const __NR_faccessat: u32 = 298;
+pub const __NR_get_robust_list: u32 = 299;
+pub const __NR_set_robust_list: u32 = 300;
+pub const __NR_move_pages: u32 = 301;
+pub const __NR_getcpu: u32 = 302;
+pub const __NR_epoll_pwait: u32 = 303;
+pub const __NR_utimensat: u32 = 304;
+pub const __NR_signalfd: u32 = 305;
+pub const __NR_timerfd_create: u32 = 306;
+pub const __NR_eventfd: u32 = 307;
+pub const __NR_sync_file_range2: u32 = 308;
+pub const __NR_fallocate: u32 = 309;
+pub const __NR_subpage_prot: u32 = 310;
+pub const __NR_timerfd_settime: u32 = 311;
+pub const __NR_timerfd_gettime: u32 = 312;
+pub const __NR_signalfd4: u32 = 313;
+pub const __NR_eventfd2: u32 = 314;
+pub const __NR_epoll_create1: u32 = 315;
+pub const __NR_dup3: u32 = 316;
+pub const __NR_pipe2: u32 = 317;
+pub const __NR_inotify_init1: u32 = 318;
+pub const __NR_perf_event_open: u32 = 319;
+pub const __NR_preadv: u32 = 320;
+pub const __NR_pwritev: u32 = 321;
+pub const __NR_rt_tgsigqueueinfo: u32 = 322;
+pub const BLKSSZGET: u32 = 536875624;
+pub const BLKPBSZGET: u32 = 536875643;
@@ -0,0 +1,1147 @@
+/* automatically generated by rust-bindgen 0.59.1 */
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct __BindgenBitfieldUnit<Storage> {
+storage: Storage,
+}
+impl<Storage> __BindgenBitfieldUnit<Storage> {
+#[inline]
+pub const fn new(storage: Storage) -> Self {
+Self { storage }
+}
+}
+impl<Storage> __BindgenBitfieldUnit<Storage>
+where
+Storage: AsRef<[u8]> + AsMut<[u8]>,
+{
+#[inline]
+pub fn get_bit(&self, index: usize) -> bool {
+debug_assert!(index / 8 < self.storage.as_ref().len());
+let byte_index = index / 8;
+let byte = self.storage.as_ref()[byte_index];
+let bit_index = if cfg!(target_endian = "big") { 7 - (index % 8) } else { index % 8 };
+let mask = 1 << bit_index;
+byte & mask == mask
+}
+#[inline]
+pub fn set_bit(&mut self, index: usize, val: bool) {
+debug_assert!(index / 8 < self.storage.as_ref().len());
+let byte_index = index / 8;
+let byte = &mut self.storage.as_mut()[byte_index];
+let bit_index = if cfg!(target_endian = "big") { 7 - (index % 8) } else { index % 8 };
+let mask = 1 << bit_index;
+if val {
+*byte |= mask;
+} else {
+*byte &= !mask;
+}
+}
+#[inline]
+pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+debug_assert!(bit_width <= 64);
+debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+let mut val = 0;
+for i in 0..(bit_width as usize) {
+if self.get_bit(i + bit_offset) {
+let index = if cfg!(target_endian = "big") { bit_width as usize - 1 - i } else { i };
+val |= 1 << index;
+}
+}
+val
+}
+#[inline]
+pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+debug_assert!(bit_width <= 64);
+debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+for i in 0..(bit_width as usize) {
+let mask = 1 << i;
+let val_bit_is_set = val & mask == mask;
+let index = if cfg!(target_endian = "big") { bit_width as usize - 1 - i } else { i };
+self.set_bit(index + bit_offset, val_bit_is_set);
+}
+}
+}
+#[repr(C)]
+#[derive(Default)]
+pub struct __IncompleteArrayField<T>(::core::marker::PhantomData<T>, [T; 0]);
+impl<T> __IncompleteArrayField<T> {
+#[inline]
+pub const fn new() -> Self {
+__IncompleteArrayField(::core::marker::PhantomData, [])
+}
+#[inline]
+pub fn as_ptr(&self) -> *const T {
+self as *const _ as *const T
+}
+#[inline]
+pub fn as_mut_ptr(&mut self) -> *mut T {
+self as *mut _ as *mut T
+}
+#[inline]
+pub unsafe fn as_slice(&self, len: usize) -> &[T] {
+::core::slice::from_raw_parts(self.as_ptr(), len)
+}
+#[inline]
+pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [T] {
+::core::slice::from_raw_parts_mut(self.as_mut_ptr(), len)
+}
+}
+impl<T> ::core::fmt::Debug for __IncompleteArrayField<T> {
+fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+fmt.write_str("__IncompleteArrayField")
+}
+}
+#[repr(C)]
+pub struct __BindgenUnionField<T>(::core::marker::PhantomData<T>);
+impl<T> __BindgenUnionField<T> {
+#[inline]
+pub const fn new() -> Self {
+__BindgenUnionField(::core::marker::PhantomData)
+}
+#[inline]
+pub unsafe fn as_ref(&self) -> &T {
+::core::mem::transmute(self)
+}
+#[inline]
+pub unsafe fn as_mut(&mut self) -> &mut T {
+::core::mem::transmute(self)
+}
+}
+impl<T> ::core::default::Default for __BindgenUnionField<T> {
+#[inline]
+fn default() -> Self {
+Self::new()
+}
+}
+impl<T> ::core::clone::Clone for __BindgenUnionField<T> {
+#[inline]
+fn clone(&self) -> Self {
+Self::new()
+}
+}
+impl<T> ::core::marker::Copy for __BindgenUnionField<T> {}
+impl<T> ::core::fmt::Debug for __BindgenUnionField<T> {
+fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+fmt.write_str("__BindgenUnionField")
+}
+}
+impl<T> ::core::hash::Hash for __BindgenUnionField<T> {
+fn hash<H: ::core::hash::Hasher>(&self, _state: &mut H) {}
+}
+impl<T> ::core::cmp::PartialEq for __BindgenUnionField<T> {
+fn eq(&self, _other: &__BindgenUnionField<T>) -> bool {
+true
+}
+}
+impl<T> ::core::cmp::Eq for __BindgenUnionField<T> {}
+pub type __s8 = crate::ctypes::c_schar;
+pub type __u8 = crate::ctypes::c_uchar;
+pub type __s16 = crate::ctypes::c_short;
+pub type __u16 = crate::ctypes::c_ushort;
+pub type __s32 = crate::ctypes::c_int;
+pub type __u32 = crate::ctypes::c_uint;
+pub type __s64 = crate::ctypes::c_longlong;
+pub type __u64 = crate::ctypes::c_ulonglong;
+pub type umode_t = crate::ctypes::c_ushort;
+pub type __kernel_sighandler_t = ::core::option::Option<unsafe extern "C" fn(arg1: crate::ctypes::c_int)>;
+pub type __kernel_key_t = crate::ctypes::c_int;
+pub type __kernel_mqd_t = crate::ctypes::c_int;
+pub type __kernel_ino_t = crate::ctypes::c_ulong;
+pub type __kernel_off_t = crate::ctypes::c_long;
+pub type __kernel_pid_t = crate::ctypes::c_int;
+pub type __kernel_time_t = crate::ctypes::c_long;
+pub type __kernel_clock_t = crate::ctypes::c_long;
+pub type __kernel_timer_t = crate::ctypes::c_int;
+pub type __kernel_clockid_t = crate::ctypes::c_int;
+pub type __kernel_suseconds_t = crate::ctypes::c_long;
+pub type __kernel_daddr_t = crate::ctypes::c_int;
+pub type __kernel_caddr_t = *mut crate::ctypes::c_char;
+pub type __kernel_uid16_t = crate::ctypes::c_ushort;
+pub type __kernel_gid16_t = crate::ctypes::c_ushort;
+pub type __kernel_loff_t = crate::ctypes::c_longlong;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct __kernel_fsid_t {
+pub val: [crate::ctypes::c_int; 2usize],
+}
+pub type __le16 = __u16;
+pub type __be16 = __u16;
+pub type __le32 = __u32;
+pub type __be32 = __u32;
+pub type __le64 = __u64;
+pub type __be64 = __u64;
+pub type __sum16 = __u16;
+pub type __wsum = __u32;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct __user_cap_header_struct {
+pub version: __u32,
+pub pid: crate::ctypes::c_int,
+}
+pub type cap_user_header_t = *mut __user_cap_header_struct;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct __user_cap_data_struct {
+pub effective: __u32,
+pub permitted: __u32,
+pub inheritable: __u32,
+}
+pub type cap_user_data_t = *mut __user_cap_data_struct;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct vfs_cap_data {
+pub magic_etc: __le32,
+pub data: [vfs_cap_data__bindgen_ty_1; 2usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct vfs_cap_data__bindgen_ty_1 {
+pub permitted: __le32,
+pub inheritable: __le32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct f_owner_ex {
+pub type_: crate::ctypes::c_int,
+pub pid: __kernel_pid_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct sock_filter {
+pub code: __u16,
+pub jt: __u8,
+pub jf: __u8,
+pub k: __u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+pub struct sock_fprog {
+pub len: crate::ctypes::c_ushort,
+pub filter: *mut sock_filter,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct files_stat_struct {
+pub nr_files: crate::ctypes::c_int,
+pub nr_free_files: crate::ctypes::c_int,
+pub max_files: crate::ctypes::c_int,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct inodes_stat_t {
+pub nr_inodes: crate::ctypes::c_int,
+pub nr_unused: crate::ctypes::c_int,
+pub dummy: [crate::ctypes::c_int; 5usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+pub struct robust_list {
+pub next: *mut robust_list,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+pub struct robust_list_head {
+pub list: robust_list,
+pub futex_offset: crate::ctypes::c_long,
+pub list_op_pending: *mut robust_list,
+}
+pub const IPPROTO_IP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_IP;
+pub const IPPROTO_ICMP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_ICMP;
+pub const IPPROTO_IGMP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_IGMP;
+pub const IPPROTO_IPIP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_IPIP;
+pub const IPPROTO_TCP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_TCP;
+pub const IPPROTO_EGP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_EGP;
+pub const IPPROTO_PUP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_PUP;
+pub const IPPROTO_UDP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_UDP;
+pub const IPPROTO_IDP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_IDP;
+pub const IPPROTO_DCCP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_DCCP;
+pub const IPPROTO_RSVP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_RSVP;
+pub const IPPROTO_GRE: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_GRE;
+pub const IPPROTO_IPV6: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_IPV6;
+pub const IPPROTO_ESP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_ESP;
+pub const IPPROTO_AH: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_AH;
+pub const IPPROTO_BEETPH: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_BEETPH;
+pub const IPPROTO_PIM: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_PIM;
+pub const IPPROTO_COMP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_COMP;
+pub const IPPROTO_SCTP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_SCTP;
+pub const IPPROTO_UDPLITE: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_UDPLITE;
+pub const IPPROTO_RAW: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_RAW;
+pub const IPPROTO_MAX: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_MAX;
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub enum _bindgen_ty_1 {
+IPPROTO_IP = 0,
+IPPROTO_ICMP = 1,
+IPPROTO_IGMP = 2,
+IPPROTO_IPIP = 4,
+IPPROTO_TCP = 6,
+IPPROTO_EGP = 8,
+IPPROTO_PUP = 12,
+IPPROTO_UDP = 17,
+IPPROTO_IDP = 22,
+IPPROTO_DCCP = 33,
+IPPROTO_RSVP = 46,
+IPPROTO_GRE = 47,
+IPPROTO_IPV6 = 41,
+IPPROTO_ESP = 50,
+IPPROTO_AH = 51,
+IPPROTO_BEETPH = 94,
+IPPROTO_PIM = 103,
+IPPROTO_COMP = 108,
+IPPROTO_SCTP = 132,
+IPPROTO_UDPLITE = 136,
+IPPROTO_RAW = 255,
+IPPROTO_MAX = 256,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct in_addr {
+pub s_addr: __be32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ip_mreq {
+pub imr_multiaddr: in_addr,
+pub imr_interface: in_addr,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ip_mreqn {
+pub imr_multiaddr: in_addr,
+pub imr_address: in_addr,
+pub imr_ifindex: crate::ctypes::c_int,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ip_mreq_source {
+pub imr_multiaddr: __be32,
+pub imr_interface: __be32,
+pub imr_sourceaddr: __be32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ip_msfilter {
+pub imsf_multiaddr: __be32,
+pub imsf_interface: __be32,
+pub imsf_fmode: __u32,
+pub imsf_numsrc: __u32,
+pub imsf_slist: [__be32; 1usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct in_pktinfo {
+pub ipi_ifindex: crate::ctypes::c_int,
+pub ipi_spec_dst: in_addr,
+pub ipi_addr: in_addr,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct sockaddr_in {
+pub sin_family: u16,
+pub sin_port: __be16,
+pub sin_addr: in_addr,
+pub __pad: [crate::ctypes::c_uchar; 8usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "union-debug", derive(Debug))]
+pub struct in6_addr {
+pub in6_u: in6_addr__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union in6_addr__bindgen_ty_1 {
+pub u6_addr8: [__u8; 16usize],
+pub u6_addr16: [__be16; 8usize],
+pub u6_addr32: [__be32; 4usize],
+}
+#[cfg(feature = "union-debug")]
+impl ::core::fmt::Debug for in6_addr__bindgen_ty_1 {
+fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+f.debug_struct("in6_addr__bindgen_ty_1").finish_non_exhaustive()
+}
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "union-debug", derive(Debug))]
+pub struct sockaddr_in6 {
+pub sin6_family: crate::ctypes::c_ushort,
+pub sin6_port: __be16,
+pub sin6_flowinfo: __be32,
+pub sin6_addr: in6_addr,
+pub sin6_scope_id: __u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "union-debug", derive(Debug))]
+pub struct ipv6_mreq {
+pub ipv6mr_multiaddr: in6_addr,
+pub ipv6mr_ifindex: crate::ctypes::c_int,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "union-debug", derive(Debug))]
+pub struct in6_flowlabel_req {
+pub flr_dst: in6_addr,
+pub flr_label: __be32,
+pub flr_action: __u8,
+pub flr_share: __u8,
+pub flr_flags: __u16,
+pub flr_expires: __u16,
+pub flr_linger: __u16,
+pub __flr_pad: __u32,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub enum socket_state {
+SS_FREE = 0,
+SS_UNCONNECTED = 1,
+SS_CONNECTING = 2,
+SS_CONNECTED = 3,
+SS_DISCONNECTING = 4,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct pollfd {
+pub fd: crate::ctypes::c_int,
+pub events: crate::ctypes::c_short,
+pub revents: crate::ctypes::c_short,
+}
+#[repr(C)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct rand_pool_info {
+pub entropy_count: crate::ctypes::c_int,
+pub buf_size: crate::ctypes::c_int,
+pub buf: __IncompleteArrayField<__u32>,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct timespec {
+pub tv_sec: __kernel_time_t,
+pub tv_nsec: crate::ctypes::c_long,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct timeval {
+pub tv_sec: __kernel_time_t,
+pub tv_usec: __kernel_suseconds_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct timezone {
+pub tz_minuteswest: crate::ctypes::c_int,
+pub tz_dsttime: crate::ctypes::c_int,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct itimerspec {
+pub it_interval: timespec,
+pub it_value: timespec,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct itimerval {
+pub it_interval: timeval,
+pub it_value: timeval,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct task_struct {
+_unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct rusage {
+pub ru_utime: timeval,
+pub ru_stime: timeval,
+pub ru_maxrss: crate::ctypes::c_long,
+pub ru_ixrss: crate::ctypes::c_long,
+pub ru_idrss: crate::ctypes::c_long,
+pub ru_isrss: crate::ctypes::c_long,
+pub ru_minflt: crate::ctypes::c_long,
+pub ru_majflt: crate::ctypes::c_long,
+pub ru_nswap: crate::ctypes::c_long,
+pub ru_inblock: crate::ctypes::c_long,
+pub ru_oublock: crate::ctypes::c_long,
+pub ru_msgsnd: crate::ctypes::c_long,
+pub ru_msgrcv: crate::ctypes::c_long,
+pub ru_nsignals: crate::ctypes::c_long,
+pub ru_nvcsw: crate::ctypes::c_long,
+pub ru_nivcsw: crate::ctypes::c_long,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct rlimit {
+pub rlim_cur: crate::ctypes::c_ulong,
+pub rlim_max: crate::ctypes::c_ulong,
+}
+extern "C" {
+pub fn getrusage(p: *mut task_struct, who: crate::ctypes::c_int, ru: *mut rusage) -> crate::ctypes::c_int;
+}
+pub type __signalfn_t = ::core::option::Option<unsafe extern "C" fn(arg1: crate::ctypes::c_int)>;
+pub type __sighandler_t = __signalfn_t;
+pub type __restorefn_t = ::core::option::Option<unsafe extern "C" fn()>;
+pub type __sigrestore_t = __restorefn_t;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+pub struct sigaltstack {
+pub ss_sp: *mut crate::ctypes::c_void,
+pub ss_flags: crate::ctypes::c_int,
+pub ss_size: size_t,
+}
+pub type stack_t = sigaltstack;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union sigval {
+pub sival_int: crate::ctypes::c_int,
+pub sival_ptr: *mut crate::ctypes::c_void,
+}
+#[cfg(feature = "union-debug")]
+impl ::core::fmt::Debug for sigval {
+fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+f.debug_struct("sigval").finish_non_exhaustive()
+}
+}
+pub type sigval_t = sigval;
+#[repr(C)]
+#[cfg_attr(feature = "union-debug", derive(Debug))]
+pub struct siginfo {
+pub si_signo: crate::ctypes::c_int,
+pub si_errno: crate::ctypes::c_int,
+pub si_code: crate::ctypes::c_int,
+pub _sifields: siginfo__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct siginfo__bindgen_ty_1__bindgen_ty_1 {
+pub _pid: __kernel_pid_t,
+pub _uid: __kernel_uid32_t,
+}
+#[repr(C)]
+#[cfg_attr(feature = "union-debug", derive(Debug))]
+pub struct siginfo__bindgen_ty_1__bindgen_ty_2 {
+pub _tid: __kernel_timer_t,
+pub _overrun: crate::ctypes::c_int,
+pub _pad: __IncompleteArrayField<crate::ctypes::c_char>,
+pub _sigval: sigval_t,
+pub _sys_private: crate::ctypes::c_int,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "union-debug", derive(Debug))]
+pub struct siginfo__bindgen_ty_1__bindgen_ty_3 {
+pub _pid: __kernel_pid_t,
+pub _uid: __kernel_uid32_t,
+pub _sigval: sigval_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+pub struct siginfo__bindgen_ty_1__bindgen_ty_5 {
+pub _addr: *mut crate::ctypes::c_void,
+pub _addr_lsb: crate::ctypes::c_short,
+}
+pub type siginfo_t = siginfo;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "union-debug", derive(Debug))]
+pub struct sigevent {
+pub sigev_value: sigval_t,
+pub sigev_signo: crate::ctypes::c_int,
+pub sigev_notify: crate::ctypes::c_int,
+pub _sigev_un: sigevent__bindgen_ty_1,
+}
+#[cfg(feature = "union-debug")]
+impl ::core::fmt::Debug for sigevent__bindgen_ty_1 {
+fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+f.debug_struct("sigevent__bindgen_ty_1").finish_non_exhaustive()
+}
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct sigevent__bindgen_ty_1__bindgen_ty_1 {
+pub _function: ::core::option::Option<unsafe extern "C" fn(arg1: sigval_t)>,
+pub _attribute: *mut crate::ctypes::c_void,
+}
+pub type sigevent_t = sigevent;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct tcphdr {
+pub source: __be16,
+pub dest: __be16,
+pub seq: __be32,
+pub ack_seq: __be32,
+pub _bitfield_align_1: [u8; 0],
+pub _bitfield_1: __BindgenBitfieldUnit<[u8; 2usize]>,
+pub window: __be16,
+pub check: __sum16,
+pub urg_ptr: __be16,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union tcp_word_hdr {
+pub hdr: tcphdr,
+pub words: [__be32; 5usize],
+}
+#[cfg(feature = "union-debug")]
+impl ::core::fmt::Debug for tcp_word_hdr {
+fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+f.debug_struct("tcp_word_hdr").finish_non_exhaustive()
+}
+}
+pub const TCP_FLAG_CWR: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_CWR;
+pub const TCP_FLAG_ECE: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_ECE;
+pub const TCP_FLAG_URG: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_URG;
+pub const TCP_FLAG_ACK: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_ACK;
+pub const TCP_FLAG_PSH: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_PSH;
+pub const TCP_FLAG_RST: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_RST;
+pub const TCP_FLAG_SYN: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_SYN;
+pub const TCP_FLAG_FIN: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_FIN;
+pub const TCP_RESERVED_BITS: _bindgen_ty_2 = _bindgen_ty_2::TCP_RESERVED_BITS;
+pub const TCP_DATA_OFFSET: _bindgen_ty_2 = _bindgen_ty_2::TCP_DATA_OFFSET;
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub enum tcp_ca_state {
+TCP_CA_Open = 0,
+TCP_CA_Disorder = 1,
+TCP_CA_CWR = 2,
+TCP_CA_Recovery = 3,
+TCP_CA_Loss = 4,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct tcp_info {
+pub tcpi_state: __u8,
+pub tcpi_ca_state: __u8,
+pub tcpi_retransmits: __u8,
+pub tcpi_probes: __u8,
+pub tcpi_backoff: __u8,
+pub tcpi_options: __u8,
+pub _bitfield_align_1: [u8; 0],
+pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+pub tcpi_rto: __u32,
+pub tcpi_ato: __u32,
+pub tcpi_snd_mss: __u32,
+pub tcpi_rcv_mss: __u32,
+pub tcpi_unacked: __u32,
+pub tcpi_sacked: __u32,
+pub tcpi_lost: __u32,
+pub tcpi_retrans: __u32,
+pub tcpi_fackets: __u32,
+pub tcpi_last_data_sent: __u32,
+pub tcpi_last_ack_sent: __u32,
+pub tcpi_last_data_recv: __u32,
+pub tcpi_last_ack_recv: __u32,
+pub tcpi_pmtu: __u32,
+pub tcpi_rcv_ssthresh: __u32,
+pub tcpi_rtt: __u32,
+pub tcpi_rttvar: __u32,
+pub tcpi_snd_ssthresh: __u32,
+pub tcpi_snd_cwnd: __u32,
+pub tcpi_advmss: __u32,
+pub tcpi_reordering: __u32,
+pub tcpi_rcv_rtt: __u32,
+pub tcpi_rcv_space: __u32,
+pub tcpi_total_retrans: __u32,
+}
+impl tcp_info {
+#[inline]
+pub fn tcpi_snd_wscale(&self) -> __u8 {
+unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 4u8) as u8) }
+}
+#[inline]
+pub fn set_tcpi_snd_wscale(&mut self, val: __u8) {
+unsafe {
+let val: u8 = ::core::mem::transmute(val);
+self._bitfield_1.set(0usize, 4u8, val as u64)
+}
+}
+#[inline]
+pub fn tcpi_rcv_wscale(&self) -> __u8 {
+unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 4u8) as u8) }
+}
+#[inline]
+pub fn set_tcpi_rcv_wscale(&mut self, val: __u8) {
+unsafe {
+let val: u8 = ::core::mem::transmute(val);
+self._bitfield_1.set(4usize, 4u8, val as u64)
+}
+}
+#[inline]
+pub fn new_bitfield_1(tcpi_snd_wscale: __u8, tcpi_rcv_wscale: __u8) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+__bindgen_bitfield_unit.set(0usize, 4u8, {
+let tcpi_snd_wscale: u8 = unsafe { ::core::mem::transmute(tcpi_snd_wscale) };
+tcpi_snd_wscale as u64
+});
+__bindgen_bitfield_unit.set(4usize, 4u8, {
+let tcpi_rcv_wscale: u8 = unsafe { ::core::mem::transmute(tcpi_rcv_wscale) };
+tcpi_rcv_wscale as u64
+});
+__bindgen_bitfield_unit
+}
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct tcp_md5sig {
+pub tcpm_addr: __kernel_sockaddr_storage,
+pub __tcpm_pad1: __u16,
+pub tcpm_keylen: __u16,
+pub __tcpm_pad2: __u32,
+pub tcpm_key: [__u8; 80usize],
+}
+pub type cc_t = crate::ctypes::c_uchar;
+pub type speed_t = crate::ctypes::c_uint;
+pub type tcflag_t = crate::ctypes::c_uint;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct winsize {
+pub ws_row: crate::ctypes::c_ushort,
+pub ws_col: crate::ctypes::c_ushort,
+pub ws_xpixel: crate::ctypes::c_ushort,
+pub ws_ypixel: crate::ctypes::c_ushort,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct termiox {
+pub x_hflag: __u16,
+pub x_cflag: __u16,
+pub x_rflag: [__u16; 5usize],
+pub x_sflag: __u16,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+pub struct iovec {
+pub iov_base: *mut crate::ctypes::c_void,
+pub iov_len: __kernel_size_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct sockaddr_un {
+pub sun_family: u16,
+pub sun_path: [crate::ctypes::c_char; 108usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct oldold_utsname {
+pub sysname: [crate::ctypes::c_char; 9usize],
+pub nodename: [crate::ctypes::c_char; 9usize],
+pub release: [crate::ctypes::c_char; 9usize],
+pub version: [crate::ctypes::c_char; 9usize],
+pub machine: [crate::ctypes::c_char; 9usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct old_utsname {
+pub sysname: [crate::ctypes::c_char; 65usize],
+pub nodename: [crate::ctypes::c_char; 65usize],
+pub release: [crate::ctypes::c_char; 65usize],
+pub version: [crate::ctypes::c_char; 65usize],
+pub machine: [crate::ctypes::c_char; 65usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct new_utsname {
+pub sysname: [crate::ctypes::c_char; 65usize],
+pub nodename: [crate::ctypes::c_char; 65usize],
+pub release: [crate::ctypes::c_char; 65usize],
+pub version: [crate::ctypes::c_char; 65usize],
+pub machine: [crate::ctypes::c_char; 65usize],
+pub domainname: [crate::ctypes::c_char; 65usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct sockaddr {
+pub __storage: __kernel_sockaddr_storage,
+}
+pub type __kernel_sa_family_t = u16;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct linger {
+pub l_onoff: crate::ctypes::c_int,
+pub l_linger: crate::ctypes::c_int,
+}
+pub type __kernel_time64_t = crate::ctypes::c_longlong;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct __kernel_timespec {
+pub tv_sec: __kernel_time64_t,
+pub tv_nsec: crate::ctypes::c_longlong,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct __kernel_itimerspec {
+pub it_interval: __kernel_timespec,
+pub it_value: __kernel_timespec,
+}
+pub type socklen_t = crate::ctypes::c_uint;
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct user_desc {
+pub entry_number: crate::ctypes::c_uint,
+pub base_addr: crate::ctypes::c_uint,
+pub limit: crate::ctypes::c_uint,
+pub _bitfield_align_1: [u8; 0],
+pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+pub __bindgen_padding_0: [u8; 3usize],
+}
+impl siginfo {
+/// The signal number.
+#[inline]
+pub fn si_signo(&self) -> crate::ctypes::c_int {
+self.si_signo
+}
+/// The errno value associated with the signal, which is usually 0.
+#[inline]
+pub fn si_errno(&self) -> crate::ctypes::c_int {
+self.si_errno
+}
+/// The signal code, such as `SI_USER` or `CLD_EXITED`, which determines which of the other fields are valid.
+#[inline]
+pub fn si_code(&self) -> crate::ctypes::c_int {
+self.si_code
+}
+/// The PID of the sending process, for signals sent by `kill`, `sigqueue`, and for `SIGCHLD`.
+///
+/// # Safety
+///
+/// The signal must be one for which the kernel fills in this field.
+#[inline]
+pub unsafe fn si_pid(&self) -> __kernel_pid_t {
+self._sifields._kill.as_ref()._pid
+}
+/// The real UID of the sending process, for signals sent by `kill`, `sigqueue`, and for `SIGCHLD`.
+///
+/// # Safety
+///
+/// The signal must be one for which the kernel fills in this field.
+#[inline]
+pub unsafe fn si_uid(&self) -> __kernel_uid32_t {
+self._sifields._kill.as_ref()._uid
+}
+/// The exit status or signal of the child, for `SIGCHLD`.
+///
+/// # Safety
+///
+/// The signal must be one for which the kernel fills in this field.
+#[inline]
+pub unsafe fn si_status(&self) -> crate::ctypes::c_int {
+self._sifields._sigchld.as_ref()._status
+}
+/// The faulting address, for `SIGSEGV`, `SIGBUS`, `SIGILL`, `SIGFPE`, and `SIGTRAP`.
+///
+/// # Safety
+///
+/// The signal must be one for which the kernel fills in this field.
+#[inline]
+pub unsafe fn si_addr(&self) -> *mut crate::ctypes::c_void {
+self._sifields._sigfault.as_ref()._addr
+}
+/// The file descriptor, for `SIGPOLL`.
+///
+/// # Safety
+///
+/// The signal must be one for which the kernel fills in this field.
+#[inline]
+pub unsafe fn si_fd(&self) -> crate::ctypes::c_int {
+self._sifields._sigpoll.as_ref()._fd
+}
+/// The value passed to `sigqueue`, or with a POSIX timer.
+///
+/// # Safety
+///
+/// The signal must be one for which the kernel fills in this field.
+#[inline]
+pub unsafe fn si_value(&self) -> sigval {
+self._sifields._rt.as_ref()._sigval
+}
+}
+impl stat {
+/// Read a `stat` from `bytes`, which need not be aligned, such as a buffer the kernel wrote it into.
+///
+/// # Safety
+///
+/// `bytes` must be at least `size_of::<Self>()` bytes long, and those bytes must be initialized.
+#[inline]
+pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> Self {
+debug_assert!(bytes.len() >= ::core::mem::size_of::<Self>());
+::core::ptr::read_unaligned(bytes.as_ptr().cast::<Self>())
+}
+/// Get a reference to a `stat` the kernel wrote into `uninit`, such as by passing its `as_mut_ptr()` to a syscall.
+///
+/// # Safety
+///
+/// All of `uninit` must have been initialized, such as by a successful syscall.
+#[inline]
+pub unsafe fn assume_init_ref(uninit: &::core::mem::MaybeUninit<Self>) -> &Self {
+&*uninit.as_ptr()
+}
+}
+impl statfs {
+/// Read a `statfs` from `bytes`, which need not be aligned, such as a buffer the kernel wrote it into.
+///
+/// # Safety
+///
+/// `bytes` must be at least `size_of::<Self>()` bytes long, and those bytes must be initialized.
+#[inline]
+pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> Self {
+debug_assert!(bytes.len() >= ::core::mem::size_of::<Self>());
+::core::ptr::read_unaligned(bytes.as_ptr().cast::<Self>())
+}
+/// Get a reference to a `statfs` the kernel wrote into `uninit`, such as by passing its `as_mut_ptr()` to a syscall.
+///
+/// # Safety
+///
+/// All of `uninit` must have been initialized, such as by a successful syscall.
+#[inline]
+pub unsafe fn assume_init_ref(uninit: &::core::mem::MaybeUninit<Self>) -> &Self {
+&*uninit.as_ptr()
+}
+}
+impl statfs64 {
+/// Read a `statfs64` from `bytes`, which need not be aligned, such as a buffer the kernel wrote it into.
+///
+/// # Safety
+///
+/// `bytes` must be at least `size_of::<Self>()` bytes long, and those bytes must be initialized.
+#[inline]
+pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> Self {
+debug_assert!(bytes.len() >= ::core::mem::size_of::<Self>());
+::core::ptr::read_unaligned(bytes.as_ptr().cast::<Self>())
+}
+/// Get a reference to a `statfs64` the kernel wrote into `uninit`, such as by passing its `as_mut_ptr()` to a syscall.
+///
+/// # Safety
+///
+/// All of `uninit` must have been initialized, such as by a successful syscall.
+#[inline]
+pub unsafe fn assume_init_ref(uninit: &::core::mem::MaybeUninit<Self>) -> &Self {
+&*uninit.as_ptr()
+}
+}
+impl rusage {
+/// Read a `rusage` from `bytes`, which need not be aligned, such as a buffer the kernel wrote it into.
+///
+/// # Safety
+///
+/// `bytes` must be at least `size_of::<Self>()` bytes long, and those bytes must be initialized.
+#[inline]
+pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> Self {
+debug_assert!(bytes.len() >= ::core::mem::size_of::<Self>());
+::core::ptr::read_unaligned(bytes.as_ptr().cast::<Self>())
+}
+/// Get a reference to a `rusage` the kernel wrote into `uninit`, such as by passing its `as_mut_ptr()` to a syscall.
+///
+/// # Safety
+///
+/// All of `uninit` must have been initialized, such as by a successful syscall.
+#[inline]
+pub unsafe fn assume_init_ref(uninit: &::core::mem::MaybeUninit<Self>) -> &Self {
+&*uninit.as_ptr()
+}
+}
@@ -1,134 +1,137 @@
-/* automatically generated by rust-bindgen 0.59.1 */
-
-pub const EPERM: u32 = 1;
-pub const ENOENT: u32 = 2;
-pub const ESRCH: u32 = 3;
-pub const EINTR: u32 = 4;
-pub const EIO: u32 = 5;
-pub const ENXIO: u32 = 6;
-pub const E2BIG: u32 = 7;
-pub const ENOEXEC: u32 = 8;
-pub const EBADF: u32 = 9;
-pub const ECHILD: u32 = 10;
-pub const EAGAIN: u32 = 11;
-pub const ENOMEM: u32 = 12;
-pub const EACCES: u32 = 13;
-pub const EFAULT: u32 = 14;
-pub const ENOTBLK: u32 = 15;
-pub const EBUSY: u32 = 16;
-pub const EEXIST: u32 = 17;
-pub const EXDEV: u32 = 18;
-pub const ENODEV: u32 = 19;
-pub const ENOTDIR: u32 = 20;
-pub const EISDIR: u32 = 21;
-pub const EINVAL: u32 = 22;
-pub const ENFILE: u32 = 23;
-pub const EMFILE: u32 = 24;
-pub const ENOTTY: u32 = 25;
-pub const ETXTBSY: u32 = 26;
-pub const EFBIG: u32 = 27;
-pub const ENOSPC: u32 = 28;
-pub const ESPIPE: u32 = 29;
-pub const EROFS: u32 = 30;
-pub const EMLINK: u32 = 31;
-pub const EPIPE: u32 = 32;
-pub const EDOM: u32 = 33;
-pub const ERANGE: u32 = 34;
-pub const EDEADLK: u32 = 35;
-pub const ENAMETOOLONG: u32 = 36;
-pub const ENOLCK: u32 = 37;
-pub const ENOSYS: u32 = 38;
-pub const ENOTEMPTY: u32 = 39;
-pub const ELOOP: u32 = 40;
-pub const EWOULDBLOCK: u32 = 11;
-pub const ENOMSG: u32 = 42;
-pub const EIDRM: u32 = 43;
-pub const ECHRNG: u32 = 44;
-pub const EL2NSYNC: u32 = 45;
-pub const EL3HLT: u32 = 46;
-pub const EL3RST: u32 = 47;
-pub const ELNRNG: u32 = 48;
-pub const EUNATCH: u32 = 49;
-pub const ENOCSI: u32 = 50;
-pub const EL2HLT: u32 = 51;
-pub const EBADE: u32 = 52;
-pub const EBADR: u32 = 53;
-pub const EXFULL: u32 = 54;
-pub const ENOANO: u32 = 55;
-pub const EBADRQC: u32 = 56;
-pub const EBADSLT: u32 = 57;
-pub const EDEADLOCK: u32 = 35;
-pub const EBFONT: u32 = 59;
-pub const ENOSTR: u32 = 60;
-pub const ENODATA: u32 = 61;
-pub const ETIME: u32 = 62;
-pub const ENOSR: u32 = 63;
-pub const ENONET: u32 = 64;
-pub const ENOPKG: u32 = 65;
-pub const EREMOTE: u32 = 66;
-pub const ENOLINK: u32 = 67;
-pub const EADV: u32 = 68;
-pub const ESRMNT: u32 = 69;
-pub const ECOMM: u32 = 70;
-pub const EPROTO: u32 = 71;
-pub const EMULTIHOP: u32 = 72;
-pub const EDOTDOT: u32 = 73;
-pub const EBADMSG: u32 = 74;
-pub const EOVERFLOW: u32 = 75;
-pub const ENOTUNIQ: u32 = 76;
-pub const EBADFD: u32 = 77;
-pub const EREMCHG: u32 = 78;
-pub const ELIBACC: u32 = 79;
-pub const ELIBBAD: u32 = 80;
-pub const ELIBSCN: u32 = 81;
-pub const ELIBMAX: u32 = 82;
-pub const ELIBEXEC: u32 = 83;
-pub const EILSEQ: u32 = 84;
-pub const ERESTART: u32 = 85;
-pub const ESTRPIPE: u32 = 86;
-pub const EUSERS: u32 = 87;
-pub const ENOTSOCK: u32 = 88;
-pub const EDESTADDRREQ: u32 = 89;
-pub const EMSGSIZE: u32 = 90;
-pub const EPROTOTYPE: u32 = 91;
-pub const ENOPROTOOPT: u32 = 92;
-pub const EPROTONOSUPPORT: u32 = 93;
-pub const ESOCKTNOSUPPORT: u32 = 94;
-pub const EOPNOTSUPP: u32 = 95;
-pub const EPFNOSUPPORT: u32 = 96;
-pub const EAFNOSUPPORT: u32 = 97;
-pub const EADDRINUSE: u32 = 98;
-pub const EADDRNOTAVAIL: u32 = 99;
-pub const ENETDOWN: u32 = 100;
-pub const ENETUNREACH: u32 = 101;
-pub const ENETRESET: u32 = 102;
-pub const ECONNABORTED: u32 = 103;
-pub const ECONNRESET: u32 = 104;
-pub const ENOBUFS: u32 = 105;
-pub const EISCONN: u32 = 106;
-pub const ENOTCONN: u32 = 107;
-pub const ESHUTDOWN: u32 = 108;
-pub const ETOOMANYREFS: u32 = 109;
-pub const ETIMEDOUT: u32 = 110;
-pub const ECONNREFUSED: u32 = 111;
-pub const EHOSTDOWN: u32 = 112;
-pub const EHOSTUNREACH: u32 = 113;
-pub const EALREADY: u32 = 114;
-pub const EINPROGRESS: u32 = 115;
-pub const ESTALE: u32 = 116;
-pub const EUCLEAN: u32 = 117;
-pub const ENOTNAM: u32 = 118;
-pub const ENAVAIL: u32 = 119;
-pub const EISNAM: u32 = 120;
-pub const EREMOTEIO: u32 = 121;
-pub const EDQUOT: u32 = 122;
-pub const ENOMEDIUM: u32 = 123;
-pub const EMEDIUMTYPE: u32 = 124;
-pub const ECANCELED: u32 = 125;
-pub const ENOKEY: u32 = 126;
-pub const EKEYEXPIRED: u32 = 127;
-pub const EKEYREVOKED: u32 = 128;
-pub const EKEYREJECTED: u32 = 129;
-pub const EOWNERDEAD: u32 = 130;
-pub const ENOTRECOVERABLE: u32 = 131;
-pub const ERFKILL: u32 = 132;
+include!("../shared/errno.rs");
+include!("errno_consts.rs");
+/// The errno values and their names, sorted by value, with aliases after the names they alias.
+pub static ERRNO_NAMES: &[(u32, &str)] = &[
+(1, "EPERM"),
+(2, "ENOENT"),
+(3, "ESRCH"),
+(4, "EINTR"),
+(5, "EIO"),
+(6, "ENXIO"),
+(7, "E2BIG"),
+(8, "ENOEXEC"),
+(9, "EBADF"),
+(10, "ECHILD"),
+(11, "EAGAIN"),
+(11, "EWOULDBLOCK"),
+(12, "ENOMEM"),
+(13, "EACCES"),
+(14, "EFAULT"),
+(15, "ENOTBLK"),
+(16, "EBUSY"),
+(17, "EEXIST"),
+(18, "EXDEV"),
+(19, "ENODEV"),
+(20, "ENOTDIR"),
+(21, "EISDIR"),
+(22, "EINVAL"),
+(23, "ENFILE"),
+(24, "EMFILE"),
+(25, "ENOTTY"),
+(26, "ETXTBSY"),
+(27, "EFBIG"),
+(28, "ENOSPC"),
+(29, "ESPIPE"),
+(30, "EROFS"),
+(31, "EMLINK"),
+(32, "EPIPE"),
+(33, "EDOM"),
+(34, "ERANGE"),
+(35, "EDEADLK"),
+(35, "EDEADLOCK"),
+(36, "ENAMETOOLONG"),
+(37, "ENOLCK"),
+(38, "ENOSYS"),
+(39, "ENOTEMPTY"),
+(40, "ELOOP"),
+(42, "ENOMSG"),
+(43, "EIDRM"),
+(44, "ECHRNG"),
+(45, "EL2NSYNC"),
+(46, "EL3HLT"),
+(47, "EL3RST"),
+(48, "ELNRNG"),
+(49, "EUNATCH"),
+(50, "ENOCSI"),
+(51, "EL2HLT"),
+(52, "EBADE"),
+(53, "EBADR"),
+(54, "EXFULL"),
+(55, "ENOANO"),
+(56, "EBADRQC"),
+(57, "EBADSLT"),
+(59, "EBFONT"),
+(60, "ENOSTR"),
+(61, "ENODATA"),
+(62, "ETIME"),
+(63, "ENOSR"),
+(64, "ENONET"),
+(65, "ENOPKG"),
+(66, "EREMOTE"),
+(67, "ENOLINK"),
+(68, "EADV"),
+(69, "ESRMNT"),
+(70, "ECOMM"),
+(71, "EPROTO"),
+(72, "EMULTIHOP"),
+(73, "EDOTDOT"),
+(74, "EBADMSG"),
+(75, "EOVERFLOW"),
+(76, "ENOTUNIQ"),
+(77, "EBADFD"),
+(78, "EREMCHG"),
+(79, "ELIBACC"),
+(80, "ELIBBAD"),
+(81, "ELIBSCN"),
+(82, "ELIBMAX"),
+(83, "ELIBEXEC"),
+(84, "EILSEQ"),
+(85, "ERESTART"),
+(86, "ESTRPIPE"),
+(87, "EUSERS"),
+(88, "ENOTSOCK"),
+(89, "EDESTADDRREQ"),
+(90, "EMSGSIZE"),
+(91, "EPROTOTYPE"),
+(92, "ENOPROTOOPT"),
+(93, "EPROTONOSUPPORT"),
+(94, "ESOCKTNOSUPPORT"),
+(95, "EOPNOTSUPP"),
+(96, "EPFNOSUPPORT"),
+(97, "EAFNOSUPPORT"),
+(98, "EADDRINUSE"),
+(99, "EADDRNOTAVAIL"),
+(100, "ENETDOWN"),
+(101, "ENETUNREACH"),
+(102, "ENETRESET"),
+(103, "ECONNABORTED"),
+(104, "ECONNRESET"),
+(105, "ENOBUFS"),
+(106, "EISCONN"),
+(107, "ENOTCONN"),
+(108, "ESHUTDOWN"),
+(109, "ETOOMANYREFS"),
+(110, "ETIMEDOUT"),
+(111, "ECONNREFUSED"),
+(112, "EHOSTDOWN"),
+(113, "EHOSTUNREACH"),
+(114, "EALREADY"),
+(115, "EINPROGRESS"),
+(116, "ESTALE"),
+(117, "EUCLEAN"),
+(118, "ENOTNAM"),
+(119, "ENAVAIL"),
+(120, "EISNAM"),
+(121, "EREMOTEIO"),
+(122, "EDQUOT"),
+(123, "ENOMEDIUM"),
+(124, "EMEDIUMTYPE"),
+(125, "ECANCELED"),
+(126, "ENOKEY"),
+(127, "EKEYEXPIRED"),
+(128, "EKEYREVOKED"),
+(129, "EKEYREJECTED"),
+(130, "EOWNERDEAD"),
+(131, "ENOTRECOVERABLE"),
+(132, "ERFKILL"),
+];
@@ -1,9 +1,65 @@
 /// modules/errno.h
 #[cfg(feature = "errno")]
+#[cfg_attr(docsrs, doc(cfg(feature = "errno")))]
 pub mod r#errno;
+/// The constants from modules/errno.h, without its types.
+#[cfg(all(feature = "const-only", feature = "errno"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "const-only", feature = "errno"))))]
+pub mod errno_consts;
 /// modules/general.h
 #[cfg(feature = "general")]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
 pub mod r#general;
+/// The constants from modules/general.h, without its types.
+#[cfg(all(feature = "const-only", feature = "general"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "const-only", feature = "general"))))]
+pub mod general_consts;
 /// modules/netlink.h
 #[cfg(feature = "netlink")]
+#[cfg_attr(docsrs, doc(cfg(feature = "netlink")))]
 pub mod r#netlink;
+/// The constants from modules/netlink.h, without its types.
+#[cfg(all(feature = "const-only", feature = "netlink"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "const-only", feature = "netlink"))))]
+pub mod netlink_consts;
+/// The `__kernel_*` types, such as `__kernel_size_t` and `__kernel_loff_t`.
+#[cfg(feature = "general")]
+#[cfg_attr(docsrs, doc(cfg(feature = "general")))]
+pub mod kernel_types {
+pub use super::general::__kernel_caddr_t;
+pub use super::general::__kernel_clock_t;
+pub use super::general::__kernel_clockid_t;
+pub use super::general::__kernel_daddr_t;
+pub use super::general::__kernel_fd_set;
+pub use super::general::__kernel_fsid_t;
+pub use super::general::__kernel_gid16_t;
+pub use super::general::__kernel_gid32_t;
+pub use super::general::__kernel_gid_t;
+pub use super::general::__kernel_ino_t;
+pub use super::general::__kernel_ipc_pid_t;
+pub use super::general::__kernel_itimerspec;
+pub use super::general::__kernel_key_t;
+pub use super::general::__kernel_loff_t;
+pub use super::general::__kernel_mode_t;
+pub use super::general::__kernel_mqd_t;
+pub use super::general::__kernel_nlink_t;
+pub use super::general::__kernel_off_t;
+pub use super::general::__kernel_old_dev_t;
+pub use super::general::__kernel_old_gid_t;
+pub use super::general::__kernel_old_uid_t;
+pub use super::general::__kernel_pid_t;
+pub use super::general::__kernel_ptrdiff_t;
+pub use super::general::__kernel_sa_family_t;
+pub use super::general::__kernel_sighandler_t;
+pub use super::general::__kernel_size_t;
+pub use super::general::__kernel_sockaddr_storage;
+pub use super::general::__kernel_ssize_t;
+pub use super::general::__kernel_suseconds_t;
+pub use super::general::__kernel_time64_t;
+pub use super::general::__kernel_time_t;
+pub use super::general::__kernel_timer_t;
+pub use super::general::__kernel_timespec;
+pub use super::general::__kernel_uid16_t;
+pub use super::general::__kernel_uid32_t;
+pub use super::general::__kernel_uid_t;
+}
@@ -1,1778 +1,37 @@
-/* automatically generated by rust-bindgen 0.59.1 */
-
-#[repr(C)]
-#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct __BindgenBitfieldUnit<Storage> {
-storage: Storage,
-}
-impl<Storage> __BindgenBitfieldUnit<Storage> {
-#[inline]
-pub const fn new(storage: Storage) -> Self {
-Self { storage }
-}
-}
-impl<Storage> __BindgenBitfieldUnit<Storage>
-where
-Storage: AsRef<[u8]> + AsMut<[u8]>,
-{
-#[inline]
-pub fn get_bit(&self, index: usize) -> bool {
-debug_assert!(index / 8 < self.storage.as_ref().len());
-let byte_index = index / 8;
-let byte = self.storage.as_ref()[byte_index];
-let bit_index = if cfg!(target_endian = "big") { 7 - (index % 8) } else { index % 8 };
-let mask = 1 << bit_index;
-byte & mask == mask
-}
-#[inline]
-pub fn set_bit(&mut self, index: usize, val: bool) {
-debug_assert!(index / 8 < self.storage.as_ref().len());
-let byte_index = index / 8;
-let byte = &mut self.storage.as_mut()[byte_index];
-let bit_index = if cfg!(target_endian = "big") { 7 - (index % 8) } else { index % 8 };
-let mask = 1 << bit_index;
-if val {
-*byte |= mask;
-} else {
-*byte &= !mask;
-}
-}
-#[inline]
-pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
-debug_assert!(bit_width <= 64);
-debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
-debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
-let mut val = 0;
-for i in 0..(bit_width as usize) {
-if self.get_bit(i + bit_offset) {
-let index = if cfg!(target_endian = "big") { bit_width as usize - 1 - i } else { i };
-val |= 1 << index;
-}
-}
-val
-}
-#[inline]
-pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
-debug_assert!(bit_width <= 64);
-debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
-debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
-for i in 0..(bit_width as usize) {
-let mask = 1 << i;
-let val_bit_is_set = val & mask == mask;
-let index = if cfg!(target_endian = "big") { bit_width as usize - 1 - i } else { i };
-self.set_bit(index + bit_offset, val_bit_is_set);
-}
-}
-}
-#[repr(C)]
-#[derive(Default)]
-pub struct __IncompleteArrayField<T>(::core::marker::PhantomData<T>, [T; 0]);
-impl<T> __IncompleteArrayField<T> {
-#[inline]
-pub const fn new() -> Self {
-__IncompleteArrayField(::core::marker::PhantomData, [])
-}
-#[inline]
-pub fn as_ptr(&self) -> *const T {
-self as *const _ as *const T
-}
-#[inline]
-pub fn as_mut_ptr(&mut self) -> *mut T {
-self as *mut _ as *mut T
-}
-#[inline]
-pub unsafe fn as_slice(&self, len: usize) -> &[T] {
-::core::slice::from_raw_parts(self.as_ptr(), len)
-}
-#[inline]
-pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [T] {
-::core::slice::from_raw_parts_mut(self.as_mut_ptr(), len)
-}
-}
-impl<T> ::core::fmt::Debug for __IncompleteArrayField<T> {
-fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-fmt.write_str("__IncompleteArrayField")
-}
-}
-#[repr(C)]
-pub struct __BindgenUnionField<T>(::core::marker::PhantomData<T>);
-impl<T> __BindgenUnionField<T> {
-#[inline]
-pub const fn new() -> Self {
-__BindgenUnionField(::core::marker::PhantomData)
-}
-#[inline]
-pub unsafe fn as_ref(&self) -> &T {
-::core::mem::transmute(self)
-}
-#[inline]
-pub unsafe fn as_mut(&mut self) -> &mut T {
-::core::mem::transmute(self)
-}
-}
-impl<T> ::core::default::Default for __BindgenUnionField<T> {
-#[inline]
-fn default() -> Self {
-Self::new()
-}
-}
-impl<T> ::core::clone::Clone for __BindgenUnionField<T> {
-#[inline]
-fn clone(&self) -> Self {
-Self::new()
-}
-}
-impl<T> ::core::marker::Copy for __BindgenUnionField<T> {}
-impl<T> ::core::fmt::Debug for __BindgenUnionField<T> {
-fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-fmt.write_str("__BindgenUnionField")
-}
-}
-impl<T> ::core::hash::Hash for __BindgenUnionField<T> {
-fn hash<H: ::core::hash::Hasher>(&self, _state: &mut H) {}
-}
-impl<T> ::core::cmp::PartialEq for __BindgenUnionField<T> {
-fn eq(&self, _other: &__BindgenUnionField<T>) -> bool {
-true
-}
-}
-impl<T> ::core::cmp::Eq for __BindgenUnionField<T> {}
-pub const LINUX_VERSION_CODE: u32 = 132640;
-pub const AT_SYSINFO_EHDR: u32 = 33;
-pub const AT_NULL: u32 = 0;
-pub const AT_IGNORE: u32 = 1;
-pub const AT_EXECFD: u32 = 2;
-pub const AT_PHDR: u32 = 3;
-pub const AT_PHENT: u32 = 4;
-pub const AT_PHNUM: u32 = 5;
-pub const AT_PAGESZ: u32 = 6;
-pub const AT_BASE: u32 = 7;
-pub const AT_FLAGS: u32 = 8;
-pub const AT_ENTRY: u32 = 9;
-pub const AT_NOTELF: u32 = 10;
-pub const AT_UID: u32 = 11;
-pub const AT_EUID: u32 = 12;
-pub const AT_GID: u32 = 13;
-pub const AT_EGID: u32 = 14;
-pub const AT_PLATFORM: u32 = 15;
-pub const AT_HWCAP: u32 = 16;
-pub const AT_CLKTCK: u32 = 17;
-pub const AT_SECURE: u32 = 23;
-pub const AT_BASE_PLATFORM: u32 = 24;
-pub const AT_RANDOM: u32 = 25;
-pub const AT_EXECFN: u32 = 31;
-pub const __BITS_PER_LONG: u32 = 64;
-pub const __FD_SETSIZE: u32 = 1024;
-pub const O_ACCMODE: u32 = 3;
-pub const O_RDONLY: u32 = 0;
-pub const O_WRONLY: u32 = 1;
-pub const O_RDWR: u32 = 2;
-pub const O_CREAT: u32 = 64;
-pub const O_EXCL: u32 = 128;
-pub const O_NOCTTY: u32 = 256;
-pub const O_TRUNC: u32 = 512;
-pub const O_APPEND: u32 = 1024;
-pub const O_NONBLOCK: u32 = 2048;
-pub const O_SYNC: u32 = 4096;
-pub const FASYNC: u32 = 8192;
-pub const O_DIRECT: u32 = 16384;
-pub const O_LARGEFILE: u32 = 32768;
-pub const O_DIRECTORY: u32 = 65536;
-pub const O_NOFOLLOW: u32 = 131072;
-pub const O_NOATIME: u32 = 262144;
-pub const O_CLOEXEC: u32 = 524288;
-pub const O_NDELAY: u32 = 2048;
-pub const F_DUPFD: u32 = 0;
-pub const F_GETFD: u32 = 1;
-pub const F_SETFD: u32 = 2;
-pub const F_GETFL: u32 = 3;
-pub const F_SETFL: u32 = 4;
-pub const F_GETLK: u32 = 5;
-pub const F_SETLK: u32 = 6;
-pub const F_SETLKW: u32 = 7;
-pub const F_SETOWN: u32 = 8;
-pub const F_GETOWN: u32 = 9;
-pub const F_SETSIG: u32 = 10;
-pub const F_GETSIG: u32 = 11;
-pub const F_GETLK64: u32 = 12;
-pub const F_SETLK64: u32 = 13;
-pub const F_SETLKW64: u32 = 14;
-pub const F_SETOWN_EX: u32 = 15;
-pub const F_GETOWN_EX: u32 = 16;
-pub const F_OWNER_TID: u32 = 0;
-pub const F_OWNER_PID: u32 = 1;
-pub const F_OWNER_PGRP: u32 = 2;
-pub const FD_CLOEXEC: u32 = 1;
-pub const F_RDLCK: u32 = 0;
-pub const F_WRLCK: u32 = 1;
-pub const F_UNLCK: u32 = 2;
-pub const F_EXLCK: u32 = 4;
-pub const F_SHLCK: u32 = 8;
-pub const F_INPROGRESS: u32 = 16;
-pub const LOCK_SH: u32 = 1;
-pub const LOCK_EX: u32 = 2;
-pub const LOCK_NB: u32 = 4;
-pub const LOCK_UN: u32 = 8;
-pub const LOCK_MAND: u32 = 32;
-pub const LOCK_READ: u32 = 64;
-pub const LOCK_WRITE: u32 = 128;
-pub const LOCK_RW: u32 = 192;
-pub const F_LINUX_SPECIFIC_BASE: u32 = 1024;
-pub const F_SETLEASE: u32 = 1024;
-pub const F_GETLEASE: u32 = 1025;
-pub const F_CANCELLK: u32 = 1029;
-pub const F_DUPFD_CLOEXEC: u32 = 1030;
-pub const F_NOTIFY: u32 = 1026;
-pub const DN_ACCESS: u32 = 1;
-pub const DN_MODIFY: u32 = 2;
-pub const DN_CREATE: u32 = 4;
-pub const DN_DELETE: u32 = 8;
-pub const DN_RENAME: u32 = 16;
-pub const DN_ATTRIB: u32 = 32;
-pub const DN_MULTISHOT: u32 = 2147483648;
-pub const AT_FDCWD: i32 = -100;
-pub const AT_SYMLINK_NOFOLLOW: u32 = 256;
-pub const AT_REMOVEDIR: u32 = 512;
-pub const AT_SYMLINK_FOLLOW: u32 = 1024;
-pub const EPOLL_CLOEXEC: u32 = 524288;
-pub const EPOLL_CTL_ADD: u32 = 1;
-pub const EPOLL_CTL_DEL: u32 = 2;
-pub const EPOLL_CTL_MOD: u32 = 3;
-pub const EPOLLONESHOT: u32 = 1073741824;
-pub const EPOLLET: u32 = 2147483648;
-pub const POSIX_FADV_NORMAL: u32 = 0;
-pub const POSIX_FADV_RANDOM: u32 = 1;
-pub const POSIX_FADV_SEQUENTIAL: u32 = 2;
-pub const POSIX_FADV_WILLNEED: u32 = 3;
-pub const POSIX_FADV_DONTNEED: u32 = 4;
-pub const POSIX_FADV_NOREUSE: u32 = 5;
-pub const FALLOC_FL_KEEP_SIZE: u32 = 1;
-pub const NR_OPEN: u32 = 1024;
-pub const NGROUPS_MAX: u32 = 65536;
-pub const ARG_MAX: u32 = 131072;
-pub const LINK_MAX: u32 = 127;
-pub const MAX_CANON: u32 = 255;
-pub const MAX_INPUT: u32 = 255;
-pub const NAME_MAX: u32 = 255;
-pub const PATH_MAX: u32 = 4096;
-pub const PIPE_BUF: u32 = 4096;
-pub const XATTR_NAME_MAX: u32 = 255;
-pub const XATTR_SIZE_MAX: u32 = 65536;
-pub const XATTR_LIST_MAX: u32 = 65536;
-pub const RTSIG_MAX: u32 = 32;
-pub const _IOC_NRBITS: u32 = 8;
-pub const _IOC_TYPEBITS: u32 = 8;
-pub const _IOC_SIZEBITS: u32 = 14;
-pub const _IOC_DIRBITS: u32 = 2;
-pub const _IOC_NRMASK: u32 = 255;
-pub const _IOC_TYPEMASK: u32 = 255;
-pub const _IOC_SIZEMASK: u32 = 16383;
-pub const _IOC_DIRMASK: u32 = 3;
-pub const _IOC_NRSHIFT: u32 = 0;
-pub const _IOC_TYPESHIFT: u32 = 8;
-pub const _IOC_SIZESHIFT: u32 = 16;
-pub const _IOC_DIRSHIFT: u32 = 30;
-pub const _IOC_NONE: u32 = 0;
-pub const _IOC_WRITE: u32 = 1;
-pub const _IOC_READ: u32 = 2;
-pub const IOC_IN: u32 = 1073741824;
-pub const IOC_OUT: u32 = 2147483648;
-pub const IOC_INOUT: u32 = 3221225472;
-pub const IOCSIZE_MASK: u32 = 1073676288;
-pub const IOCSIZE_SHIFT: u32 = 16;
-pub const INR_OPEN: u32 = 1024;
-pub const BLOCK_SIZE_BITS: u32 = 10;
-pub const BLOCK_SIZE: u32 = 1024;
-pub const SEEK_SET: u32 = 0;
-pub const SEEK_CUR: u32 = 1;
-pub const SEEK_END: u32 = 2;
-pub const SEEK_MAX: u32 = 2;
-pub const NR_FILE: u32 = 8192;
-pub const MAY_EXEC: u32 = 1;
-pub const MAY_WRITE: u32 = 2;
-pub const MAY_READ: u32 = 4;
-pub const MAY_APPEND: u32 = 8;
-pub const MAY_ACCESS: u32 = 16;
-pub const MAY_OPEN: u32 = 32;
-pub const RW_MASK: u32 = 1;
-pub const RWA_MASK: u32 = 2;
-pub const READ: u32 = 0;
-pub const WRITE: u32 = 1;
-pub const READA: u32 = 2;
-pub const SWRITE: u32 = 3;
-pub const SEL_IN: u32 = 1;
-pub const SEL_OUT: u32 = 2;
-pub const SEL_EX: u32 = 4;
-pub const FS_REQUIRES_DEV: u32 = 1;
-pub const FS_BINARY_MOUNTDATA: u32 = 2;
-pub const FS_HAS_SUBTYPE: u32 = 4;
-pub const FS_REVAL_DOT: u32 = 16384;
-pub const FS_RENAME_DOES_D_MOVE: u32 = 32768;
-pub const MS_RDONLY: u32 = 1;
-pub const MS_NOSUID: u32 = 2;
-pub const MS_NODEV: u32 = 4;
-pub const MS_NOEXEC: u32 = 8;
-pub const MS_SYNCHRONOUS: u32 = 16;
-pub const MS_REMOUNT: u32 = 32;
-pub const MS_MANDLOCK: u32 = 64;
-pub const MS_DIRSYNC: u32 = 128;
-pub const MS_NOATIME: u32 = 1024;
-pub const MS_NODIRATIME: u32 = 2048;
-pub const MS_BIND: u32 = 4096;
-pub const MS_MOVE: u32 = 8192;
-pub const MS_REC: u32 = 16384;
-pub const MS_VERBOSE: u32 = 32768;
-pub const MS_SILENT: u32 = 32768;
-pub const MS_POSIXACL: u32 = 65536;
-pub const MS_UNBINDABLE: u32 = 131072;
-pub const MS_PRIVATE: u32 = 262144;
-pub const MS_SLAVE: u32 = 524288;
-pub const MS_SHARED: u32 = 1048576;
-pub const MS_RELATIME: u32 = 2097152;
-pub const MS_KERNMOUNT: u32 = 4194304;
-pub const MS_I_VERSION: u32 = 8388608;
-pub const MS_STRICTATIME: u32 = 16777216;
-pub const MS_ACTIVE: u32 = 1073741824;
-pub const MS_NOUSER: u32 = 2147483648;
-pub const MS_RMT_MASK: u32 = 8388689;
-pub const MS_MGC_VAL: u32 = 3236757504;
-pub const MS_MGC_MSK: u32 = 4294901760;
-pub const S_SYNC: u32 = 1;
-pub const S_NOATIME: u32 = 2;
-pub const S_APPEND: u32 = 4;
-pub const S_IMMUTABLE: u32 = 8;
-pub const S_DEAD: u32 = 16;
-pub const S_NOQUOTA: u32 = 32;
-pub const S_DIRSYNC: u32 = 64;
-pub const S_NOCMTIME: u32 = 128;
-pub const S_SWAPFILE: u32 = 256;
-pub const S_PRIVATE: u32 = 512;
-pub const BMAP_IOCTL: u32 = 1;
-pub const FS_SECRM_FL: u32 = 1;
-pub const FS_UNRM_FL: u32 = 2;
-pub const FS_COMPR_FL: u32 = 4;
-pub const FS_SYNC_FL: u32 = 8;
-pub const FS_IMMUTABLE_FL: u32 = 16;
-pub const FS_APPEND_FL: u32 = 32;
-pub const FS_NODUMP_FL: u32 = 64;
-pub const FS_NOATIME_FL: u32 = 128;
-pub const FS_DIRTY_FL: u32 = 256;
-pub const FS_COMPRBLK_FL: u32 = 512;
-pub const FS_NOCOMP_FL: u32 = 1024;
-pub const FS_ECOMPR_FL: u32 = 2048;
-pub const FS_BTREE_FL: u32 = 4096;
-pub const FS_INDEX_FL: u32 = 4096;
-pub const FS_IMAGIC_FL: u32 = 8192;
-pub const FS_JOURNAL_DATA_FL: u32 = 16384;
-pub const FS_NOTAIL_FL: u32 = 32768;
-pub const FS_DIRSYNC_FL: u32 = 65536;
-pub const FS_TOPDIR_FL: u32 = 131072;
-pub const FS_EXTENT_FL: u32 = 524288;
-pub const FS_DIRECTIO_FL: u32 = 1048576;
-pub const FS_RESERVED_FL: u32 = 2147483648;
-pub const FS_FL_USER_VISIBLE: u32 = 253951;
-pub const FS_FL_USER_MODIFIABLE: u32 = 229631;
-pub const SYNC_FILE_RANGE_WAIT_BEFORE: u32 = 1;
-pub const SYNC_FILE_RANGE_WRITE: u32 = 2;
-pub const SYNC_FILE_RANGE_WAIT_AFTER: u32 = 4;
-pub const FUTEX_WAIT: u32 = 0;
-pub const FUTEX_WAKE: u32 = 1;
-pub const FUTEX_FD: u32 = 2;
-pub const FUTEX_REQUEUE: u32 = 3;
-pub const FUTEX_CMP_REQUEUE: u32 = 4;
-pub const FUTEX_WAKE_OP: u32 = 5;
-pub const FUTEX_LOCK_PI: u32 = 6;
-pub const FUTEX_UNLOCK_PI: u32 = 7;
-pub const FUTEX_TRYLOCK_PI: u32 = 8;
-pub const FUTEX_WAIT_BITSET: u32 = 9;
-pub const FUTEX_WAKE_BITSET: u32 = 10;
-pub const FUTEX_WAIT_REQUEUE_PI: u32 = 11;
-pub const FUTEX_CMP_REQUEUE_PI: u32 = 12;
-pub const FUTEX_PRIVATE_FLAG: u32 = 128;
-pub const FUTEX_CLOCK_REALTIME: u32 = 256;
-pub const FUTEX_CMD_MASK: i32 = -385;
-pub const FUTEX_WAIT_PRIVATE: u32 = 128;
-pub const FUTEX_WAKE_PRIVATE: u32 = 129;
-pub const FUTEX_REQUEUE_PRIVATE: u32 = 131;
-pub const FUTEX_CMP_REQUEUE_PRIVATE: u32 = 132;
-pub const FUTEX_WAKE_OP_PRIVATE: u32 = 133;
-pub const FUTEX_LOCK_PI_PRIVATE: u32 = 134;
-pub const FUTEX_UNLOCK_PI_PRIVATE: u32 = 135;
-pub const FUTEX_TRYLOCK_PI_PRIVATE: u32 = 136;
-pub const FUTEX_WAIT_BITSET_PRIVATE: u32 = 137;
-pub const FUTEX_WAKE_BITSET_PRIVATE: u32 = 138;
-pub const FUTEX_WAIT_REQUEUE_PI_PRIVATE: u32 = 139;
-pub const FUTEX_CMP_REQUEUE_PI_PRIVATE: u32 = 140;
-pub const FUTEX_WAITERS: u32 = 2147483648;
-pub const FUTEX_OWNER_DIED: u32 = 1073741824;
-pub const FUTEX_TID_MASK: u32 = 1073741823;
-pub const ROBUST_LIST_LIMIT: u32 = 2048;
-pub const FUTEX_BITSET_MATCH_ANY: u32 = 4294967295;
-pub const FUTEX_OP_SET: u32 = 0;
-pub const FUTEX_OP_ADD: u32 = 1;
-pub const FUTEX_OP_OR: u32 = 2;
-pub const FUTEX_OP_ANDN: u32 = 3;
-pub const FUTEX_OP_XOR: u32 = 4;
-pub const FUTEX_OP_OPARG_SHIFT: u32 = 8;
-pub const FUTEX_OP_CMP_EQ: u32 = 0;
-pub const FUTEX_OP_CMP_NE: u32 = 1;
-pub const FUTEX_OP_CMP_LT: u32 = 2;
-pub const FUTEX_OP_CMP_LE: u32 = 3;
-pub const FUTEX_OP_CMP_GT: u32 = 4;
-pub const FUTEX_OP_CMP_GE: u32 = 5;
-pub const _K_SS_MAXSIZE: u32 = 128;
-pub const IP_TOS: u32 = 1;
-pub const IP_TTL: u32 = 2;
-pub const IP_HDRINCL: u32 = 3;
-pub const IP_OPTIONS: u32 = 4;
-pub const IP_ROUTER_ALERT: u32 = 5;
-pub const IP_RECVOPTS: u32 = 6;
-pub const IP_RETOPTS: u32 = 7;
-pub const IP_PKTINFO: u32 = 8;
-pub const IP_PKTOPTIONS: u32 = 9;
-pub const IP_MTU_DISCOVER: u32 = 10;
-pub const IP_RECVERR: u32 = 11;
-pub const IP_RECVTTL: u32 = 12;
-pub const IP_RECVTOS: u32 = 13;
-pub const IP_MTU: u32 = 14;
-pub const IP_FREEBIND: u32 = 15;
-pub const IP_IPSEC_POLICY: u32 = 16;
-pub const IP_XFRM_POLICY: u32 = 17;
-pub const IP_PASSSEC: u32 = 18;
-pub const IP_TRANSPARENT: u32 = 19;
-pub const IP_RECVRETOPTS: u32 = 7;
-pub const IP_ORIGDSTADDR: u32 = 20;
-pub const IP_RECVORIGDSTADDR: u32 = 20;
-pub const IP_PMTUDISC_DONT: u32 = 0;
-pub const IP_PMTUDISC_WANT: u32 = 1;
-pub const IP_PMTUDISC_DO: u32 = 2;
-pub const IP_PMTUDISC_PROBE: u32 = 3;
-pub const IP_MULTICAST_IF: u32 = 32;
-pub const IP_MULTICAST_TTL: u32 = 33;
-pub const IP_MULTICAST_LOOP: u32 = 34;
-pub const IP_ADD_MEMBERSHIP: u32 = 35;
-pub const IP_DROP_MEMBERSHIP: u32 = 36;
-pub const IP_UNBLOCK_SOURCE: u32 = 37;
-pub const IP_BLOCK_SOURCE: u32 = 38;
-pub const IP_ADD_SOURCE_MEMBERSHIP: u32 = 39;
-pub const IP_DROP_SOURCE_MEMBERSHIP: u32 = 40;
-pub const IP_MSFILTER: u32 = 41;
-pub const MCAST_JOIN_GROUP: u32 = 42;
-pub const MCAST_BLOCK_SOURCE: u32 = 43;
-pub const MCAST_UNBLOCK_SOURCE: u32 = 44;
-pub const MCAST_LEAVE_GROUP: u32 = 45;
-pub const MCAST_JOIN_SOURCE_GROUP: u32 = 46;
-pub const MCAST_LEAVE_SOURCE_GROUP: u32 = 47;
-pub const MCAST_MSFILTER: u32 = 48;
-pub const IP_MULTICAST_ALL: u32 = 49;
-pub const MCAST_EXCLUDE: u32 = 0;
-pub const MCAST_INCLUDE: u32 = 1;
-pub const IP_DEFAULT_MULTICAST_TTL: u32 = 1;
-pub const IP_DEFAULT_MULTICAST_LOOP: u32 = 1;
-pub const __SOCK_SIZE__: u32 = 16;
-pub const IN_CLASSA_NET: u32 = 4278190080;
-pub const IN_CLASSA_NSHIFT: u32 = 24;
-pub const IN_CLASSA_HOST: u32 = 16777215;
-pub const IN_CLASSA_MAX: u32 = 128;
-pub const IN_CLASSB_NET: u32 = 4294901760;
-pub const IN_CLASSB_NSHIFT: u32 = 16;
-pub const IN_CLASSB_HOST: u32 = 65535;
-pub const IN_CLASSB_MAX: u32 = 65536;
-pub const IN_CLASSC_NET: u32 = 4294967040;
-pub const IN_CLASSC_NSHIFT: u32 = 8;
-pub const IN_CLASSC_HOST: u32 = 255;
-pub const IN_MULTICAST_NET: u32 = 4026531840;
-pub const IN_LOOPBACKNET: u32 = 127;
-pub const INADDR_LOOPBACK: u32 = 2130706433;
-pub const INADDR_UNSPEC_GROUP: u32 = 3758096384;
-pub const INADDR_ALLHOSTS_GROUP: u32 = 3758096385;
-pub const INADDR_ALLRTRS_GROUP: u32 = 3758096386;
-pub const INADDR_MAX_LOCAL_GROUP: u32 = 3758096639;
-pub const __LITTLE_ENDIAN: u32 = 1234;
-pub const IPV6_FL_A_GET: u32 = 0;
-pub const IPV6_FL_A_PUT: u32 = 1;
-pub const IPV6_FL_A_RENEW: u32 = 2;
-pub const IPV6_FL_F_CREATE: u32 = 1;
-pub const IPV6_FL_F_EXCL: u32 = 2;
-pub const IPV6_FL_S_NONE: u32 = 0;
-pub const IPV6_FL_S_EXCL: u32 = 1;
-pub const IPV6_FL_S_PROCESS: u32 = 2;
-pub const IPV6_FL_S_USER: u32 = 3;
-pub const IPV6_FL_S_ANY: u32 = 255;
-pub const IPV6_FLOWINFO_FLOWLABEL: u32 = 1048575;
-pub const IPV6_FLOWINFO_PRIORITY: u32 = 267386880;
-pub const IPV6_PRIORITY_UNCHARACTERIZED: u32 = 0;
-pub const IPV6_PRIORITY_FILLER: u32 = 256;
-pub const IPV6_PRIORITY_UNATTENDED: u32 = 512;
-pub const IPV6_PRIORITY_RESERVED1: u32 = 768;
-pub const IPV6_PRIORITY_BULK: u32 = 1024;
-pub const IPV6_PRIORITY_RESERVED2: u32 = 1280;
-pub const IPV6_PRIORITY_INTERACTIVE: u32 = 1536;
-pub const IPV6_PRIORITY_CONTROL: u32 = 1792;
-pub const IPV6_PRIORITY_8: u32 = 2048;
-pub const IPV6_PRIORITY_9: u32 = 2304;
-pub const IPV6_PRIORITY_10: u32 = 2560;
-pub const IPV6_PRIORITY_11: u32 = 2816;
-pub const IPV6_PRIORITY_12: u32 = 3072;
-pub const IPV6_PRIORITY_13: u32 = 3328;
-pub const IPV6_PRIORITY_14: u32 = 3584;
-pub const IPV6_PRIORITY_15: u32 = 3840;
-pub const IPPROTO_HOPOPTS: u32 = 0;
-pub const IPPROTO_ROUTING: u32 = 43;
-pub const IPPROTO_FRAGMENT: u32 = 44;
-pub const IPPROTO_ICMPV6: u32 = 58;
-pub const IPPROTO_NONE: u32 = 59;
-pub const IPPROTO_DSTOPTS: u32 = 60;
-pub const IPPROTO_MH: u32 = 135;
-pub const IPV6_TLV_PAD0: u32 = 0;
-pub const IPV6_TLV_PADN: u32 = 1;
-pub const IPV6_TLV_ROUTERALERT: u32 = 5;
-pub const IPV6_TLV_JUMBO: u32 = 194;
-pub const IPV6_TLV_HAO: u32 = 201;
-pub const IPV6_ADDRFORM: u32 = 1;
-pub const IPV6_2292PKTINFO: u32 = 2;
-pub const IPV6_2292HOPOPTS: u32 = 3;
-pub const IPV6_2292DSTOPTS: u32 = 4;
-pub const IPV6_2292RTHDR: u32 = 5;
-pub const IPV6_2292PKTOPTIONS: u32 = 6;
-pub const IPV6_CHECKSUM: u32 = 7;
-pub const IPV6_2292HOPLIMIT: u32 = 8;
-pub const IPV6_NEXTHOP: u32 = 9;
-pub const IPV6_AUTHHDR: u32 = 10;
-pub const IPV6_FLOWINFO: u32 = 11;
-pub const IPV6_UNICAST_HOPS: u32 = 16;
-pub const IPV6_MULTICAST_IF: u32 = 17;
-pub const IPV6_MULTICAST_HOPS: u32 = 18;
-pub const IPV6_MULTICAST_LOOP: u32 = 19;
-pub const IPV6_ADD_MEMBERSHIP: u32 = 20;
-pub const IPV6_DROP_MEMBERSHIP: u32 = 21;
-pub const IPV6_ROUTER_ALERT: u32 = 22;
-pub const IPV6_MTU_DISCOVER: u32 = 23;
-pub const IPV6_MTU: u32 = 24;
-pub const IPV6_RECVERR: u32 = 25;
-pub const IPV6_V6ONLY: u32 = 26;
-pub const IPV6_JOIN_ANYCAST: u32 = 27;
-pub const IPV6_LEAVE_ANYCAST: u32 = 28;
-pub const IPV6_PMTUDISC_DONT: u32 = 0;
-pub const IPV6_PMTUDISC_WANT: u32 = 1;
-pub const IPV6_PMTUDISC_DO: u32 = 2;
-pub const IPV6_PMTUDISC_PROBE: u32 = 3;
-pub const IPV6_FLOWLABEL_MGR: u32 = 32;
-pub const IPV6_FLOWINFO_SEND: u32 = 33;
-pub const IPV6_IPSEC_POLICY: u32 = 34;
-pub const IPV6_XFRM_POLICY: u32 = 35;
-pub const IPV6_RECVPKTINFO: u32 = 49;
-pub const IPV6_PKTINFO: u32 = 50;
-pub const IPV6_RECVHOPLIMIT: u32 = 51;
-pub const IPV6_HOPLIMIT: u32 = 52;
-pub const IPV6_RECVHOPOPTS: u32 = 53;
-pub const IPV6_HOPOPTS: u32 = 54;
-pub const IPV6_RTHDRDSTOPTS: u32 = 55;
-pub const IPV6_RECVRTHDR: u32 = 56;
-pub const IPV6_RTHDR: u32 = 57;
-pub const IPV6_RECVDSTOPTS: u32 = 58;
-pub const IPV6_DSTOPTS: u32 = 59;
-pub const IPV6_RECVTCLASS: u32 = 66;
-pub const IPV6_TCLASS: u32 = 67;
-pub const IPV6_ADDR_PREFERENCES: u32 = 72;
-pub const IPV6_PREFER_SRC_TMP: u32 = 1;
-pub const IPV6_PREFER_SRC_PUBLIC: u32 = 2;
-pub const IPV6_PREFER_SRC_PUBTMP_DEFAULT: u32 = 256;
-pub const IPV6_PREFER_SRC_COA: u32 = 4;
-pub const IPV6_PREFER_SRC_HOME: u32 = 1024;
-pub const IPV6_PREFER_SRC_CGA: u32 = 8;
-pub const IPV6_PREFER_SRC_NONCGA: u32 = 2048;
-pub const ADFS_SUPER_MAGIC: u32 = 44533;
-pub const AFFS_SUPER_MAGIC: u32 = 44543;
-pub const AFS_SUPER_MAGIC: u32 = 1397113167;
-pub const AUTOFS_SUPER_MAGIC: u32 = 391;
-pub const CODA_SUPER_MAGIC: u32 = 1937076805;
-pub const CRAMFS_MAGIC: u32 = 684539205;
-pub const CRAMFS_MAGIC_WEND: u32 = 1161678120;
-pub const DEBUGFS_MAGIC: u32 = 1684170528;
-pub const SYSFS_MAGIC: u32 = 1650812274;
-pub const SECURITYFS_MAGIC: u32 = 1935894131;
-pub const SELINUX_MAGIC: u32 = 4185718668;
-pub const RAMFS_MAGIC: u32 = 2240043254;
-pub const TMPFS_MAGIC: u32 = 16914836;
-pub const HUGETLBFS_MAGIC: u32 = 2508478710;
-pub const SQUASHFS_MAGIC: u32 = 1936814952;
-pub const EFS_SUPER_MAGIC: u32 = 4278867;
-pub const EXT2_SUPER_MAGIC: u32 = 61267;
-pub const EXT3_SUPER_MAGIC: u32 = 61267;
-pub const XENFS_SUPER_MAGIC: u32 = 2881100148;
-pub const EXT4_SUPER_MAGIC: u32 = 61267;
-pub const BTRFS_SUPER_MAGIC: u32 = 2435016766;
-pub const HPFS_SUPER_MAGIC: u32 = 4187351113;
-pub const ISOFS_SUPER_MAGIC: u32 = 38496;
-pub const JFFS2_SUPER_MAGIC: u32 = 29366;
-pub const ANON_INODE_FS_MAGIC: u32 = 151263540;
-pub const MINIX_SUPER_MAGIC: u32 = 4991;
-pub const MINIX_SUPER_MAGIC2: u32 = 5007;
-pub const MINIX2_SUPER_MAGIC: u32 = 9320;
-pub const MINIX2_SUPER_MAGIC2: u32 = 9336;
-pub const MINIX3_SUPER_MAGIC: u32 = 19802;
-pub const MSDOS_SUPER_MAGIC: u32 = 19780;
-pub const NCP_SUPER_MAGIC: u32 = 22092;
-pub const NFS_SUPER_MAGIC: u32 = 26985;
-pub const OPENPROM_SUPER_MAGIC: u32 = 40865;
-pub const PROC_SUPER_MAGIC: u32 = 40864;
-pub const QNX4_SUPER_MAGIC: u32 = 47;
-pub const REISERFS_SUPER_MAGIC: u32 = 1382369651;
-pub const REISERFS_SUPER_MAGIC_STRING: &'static [u8; 9usize] = b"ReIsErFs\0";
-pub const REISER2FS_SUPER_MAGIC_STRING: &'static [u8; 10usize] = b"ReIsEr2Fs\0";
-pub const REISER2FS_JR_SUPER_MAGIC_STRING: &'static [u8; 10usize] = b"ReIsEr3Fs\0";
-pub const SMB_SUPER_MAGIC: u32 = 20859;
-pub const USBDEVICE_SUPER_MAGIC: u32 = 40866;
-pub const CGROUP_SUPER_MAGIC: u32 = 2613483;
-pub const FUTEXFS_SUPER_MAGIC: u32 = 195894762;
-pub const INOTIFYFS_SUPER_MAGIC: u32 = 732765674;
-pub const STACK_END_MAGIC: u32 = 1470918301;
-pub const DEVPTS_SUPER_MAGIC: u32 = 7377;
-pub const SOCKFS_MAGIC: u32 = 1397703499;
-pub const MAP_32BIT: u32 = 64;
-pub const PROT_READ: u32 = 1;
-pub const PROT_WRITE: u32 = 2;
-pub const PROT_EXEC: u32 = 4;
-pub const PROT_SEM: u32 = 8;
-pub const PROT_NONE: u32 = 0;
-pub const PROT_GROWSDOWN: u32 = 16777216;
-pub const PROT_GROWSUP: u32 = 33554432;
-pub const MAP_SHARED: u32 = 1;
-pub const MAP_PRIVATE: u32 = 2;
-pub const MAP_TYPE: u32 = 15;
-pub const MAP_FIXED: u32 = 16;
-pub const MAP_ANONYMOUS: u32 = 32;
-pub const MS_ASYNC: u32 = 1;
-pub const MS_INVALIDATE: u32 = 2;
-pub const MS_SYNC: u32 = 4;
-pub const MADV_NORMAL: u32 = 0;
-pub const MADV_RANDOM: u32 = 1;
-pub const MADV_SEQUENTIAL: u32 = 2;
-pub const MADV_WILLNEED: u32 = 3;
-pub const MADV_DONTNEED: u32 = 4;
-pub const MADV_REMOVE: u32 = 9;
-pub const MADV_DONTFORK: u32 = 10;
-pub const MADV_DOFORK: u32 = 11;
-pub const MADV_HWPOISON: u32 = 100;
-pub const MADV_MERGEABLE: u32 = 12;
-pub const MADV_UNMERGEABLE: u32 = 13;
-pub const MAP_FILE: u32 = 0;
-pub const MAP_GROWSDOWN: u32 = 256;
-pub const MAP_DENYWRITE: u32 = 2048;
-pub const MAP_EXECUTABLE: u32 = 4096;
-pub const MAP_LOCKED: u32 = 8192;
-pub const MAP_NORESERVE: u32 = 16384;
-pub const MAP_POPULATE: u32 = 32768;
-pub const MAP_NONBLOCK: u32 = 65536;
-pub const MAP_STACK: u32 = 131072;
-pub const MAP_HUGETLB: u32 = 262144;
-pub const MCL_CURRENT: u32 = 1;
-pub const MCL_FUTURE: u32 = 2;
-pub const MREMAP_MAYMOVE: u32 = 1;
-pub const MREMAP_FIXED: u32 = 2;
-pub const OVERCOMMIT_GUESS: u32 = 0;
-pub const OVERCOMMIT_ALWAYS: u32 = 1;
-pub const OVERCOMMIT_NEVER: u32 = 2;
-pub const FIOSETOWN: u32 = 35073;
-pub const SIOCSPGRP: u32 = 35074;
-pub const FIOGETOWN: u32 = 35075;
-pub const SIOCGPGRP: u32 = 35076;
-pub const SIOCATMARK: u32 = 35077;
-pub const SIOCGSTAMP: u32 = 35078;
-pub const SIOCGSTAMPNS: u32 = 35079;
-pub const SOL_SOCKET: u32 = 1;
-pub const SO_DEBUG: u32 = 1;
-pub const SO_REUSEADDR: u32 = 2;
-pub const SO_TYPE: u32 = 3;
-pub const SO_ERROR: u32 = 4;
-pub const SO_DONTROUTE: u32 = 5;
-pub const SO_BROADCAST: u32 = 6;
-pub const SO_SNDBUF: u32 = 7;
-pub const SO_RCVBUF: u32 = 8;
-pub const SO_SNDBUFFORCE: u32 = 32;
-pub const SO_RCVBUFFORCE: u32 = 33;
-pub const SO_KEEPALIVE: u32 = 9;
-pub const SO_OOBINLINE: u32 = 10;
-pub const SO_NO_CHECK: u32 = 11;
-pub const SO_PRIORITY: u32 = 12;
-pub const SO_LINGER: u32 = 13;
-pub const SO_BSDCOMPAT: u32 = 14;
-pub const SO_PASSCRED: u32 = 16;
-pub const SO_PEERCRED: u32 = 17;
-pub const SO_RCVLOWAT: u32 = 18;
-pub const SO_SNDLOWAT: u32 = 19;
-pub const SO_RCVTIMEO: u32 = 20;
-pub const SO_SNDTIMEO: u32 = 21;
-pub const SO_SECURITY_AUTHENTICATION: u32 = 22;
-pub const SO_SECURITY_ENCRYPTION_TRANSPORT: u32 = 23;
-pub const SO_SECURITY_ENCRYPTION_NETWORK: u32 = 24;
-pub const SO_BINDTODEVICE: u32 = 25;
-pub const SO_ATTACH_FILTER: u32 = 26;
-pub const SO_DETACH_FILTER: u32 = 27;
-pub const SO_PEERNAME: u32 = 28;
-pub const SO_TIMESTAMP: u32 = 29;
-pub const SCM_TIMESTAMP: u32 = 29;
-pub const SO_ACCEPTCONN: u32 = 30;
-pub const SO_PEERSEC: u32 = 31;
-pub const SO_PASSSEC: u32 = 34;
-pub const SO_TIMESTAMPNS: u32 = 35;
-pub const SCM_TIMESTAMPNS: u32 = 35;
-pub const SO_MARK: u32 = 36;
-pub const SO_TIMESTAMPING: u32 = 37;
-pub const SCM_TIMESTAMPING: u32 = 37;
-pub const SO_PROTOCOL: u32 = 38;
-pub const SO_DOMAIN: u32 = 39;
-pub const SYS_SOCKET: u32 = 1;
-pub const SYS_BIND: u32 = 2;
-pub const SYS_CONNECT: u32 = 3;
-pub const SYS_LISTEN: u32 = 4;
-pub const SYS_ACCEPT: u32 = 5;
-pub const SYS_GETSOCKNAME: u32 = 6;
-pub const SYS_GETPEERNAME: u32 = 7;
-pub const SYS_SOCKETPAIR: u32 = 8;
-pub const SYS_SEND: u32 = 9;
-pub const SYS_RECV: u32 = 10;
-pub const SYS_SENDTO: u32 = 11;
-pub const SYS_RECVFROM: u32 = 12;
-pub const SYS_SHUTDOWN: u32 = 13;
-pub const SYS_SETSOCKOPT: u32 = 14;
-pub const SYS_GETSOCKOPT: u32 = 15;
-pub const SYS_SENDMSG: u32 = 16;
-pub const SYS_RECVMSG: u32 = 17;
-pub const SYS_ACCEPT4: u32 = 18;
-pub const __SO_ACCEPTCON: u32 = 65536;
-pub const POLLIN: u32 = 1;
-pub const POLLPRI: u32 = 2;
-pub const POLLOUT: u32 = 4;
-pub const POLLERR: u32 = 8;
-pub const POLLHUP: u32 = 16;
-pub const POLLNVAL: u32 = 32;
-pub const POLLRDNORM: u32 = 64;
-pub const POLLRDBAND: u32 = 128;
-pub const POLLWRNORM: u32 = 256;
-pub const POLLWRBAND: u32 = 512;
-pub const POLLMSG: u32 = 1024;
-pub const POLLREMOVE: u32 = 4096;
-pub const POLLRDHUP: u32 = 8192;
-pub const PR_SET_PDEATHSIG: u32 = 1;
-pub const PR_GET_PDEATHSIG: u32 = 2;
-pub const PR_GET_DUMPABLE: u32 = 3;
-pub const PR_SET_DUMPABLE: u32 = 4;
-pub const PR_GET_UNALIGN: u32 = 5;
-pub const PR_SET_UNALIGN: u32 = 6;
-pub const PR_UNALIGN_NOPRINT: u32 = 1;
-pub const PR_UNALIGN_SIGBUS: u32 = 2;
-pub const PR_GET_KEEPCAPS: u32 = 7;
-pub const PR_SET_KEEPCAPS: u32 = 8;
-pub const PR_GET_FPEMU: u32 = 9;
-pub const PR_SET_FPEMU: u32 = 10;
-pub const PR_FPEMU_NOPRINT: u32 = 1;
-pub const PR_FPEMU_SIGFPE: u32 = 2;
-pub const PR_GET_FPEXC: u32 = 11;
-pub const PR_SET_FPEXC: u32 = 12;
-pub const PR_FP_EXC_SW_ENABLE: u32 = 128;
-pub const PR_FP_EXC_DIV: u32 = 65536;
-pub const PR_FP_EXC_OVF: u32 = 131072;
-pub const PR_FP_EXC_UND: u32 = 262144;
-pub const PR_FP_EXC_RES: u32 = 524288;
-pub const PR_FP_EXC_INV: u32 = 1048576;
-pub const PR_FP_EXC_DISABLED: u32 = 0;
-pub const PR_FP_EXC_NONRECOV: u32 = 1;
-pub const PR_FP_EXC_ASYNC: u32 = 2;
-pub const PR_FP_EXC_PRECISE: u32 = 3;
-pub const PR_GET_TIMING: u32 = 13;
-pub const PR_SET_TIMING: u32 = 14;
-pub const PR_TIMING_STATISTICAL: u32 = 0;
-pub const PR_TIMING_TIMESTAMP: u32 = 1;
-pub const PR_SET_NAME: u32 = 15;
-pub const PR_GET_NAME: u32 = 16;
-pub const PR_GET_ENDIAN: u32 = 19;
-pub const PR_SET_ENDIAN: u32 = 20;
-pub const PR_ENDIAN_BIG: u32 = 0;
-pub const PR_ENDIAN_LITTLE: u32 = 1;
-pub const PR_ENDIAN_PPC_LITTLE: u32 = 2;
-pub const PR_GET_SECCOMP: u32 = 21;
-pub const PR_SET_SECCOMP: u32 = 22;
-pub const PR_CAPBSET_READ: u32 = 23;
-pub const PR_CAPBSET_DROP: u32 = 24;
-pub const PR_GET_TSC: u32 = 25;
-pub const PR_SET_TSC: u32 = 26;
-pub const PR_TSC_ENABLE: u32 = 1;
-pub const PR_TSC_SIGSEGV: u32 = 2;
-pub const PR_GET_SECUREBITS: u32 = 27;
-pub const PR_SET_SECUREBITS: u32 = 28;
-pub const PR_SET_TIMERSLACK: u32 = 29;
-pub const PR_GET_TIMERSLACK: u32 = 30;
-pub const PR_TASK_PERF_EVENTS_DISABLE: u32 = 31;
-pub const PR_TASK_PERF_EVENTS_ENABLE: u32 = 32;
-pub const PR_MCE_KILL: u32 = 33;
-pub const PR_MCE_KILL_CLEAR: u32 = 0;
-pub const PR_MCE_KILL_SET: u32 = 1;
-pub const PR_MCE_KILL_LATE: u32 = 0;
-pub const PR_MCE_KILL_EARLY: u32 = 1;
-pub const PR_MCE_KILL_DEFAULT: u32 = 2;
-pub const PR_MCE_KILL_GET: u32 = 34;
-pub const FD_SETSIZE: u32 = 1024;
-pub const ITIMER_REAL: u32 = 0;
-pub const ITIMER_VIRTUAL: u32 = 1;
-pub const ITIMER_PROF: u32 = 2;
-pub const CLOCK_REALTIME: u32 = 0;
-pub const CLOCK_MONOTONIC: u32 = 1;
-pub const CLOCK_PROCESS_CPUTIME_ID: u32 = 2;
-pub const CLOCK_THREAD_CPUTIME_ID: u32 = 3;
-pub const CLOCK_MONOTONIC_RAW: u32 = 4;
-pub const CLOCK_REALTIME_COARSE: u32 = 5;
-pub const CLOCK_MONOTONIC_COARSE: u32 = 6;
-pub const CLOCK_SGI_CYCLE: u32 = 10;
-pub const MAX_CLOCKS: u32 = 16;
-pub const CLOCKS_MASK: u32 = 1;
-pub const CLOCKS_MONO: u32 = 1;
-pub const TIMER_ABSTIME: u32 = 1;
-pub const RUSAGE_SELF: u32 = 0;
-pub const RUSAGE_CHILDREN: i32 = -1;
-pub const RUSAGE_BOTH: i32 = -2;
-pub const RUSAGE_THREAD: u32 = 1;
-pub const PRIO_MIN: i32 = -20;
-pub const PRIO_MAX: u32 = 20;
-pub const PRIO_PROCESS: u32 = 0;
-pub const PRIO_PGRP: u32 = 1;
-pub const PRIO_USER: u32 = 2;
-pub const _STK_LIM: u32 = 8388608;
-pub const RLIMIT_CPU: u32 = 0;
-pub const RLIMIT_FSIZE: u32 = 1;
-pub const RLIMIT_DATA: u32 = 2;
-pub const RLIMIT_STACK: u32 = 3;
-pub const RLIMIT_CORE: u32 = 4;
-pub const RLIMIT_RSS: u32 = 5;
-pub const RLIMIT_NPROC: u32 = 6;
-pub const RLIMIT_NOFILE: u32 = 7;
-pub const RLIMIT_MEMLOCK: u32 = 8;
-pub const RLIMIT_AS: u32 = 9;
-pub const RLIMIT_LOCKS: u32 = 10;
-pub const RLIMIT_SIGPENDING: u32 = 11;
-pub const RLIMIT_MSGQUEUE: u32 = 12;
-pub const RLIMIT_NICE: u32 = 13;
-pub const RLIMIT_RTPRIO: u32 = 14;
-pub const RLIMIT_RTTIME: u32 = 15;
-pub const RLIM_NLIMITS: u32 = 16;
-pub const RLIM_INFINITY: i32 = -1;
-pub const _STK_LIM_MAX: i32 = -1;
-pub const CSIGNAL: u32 = 255;
-pub const CLONE_VM: u32 = 256;
-pub const CLONE_FS: u32 = 512;
-pub const CLONE_FILES: u32 = 1024;
-pub const CLONE_SIGHAND: u32 = 2048;
-pub const CLONE_PTRACE: u32 = 8192;
-pub const CLONE_VFORK: u32 = 16384;
-pub const CLONE_PARENT: u32 = 32768;
-pub const CLONE_THREAD: u32 = 65536;
-pub const CLONE_NEWNS: u32 = 131072;
-pub const CLONE_SYSVSEM: u32 = 262144;
-pub const CLONE_SETTLS: u32 = 524288;
-pub const CLONE_PARENT_SETTID: u32 = 1048576;
-pub const CLONE_CHILD_CLEARTID: u32 = 2097152;
-pub const CLONE_DETACHED: u32 = 4194304;
-pub const CLONE_UNTRACED: u32 = 8388608;
-pub const CLONE_CHILD_SETTID: u32 = 16777216;
-pub const CLONE_STOPPED: u32 = 33554432;
-pub const CLONE_NEWUTS: u32 = 67108864;
-pub const CLONE_NEWIPC: u32 = 134217728;
-pub const CLONE_NEWUSER: u32 = 268435456;
-pub const CLONE_NEWPID: u32 = 536870912;
-pub const CLONE_NEWNET: u32 = 1073741824;
-pub const CLONE_IO: u32 = 2147483648;
-pub const SCHED_NORMAL: u32 = 0;
-pub const SCHED_FIFO: u32 = 1;
-pub const SCHED_RR: u32 = 2;
-pub const SCHED_BATCH: u32 = 3;
-pub const SCHED_IDLE: u32 = 5;
-pub const SCHED_RESET_ON_FORK: u32 = 1073741824;
-pub const NSIG: u32 = 32;
-pub const SIGHUP: u32 = 1;
-pub const SIGINT: u32 = 2;
-pub const SIGQUIT: u32 = 3;
-pub const SIGILL: u32 = 4;
-pub const SIGTRAP: u32 = 5;
-pub const SIGABRT: u32 = 6;
-pub const SIGIOT: u32 = 6;
-pub const SIGBUS: u32 = 7;
-pub const SIGFPE: u32 = 8;
-pub const SIGKILL: u32 = 9;
-pub const SIGUSR1: u32 = 10;
-pub const SIGSEGV: u32 = 11;
-pub const SIGUSR2: u32 = 12;
-pub const SIGPIPE: u32 = 13;
-pub const SIGALRM: u32 = 14;
-pub const SIGTERM: u32 = 15;
-pub const SIGSTKFLT: u32 = 16;
-pub const SIGCHLD: u32 = 17;
-pub const SIGCONT: u32 = 18;
-pub const SIGSTOP: u32 = 19;
-pub const SIGTSTP: u32 = 20;
-pub const SIGTTIN: u32 = 21;
-pub const SIGTTOU: u32 = 22;
-pub const SIGURG: u32 = 23;
-pub const SIGXCPU: u32 = 24;
-pub const SIGXFSZ: u32 = 25;
-pub const SIGVTALRM: u32 = 26;
-pub const SIGPROF: u32 = 27;
-pub const SIGWINCH: u32 = 28;
-pub const SIGIO: u32 = 29;
-pub const SIGPOLL: u32 = 29;
-pub const SIGPWR: u32 = 30;
-pub const SIGSYS: u32 = 31;
-pub const SIGUNUSED: u32 = 31;
-pub const SIGRTMIN: u32 = 32;
-pub const SA_NOCLDSTOP: u32 = 1;
-pub const SA_NOCLDWAIT: u32 = 2;
-pub const SA_SIGINFO: u32 = 4;
-pub const SA_ONSTACK: u32 = 134217728;
-pub const SA_RESTART: u32 = 268435456;
-pub const SA_NODEFER: u32 = 1073741824;
-pub const SA_RESETHAND: u32 = 2147483648;
-pub const SA_NOMASK: u32 = 1073741824;
-pub const SA_ONESHOT: u32 = 2147483648;
-pub const SA_RESTORER: u32 = 67108864;
-pub const SS_ONSTACK: u32 = 1;
-pub const SS_DISABLE: u32 = 2;
-pub const MINSIGSTKSZ: u32 = 2048;
-pub const SIGSTKSZ: u32 = 8192;
-pub const SIG_BLOCK: u32 = 0;
-pub const SIG_UNBLOCK: u32 = 1;
-pub const SIG_SETMASK: u32 = 2;
-pub const SI_MAX_SIZE: u32 = 128;
-pub const __SI_KILL: u32 = 0;
-pub const __SI_TIMER: u32 = 0;
-pub const __SI_POLL: u32 = 0;
-pub const __SI_FAULT: u32 = 0;
-pub const __SI_CHLD: u32 = 0;
-pub const __SI_RT: u32 = 0;
-pub const __SI_MESGQ: u32 = 0;
-pub const SI_USER: u32 = 0;
-pub const SI_KERNEL: u32 = 128;
-pub const SI_QUEUE: i32 = -1;
-pub const SI_ASYNCIO: i32 = -4;
-pub const SI_SIGIO: i32 = -5;
-pub const SI_TKILL: i32 = -6;
-pub const SI_DETHREAD: i32 = -7;
-pub const ILL_ILLOPC: u32 = 1;
-pub const ILL_ILLOPN: u32 = 2;
-pub const ILL_ILLADR: u32 = 3;
-pub const ILL_ILLTRP: u32 = 4;
-pub const ILL_PRVOPC: u32 = 5;
-pub const ILL_PRVREG: u32 = 6;
-pub const ILL_COPROC: u32 = 7;
-pub const ILL_BADSTK: u32 = 8;
-pub const NSIGILL: u32 = 8;
-pub const FPE_INTDIV: u32 = 1;
-pub const FPE_INTOVF: u32 = 2;
-pub const FPE_FLTDIV: u32 = 3;
-pub const FPE_FLTOVF: u32 = 4;
-pub const FPE_FLTUND: u32 = 5;
-pub const FPE_FLTRES: u32 = 6;
-pub const FPE_FLTINV: u32 = 7;
-pub const FPE_FLTSUB: u32 = 8;
-pub const NSIGFPE: u32 = 8;
-pub const SEGV_MAPERR: u32 = 1;
-pub const SEGV_ACCERR: u32 = 2;
-pub const NSIGSEGV: u32 = 2;
-pub const BUS_ADRALN: u32 = 1;
-pub const BUS_ADRERR: u32 = 2;
-pub const BUS_OBJERR: u32 = 3;
-pub const BUS_MCEERR_AR: u32 = 4;
-pub const BUS_MCEERR_AO: u32 = 5;
-pub const NSIGBUS: u32 = 5;
-pub const TRAP_BRKPT: u32 = 1;
-pub const TRAP_TRACE: u32 = 2;
-pub const TRAP_BRANCH: u32 = 3;
-pub const TRAP_HWBKPT: u32 = 4;
-pub const NSIGTRAP: u32 = 4;
-pub const CLD_EXITED: u32 = 1;
-pub const CLD_KILLED: u32 = 2;
-pub const CLD_DUMPED: u32 = 3;
-pub const CLD_TRAPPED: u32 = 4;
-pub const CLD_STOPPED: u32 = 5;
-pub const CLD_CONTINUED: u32 = 6;
-pub const NSIGCHLD: u32 = 6;
-pub const POLL_IN: u32 = 1;
-pub const POLL_OUT: u32 = 2;
-pub const POLL_MSG: u32 = 3;
-pub const POLL_ERR: u32 = 4;
-pub const POLL_PRI: u32 = 5;
-pub const POLL_HUP: u32 = 6;
-pub const NSIGPOLL: u32 = 6;
-pub const SIGEV_SIGNAL: u32 = 0;
-pub const SIGEV_NONE: u32 = 1;
-pub const SIGEV_THREAD: u32 = 2;
-pub const SIGEV_THREAD_ID: u32 = 4;
-pub const SIGEV_MAX_SIZE: u32 = 64;
-pub const S_IFMT: u32 = 61440;
-pub const S_IFSOCK: u32 = 49152;
-pub const S_IFLNK: u32 = 40960;
-pub const S_IFREG: u32 = 32768;
-pub const S_IFBLK: u32 = 24576;
-pub const S_IFDIR: u32 = 16384;
-pub const S_IFCHR: u32 = 8192;
-pub const S_IFIFO: u32 = 4096;
-pub const S_ISUID: u32 = 2048;
-pub const S_ISGID: u32 = 1024;
-pub const S_ISVTX: u32 = 512;
-pub const S_IRWXU: u32 = 448;
-pub const S_IRUSR: u32 = 256;
-pub const S_IWUSR: u32 = 128;
-pub const S_IXUSR: u32 = 64;
-pub const S_IRWXG: u32 = 56;
-pub const S_IRGRP: u32 = 32;
-pub const S_IWGRP: u32 = 16;
-pub const S_IXGRP: u32 = 8;
-pub const S_IRWXO: u32 = 7;
-pub const S_IROTH: u32 = 4;
-pub const S_IWOTH: u32 = 2;
-pub const S_IXOTH: u32 = 1;
-pub const TCP_NODELAY: u32 = 1;
-pub const TCP_MAXSEG: u32 = 2;
-pub const TCP_CORK: u32 = 3;
-pub const TCP_KEEPIDLE: u32 = 4;
-pub const TCP_KEEPINTVL: u32 = 5;
-pub const TCP_KEEPCNT: u32 = 6;
-pub const TCP_SYNCNT: u32 = 7;
-pub const TCP_LINGER2: u32 = 8;
-pub const TCP_DEFER_ACCEPT: u32 = 9;
-pub const TCP_WINDOW_CLAMP: u32 = 10;
-pub const TCP_INFO: u32 = 11;
-pub const TCP_QUICKACK: u32 = 12;
-pub const TCP_CONGESTION: u32 = 13;
-pub const TCP_MD5SIG: u32 = 14;
-pub const TCPI_OPT_TIMESTAMPS: u32 = 1;
-pub const TCPI_OPT_SACK: u32 = 2;
-pub const TCPI_OPT_WSCALE: u32 = 4;
-pub const TCPI_OPT_ECN: u32 = 8;
-pub const TCP_MD5SIG_MAXKEYLEN: u32 = 80;
-pub const NCCS: u32 = 19;
-pub const VINTR: u32 = 0;
-pub const VQUIT: u32 = 1;
-pub const VERASE: u32 = 2;
-pub const VKILL: u32 = 3;
-pub const VEOF: u32 = 4;
-pub const VTIME: u32 = 5;
-pub const VMIN: u32 = 6;
-pub const VSWTC: u32 = 7;
-pub const VSTART: u32 = 8;
-pub const VSTOP: u32 = 9;
-pub const VSUSP: u32 = 10;
-pub const VEOL: u32 = 11;
-pub const VREPRINT: u32 = 12;
-pub const VDISCARD: u32 = 13;
-pub const VWERASE: u32 = 14;
-pub const VLNEXT: u32 = 15;
-pub const VEOL2: u32 = 16;
-pub const IGNBRK: u32 = 1;
-pub const BRKINT: u32 = 2;
-pub const IGNPAR: u32 = 4;
-pub const PARMRK: u32 = 8;
-pub const INPCK: u32 = 16;
-pub const ISTRIP: u32 = 32;
-pub const INLCR: u32 = 64;
-pub const IGNCR: u32 = 128;
-pub const ICRNL: u32 = 256;
-pub const IUCLC: u32 = 512;
-pub const IXON: u32 = 1024;
-pub const IXANY: u32 = 2048;
-pub const IXOFF: u32 = 4096;
-pub const IMAXBEL: u32 = 8192;
-pub const IUTF8: u32 = 16384;
-pub const OPOST: u32 = 1;
-pub const OLCUC: u32 = 2;
-pub const ONLCR: u32 = 4;
-pub const OCRNL: u32 = 8;
-pub const ONOCR: u32 = 16;
-pub const ONLRET: u32 = 32;
-pub const OFILL: u32 = 64;
-pub const OFDEL: u32 = 128;
-pub const NLDLY: u32 = 256;
-pub const NL0: u32 = 0;
-pub const NL1: u32 = 256;
-pub const CRDLY: u32 = 1536;
-pub const CR0: u32 = 0;
-pub const CR1: u32 = 512;
-pub const CR2: u32 = 1024;
-pub const CR3: u32 = 1536;
-pub const TABDLY: u32 = 6144;
-pub const TAB0: u32 = 0;
-pub const TAB1: u32 = 2048;
-pub const TAB2: u32 = 4096;
-pub const TAB3: u32 = 6144;
-pub const XTABS: u32 = 6144;
-pub const BSDLY: u32 = 8192;
-pub const BS0: u32 = 0;
-pub const BS1: u32 = 8192;
-pub const VTDLY: u32 = 16384;
-pub const VT0: u32 = 0;
-pub const VT1: u32 = 16384;
-pub const FFDLY: u32 = 32768;
-pub const FF0: u32 = 0;
-pub const FF1: u32 = 32768;
-pub const CBAUD: u32 = 4111;
-pub const B0: u32 = 0;
-pub const B50: u32 = 1;
-pub const B75: u32 = 2;
-pub const B110: u32 = 3;
-pub const B134: u32 = 4;
-pub const B150: u32 = 5;
-pub const B200: u32 = 6;
-pub const B300: u32 = 7;
-pub const B600: u32 = 8;
-pub const B1200: u32 = 9;
-pub const B1800: u32 = 10;
-pub const B2400: u32 = 11;
-pub const B4800: u32 = 12;
-pub const B9600: u32 = 13;
-pub const B19200: u32 = 14;
-pub const B38400: u32 = 15;
-pub const EXTA: u32 = 14;
-pub const EXTB: u32 = 15;
-pub const CSIZE: u32 = 48;
-pub const CS5: u32 = 0;
-pub const CS6: u32 = 16;
-pub const CS7: u32 = 32;
-pub const CS8: u32 = 48;
-pub const CSTOPB: u32 = 64;
-pub const CREAD: u32 = 128;
-pub const PARENB: u32 = 256;
-pub const PARODD: u32 = 512;
-pub const HUPCL: u32 = 1024;
-pub const CLOCAL: u32 = 2048;
-pub const CBAUDEX: u32 = 4096;
-pub const BOTHER: u32 = 4096;
-pub const B57600: u32 = 4097;
-pub const B115200: u32 = 4098;
-pub const B230400: u32 = 4099;
-pub const B460800: u32 = 4100;
-pub const B500000: u32 = 4101;
-pub const B576000: u32 = 4102;
-pub const B921600: u32 = 4103;
-pub const B1000000: u32 = 4104;
-pub const B1152000: u32 = 4105;
-pub const B1500000: u32 = 4106;
-pub const B2000000: u32 = 4107;
-pub const B2500000: u32 = 4108;
-pub const B3000000: u32 = 4109;
-pub const B3500000: u32 = 4110;
-pub const B4000000: u32 = 4111;
-pub const CIBAUD: u32 = 269418496;
-pub const CMSPAR: u32 = 1073741824;
-pub const CRTSCTS: u32 = 2147483648;
-pub const IBSHIFT: u32 = 16;
-pub const ISIG: u32 = 1;
-pub const ICANON: u32 = 2;
-pub const XCASE: u32 = 4;
-pub const ECHO: u32 = 8;
-pub const ECHOE: u32 = 16;
-pub const ECHOK: u32 = 32;
-pub const ECHONL: u32 = 64;
-pub const NOFLSH: u32 = 128;
-pub const TOSTOP: u32 = 256;
-pub const ECHOCTL: u32 = 512;
-pub const ECHOPRT: u32 = 1024;
-pub const ECHOKE: u32 = 2048;
-pub const FLUSHO: u32 = 4096;
-pub const PENDIN: u32 = 16384;
-pub const IEXTEN: u32 = 32768;
-pub const TCOOFF: u32 = 0;
-pub const TCOON: u32 = 1;
-pub const TCIOFF: u32 = 2;
-pub const TCION: u32 = 3;
-pub const TCIFLUSH: u32 = 0;
-pub const TCOFLUSH: u32 = 1;
-pub const TCIOFLUSH: u32 = 2;
-pub const TCSANOW: u32 = 0;
-pub const TCSADRAIN: u32 = 1;
-pub const TCSAFLUSH: u32 = 2;
-pub const TCGETS: u32 = 21505;
-pub const TCSETS: u32 = 21506;
-pub const TCSETSW: u32 = 21507;
-pub const TCSETSF: u32 = 21508;
-pub const TCGETA: u32 = 21509;
-pub const TCSETA: u32 = 21510;
-pub const TCSETAW: u32 = 21511;
-pub const TCSETAF: u32 = 21512;
-pub const TCSBRK: u32 = 21513;
-pub const TCXONC: u32 = 21514;
-pub const TCFLSH: u32 = 21515;
-pub const TIOCEXCL: u32 = 21516;
-pub const TIOCNXCL: u32 = 21517;
-pub const TIOCSCTTY: u32 = 21518;
-pub const TIOCGPGRP: u32 = 21519;
-pub const TIOCSPGRP: u32 = 21520;
-pub const TIOCOUTQ: u32 = 21521;
-pub const TIOCSTI: u32 = 21522;
-pub const TIOCGWINSZ: u32 = 21523;
-pub const TIOCSWINSZ: u32 = 21524;
-pub const TIOCMGET: u32 = 21525;
-pub const TIOCMBIS: u32 = 21526;
-pub const TIOCMBIC: u32 = 21527;
-pub const TIOCMSET: u32 = 21528;
-pub const TIOCGSOFTCAR: u32 = 21529;
-pub const TIOCSSOFTCAR: u32 = 21530;
-pub const FIONREAD: u32 = 21531;
-pub const TIOCINQ: u32 = 21531;
-pub const TIOCLINUX: u32 = 21532;
-pub const TIOCCONS: u32 = 21533;
-pub const TIOCGSERIAL: u32 = 21534;
-pub const TIOCSSERIAL: u32 = 21535;
-pub const TIOCPKT: u32 = 21536;
-pub const FIONBIO: u32 = 21537;
-pub const TIOCNOTTY: u32 = 21538;
-pub const TIOCSETD: u32 = 21539;
-pub const TIOCGETD: u32 = 21540;
-pub const TCSBRKP: u32 = 21541;
-pub const TIOCSBRK: u32 = 21543;
-pub const TIOCCBRK: u32 = 21544;
-pub const TIOCGSID: u32 = 21545;
-pub const TIOCGRS485: u32 = 21550;
-pub const TIOCSRS485: u32 = 21551;
-pub const TCGETX: u32 = 21554;
-pub const TCSETX: u32 = 21555;
-pub const TCSETXF: u32 = 21556;
-pub const TCSETXW: u32 = 21557;
-pub const FIONCLEX: u32 = 21584;
-pub const FIOCLEX: u32 = 21585;
-pub const FIOASYNC: u32 = 21586;
-pub const TIOCSERCONFIG: u32 = 21587;
-pub const TIOCSERGWILD: u32 = 21588;
-pub const TIOCSERSWILD: u32 = 21589;
-pub const TIOCGLCKTRMIOS: u32 = 21590;
-pub const TIOCSLCKTRMIOS: u32 = 21591;
-pub const TIOCSERGSTRUCT: u32 = 21592;
-pub const TIOCSERGETLSR: u32 = 21593;
-pub const TIOCSERGETMULTI: u32 = 21594;
-pub const TIOCSERSETMULTI: u32 = 21595;
-pub const TIOCMIWAIT: u32 = 21596;
-pub const TIOCGICOUNT: u32 = 21597;
-pub const TIOCGHAYESESP: u32 = 21598;
-pub const TIOCSHAYESESP: u32 = 21599;
-pub const FIOQSIZE: u32 = 21600;
-pub const TIOCPKT_DATA: u32 = 0;
-pub const TIOCPKT_FLUSHREAD: u32 = 1;
-pub const TIOCPKT_FLUSHWRITE: u32 = 2;
-pub const TIOCPKT_STOP: u32 = 4;
-pub const TIOCPKT_START: u32 = 8;
-pub const TIOCPKT_NOSTOP: u32 = 16;
-pub const TIOCPKT_DOSTOP: u32 = 32;
-pub const TIOCSER_TEMT: u32 = 1;
-pub const NCC: u32 = 8;
-pub const TIOCM_LE: u32 = 1;
-pub const TIOCM_DTR: u32 = 2;
-pub const TIOCM_RTS: u32 = 4;
-pub const TIOCM_ST: u32 = 8;
-pub const TIOCM_SR: u32 = 16;
-pub const TIOCM_CTS: u32 = 32;
-pub const TIOCM_CAR: u32 = 64;
-pub const TIOCM_RNG: u32 = 128;
-pub const TIOCM_DSR: u32 = 256;
-pub const TIOCM_CD: u32 = 64;
-pub const TIOCM_RI: u32 = 128;
-pub const TIOCM_OUT1: u32 = 8192;
-pub const TIOCM_OUT2: u32 = 16384;
-pub const TIOCM_LOOP: u32 = 32768;
-pub const NFF: u32 = 5;
-pub const RTSXOFF: u32 = 1;
-pub const CTSXON: u32 = 2;
-pub const DTRXOFF: u32 = 4;
-pub const DSRXON: u32 = 8;
-pub const UIO_FASTIOV: u32 = 8;
-pub const UIO_MAXIOV: u32 = 1024;
-pub const UNIX_PATH_MAX: u32 = 108;
-pub const __NR_read: u32 = 0;
-pub const __NR_write: u32 = 1;
-pub const __NR_open: u32 = 2;
-pub const __NR_close: u32 = 3;
-pub const __NR_stat: u32 = 4;
-pub const __NR_fstat: u32 = 5;
-pub const __NR_lstat: u32 = 6;
-pub const __NR_poll: u32 = 7;
-pub const __NR_lseek: u32 = 8;
-pub const __NR_mmap: u32 = 9;
-pub const __NR_mprotect: u32 = 10;
-pub const __NR_munmap: u32 = 11;
-pub const __NR_brk: u32 = 12;
-pub const __NR_rt_sigaction: u32 = 13;
-pub const __NR_rt_sigprocmask: u32 = 14;
-pub const __NR_rt_sigreturn: u32 = 15;
-pub const __NR_ioctl: u32 = 16;
-pub const __NR_pread64: u32 = 17;
-pub const __NR_pwrite64: u32 = 18;
-pub const __NR_readv: u32 = 19;
-pub const __NR_writev: u32 = 20;
-pub const __NR_access: u32 = 21;
-pub const __NR_pipe: u32 = 22;
-pub const __NR_select: u32 = 23;
-pub const __NR_sched_yield: u32 = 24;
-pub const __NR_mremap: u32 = 25;
-pub const __NR_msync: u32 = 26;
-pub const __NR_mincore: u32 = 27;
-pub const __NR_madvise: u32 = 28;
-pub const __NR_shmget: u32 = 29;
-pub const __NR_shmat: u32 = 30;
-pub const __NR_shmctl: u32 = 31;
-pub const __NR_dup: u32 = 32;
-pub const __NR_dup2: u32 = 33;
-pub const __NR_pause: u32 = 34;
-pub const __NR_nanosleep: u32 = 35;
-pub const __NR_getitimer: u32 = 36;
-pub const __NR_alarm: u32 = 37;
-pub const __NR_setitimer: u32 = 38;
-pub const __NR_getpid: u32 = 39;
-pub const __NR_sendfile: u32 = 40;
-pub const __NR_socket: u32 = 41;
-pub const __NR_connect: u32 = 42;
-pub const __NR_accept: u32 = 43;
-pub const __NR_sendto: u32 = 44;
-pub const __NR_recvfrom: u32 = 45;
-pub const __NR_sendmsg: u32 = 46;
-pub const __NR_recvmsg: u32 = 47;
-pub const __NR_shutdown: u32 = 48;
-pub const __NR_bind: u32 = 49;
-pub const __NR_listen: u32 = 50;
-pub const __NR_getsockname: u32 = 51;
-pub const __NR_getpeername: u32 = 52;
-pub const __NR_socketpair: u32 = 53;
-pub const __NR_setsockopt: u32 = 54;
-pub const __NR_getsockopt: u32 = 55;
-pub const __NR_clone: u32 = 56;
-pub const __NR_fork: u32 = 57;
-pub const __NR_vfork: u32 = 58;
-pub const __NR_execve: u32 = 59;
-pub const __NR_exit: u32 = 60;
-pub const __NR_wait4: u32 = 61;
-pub const __NR_kill: u32 = 62;
-pub const __NR_uname: u32 = 63;
-pub const __NR_semget: u32 = 64;
-pub const __NR_semop: u32 = 65;
-pub const __NR_semctl: u32 = 66;
-pub const __NR_shmdt: u32 = 67;
-pub const __NR_msgget: u32 = 68;
-pub const __NR_msgsnd: u32 = 69;
-pub const __NR_msgrcv: u32 = 70;
-pub const __NR_msgctl: u32 = 71;
-pub const __NR_fcntl: u32 = 72;
-pub const __NR_flock: u32 = 73;
-pub const __NR_fsync: u32 = 74;
-pub const __NR_fdatasync: u32 = 75;
-pub const __NR_truncate: u32 = 76;
-pub const __NR_ftruncate: u32 = 77;
-pub const __NR_getdents: u32 = 78;
-pub const __NR_getcwd: u32 = 79;
-pub const __NR_chdir: u32 = 80;
-pub const __NR_fchdir: u32 = 81;
-pub const __NR_rename: u32 = 82;
-pub const __NR_mkdir: u32 = 83;
-pub const __NR_rmdir: u32 = 84;
-pub const __NR_creat: u32 = 85;
-pub const __NR_link: u32 = 86;
-pub const __NR_unlink: u32 = 87;
-pub const __NR_symlink: u32 = 88;
-pub const __NR_readlink: u32 = 89;
-pub const __NR_chmod: u32 = 90;
-pub const __NR_fchmod: u32 = 91;
-pub const __NR_chown: u32 = 92;
-pub const __NR_fchown: u32 = 93;
-pub const __NR_lchown: u32 = 94;
-pub const __NR_umask: u32 = 95;
-pub const __NR_gettimeofday: u32 = 96;
-pub const __NR_getrlimit: u32 = 97;
-pub const __NR_getrusage: u32 = 98;
-pub const __NR_sysinfo: u32 = 99;
-pub const __NR_times: u32 = 100;
-pub const __NR_ptrace: u32 = 101;
-pub const __NR_getuid: u32 = 102;
-pub const __NR_syslog: u32 = 103;
-pub const __NR_getgid: u32 = 104;
-pub const __NR_setuid: u32 = 105;
-pub const __NR_setgid: u32 = 106;
-pub const __NR_geteuid: u32 = 107;
-pub const __NR_getegid: u32 = 108;
-pub const __NR_setpgid: u32 = 109;
-pub const __NR_getppid: u32 = 110;
-pub const __NR_getpgrp: u32 = 111;
-pub const __NR_setsid: u32 = 112;
-pub const __NR_setreuid: u32 = 113;
-pub const __NR_setregid: u32 = 114;
-pub const __NR_getgroups: u32 = 115;
-pub const __NR_setgroups: u32 = 116;
-pub const __NR_setresuid: u32 = 117;
-pub const __NR_getresuid: u32 = 118;
-pub const __NR_setresgid: u32 = 119;
-pub const __NR_getresgid: u32 = 120;
-pub const __NR_getpgid: u32 = 121;
-pub const __NR_setfsuid: u32 = 122;
-pub const __NR_setfsgid: u32 = 123;
-pub const __NR_getsid: u32 = 124;
-pub const __NR_capget: u32 = 125;
-pub const __NR_capset: u32 = 126;
-pub const __NR_rt_sigpending: u32 = 127;
-pub const __NR_rt_sigtimedwait: u32 = 128;
-pub const __NR_rt_sigqueueinfo: u32 = 129;
-pub const __NR_rt_sigsuspend: u32 = 130;
-pub const __NR_sigaltstack: u32 = 131;
-pub const __NR_utime: u32 = 132;
-pub const __NR_mknod: u32 = 133;
-pub const __NR_uselib: u32 = 134;
-pub const __NR_personality: u32 = 135;
-pub const __NR_ustat: u32 = 136;
-pub const __NR_statfs: u32 = 137;
-pub const __NR_fstatfs: u32 = 138;
-pub const __NR_sysfs: u32 = 139;
-pub const __NR_getpriority: u32 = 140;
-pub const __NR_setpriority: u32 = 141;
-pub const __NR_sched_setparam: u32 = 142;
-pub const __NR_sched_getparam: u32 = 143;
-pub const __NR_sched_setscheduler: u32 = 144;
-pub const __NR_sched_getscheduler: u32 = 145;
-pub const __NR_sched_get_priority_max: u32 = 146;
-pub const __NR_sched_get_priority_min: u32 = 147;
-pub const __NR_sched_rr_get_interval: u32 = 148;
-pub const __NR_mlock: u32 = 149;
-pub const __NR_munlock: u32 = 150;
-pub const __NR_mlockall: u32 = 151;
-pub const __NR_munlockall: u32 = 152;
-pub const __NR_vhangup: u32 = 153;
-pub const __NR_modify_ldt: u32 = 154;
-pub const __NR_pivot_root: u32 = 155;
-pub const __NR__sysctl: u32 = 156;
-pub const __NR_prctl: u32 = 157;
-pub const __NR_arch_prctl: u32 = 158;
-pub const __NR_adjtimex: u32 = 159;
-pub const __NR_setrlimit: u32 = 160;
-pub const __NR_chroot: u32 = 161;
-pub const __NR_sync: u32 = 162;
-pub const __NR_acct: u32 = 163;
-pub const __NR_settimeofday: u32 = 164;
-pub const __NR_mount: u32 = 165;
-pub const __NR_umount2: u32 = 166;
-pub const __NR_swapon: u32 = 167;
-pub const __NR_swapoff: u32 = 168;
-pub const __NR_reboot: u32 = 169;
-pub const __NR_sethostname: u32 = 170;
-pub const __NR_setdomainname: u32 = 171;
-pub const __NR_iopl: u32 = 172;
-pub const __NR_ioperm: u32 = 173;
-pub const __NR_create_module: u32 = 174;
-pub const __NR_init_module: u32 = 175;
-pub const __NR_delete_module: u32 = 176;
-pub const __NR_get_kernel_syms: u32 = 177;
-pub const __NR_query_module: u32 = 178;
-pub const __NR_quotactl: u32 = 179;
-pub const __NR_nfsservctl: u32 = 180;
-pub const __NR_getpmsg: u32 = 181;
-pub const __NR_putpmsg: u32 = 182;
-pub const __NR_afs_syscall: u32 = 183;
-pub const __NR_tuxcall: u32 = 184;
-pub const __NR_security: u32 = 185;
-pub const __NR_gettid: u32 = 186;
-pub const __NR_readahead: u32 = 187;
-pub const __NR_setxattr: u32 = 188;
-pub const __NR_lsetxattr: u32 = 189;
-pub const __NR_fsetxattr: u32 = 190;
-pub const __NR_getxattr: u32 = 191;
-pub const __NR_lgetxattr: u32 = 192;
-pub const __NR_fgetxattr: u32 = 193;
-pub const __NR_listxattr: u32 = 194;
-pub const __NR_llistxattr: u32 = 195;
-pub const __NR_flistxattr: u32 = 196;
-pub const __NR_removexattr: u32 = 197;
-pub const __NR_lremovexattr: u32 = 198;
-pub const __NR_fremovexattr: u32 = 199;
-pub const __NR_tkill: u32 = 200;
-pub const __NR_time: u32 = 201;
-pub const __NR_futex: u32 = 202;
-pub const __NR_sched_setaffinity: u32 = 203;
-pub const __NR_sched_getaffinity: u32 = 204;
-pub const __NR_set_thread_area: u32 = 205;
-pub const __NR_io_setup: u32 = 206;
-pub const __NR_io_destroy: u32 = 207;
-pub const __NR_io_getevents: u32 = 208;
-pub const __NR_io_submit: u32 = 209;
-pub const __NR_io_cancel: u32 = 210;
-pub const __NR_get_thread_area: u32 = 211;
-pub const __NR_lookup_dcookie: u32 = 212;
-pub const __NR_epoll_create: u32 = 213;
-pub const __NR_epoll_ctl_old: u32 = 214;
-pub const __NR_epoll_wait_old: u32 = 215;
-pub const __NR_remap_file_pages: u32 = 216;
-pub const __NR_getdents64: u32 = 217;
-pub const __NR_set_tid_address: u32 = 218;
-pub const __NR_restart_syscall: u32 = 219;
-pub const __NR_semtimedop: u32 = 220;
-pub const __NR_fadvise64: u32 = 221;
-pub const __NR_timer_create: u32 = 222;
-pub const __NR_timer_settime: u32 = 223;
-pub const __NR_timer_gettime: u32 = 224;
-pub const __NR_timer_getoverrun: u32 = 225;
-pub const __NR_timer_delete: u32 = 226;
-pub const __NR_clock_settime: u32 = 227;
-pub const __NR_clock_gettime: u32 = 228;
-pub const __NR_clock_getres: u32 = 229;
-pub const __NR_clock_nanosleep: u32 = 230;
-pub const __NR_exit_group: u32 = 231;
-pub const __NR_epoll_wait: u32 = 232;
-pub const __NR_epoll_ctl: u32 = 233;
-pub const __NR_tgkill: u32 = 234;
-pub const __NR_utimes: u32 = 235;
-pub const __NR_vserver: u32 = 236;
-pub const __NR_mbind: u32 = 237;
-pub const __NR_set_mempolicy: u32 = 238;
-pub const __NR_get_mempolicy: u32 = 239;
-pub const __NR_mq_open: u32 = 240;
-pub const __NR_mq_unlink: u32 = 241;
-pub const __NR_mq_timedsend: u32 = 242;
-pub const __NR_mq_timedreceive: u32 = 243;
-pub const __NR_mq_notify: u32 = 244;
-pub const __NR_mq_getsetattr: u32 = 245;
-pub const __NR_kexec_load: u32 = 246;
-pub const __NR_waitid: u32 = 247;
-pub const __NR_add_key: u32 = 248;
-pub const __NR_request_key: u32 = 249;
-pub const __NR_keyctl: u32 = 250;
-pub const __NR_ioprio_set: u32 = 251;
-pub const __NR_ioprio_get: u32 = 252;
-pub const __NR_inotify_init: u32 = 253;
-pub const __NR_inotify_add_watch: u32 = 254;
-pub const __NR_inotify_rm_watch: u32 = 255;
-pub const __NR_migrate_pages: u32 = 256;
-pub const __NR_openat: u32 = 257;
-pub const __NR_mkdirat: u32 = 258;
-pub const __NR_mknodat: u32 = 259;
-pub const __NR_fchownat: u32 = 260;
-pub const __NR_futimesat: u32 = 261;
-pub const __NR_newfstatat: u32 = 262;
-pub const __NR_unlinkat: u32 = 263;
-pub const __NR_renameat: u32 = 264;
-pub const __NR_linkat: u32 = 265;
-pub const __NR_symlinkat: u32 = 266;
-pub const __NR_readlinkat: u32 = 267;
-pub const __NR_fchmodat: u32 = 268;
-pub const __NR_faccessat: u32 = 269;
-pub const __NR_pselect6: u32 = 270;
-pub const __NR_ppoll: u32 = 271;
-pub const __NR_unshare: u32 = 272;
-pub const __NR_set_robust_list: u32 = 273;
-pub const __NR_get_robust_list: u32 = 274;
-pub const __NR_splice: u32 = 275;
-pub const __NR_tee: u32 = 276;
-pub const __NR_sync_file_range: u32 = 277;
-pub const __NR_vmsplice: u32 = 278;
-pub const __NR_move_pages: u32 = 279;
-pub const __NR_utimensat: u32 = 280;
-pub const __NR_epoll_pwait: u32 = 281;
-pub const __NR_signalfd: u32 = 282;
-pub const __NR_timerfd_create: u32 = 283;
-pub const __NR_eventfd: u32 = 284;
-pub const __NR_fallocate: u32 = 285;
-pub const __NR_timerfd_settime: u32 = 286;
-pub const __NR_timerfd_gettime: u32 = 287;
-pub const __NR_accept4: u32 = 288;
-pub const __NR_signalfd4: u32 = 289;
-pub const __NR_eventfd2: u32 = 290;
-pub const __NR_epoll_create1: u32 = 291;
-pub const __NR_dup3: u32 = 292;
-pub const __NR_pipe2: u32 = 293;
-pub const __NR_inotify_init1: u32 = 294;
-pub const __NR_preadv: u32 = 295;
-pub const __NR_pwritev: u32 = 296;
-pub const __NR_rt_tgsigqueueinfo: u32 = 297;
-pub const __NR_perf_event_open: u32 = 298;
-pub const __OLD_UTS_LEN: u32 = 8;
-pub const __NEW_UTS_LEN: u32 = 64;
-pub const WNOHANG: u32 = 1;
-pub const WUNTRACED: u32 = 2;
-pub const WSTOPPED: u32 = 2;
-pub const WEXITED: u32 = 4;
-pub const WCONTINUED: u32 = 8;
-pub const WNOWAIT: u32 = 16777216;
-pub const __WNOTHREAD: u32 = 536870912;
-pub const __WALL: u32 = 1073741824;
-pub const __WCLONE: u32 = 2147483648;
-pub const P_ALL: u32 = 0;
-pub const P_PID: u32 = 1;
-pub const P_PGID: u32 = 2;
-pub const DT_UNKNOWN: u32 = 0;
-pub const DT_FIFO: u32 = 1;
-pub const DT_CHR: u32 = 2;
-pub const DT_DIR: u32 = 4;
-pub const DT_BLK: u32 = 6;
-pub const DT_REG: u32 = 8;
-pub const DT_LNK: u32 = 10;
-pub const DT_SOCK: u32 = 12;
-pub const SHUT_RD: u32 = 0;
-pub const SHUT_WR: u32 = 1;
-pub const SHUT_RDWR: u32 = 2;
-pub const STAT_HAVE_NSEC: u32 = 1;
-pub const SOCK_STREAM: u32 = 1;
-pub const SOCK_DGRAM: u32 = 2;
-pub const SOCK_RAW: u32 = 3;
-pub const SOCK_RDM: u32 = 4;
-pub const SOCK_SEQPACKET: u32 = 5;
-pub const F_OK: u32 = 0;
-pub const R_OK: u32 = 4;
-pub const W_OK: u32 = 2;
-pub const X_OK: u32 = 1;
-pub const UTIME_NOW: u32 = 1073741823;
-pub const UTIME_OMIT: u32 = 1073741822;
-pub const MSG_DONTWAIT: u32 = 64;
-pub const AF_UNSPEC: u32 = 0;
-pub const AF_UNIX: u32 = 1;
-pub const AF_INET: u32 = 2;
-pub const AF_AX25: u32 = 3;
-pub const AF_IPX: u32 = 4;
-pub const AF_APPLETALK: u32 = 5;
-pub const AF_NETROM: u32 = 6;
-pub const AF_BRIDGE: u32 = 7;
-pub const AF_ATMPVC: u32 = 8;
-pub const AF_X25: u32 = 9;
-pub const AF_INET6: u32 = 10;
-pub const AF_ROSE: u32 = 11;
-pub const AF_DECnet: u32 = 12;
-pub const AF_NETBEUI: u32 = 13;
-pub const AF_SECURITY: u32 = 14;
-pub const AF_KEY: u32 = 15;
-pub const AF_NETLINK: u32 = 16;
-pub const AF_PACKET: u32 = 17;
-pub const AF_ASH: u32 = 18;
-pub const AF_ECONET: u32 = 19;
-pub const AF_ATMSVC: u32 = 20;
-pub const AF_RDS: u32 = 21;
-pub const AF_SNA: u32 = 22;
-pub const AF_IRDA: u32 = 23;
-pub const AF_PPPOX: u32 = 24;
-pub const AF_WANPIPE: u32 = 25;
-pub const AF_LLC: u32 = 26;
-pub const AF_CAN: u32 = 29;
-pub const AF_TIPC: u32 = 30;
-pub const AF_BLUETOOTH: u32 = 31;
-pub const AF_IUCV: u32 = 32;
-pub const AF_RXRPC: u32 = 33;
-pub const AF_ISDN: u32 = 34;
-pub const AF_PHONET: u32 = 35;
-pub const AF_IEEE802154: u32 = 36;
-pub const AF_MAX: u32 = 37;
-pub const MSG_OOB: u32 = 1;
-pub const MSG_PEEK: u32 = 2;
-pub const MSG_DONTROUTE: u32 = 4;
-pub const MSG_CTRUNC: u32 = 8;
-pub const MSG_PROBE: u32 = 16;
-pub const MSG_TRUNC: u32 = 32;
-pub const MSG_EOR: u32 = 128;
-pub const MSG_WAITALL: u32 = 256;
-pub const MSG_FIN: u32 = 512;
-pub const MSG_SYN: u32 = 1024;
-pub const MSG_CONFIRM: u32 = 2048;
-pub const MSG_RST: u32 = 4096;
-pub const MSG_ERRQUEUE: u32 = 8192;
-pub const MSG_NOSIGNAL: u32 = 16384;
-pub const MSG_MORE: u32 = 32768;
-pub const MSG_CMSG_CLOEXEC: u32 = 1073741824;
-pub const STDIN_FILENO: u32 = 0;
-pub const STDOUT_FILENO: u32 = 1;
-pub const STDERR_FILENO: u32 = 2;
-pub const RWF_HIPRI: u32 = 1;
-pub const RWF_DSYNC: u32 = 2;
-pub const RWF_SYNC: u32 = 4;
-pub const RWF_NOWAIT: u32 = 8;
-pub const RWF_APPEND: u32 = 16;
-pub const EFD_SEMAPHORE: u32 = 1;
-pub const EFD_CLOEXEC: u32 = 524288;
-pub const EFD_NONBLOCK: u32 = 2048;
-pub const EPOLLIN: u32 = 1;
-pub const EPOLLPRI: u32 = 2;
-pub const EPOLLOUT: u32 = 4;
-pub const EPOLLERR: u32 = 8;
-pub const EPOLLHUP: u32 = 16;
-pub const EPOLLNVAL: u32 = 32;
-pub const EPOLLRDNORM: u32 = 64;
-pub const EPOLLRDBAND: u32 = 128;
-pub const EPOLLWRNORM: u32 = 256;
-pub const EPOLLWRBAND: u32 = 512;
-pub const EPOLLMSG: u32 = 1024;
-pub const EPOLLRDHUP: u32 = 8192;
-pub const EPOLLEXCLUSIVE: u32 = 268435456;
-pub const EPOLLWAKEUP: u32 = 536870912;
-pub const TFD_TIMER_ABSTIME: u32 = 1;
-pub const TFD_CLOEXEC: u32 = 524288;
-pub const TFD_NONBLOCK: u32 = 2048;
-pub const TFD_SHARED_FCNTL_FLAGS: u32 = 526336;
-pub const TFD_CREATE_FLAGS: u32 = 526336;
-pub const TFD_SETTIME_FLAGS: u32 = 1;
-pub const ARCH_SET_FS: u32 = 4098;
-pub const BLKSSZGET: u32 = 4712;
-pub const BLKPBSZGET: u32 = 4731;
+include!("../shared/general.rs");
+include!("general_consts.rs");
 pub type size_t = crate::ctypes::c_ulong;
 pub type ssize_t = crate::ctypes::c_long;
-pub type __s8 = crate::ctypes::c_schar;
-pub type __u8 = crate::ctypes::c_uchar;
-pub type __s16 = crate::ctypes::c_short;
-pub type __u16 = crate::ctypes::c_ushort;
-pub type __s32 = crate::ctypes::c_int;
-pub type __u32 = crate::ctypes::c_uint;
-pub type __s64 = crate::ctypes::c_longlong;
-pub type __u64 = crate::ctypes::c_ulonglong;
-pub type umode_t = crate::ctypes::c_ushort;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct __kernel_fd_set {
 pub fds_bits: [crate::ctypes::c_ulong; 16usize],
 }
-pub type __kernel_sighandler_t = ::core::option::Option<unsafe extern "C" fn(arg1: crate::ctypes::c_int)>;
-pub type __kernel_key_t = crate::ctypes::c_int;
-pub type __kernel_mqd_t = crate::ctypes::c_int;
-pub type __kernel_ino_t = crate::ctypes::c_ulong;
 pub type __kernel_mode_t = crate::ctypes::c_uint;
 pub type __kernel_nlink_t = crate::ctypes::c_ulong;
-pub type __kernel_off_t = crate::ctypes::c_long;
-pub type __kernel_pid_t = crate::ctypes::c_int;
 pub type __kernel_ipc_pid_t = crate::ctypes::c_int;
 pub type __kernel_uid_t = crate::ctypes::c_uint;
 pub type __kernel_gid_t = crate::ctypes::c_uint;
 pub type __kernel_size_t = crate::ctypes::c_ulong;
 pub type __kernel_ssize_t = crate::ctypes::c_long;
 pub type __kernel_ptrdiff_t = crate::ctypes::c_long;
-pub type __kernel_time_t = crate::ctypes::c_long;
-pub type __kernel_suseconds_t = crate::ctypes::c_long;
-pub type __kernel_clock_t = crate::ctypes::c_long;
-pub type __kernel_timer_t = crate::ctypes::c_int;
-pub type __kernel_clockid_t = crate::ctypes::c_int;
-pub type __kernel_daddr_t = crate::ctypes::c_int;
-pub type __kernel_caddr_t = *mut crate::ctypes::c_char;
-pub type __kernel_uid16_t = crate::ctypes::c_ushort;
-pub type __kernel_gid16_t = crate::ctypes::c_ushort;
-pub type __kernel_loff_t = crate::ctypes::c_longlong;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct __kernel_fsid_t {
-pub val: [crate::ctypes::c_int; 2usize],
-}
 pub type __kernel_old_uid_t = crate::ctypes::c_ushort;
 pub type __kernel_old_gid_t = crate::ctypes::c_ushort;
 pub type __kernel_uid32_t = __kernel_uid_t;
 pub type __kernel_gid32_t = __kernel_gid_t;
 pub type __kernel_old_dev_t = crate::ctypes::c_ulong;
-pub type __le16 = __u16;
-pub type __be16 = __u16;
-pub type __le32 = __u32;
-pub type __be32 = __u32;
-pub type __le64 = __u64;
-pub type __be64 = __u64;
-pub type __sum16 = __u16;
-pub type __wsum = __u32;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct f_owner_ex {
-pub type_: crate::ctypes::c_int,
-pub pid: __kernel_pid_t,
-}
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct flock {
 pub l_type: crate::ctypes::c_short,
 pub l_whence: crate::ctypes::c_short,
@@ -1781,7 +40,12 @@ pub l_len: __kernel_off_t,
 pub l_pid: __kernel_pid_t,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct flock64 {
 pub l_type: crate::ctypes::c_short,
 pub l_whence: crate::ctypes::c_short,
@@ -1790,142 +54,54 @@ pub l_len: __kernel_loff_t,
 pub l_pid: __kernel_pid_t,
 }
 #[repr(C, packed)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct epoll_event {
 pub events: __u32,
 pub data: __u64,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct files_stat_struct {
-pub nr_files: crate::ctypes::c_int,
-pub nr_free_files: crate::ctypes::c_int,
-pub max_files: crate::ctypes::c_int,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct inodes_stat_t {
-pub nr_inodes: crate::ctypes::c_int,
-pub nr_unused: crate::ctypes::c_int,
-pub dummy: [crate::ctypes::c_int; 5usize],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct robust_list {
-pub next: *mut robust_list,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct robust_list_head {
-pub list: robust_list,
-pub futex_offset: crate::ctypes::c_long,
-pub list_op_pending: *mut robust_list,
-}
-#[repr(C)]
 #[repr(align(8))]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct __kernel_sockaddr_storage {
 pub ss_family: crate::ctypes::c_ushort,
 pub __data: [crate::ctypes::c_char; 126usize],
 }
-pub const IPPROTO_IP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_IP;
-pub const IPPROTO_ICMP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_ICMP;
-pub const IPPROTO_IGMP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_IGMP;
-pub const IPPROTO_IPIP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_IPIP;
-pub const IPPROTO_TCP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_TCP;
-pub const IPPROTO_EGP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_EGP;
-pub const IPPROTO_PUP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_PUP;
-pub const IPPROTO_UDP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_UDP;
-pub const IPPROTO_IDP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_IDP;
-pub const IPPROTO_DCCP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_DCCP;
-pub const IPPROTO_RSVP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_RSVP;
-pub const IPPROTO_GRE: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_GRE;
-pub const IPPROTO_IPV6: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_IPV6;
-pub const IPPROTO_ESP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_ESP;
-pub const IPPROTO_AH: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_AH;
-pub const IPPROTO_BEETPH: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_BEETPH;
-pub const IPPROTO_PIM: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_PIM;
-pub const IPPROTO_COMP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_COMP;
-pub const IPPROTO_SCTP: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_SCTP;
-pub const IPPROTO_UDPLITE: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_UDPLITE;
-pub const IPPROTO_RAW: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_RAW;
-pub const IPPROTO_MAX: _bindgen_ty_1 = _bindgen_ty_1::IPPROTO_MAX;
-#[repr(u32)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum _bindgen_ty_1 {
-IPPROTO_IP = 0,
-IPPROTO_ICMP = 1,
-IPPROTO_IGMP = 2,
-IPPROTO_IPIP = 4,
-IPPROTO_TCP = 6,
-IPPROTO_EGP = 8,
-IPPROTO_PUP = 12,
-IPPROTO_UDP = 17,
-IPPROTO_IDP = 22,
-IPPROTO_DCCP = 33,
-IPPROTO_RSVP = 46,
-IPPROTO_GRE = 47,
-IPPROTO_IPV6 = 41,
-IPPROTO_ESP = 50,
-IPPROTO_AH = 51,
-IPPROTO_BEETPH = 94,
-IPPROTO_PIM = 103,
-IPPROTO_COMP = 108,
-IPPROTO_SCTP = 132,
-IPPROTO_UDPLITE = 136,
-IPPROTO_RAW = 255,
-IPPROTO_MAX = 256,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct in_addr {
-pub s_addr: __be32,
-}
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ip_mreq {
-pub imr_multiaddr: in_addr,
-pub imr_interface: in_addr,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ip_mreqn {
-pub imr_multiaddr: in_addr,
-pub imr_address: in_addr,
-pub imr_ifindex: crate::ctypes::c_int,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ip_mreq_source {
-pub imr_multiaddr: __be32,
-pub imr_interface: __be32,
-pub imr_sourceaddr: __be32,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ip_msfilter {
-pub imsf_multiaddr: __be32,
-pub imsf_interface: __be32,
-pub imsf_fmode: __u32,
-pub imsf_numsrc: __u32,
-pub imsf_slist: [__be32; 1usize],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub struct group_req {
 pub gr_interface: __u32,
 pub gr_group: __kernel_sockaddr_storage,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub struct group_source_req {
 pub gsr_interface: __u32,
 pub gsr_group: __kernel_sockaddr_storage,
 pub gsr_source: __kernel_sockaddr_storage,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub struct group_filter {
 pub gf_interface: __u32,
 pub gf_group: __kernel_sockaddr_storage,
@@ -1933,155 +109,10 @@ pub gf_fmode: __u32,
 pub gf_numsrc: __u32,
 pub gf_slist: [__kernel_sockaddr_storage; 1usize],
 }
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct in_pktinfo {
-pub ipi_ifindex: crate::ctypes::c_int,
-pub ipi_spec_dst: in_addr,
-pub ipi_addr: in_addr,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct sockaddr_in {
-pub sin_family: u16,
-pub sin_port: __be16,
-pub sin_addr: in_addr,
-pub __pad: [crate::ctypes::c_uchar; 8usize],
-}
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub struct in6_addr {
-pub in6_u: in6_addr__bindgen_ty_1,
-}
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub union in6_addr__bindgen_ty_1 {
-pub u6_addr8: [__u8; 16usize],
-pub u6_addr16: [__be16; 8usize],
-pub u6_addr32: [__be32; 4usize],
-}
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub struct sockaddr_in6 {
-pub sin6_family: crate::ctypes::c_ushort,
-pub sin6_port: __be16,
-pub sin6_flowinfo: __be32,
-pub sin6_addr: in6_addr,
-pub sin6_scope_id: __u32,
-}
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub struct ipv6_mreq {
-pub ipv6mr_multiaddr: in6_addr,
-pub ipv6mr_ifindex: crate::ctypes::c_int,
-}
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub struct in6_flowlabel_req {
-pub flr_dst: in6_addr,
-pub flr_label: __be32,
-pub flr_action: __u8,
-pub flr_share: __u8,
-pub flr_flags: __u16,
-pub flr_expires: __u16,
-pub flr_linger: __u16,
-pub __flr_pad: __u32,
-}
-#[repr(u32)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum socket_state {
-SS_FREE = 0,
-SS_UNCONNECTED = 1,
-SS_CONNECTING = 2,
-SS_CONNECTED = 3,
-SS_DISCONNECTING = 4,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct pollfd {
-pub fd: crate::ctypes::c_int,
-pub events: crate::ctypes::c_short,
-pub revents: crate::ctypes::c_short,
-}
-#[repr(C)]
-#[derive(Debug)]
-pub struct rand_pool_info {
-pub entropy_count: crate::ctypes::c_int,
-pub buf_size: crate::ctypes::c_int,
-pub buf: __IncompleteArrayField<__u32>,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct timespec {
-pub tv_sec: __kernel_time_t,
-pub tv_nsec: crate::ctypes::c_long,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct timeval {
-pub tv_sec: __kernel_time_t,
-pub tv_usec: __kernel_suseconds_t,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct timezone {
-pub tz_minuteswest: crate::ctypes::c_int,
-pub tz_dsttime: crate::ctypes::c_int,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct itimerspec {
-pub it_interval: timespec,
-pub it_value: timespec,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct itimerval {
-pub it_interval: timeval,
-pub it_value: timeval,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct task_struct {
-_unused: [u8; 0],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct rusage {
-pub ru_utime: timeval,
-pub ru_stime: timeval,
-pub ru_maxrss: crate::ctypes::c_long,
-pub ru_ixrss: crate::ctypes::c_long,
-pub ru_idrss: crate::ctypes::c_long,
-pub ru_isrss: crate::ctypes::c_long,
-pub ru_minflt: crate::ctypes::c_long,
-pub ru_majflt: crate::ctypes::c_long,
-pub ru_nswap: crate::ctypes::c_long,
-pub ru_inblock: crate::ctypes::c_long,
-pub ru_oublock: crate::ctypes::c_long,
-pub ru_msgsnd: crate::ctypes::c_long,
-pub ru_msgrcv: crate::ctypes::c_long,
-pub ru_nsignals: crate::ctypes::c_long,
-pub ru_nvcsw: crate::ctypes::c_long,
-pub ru_nivcsw: crate::ctypes::c_long,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct rlimit {
-pub rlim_cur: crate::ctypes::c_ulong,
-pub rlim_max: crate::ctypes::c_ulong,
-}
-extern "C" {
-pub fn getrusage(p: *mut task_struct, who: crate::ctypes::c_int, ru: *mut rusage) -> crate::ctypes::c_int;
-}
 pub type sigset_t = crate::ctypes::c_ulong;
-pub type __signalfn_t = ::core::option::Option<unsafe extern "C" fn(arg1: crate::ctypes::c_int)>;
-pub type __sighandler_t = __signalfn_t;
-pub type __restorefn_t = ::core::option::Option<unsafe extern "C" fn()>;
-pub type __sigrestore_t = __restorefn_t;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub struct sigaction {
 pub sa_handler: __sighandler_t,
 pub sa_flags: crate::ctypes::c_ulong,
@@ -2089,33 +120,13 @@ pub sa_restorer: __sigrestore_t,
 pub sa_mask: sigset_t,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub struct k_sigaction {
 pub sa: sigaction,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct sigaltstack {
-pub ss_sp: *mut crate::ctypes::c_void,
-pub ss_flags: crate::ctypes::c_int,
-pub ss_size: size_t,
-}
-pub type stack_t = sigaltstack;
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub union sigval {
-pub sival_int: crate::ctypes::c_int,
-pub sival_ptr: *mut crate::ctypes::c_void,
-}
-pub type sigval_t = sigval;
-#[repr(C)]
-pub struct siginfo {
-pub si_signo: crate::ctypes::c_int,
-pub si_errno: crate::ctypes::c_int,
-pub si_code: crate::ctypes::c_int,
-pub _sifields: siginfo__bindgen_ty_1,
-}
-#[repr(C)]
+#[cfg_attr(feature = "union-debug", derive(Debug))]
 pub struct siginfo__bindgen_ty_1 {
 pub _pad: __BindgenUnionField<[crate::ctypes::c_int; 28usize]>,
 pub _kill: __BindgenUnionField<siginfo__bindgen_ty_1__bindgen_ty_1>,
@@ -2127,28 +138,12 @@ pub _sigpoll: __BindgenUnionField<siginfo__bindgen_ty_1__bindgen_ty_6>,
 pub bindgen_union_field: [u64; 14usize],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct siginfo__bindgen_ty_1__bindgen_ty_1 {
-pub _pid: __kernel_pid_t,
-pub _uid: __kernel_uid32_t,
-}
-#[repr(C)]
-pub struct siginfo__bindgen_ty_1__bindgen_ty_2 {
-pub _tid: __kernel_timer_t,
-pub _overrun: crate::ctypes::c_int,
-pub _pad: __IncompleteArrayField<crate::ctypes::c_char>,
-pub _sigval: sigval_t,
-pub _sys_private: crate::ctypes::c_int,
-}
-#[repr(C)]
 #[derive(Copy, Clone)]
-pub struct siginfo__bindgen_ty_1__bindgen_ty_3 {
-pub _pid: __kernel_pid_t,
-pub _uid: __kernel_uid32_t,
-pub _sigval: sigval_t,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct siginfo__bindgen_ty_1__bindgen_ty_4 {
 pub _pid: __kernel_pid_t,
 pub _uid: __kernel_uid32_t,
@@ -2157,26 +152,16 @@ pub _utime: __kernel_clock_t,
 pub _stime: __kernel_clock_t,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct siginfo__bindgen_ty_1__bindgen_ty_5 {
-pub _addr: *mut crate::ctypes::c_void,
-pub _addr_lsb: crate::ctypes::c_short,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct siginfo__bindgen_ty_1__bindgen_ty_6 {
 pub _band: crate::ctypes::c_long,
 pub _fd: crate::ctypes::c_int,
 }
-pub type siginfo_t = siginfo;
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub struct sigevent {
-pub sigev_value: sigval_t,
-pub sigev_signo: crate::ctypes::c_int,
-pub sigev_notify: crate::ctypes::c_int,
-pub _sigev_un: sigevent__bindgen_ty_1,
-}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union sigevent__bindgen_ty_1 {
@@ -2184,26 +169,6 @@ pub _pad: [crate::ctypes::c_int; 12usize],
 pub _tid: crate::ctypes::c_int,
 pub _sigev_thread: sigevent__bindgen_ty_1__bindgen_ty_1,
 }
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct sigevent__bindgen_ty_1__bindgen_ty_1 {
-pub _function: ::core::option::Option<unsafe extern "C" fn(arg1: sigval_t)>,
-pub _attribute: *mut crate::ctypes::c_void,
-}
-pub type sigevent_t = sigevent;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct tcphdr {
-pub source: __be16,
-pub dest: __be16,
-pub seq: __be32,
-pub ack_seq: __be32,
-pub _bitfield_align_1: [u8; 0],
-pub _bitfield_1: __BindgenBitfieldUnit<[u8; 2usize]>,
-pub window: __be16,
-pub check: __sum16,
-pub urg_ptr: __be16,
-}
 impl tcphdr {
 #[inline]
 pub fn res1(&self) -> __u16 {
@@ -2361,25 +326,10 @@ cwr as u64
 __bindgen_bitfield_unit
 }
 }
-#[repr(C)]
-#[derive(Copy, Clone)]
-pub union tcp_word_hdr {
-pub hdr: tcphdr,
-pub words: [__be32; 5usize],
-}
-pub const TCP_FLAG_CWR: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_CWR;
-pub const TCP_FLAG_ECE: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_ECE;
-pub const TCP_FLAG_URG: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_URG;
-pub const TCP_FLAG_ACK: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_ACK;
-pub const TCP_FLAG_PSH: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_PSH;
-pub const TCP_FLAG_RST: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_RST;
-pub const TCP_FLAG_SYN: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_SYN;
-pub const TCP_FLAG_FIN: _bindgen_ty_2 = _bindgen_ty_2::TCP_FLAG_FIN;
-pub const TCP_RESERVED_BITS: _bindgen_ty_2 = _bindgen_ty_2::TCP_RESERVED_BITS;
-pub const TCP_DATA_OFFSET: _bindgen_ty_2 = _bindgen_ty_2::TCP_DATA_OFFSET;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_2 {
 TCP_FLAG_CWR = 32768,
 TCP_FLAG_ECE = 16384,
@@ -2392,103 +342,13 @@ TCP_FLAG_FIN = 256,
 TCP_RESERVED_BITS = 15,
 TCP_DATA_OFFSET = 240,
 }
-#[repr(u32)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum tcp_ca_state {
-TCP_CA_Open = 0,
-TCP_CA_Disorder = 1,
-TCP_CA_CWR = 2,
-TCP_CA_Recovery = 3,
-TCP_CA_Loss = 4,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct tcp_info {
-pub tcpi_state: __u8,
-pub tcpi_ca_state: __u8,
-pub tcpi_retransmits: __u8,
-pub tcpi_probes: __u8,
-pub tcpi_backoff: __u8,
-pub tcpi_options: __u8,
-pub _bitfield_align_1: [u8; 0],
-pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
-pub tcpi_rto: __u32,
-pub tcpi_ato: __u32,
-pub tcpi_snd_mss: __u32,
-pub tcpi_rcv_mss: __u32,
-pub tcpi_unacked: __u32,
-pub tcpi_sacked: __u32,
-pub tcpi_lost: __u32,
-pub tcpi_retrans: __u32,
-pub tcpi_fackets: __u32,
-pub tcpi_last_data_sent: __u32,
-pub tcpi_last_ack_sent: __u32,
-pub tcpi_last_data_recv: __u32,
-pub tcpi_last_ack_recv: __u32,
-pub tcpi_pmtu: __u32,
-pub tcpi_rcv_ssthresh: __u32,
-pub tcpi_rtt: __u32,
-pub tcpi_rttvar: __u32,
-pub tcpi_snd_ssthresh: __u32,
-pub tcpi_snd_cwnd: __u32,
-pub tcpi_advmss: __u32,
-pub tcpi_reordering: __u32,
-pub tcpi_rcv_rtt: __u32,
-pub tcpi_rcv_space: __u32,
-pub tcpi_total_retrans: __u32,
-}
-impl tcp_info {
-#[inline]
-pub fn tcpi_snd_wscale(&self) -> __u8 {
-unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 4u8) as u8) }
-}
-#[inline]
-pub fn set_tcpi_snd_wscale(&mut self, val: __u8) {
-unsafe {
-let val: u8 = ::core::mem::transmute(val);
-self._bitfield_1.set(0usize, 4u8, val as u64)
-}
-}
-#[inline]
-pub fn tcpi_rcv_wscale(&self) -> __u8 {
-unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 4u8) as u8) }
-}
-#[inline]
-pub fn set_tcpi_rcv_wscale(&mut self, val: __u8) {
-unsafe {
-let val: u8 = ::core::mem::transmute(val);
-self._bitfield_1.set(4usize, 4u8, val as u64)
-}
-}
-#[inline]
-pub fn new_bitfield_1(tcpi_snd_wscale: __u8, tcpi_rcv_wscale: __u8) -> __BindgenBitfieldUnit<[u8; 1usize]> {
-let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
-__bindgen_bitfield_unit.set(0usize, 4u8, {
-let tcpi_snd_wscale: u8 = unsafe { ::core::mem::transmute(tcpi_snd_wscale) };
-tcpi_snd_wscale as u64
-});
-__bindgen_bitfield_unit.set(4usize, 4u8, {
-let tcpi_rcv_wscale: u8 = unsafe { ::core::mem::transmute(tcpi_rcv_wscale) };
-tcpi_rcv_wscale as u64
-});
-__bindgen_bitfield_unit
-}
-}
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct tcp_md5sig {
-pub tcpm_addr: __kernel_sockaddr_storage,
-pub __tcpm_pad1: __u16,
-pub tcpm_keylen: __u16,
-pub __tcpm_pad2: __u32,
-pub tcpm_key: [__u8; 80usize],
-}
-pub type cc_t = crate::ctypes::c_uchar;
-pub type speed_t = crate::ctypes::c_uint;
-pub type tcflag_t = crate::ctypes::c_uint;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct termios {
 pub c_iflag: tcflag_t,
 pub c_oflag: tcflag_t,
@@ -2498,7 +358,12 @@ pub c_line: cc_t,
 pub c_cc: [cc_t; 19usize],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct termios2 {
 pub c_iflag: tcflag_t,
 pub c_oflag: tcflag_t,
@@ -2510,7 +375,12 @@ pub c_ispeed: speed_t,
 pub c_ospeed: speed_t,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ktermios {
 pub c_iflag: tcflag_t,
 pub c_oflag: tcflag_t,
@@ -2522,15 +392,12 @@ pub c_ispeed: speed_t,
 pub c_ospeed: speed_t,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct winsize {
-pub ws_row: crate::ctypes::c_ushort,
-pub ws_col: crate::ctypes::c_ushort,
-pub ws_xpixel: crate::ctypes::c_ushort,
-pub ws_ypixel: crate::ctypes::c_ushort,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct termio {
 pub c_iflag: crate::ctypes::c_ushort,
 pub c_oflag: crate::ctypes::c_ushort,
@@ -2540,74 +407,7 @@ pub c_line: crate::ctypes::c_uchar,
 pub c_cc: [crate::ctypes::c_uchar; 8usize],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct termiox {
-pub x_hflag: __u16,
-pub x_cflag: __u16,
-pub x_rflag: [__u16; 5usize],
-pub x_sflag: __u16,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct iovec {
-pub iov_base: *mut crate::ctypes::c_void,
-pub iov_len: __kernel_size_t,
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct sockaddr_un {
-pub sun_family: u16,
-pub sun_path: [crate::ctypes::c_char; 108usize],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct oldold_utsname {
-pub sysname: [crate::ctypes::c_char; 9usize],
-pub nodename: [crate::ctypes::c_char; 9usize],
-pub release: [crate::ctypes::c_char; 9usize],
-pub version: [crate::ctypes::c_char; 9usize],
-pub machine: [crate::ctypes::c_char; 9usize],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct old_utsname {
-pub sysname: [crate::ctypes::c_char; 65usize],
-pub nodename: [crate::ctypes::c_char; 65usize],
-pub release: [crate::ctypes::c_char; 65usize],
-pub version: [crate::ctypes::c_char; 65usize],
-pub machine: [crate::ctypes::c_char; 65usize],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct new_utsname {
-pub sysname: [crate::ctypes::c_char; 65usize],
-pub nodename: [crate::ctypes::c_char; 65usize],
-pub release: [crate::ctypes::c_char; 65usize],
-pub version: [crate::ctypes::c_char; 65usize],
-pub machine: [crate::ctypes::c_char; 65usize],
-pub domainname: [crate::ctypes::c_char; 65usize],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct sockaddr {
-pub __storage: __kernel_sockaddr_storage,
-}
-pub type __kernel_sa_family_t = u16;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct linger {
-pub l_onoff: crate::ctypes::c_int,
-pub l_linger: crate::ctypes::c_int,
-}
-pub type __kernel_time64_t = crate::ctypes::c_longlong;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct __kernel_timespec {
-pub tv_sec: __kernel_time64_t,
-pub tv_nsec: crate::ctypes::c_longlong,
-}
-#[repr(C)]
-#[derive(Debug)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub struct linux_dirent64 {
 pub d_ino: crate::ctypes::c_ulong,
 pub d_off: crate::ctypes::c_long,
@@ -2615,9 +415,13 @@ pub d_reclen: __u16,
 pub d_type: __u8,
 pub d_name: __IncompleteArrayField<crate::ctypes::c_char>,
 }
-pub type socklen_t = crate::ctypes::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct stat {
 pub st_dev: crate::ctypes::c_ulong,
 pub st_ino: crate::ctypes::c_ulong,
@@ -2639,7 +443,12 @@ pub st_ctime_nsec: crate::ctypes::c_ulong,
 pub __unused: [crate::ctypes::c_long; 3usize],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct __old_kernel_stat {
 pub st_dev: crate::ctypes::c_ushort,
 pub st_ino: crate::ctypes::c_ushort,
@@ -2654,7 +463,12 @@ pub st_mtime: crate::ctypes::c_uint,
 pub st_ctime: crate::ctypes::c_uint,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct statfs {
 pub f_type: crate::ctypes::c_long,
 pub f_bsize: crate::ctypes::c_long,
@@ -2669,7 +483,12 @@ pub f_frsize: crate::ctypes::c_long,
 pub f_spare: [crate::ctypes::c_long; 5usize],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct statfs64 {
 pub f_type: crate::ctypes::c_long,
 pub f_bsize: crate::ctypes::c_long,
@@ -2684,7 +503,12 @@ pub f_frsize: crate::ctypes::c_long,
 pub f_spare: [crate::ctypes::c_long; 5usize],
 }
 #[repr(C, packed(4))]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct compat_statfs64 {
 pub f_type: __u32,
 pub f_bsize: __u32,
@@ -2699,16 +523,6 @@ pub f_frsize: __u32,
 pub f_spare: [__u32; 5usize],
 }
 pub type __fsword_t = crate::ctypes::c_long;
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct user_desc {
-pub entry_number: crate::ctypes::c_uint,
-pub base_addr: crate::ctypes::c_uint,
-pub limit: crate::ctypes::c_uint,
-pub _bitfield_align_1: [u8; 0],
-pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
-pub __bindgen_padding_0: [u8; 3usize],
-}
 impl user_desc {
 #[inline]
 pub fn seg_32bit(&self) -> crate::ctypes::c_uint {
@@ -2821,3 +635,497 @@ lm as u64
 __bindgen_bitfield_unit
 }
 }
+/// The names of the syscalls, indexed by syscall number minus `SYSCALL_NAMES_BASE`.
+pub static SYSCALL_NAMES: &[::core::option::Option<&str>] = &[
+Some("read"),
+Some("write"),
+Some("open"),
+Some("close"),
+Some("stat"),
+Some("fstat"),
+Some("lstat"),
+Some("poll"),
+Some("lseek"),
+Some("mmap"),
+Some("mprotect"),
+Some("munmap"),
+Some("brk"),
+Some("rt_sigaction"),
+Some("rt_sigprocmask"),
+Some("rt_sigreturn"),
+Some("ioctl"),
+Some("pread64"),
+Some("pwrite64"),
+Some("readv"),
+Some("writev"),
+Some("access"),
+Some("pipe"),
+Some("select"),
+Some("sched_yield"),
+Some("mremap"),
+Some("msync"),
+Some("mincore"),
+Some("madvise"),
+Some("shmget"),
+Some("shmat"),
+Some("shmctl"),
+Some("dup"),
+Some("dup2"),
+Some("pause"),
+Some("nanosleep"),
+Some("getitimer"),
+Some("alarm"),
+Some("setitimer"),
+Some("getpid"),
+Some("sendfile"),
+Some("socket"),
+Some("connect"),
+Some("accept"),
+Some("sendto"),
+Some("recvfrom"),
+Some("sendmsg"),
+Some("recvmsg"),
+Some("shutdown"),
+Some("bind"),
+Some("listen"),
+Some("getsockname"),
+Some("getpeername"),
+Some("socketpair"),
+Some("setsockopt"),
+Some("getsockopt"),
+Some("clone"),
+Some("fork"),
+Some("vfork"),
+Some("execve"),
+Some("exit"),
+Some("wait4"),
+Some("kill"),
+Some("uname"),
+Some("semget"),
+Some("semop"),
+Some("semctl"),
+Some("shmdt"),
+Some("msgget"),
+Some("msgsnd"),
+Some("msgrcv"),
+Some("msgctl"),
+Some("fcntl"),
+Some("flock"),
+Some("fsync"),
+Some("fdatasync"),
+Some("truncate"),
+Some("ftruncate"),
+Some("getdents"),
+Some("getcwd"),
+Some("chdir"),
+Some("fchdir"),
+Some("rename"),
+Some("mkdir"),
+Some("rmdir"),
+Some("creat"),
+Some("link"),
+Some("unlink"),
+Some("symlink"),
+Some("readlink"),
+Some("chmod"),
+Some("fchmod"),
+Some("chown"),
+Some("fchown"),
+Some("lchown"),
+Some("umask"),
+Some("gettimeofday"),
+Some("getrlimit"),
+Some("getrusage"),
+Some("sysinfo"),
+Some("times"),
+Some("ptrace"),
+Some("getuid"),
+Some("syslog"),
+Some("getgid"),
+Some("setuid"),
+Some("setgid"),
+Some("geteuid"),
+Some("getegid"),
+Some("setpgid"),
+Some("getppid"),
+Some("getpgrp"),
+Some("setsid"),
+Some("setreuid"),
+Some("setregid"),
+Some("getgroups"),
+Some("setgroups"),
+Some("setresuid"),
+Some("getresuid"),
+Some("setresgid"),
+Some("getresgid"),
+Some("getpgid"),
+Some("setfsuid"),
+Some("setfsgid"),
+Some("getsid"),
+Some("capget"),
+Some("capset"),
+Some("rt_sigpending"),
+Some("rt_sigtimedwait"),
+Some("rt_sigqueueinfo"),
+Some("rt_sigsuspend"),
+Some("sigaltstack"),
+Some("utime"),
+Some("mknod"),
+Some("uselib"),
+Some("personality"),
+Some("ustat"),
+Some("statfs"),
+Some("fstatfs"),
+Some("sysfs"),
+Some("getpriority"),
+Some("setpriority"),
+Some("sched_setparam"),
+Some("sched_getparam"),
+Some("sched_setscheduler"),
+Some("sched_getscheduler"),
+Some("sched_get_priority_max"),
+Some("sched_get_priority_min"),
+Some("sched_rr_get_interval"),
+Some("mlock"),
+Some("munlock"),
+Some("mlockall"),
+Some("munlockall"),
+Some("vhangup"),
+Some("modify_ldt"),
+Some("pivot_root"),
+Some("_sysctl"),
+Some("prctl"),
+Some("arch_prctl"),
+Some("adjtimex"),
+Some("setrlimit"),
+Some("chroot"),
+Some("sync"),
+Some("acct"),
+Some("settimeofday"),
+Some("mount"),
+Some("umount2"),
+Some("swapon"),
+Some("swapoff"),
+Some("reboot"),
+Some("sethostname"),
+Some("setdomainname"),
+Some("iopl"),
+Some("ioperm"),
+Some("create_module"),
+Some("init_module"),
+Some("delete_module"),
+Some("get_kernel_syms"),
+Some("query_module"),
+Some("quotactl"),
+Some("nfsservctl"),
+Some("getpmsg"),
+Some("putpmsg"),
+Some("afs_syscall"),
+Some("tuxcall"),
+Some("security"),
+Some("gettid"),
+Some("readahead"),
+Some("setxattr"),
+Some("lsetxattr"),
+Some("fsetxattr"),
+Some("getxattr"),
+Some("lgetxattr"),
+Some("fgetxattr"),
+Some("listxattr"),
+Some("llistxattr"),
+Some("flistxattr"),
+Some("removexattr"),
+Some("lremovexattr"),
+Some("fremovexattr"),
+Some("tkill"),
+Some("time"),
+Some("futex"),
+Some("sched_setaffinity"),
+Some("sched_getaffinity"),
+Some("set_thread_area"),
+Some("io_setup"),
+Some("io_destroy"),
+Some("io_getevents"),
+Some("io_submit"),
+Some("io_cancel"),
+Some("get_thread_area"),
+Some("lookup_dcookie"),
+Some("epoll_create"),
+Some("epoll_ctl_old"),
+Some("epoll_wait_old"),
+Some("remap_file_pages"),
+Some("getdents64"),
+Some("set_tid_address"),
+Some("restart_syscall"),
+Some("semtimedop"),
+Some("fadvise64"),
+Some("timer_create"),
+Some("timer_settime"),
+Some("timer_gettime"),
+Some("timer_getoverrun"),
+Some("timer_delete"),
+Some("clock_settime"),
+Some("clock_gettime"),
+Some("clock_getres"),
+Some("clock_nanosleep"),
+Some("exit_group"),
+Some("epoll_wait"),
+Some("epoll_ctl"),
+Some("tgkill"),
+Some("utimes"),
+Some("vserver"),
+Some("mbind"),
+Some("set_mempolicy"),
+Some("get_mempolicy"),
+Some("mq_open"),
+Some("mq_unlink"),
+Some("mq_timedsend"),
+Some("mq_timedreceive"),
+Some("mq_notify"),
+Some("mq_getsetattr"),
+Some("kexec_load"),
+Some("waitid"),
+Some("add_key"),
+Some("request_key"),
+Some("keyctl"),
+Some("ioprio_set"),
+Some("ioprio_get"),
+Some("inotify_init"),
+Some("inotify_add_watch"),
+Some("inotify_rm_watch"),
+Some("migrate_pages"),
+Some("openat"),
+Some("mkdirat"),
+Some("mknodat"),
+Some("fchownat"),
+Some("futimesat"),
+Some("newfstatat"),
+Some("unlinkat"),
+Some("renameat"),
+Some("linkat"),
+Some("symlinkat"),
+Some("readlinkat"),
+Some("fchmodat"),
+Some("faccessat"),
+Some("pselect6"),
+Some("ppoll"),
+Some("unshare"),
+Some("set_robust_list"),
+Some("get_robust_list"),
+Some("splice"),
+Some("tee"),
+Some("sync_file_range"),
+Some("vmsplice"),
+Some("move_pages"),
+Some("utimensat"),
+Some("epoll_pwait"),
+Some("signalfd"),
+Some("timerfd_create"),
+Some("eventfd"),
+Some("fallocate"),
+Some("timerfd_settime"),
+Some("timerfd_gettime"),
+Some("accept4"),
+Some("signalfd4"),
+Some("eventfd2"),
+Some("epoll_create1"),
+Some("dup3"),
+Some("pipe2"),
+Some("inotify_init1"),
+Some("preadv"),
+Some("pwritev"),
+Some("rt_tgsigqueueinfo"),
+Some("perf_event_open"),
+];
+#[cfg(feature = "typed-flags")]
+typed_flags! {
+OFlags: u32 {
+O_ACCMODE,
+O_RDONLY,
+O_WRONLY,
+O_RDWR,
+O_CREAT,
+O_EXCL,
+O_NOCTTY,
+O_TRUNC,
+O_APPEND,
+O_NONBLOCK,
+O_SYNC,
+O_DIRECT,
+O_LARGEFILE,
+O_DIRECTORY,
+O_NOFOLLOW,
+O_NOATIME,
+O_CLOEXEC,
+O_NDELAY,
+}
+MapFlags: u32 {
+MAP_32BIT,
+MAP_SHARED,
+MAP_PRIVATE,
+MAP_TYPE,
+MAP_FIXED,
+MAP_ANONYMOUS,
+MAP_FILE,
+MAP_GROWSDOWN,
+MAP_DENYWRITE,
+MAP_EXECUTABLE,
+MAP_LOCKED,
+MAP_NORESERVE,
+MAP_POPULATE,
+MAP_NONBLOCK,
+MAP_STACK,
+MAP_HUGETLB,
+}
+EpollFlags: u32 {
+EPOLLONESHOT,
+EPOLLET,
+EPOLLIN,
+EPOLLPRI,
+EPOLLOUT,
+EPOLLERR,
+EPOLLHUP,
+EPOLLNVAL,
+EPOLLRDNORM,
+EPOLLRDBAND,
+EPOLLWRNORM,
+EPOLLWRBAND,
+EPOLLMSG,
+EPOLLRDHUP,
+EPOLLEXCLUSIVE,
+EPOLLWAKEUP,
+}
+CloneFlags: u64 {
+CLONE_VM,
+CLONE_FS,
+CLONE_FILES,
+CLONE_SIGHAND,
+CLONE_PTRACE,
+CLONE_VFORK,
+CLONE_PARENT,
+CLONE_THREAD,
+CLONE_NEWNS,
+CLONE_SYSVSEM,
+CLONE_SETTLS,
+CLONE_PARENT_SETTID,
+CLONE_CHILD_CLEARTID,
+CLONE_DETACHED,
+CLONE_UNTRACED,
+CLONE_CHILD_SETTID,
+CLONE_STOPPED,
+CLONE_NEWUTS,
+CLONE_NEWIPC,
+CLONE_NEWUSER,
+CLONE_NEWPID,
+CLONE_NEWNET,
+CLONE_IO,
+}
+}
+impl epoll_event {
+#[inline]
+pub fn events(&self) -> __u32 {
+unsafe { ::core::ptr::addr_of!(self.events).read_unaligned() }
+}
+#[inline]
+pub fn set_events(&mut self, val: __u32) {
+unsafe { ::core::ptr::addr_of_mut!(self.events).write_unaligned(val) }
+}
+#[inline]
+pub fn data(&self) -> __u64 {
+unsafe { ::core::ptr::addr_of!(self.data).read_unaligned() }
+}
+#[inline]
+pub fn set_data(&mut self, val: __u64) {
+unsafe { ::core::ptr::addr_of_mut!(self.data).write_unaligned(val) }
+}
+}
+impl compat_statfs64 {
+#[inline]
+pub fn f_type(&self) -> __u32 {
+unsafe { ::core::ptr::addr_of!(self.f_type).read_unaligned() }
+}
+#[inline]
+pub fn set_f_type(&mut self, val: __u32) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_type).write_unaligned(val) }
+}
+#[inline]
+pub fn f_bsize(&self) -> __u32 {
+unsafe { ::core::ptr::addr_of!(self.f_bsize).read_unaligned() }
+}
+#[inline]
+pub fn set_f_bsize(&mut self, val: __u32) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_bsize).write_unaligned(val) }
+}
+#[inline]
+pub fn f_blocks(&self) -> __u64 {
+unsafe { ::core::ptr::addr_of!(self.f_blocks).read_unaligned() }
+}
+#[inline]
+pub fn set_f_blocks(&mut self, val: __u64) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_blocks).write_unaligned(val) }
+}
+#[inline]
+pub fn f_bfree(&self) -> __u64 {
+unsafe { ::core::ptr::addr_of!(self.f_bfree).read_unaligned() }
+}
+#[inline]
+pub fn set_f_bfree(&mut self, val: __u64) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_bfree).write_unaligned(val) }
+}
+#[inline]
+pub fn f_bavail(&self) -> __u64 {
+unsafe { ::core::ptr::addr_of!(self.f_bavail).read_unaligned() }
+}
+#[inline]
+pub fn set_f_bavail(&mut self, val: __u64) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_bavail).write_unaligned(val) }
+}
+#[inline]
+pub fn f_files(&self) -> __u64 {
+unsafe { ::core::ptr::addr_of!(self.f_files).read_unaligned() }
+}
+#[inline]
+pub fn set_f_files(&mut self, val: __u64) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_files).write_unaligned(val) }
+}
+#[inline]
+pub fn f_ffree(&self) -> __u64 {
+unsafe { ::core::ptr::addr_of!(self.f_ffree).read_unaligned() }
+}
+#[inline]
+pub fn set_f_ffree(&mut self, val: __u64) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_ffree).write_unaligned(val) }
+}
+#[inline]
+pub fn f_fsid(&self) -> __kernel_fsid_t {
+unsafe { ::core::ptr::addr_of!(self.f_fsid).read_unaligned() }
+}
+#[inline]
+pub fn set_f_fsid(&mut self, val: __kernel_fsid_t) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_fsid).write_unaligned(val) }
+}
+#[inline]
+pub fn f_namelen(&self) -> __u32 {
+unsafe { ::core::ptr::addr_of!(self.f_namelen).read_unaligned() }
+}
+#[inline]
+pub fn set_f_namelen(&mut self, val: __u32) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_namelen).write_unaligned(val) }
+}
+#[inline]
+pub fn f_frsize(&self) -> __u32 {
+unsafe { ::core::ptr::addr_of!(self.f_frsize).read_unaligned() }
+}
+#[inline]
+pub fn set_f_frsize(&mut self, val: __u32) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_frsize).write_unaligned(val) }
+}
+#[inline]
+pub fn f_spare(&self) -> [__u32; 5usize] {
+unsafe { ::core::ptr::addr_of!(self.f_spare).read_unaligned() }
+}
+#[inline]
+pub fn set_f_spare(&mut self, val: [__u32; 5usize]) {
+unsafe { ::core::ptr::addr_of_mut!(self.f_spare).write_unaligned(val) }
+}
+}
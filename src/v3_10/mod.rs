@@ -1,3 +1,5 @@
+/// The `LINUX_VERSION_CODE` of the headers these bindings were generated from.
+pub const LINUX_VERSION_CODE: u32 = 0x030a00;
 #[cfg(target_arch = "powerpc64")]
 mod powerpc64;
 #[cfg(target_arch = "powerpc64")]
@@ -1,3 +1,5 @@
+/// The `LINUX_VERSION_CODE` of the headers these bindings were generated from.
+pub const LINUX_VERSION_CODE: u32 = 0x030200;
 #[cfg(target_arch = "arm")]
 mod arm;
 #[cfg(target_arch = "arm")]
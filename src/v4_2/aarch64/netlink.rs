@@ -1,3 +1,4 @@
+include!("netlink_consts.rs");
 /* automatically generated by rust-bindgen 0.59.1 */
 
 #[repr(C)]
@@ -30,156 +31,6 @@ fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
 fmt.write_str("__IncompleteArrayField")
 }
 }
-pub const LINUX_VERSION_CODE: u32 = 262656;
-pub const __BITS_PER_LONG: u32 = 64;
-pub const __FD_SETSIZE: u32 = 1024;
-pub const SI_LOAD_SHIFT: u32 = 16;
-pub const _K_SS_MAXSIZE: u32 = 128;
-pub const NETLINK_ROUTE: u32 = 0;
-pub const NETLINK_UNUSED: u32 = 1;
-pub const NETLINK_USERSOCK: u32 = 2;
-pub const NETLINK_FIREWALL: u32 = 3;
-pub const NETLINK_SOCK_DIAG: u32 = 4;
-pub const NETLINK_NFLOG: u32 = 5;
-pub const NETLINK_XFRM: u32 = 6;
-pub const NETLINK_SELINUX: u32 = 7;
-pub const NETLINK_ISCSI: u32 = 8;
-pub const NETLINK_AUDIT: u32 = 9;
-pub const NETLINK_FIB_LOOKUP: u32 = 10;
-pub const NETLINK_CONNECTOR: u32 = 11;
-pub const NETLINK_NETFILTER: u32 = 12;
-pub const NETLINK_IP6_FW: u32 = 13;
-pub const NETLINK_DNRTMSG: u32 = 14;
-pub const NETLINK_KOBJECT_UEVENT: u32 = 15;
-pub const NETLINK_GENERIC: u32 = 16;
-pub const NETLINK_SCSITRANSPORT: u32 = 18;
-pub const NETLINK_ECRYPTFS: u32 = 19;
-pub const NETLINK_RDMA: u32 = 20;
-pub const NETLINK_CRYPTO: u32 = 21;
-pub const NETLINK_INET_DIAG: u32 = 4;
-pub const MAX_LINKS: u32 = 32;
-pub const NLM_F_REQUEST: u32 = 1;
-pub const NLM_F_MULTI: u32 = 2;
-pub const NLM_F_ACK: u32 = 4;
-pub const NLM_F_ECHO: u32 = 8;
-pub const NLM_F_DUMP_INTR: u32 = 16;
-pub const NLM_F_ROOT: u32 = 256;
-pub const NLM_F_MATCH: u32 = 512;
-pub const NLM_F_ATOMIC: u32 = 1024;
-pub const NLM_F_DUMP: u32 = 768;
-pub const NLM_F_REPLACE: u32 = 256;
-pub const NLM_F_EXCL: u32 = 512;
-pub const NLM_F_CREATE: u32 = 1024;
-pub const NLM_F_APPEND: u32 = 2048;
-pub const NLMSG_ALIGNTO: u32 = 4;
-pub const NLMSG_NOOP: u32 = 1;
-pub const NLMSG_ERROR: u32 = 2;
-pub const NLMSG_DONE: u32 = 3;
-pub const NLMSG_OVERRUN: u32 = 4;
-pub const NLMSG_MIN_TYPE: u32 = 16;
-pub const NETLINK_ADD_MEMBERSHIP: u32 = 1;
-pub const NETLINK_DROP_MEMBERSHIP: u32 = 2;
-pub const NETLINK_PKTINFO: u32 = 3;
-pub const NETLINK_BROADCAST_ERROR: u32 = 4;
-pub const NETLINK_NO_ENOBUFS: u32 = 5;
-pub const NETLINK_RX_RING: u32 = 6;
-pub const NETLINK_TX_RING: u32 = 7;
-pub const NETLINK_LISTEN_ALL_NSID: u32 = 8;
-pub const NETLINK_LIST_MEMBERSHIPS: u32 = 9;
-pub const NL_MMAP_MSG_ALIGNMENT: u32 = 4;
-pub const NET_MAJOR: u32 = 36;
-pub const NLA_F_NESTED: u32 = 32768;
-pub const NLA_F_NET_BYTEORDER: u32 = 16384;
-pub const NLA_TYPE_MASK: i32 = -49153;
-pub const NLA_ALIGNTO: u32 = 4;
-pub const MACVLAN_FLAG_NOPROMISC: u32 = 1;
-pub const PORT_PROFILE_MAX: u32 = 40;
-pub const PORT_UUID_MAX: u32 = 16;
-pub const PORT_SELF_VF: i32 = -1;
-pub const IFA_F_SECONDARY: u32 = 1;
-pub const IFA_F_TEMPORARY: u32 = 1;
-pub const IFA_F_NODAD: u32 = 2;
-pub const IFA_F_OPTIMISTIC: u32 = 4;
-pub const IFA_F_DADFAILED: u32 = 8;
-pub const IFA_F_HOMEADDRESS: u32 = 16;
-pub const IFA_F_DEPRECATED: u32 = 32;
-pub const IFA_F_TENTATIVE: u32 = 64;
-pub const IFA_F_PERMANENT: u32 = 128;
-pub const IFA_F_MANAGETEMPADDR: u32 = 256;
-pub const IFA_F_NOPREFIXROUTE: u32 = 512;
-pub const IFA_F_MCAUTOJOIN: u32 = 1024;
-pub const IFA_F_STABLE_PRIVACY: u32 = 2048;
-pub const NTF_USE: u32 = 1;
-pub const NTF_SELF: u32 = 2;
-pub const NTF_MASTER: u32 = 4;
-pub const NTF_PROXY: u32 = 8;
-pub const NTF_EXT_LEARNED: u32 = 16;
-pub const NTF_ROUTER: u32 = 128;
-pub const NUD_INCOMPLETE: u32 = 1;
-pub const NUD_REACHABLE: u32 = 2;
-pub const NUD_STALE: u32 = 4;
-pub const NUD_DELAY: u32 = 8;
-pub const NUD_PROBE: u32 = 16;
-pub const NUD_FAILED: u32 = 32;
-pub const NUD_NOARP: u32 = 64;
-pub const NUD_PERMANENT: u32 = 128;
-pub const NUD_NONE: u32 = 0;
-pub const RTNL_FAMILY_IPMR: u32 = 128;
-pub const RTNL_FAMILY_IP6MR: u32 = 129;
-pub const RTNL_FAMILY_MAX: u32 = 129;
-pub const RTA_ALIGNTO: u32 = 4;
-pub const RTPROT_UNSPEC: u32 = 0;
-pub const RTPROT_REDIRECT: u32 = 1;
-pub const RTPROT_KERNEL: u32 = 2;
-pub const RTPROT_BOOT: u32 = 3;
-pub const RTPROT_STATIC: u32 = 4;
-pub const RTPROT_GATED: u32 = 8;
-pub const RTPROT_RA: u32 = 9;
-pub const RTPROT_MRT: u32 = 10;
-pub const RTPROT_ZEBRA: u32 = 11;
-pub const RTPROT_BIRD: u32 = 12;
-pub const RTPROT_DNROUTED: u32 = 13;
-pub const RTPROT_XORP: u32 = 14;
-pub const RTPROT_NTK: u32 = 15;
-pub const RTPROT_DHCP: u32 = 16;
-pub const RTPROT_MROUTED: u32 = 17;
-pub const RTPROT_BABEL: u32 = 42;
-pub const RTM_F_NOTIFY: u32 = 256;
-pub const RTM_F_CLONED: u32 = 512;
-pub const RTM_F_EQUALIZE: u32 = 1024;
-pub const RTM_F_PREFIX: u32 = 2048;
-pub const RTNH_F_DEAD: u32 = 1;
-pub const RTNH_F_PERVASIVE: u32 = 2;
-pub const RTNH_F_ONLINK: u32 = 4;
-pub const RTNH_F_OFFLOAD: u32 = 8;
-pub const RTNH_F_LINKDOWN: u32 = 16;
-pub const RTNH_COMPARE_MASK: u32 = 17;
-pub const RTNH_ALIGNTO: u32 = 4;
-pub const RTNETLINK_HAVE_PEERINFO: u32 = 1;
-pub const RTAX_FEATURE_ECN: u32 = 1;
-pub const RTAX_FEATURE_SACK: u32 = 2;
-pub const RTAX_FEATURE_TIMESTAMP: u32 = 4;
-pub const RTAX_FEATURE_ALLFRAG: u32 = 8;
-pub const RTMGRP_LINK: u32 = 1;
-pub const RTMGRP_NOTIFY: u32 = 2;
-pub const RTMGRP_NEIGH: u32 = 4;
-pub const RTMGRP_TC: u32 = 8;
-pub const RTMGRP_IPV4_IFADDR: u32 = 16;
-pub const RTMGRP_IPV4_MROUTE: u32 = 32;
-pub const RTMGRP_IPV4_ROUTE: u32 = 64;
-pub const RTMGRP_IPV4_RULE: u32 = 128;
-pub const RTMGRP_IPV6_IFADDR: u32 = 256;
-pub const RTMGRP_IPV6_MROUTE: u32 = 512;
-pub const RTMGRP_IPV6_ROUTE: u32 = 1024;
-pub const RTMGRP_IPV6_IFINFO: u32 = 2048;
-pub const RTMGRP_DECnet_IFADDR: u32 = 4096;
-pub const RTMGRP_DECnet_ROUTE: u32 = 16384;
-pub const RTMGRP_IPV6_PREFIX: u32 = 131072;
-pub const TCA_ACT_TAB: u32 = 1;
-pub const TCAA_MAX: u32 = 1;
-pub const RTEXT_FILTER_VF: u32 = 1;
-pub const RTEXT_FILTER_BRVLAN: u32 = 2;
-pub const RTEXT_FILTER_BRVLAN_COMPRESSED: u32 = 4;
 pub type size_t = crate::ctypes::c_ulong;
 pub type ssize_t = crate::ctypes::c_long;
 pub type __s8 = crate::ctypes::c_schar;
@@ -191,7 +42,12 @@ pub type __u32 = crate::ctypes::c_uint;
 pub type __s64 = crate::ctypes::c_longlong;
 pub type __u64 = crate::ctypes::c_ulonglong;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct __kernel_fd_set {
 pub fds_bits: [crate::ctypes::c_ulong; 16usize],
 }
@@ -217,7 +73,12 @@ pub type __kernel_size_t = __kernel_ulong_t;
 pub type __kernel_ssize_t = __kernel_long_t;
 pub type __kernel_ptrdiff_t = __kernel_long_t;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct __kernel_fsid_t {
 pub val: [crate::ctypes::c_int; 2usize],
 }
@@ -239,7 +100,7 @@ pub type __be64 = __u64;
 pub type __sum16 = __u16;
 pub type __wsum = __u32;
 #[repr(C)]
-#[derive(Debug)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub struct sysinfo {
 pub uptime: __kernel_long_t,
 pub loads: [__kernel_ulong_t; 3usize],
@@ -259,13 +120,22 @@ pub _f: __IncompleteArrayField<crate::ctypes::c_char>,
 pub type __kernel_sa_family_t = crate::ctypes::c_ushort;
 #[repr(C)]
 #[repr(align(8))]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct __kernel_sockaddr_storage {
 pub ss_family: __kernel_sa_family_t,
 pub __data: [crate::ctypes::c_char; 126usize],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct sockaddr_nl {
 pub nl_family: __kernel_sa_family_t,
 pub nl_pad: crate::ctypes::c_ushort,
@@ -273,7 +143,12 @@ pub nl_pid: __u32,
 pub nl_groups: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct nlmsghdr {
 pub nlmsg_len: __u32,
 pub nlmsg_type: __u16,
@@ -282,18 +157,33 @@ pub nlmsg_seq: __u32,
 pub nlmsg_pid: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct nlmsgerr {
 pub error: crate::ctypes::c_int,
 pub msg: nlmsghdr,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct nl_pktinfo {
 pub group: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct nl_mmap_req {
 pub nm_block_size: crate::ctypes::c_uint,
 pub nm_block_nr: crate::ctypes::c_uint,
@@ -301,7 +191,12 @@ pub nm_frame_size: crate::ctypes::c_uint,
 pub nm_frame_nr: crate::ctypes::c_uint,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct nl_mmap_hdr {
 pub nm_status: crate::ctypes::c_uint,
 pub nm_len: crate::ctypes::c_uint,
@@ -312,7 +207,8 @@ pub nm_gid: __u32,
 }
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum nl_mmap_status {
 NL_MMAP_STATUS_UNUSED = 0,
 NL_MMAP_STATUS_RESERVED = 1,
@@ -324,19 +220,30 @@ pub const NETLINK_UNCONNECTED: _bindgen_ty_1 = _bindgen_ty_1::NETLINK_UNCONNECTE
 pub const NETLINK_CONNECTED: _bindgen_ty_1 = _bindgen_ty_1::NETLINK_CONNECTED;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_1 {
 NETLINK_UNCONNECTED = 0,
 NETLINK_CONNECTED = 1,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct nlattr {
 pub nla_len: __u16,
 pub nla_type: __u16,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rtnl_link_stats {
 pub rx_packets: __u32,
 pub tx_packets: __u32,
@@ -363,7 +270,12 @@ pub rx_compressed: __u32,
 pub tx_compressed: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rtnl_link_stats64 {
 pub rx_packets: __u64,
 pub tx_packets: __u64,
@@ -390,7 +302,12 @@ pub rx_compressed: __u64,
 pub tx_compressed: __u64,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rtnl_link_ifmap {
 pub mem_start: __u64,
 pub mem_end: __u64,
@@ -441,7 +358,8 @@ pub const IFLA_PHYS_PORT_NAME: _bindgen_ty_2 = _bindgen_ty_2::IFLA_PHYS_PORT_NAM
 pub const __IFLA_MAX: _bindgen_ty_2 = _bindgen_ty_2::__IFLA_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_2 {
 IFLA_UNSPEC = 0,
 IFLA_ADDRESS = 1,
@@ -489,7 +407,8 @@ pub const IFLA_INET_CONF: _bindgen_ty_3 = _bindgen_ty_3::IFLA_INET_CONF;
 pub const __IFLA_INET_MAX: _bindgen_ty_3 = _bindgen_ty_3::__IFLA_INET_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_3 {
 IFLA_INET_UNSPEC = 0,
 IFLA_INET_CONF = 1,
@@ -507,7 +426,8 @@ pub const IFLA_INET6_ADDR_GEN_MODE: _bindgen_ty_4 = _bindgen_ty_4::IFLA_INET6_AD
 pub const __IFLA_INET6_MAX: _bindgen_ty_4 = _bindgen_ty_4::__IFLA_INET6_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_4 {
 IFLA_INET6_UNSPEC = 0,
 IFLA_INET6_FLAGS = 1,
@@ -522,7 +442,8 @@ __IFLA_INET6_MAX = 9,
 }
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum in6_addr_gen_mode {
 IN6_ADDR_GEN_MODE_EUI64 = 0,
 IN6_ADDR_GEN_MODE_NONE = 1,
@@ -538,7 +459,8 @@ pub const IFLA_BR_PRIORITY: _bindgen_ty_5 = _bindgen_ty_5::IFLA_BR_PRIORITY;
 pub const __IFLA_BR_MAX: _bindgen_ty_5 = _bindgen_ty_5::__IFLA_BR_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_5 {
 IFLA_BR_UNSPEC = 0,
 IFLA_BR_FORWARD_DELAY = 1,
@@ -553,7 +475,8 @@ pub const BRIDGE_MODE_UNSPEC: _bindgen_ty_6 = _bindgen_ty_6::BRIDGE_MODE_UNSPEC;
 pub const BRIDGE_MODE_HAIRPIN: _bindgen_ty_6 = _bindgen_ty_6::BRIDGE_MODE_HAIRPIN;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_6 {
 BRIDGE_MODE_UNSPEC = 0,
 BRIDGE_MODE_HAIRPIN = 1,
@@ -574,7 +497,8 @@ pub const IFLA_BRPORT_PROXYARP_WIFI: _bindgen_ty_7 = _bindgen_ty_7::IFLA_BRPORT_
 pub const __IFLA_BRPORT_MAX: _bindgen_ty_7 = _bindgen_ty_7::__IFLA_BRPORT_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_7 {
 IFLA_BRPORT_UNSPEC = 0,
 IFLA_BRPORT_STATE = 1,
@@ -592,7 +516,12 @@ IFLA_BRPORT_PROXYARP_WIFI = 12,
 __IFLA_BRPORT_MAX = 13,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_cacheinfo {
 pub max_reasm_len: __u32,
 pub tstamp: __u32,
@@ -608,7 +537,8 @@ pub const IFLA_INFO_SLAVE_DATA: _bindgen_ty_8 = _bindgen_ty_8::IFLA_INFO_SLAVE_D
 pub const __IFLA_INFO_MAX: _bindgen_ty_8 = _bindgen_ty_8::__IFLA_INFO_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_8 {
 IFLA_INFO_UNSPEC = 0,
 IFLA_INFO_KIND = 1,
@@ -627,7 +557,8 @@ pub const IFLA_VLAN_PROTOCOL: _bindgen_ty_9 = _bindgen_ty_9::IFLA_VLAN_PROTOCOL;
 pub const __IFLA_VLAN_MAX: _bindgen_ty_9 = _bindgen_ty_9::__IFLA_VLAN_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_9 {
 IFLA_VLAN_UNSPEC = 0,
 IFLA_VLAN_ID = 1,
@@ -638,7 +569,12 @@ IFLA_VLAN_PROTOCOL = 5,
 __IFLA_VLAN_MAX = 6,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_vlan_flags {
 pub flags: __u32,
 pub mask: __u32,
@@ -648,14 +584,20 @@ pub const IFLA_VLAN_QOS_MAPPING: _bindgen_ty_10 = _bindgen_ty_10::IFLA_VLAN_QOS_
 pub const __IFLA_VLAN_QOS_MAX: _bindgen_ty_10 = _bindgen_ty_10::__IFLA_VLAN_QOS_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_10 {
 IFLA_VLAN_QOS_UNSPEC = 0,
 IFLA_VLAN_QOS_MAPPING = 1,
 __IFLA_VLAN_QOS_MAX = 2,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_vlan_qos_mapping {
 pub from: __u32,
 pub to: __u32,
@@ -670,7 +612,8 @@ pub const IFLA_MACVLAN_MACADDR_COUNT: _bindgen_ty_11 = _bindgen_ty_11::IFLA_MACV
 pub const __IFLA_MACVLAN_MAX: _bindgen_ty_11 = _bindgen_ty_11::__IFLA_MACVLAN_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_11 {
 IFLA_MACVLAN_UNSPEC = 0,
 IFLA_MACVLAN_MODE = 1,
@@ -683,7 +626,8 @@ __IFLA_MACVLAN_MAX = 7,
 }
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum macvlan_mode {
 MACVLAN_MODE_PRIVATE = 1,
 MACVLAN_MODE_VEPA = 2,
@@ -693,7 +637,8 @@ MACVLAN_MODE_SOURCE = 16,
 }
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum macvlan_macaddr_mode {
 MACVLAN_MACADDR_ADD = 0,
 MACVLAN_MACADDR_DEL = 1,
@@ -705,7 +650,8 @@ pub const IFLA_IPVLAN_MODE: _bindgen_ty_12 = _bindgen_ty_12::IFLA_IPVLAN_MODE;
 pub const __IFLA_IPVLAN_MAX: _bindgen_ty_12 = _bindgen_ty_12::__IFLA_IPVLAN_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_12 {
 IFLA_IPVLAN_UNSPEC = 0,
 IFLA_IPVLAN_MODE = 1,
@@ -713,7 +659,8 @@ __IFLA_IPVLAN_MAX = 2,
 }
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum ipvlan_mode {
 IPVLAN_MODE_L2 = 0,
 IPVLAN_MODE_L3 = 1,
@@ -747,7 +694,8 @@ pub const IFLA_VXLAN_REMCSUM_NOPARTIAL: _bindgen_ty_13 = _bindgen_ty_13::IFLA_VX
 pub const __IFLA_VXLAN_MAX: _bindgen_ty_13 = _bindgen_ty_13::__IFLA_VXLAN_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_13 {
 IFLA_VXLAN_UNSPEC = 0,
 IFLA_VXLAN_ID = 1,
@@ -777,7 +725,12 @@ IFLA_VXLAN_REMCSUM_NOPARTIAL = 24,
 __IFLA_VXLAN_MAX = 25,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_vxlan_port_range {
 pub low: __be16,
 pub high: __be16,
@@ -790,7 +743,8 @@ pub const IFLA_GENEVE_TOS: _bindgen_ty_14 = _bindgen_ty_14::IFLA_GENEVE_TOS;
 pub const __IFLA_GENEVE_MAX: _bindgen_ty_14 = _bindgen_ty_14::__IFLA_GENEVE_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_14 {
 IFLA_GENEVE_UNSPEC = 0,
 IFLA_GENEVE_ID = 1,
@@ -829,7 +783,8 @@ pub const IFLA_BOND_AD_ACTOR_SYSTEM: _bindgen_ty_15 = _bindgen_ty_15::IFLA_BOND_
 pub const __IFLA_BOND_MAX: _bindgen_ty_15 = _bindgen_ty_15::__IFLA_BOND_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_15 {
 IFLA_BOND_UNSPEC = 0,
 IFLA_BOND_MODE = 1,
@@ -869,7 +824,8 @@ pub const IFLA_BOND_AD_INFO_PARTNER_MAC: _bindgen_ty_16 = _bindgen_ty_16::IFLA_B
 pub const __IFLA_BOND_AD_INFO_MAX: _bindgen_ty_16 = _bindgen_ty_16::__IFLA_BOND_AD_INFO_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_16 {
 IFLA_BOND_AD_INFO_UNSPEC = 0,
 IFLA_BOND_AD_INFO_AGGREGATOR = 1,
@@ -891,7 +847,8 @@ pub const IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE: _bindgen_ty_17 = _bindgen_
 pub const __IFLA_BOND_SLAVE_MAX: _bindgen_ty_17 = _bindgen_ty_17::__IFLA_BOND_SLAVE_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_17 {
 IFLA_BOND_SLAVE_UNSPEC = 0,
 IFLA_BOND_SLAVE_STATE = 1,
@@ -909,7 +866,8 @@ pub const IFLA_VF_INFO: _bindgen_ty_18 = _bindgen_ty_18::IFLA_VF_INFO;
 pub const __IFLA_VF_INFO_MAX: _bindgen_ty_18 = _bindgen_ty_18::__IFLA_VF_INFO_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_18 {
 IFLA_VF_INFO_UNSPEC = 0,
 IFLA_VF_INFO = 1,
@@ -927,7 +885,8 @@ pub const IFLA_VF_STATS: _bindgen_ty_19 = _bindgen_ty_19::IFLA_VF_STATS;
 pub const __IFLA_VF_MAX: _bindgen_ty_19 = _bindgen_ty_19::__IFLA_VF_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_19 {
 IFLA_VF_UNSPEC = 0,
 IFLA_VF_MAC = 1,
@@ -941,33 +900,58 @@ IFLA_VF_STATS = 8,
 __IFLA_VF_MAX = 9,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_vf_mac {
 pub vf: __u32,
 pub mac: [__u8; 32usize],
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_vf_vlan {
 pub vf: __u32,
 pub vlan: __u32,
 pub qos: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_vf_tx_rate {
 pub vf: __u32,
 pub rate: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_vf_rate {
 pub vf: __u32,
 pub min_tx_rate: __u32,
 pub max_tx_rate: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_vf_spoofchk {
 pub vf: __u32,
 pub setting: __u32,
@@ -978,7 +962,8 @@ pub const IFLA_VF_LINK_STATE_DISABLE: _bindgen_ty_20 = _bindgen_ty_20::IFLA_VF_L
 pub const __IFLA_VF_LINK_STATE_MAX: _bindgen_ty_20 = _bindgen_ty_20::__IFLA_VF_LINK_STATE_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_20 {
 IFLA_VF_LINK_STATE_AUTO = 0,
 IFLA_VF_LINK_STATE_ENABLE = 1,
@@ -986,13 +971,23 @@ IFLA_VF_LINK_STATE_DISABLE = 2,
 __IFLA_VF_LINK_STATE_MAX = 3,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_vf_link_state {
 pub vf: __u32,
 pub link_state: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_vf_rss_query_en {
 pub vf: __u32,
 pub setting: __u32,
@@ -1006,7 +1001,8 @@ pub const IFLA_VF_STATS_MULTICAST: _bindgen_ty_21 = _bindgen_ty_21::IFLA_VF_STAT
 pub const __IFLA_VF_STATS_MAX: _bindgen_ty_21 = _bindgen_ty_21::__IFLA_VF_STATS_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_21 {
 IFLA_VF_STATS_RX_PACKETS = 0,
 IFLA_VF_STATS_TX_PACKETS = 1,
@@ -1021,7 +1017,8 @@ pub const IFLA_VF_PORT: _bindgen_ty_22 = _bindgen_ty_22::IFLA_VF_PORT;
 pub const __IFLA_VF_PORT_MAX: _bindgen_ty_22 = _bindgen_ty_22::__IFLA_VF_PORT_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_22 {
 IFLA_VF_PORT_UNSPEC = 0,
 IFLA_VF_PORT = 1,
@@ -1038,7 +1035,8 @@ pub const IFLA_PORT_RESPONSE: _bindgen_ty_23 = _bindgen_ty_23::IFLA_PORT_RESPONS
 pub const __IFLA_PORT_MAX: _bindgen_ty_23 = _bindgen_ty_23::__IFLA_PORT_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_23 {
 IFLA_PORT_UNSPEC = 0,
 IFLA_PORT_VF = 1,
@@ -1056,7 +1054,8 @@ pub const PORT_REQUEST_ASSOCIATE: _bindgen_ty_24 = _bindgen_ty_24::PORT_REQUEST_
 pub const PORT_REQUEST_DISASSOCIATE: _bindgen_ty_24 = _bindgen_ty_24::PORT_REQUEST_DISASSOCIATE;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_24 {
 PORT_REQUEST_PREASSOCIATE = 0,
 PORT_REQUEST_PREASSOCIATE_RR = 1,
@@ -1078,7 +1077,8 @@ pub const PORT_PROFILE_RESPONSE_INSUFFICIENT_RESOURCES: _bindgen_ty_25 = _bindge
 pub const PORT_PROFILE_RESPONSE_ERROR: _bindgen_ty_25 = _bindgen_ty_25::PORT_PROFILE_RESPONSE_ERROR;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_25 {
 PORT_VDP_RESPONSE_SUCCESS = 0,
 PORT_VDP_RESPONSE_INVALID_FORMAT = 1,
@@ -1095,7 +1095,12 @@ PORT_PROFILE_RESPONSE_INSUFFICIENT_RESOURCES = 260,
 PORT_PROFILE_RESPONSE_ERROR = 261,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifla_port_vsi {
 pub vsi_mgr_id: __u8,
 pub vsi_type_id: [__u8; 3usize],
@@ -1109,7 +1114,8 @@ pub const IFLA_IPOIB_UMCAST: _bindgen_ty_26 = _bindgen_ty_26::IFLA_IPOIB_UMCAST;
 pub const __IFLA_IPOIB_MAX: _bindgen_ty_26 = _bindgen_ty_26::__IFLA_IPOIB_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_26 {
 IFLA_IPOIB_UNSPEC = 0,
 IFLA_IPOIB_PKEY = 1,
@@ -1121,7 +1127,8 @@ pub const IPOIB_MODE_DATAGRAM: _bindgen_ty_27 = _bindgen_ty_27::IPOIB_MODE_DATAG
 pub const IPOIB_MODE_CONNECTED: _bindgen_ty_27 = _bindgen_ty_27::IPOIB_MODE_CONNECTED;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_27 {
 IPOIB_MODE_DATAGRAM = 0,
 IPOIB_MODE_CONNECTED = 1,
@@ -1135,7 +1142,8 @@ pub const IFLA_HSR_SEQ_NR: _bindgen_ty_28 = _bindgen_ty_28::IFLA_HSR_SEQ_NR;
 pub const __IFLA_HSR_MAX: _bindgen_ty_28 = _bindgen_ty_28::__IFLA_HSR_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_28 {
 IFLA_HSR_UNSPEC = 0,
 IFLA_HSR_SLAVE1 = 1,
@@ -1146,7 +1154,12 @@ IFLA_HSR_SEQ_NR = 5,
 __IFLA_HSR_MAX = 6,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifaddrmsg {
 pub ifa_family: __u8,
 pub ifa_prefixlen: __u8,
@@ -1166,7 +1179,8 @@ pub const IFA_FLAGS: _bindgen_ty_29 = _bindgen_ty_29::IFA_FLAGS;
 pub const __IFA_MAX: _bindgen_ty_29 = _bindgen_ty_29::__IFA_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_29 {
 IFA_UNSPEC = 0,
 IFA_ADDRESS = 1,
@@ -1180,7 +1194,12 @@ IFA_FLAGS = 8,
 __IFA_MAX = 9,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifa_cacheinfo {
 pub ifa_prefered: __u32,
 pub ifa_valid: __u32,
@@ -1188,7 +1207,12 @@ pub cstamp: __u32,
 pub tstamp: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ndmsg {
 pub ndm_family: __u8,
 pub ndm_pad1: __u8,
@@ -1212,7 +1236,8 @@ pub const NDA_LINK_NETNSID: _bindgen_ty_30 = _bindgen_ty_30::NDA_LINK_NETNSID;
 pub const __NDA_MAX: _bindgen_ty_30 = _bindgen_ty_30::__NDA_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_30 {
 NDA_UNSPEC = 0,
 NDA_DST = 1,
@@ -1228,7 +1253,12 @@ NDA_LINK_NETNSID = 10,
 __NDA_MAX = 11,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct nda_cacheinfo {
 pub ndm_confirmed: __u32,
 pub ndm_used: __u32,
@@ -1236,7 +1266,12 @@ pub ndm_updated: __u32,
 pub ndm_refcnt: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ndt_stats {
 pub ndts_allocs: __u64,
 pub ndts_destroys: __u64,
@@ -1270,7 +1305,8 @@ pub const NDTPA_MCAST_REPROBES: _bindgen_ty_31 = _bindgen_ty_31::NDTPA_MCAST_REP
 pub const __NDTPA_MAX: _bindgen_ty_31 = _bindgen_ty_31::__NDTPA_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_31 {
 NDTPA_UNSPEC = 0,
 NDTPA_IFINDEX = 1,
@@ -1293,14 +1329,24 @@ NDTPA_MCAST_REPROBES = 17,
 __NDTPA_MAX = 18,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ndtmsg {
 pub ndtm_family: __u8,
 pub ndtm_pad1: __u8,
 pub ndtm_pad2: __u16,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ndt_config {
 pub ndtc_key_len: __u16,
 pub ndtc_entry_size: __u16,
@@ -1324,7 +1370,8 @@ pub const NDTA_GC_INTERVAL: _bindgen_ty_32 = _bindgen_ty_32::NDTA_GC_INTERVAL;
 pub const __NDTA_MAX: _bindgen_ty_32 = _bindgen_ty_32::__NDTA_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_32 {
 NDTA_UNSPEC = 0,
 NDTA_NAME = 1,
@@ -1389,7 +1436,8 @@ pub const RTM_GETNSID: _bindgen_ty_33 = _bindgen_ty_33::RTM_GETNSID;
 pub const __RTM_MAX: _bindgen_ty_33 = _bindgen_ty_33::__RTM_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_33 {
 RTM_BASE = 16,
 RTM_DELLINK = 17,
@@ -1442,13 +1490,23 @@ RTM_GETNSID = 90,
 __RTM_MAX = 91,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rtattr {
 pub rta_len: crate::ctypes::c_ushort,
 pub rta_type: crate::ctypes::c_ushort,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rtmsg {
 pub rtm_family: crate::ctypes::c_uchar,
 pub rtm_dst_len: crate::ctypes::c_uchar,
@@ -1475,7 +1533,8 @@ pub const RTN_XRESOLVE: _bindgen_ty_34 = _bindgen_ty_34::RTN_XRESOLVE;
 pub const __RTN_MAX: _bindgen_ty_34 = _bindgen_ty_34::__RTN_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_34 {
 RTN_UNSPEC = 0,
 RTN_UNICAST = 1,
@@ -1493,7 +1552,8 @@ __RTN_MAX = 12,
 }
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum rt_scope_t {
 RT_SCOPE_UNIVERSE = 0,
 RT_SCOPE_SITE = 200,
@@ -1503,7 +1563,8 @@ RT_SCOPE_NOWHERE = 255,
 }
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum rt_class_t {
 RT_TABLE_UNSPEC = 0,
 RT_TABLE_COMPAT = 252,
@@ -1514,7 +1575,8 @@ RT_TABLE_MAX = 4294967295,
 }
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum rtattr_type_t {
 RTA_UNSPEC = 0,
 RTA_DST = 1,
@@ -1540,7 +1602,12 @@ RTA_PREF = 20,
 __RTA_MAX = 21,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rtnexthop {
 pub rtnh_len: crate::ctypes::c_ushort,
 pub rtnh_flags: crate::ctypes::c_uchar,
@@ -1548,13 +1615,18 @@ pub rtnh_hops: crate::ctypes::c_uchar,
 pub rtnh_ifindex: crate::ctypes::c_int,
 }
 #[repr(C)]
-#[derive(Debug)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub struct rtvia {
 pub rtvia_family: __kernel_sa_family_t,
 pub rtvia_addr: __IncompleteArrayField<__u8>,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rta_cacheinfo {
 pub rta_clntref: __u32,
 pub rta_lastuse: __u32,
@@ -1585,7 +1657,8 @@ pub const RTAX_CC_ALGO: _bindgen_ty_35 = _bindgen_ty_35::RTAX_CC_ALGO;
 pub const __RTAX_MAX: _bindgen_ty_35 = _bindgen_ty_35::__RTAX_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_35 {
 RTAX_UNSPEC = 0,
 RTAX_LOCK = 1,
@@ -1608,6 +1681,7 @@ __RTAX_MAX = 17,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "union-debug", derive(Debug))]
 pub struct rta_session {
 pub proto: __u8,
 pub pad1: __u8,
@@ -1621,33 +1695,64 @@ pub ports: rta_session__bindgen_ty_1__bindgen_ty_1,
 pub icmpt: rta_session__bindgen_ty_1__bindgen_ty_2,
 pub spi: __u32,
 }
+#[cfg(feature = "union-debug")]
+impl ::core::fmt::Debug for rta_session__bindgen_ty_1 {
+fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+f.debug_struct("rta_session__bindgen_ty_1").finish_non_exhaustive()
+}
+}
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rta_session__bindgen_ty_1__bindgen_ty_1 {
 pub sport: __u16,
 pub dport: __u16,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rta_session__bindgen_ty_1__bindgen_ty_2 {
 pub type_: __u8,
 pub code: __u8,
 pub ident: __u16,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rta_mfc_stats {
 pub mfcs_packets: __u64,
 pub mfcs_bytes: __u64,
 pub mfcs_wrong_if: __u64,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct rtgenmsg {
 pub rtgen_family: crate::ctypes::c_uchar,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ifinfomsg {
 pub ifi_family: crate::ctypes::c_uchar,
 pub __ifi_pad: crate::ctypes::c_uchar,
@@ -1657,7 +1762,12 @@ pub ifi_flags: crate::ctypes::c_uint,
 pub ifi_change: crate::ctypes::c_uint,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct prefixmsg {
 pub prefix_family: crate::ctypes::c_uchar,
 pub prefix_pad1: crate::ctypes::c_uchar,
@@ -1674,7 +1784,8 @@ pub const PREFIX_CACHEINFO: _bindgen_ty_36 = _bindgen_ty_36::PREFIX_CACHEINFO;
 pub const __PREFIX_MAX: _bindgen_ty_36 = _bindgen_ty_36::__PREFIX_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_36 {
 PREFIX_UNSPEC = 0,
 PREFIX_ADDRESS = 1,
@@ -1682,13 +1793,23 @@ PREFIX_CACHEINFO = 2,
 __PREFIX_MAX = 3,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct prefix_cacheinfo {
 pub preferred_time: __u32,
 pub valid_time: __u32,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct tcmsg {
 pub tcm_family: crate::ctypes::c_uchar,
 pub tcm__pad1: crate::ctypes::c_uchar,
@@ -1710,7 +1831,8 @@ pub const TCA_STAB: _bindgen_ty_37 = _bindgen_ty_37::TCA_STAB;
 pub const __TCA_MAX: _bindgen_ty_37 = _bindgen_ty_37::__TCA_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_37 {
 TCA_UNSPEC = 0,
 TCA_KIND = 1,
@@ -1724,7 +1846,12 @@ TCA_STAB = 8,
 __TCA_MAX = 9,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct nduseroptmsg {
 pub nduseropt_family: crate::ctypes::c_uchar,
 pub nduseropt_pad1: crate::ctypes::c_uchar,
@@ -1740,7 +1867,8 @@ pub const NDUSEROPT_SRCADDR: _bindgen_ty_38 = _bindgen_ty_38::NDUSEROPT_SRCADDR;
 pub const __NDUSEROPT_MAX: _bindgen_ty_38 = _bindgen_ty_38::__NDUSEROPT_MAX;
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum _bindgen_ty_38 {
 NDUSEROPT_UNSPEC = 0,
 NDUSEROPT_SRCADDR = 1,
@@ -1748,7 +1876,8 @@ __NDUSEROPT_MAX = 2,
 }
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
 pub enum rtnetlink_groups {
 RTNLGRP_NONE = 0,
 RTNLGRP_LINK = 1,
@@ -1782,7 +1911,12 @@ RTNLGRP_NSID = 28,
 __RTNLGRP_MAX = 29,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[cfg_attr(feature = "derive-eq", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct tcamsg {
 pub tca_family: crate::ctypes::c_uchar,
 pub tca__pad1: crate::ctypes::c_uchar,
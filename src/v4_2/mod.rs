@@ -1,3 +1,5 @@
+/// The `LINUX_VERSION_CODE` of the headers these bindings were generated from.
+pub const LINUX_VERSION_CODE: u32 = 0x040200;
 #[cfg(target_arch = "aarch64")]
 mod aarch64;
 #[cfg(target_arch = "aarch64")]
@@ -1,3 +1,5 @@
+/// The `LINUX_VERSION_CODE` of the headers these bindings were generated from.
+pub const LINUX_VERSION_CODE: u32 = 0x041400;
 #[cfg(target_arch = "riscv64")]
 mod riscv64;
 #[cfg(target_arch = "riscv64")]
@@ -0,0 +1,368 @@
+include!("../shared/general_consts.rs");
+pub const F_GETLK64: u32 = 33;
+pub const F_SETLK64: u32 = 34;
+pub const F_SETLKW64: u32 = 35;
+pub const RLIM_INFINITY: u32 = 2147483647;
+pub const __NR_Linux: u32 = 4000;
+pub const __NR_syscall: u32 = 4000;
+pub const __NR_exit: u32 = 4001;
+pub const __NR_fork: u32 = 4002;
+pub const __NR_read: u32 = 4003;
+pub const __NR_write: u32 = 4004;
+pub const __NR_open: u32 = 4005;
+pub const __NR_close: u32 = 4006;
+pub const __NR_waitpid: u32 = 4007;
+pub const __NR_creat: u32 = 4008;
+pub const __NR_link: u32 = 4009;
+pub const __NR_unlink: u32 = 4010;
+pub const __NR_execve: u32 = 4011;
+pub const __NR_chdir: u32 = 4012;
+pub const __NR_time: u32 = 4013;
+pub const __NR_mknod: u32 = 4014;
+pub const __NR_chmod: u32 = 4015;
+pub const __NR_lchown: u32 = 4016;
+pub const __NR_break: u32 = 4017;
+pub const __NR_unused18: u32 = 4018;
+pub const __NR_lseek: u32 = 4019;
+pub const __NR_getpid: u32 = 4020;
+pub const __NR_mount: u32 = 4021;
+pub const __NR_umount: u32 = 4022;
+pub const __NR_setuid: u32 = 4023;
+pub const __NR_getuid: u32 = 4024;
+pub const __NR_stime: u32 = 4025;
+pub const __NR_ptrace: u32 = 4026;
+pub const __NR_alarm: u32 = 4027;
+pub const __NR_unused28: u32 = 4028;
+pub const __NR_pause: u32 = 4029;
+pub const __NR_utime: u32 = 4030;
+pub const __NR_stty: u32 = 4031;
+pub const __NR_gtty: u32 = 4032;
+pub const __NR_access: u32 = 4033;
+pub const __NR_nice: u32 = 4034;
+pub const __NR_ftime: u32 = 4035;
+pub const __NR_sync: u32 = 4036;
+pub const __NR_kill: u32 = 4037;
+pub const __NR_rename: u32 = 4038;
+pub const __NR_mkdir: u32 = 4039;
+pub const __NR_rmdir: u32 = 4040;
+pub const __NR_dup: u32 = 4041;
+pub const __NR_pipe: u32 = 4042;
+pub const __NR_times: u32 = 4043;
+pub const __NR_prof: u32 = 4044;
+pub const __NR_brk: u32 = 4045;
+pub const __NR_setgid: u32 = 4046;
+pub const __NR_getgid: u32 = 4047;
+pub const __NR_signal: u32 = 4048;
+pub const __NR_geteuid: u32 = 4049;
+pub const __NR_getegid: u32 = 4050;
+pub const __NR_acct: u32 = 4051;
+pub const __NR_umount2: u32 = 4052;
+pub const __NR_lock: u32 = 4053;
+pub const __NR_ioctl: u32 = 4054;
+pub const __NR_fcntl: u32 = 4055;
+pub const __NR_mpx: u32 = 4056;
+pub const __NR_setpgid: u32 = 4057;
+pub const __NR_ulimit: u32 = 4058;
+pub const __NR_unused59: u32 = 4059;
+pub const __NR_umask: u32 = 4060;
+pub const __NR_chroot: u32 = 4061;
+pub const __NR_ustat: u32 = 4062;
+pub const __NR_dup2: u32 = 4063;
+pub const __NR_getppid: u32 = 4064;
+pub const __NR_getpgrp: u32 = 4065;
+pub const __NR_setsid: u32 = 4066;
+pub const __NR_sigaction: u32 = 4067;
+pub const __NR_sgetmask: u32 = 4068;
+pub const __NR_ssetmask: u32 = 4069;
+pub const __NR_setreuid: u32 = 4070;
+pub const __NR_setregid: u32 = 4071;
+pub const __NR_sigsuspend: u32 = 4072;
+pub const __NR_sigpending: u32 = 4073;
+pub const __NR_sethostname: u32 = 4074;
+pub const __NR_setrlimit: u32 = 4075;
+pub const __NR_getrlimit: u32 = 4076;
+pub const __NR_getrusage: u32 = 4077;
+pub const __NR_gettimeofday: u32 = 4078;
+pub const __NR_settimeofday: u32 = 4079;
+pub const __NR_getgroups: u32 = 4080;
+pub const __NR_setgroups: u32 = 4081;
+pub const __NR_reserved82: u32 = 4082;
+pub const __NR_symlink: u32 = 4083;
+pub const __NR_unused84: u32 = 4084;
+pub const __NR_readlink: u32 = 4085;
+pub const __NR_uselib: u32 = 4086;
+pub const __NR_swapon: u32 = 4087;
+pub const __NR_reboot: u32 = 4088;
+pub const __NR_readdir: u32 = 4089;
+pub const __NR_mmap: u32 = 4090;
+pub const __NR_munmap: u32 = 4091;
+pub const __NR_truncate: u32 = 4092;
+pub const __NR_ftruncate: u32 = 4093;
+pub const __NR_fchmod: u32 = 4094;
+pub const __NR_fchown: u32 = 4095;
+pub const __NR_getpriority: u32 = 4096;
+pub const __NR_setpriority: u32 = 4097;
+pub const __NR_profil: u32 = 4098;
+pub const __NR_statfs: u32 = 4099;
+pub const __NR_fstatfs: u32 = 4100;
+pub const __NR_ioperm: u32 = 4101;
+pub const __NR_socketcall: u32 = 4102;
+pub const __NR_syslog: u32 = 4103;
+pub const __NR_setitimer: u32 = 4104;
+pub const __NR_getitimer: u32 = 4105;
+pub const __NR_stat: u32 = 4106;
+pub const __NR_lstat: u32 = 4107;
+pub const __NR_fstat: u32 = 4108;
+pub const __NR_unused109: u32 = 4109;
+pub const __NR_iopl: u32 = 4110;
+pub const __NR_vhangup: u32 = 4111;
+pub const __NR_idle: u32 = 4112;
+pub const __NR_vm86: u32 = 4113;
+pub const __NR_wait4: u32 = 4114;
+pub const __NR_swapoff: u32 = 4115;
+pub const __NR_sysinfo: u32 = 4116;
+pub const __NR_ipc: u32 = 4117;
+pub const __NR_fsync: u32 = 4118;
+pub const __NR_sigreturn: u32 = 4119;
+pub const __NR_clone: u32 = 4120;
+pub const __NR_setdomainname: u32 = 4121;
+pub const __NR_uname: u32 = 4122;
+pub const __NR_modify_ldt: u32 = 4123;
+pub const __NR_adjtimex: u32 = 4124;
+pub const __NR_mprotect: u32 = 4125;
+pub const __NR_sigprocmask: u32 = 4126;
+pub const __NR_create_module: u32 = 4127;
+pub const __NR_init_module: u32 = 4128;
+pub const __NR_delete_module: u32 = 4129;
+pub const __NR_get_kernel_syms: u32 = 4130;
+pub const __NR_quotactl: u32 = 4131;
+pub const __NR_getpgid: u32 = 4132;
+pub const __NR_fchdir: u32 = 4133;
+pub const __NR_bdflush: u32 = 4134;
+pub const __NR_sysfs: u32 = 4135;
+pub const __NR_personality: u32 = 4136;
+pub const __NR_afs_syscall: u32 = 4137;
+pub const __NR_setfsuid: u32 = 4138;
+pub const __NR_setfsgid: u32 = 4139;
+pub const __NR__llseek: u32 = 4140;
+pub const __NR_getdents: u32 = 4141;
+pub const __NR__newselect: u32 = 4142;
+pub const __NR_flock: u32 = 4143;
+pub const __NR_msync: u32 = 4144;
+pub const __NR_readv: u32 = 4145;
+pub const __NR_writev: u32 = 4146;
+pub const __NR_cacheflush: u32 = 4147;
+pub const __NR_cachectl: u32 = 4148;
+pub const __NR_sysmips: u32 = 4149;
+pub const __NR_unused150: u32 = 4150;
+pub const __NR_getsid: u32 = 4151;
+pub const __NR_fdatasync: u32 = 4152;
+pub const __NR__sysctl: u32 = 4153;
+pub const __NR_mlock: u32 = 4154;
+pub const __NR_munlock: u32 = 4155;
+pub const __NR_mlockall: u32 = 4156;
+pub const __NR_munlockall: u32 = 4157;
+pub const __NR_sched_setparam: u32 = 4158;
+pub const __NR_sched_getparam: u32 = 4159;
+pub const __NR_sched_setscheduler: u32 = 4160;
+pub const __NR_sched_getscheduler: u32 = 4161;
+pub const __NR_sched_yield: u32 = 4162;
+pub const __NR_sched_get_priority_max: u32 = 4163;
+pub const __NR_sched_get_priority_min: u32 = 4164;
+pub const __NR_sched_rr_get_interval: u32 = 4165;
+pub const __NR_nanosleep: u32 = 4166;
+pub const __NR_mremap: u32 = 4167;
+pub const __NR_accept: u32 = 4168;
+pub const __NR_bind: u32 = 4169;
+pub const __NR_connect: u32 = 4170;
+pub const __NR_getpeername: u32 = 4171;
+pub const __NR_getsockname: u32 = 4172;
+pub const __NR_getsockopt: u32 = 4173;
+pub const __NR_listen: u32 = 4174;
+pub const __NR_recv: u32 = 4175;
+pub const __NR_recvfrom: u32 = 4176;
+pub const __NR_recvmsg: u32 = 4177;
+pub const __NR_send: u32 = 4178;
+pub const __NR_sendmsg: u32 = 4179;
+pub const __NR_sendto: u32 = 4180;
+pub const __NR_setsockopt: u32 = 4181;
+pub const __NR_shutdown: u32 = 4182;
+pub const __NR_socket: u32 = 4183;
+pub const __NR_socketpair: u32 = 4184;
+pub const __NR_setresuid: u32 = 4185;
+pub const __NR_getresuid: u32 = 4186;
+pub const __NR_query_module: u32 = 4187;
+pub const __NR_poll: u32 = 4188;
+pub const __NR_nfsservctl: u32 = 4189;
+pub const __NR_setresgid: u32 = 4190;
+pub const __NR_getresgid: u32 = 4191;
+pub const __NR_prctl: u32 = 4192;
+pub const __NR_rt_sigreturn: u32 = 4193;
+pub const __NR_rt_sigaction: u32 = 4194;
+pub const __NR_rt_sigprocmask: u32 = 4195;
+pub const __NR_rt_sigpending: u32 = 4196;
+pub const __NR_rt_sigtimedwait: u32 = 4197;
+pub const __NR_rt_sigqueueinfo: u32 = 4198;
+pub const __NR_rt_sigsuspend: u32 = 4199;
+pub const __NR_pread64: u32 = 4200;
+pub const __NR_pwrite64: u32 = 4201;
+pub const __NR_chown: u32 = 4202;
+pub const __NR_getcwd: u32 = 4203;
+pub const __NR_capget: u32 = 4204;
+pub const __NR_capset: u32 = 4205;
+pub const __NR_sigaltstack: u32 = 4206;
+pub const __NR_sendfile: u32 = 4207;
+pub const __NR_getpmsg: u32 = 4208;
+pub const __NR_putpmsg: u32 = 4209;
+pub const __NR_mmap2: u32 = 4210;
+pub const __NR_truncate64: u32 = 4211;
+pub const __NR_ftruncate64: u32 = 4212;
+pub const __NR_stat64: u32 = 4213;
+pub const __NR_lstat64: u32 = 4214;
+pub const __NR_fstat64: u32 = 4215;
+pub const __NR_pivot_root: u32 = 4216;
+pub const __NR_mincore: u32 = 4217;
+pub const __NR_madvise: u32 = 4218;
+pub const __NR_getdents64: u32 = 4219;
+pub const __NR_fcntl64: u32 = 4220;
+pub const __NR_reserved221: u32 = 4221;
+pub const __NR_gettid: u32 = 4222;
+pub const __NR_readahead: u32 = 4223;
+pub const __NR_setxattr: u32 = 4224;
+pub const __NR_lsetxattr: u32 = 4225;
+pub const __NR_fsetxattr: u32 = 4226;
+pub const __NR_getxattr: u32 = 4227;
+pub const __NR_lgetxattr: u32 = 4228;
+pub const __NR_fgetxattr: u32 = 4229;
+pub const __NR_listxattr: u32 = 4230;
+pub const __NR_llistxattr: u32 = 4231;
+pub const __NR_flistxattr: u32 = 4232;
+pub const __NR_removexattr: u32 = 4233;
+pub const __NR_lremovexattr: u32 = 4234;
+pub const __NR_fremovexattr: u32 = 4235;
+pub const __NR_tkill: u32 = 4236;
+pub const __NR_sendfile64: u32 = 4237;
+pub const __NR_futex: u32 = 4238;
+pub const __NR_sched_setaffinity: u32 = 4239;
+pub const __NR_sched_getaffinity: u32 = 4240;
+pub const __NR_io_setup: u32 = 4241;
+pub const __NR_io_destroy: u32 = 4242;
+pub const __NR_io_getevents: u32 = 4243;
+pub const __NR_io_submit: u32 = 4244;
+pub const __NR_io_cancel: u32 = 4245;
+pub const __NR_exit_group: u32 = 4246;
+pub const __NR_lookup_dcookie: u32 = 4247;
+pub const __NR_epoll_create: u32 = 4248;
+pub const __NR_epoll_ctl: u32 = 4249;
+pub const __NR_epoll_wait: u32 = 4250;
+pub const __NR_remap_file_pages: u32 = 4251;
+pub const __NR_set_tid_address: u32 = 4252;
+pub const __NR_restart_syscall: u32 = 4253;
+pub const __NR_fadvise64: u32 = 4254;
+pub const __NR_statfs64: u32 = 4255;
+pub const __NR_fstatfs64: u32 = 4256;
+pub const __NR_timer_create: u32 = 4257;
+pub const __NR_timer_settime: u32 = 4258;
+pub const __NR_timer_gettime: u32 = 4259;
+pub const __NR_timer_getoverrun: u32 = 4260;
+pub const __NR_timer_delete: u32 = 4261;
+pub const __NR_clock_settime: u32 = 4262;
+pub const __NR_clock_gettime: u32 = 4263;
+pub const __NR_clock_getres: u32 = 4264;
+pub const __NR_clock_nanosleep: u32 = 4265;
+pub const __NR_tgkill: u32 = 4266;
+pub const __NR_utimes: u32 = 4267;
+pub const __NR_mbind: u32 = 4268;
+pub const __NR_get_mempolicy: u32 = 4269;
+pub const __NR_set_mempolicy: u32 = 4270;
+pub const __NR_mq_open: u32 = 4271;
+pub const __NR_mq_unlink: u32 = 4272;
+pub const __NR_mq_timedsend: u32 = 4273;
+pub const __NR_mq_timedreceive: u32 = 4274;
+pub const __NR_mq_notify: u32 = 4275;
+pub const __NR_mq_getsetattr: u32 = 4276;
+pub const __NR_vserver: u32 = 4277;
+pub const __NR_waitid: u32 = 4278;
+pub const __NR_add_key: u32 = 4280;
+pub const __NR_request_key: u32 = 4281;
+pub const __NR_keyctl: u32 = 4282;
+pub const __NR_set_thread_area: u32 = 4283;
+pub const __NR_inotify_init: u32 = 4284;
+pub const __NR_inotify_add_watch: u32 = 4285;
+pub const __NR_inotify_rm_watch: u32 = 4286;
+pub const __NR_migrate_pages: u32 = 4287;
+pub const __NR_openat: u32 = 4288;
+pub const __NR_mkdirat: u32 = 4289;
+pub const __NR_mknodat: u32 = 4290;
+pub const __NR_fchownat: u32 = 4291;
+pub const __NR_futimesat: u32 = 4292;
+pub const __NR_fstatat64: u32 = 4293;
+pub const __NR_unlinkat: u32 = 4294;
+pub const __NR_renameat: u32 = 4295;
+pub const __NR_linkat: u32 = 4296;
+pub const __NR_symlinkat: u32 = 4297;
+pub const __NR_readlinkat: u32 = 4298;
+pub const __NR_fchmodat: u32 = 4299;
+pub const __NR_faccessat: u32 = 4300;
+pub const __NR_pselect6: u32 = 4301;
+pub const __NR_ppoll: u32 = 4302;
+pub const __NR_unshare: u32 = 4303;
+pub const __NR_splice: u32 = 4304;
+pub const __NR_sync_file_range: u32 = 4305;
+pub const __NR_tee: u32 = 4306;
+pub const __NR_vmsplice: u32 = 4307;
+pub const __NR_move_pages: u32 = 4308;
+pub const __NR_set_robust_list: u32 = 4309;
+pub const __NR_get_robust_list: u32 = 4310;
+pub const __NR_kexec_load: u32 = 4311;
+pub const __NR_getcpu: u32 = 4312;
+pub const __NR_epoll_pwait: u32 = 4313;
+pub const __NR_ioprio_set: u32 = 4314;
+pub const __NR_ioprio_get: u32 = 4315;
+pub const __NR_utimensat: u32 = 4316;
+pub const __NR_signalfd: u32 = 4317;
+pub const __NR_timerfd: u32 = 4318;
+pub const __NR_eventfd: u32 = 4319;
+pub const __NR_fallocate: u32 = 4320;
+pub const __NR_timerfd_create: u32 = 4321;
+pub const __NR_timerfd_gettime: u32 = 4322;
+pub const __NR_timerfd_settime: u32 = 4323;
+pub const __NR_signalfd4: u32 = 4324;
+pub const __NR_eventfd2: u32 = 4325;
+pub const __NR_epoll_create1: u32 = 4326;
+pub const __NR_dup3: u32 = 4327;
+pub const __NR_pipe2: u32 = 4328;
+pub const __NR_inotify_init1: u32 = 4329;
+pub const __NR_preadv: u32 = 4330;
+pub const __NR_pwritev: u32 = 4331;
+pub const __NR_rt_tgsigqueueinfo: u32 = 4332;
+pub const __NR_perf_event_open: u32 = 4333;
+pub const __NR_accept4: u32 = 4334;
+pub const __NR_recvmmsg: u32 = 4335;
+pub const __NR_fanotify_init: u32 = 4336;
+pub const __NR_fanotify_mark: u32 = 4337;
+pub const __NR_prlimit64: u32 = 4338;
+pub const __NR_name_to_handle_at: u32 = 4339;
+pub const __NR_open_by_handle_at: u32 = 4340;
+pub const __NR_clock_adjtime: u32 = 4341;
+pub const __NR_syncfs: u32 = 4342;
+pub const __NR_sendmmsg: u32 = 4343;
+pub const __NR_setns: u32 = 4344;
+pub const __NR_process_vm_readv: u32 = 4345;
+pub const __NR_process_vm_writev: u32 = 4346;
+pub const __NR_kcmp: u32 = 4347;
+pub const __NR_finit_module: u32 = 4348;
+pub const __NR_sched_setattr: u32 = 4349;
+pub const __NR_sched_getattr: u32 = 4350;
+pub const __NR_renameat2: u32 = 4351;
+pub const __NR_seccomp: u32 = 4352;
+pub const __NR_getrandom: u32 = 4353;
+pub const __NR_memfd_create: u32 = 4354;
+pub const __NR_bpf: u32 = 4355;
+pub const __NR_execveat: u32 = 4356;
+pub const __NR_userfaultfd: u32 = 4357;
+pub const __NR_membarrier: u32 = 4358;
+pub const __NR_mlock2: u32 = 4359;
+pub const __NR_Linux_syscalls: u32 = 359;
+/// The syscall number corresponding to index 0 of `SYSCALL_NAMES`.
+pub const SYSCALL_NAMES_BASE: u32 = 4000;
@@ -0,0 +1 @@
+include!("../shared/errno_consts.rs");
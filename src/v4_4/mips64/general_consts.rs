@@ -0,0 +1,328 @@
+include!("../shared/general_consts.rs");
+pub const F_GETLK64: u32 = 12;
+pub const F_SETLK64: u32 = 13;
+pub const F_SETLKW64: u32 = 14;
+pub const RLIM_INFINITY: i32 = -1;
+pub const __NR_Linux: u32 = 5000;
+pub const __NR_read: u32 = 5000;
+pub const __NR_write: u32 = 5001;
+pub const __NR_open: u32 = 5002;
+pub const __NR_close: u32 = 5003;
+pub const __NR_stat: u32 = 5004;
+pub const __NR_fstat: u32 = 5005;
+pub const __NR_lstat: u32 = 5006;
+pub const __NR_poll: u32 = 5007;
+pub const __NR_lseek: u32 = 5008;
+pub const __NR_mmap: u32 = 5009;
+pub const __NR_mprotect: u32 = 5010;
+pub const __NR_munmap: u32 = 5011;
+pub const __NR_brk: u32 = 5012;
+pub const __NR_rt_sigaction: u32 = 5013;
+pub const __NR_rt_sigprocmask: u32 = 5014;
+pub const __NR_ioctl: u32 = 5015;
+pub const __NR_pread64: u32 = 5016;
+pub const __NR_pwrite64: u32 = 5017;
+pub const __NR_readv: u32 = 5018;
+pub const __NR_writev: u32 = 5019;
+pub const __NR_access: u32 = 5020;
+pub const __NR_pipe: u32 = 5021;
+pub const __NR__newselect: u32 = 5022;
+pub const __NR_sched_yield: u32 = 5023;
+pub const __NR_mremap: u32 = 5024;
+pub const __NR_msync: u32 = 5025;
+pub const __NR_mincore: u32 = 5026;
+pub const __NR_madvise: u32 = 5027;
+pub const __NR_shmget: u32 = 5028;
+pub const __NR_shmat: u32 = 5029;
+pub const __NR_shmctl: u32 = 5030;
+pub const __NR_dup: u32 = 5031;
+pub const __NR_dup2: u32 = 5032;
+pub const __NR_pause: u32 = 5033;
+pub const __NR_nanosleep: u32 = 5034;
+pub const __NR_getitimer: u32 = 5035;
+pub const __NR_setitimer: u32 = 5036;
+pub const __NR_alarm: u32 = 5037;
+pub const __NR_getpid: u32 = 5038;
+pub const __NR_sendfile: u32 = 5039;
+pub const __NR_socket: u32 = 5040;
+pub const __NR_connect: u32 = 5041;
+pub const __NR_accept: u32 = 5042;
+pub const __NR_sendto: u32 = 5043;
+pub const __NR_recvfrom: u32 = 5044;
+pub const __NR_sendmsg: u32 = 5045;
+pub const __NR_recvmsg: u32 = 5046;
+pub const __NR_shutdown: u32 = 5047;
+pub const __NR_bind: u32 = 5048;
+pub const __NR_listen: u32 = 5049;
+pub const __NR_getsockname: u32 = 5050;
+pub const __NR_getpeername: u32 = 5051;
+pub const __NR_socketpair: u32 = 5052;
+pub const __NR_setsockopt: u32 = 5053;
+pub const __NR_getsockopt: u32 = 5054;
+pub const __NR_clone: u32 = 5055;
+pub const __NR_fork: u32 = 5056;
+pub const __NR_execve: u32 = 5057;
+pub const __NR_exit: u32 = 5058;
+pub const __NR_wait4: u32 = 5059;
+pub const __NR_kill: u32 = 5060;
+pub const __NR_uname: u32 = 5061;
+pub const __NR_semget: u32 = 5062;
+pub const __NR_semop: u32 = 5063;
+pub const __NR_semctl: u32 = 5064;
+pub const __NR_shmdt: u32 = 5065;
+pub const __NR_msgget: u32 = 5066;
+pub const __NR_msgsnd: u32 = 5067;
+pub const __NR_msgrcv: u32 = 5068;
+pub const __NR_msgctl: u32 = 5069;
+pub const __NR_fcntl: u32 = 5070;
+pub const __NR_flock: u32 = 5071;
+pub const __NR_fsync: u32 = 5072;
+pub const __NR_fdatasync: u32 = 5073;
+pub const __NR_truncate: u32 = 5074;
+pub const __NR_ftruncate: u32 = 5075;
+pub const __NR_getdents: u32 = 5076;
+pub const __NR_getcwd: u32 = 5077;
+pub const __NR_chdir: u32 = 5078;
+pub const __NR_fchdir: u32 = 5079;
+pub const __NR_rename: u32 = 5080;
+pub const __NR_mkdir: u32 = 5081;
+pub const __NR_rmdir: u32 = 5082;
+pub const __NR_creat: u32 = 5083;
+pub const __NR_link: u32 = 5084;
+pub const __NR_unlink: u32 = 5085;
+pub const __NR_symlink: u32 = 5086;
+pub const __NR_readlink: u32 = 5087;
+pub const __NR_chmod: u32 = 5088;
+pub const __NR_fchmod: u32 = 5089;
+pub const __NR_chown: u32 = 5090;
+pub const __NR_fchown: u32 = 5091;
+pub const __NR_lchown: u32 = 5092;
+pub const __NR_umask: u32 = 5093;
+pub const __NR_gettimeofday: u32 = 5094;
+pub const __NR_getrlimit: u32 = 5095;
+pub const __NR_getrusage: u32 = 5096;
+pub const __NR_sysinfo: u32 = 5097;
+pub const __NR_times: u32 = 5098;
+pub const __NR_ptrace: u32 = 5099;
+pub const __NR_getuid: u32 = 5100;
+pub const __NR_syslog: u32 = 5101;
+pub const __NR_getgid: u32 = 5102;
+pub const __NR_setuid: u32 = 5103;
+pub const __NR_setgid: u32 = 5104;
+pub const __NR_geteuid: u32 = 5105;
+pub const __NR_getegid: u32 = 5106;
+pub const __NR_setpgid: u32 = 5107;
+pub const __NR_getppid: u32 = 5108;
+pub const __NR_getpgrp: u32 = 5109;
+pub const __NR_setsid: u32 = 5110;
+pub const __NR_setreuid: u32 = 5111;
+pub const __NR_setregid: u32 = 5112;
+pub const __NR_getgroups: u32 = 5113;
+pub const __NR_setgroups: u32 = 5114;
+pub const __NR_setresuid: u32 = 5115;
+pub const __NR_getresuid: u32 = 5116;
+pub const __NR_setresgid: u32 = 5117;
+pub const __NR_getresgid: u32 = 5118;
+pub const __NR_getpgid: u32 = 5119;
+pub const __NR_setfsuid: u32 = 5120;
+pub const __NR_setfsgid: u32 = 5121;
+pub const __NR_getsid: u32 = 5122;
+pub const __NR_capget: u32 = 5123;
+pub const __NR_capset: u32 = 5124;
+pub const __NR_rt_sigpending: u32 = 5125;
+pub const __NR_rt_sigtimedwait: u32 = 5126;
+pub const __NR_rt_sigqueueinfo: u32 = 5127;
+pub const __NR_rt_sigsuspend: u32 = 5128;
+pub const __NR_sigaltstack: u32 = 5129;
+pub const __NR_utime: u32 = 5130;
+pub const __NR_mknod: u32 = 5131;
+pub const __NR_personality: u32 = 5132;
+pub const __NR_ustat: u32 = 5133;
+pub const __NR_statfs: u32 = 5134;
+pub const __NR_fstatfs: u32 = 5135;
+pub const __NR_sysfs: u32 = 5136;
+pub const __NR_getpriority: u32 = 5137;
+pub const __NR_setpriority: u32 = 5138;
+pub const __NR_sched_setparam: u32 = 5139;
+pub const __NR_sched_getparam: u32 = 5140;
+pub const __NR_sched_setscheduler: u32 = 5141;
+pub const __NR_sched_getscheduler: u32 = 5142;
+pub const __NR_sched_get_priority_max: u32 = 5143;
+pub const __NR_sched_get_priority_min: u32 = 5144;
+pub const __NR_sched_rr_get_interval: u32 = 5145;
+pub const __NR_mlock: u32 = 5146;
+pub const __NR_munlock: u32 = 5147;
+pub const __NR_mlockall: u32 = 5148;
+pub const __NR_munlockall: u32 = 5149;
+pub const __NR_vhangup: u32 = 5150;
+pub const __NR_pivot_root: u32 = 5151;
+pub const __NR__sysctl: u32 = 5152;
+pub const __NR_prctl: u32 = 5153;
+pub const __NR_adjtimex: u32 = 5154;
+pub const __NR_setrlimit: u32 = 5155;
+pub const __NR_chroot: u32 = 5156;
+pub const __NR_sync: u32 = 5157;
+pub const __NR_acct: u32 = 5158;
+pub const __NR_settimeofday: u32 = 5159;
+pub const __NR_mount: u32 = 5160;
+pub const __NR_umount2: u32 = 5161;
+pub const __NR_swapon: u32 = 5162;
+pub const __NR_swapoff: u32 = 5163;
+pub const __NR_reboot: u32 = 5164;
+pub const __NR_sethostname: u32 = 5165;
+pub const __NR_setdomainname: u32 = 5166;
+pub const __NR_create_module: u32 = 5167;
+pub const __NR_init_module: u32 = 5168;
+pub const __NR_delete_module: u32 = 5169;
+pub const __NR_get_kernel_syms: u32 = 5170;
+pub const __NR_query_module: u32 = 5171;
+pub const __NR_quotactl: u32 = 5172;
+pub const __NR_nfsservctl: u32 = 5173;
+pub const __NR_getpmsg: u32 = 5174;
+pub const __NR_putpmsg: u32 = 5175;
+pub const __NR_afs_syscall: u32 = 5176;
+pub const __NR_reserved177: u32 = 5177;
+pub const __NR_gettid: u32 = 5178;
+pub const __NR_readahead: u32 = 5179;
+pub const __NR_setxattr: u32 = 5180;
+pub const __NR_lsetxattr: u32 = 5181;
+pub const __NR_fsetxattr: u32 = 5182;
+pub const __NR_getxattr: u32 = 5183;
+pub const __NR_lgetxattr: u32 = 5184;
+pub const __NR_fgetxattr: u32 = 5185;
+pub const __NR_listxattr: u32 = 5186;
+pub const __NR_llistxattr: u32 = 5187;
+pub const __NR_flistxattr: u32 = 5188;
+pub const __NR_removexattr: u32 = 5189;
+pub const __NR_lremovexattr: u32 = 5190;
+pub const __NR_fremovexattr: u32 = 5191;
+pub const __NR_tkill: u32 = 5192;
+pub const __NR_reserved193: u32 = 5193;
+pub const __NR_futex: u32 = 5194;
+pub const __NR_sched_setaffinity: u32 = 5195;
+pub const __NR_sched_getaffinity: u32 = 5196;
+pub const __NR_cacheflush: u32 = 5197;
+pub const __NR_cachectl: u32 = 5198;
+pub const __NR_sysmips: u32 = 5199;
+pub const __NR_io_setup: u32 = 5200;
+pub const __NR_io_destroy: u32 = 5201;
+pub const __NR_io_getevents: u32 = 5202;
+pub const __NR_io_submit: u32 = 5203;
+pub const __NR_io_cancel: u32 = 5204;
+pub const __NR_exit_group: u32 = 5205;
+pub const __NR_lookup_dcookie: u32 = 5206;
+pub const __NR_epoll_create: u32 = 5207;
+pub const __NR_epoll_ctl: u32 = 5208;
+pub const __NR_epoll_wait: u32 = 5209;
+pub const __NR_remap_file_pages: u32 = 5210;
+pub const __NR_rt_sigreturn: u32 = 5211;
+pub const __NR_set_tid_address: u32 = 5212;
+pub const __NR_restart_syscall: u32 = 5213;
+pub const __NR_semtimedop: u32 = 5214;
+pub const __NR_fadvise64: u32 = 5215;
+pub const __NR_timer_create: u32 = 5216;
+pub const __NR_timer_settime: u32 = 5217;
+pub const __NR_timer_gettime: u32 = 5218;
+pub const __NR_timer_getoverrun: u32 = 5219;
+pub const __NR_timer_delete: u32 = 5220;
+pub const __NR_clock_settime: u32 = 5221;
+pub const __NR_clock_gettime: u32 = 5222;
+pub const __NR_clock_getres: u32 = 5223;
+pub const __NR_clock_nanosleep: u32 = 5224;
+pub const __NR_tgkill: u32 = 5225;
+pub const __NR_utimes: u32 = 5226;
+pub const __NR_mbind: u32 = 5227;
+pub const __NR_get_mempolicy: u32 = 5228;
+pub const __NR_set_mempolicy: u32 = 5229;
+pub const __NR_mq_open: u32 = 5230;
+pub const __NR_mq_unlink: u32 = 5231;
+pub const __NR_mq_timedsend: u32 = 5232;
+pub const __NR_mq_timedreceive: u32 = 5233;
+pub const __NR_mq_notify: u32 = 5234;
+pub const __NR_mq_getsetattr: u32 = 5235;
+pub const __NR_vserver: u32 = 5236;
+pub const __NR_waitid: u32 = 5237;
+pub const __NR_add_key: u32 = 5239;
+pub const __NR_request_key: u32 = 5240;
+pub const __NR_keyctl: u32 = 5241;
+pub const __NR_set_thread_area: u32 = 5242;
+pub const __NR_inotify_init: u32 = 5243;
+pub const __NR_inotify_add_watch: u32 = 5244;
+pub const __NR_inotify_rm_watch: u32 = 5245;
+pub const __NR_migrate_pages: u32 = 5246;
+pub const __NR_openat: u32 = 5247;
+pub const __NR_mkdirat: u32 = 5248;
+pub const __NR_mknodat: u32 = 5249;
+pub const __NR_fchownat: u32 = 5250;
+pub const __NR_futimesat: u32 = 5251;
+pub const __NR_newfstatat: u32 = 5252;
+pub const __NR_unlinkat: u32 = 5253;
+pub const __NR_renameat: u32 = 5254;
+pub const __NR_linkat: u32 = 5255;
+pub const __NR_symlinkat: u32 = 5256;
+pub const __NR_readlinkat: u32 = 5257;
+pub const __NR_fchmodat: u32 = 5258;
+pub const __NR_faccessat: u32 = 5259;
+pub const __NR_pselect6: u32 = 5260;
+pub const __NR_ppoll: u32 = 5261;
+pub const __NR_unshare: u32 = 5262;
+pub const __NR_splice: u32 = 5263;
+pub const __NR_sync_file_range: u32 = 5264;
+pub const __NR_tee: u32 = 5265;
+pub const __NR_vmsplice: u32 = 5266;
+pub const __NR_move_pages: u32 = 5267;
+pub const __NR_set_robust_list: u32 = 5268;
+pub const __NR_get_robust_list: u32 = 5269;
+pub const __NR_kexec_load: u32 = 5270;
+pub const __NR_getcpu: u32 = 5271;
+pub const __NR_epoll_pwait: u32 = 5272;
+pub const __NR_ioprio_set: u32 = 5273;
+pub const __NR_ioprio_get: u32 = 5274;
+pub const __NR_utimensat: u32 = 5275;
+pub const __NR_signalfd: u32 = 5276;
+pub const __NR_timerfd: u32 = 5277;
+pub const __NR_eventfd: u32 = 5278;
+pub const __NR_fallocate: u32 = 5279;
+pub const __NR_timerfd_create: u32 = 5280;
+pub const __NR_timerfd_gettime: u32 = 5281;
+pub const __NR_timerfd_settime: u32 = 5282;
+pub const __NR_signalfd4: u32 = 5283;
+pub const __NR_eventfd2: u32 = 5284;
+pub const __NR_epoll_create1: u32 = 5285;
+pub const __NR_dup3: u32 = 5286;
+pub const __NR_pipe2: u32 = 5287;
+pub const __NR_inotify_init1: u32 = 5288;
+pub const __NR_preadv: u32 = 5289;
+pub const __NR_pwritev: u32 = 5290;
+pub const __NR_rt_tgsigqueueinfo: u32 = 5291;
+pub const __NR_perf_event_open: u32 = 5292;
+pub const __NR_accept4: u32 = 5293;
+pub const __NR_recvmmsg: u32 = 5294;
+pub const __NR_fanotify_init: u32 = 5295;
+pub const __NR_fanotify_mark: u32 = 5296;
+pub const __NR_prlimit64: u32 = 5297;
+pub const __NR_name_to_handle_at: u32 = 5298;
+pub const __NR_open_by_handle_at: u32 = 5299;
+pub const __NR_clock_adjtime: u32 = 5300;
+pub const __NR_syncfs: u32 = 5301;
+pub const __NR_sendmmsg: u32 = 5302;
+pub const __NR_setns: u32 = 5303;
+pub const __NR_process_vm_readv: u32 = 5304;
+pub const __NR_process_vm_writev: u32 = 5305;
+pub const __NR_kcmp: u32 = 5306;
+pub const __NR_finit_module: u32 = 5307;
+pub const __NR_getdents64: u32 = 5308;
+pub const __NR_sched_setattr: u32 = 5309;
+pub const __NR_sched_getattr: u32 = 5310;
+pub const __NR_renameat2: u32 = 5311;
+pub const __NR_seccomp: u32 = 5312;
+pub const __NR_getrandom: u32 = 5313;
+pub const __NR_memfd_create: u32 = 5314;
+pub const __NR_bpf: u32 = 5315;
+pub const __NR_execveat: u32 = 5316;
+pub const __NR_userfaultfd: u32 = 5317;
+pub const __NR_membarrier: u32 = 5318;
+pub const __NR_mlock2: u32 = 5319;
+pub const __NR_Linux_syscalls: u32 = 319;
+/// The syscall number corresponding to index 0 of `SYSCALL_NAMES`.
+pub const SYSCALL_NAMES_BASE: u32 = 5000;
@@ -0,0 +1,840 @@
+include!("../shared/general_consts.rs");
+pub const AT_VECTOR_SIZE_ARCH: u32 = 1;
+pub const _MIPS_ISA_MIPS1: u32 = 1;
+pub const _MIPS_ISA_MIPS2: u32 = 2;
+pub const _MIPS_ISA_MIPS3: u32 = 3;
+pub const _MIPS_ISA_MIPS4: u32 = 4;
+pub const _MIPS_ISA_MIPS5: u32 = 5;
+pub const _MIPS_ISA_MIPS32: u32 = 6;
+pub const _MIPS_ISA_MIPS64: u32 = 7;
+pub const _MIPS_SIM_ABI32: u32 = 1;
+pub const _MIPS_SIM_NABI32: u32 = 2;
+pub const _MIPS_SIM_ABI64: u32 = 3;
+pub const O_APPEND: u32 = 8;
+pub const O_DSYNC: u32 = 16;
+pub const O_NONBLOCK: u32 = 128;
+pub const O_CREAT: u32 = 256;
+pub const O_TRUNC: u32 = 512;
+pub const O_EXCL: u32 = 1024;
+pub const O_NOCTTY: u32 = 2048;
+pub const FASYNC: u32 = 4096;
+pub const O_LARGEFILE: u32 = 8192;
+pub const __O_SYNC: u32 = 16384;
+pub const O_SYNC: u32 = 16400;
+pub const O_DIRECT: u32 = 32768;
+pub const F_GETLK: u32 = 14;
+pub const F_SETLK: u32 = 6;
+pub const F_SETLKW: u32 = 7;
+pub const F_SETOWN: u32 = 24;
+pub const F_GETOWN: u32 = 23;
+pub const O_DIRECTORY: u32 = 65536;
+pub const O_NOFOLLOW: u32 = 131072;
+pub const O_NOATIME: u32 = 262144;
+pub const O_CLOEXEC: u32 = 524288;
+pub const O_PATH: u32 = 2097152;
+pub const __O_TMPFILE: u32 = 4194304;
+pub const O_TMPFILE: u32 = 4259840;
+pub const O_TMPFILE_MASK: u32 = 4260096;
+pub const O_NDELAY: u32 = 128;
+pub const F_GETLK64: u32 = 12;
+pub const F_SETLK64: u32 = 13;
+pub const F_SETLKW64: u32 = 14;
+pub const F_RDLCK: u32 = 0;
+pub const F_WRLCK: u32 = 1;
+pub const F_UNLCK: u32 = 2;
+pub const EPOLL_CLOEXEC: u32 = 524288;
+pub const POSIX_FADV_DONTNEED: u32 = 4;
+pub const POSIX_FADV_NOREUSE: u32 = 5;
+pub const _IOC_SIZEBITS: u32 = 13;
+pub const _IOC_DIRBITS: u32 = 3;
+pub const _IOC_NONE: u32 = 1;
+pub const _IOC_WRITE: u32 = 4;
+pub const _IOC_SIZEMASK: u32 = 8191;
+pub const _IOC_DIRMASK: u32 = 7;
+pub const _IOC_DIRSHIFT: u32 = 29;
+pub const IOC_IN: u32 = 2147483648;
+pub const IOC_OUT: u32 = 1073741824;
+pub const IOCSIZE_MASK: u32 = 536805376;
+pub const OPEN_TREE_CLOEXEC: u32 = 524288;
+pub const __BIG_ENDIAN: u32 = 4321;
+pub const PROT_SEM: u32 = 16;
+pub const MAP_RENAME: u32 = 32;
+pub const MAP_AUTOGROW: u32 = 64;
+pub const MAP_LOCAL: u32 = 128;
+pub const MAP_AUTORSRV: u32 = 256;
+pub const MAP_NORESERVE: u32 = 1024;
+pub const MAP_ANONYMOUS: u32 = 2048;
+pub const MAP_GROWSDOWN: u32 = 4096;
+pub const MAP_DENYWRITE: u32 = 8192;
+pub const MAP_EXECUTABLE: u32 = 16384;
+pub const MAP_LOCKED: u32 = 32768;
+pub const MAP_POPULATE: u32 = 65536;
+pub const MAP_NONBLOCK: u32 = 131072;
+pub const MAP_STACK: u32 = 262144;
+pub const MAP_HUGETLB: u32 = 524288;
+pub const MCL_CURRENT: u32 = 1;
+pub const MCL_FUTURE: u32 = 2;
+pub const MCL_ONFAULT: u32 = 4;
+pub const SOL_SOCKET: u32 = 65535;
+pub const SO_REUSEADDR: u32 = 4;
+pub const SO_KEEPALIVE: u32 = 8;
+pub const SO_DONTROUTE: u32 = 16;
+pub const SO_BROADCAST: u32 = 32;
+pub const SO_LINGER: u32 = 128;
+pub const SO_OOBINLINE: u32 = 256;
+pub const SO_REUSEPORT: u32 = 512;
+pub const SO_TYPE: u32 = 4104;
+pub const SO_STYLE: u32 = 4104;
+pub const SO_ERROR: u32 = 4103;
+pub const SO_SNDBUF: u32 = 4097;
+pub const SO_RCVBUF: u32 = 4098;
+pub const SO_SNDLOWAT: u32 = 4099;
+pub const SO_RCVLOWAT: u32 = 4100;
+pub const SO_SNDTIMEO_OLD: u32 = 4101;
+pub const SO_RCVTIMEO_OLD: u32 = 4102;
+pub const SO_ACCEPTCONN: u32 = 4105;
+pub const SO_PROTOCOL: u32 = 4136;
+pub const SO_DOMAIN: u32 = 4137;
+pub const SO_BSDCOMPAT: u32 = 14;
+pub const SO_PASSCRED: u32 = 17;
+pub const SO_PEERCRED: u32 = 18;
+pub const SO_SECURITY_AUTHENTICATION: u32 = 22;
+pub const SO_SECURITY_ENCRYPTION_TRANSPORT: u32 = 23;
+pub const SO_SECURITY_ENCRYPTION_NETWORK: u32 = 24;
+pub const SO_BINDTODEVICE: u32 = 25;
+pub const SO_PEERSEC: u32 = 30;
+pub const SO_SNDBUFFORCE: u32 = 31;
+pub const SO_RCVBUFFORCE: u32 = 33;
+pub const SO_PASSSEC: u32 = 34;
+pub const SO_MARK: u32 = 36;
+pub const SO_RXQ_OVFL: u32 = 40;
+pub const SO_WIFI_STATUS: u32 = 41;
+pub const SCM_WIFI_STATUS: u32 = 41;
+pub const SO_PEEK_OFF: u32 = 42;
+pub const SO_NOFCS: u32 = 43;
+pub const SO_LOCK_FILTER: u32 = 44;
+pub const SO_SELECT_ERR_QUEUE: u32 = 45;
+pub const SO_BUSY_POLL: u32 = 46;
+pub const SO_MAX_PACING_RATE: u32 = 47;
+pub const SO_BPF_EXTENSIONS: u32 = 48;
+pub const SO_INCOMING_CPU: u32 = 49;
+pub const SO_ATTACH_BPF: u32 = 50;
+pub const SO_ATTACH_REUSEPORT_CBPF: u32 = 51;
+pub const SO_ATTACH_REUSEPORT_EBPF: u32 = 52;
+pub const SO_CNX_ADVICE: u32 = 53;
+pub const SCM_TIMESTAMPING_OPT_STATS: u32 = 54;
+pub const SO_MEMINFO: u32 = 55;
+pub const SO_INCOMING_NAPI_ID: u32 = 56;
+pub const SO_COOKIE: u32 = 57;
+pub const SCM_TIMESTAMPING_PKTINFO: u32 = 58;
+pub const SO_PEERGROUPS: u32 = 59;
+pub const SO_ZEROCOPY: u32 = 60;
+pub const SO_TXTIME: u32 = 61;
+pub const SCM_TXTIME: u32 = 61;
+pub const SO_BINDTOIFINDEX: u32 = 62;
+pub const SO_TIMESTAMPNS_OLD: u32 = 35;
+pub const SO_TIMESTAMPING_OLD: u32 = 37;
+pub const SO_TIMESTAMP_NEW: u32 = 63;
+pub const SO_TIMESTAMPNS_NEW: u32 = 64;
+pub const SO_TIMESTAMPING_NEW: u32 = 65;
+pub const SO_RCVTIMEO_NEW: u32 = 66;
+pub const SO_SNDTIMEO_NEW: u32 = 67;
+pub const SO_DETACH_REUSEPORT_BPF: u32 = 68;
+pub const SO_PREFER_BUSY_POLL: u32 = 69;
+pub const SO_BUSY_POLL_BUDGET: u32 = 70;
+pub const SO_TIMESTAMP: u32 = 29;
+pub const SO_TIMESTAMPNS: u32 = 35;
+pub const SO_TIMESTAMPING: u32 = 37;
+pub const SO_RCVTIMEO: u32 = 4102;
+pub const SO_SNDTIMEO: u32 = 4101;
+pub const SCM_TIMESTAMP: u32 = 29;
+pub const SCM_TIMESTAMPNS: u32 = 35;
+pub const SCM_TIMESTAMPING: u32 = 37;
+pub const POLLWRBAND: u32 = 256;
+pub const POLLMSG: u32 = 1024;
+pub const POLLREMOVE: u32 = 4096;
+pub const POLLRDHUP: u32 = 8192;
+pub const RLIMIT_NOFILE: u32 = 5;
+pub const RLIMIT_AS: u32 = 6;
+pub const RLIMIT_RSS: u32 = 7;
+pub const RLIMIT_NPROC: u32 = 8;
+pub const RLIMIT_MEMLOCK: u32 = 9;
+pub const RLIM_INFINITY: i32 = -1;
+pub const _NSIG: u32 = 128;
+pub const SIGEMT: u32 = 7;
+pub const SIGBUS: u32 = 10;
+pub const SIGSYS: u32 = 12;
+pub const SIGUSR1: u32 = 16;
+pub const SIGUSR2: u32 = 17;
+pub const SIGCHLD: u32 = 18;
+pub const SIGCLD: u32 = 18;
+pub const SIGPWR: u32 = 19;
+pub const SIGWINCH: u32 = 20;
+pub const SIGURG: u32 = 21;
+pub const SIGIO: u32 = 22;
+pub const SIGPOLL: u32 = 22;
+pub const SIGSTOP: u32 = 23;
+pub const SIGTSTP: u32 = 24;
+pub const SIGCONT: u32 = 25;
+pub const SIGTTIN: u32 = 26;
+pub const SIGTTOU: u32 = 27;
+pub const SIGVTALRM: u32 = 28;
+pub const SIGPROF: u32 = 29;
+pub const SIGXCPU: u32 = 30;
+pub const SIGXFSZ: u32 = 31;
+pub const SIGRTMAX: u32 = 128;
+pub const SA_ONSTACK: u32 = 134217728;
+pub const SA_RESETHAND: u32 = 2147483648;
+pub const SA_RESTART: u32 = 268435456;
+pub const SA_SIGINFO: u32 = 8;
+pub const SA_NODEFER: u32 = 1073741824;
+pub const SA_NOCLDWAIT: u32 = 65536;
+pub const SA_NOCLDSTOP: u32 = 1;
+pub const SA_NOMASK: u32 = 1073741824;
+pub const SA_ONESHOT: u32 = 2147483648;
+pub const MINSIGSTKSZ: u32 = 2048;
+pub const SIGSTKSZ: u32 = 8192;
+pub const SIG_BLOCK: u32 = 1;
+pub const SIG_UNBLOCK: u32 = 2;
+pub const SIG_SETMASK: u32 = 3;
+pub const EPERM: u32 = 1;
+pub const ENOENT: u32 = 2;
+pub const ESRCH: u32 = 3;
+pub const EINTR: u32 = 4;
+pub const EIO: u32 = 5;
+pub const ENXIO: u32 = 6;
+pub const E2BIG: u32 = 7;
+pub const ENOEXEC: u32 = 8;
+pub const EBADF: u32 = 9;
+pub const ECHILD: u32 = 10;
+pub const EAGAIN: u32 = 11;
+pub const ENOMEM: u32 = 12;
+pub const EACCES: u32 = 13;
+pub const EFAULT: u32 = 14;
+pub const ENOTBLK: u32 = 15;
+pub const EBUSY: u32 = 16;
+pub const EEXIST: u32 = 17;
+pub const EXDEV: u32 = 18;
+pub const ENODEV: u32 = 19;
+pub const ENOTDIR: u32 = 20;
+pub const EISDIR: u32 = 21;
+pub const EINVAL: u32 = 22;
+pub const ENFILE: u32 = 23;
+pub const EMFILE: u32 = 24;
+pub const ENOTTY: u32 = 25;
+pub const ETXTBSY: u32 = 26;
+pub const EFBIG: u32 = 27;
+pub const ENOSPC: u32 = 28;
+pub const ESPIPE: u32 = 29;
+pub const EROFS: u32 = 30;
+pub const EMLINK: u32 = 31;
+pub const EPIPE: u32 = 32;
+pub const EDOM: u32 = 33;
+pub const ERANGE: u32 = 34;
+pub const ENOMSG: u32 = 35;
+pub const EIDRM: u32 = 36;
+pub const ECHRNG: u32 = 37;
+pub const EL2NSYNC: u32 = 38;
+pub const EL3HLT: u32 = 39;
+pub const EL3RST: u32 = 40;
+pub const ELNRNG: u32 = 41;
+pub const EUNATCH: u32 = 42;
+pub const ENOCSI: u32 = 43;
+pub const EL2HLT: u32 = 44;
+pub const EDEADLK: u32 = 45;
+pub const ENOLCK: u32 = 46;
+pub const EBADE: u32 = 50;
+pub const EBADR: u32 = 51;
+pub const EXFULL: u32 = 52;
+pub const ENOANO: u32 = 53;
+pub const EBADRQC: u32 = 54;
+pub const EBADSLT: u32 = 55;
+pub const EDEADLOCK: u32 = 56;
+pub const EBFONT: u32 = 59;
+pub const ENOSTR: u32 = 60;
+pub const ENODATA: u32 = 61;
+pub const ETIME: u32 = 62;
+pub const ENOSR: u32 = 63;
+pub const ENONET: u32 = 64;
+pub const ENOPKG: u32 = 65;
+pub const EREMOTE: u32 = 66;
+pub const ENOLINK: u32 = 67;
+pub const EADV: u32 = 68;
+pub const ESRMNT: u32 = 69;
+pub const ECOMM: u32 = 70;
+pub const EPROTO: u32 = 71;
+pub const EDOTDOT: u32 = 73;
+pub const EMULTIHOP: u32 = 74;
+pub const EBADMSG: u32 = 77;
+pub const ENAMETOOLONG: u32 = 78;
+pub const EOVERFLOW: u32 = 79;
+pub const ENOTUNIQ: u32 = 80;
+pub const EBADFD: u32 = 81;
+pub const EREMCHG: u32 = 82;
+pub const ELIBACC: u32 = 83;
+pub const ELIBBAD: u32 = 84;
+pub const ELIBSCN: u32 = 85;
+pub const ELIBMAX: u32 = 86;
+pub const ELIBEXEC: u32 = 87;
+pub const EILSEQ: u32 = 88;
+pub const ENOSYS: u32 = 89;
+pub const ELOOP: u32 = 90;
+pub const ERESTART: u32 = 91;
+pub const ESTRPIPE: u32 = 92;
+pub const ENOTEMPTY: u32 = 93;
+pub const EUSERS: u32 = 94;
+pub const ENOTSOCK: u32 = 95;
+pub const EDESTADDRREQ: u32 = 96;
+pub const EMSGSIZE: u32 = 97;
+pub const EPROTOTYPE: u32 = 98;
+pub const ENOPROTOOPT: u32 = 99;
+pub const EPROTONOSUPPORT: u32 = 120;
+pub const ESOCKTNOSUPPORT: u32 = 121;
+pub const EOPNOTSUPP: u32 = 122;
+pub const EPFNOSUPPORT: u32 = 123;
+pub const EAFNOSUPPORT: u32 = 124;
+pub const EADDRINUSE: u32 = 125;
+pub const EADDRNOTAVAIL: u32 = 126;
+pub const ENETDOWN: u32 = 127;
+pub const ENETUNREACH: u32 = 128;
+pub const ENETRESET: u32 = 129;
+pub const ECONNABORTED: u32 = 130;
+pub const ECONNRESET: u32 = 131;
+pub const ENOBUFS: u32 = 132;
+pub const EISCONN: u32 = 133;
+pub const ENOTCONN: u32 = 134;
+pub const EUCLEAN: u32 = 135;
+pub const ENOTNAM: u32 = 137;
+pub const ENAVAIL: u32 = 138;
+pub const EISNAM: u32 = 139;
+pub const EREMOTEIO: u32 = 140;
+pub const EINIT: u32 = 141;
+pub const EREMDEV: u32 = 142;
+pub const ESHUTDOWN: u32 = 143;
+pub const ETOOMANYREFS: u32 = 144;
+pub const ETIMEDOUT: u32 = 145;
+pub const ECONNREFUSED: u32 = 146;
+pub const EHOSTDOWN: u32 = 147;
+pub const EHOSTUNREACH: u32 = 148;
+pub const EWOULDBLOCK: u32 = 11;
+pub const EALREADY: u32 = 149;
+pub const EINPROGRESS: u32 = 150;
+pub const ESTALE: u32 = 151;
+pub const ECANCELED: u32 = 158;
+pub const ENOMEDIUM: u32 = 159;
+pub const EMEDIUMTYPE: u32 = 160;
+pub const ENOKEY: u32 = 161;
+pub const EKEYEXPIRED: u32 = 162;
+pub const EKEYREVOKED: u32 = 163;
+pub const EKEYREJECTED: u32 = 164;
+pub const EOWNERDEAD: u32 = 165;
+pub const ENOTRECOVERABLE: u32 = 166;
+pub const ERFKILL: u32 = 167;
+pub const EHWPOISON: u32 = 168;
+pub const EDQUOT: u32 = 1133;
+pub const NCCS: u32 = 23;
+pub const VMIN: u32 = 4;
+pub const VTIME: u32 = 5;
+pub const VEOL2: u32 = 6;
+pub const VSWTC: u32 = 7;
+pub const VSWTCH: u32 = 7;
+pub const VSTART: u32 = 8;
+pub const VSTOP: u32 = 9;
+pub const VSUSP: u32 = 10;
+pub const VREPRINT: u32 = 12;
+pub const VDISCARD: u32 = 13;
+pub const VWERASE: u32 = 14;
+pub const VEOF: u32 = 16;
+pub const VEOL: u32 = 17;
+pub const IUCLC: u32 = 512;
+pub const IXON: u32 = 1024;
+pub const IXOFF: u32 = 4096;
+pub const OLCUC: u32 = 2;
+pub const ONLCR: u32 = 4;
+pub const NLDLY: u32 = 256;
+pub const CRDLY: u32 = 1536;
+pub const CR1: u32 = 512;
+pub const CR2: u32 = 1024;
+pub const CR3: u32 = 1536;
+pub const TABDLY: u32 = 6144;
+pub const TAB1: u32 = 2048;
+pub const TAB2: u32 = 4096;
+pub const TAB3: u32 = 6144;
+pub const XTABS: u32 = 6144;
+pub const BSDLY: u32 = 8192;
+pub const BS1: u32 = 8192;
+pub const VTDLY: u32 = 16384;
+pub const VT1: u32 = 16384;
+pub const FFDLY: u32 = 32768;
+pub const FF1: u32 = 32768;
+pub const CBAUD: u32 = 4111;
+pub const CSIZE: u32 = 48;
+pub const CS6: u32 = 16;
+pub const CS7: u32 = 32;
+pub const CS8: u32 = 48;
+pub const CSTOPB: u32 = 64;
+pub const CREAD: u32 = 128;
+pub const PARENB: u32 = 256;
+pub const PARODD: u32 = 512;
+pub const HUPCL: u32 = 1024;
+pub const CLOCAL: u32 = 2048;
+pub const CBAUDEX: u32 = 4096;
+pub const BOTHER: u32 = 4096;
+pub const B57600: u32 = 4097;
+pub const B115200: u32 = 4098;
+pub const B230400: u32 = 4099;
+pub const B460800: u32 = 4100;
+pub const B500000: u32 = 4101;
+pub const B576000: u32 = 4102;
+pub const B921600: u32 = 4103;
+pub const B1000000: u32 = 4104;
+pub const B1152000: u32 = 4105;
+pub const B1500000: u32 = 4106;
+pub const B2000000: u32 = 4107;
+pub const B2500000: u32 = 4108;
+pub const B3000000: u32 = 4109;
+pub const B3500000: u32 = 4110;
+pub const B4000000: u32 = 4111;
+pub const CIBAUD: u32 = 269418496;
+pub const ISIG: u32 = 1;
+pub const ICANON: u32 = 2;
+pub const XCASE: u32 = 4;
+pub const ECHOE: u32 = 16;
+pub const ECHOK: u32 = 32;
+pub const ECHONL: u32 = 64;
+pub const NOFLSH: u32 = 128;
+pub const IEXTEN: u32 = 256;
+pub const ECHOCTL: u32 = 512;
+pub const ECHOPRT: u32 = 1024;
+pub const ECHOKE: u32 = 2048;
+pub const FLUSHO: u32 = 8192;
+pub const PENDIN: u32 = 16384;
+pub const TOSTOP: u32 = 32768;
+pub const ITOSTOP: u32 = 32768;
+pub const EXTPROC: u32 = 65536;
+pub const TCGETA: u32 = 21505;
+pub const TCSETA: u32 = 21506;
+pub const TCSETAW: u32 = 21507;
+pub const TCSETAF: u32 = 21508;
+pub const TCSBRK: u32 = 21509;
+pub const TCXONC: u32 = 21510;
+pub const TCFLSH: u32 = 21511;
+pub const TCGETS: u32 = 21517;
+pub const TCSETS: u32 = 21518;
+pub const TCSETSW: u32 = 21519;
+pub const TCSETSF: u32 = 21520;
+pub const TIOCEXCL: u32 = 29709;
+pub const TIOCNXCL: u32 = 29710;
+pub const TIOCOUTQ: u32 = 29810;
+pub const TIOCSTI: u32 = 21618;
+pub const TIOCMGET: u32 = 29725;
+pub const TIOCMBIS: u32 = 29723;
+pub const TIOCMBIC: u32 = 29724;
+pub const TIOCMSET: u32 = 29722;
+pub const TIOCPKT: u32 = 21616;
+pub const TIOCNOTTY: u32 = 21617;
+pub const TIOCSETD: u32 = 29697;
+pub const TIOCGETD: u32 = 29696;
+pub const FIOCLEX: u32 = 26113;
+pub const FIONCLEX: u32 = 26114;
+pub const FIOASYNC: u32 = 26237;
+pub const FIONBIO: u32 = 26238;
+pub const FIOQSIZE: u32 = 26239;
+pub const TIOCGLTC: u32 = 29812;
+pub const TIOCSLTC: u32 = 29813;
+pub const FIONREAD: u32 = 18047;
+pub const TIOCINQ: u32 = 18047;
+pub const TIOCGETP: u32 = 29704;
+pub const TIOCSETP: u32 = 29705;
+pub const TIOCSETN: u32 = 29706;
+pub const TIOCSBRK: u32 = 21543;
+pub const TIOCCBRK: u32 = 21544;
+pub const TIOCGSID: u32 = 29718;
+pub const TIOCVHANGUP: u32 = 21559;
+pub const TIOCSCTTY: u32 = 21632;
+pub const TIOCGSOFTCAR: u32 = 21633;
+pub const TIOCSSOFTCAR: u32 = 21634;
+pub const TIOCLINUX: u32 = 21635;
+pub const TIOCGSERIAL: u32 = 21636;
+pub const TIOCSSERIAL: u32 = 21637;
+pub const TCSBRKP: u32 = 21638;
+pub const TIOCSERCONFIG: u32 = 21640;
+pub const TIOCSERGWILD: u32 = 21641;
+pub const TIOCSERSWILD: u32 = 21642;
+pub const TIOCGLCKTRMIOS: u32 = 21643;
+pub const TIOCSLCKTRMIOS: u32 = 21644;
+pub const TIOCSERGSTRUCT: u32 = 21645;
+pub const TIOCSERGETLSR: u32 = 21646;
+pub const TIOCSERGETMULTI: u32 = 21647;
+pub const TIOCSERSETMULTI: u32 = 21648;
+pub const TIOCMIWAIT: u32 = 21649;
+pub const TIOCGICOUNT: u32 = 21650;
+pub const NCC: u32 = 8;
+pub const TIOCM_ST: u32 = 16;
+pub const TIOCM_SR: u32 = 32;
+pub const TIOCM_CTS: u32 = 64;
+pub const TIOCM_CAR: u32 = 256;
+pub const TIOCM_CD: u32 = 256;
+pub const TIOCM_RNG: u32 = 512;
+pub const TIOCM_RI: u32 = 512;
+pub const TIOCM_DSR: u32 = 1024;
+pub const __NR_Linux: u32 = 5000;
+pub const __NR_read: u32 = 5000;
+pub const __NR_write: u32 = 5001;
+pub const __NR_open: u32 = 5002;
+pub const __NR_close: u32 = 5003;
+pub const __NR_stat: u32 = 5004;
+pub const __NR_fstat: u32 = 5005;
+pub const __NR_lstat: u32 = 5006;
+pub const __NR_poll: u32 = 5007;
+pub const __NR_lseek: u32 = 5008;
+pub const __NR_mmap: u32 = 5009;
+pub const __NR_mprotect: u32 = 5010;
+pub const __NR_munmap: u32 = 5011;
+pub const __NR_brk: u32 = 5012;
+pub const __NR_rt_sigaction: u32 = 5013;
+pub const __NR_rt_sigprocmask: u32 = 5014;
+pub const __NR_ioctl: u32 = 5015;
+pub const __NR_pread64: u32 = 5016;
+pub const __NR_pwrite64: u32 = 5017;
+pub const __NR_readv: u32 = 5018;
+pub const __NR_writev: u32 = 5019;
+pub const __NR_access: u32 = 5020;
+pub const __NR_pipe: u32 = 5021;
+pub const __NR__newselect: u32 = 5022;
+pub const __NR_sched_yield: u32 = 5023;
+pub const __NR_mremap: u32 = 5024;
+pub const __NR_msync: u32 = 5025;
+pub const __NR_mincore: u32 = 5026;
+pub const __NR_madvise: u32 = 5027;
+pub const __NR_shmget: u32 = 5028;
+pub const __NR_shmat: u32 = 5029;
+pub const __NR_shmctl: u32 = 5030;
+pub const __NR_dup: u32 = 5031;
+pub const __NR_dup2: u32 = 5032;
+pub const __NR_pause: u32 = 5033;
+pub const __NR_nanosleep: u32 = 5034;
+pub const __NR_getitimer: u32 = 5035;
+pub const __NR_setitimer: u32 = 5036;
+pub const __NR_alarm: u32 = 5037;
+pub const __NR_getpid: u32 = 5038;
+pub const __NR_sendfile: u32 = 5039;
+pub const __NR_socket: u32 = 5040;
+pub const __NR_connect: u32 = 5041;
+pub const __NR_accept: u32 = 5042;
+pub const __NR_sendto: u32 = 5043;
+pub const __NR_recvfrom: u32 = 5044;
+pub const __NR_sendmsg: u32 = 5045;
+pub const __NR_recvmsg: u32 = 5046;
+pub const __NR_shutdown: u32 = 5047;
+pub const __NR_bind: u32 = 5048;
+pub const __NR_listen: u32 = 5049;
+pub const __NR_getsockname: u32 = 5050;
+pub const __NR_getpeername: u32 = 5051;
+pub const __NR_socketpair: u32 = 5052;
+pub const __NR_setsockopt: u32 = 5053;
+pub const __NR_getsockopt: u32 = 5054;
+pub const __NR_clone: u32 = 5055;
+pub const __NR_fork: u32 = 5056;
+pub const __NR_execve: u32 = 5057;
+pub const __NR_exit: u32 = 5058;
+pub const __NR_wait4: u32 = 5059;
+pub const __NR_kill: u32 = 5060;
+pub const __NR_uname: u32 = 5061;
+pub const __NR_semget: u32 = 5062;
+pub const __NR_semop: u32 = 5063;
+pub const __NR_semctl: u32 = 5064;
+pub const __NR_shmdt: u32 = 5065;
+pub const __NR_msgget: u32 = 5066;
+pub const __NR_msgsnd: u32 = 5067;
+pub const __NR_msgrcv: u32 = 5068;
+pub const __NR_msgctl: u32 = 5069;
+pub const __NR_fcntl: u32 = 5070;
+pub const __NR_flock: u32 = 5071;
+pub const __NR_fsync: u32 = 5072;
+pub const __NR_fdatasync: u32 = 5073;
+pub const __NR_truncate: u32 = 5074;
+pub const __NR_ftruncate: u32 = 5075;
+pub const __NR_getdents: u32 = 5076;
+pub const __NR_getcwd: u32 = 5077;
+pub const __NR_chdir: u32 = 5078;
+pub const __NR_fchdir: u32 = 5079;
+pub const __NR_rename: u32 = 5080;
+pub const __NR_mkdir: u32 = 5081;
+pub const __NR_rmdir: u32 = 5082;
+pub const __NR_creat: u32 = 5083;
+pub const __NR_link: u32 = 5084;
+pub const __NR_unlink: u32 = 5085;
+pub const __NR_symlink: u32 = 5086;
+pub const __NR_readlink: u32 = 5087;
+pub const __NR_chmod: u32 = 5088;
+pub const __NR_fchmod: u32 = 5089;
+pub const __NR_chown: u32 = 5090;
+pub const __NR_fchown: u32 = 5091;
+pub const __NR_lchown: u32 = 5092;
+pub const __NR_umask: u32 = 5093;
+pub const __NR_gettimeofday: u32 = 5094;
+pub const __NR_getrlimit: u32 = 5095;
+pub const __NR_getrusage: u32 = 5096;
+pub const __NR_sysinfo: u32 = 5097;
+pub const __NR_times: u32 = 5098;
+pub const __NR_ptrace: u32 = 5099;
+pub const __NR_getuid: u32 = 5100;
+pub const __NR_syslog: u32 = 5101;
+pub const __NR_getgid: u32 = 5102;
+pub const __NR_setuid: u32 = 5103;
+pub const __NR_setgid: u32 = 5104;
+pub const __NR_geteuid: u32 = 5105;
+pub const __NR_getegid: u32 = 5106;
+pub const __NR_setpgid: u32 = 5107;
+pub const __NR_getppid: u32 = 5108;
+pub const __NR_getpgrp: u32 = 5109;
+pub const __NR_setsid: u32 = 5110;
+pub const __NR_setreuid: u32 = 5111;
+pub const __NR_setregid: u32 = 5112;
+pub const __NR_getgroups: u32 = 5113;
+pub const __NR_setgroups: u32 = 5114;
+pub const __NR_setresuid: u32 = 5115;
+pub const __NR_getresuid: u32 = 5116;
+pub const __NR_setresgid: u32 = 5117;
+pub const __NR_getresgid: u32 = 5118;
+pub const __NR_getpgid: u32 = 5119;
+pub const __NR_setfsuid: u32 = 5120;
+pub const __NR_setfsgid: u32 = 5121;
+pub const __NR_getsid: u32 = 5122;
+pub const __NR_capget: u32 = 5123;
+pub const __NR_capset: u32 = 5124;
+pub const __NR_rt_sigpending: u32 = 5125;
+pub const __NR_rt_sigtimedwait: u32 = 5126;
+pub const __NR_rt_sigqueueinfo: u32 = 5127;
+pub const __NR_rt_sigsuspend: u32 = 5128;
+pub const __NR_sigaltstack: u32 = 5129;
+pub const __NR_utime: u32 = 5130;
+pub const __NR_mknod: u32 = 5131;
+pub const __NR_personality: u32 = 5132;
+pub const __NR_ustat: u32 = 5133;
+pub const __NR_statfs: u32 = 5134;
+pub const __NR_fstatfs: u32 = 5135;
+pub const __NR_sysfs: u32 = 5136;
+pub const __NR_getpriority: u32 = 5137;
+pub const __NR_setpriority: u32 = 5138;
+pub const __NR_sched_setparam: u32 = 5139;
+pub const __NR_sched_getparam: u32 = 5140;
+pub const __NR_sched_setscheduler: u32 = 5141;
+pub const __NR_sched_getscheduler: u32 = 5142;
+pub const __NR_sched_get_priority_max: u32 = 5143;
+pub const __NR_sched_get_priority_min: u32 = 5144;
+pub const __NR_sched_rr_get_interval: u32 = 5145;
+pub const __NR_mlock: u32 = 5146;
+pub const __NR_munlock: u32 = 5147;
+pub const __NR_mlockall: u32 = 5148;
+pub const __NR_munlockall: u32 = 5149;
+pub const __NR_vhangup: u32 = 5150;
+pub const __NR_pivot_root: u32 = 5151;
+pub const __NR__sysctl: u32 = 5152;
+pub const __NR_prctl: u32 = 5153;
+pub const __NR_adjtimex: u32 = 5154;
+pub const __NR_setrlimit: u32 = 5155;
+pub const __NR_chroot: u32 = 5156;
+pub const __NR_sync: u32 = 5157;
+pub const __NR_acct: u32 = 5158;
+pub const __NR_settimeofday: u32 = 5159;
+pub const __NR_mount: u32 = 5160;
+pub const __NR_umount2: u32 = 5161;
+pub const __NR_swapon: u32 = 5162;
+pub const __NR_swapoff: u32 = 5163;
+pub const __NR_reboot: u32 = 5164;
+pub const __NR_sethostname: u32 = 5165;
+pub const __NR_setdomainname: u32 = 5166;
+pub const __NR_create_module: u32 = 5167;
+pub const __NR_init_module: u32 = 5168;
+pub const __NR_delete_module: u32 = 5169;
+pub const __NR_get_kernel_syms: u32 = 5170;
+pub const __NR_query_module: u32 = 5171;
+pub const __NR_quotactl: u32 = 5172;
+pub const __NR_nfsservctl: u32 = 5173;
+pub const __NR_getpmsg: u32 = 5174;
+pub const __NR_putpmsg: u32 = 5175;
+pub const __NR_afs_syscall: u32 = 5176;
+pub const __NR_reserved177: u32 = 5177;
+pub const __NR_gettid: u32 = 5178;
+pub const __NR_readahead: u32 = 5179;
+pub const __NR_setxattr: u32 = 5180;
+pub const __NR_lsetxattr: u32 = 5181;
+pub const __NR_fsetxattr: u32 = 5182;
+pub const __NR_getxattr: u32 = 5183;
+pub const __NR_lgetxattr: u32 = 5184;
+pub const __NR_fgetxattr: u32 = 5185;
+pub const __NR_listxattr: u32 = 5186;
+pub const __NR_llistxattr: u32 = 5187;
+pub const __NR_flistxattr: u32 = 5188;
+pub const __NR_removexattr: u32 = 5189;
+pub const __NR_lremovexattr: u32 = 5190;
+pub const __NR_fremovexattr: u32 = 5191;
+pub const __NR_tkill: u32 = 5192;
+pub const __NR_reserved193: u32 = 5193;
+pub const __NR_futex: u32 = 5194;
+pub const __NR_sched_setaffinity: u32 = 5195;
+pub const __NR_sched_getaffinity: u32 = 5196;
+pub const __NR_cacheflush: u32 = 5197;
+pub const __NR_cachectl: u32 = 5198;
+pub const __NR_sysmips: u32 = 5199;
+pub const __NR_io_setup: u32 = 5200;
+pub const __NR_io_destroy: u32 = 5201;
+pub const __NR_io_getevents: u32 = 5202;
+pub const __NR_io_submit: u32 = 5203;
+pub const __NR_io_cancel: u32 = 5204;
+pub const __NR_exit_group: u32 = 5205;
+pub const __NR_lookup_dcookie: u32 = 5206;
+pub const __NR_epoll_create: u32 = 5207;
+pub const __NR_epoll_ctl: u32 = 5208;
+pub const __NR_epoll_wait: u32 = 5209;
+pub const __NR_remap_file_pages: u32 = 5210;
+pub const __NR_rt_sigreturn: u32 = 5211;
+pub const __NR_set_tid_address: u32 = 5212;
+pub const __NR_restart_syscall: u32 = 5213;
+pub const __NR_semtimedop: u32 = 5214;
+pub const __NR_fadvise64: u32 = 5215;
+pub const __NR_timer_create: u32 = 5216;
+pub const __NR_timer_settime: u32 = 5217;
+pub const __NR_timer_gettime: u32 = 5218;
+pub const __NR_timer_getoverrun: u32 = 5219;
+pub const __NR_timer_delete: u32 = 5220;
+pub const __NR_clock_settime: u32 = 5221;
+pub const __NR_clock_gettime: u32 = 5222;
+pub const __NR_clock_getres: u32 = 5223;
+pub const __NR_clock_nanosleep: u32 = 5224;
+pub const __NR_tgkill: u32 = 5225;
+pub const __NR_utimes: u32 = 5226;
+pub const __NR_mbind: u32 = 5227;
+pub const __NR_get_mempolicy: u32 = 5228;
+pub const __NR_set_mempolicy: u32 = 5229;
+pub const __NR_mq_open: u32 = 5230;
+pub const __NR_mq_unlink: u32 = 5231;
+pub const __NR_mq_timedsend: u32 = 5232;
+pub const __NR_mq_timedreceive: u32 = 5233;
+pub const __NR_mq_notify: u32 = 5234;
+pub const __NR_mq_getsetattr: u32 = 5235;
+pub const __NR_vserver: u32 = 5236;
+pub const __NR_waitid: u32 = 5237;
+pub const __NR_add_key: u32 = 5239;
+pub const __NR_request_key: u32 = 5240;
+pub const __NR_keyctl: u32 = 5241;
+pub const __NR_set_thread_area: u32 = 5242;
+pub const __NR_inotify_init: u32 = 5243;
+pub const __NR_inotify_add_watch: u32 = 5244;
+pub const __NR_inotify_rm_watch: u32 = 5245;
+pub const __NR_migrate_pages: u32 = 5246;
+pub const __NR_openat: u32 = 5247;
+pub const __NR_mkdirat: u32 = 5248;
+pub const __NR_mknodat: u32 = 5249;
+pub const __NR_fchownat: u32 = 5250;
+pub const __NR_futimesat: u32 = 5251;
+pub const __NR_newfstatat: u32 = 5252;
+pub const __NR_unlinkat: u32 = 5253;
+pub const __NR_renameat: u32 = 5254;
+pub const __NR_linkat: u32 = 5255;
+pub const __NR_symlinkat: u32 = 5256;
+pub const __NR_readlinkat: u32 = 5257;
+pub const __NR_fchmodat: u32 = 5258;
+pub const __NR_faccessat: u32 = 5259;
+pub const __NR_pselect6: u32 = 5260;
+pub const __NR_ppoll: u32 = 5261;
+pub const __NR_unshare: u32 = 5262;
+pub const __NR_splice: u32 = 5263;
+pub const __NR_sync_file_range: u32 = 5264;
+pub const __NR_tee: u32 = 5265;
+pub const __NR_vmsplice: u32 = 5266;
+pub const __NR_move_pages: u32 = 5267;
+pub const __NR_set_robust_list: u32 = 5268;
+pub const __NR_get_robust_list: u32 = 5269;
+pub const __NR_kexec_load: u32 = 5270;
+pub const __NR_getcpu: u32 = 5271;
+pub const __NR_epoll_pwait: u32 = 5272;
+pub const __NR_ioprio_set: u32 = 5273;
+pub const __NR_ioprio_get: u32 = 5274;
+pub const __NR_utimensat: u32 = 5275;
+pub const __NR_signalfd: u32 = 5276;
+pub const __NR_timerfd: u32 = 5277;
+pub const __NR_eventfd: u32 = 5278;
+pub const __NR_fallocate: u32 = 5279;
+pub const __NR_timerfd_create: u32 = 5280;
+pub const __NR_timerfd_gettime: u32 = 5281;
+pub const __NR_timerfd_settime: u32 = 5282;
+pub const __NR_signalfd4: u32 = 5283;
+pub const __NR_eventfd2: u32 = 5284;
+pub const __NR_epoll_create1: u32 = 5285;
+pub const __NR_dup3: u32 = 5286;
+pub const __NR_pipe2: u32 = 5287;
+pub const __NR_inotify_init1: u32 = 5288;
+pub const __NR_preadv: u32 = 5289;
+pub const __NR_pwritev: u32 = 5290;
+pub const __NR_rt_tgsigqueueinfo: u32 = 5291;
+pub const __NR_perf_event_open: u32 = 5292;
+pub const __NR_accept4: u32 = 5293;
+pub const __NR_recvmmsg: u32 = 5294;
+pub const __NR_fanotify_init: u32 = 5295;
+pub const __NR_fanotify_mark: u32 = 5296;
+pub const __NR_prlimit64: u32 = 5297;
+pub const __NR_name_to_handle_at: u32 = 5298;
+pub const __NR_open_by_handle_at: u32 = 5299;
+pub const __NR_clock_adjtime: u32 = 5300;
+pub const __NR_syncfs: u32 = 5301;
+pub const __NR_sendmmsg: u32 = 5302;
+pub const __NR_setns: u32 = 5303;
+pub const __NR_process_vm_readv: u32 = 5304;
+pub const __NR_process_vm_writev: u32 = 5305;
+pub const __NR_kcmp: u32 = 5306;
+pub const __NR_finit_module: u32 = 5307;
+pub const __NR_getdents64: u32 = 5308;
+pub const __NR_sched_setattr: u32 = 5309;
+pub const __NR_sched_getattr: u32 = 5310;
+pub const __NR_renameat2: u32 = 5311;
+pub const __NR_seccomp: u32 = 5312;
+pub const __NR_getrandom: u32 = 5313;
+pub const __NR_memfd_create: u32 = 5314;
+pub const __NR_bpf: u32 = 5315;
+pub const __NR_execveat: u32 = 5316;
+pub const __NR_userfaultfd: u32 = 5317;
+pub const __NR_membarrier: u32 = 5318;
+pub const __NR_mlock2: u32 = 5319;
+pub const __NR_copy_file_range: u32 = 5320;
+pub const __NR_preadv2: u32 = 5321;
+pub const __NR_pwritev2: u32 = 5322;
+pub const __NR_pkey_mprotect: u32 = 5323;
+pub const __NR_pkey_alloc: u32 = 5324;
+pub const __NR_pkey_free: u32 = 5325;
+pub const __NR_statx: u32 = 5326;
+pub const __NR_rseq: u32 = 5327;
+pub const __NR_io_pgetevents: u32 = 5328;
+pub const __NR_pidfd_send_signal: u32 = 5424;
+pub const __NR_io_uring_setup: u32 = 5425;
+pub const __NR_io_uring_enter: u32 = 5426;
+pub const __NR_io_uring_register: u32 = 5427;
+pub const __NR_open_tree: u32 = 5428;
+pub const __NR_move_mount: u32 = 5429;
+pub const __NR_fsopen: u32 = 5430;
+pub const __NR_fsconfig: u32 = 5431;
+pub const __NR_fsmount: u32 = 5432;
+pub const __NR_fspick: u32 = 5433;
+pub const __NR_pidfd_open: u32 = 5434;
+pub const __NR_clone3: u32 = 5435;
+pub const __NR_close_range: u32 = 5436;
+pub const __NR_openat2: u32 = 5437;
+pub const __NR_pidfd_getfd: u32 = 5438;
+pub const __NR_faccessat2: u32 = 5439;
+pub const __NR_process_madvise: u32 = 5440;
+pub const __NR_epoll_pwait2: u32 = 5441;
+pub const TFD_CLOEXEC: u32 = 524288;
+pub const TFD_NONBLOCK: u32 = 128;
+pub const STAT_HAVE_NSEC: u32 = 1;
+pub const SOCK_STREAM: u32 = 2;
+pub const SOCK_DGRAM: u32 = 1;
+pub const EFD_CLOEXEC: u32 = 524288;
+pub const EFD_NONBLOCK: u32 = 128;
+pub const TFD_SHARED_FCNTL_FLAGS: u32 = 524416;
+pub const TFD_CREATE_FLAGS: u32 = 524416;
+pub const BLKSSZGET: u32 = 536875624;
+pub const BLKPBSZGET: u32 = 536875643;
+/// The syscall number corresponding to index 0 of `SYSCALL_NAMES`.
+pub const SYSCALL_NAMES_BASE: u32 = 5000;
@@ -0,0 +1,2 @@
+include!("../shared/netlink_consts.rs");
+pub const __BITS_PER_LONG: u32 = 64;
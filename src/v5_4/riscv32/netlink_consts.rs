@@ -0,0 +1 @@
+include!("../shared/netlink_consts.rs");
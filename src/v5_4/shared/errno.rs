@@ -0,0 +1,2 @@
+/* automatically generated by rust-bindgen 0.59.1 */
+
@@ -1,137 +1,140 @@
-/* automatically generated by rust-bindgen 0.59.1 */
-
-pub const EPERM: u32 = 1;
-pub const ENOENT: u32 = 2;
-pub const ESRCH: u32 = 3;
-pub const EINTR: u32 = 4;
-pub const EIO: u32 = 5;
-pub const ENXIO: u32 = 6;
-pub const E2BIG: u32 = 7;
-pub const ENOEXEC: u32 = 8;
-pub const EBADF: u32 = 9;
-pub const ECHILD: u32 = 10;
-pub const EAGAIN: u32 = 11;
-pub const ENOMEM: u32 = 12;
-pub const EACCES: u32 = 13;
-pub const EFAULT: u32 = 14;
-pub const ENOTBLK: u32 = 15;
-pub const EBUSY: u32 = 16;
-pub const EEXIST: u32 = 17;
-pub const EXDEV: u32 = 18;
-pub const ENODEV: u32 = 19;
-pub const ENOTDIR: u32 = 20;
-pub const EISDIR: u32 = 21;
-pub const EINVAL: u32 = 22;
-pub const ENFILE: u32 = 23;
-pub const EMFILE: u32 = 24;
-pub const ENOTTY: u32 = 25;
-pub const ETXTBSY: u32 = 26;
-pub const EFBIG: u32 = 27;
-pub const ENOSPC: u32 = 28;
-pub const ESPIPE: u32 = 29;
-pub const EROFS: u32 = 30;
-pub const EMLINK: u32 = 31;
-pub const EPIPE: u32 = 32;
-pub const EDOM: u32 = 33;
-pub const ERANGE: u32 = 34;
-pub const EWOULDBLOCK: u32 = 11;
-pub const EINPROGRESS: u32 = 36;
-pub const EALREADY: u32 = 37;
-pub const ENOTSOCK: u32 = 38;
-pub const EDESTADDRREQ: u32 = 39;
-pub const EMSGSIZE: u32 = 40;
-pub const EPROTOTYPE: u32 = 41;
-pub const ENOPROTOOPT: u32 = 42;
-pub const EPROTONOSUPPORT: u32 = 43;
-pub const ESOCKTNOSUPPORT: u32 = 44;
-pub const EOPNOTSUPP: u32 = 45;
-pub const EPFNOSUPPORT: u32 = 46;
-pub const EAFNOSUPPORT: u32 = 47;
-pub const EADDRINUSE: u32 = 48;
-pub const EADDRNOTAVAIL: u32 = 49;
-pub const ENETDOWN: u32 = 50;
-pub const ENETUNREACH: u32 = 51;
-pub const ENETRESET: u32 = 52;
-pub const ECONNABORTED: u32 = 53;
-pub const ECONNRESET: u32 = 54;
-pub const ENOBUFS: u32 = 55;
-pub const EISCONN: u32 = 56;
-pub const ENOTCONN: u32 = 57;
-pub const ESHUTDOWN: u32 = 58;
-pub const ETOOMANYREFS: u32 = 59;
-pub const ETIMEDOUT: u32 = 60;
-pub const ECONNREFUSED: u32 = 61;
-pub const ELOOP: u32 = 62;
-pub const ENAMETOOLONG: u32 = 63;
-pub const EHOSTDOWN: u32 = 64;
-pub const EHOSTUNREACH: u32 = 65;
-pub const ENOTEMPTY: u32 = 66;
-pub const EPROCLIM: u32 = 67;
-pub const EUSERS: u32 = 68;
-pub const EDQUOT: u32 = 69;
-pub const ESTALE: u32 = 70;
-pub const EREMOTE: u32 = 71;
-pub const ENOSTR: u32 = 72;
-pub const ETIME: u32 = 73;
-pub const ENOSR: u32 = 74;
-pub const ENOMSG: u32 = 75;
-pub const EBADMSG: u32 = 76;
-pub const EIDRM: u32 = 77;
-pub const EDEADLK: u32 = 78;
-pub const ENOLCK: u32 = 79;
-pub const ENONET: u32 = 80;
-pub const ERREMOTE: u32 = 81;
-pub const ENOLINK: u32 = 82;
-pub const EADV: u32 = 83;
-pub const ESRMNT: u32 = 84;
-pub const ECOMM: u32 = 85;
-pub const EPROTO: u32 = 86;
-pub const EMULTIHOP: u32 = 87;
-pub const EDOTDOT: u32 = 88;
-pub const EREMCHG: u32 = 89;
-pub const ENOSYS: u32 = 90;
-pub const ESTRPIPE: u32 = 91;
-pub const EOVERFLOW: u32 = 92;
-pub const EBADFD: u32 = 93;
-pub const ECHRNG: u32 = 94;
-pub const EL2NSYNC: u32 = 95;
-pub const EL3HLT: u32 = 96;
-pub const EL3RST: u32 = 97;
-pub const ELNRNG: u32 = 98;
-pub const EUNATCH: u32 = 99;
-pub const ENOCSI: u32 = 100;
-pub const EL2HLT: u32 = 101;
-pub const EBADE: u32 = 102;
-pub const EBADR: u32 = 103;
-pub const EXFULL: u32 = 104;
-pub const ENOANO: u32 = 105;
-pub const EBADRQC: u32 = 106;
-pub const EBADSLT: u32 = 107;
-pub const EDEADLOCK: u32 = 108;
-pub const EBFONT: u32 = 109;
-pub const ELIBEXEC: u32 = 110;
-pub const ENODATA: u32 = 111;
-pub const ELIBBAD: u32 = 112;
-pub const ENOPKG: u32 = 113;
-pub const ELIBACC: u32 = 114;
-pub const ENOTUNIQ: u32 = 115;
-pub const ERESTART: u32 = 116;
-pub const EUCLEAN: u32 = 117;
-pub const ENOTNAM: u32 = 118;
-pub const ENAVAIL: u32 = 119;
-pub const EISNAM: u32 = 120;
-pub const EREMOTEIO: u32 = 121;
-pub const EILSEQ: u32 = 122;
-pub const ELIBMAX: u32 = 123;
-pub const ELIBSCN: u32 = 124;
-pub const ENOMEDIUM: u32 = 125;
-pub const EMEDIUMTYPE: u32 = 126;
-pub const ECANCELED: u32 = 127;
-pub const ENOKEY: u32 = 128;
-pub const EKEYEXPIRED: u32 = 129;
-pub const EKEYREVOKED: u32 = 130;
-pub const EKEYREJECTED: u32 = 131;
-pub const EOWNERDEAD: u32 = 132;
-pub const ENOTRECOVERABLE: u32 = 133;
-pub const ERFKILL: u32 = 134;
-pub const EHWPOISON: u32 = 135;
+include!("../shared/errno.rs");
+include!("errno_consts.rs");
+/// The errno values and their names, sorted by value, with aliases after the names they alias.
+pub static ERRNO_NAMES: &[(u32, &str)] = &[
+(1, "EPERM"),
+(2, "ENOENT"),
+(3, "ESRCH"),
+(4, "EINTR"),
+(5, "EIO"),
+(6, "ENXIO"),
+(7, "E2BIG"),
+(8, "ENOEXEC"),
+(9, "EBADF"),
+(10, "ECHILD"),
+(11, "EAGAIN"),
+(11, "EWOULDBLOCK"),
+(12, "ENOMEM"),
+(13, "EACCES"),
+(14, "EFAULT"),
+(15, "ENOTBLK"),
+(16, "EBUSY"),
+(17, "EEXIST"),
+(18, "EXDEV"),
+(19, "ENODEV"),
+(20, "ENOTDIR"),
+(21, "EISDIR"),
+(22, "EINVAL"),
+(23, "ENFILE"),
+(24, "EMFILE"),
+(25, "ENOTTY"),
+(26, "ETXTBSY"),
+(27, "EFBIG"),
+(28, "ENOSPC"),
+(29, "ESPIPE"),
+(30, "EROFS"),
+(31, "EMLINK"),
+(32, "EPIPE"),
+(33, "EDOM"),
+(34, "ERANGE"),
+(36, "EINPROGRESS"),
+(37, "EALREADY"),
+(38, "ENOTSOCK"),
+(39, "EDESTADDRREQ"),
+(40, "EMSGSIZE"),
+(41, "EPROTOTYPE"),
+(42, "ENOPROTOOPT"),
+(43, "EPROTONOSUPPORT"),
+(44, "ESOCKTNOSUPPORT"),
+(45, "EOPNOTSUPP"),
+(46, "EPFNOSUPPORT"),
+(47, "EAFNOSUPPORT"),
+(48, "EADDRINUSE"),
+(49, "EADDRNOTAVAIL"),
+(50, "ENETDOWN"),
+(51, "ENETUNREACH"),
+(52, "ENETRESET"),
+(53, "ECONNABORTED"),
+(54, "ECONNRESET"),
+(55, "ENOBUFS"),
+(56, "EISCONN"),
+(57, "ENOTCONN"),
+(58, "ESHUTDOWN"),
+(59, "ETOOMANYREFS"),
+(60, "ETIMEDOUT"),
+(61, "ECONNREFUSED"),
+(62, "ELOOP"),
+(63, "ENAMETOOLONG"),
+(64, "EHOSTDOWN"),
+(65, "EHOSTUNREACH"),
+(66, "ENOTEMPTY"),
+(67, "EPROCLIM"),
+(68, "EUSERS"),
+(69, "EDQUOT"),
+(70, "ESTALE"),
+(71, "EREMOTE"),
+(72, "ENOSTR"),
+(73, "ETIME"),
+(74, "ENOSR"),
+(75, "ENOMSG"),
+(76, "EBADMSG"),
+(77, "EIDRM"),
+(78, "EDEADLK"),
+(79, "ENOLCK"),
+(80, "ENONET"),
+(81, "ERREMOTE"),
+(82, "ENOLINK"),
+(83, "EADV"),
+(84, "ESRMNT"),
+(85, "ECOMM"),
+(86, "EPROTO"),
+(87, "EMULTIHOP"),
+(88, "EDOTDOT"),
+(89, "EREMCHG"),
+(90, "ENOSYS"),
+(91, "ESTRPIPE"),
+(92, "EOVERFLOW"),
+(93, "EBADFD"),
+(94, "ECHRNG"),
+(95, "EL2NSYNC"),
+(96, "EL3HLT"),
+(97, "EL3RST"),
+(98, "ELNRNG"),
+(99, "EUNATCH"),
+(100, "ENOCSI"),
+(101, "EL2HLT"),
+(102, "EBADE"),
+(103, "EBADR"),
+(104, "EXFULL"),
+(105, "ENOANO"),
+(106, "EBADRQC"),
+(107, "EBADSLT"),
+(108, "EDEADLOCK"),
+(109, "EBFONT"),
+(110, "ELIBEXEC"),
+(111, "ENODATA"),
+(112, "ELIBBAD"),
+(113, "ENOPKG"),
+(114, "ELIBACC"),
+(115, "ENOTUNIQ"),
+(116, "ERESTART"),
+(117, "EUCLEAN"),
+(118, "ENOTNAM"),
+(119, "ENAVAIL"),
+(120, "EISNAM"),
+(121, "EREMOTEIO"),
+(122, "EILSEQ"),
+(123, "ELIBMAX"),
+(124, "ELIBSCN"),
+(125, "ENOMEDIUM"),
+(126, "EMEDIUMTYPE"),
+(127, "ECANCELED"),
+(128, "ENOKEY"),
+(129, "EKEYEXPIRED"),
+(130, "EKEYREVOKED"),
+(131, "EKEYREJECTED"),
+(132, "EOWNERDEAD"),
+(133, "ENOTRECOVERABLE"),
+(134, "ERFKILL"),
+(135, "EHWPOISON"),
+];
@@ -0,0 +1,965 @@
+include!("../shared/general_consts.rs");
+pub const AT_ADI_BLKSZ: u32 = 48;
+pub const AT_ADI_NBITS: u32 = 49;
+pub const AT_ADI_UEONADI: u32 = 50;
+pub const AT_VECTOR_SIZE_ARCH: u32 = 4;
+pub const O_APPEND: u32 = 8;
+pub const FASYNC: u32 = 64;
+pub const O_CREAT: u32 = 512;
+pub const O_TRUNC: u32 = 1024;
+pub const O_EXCL: u32 = 2048;
+pub const O_DSYNC: u32 = 8192;
+pub const O_NONBLOCK: u32 = 16384;
+pub const O_NDELAY: u32 = 16388;
+pub const O_NOCTTY: u32 = 32768;
+pub const O_LARGEFILE: u32 = 262144;
+pub const O_DIRECT: u32 = 1048576;
+pub const O_NOATIME: u32 = 2097152;
+pub const O_CLOEXEC: u32 = 4194304;
+pub const __O_SYNC: u32 = 8388608;
+pub const O_SYNC: u32 = 8396800;
+pub const O_PATH: u32 = 16777216;
+pub const __O_TMPFILE: u32 = 33554432;
+pub const F_GETOWN: u32 = 5;
+pub const F_SETOWN: u32 = 6;
+pub const F_GETLK: u32 = 7;
+pub const F_SETLK: u32 = 8;
+pub const F_SETLKW: u32 = 9;
+pub const F_RDLCK: u32 = 1;
+pub const F_WRLCK: u32 = 2;
+pub const F_UNLCK: u32 = 3;
+pub const __BITS_PER_LONG: u32 = 32;
+pub const O_DIRECTORY: u32 = 65536;
+pub const O_NOFOLLOW: u32 = 131072;
+pub const O_TMPFILE: u32 = 33619968;
+pub const O_TMPFILE_MASK: u32 = 33620480;
+pub const F_GETLK64: u32 = 12;
+pub const F_SETLK64: u32 = 13;
+pub const F_SETLKW64: u32 = 14;
+pub const EPOLL_CLOEXEC: u32 = 4194304;
+pub const POSIX_FADV_DONTNEED: u32 = 4;
+pub const POSIX_FADV_NOREUSE: u32 = 5;
+pub const _IOC_SIZEBITS: u32 = 13;
+pub const _IOC_DIRBITS: u32 = 3;
+pub const _IOC_SIZEMASK: u32 = 8191;
+pub const _IOC_XSIZEMASK: u32 = 16383;
+pub const _IOC_DIRMASK: u32 = 7;
+pub const _IOC_DIRSHIFT: u32 = 29;
+pub const _IOC_NONE: u32 = 1;
+pub const _IOC_WRITE: u32 = 4;
+pub const IOC_IN: u32 = 2147483648;
+pub const IOC_OUT: u32 = 1073741824;
+pub const IOCSIZE_MASK: u32 = 1073676288;
+pub const OPEN_TREE_CLOEXEC: u32 = 4194304;
+pub const __BIG_ENDIAN: u32 = 4321;
+pub const ASI_NULL1: u32 = 0;
+pub const ASI_NULL2: u32 = 1;
+pub const ASI_CONTROL: u32 = 2;
+pub const ASI_SEGMAP: u32 = 3;
+pub const ASI_PTE: u32 = 4;
+pub const ASI_HWFLUSHSEG: u32 = 5;
+pub const ASI_HWFLUSHPAGE: u32 = 6;
+pub const ASI_REGMAP: u32 = 6;
+pub const ASI_HWFLUSHCONTEXT: u32 = 7;
+pub const ASI_USERTXT: u32 = 8;
+pub const ASI_KERNELTXT: u32 = 9;
+pub const ASI_USERDATA: u32 = 10;
+pub const ASI_KERNELDATA: u32 = 11;
+pub const ASI_FLUSHSEG: u32 = 12;
+pub const ASI_FLUSHPG: u32 = 13;
+pub const ASI_FLUSHCTX: u32 = 14;
+pub const ASI_M_RES00: u32 = 0;
+pub const ASI_M_UNA01: u32 = 1;
+pub const ASI_M_MXCC: u32 = 2;
+pub const ASI_M_FLUSH_PROBE: u32 = 3;
+pub const ASI_M_MMUREGS: u32 = 4;
+pub const ASI_M_TLBDIAG: u32 = 5;
+pub const ASI_M_DIAGS: u32 = 6;
+pub const ASI_M_IODIAG: u32 = 7;
+pub const ASI_M_USERTXT: u32 = 8;
+pub const ASI_M_KERNELTXT: u32 = 9;
+pub const ASI_M_USERDATA: u32 = 10;
+pub const ASI_M_KERNELDATA: u32 = 11;
+pub const ASI_M_TXTC_TAG: u32 = 12;
+pub const ASI_M_TXTC_DATA: u32 = 13;
+pub const ASI_M_DATAC_TAG: u32 = 14;
+pub const ASI_M_DATAC_DATA: u32 = 15;
+pub const ASI_M_FLUSH_PAGE: u32 = 16;
+pub const ASI_M_FLUSH_SEG: u32 = 17;
+pub const ASI_M_FLUSH_REGION: u32 = 18;
+pub const ASI_M_FLUSH_CTX: u32 = 19;
+pub const ASI_M_FLUSH_USER: u32 = 20;
+pub const ASI_M_BCOPY: u32 = 23;
+pub const ASI_M_IFLUSH_PAGE: u32 = 24;
+pub const ASI_M_IFLUSH_SEG: u32 = 25;
+pub const ASI_M_IFLUSH_REGION: u32 = 26;
+pub const ASI_M_IFLUSH_CTX: u32 = 27;
+pub const ASI_M_IFLUSH_USER: u32 = 28;
+pub const ASI_M_BFILL: u32 = 31;
+pub const ASI_M_BYPASS: u32 = 32;
+pub const ASI_M_FBMEM: u32 = 41;
+pub const ASI_M_VMEUS: u32 = 42;
+pub const ASI_M_VMEPS: u32 = 43;
+pub const ASI_M_VMEUT: u32 = 44;
+pub const ASI_M_VMEPT: u32 = 45;
+pub const ASI_M_SBUS: u32 = 46;
+pub const ASI_M_CTL: u32 = 47;
+pub const ASI_M_FLUSH_IWHOLE: u32 = 49;
+pub const ASI_M_IC_FLCLEAR: u32 = 54;
+pub const ASI_M_DC_FLCLEAR: u32 = 55;
+pub const ASI_M_DCDR: u32 = 57;
+pub const ASI_M_VIKING_TMP1: u32 = 64;
+pub const ASI_M_ACTION: u32 = 76;
+pub const ASI_LEON_NOCACHE: u32 = 1;
+pub const ASI_LEON_DCACHE_MISS: u32 = 1;
+pub const ASI_LEON_CACHEREGS: u32 = 2;
+pub const ASI_LEON_IFLUSH: u32 = 16;
+pub const ASI_LEON_DFLUSH: u32 = 17;
+pub const ASI_LEON_MMUFLUSH: u32 = 24;
+pub const ASI_LEON_MMUREGS: u32 = 25;
+pub const ASI_LEON_BYPASS: u32 = 28;
+pub const ASI_LEON_FLUSH_PAGE: u32 = 16;
+pub const ASI_N: u32 = 4;
+pub const ASI_NL: u32 = 12;
+pub const ASI_AIUP: u32 = 16;
+pub const ASI_AIUS: u32 = 17;
+pub const ASI_AIUPL: u32 = 24;
+pub const ASI_AIUSL: u32 = 25;
+pub const ASI_P: u32 = 128;
+pub const ASI_S: u32 = 129;
+pub const ASI_PNF: u32 = 130;
+pub const ASI_SNF: u32 = 131;
+pub const ASI_PL: u32 = 136;
+pub const ASI_SL: u32 = 137;
+pub const ASI_PNFL: u32 = 138;
+pub const ASI_SNFL: u32 = 139;
+pub const ASI_MCD_PRIV_PRIMARY: u32 = 2;
+pub const ASI_MCD_REAL: u32 = 5;
+pub const ASI_PHYS_USE_EC: u32 = 20;
+pub const ASI_PHYS_BYPASS_EC_E: u32 = 21;
+pub const ASI_BLK_AIUP_4V: u32 = 22;
+pub const ASI_BLK_AIUS_4V: u32 = 23;
+pub const ASI_PHYS_USE_EC_L: u32 = 28;
+pub const ASI_PHYS_BYPASS_EC_E_L: u32 = 29;
+pub const ASI_BLK_AIUP_L_4V: u32 = 30;
+pub const ASI_BLK_AIUS_L_4V: u32 = 31;
+pub const ASI_SCRATCHPAD: u32 = 32;
+pub const ASI_MMU: u32 = 33;
+pub const ASI_BLK_INIT_QUAD_LDD_AIUS: u32 = 35;
+pub const ASI_NUCLEUS_QUAD_LDD: u32 = 36;
+pub const ASI_QUEUE: u32 = 37;
+pub const ASI_QUAD_LDD_PHYS_4V: u32 = 38;
+pub const ASI_NUCLEUS_QUAD_LDD_L: u32 = 44;
+pub const ASI_QUAD_LDD_PHYS_L_4V: u32 = 46;
+pub const ASI_PCACHE_DATA_STATUS: u32 = 48;
+pub const ASI_PCACHE_DATA: u32 = 49;
+pub const ASI_PCACHE_TAG: u32 = 50;
+pub const ASI_PCACHE_SNOOP_TAG: u32 = 51;
+pub const ASI_QUAD_LDD_PHYS: u32 = 52;
+pub const ASI_WCACHE_VALID_BITS: u32 = 56;
+pub const ASI_WCACHE_DATA: u32 = 57;
+pub const ASI_WCACHE_TAG: u32 = 58;
+pub const ASI_WCACHE_SNOOP_TAG: u32 = 59;
+pub const ASI_QUAD_LDD_PHYS_L: u32 = 60;
+pub const ASI_SRAM_FAST_INIT: u32 = 64;
+pub const ASI_CORE_AVAILABLE: u32 = 65;
+pub const ASI_CORE_ENABLE_STAT: u32 = 65;
+pub const ASI_CORE_ENABLE: u32 = 65;
+pub const ASI_XIR_STEERING: u32 = 65;
+pub const ASI_CORE_RUNNING_RW: u32 = 65;
+pub const ASI_CORE_RUNNING_W1S: u32 = 65;
+pub const ASI_CORE_RUNNING_W1C: u32 = 65;
+pub const ASI_CORE_RUNNING_STAT: u32 = 65;
+pub const ASI_CMT_ERROR_STEERING: u32 = 65;
+pub const ASI_DCACHE_INVALIDATE: u32 = 66;
+pub const ASI_DCACHE_UTAG: u32 = 67;
+pub const ASI_DCACHE_SNOOP_TAG: u32 = 68;
+pub const ASI_LSU_CONTROL: u32 = 69;
+pub const ASI_DCU_CONTROL_REG: u32 = 69;
+pub const ASI_DCACHE_DATA: u32 = 70;
+pub const ASI_DCACHE_TAG: u32 = 71;
+pub const ASI_INTR_DISPATCH_STAT: u32 = 72;
+pub const ASI_INTR_RECEIVE: u32 = 73;
+pub const ASI_UPA_CONFIG: u32 = 74;
+pub const ASI_JBUS_CONFIG: u32 = 74;
+pub const ASI_SAFARI_CONFIG: u32 = 74;
+pub const ASI_SAFARI_ADDRESS: u32 = 74;
+pub const ASI_ESTATE_ERROR_EN: u32 = 75;
+pub const ASI_AFSR: u32 = 76;
+pub const ASI_AFAR: u32 = 77;
+pub const ASI_EC_TAG_DATA: u32 = 78;
+pub const ASI_IMMU: u32 = 80;
+pub const ASI_IMMU_TSB_8KB_PTR: u32 = 81;
+pub const ASI_IMMU_TSB_64KB_PTR: u32 = 82;
+pub const ASI_ITLB_DATA_IN: u32 = 84;
+pub const ASI_ITLB_DATA_ACCESS: u32 = 85;
+pub const ASI_ITLB_TAG_READ: u32 = 86;
+pub const ASI_IMMU_DEMAP: u32 = 87;
+pub const ASI_DMMU: u32 = 88;
+pub const ASI_DMMU_TSB_8KB_PTR: u32 = 89;
+pub const ASI_DMMU_TSB_64KB_PTR: u32 = 90;
+pub const ASI_DMMU_TSB_DIRECT_PTR: u32 = 91;
+pub const ASI_DTLB_DATA_IN: u32 = 92;
+pub const ASI_DTLB_DATA_ACCESS: u32 = 93;
+pub const ASI_DTLB_TAG_READ: u32 = 94;
+pub const ASI_DMMU_DEMAP: u32 = 95;
+pub const ASI_IIU_INST_TRAP: u32 = 96;
+pub const ASI_INTR_ID: u32 = 99;
+pub const ASI_CORE_ID: u32 = 99;
+pub const ASI_CESR_ID: u32 = 99;
+pub const ASI_IC_INSTR: u32 = 102;
+pub const ASI_IC_TAG: u32 = 103;
+pub const ASI_IC_STAG: u32 = 104;
+pub const ASI_IC_PRE_DECODE: u32 = 110;
+pub const ASI_IC_NEXT_FIELD: u32 = 111;
+pub const ASI_BRPRED_ARRAY: u32 = 111;
+pub const ASI_BLK_AIUP: u32 = 112;
+pub const ASI_BLK_AIUS: u32 = 113;
+pub const ASI_MCU_CTRL_REG: u32 = 114;
+pub const ASI_EC_DATA: u32 = 116;
+pub const ASI_EC_CTRL: u32 = 117;
+pub const ASI_EC_W: u32 = 118;
+pub const ASI_UDB_ERROR_W: u32 = 119;
+pub const ASI_UDB_CONTROL_W: u32 = 119;
+pub const ASI_INTR_W: u32 = 119;
+pub const ASI_INTR_DATAN_W: u32 = 119;
+pub const ASI_INTR_DISPATCH_W: u32 = 119;
+pub const ASI_BLK_AIUPL: u32 = 120;
+pub const ASI_BLK_AIUSL: u32 = 121;
+pub const ASI_EC_R: u32 = 126;
+pub const ASI_UDBH_ERROR_R: u32 = 127;
+pub const ASI_UDBL_ERROR_R: u32 = 127;
+pub const ASI_UDBH_CONTROL_R: u32 = 127;
+pub const ASI_UDBL_CONTROL_R: u32 = 127;
+pub const ASI_INTR_R: u32 = 127;
+pub const ASI_INTR_DATAN_R: u32 = 127;
+pub const ASI_MCD_PRIMARY: u32 = 144;
+pub const ASI_MCD_ST_BLKINIT_PRIMARY: u32 = 146;
+pub const ASI_PIC: u32 = 176;
+pub const ASI_PST8_P: u32 = 192;
+pub const ASI_PST8_S: u32 = 193;
+pub const ASI_PST16_P: u32 = 194;
+pub const ASI_PST16_S: u32 = 195;
+pub const ASI_PST32_P: u32 = 196;
+pub const ASI_PST32_S: u32 = 197;
+pub const ASI_PST8_PL: u32 = 200;
+pub const ASI_PST8_SL: u32 = 201;
+pub const ASI_PST16_PL: u32 = 202;
+pub const ASI_PST16_SL: u32 = 203;
+pub const ASI_PST32_PL: u32 = 204;
+pub const ASI_PST32_SL: u32 = 205;
+pub const ASI_FL8_P: u32 = 208;
+pub const ASI_FL8_S: u32 = 209;
+pub const ASI_FL16_P: u32 = 210;
+pub const ASI_FL16_S: u32 = 211;
+pub const ASI_FL8_PL: u32 = 216;
+pub const ASI_FL8_SL: u32 = 217;
+pub const ASI_FL16_PL: u32 = 218;
+pub const ASI_FL16_SL: u32 = 219;
+pub const ASI_BLK_COMMIT_P: u32 = 224;
+pub const ASI_BLK_COMMIT_S: u32 = 225;
+pub const ASI_BLK_INIT_QUAD_LDD_P: u32 = 226;
+pub const ASI_BLK_INIT_QUAD_LDD_S: u32 = 227;
+pub const ASI_BLK_P: u32 = 240;
+pub const ASI_BLK_S: u32 = 241;
+pub const ASI_ST_BLKINIT_MRU_P: u32 = 242;
+pub const ASI_ST_BLKINIT_MRU_S: u32 = 243;
+pub const ASI_BLK_PL: u32 = 248;
+pub const ASI_BLK_SL: u32 = 249;
+pub const ASI_ST_BLKINIT_MRU_PL: u32 = 250;
+pub const ASI_ST_BLKINIT_MRU_SL: u32 = 251;
+pub const PROT_SEM: u32 = 8;
+pub const MAP_ANONYMOUS: u32 = 32;
+pub const MAP_POPULATE: u32 = 32768;
+pub const MAP_NONBLOCK: u32 = 65536;
+pub const MAP_STACK: u32 = 131072;
+pub const MAP_HUGETLB: u32 = 262144;
+pub const MAP_SYNC: u32 = 524288;
+pub const MAP_UNINITIALIZED: u32 = 67108864;
+pub const MADV_SOFT_OFFLINE: u32 = 101;
+pub const PROT_ADI: u32 = 16;
+pub const MAP_RENAME: u32 = 32;
+pub const MAP_NORESERVE: u32 = 64;
+pub const MAP_INHERIT: u32 = 128;
+pub const MAP_LOCKED: u32 = 256;
+pub const _MAP_NEW: u32 = 2147483648;
+pub const MAP_GROWSDOWN: u32 = 512;
+pub const MAP_DENYWRITE: u32 = 2048;
+pub const MAP_EXECUTABLE: u32 = 4096;
+pub const MCL_CURRENT: u32 = 8192;
+pub const MCL_FUTURE: u32 = 16384;
+pub const MCL_ONFAULT: u32 = 32768;
+pub const FIOSETOWN: u32 = 35073;
+pub const SIOCSPGRP: u32 = 35074;
+pub const FIOGETOWN: u32 = 35075;
+pub const SIOCGPGRP: u32 = 35076;
+pub const SIOCATMARK: u32 = 35077;
+pub const SOL_SOCKET: u32 = 65535;
+pub const SO_PASSCRED: u32 = 2;
+pub const SO_REUSEADDR: u32 = 4;
+pub const SO_KEEPALIVE: u32 = 8;
+pub const SO_DONTROUTE: u32 = 16;
+pub const SO_BROADCAST: u32 = 32;
+pub const SO_PEERCRED: u32 = 64;
+pub const SO_LINGER: u32 = 128;
+pub const SO_OOBINLINE: u32 = 256;
+pub const SO_REUSEPORT: u32 = 512;
+pub const SO_BSDCOMPAT: u32 = 1024;
+pub const SO_RCVLOWAT: u32 = 2048;
+pub const SO_SNDLOWAT: u32 = 4096;
+pub const SO_RCVTIMEO_OLD: u32 = 8192;
+pub const SO_SNDTIMEO_OLD: u32 = 16384;
+pub const SO_ACCEPTCONN: u32 = 32768;
+pub const SO_SNDBUF: u32 = 4097;
+pub const SO_RCVBUF: u32 = 4098;
+pub const SO_SNDBUFFORCE: u32 = 4106;
+pub const SO_RCVBUFFORCE: u32 = 4107;
+pub const SO_ERROR: u32 = 4103;
+pub const SO_TYPE: u32 = 4104;
+pub const SO_PROTOCOL: u32 = 4136;
+pub const SO_DOMAIN: u32 = 4137;
+pub const SO_BINDTODEVICE: u32 = 13;
+pub const SO_PEERSEC: u32 = 30;
+pub const SO_PASSSEC: u32 = 31;
+pub const SO_MARK: u32 = 34;
+pub const SO_RXQ_OVFL: u32 = 36;
+pub const SO_WIFI_STATUS: u32 = 37;
+pub const SCM_WIFI_STATUS: u32 = 37;
+pub const SO_PEEK_OFF: u32 = 38;
+pub const SO_NOFCS: u32 = 39;
+pub const SO_LOCK_FILTER: u32 = 40;
+pub const SO_SELECT_ERR_QUEUE: u32 = 41;
+pub const SO_BUSY_POLL: u32 = 48;
+pub const SO_MAX_PACING_RATE: u32 = 49;
+pub const SO_BPF_EXTENSIONS: u32 = 50;
+pub const SO_INCOMING_CPU: u32 = 51;
+pub const SO_ATTACH_BPF: u32 = 52;
+pub const SO_ATTACH_REUSEPORT_CBPF: u32 = 53;
+pub const SO_ATTACH_REUSEPORT_EBPF: u32 = 54;
+pub const SO_CNX_ADVICE: u32 = 55;
+pub const SCM_TIMESTAMPING_OPT_STATS: u32 = 56;
+pub const SO_MEMINFO: u32 = 57;
+pub const SO_INCOMING_NAPI_ID: u32 = 58;
+pub const SO_COOKIE: u32 = 59;
+pub const SCM_TIMESTAMPING_PKTINFO: u32 = 60;
+pub const SO_PEERGROUPS: u32 = 61;
+pub const SO_ZEROCOPY: u32 = 62;
+pub const SO_TXTIME: u32 = 63;
+pub const SCM_TXTIME: u32 = 63;
+pub const SO_BINDTOIFINDEX: u32 = 65;
+pub const SO_SECURITY_AUTHENTICATION: u32 = 20481;
+pub const SO_SECURITY_ENCRYPTION_TRANSPORT: u32 = 20482;
+pub const SO_SECURITY_ENCRYPTION_NETWORK: u32 = 20484;
+pub const SO_TIMESTAMPNS_OLD: u32 = 33;
+pub const SO_TIMESTAMPING_OLD: u32 = 35;
+pub const SO_TIMESTAMP_NEW: u32 = 70;
+pub const SO_TIMESTAMPNS_NEW: u32 = 66;
+pub const SO_TIMESTAMPING_NEW: u32 = 67;
+pub const SO_RCVTIMEO_NEW: u32 = 68;
+pub const SO_SNDTIMEO_NEW: u32 = 69;
+pub const SO_DETACH_REUSEPORT_BPF: u32 = 71;
+pub const POLLWRBAND: u32 = 256;
+pub const POLLMSG: u32 = 512;
+pub const POLLREMOVE: u32 = 1024;
+pub const POLLRDHUP: u32 = 2048;
+pub const RLIMIT_NOFILE: u32 = 6;
+pub const RLIMIT_NPROC: u32 = 7;
+pub const RLIM_INFINITY: u32 = 2147483647;
+pub const RLIMIT_RSS: u32 = 5;
+pub const RLIMIT_MEMLOCK: u32 = 8;
+pub const RLIMIT_AS: u32 = 9;
+pub const SUBSIG_STACK: u32 = 0;
+pub const SUBSIG_ILLINST: u32 = 2;
+pub const SUBSIG_PRIVINST: u32 = 3;
+pub const SIGEMT: u32 = 7;
+pub const SUBSIG_TAG: u32 = 10;
+pub const SUBSIG_FPDISABLED: u32 = 1024;
+pub const SUBSIG_FPERROR: u32 = 1028;
+pub const SUBSIG_FPINTOVFL: u32 = 1;
+pub const SUBSIG_FPSTSIG: u32 = 2;
+pub const SUBSIG_IDIVZERO: u32 = 20;
+pub const SUBSIG_FPINEXACT: u32 = 196;
+pub const SUBSIG_FPDIVZERO: u32 = 200;
+pub const SUBSIG_FPUNFLOW: u32 = 204;
+pub const SUBSIG_FPOPERROR: u32 = 208;
+pub const SUBSIG_FPOVFLOW: u32 = 212;
+pub const SIGBUS: u32 = 10;
+pub const SUBSIG_BUSTIMEOUT: u32 = 1;
+pub const SUBSIG_ALIGNMENT: u32 = 2;
+pub const SUBSIG_MISCERROR: u32 = 5;
+pub const SUBSIG_NOMAPPING: u32 = 3;
+pub const SUBSIG_PROTECTION: u32 = 4;
+pub const SUBSIG_SEGERROR: u32 = 5;
+pub const SIGSYS: u32 = 12;
+pub const SIGURG: u32 = 16;
+pub const SIGSTOP: u32 = 17;
+pub const SIGTSTP: u32 = 18;
+pub const SIGCONT: u32 = 19;
+pub const SIGCHLD: u32 = 20;
+pub const SIGTTIN: u32 = 21;
+pub const SIGTTOU: u32 = 22;
+pub const SIGIO: u32 = 23;
+pub const SIGPOLL: u32 = 23;
+pub const SIGXCPU: u32 = 24;
+pub const SIGXFSZ: u32 = 25;
+pub const SIGVTALRM: u32 = 26;
+pub const SIGPROF: u32 = 27;
+pub const SIGWINCH: u32 = 28;
+pub const SIGLOST: u32 = 29;
+pub const SIGPWR: u32 = 29;
+pub const SIGUSR1: u32 = 30;
+pub const SIGUSR2: u32 = 31;
+pub const __OLD_NSIG: u32 = 32;
+pub const __NEW_NSIG: u32 = 64;
+pub const _NSIG_BPW: u32 = 32;
+pub const _NSIG_WORDS: u32 = 2;
+pub const SIGRTMAX: u32 = 64;
+pub const _NSIG: u32 = 32;
+pub const NSIG: u32 = 32;
+pub const _SV_SSTACK: u32 = 1;
+pub const _SV_INTR: u32 = 2;
+pub const _SV_RESET: u32 = 4;
+pub const _SV_IGNCHILD: u32 = 8;
+pub const SA_NOCLDSTOP: u32 = 8;
+pub const SA_STACK: u32 = 1;
+pub const SA_ONSTACK: u32 = 1;
+pub const SA_RESTART: u32 = 2;
+pub const SA_ONESHOT: u32 = 4;
+pub const SA_NODEFER: u32 = 32;
+pub const SA_NOCLDWAIT: u32 = 256;
+pub const SA_SIGINFO: u32 = 512;
+pub const SA_NOMASK: u32 = 32;
+pub const SIG_BLOCK: u32 = 1;
+pub const SIG_UNBLOCK: u32 = 2;
+pub const SIG_SETMASK: u32 = 4;
+pub const MINSIGSTKSZ: u32 = 4096;
+pub const SIGSTKSZ: u32 = 16384;
+pub const SI_NOINFO: u32 = 32767;
+pub const TIOCLINUX: u32 = 21532;
+pub const TIOCGSERIAL: u32 = 21534;
+pub const TIOCSSERIAL: u32 = 21535;
+pub const TCSBRKP: u32 = 21541;
+pub const TIOCSERCONFIG: u32 = 21587;
+pub const TIOCSERGWILD: u32 = 21588;
+pub const TIOCSERSWILD: u32 = 21589;
+pub const TIOCGLCKTRMIOS: u32 = 21590;
+pub const TIOCSLCKTRMIOS: u32 = 21591;
+pub const TIOCSERGSTRUCT: u32 = 21592;
+pub const TIOCSERGETLSR: u32 = 21593;
+pub const TIOCSERGETMULTI: u32 = 21594;
+pub const TIOCSERSETMULTI: u32 = 21595;
+pub const TIOCMIWAIT: u32 = 21596;
+pub const TIOCGICOUNT: u32 = 21597;
+pub const NCC: u32 = 8;
+pub const NCCS: u32 = 17;
+pub const VEOF: u32 = 4;
+pub const VEOL: u32 = 5;
+pub const VEOL2: u32 = 6;
+pub const VSWTC: u32 = 7;
+pub const VSTART: u32 = 8;
+pub const VSTOP: u32 = 9;
+pub const VSUSP: u32 = 10;
+pub const VDSUSP: u32 = 11;
+pub const VREPRINT: u32 = 12;
+pub const VDISCARD: u32 = 13;
+pub const VWERASE: u32 = 14;
+pub const VMIN: u32 = 4;
+pub const VTIME: u32 = 5;
+pub const IUCLC: u32 = 512;
+pub const IXON: u32 = 1024;
+pub const IXOFF: u32 = 4096;
+pub const OLCUC: u32 = 2;
+pub const ONLCR: u32 = 4;
+pub const NLDLY: u32 = 256;
+pub const CRDLY: u32 = 1536;
+pub const CR1: u32 = 512;
+pub const CR2: u32 = 1024;
+pub const CR3: u32 = 1536;
+pub const TABDLY: u32 = 6144;
+pub const TAB1: u32 = 2048;
+pub const TAB2: u32 = 4096;
+pub const TAB3: u32 = 6144;
+pub const XTABS: u32 = 6144;
+pub const BSDLY: u32 = 8192;
+pub const BS1: u32 = 8192;
+pub const VTDLY: u32 = 16384;
+pub const VT1: u32 = 16384;
+pub const FFDLY: u32 = 32768;
+pub const FF1: u32 = 32768;
+pub const PAGEOUT: u32 = 65536;
+pub const WRAP: u32 = 131072;
+pub const CBAUD: u32 = 4111;
+pub const CSIZE: u32 = 48;
+pub const CS6: u32 = 16;
+pub const CS7: u32 = 32;
+pub const CS8: u32 = 48;
+pub const CSTOPB: u32 = 64;
+pub const CREAD: u32 = 128;
+pub const PARENB: u32 = 256;
+pub const PARODD: u32 = 512;
+pub const HUPCL: u32 = 1024;
+pub const CLOCAL: u32 = 2048;
+pub const CBAUDEX: u32 = 4096;
+pub const BOTHER: u32 = 4096;
+pub const B57600: u32 = 4097;
+pub const B115200: u32 = 4098;
+pub const B230400: u32 = 4099;
+pub const B460800: u32 = 4100;
+pub const B76800: u32 = 4101;
+pub const B153600: u32 = 4102;
+pub const B307200: u32 = 4103;
+pub const B614400: u32 = 4104;
+pub const B921600: u32 = 4105;
+pub const B500000: u32 = 4106;
+pub const B576000: u32 = 4107;
+pub const B1000000: u32 = 4108;
+pub const B1152000: u32 = 4109;
+pub const B1500000: u32 = 4110;
+pub const B2000000: u32 = 4111;
+pub const CIBAUD: u32 = 269418496;
+pub const ISIG: u32 = 1;
+pub const ICANON: u32 = 2;
+pub const XCASE: u32 = 4;
+pub const ECHOE: u32 = 16;
+pub const ECHOK: u32 = 32;
+pub const ECHONL: u32 = 64;
+pub const NOFLSH: u32 = 128;
+pub const TOSTOP: u32 = 256;
+pub const ECHOCTL: u32 = 512;
+pub const ECHOPRT: u32 = 1024;
+pub const ECHOKE: u32 = 2048;
+pub const DEFECHO: u32 = 4096;
+pub const FLUSHO: u32 = 8192;
+pub const PENDIN: u32 = 16384;
+pub const IEXTEN: u32 = 32768;
+pub const EXTPROC: u32 = 65536;
+pub const TIOCM_ST: u32 = 8;
+pub const TIOCM_SR: u32 = 16;
+pub const TIOCM_CTS: u32 = 32;
+pub const TIOCM_CAR: u32 = 64;
+pub const TIOCM_RNG: u32 = 128;
+pub const TIOCM_DSR: u32 = 256;
+pub const TIOCM_CD: u32 = 64;
+pub const TIOCM_RI: u32 = 128;
+pub const TCSANOW: u32 = 0;
+pub const TCSADRAIN: u32 = 1;
+pub const TCSAFLUSH: u32 = 2;
+pub const __NR_restart_syscall: u32 = 0;
+pub const __NR_exit: u32 = 1;
+pub const __NR_fork: u32 = 2;
+pub const __NR_read: u32 = 3;
+pub const __NR_write: u32 = 4;
+pub const __NR_open: u32 = 5;
+pub const __NR_close: u32 = 6;
+pub const __NR_wait4: u32 = 7;
+pub const __NR_creat: u32 = 8;
+pub const __NR_link: u32 = 9;
+pub const __NR_unlink: u32 = 10;
+pub const __NR_execv: u32 = 11;
+pub const __NR_chdir: u32 = 12;
+pub const __NR_chown: u32 = 13;
+pub const __NR_mknod: u32 = 14;
+pub const __NR_chmod: u32 = 15;
+pub const __NR_lchown: u32 = 16;
+pub const __NR_brk: u32 = 17;
+pub const __NR_perfctr: u32 = 18;
+pub const __NR_lseek: u32 = 19;
+pub const __NR_getpid: u32 = 20;
+pub const __NR_capget: u32 = 21;
+pub const __NR_capset: u32 = 22;
+pub const __NR_setuid: u32 = 23;
+pub const __NR_getuid: u32 = 24;
+pub const __NR_vmsplice: u32 = 25;
+pub const __NR_ptrace: u32 = 26;
+pub const __NR_alarm: u32 = 27;
+pub const __NR_sigaltstack: u32 = 28;
+pub const __NR_pause: u32 = 29;
+pub const __NR_utime: u32 = 30;
+pub const __NR_lchown32: u32 = 31;
+pub const __NR_fchown32: u32 = 32;
+pub const __NR_access: u32 = 33;
+pub const __NR_nice: u32 = 34;
+pub const __NR_chown32: u32 = 35;
+pub const __NR_sync: u32 = 36;
+pub const __NR_kill: u32 = 37;
+pub const __NR_stat: u32 = 38;
+pub const __NR_sendfile: u32 = 39;
+pub const __NR_lstat: u32 = 40;
+pub const __NR_dup: u32 = 41;
+pub const __NR_pipe: u32 = 42;
+pub const __NR_times: u32 = 43;
+pub const __NR_getuid32: u32 = 44;
+pub const __NR_umount2: u32 = 45;
+pub const __NR_setgid: u32 = 46;
+pub const __NR_getgid: u32 = 47;
+pub const __NR_signal: u32 = 48;
+pub const __NR_geteuid: u32 = 49;
+pub const __NR_getegid: u32 = 50;
+pub const __NR_acct: u32 = 51;
+pub const __NR_getgid32: u32 = 53;
+pub const __NR_ioctl: u32 = 54;
+pub const __NR_reboot: u32 = 55;
+pub const __NR_mmap2: u32 = 56;
+pub const __NR_symlink: u32 = 57;
+pub const __NR_readlink: u32 = 58;
+pub const __NR_execve: u32 = 59;
+pub const __NR_umask: u32 = 60;
+pub const __NR_chroot: u32 = 61;
+pub const __NR_fstat: u32 = 62;
+pub const __NR_fstat64: u32 = 63;
+pub const __NR_getpagesize: u32 = 64;
+pub const __NR_msync: u32 = 65;
+pub const __NR_vfork: u32 = 66;
+pub const __NR_pread64: u32 = 67;
+pub const __NR_pwrite64: u32 = 68;
+pub const __NR_geteuid32: u32 = 69;
+pub const __NR_getegid32: u32 = 70;
+pub const __NR_mmap: u32 = 71;
+pub const __NR_setreuid32: u32 = 72;
+pub const __NR_munmap: u32 = 73;
+pub const __NR_mprotect: u32 = 74;
+pub const __NR_madvise: u32 = 75;
+pub const __NR_vhangup: u32 = 76;
+pub const __NR_truncate64: u32 = 77;
+pub const __NR_mincore: u32 = 78;
+pub const __NR_getgroups: u32 = 79;
+pub const __NR_setgroups: u32 = 80;
+pub const __NR_getpgrp: u32 = 81;
+pub const __NR_setgroups32: u32 = 82;
+pub const __NR_setitimer: u32 = 83;
+pub const __NR_ftruncate64: u32 = 84;
+pub const __NR_swapon: u32 = 85;
+pub const __NR_getitimer: u32 = 86;
+pub const __NR_setuid32: u32 = 87;
+pub const __NR_sethostname: u32 = 88;
+pub const __NR_setgid32: u32 = 89;
+pub const __NR_dup2: u32 = 90;
+pub const __NR_setfsuid32: u32 = 91;
+pub const __NR_fcntl: u32 = 92;
+pub const __NR_select: u32 = 93;
+pub const __NR_setfsgid32: u32 = 94;
+pub const __NR_fsync: u32 = 95;
+pub const __NR_setpriority: u32 = 96;
+pub const __NR_socket: u32 = 97;
+pub const __NR_connect: u32 = 98;
+pub const __NR_accept: u32 = 99;
+pub const __NR_getpriority: u32 = 100;
+pub const __NR_rt_sigreturn: u32 = 101;
+pub const __NR_rt_sigaction: u32 = 102;
+pub const __NR_rt_sigprocmask: u32 = 103;
+pub const __NR_rt_sigpending: u32 = 104;
+pub const __NR_rt_sigtimedwait: u32 = 105;
+pub const __NR_rt_sigqueueinfo: u32 = 106;
+pub const __NR_rt_sigsuspend: u32 = 107;
+pub const __NR_setresuid32: u32 = 108;
+pub const __NR_getresuid32: u32 = 109;
+pub const __NR_setresgid32: u32 = 110;
+pub const __NR_getresgid32: u32 = 111;
+pub const __NR_setregid32: u32 = 112;
+pub const __NR_recvmsg: u32 = 113;
+pub const __NR_sendmsg: u32 = 114;
+pub const __NR_getgroups32: u32 = 115;
+pub const __NR_gettimeofday: u32 = 116;
+pub const __NR_getrusage: u32 = 117;
+pub const __NR_getsockopt: u32 = 118;
+pub const __NR_getcwd: u32 = 119;
+pub const __NR_readv: u32 = 120;
+pub const __NR_writev: u32 = 121;
+pub const __NR_settimeofday: u32 = 122;
+pub const __NR_fchown: u32 = 123;
+pub const __NR_fchmod: u32 = 124;
+pub const __NR_recvfrom: u32 = 125;
+pub const __NR_setreuid: u32 = 126;
+pub const __NR_setregid: u32 = 127;
+pub const __NR_rename: u32 = 128;
+pub const __NR_truncate: u32 = 129;
+pub const __NR_ftruncate: u32 = 130;
+pub const __NR_flock: u32 = 131;
+pub const __NR_lstat64: u32 = 132;
+pub const __NR_sendto: u32 = 133;
+pub const __NR_shutdown: u32 = 134;
+pub const __NR_socketpair: u32 = 135;
+pub const __NR_mkdir: u32 = 136;
+pub const __NR_rmdir: u32 = 137;
+pub const __NR_utimes: u32 = 138;
+pub const __NR_stat64: u32 = 139;
+pub const __NR_sendfile64: u32 = 140;
+pub const __NR_getpeername: u32 = 141;
+pub const __NR_futex: u32 = 142;
+pub const __NR_gettid: u32 = 143;
+pub const __NR_getrlimit: u32 = 144;
+pub const __NR_setrlimit: u32 = 145;
+pub const __NR_pivot_root: u32 = 146;
+pub const __NR_prctl: u32 = 147;
+pub const __NR_pciconfig_read: u32 = 148;
+pub const __NR_pciconfig_write: u32 = 149;
+pub const __NR_getsockname: u32 = 150;
+pub const __NR_inotify_init: u32 = 151;
+pub const __NR_inotify_add_watch: u32 = 152;
+pub const __NR_poll: u32 = 153;
+pub const __NR_getdents64: u32 = 154;
+pub const __NR_fcntl64: u32 = 155;
+pub const __NR_inotify_rm_watch: u32 = 156;
+pub const __NR_statfs: u32 = 157;
+pub const __NR_fstatfs: u32 = 158;
+pub const __NR_umount: u32 = 159;
+pub const __NR_sched_set_affinity: u32 = 160;
+pub const __NR_sched_get_affinity: u32 = 161;
+pub const __NR_getdomainname: u32 = 162;
+pub const __NR_setdomainname: u32 = 163;
+pub const __NR_quotactl: u32 = 165;
+pub const __NR_set_tid_address: u32 = 166;
+pub const __NR_mount: u32 = 167;
+pub const __NR_ustat: u32 = 168;
+pub const __NR_setxattr: u32 = 169;
+pub const __NR_lsetxattr: u32 = 170;
+pub const __NR_fsetxattr: u32 = 171;
+pub const __NR_getxattr: u32 = 172;
+pub const __NR_lgetxattr: u32 = 173;
+pub const __NR_getdents: u32 = 174;
+pub const __NR_setsid: u32 = 175;
+pub const __NR_fchdir: u32 = 176;
+pub const __NR_fgetxattr: u32 = 177;
+pub const __NR_listxattr: u32 = 178;
+pub const __NR_llistxattr: u32 = 179;
+pub const __NR_flistxattr: u32 = 180;
+pub const __NR_removexattr: u32 = 181;
+pub const __NR_lremovexattr: u32 = 182;
+pub const __NR_sigpending: u32 = 183;
+pub const __NR_query_module: u32 = 184;
+pub const __NR_setpgid: u32 = 185;
+pub const __NR_fremovexattr: u32 = 186;
+pub const __NR_tkill: u32 = 187;
+pub const __NR_exit_group: u32 = 188;
+pub const __NR_uname: u32 = 189;
+pub const __NR_init_module: u32 = 190;
+pub const __NR_personality: u32 = 191;
+pub const __NR_remap_file_pages: u32 = 192;
+pub const __NR_epoll_create: u32 = 193;
+pub const __NR_epoll_ctl: u32 = 194;
+pub const __NR_epoll_wait: u32 = 195;
+pub const __NR_ioprio_set: u32 = 196;
+pub const __NR_getppid: u32 = 197;
+pub const __NR_sigaction: u32 = 198;
+pub const __NR_sgetmask: u32 = 199;
+pub const __NR_ssetmask: u32 = 200;
+pub const __NR_sigsuspend: u32 = 201;
+pub const __NR_oldlstat: u32 = 202;
+pub const __NR_uselib: u32 = 203;
+pub const __NR_readdir: u32 = 204;
+pub const __NR_readahead: u32 = 205;
+pub const __NR_socketcall: u32 = 206;
+pub const __NR_syslog: u32 = 207;
+pub const __NR_lookup_dcookie: u32 = 208;
+pub const __NR_fadvise64: u32 = 209;
+pub const __NR_fadvise64_64: u32 = 210;
+pub const __NR_tgkill: u32 = 211;
+pub const __NR_waitpid: u32 = 212;
+pub const __NR_swapoff: u32 = 213;
+pub const __NR_sysinfo: u32 = 214;
+pub const __NR_ipc: u32 = 215;
+pub const __NR_sigreturn: u32 = 216;
+pub const __NR_clone: u32 = 217;
+pub const __NR_ioprio_get: u32 = 218;
+pub const __NR_adjtimex: u32 = 219;
+pub const __NR_sigprocmask: u32 = 220;
+pub const __NR_create_module: u32 = 221;
+pub const __NR_delete_module: u32 = 222;
+pub const __NR_get_kernel_syms: u32 = 223;
+pub const __NR_getpgid: u32 = 224;
+pub const __NR_bdflush: u32 = 225;
+pub const __NR_sysfs: u32 = 226;
+pub const __NR_afs_syscall: u32 = 227;
+pub const __NR_setfsuid: u32 = 228;
+pub const __NR_setfsgid: u32 = 229;
+pub const __NR__newselect: u32 = 230;
+pub const __NR_time: u32 = 231;
+pub const __NR_splice: u32 = 232;
+pub const __NR_stime: u32 = 233;
+pub const __NR_statfs64: u32 = 234;
+pub const __NR_fstatfs64: u32 = 235;
+pub const __NR__llseek: u32 = 236;
+pub const __NR_mlock: u32 = 237;
+pub const __NR_munlock: u32 = 238;
+pub const __NR_mlockall: u32 = 239;
+pub const __NR_munlockall: u32 = 240;
+pub const __NR_sched_setparam: u32 = 241;
+pub const __NR_sched_getparam: u32 = 242;
+pub const __NR_sched_setscheduler: u32 = 243;
+pub const __NR_sched_getscheduler: u32 = 244;
+pub const __NR_sched_yield: u32 = 245;
+pub const __NR_sched_get_priority_max: u32 = 246;
+pub const __NR_sched_get_priority_min: u32 = 247;
+pub const __NR_sched_rr_get_interval: u32 = 248;
+pub const __NR_nanosleep: u32 = 249;
+pub const __NR_mremap: u32 = 250;
+pub const __NR__sysctl: u32 = 251;
+pub const __NR_getsid: u32 = 252;
+pub const __NR_fdatasync: u32 = 253;
+pub const __NR_nfsservctl: u32 = 254;
+pub const __NR_sync_file_range: u32 = 255;
+pub const __NR_clock_settime: u32 = 256;
+pub const __NR_clock_gettime: u32 = 257;
+pub const __NR_clock_getres: u32 = 258;
+pub const __NR_clock_nanosleep: u32 = 259;
+pub const __NR_sched_getaffinity: u32 = 260;
+pub const __NR_sched_setaffinity: u32 = 261;
+pub const __NR_timer_settime: u32 = 262;
+pub const __NR_timer_gettime: u32 = 263;
+pub const __NR_timer_getoverrun: u32 = 264;
+pub const __NR_timer_delete: u32 = 265;
+pub const __NR_timer_create: u32 = 266;
+pub const __NR_vserver: u32 = 267;
+pub const __NR_io_setup: u32 = 268;
+pub const __NR_io_destroy: u32 = 269;
+pub const __NR_io_submit: u32 = 270;
+pub const __NR_io_cancel: u32 = 271;
+pub const __NR_io_getevents: u32 = 272;
+pub const __NR_mq_open: u32 = 273;
+pub const __NR_mq_unlink: u32 = 274;
+pub const __NR_mq_timedsend: u32 = 275;
+pub const __NR_mq_timedreceive: u32 = 276;
+pub const __NR_mq_notify: u32 = 277;
+pub const __NR_mq_getsetattr: u32 = 278;
+pub const __NR_waitid: u32 = 279;
+pub const __NR_tee: u32 = 280;
+pub const __NR_add_key: u32 = 281;
+pub const __NR_request_key: u32 = 282;
+pub const __NR_keyctl: u32 = 283;
+pub const __NR_openat: u32 = 284;
+pub const __NR_mkdirat: u32 = 285;
+pub const __NR_mknodat: u32 = 286;
+pub const __NR_fchownat: u32 = 287;
+pub const __NR_futimesat: u32 = 288;
+pub const __NR_fstatat64: u32 = 289;
+pub const __NR_unlinkat: u32 = 290;
+pub const __NR_renameat: u32 = 291;
+pub const __NR_linkat: u32 = 292;
+pub const __NR_symlinkat: u32 = 293;
+pub const __NR_readlinkat: u32 = 294;
+pub const __NR_fchmodat: u32 = 295;
+pub const __NR_faccessat: u32 = 296;
+pub const __NR_pselect6: u32 = 297;
+pub const __NR_ppoll: u32 = 298;
+pub const __NR_unshare: u32 = 299;
+pub const __NR_set_robust_list: u32 = 300;
+pub const __NR_get_robust_list: u32 = 301;
+pub const __NR_migrate_pages: u32 = 302;
+pub const __NR_mbind: u32 = 303;
+pub const __NR_get_mempolicy: u32 = 304;
+pub const __NR_set_mempolicy: u32 = 305;
+pub const __NR_kexec_load: u32 = 306;
+pub const __NR_move_pages: u32 = 307;
+pub const __NR_getcpu: u32 = 308;
+pub const __NR_epoll_pwait: u32 = 309;
+pub const __NR_utimensat: u32 = 310;
+pub const __NR_signalfd: u32 = 311;
+pub const __NR_timerfd_create: u32 = 312;
+pub const __NR_eventfd: u32 = 313;
+pub const __NR_fallocate: u32 = 314;
+pub const __NR_timerfd_settime: u32 = 315;
+pub const __NR_timerfd_gettime: u32 = 316;
+pub const __NR_signalfd4: u32 = 317;
+pub const __NR_eventfd2: u32 = 318;
+pub const __NR_epoll_create1: u32 = 319;
+pub const __NR_dup3: u32 = 320;
+pub const __NR_pipe2: u32 = 321;
+pub const __NR_inotify_init1: u32 = 322;
+pub const __NR_accept4: u32 = 323;
+pub const __NR_preadv: u32 = 324;
+pub const __NR_pwritev: u32 = 325;
+pub const __NR_rt_tgsigqueueinfo: u32 = 326;
+pub const __NR_perf_event_open: u32 = 327;
+pub const __NR_recvmmsg: u32 = 328;
+pub const __NR_fanotify_init: u32 = 329;
+pub const __NR_fanotify_mark: u32 = 330;
+pub const __NR_prlimit64: u32 = 331;
+pub const __NR_name_to_handle_at: u32 = 332;
+pub const __NR_open_by_handle_at: u32 = 333;
+pub const __NR_clock_adjtime: u32 = 334;
+pub const __NR_syncfs: u32 = 335;
+pub const __NR_sendmmsg: u32 = 336;
+pub const __NR_setns: u32 = 337;
+pub const __NR_process_vm_readv: u32 = 338;
+pub const __NR_process_vm_writev: u32 = 339;
+pub const __NR_kern_features: u32 = 340;
+pub const __NR_kcmp: u32 = 341;
+pub const __NR_finit_module: u32 = 342;
+pub const __NR_sched_setattr: u32 = 343;
+pub const __NR_sched_getattr: u32 = 344;
+pub const __NR_renameat2: u32 = 345;
+pub const __NR_seccomp: u32 = 346;
+pub const __NR_getrandom: u32 = 347;
+pub const __NR_memfd_create: u32 = 348;
+pub const __NR_bpf: u32 = 349;
+pub const __NR_execveat: u32 = 350;
+pub const __NR_membarrier: u32 = 351;
+pub const __NR_userfaultfd: u32 = 352;
+pub const __NR_bind: u32 = 353;
+pub const __NR_listen: u32 = 354;
+pub const __NR_setsockopt: u32 = 355;
+pub const __NR_mlock2: u32 = 356;
+pub const __NR_copy_file_range: u32 = 357;
+pub const __NR_preadv2: u32 = 358;
+pub const __NR_pwritev2: u32 = 359;
+pub const __NR_statx: u32 = 360;
+pub const __NR_io_pgetevents: u32 = 361;
+pub const __NR_pkey_mprotect: u32 = 362;
+pub const __NR_pkey_alloc: u32 = 363;
+pub const __NR_pkey_free: u32 = 364;
+pub const __NR_rseq: u32 = 365;
+pub const __NR_semget: u32 = 393;
+pub const __NR_semctl: u32 = 394;
+pub const __NR_shmget: u32 = 395;
+pub const __NR_shmctl: u32 = 396;
+pub const __NR_shmat: u32 = 397;
+pub const __NR_shmdt: u32 = 398;
+pub const __NR_msgget: u32 = 399;
+pub const __NR_msgsnd: u32 = 400;
+pub const __NR_msgrcv: u32 = 401;
+pub const __NR_msgctl: u32 = 402;
+pub const __NR_clock_gettime64: u32 = 403;
+pub const __NR_clock_settime64: u32 = 404;
+pub const __NR_clock_adjtime64: u32 = 405;
+pub const __NR_clock_getres_time64: u32 = 406;
+pub const __NR_clock_nanosleep_time64: u32 = 407;
+pub const __NR_timer_gettime64: u32 = 408;
+pub const __NR_timer_settime64: u32 = 409;
+pub const __NR_timerfd_gettime64: u32 = 410;
+pub const __NR_timerfd_settime64: u32 = 411;
+pub const __NR_utimensat_time64: u32 = 412;
+pub const __NR_pselect6_time64: u32 = 413;
+pub const __NR_ppoll_time64: u32 = 414;
+pub const __NR_io_pgetevents_time64: u32 = 416;
+pub const __NR_recvmmsg_time64: u32 = 417;
+pub const __NR_mq_timedsend_time64: u32 = 418;
+pub const __NR_mq_timedreceive_time64: u32 = 419;
+pub const __NR_semtimedop_time64: u32 = 420;
+pub const __NR_rt_sigtimedwait_time64: u32 = 421;
+pub const __NR_futex_time64: u32 = 422;
+pub const __NR_sched_rr_get_interval_time64: u32 = 423;
+pub const __NR_pidfd_send_signal: u32 = 424;
+pub const __NR_io_uring_setup: u32 = 425;
+pub const __NR_io_uring_enter: u32 = 426;
+pub const __NR_io_uring_register: u32 = 427;
+pub const __NR_open_tree: u32 = 428;
+pub const __NR_move_mount: u32 = 429;
+pub const __NR_fsopen: u32 = 430;
+pub const __NR_fsconfig: u32 = 431;
+pub const __NR_fsmount: u32 = 432;
+pub const __NR_fspick: u32 = 433;
+pub const __NR_pidfd_open: u32 = 434;
+pub const KERN_FEATURE_MIXED_MODE_STACK: u32 = 1;
+pub const TFD_CLOEXEC: u32 = 4194304;
+pub const TFD_NONBLOCK: u32 = 16384;
+pub const STAT_HAVE_NSEC: u32 = 1;
+pub const SOCK_STREAM: u32 = 1;
+pub const SOCK_DGRAM: u32 = 2;
+pub const EFD_CLOEXEC: u32 = 4194304;
+pub const EFD_NONBLOCK: u32 = 16384;
+pub const TFD_SHARED_FCNTL_FLAGS: u32 = 4210688;
+pub const TFD_CREATE_FLAGS: u32 = 4210688;
+pub const BLKSSZGET: u32 = 536875624;
+pub const BLKPBSZGET: u32 = 536875643;
+/// The syscall number corresponding to index 0 of `SYSCALL_NAMES`.
+pub const SYSCALL_NAMES_BASE: u32 = 0;
@@ -0,0 +1,123 @@
+//! Equivalents of the `KERNEL_VERSION` macro from <linux/version.h>, and its
+//! inverse, for comparing kernel versions with `LINUX_VERSION_CODE`.
+
+/// Encode a kernel version as a version code, like `LINUX_VERSION_CODE`.
+///
+/// As in newer kernels, `c` saturates at 255. `a` and `b` must fit in 16 and
+/// 8 bits; as with the C macro, larger values wrap around into meaningless
+/// codes rather than panicking.
+#[inline]
+pub const fn KERNEL_VERSION(a: u32, b: u32, c: u32) -> u32 {
+    (a << 16)
+        .wrapping_add(b << 8)
+        .wrapping_add(if c > 255 { 255 } else { c })
+}
+
+/// Decode a version code into its major, minor, and patch numbers.
+#[inline]
+pub const fn decode_kernel_version(code: u32) -> (u32, u32, u32) {
+    (code >> 16, (code >> 8) & 0xff, code & 0xff)
+}
+
+/// Parse the leading version numbers of a kernel release string, such as
+/// `utsname`'s `release` field, into a version code.
+///
+/// Trailing components, such as in "5.11.0-rc1" or "4.19.0-17-amd64", are
+/// ignored, and a missing patch number is treated as 0. As in
+/// `KERNEL_VERSION`, the patch number saturates at 255. Return `None` if
+/// there's no minor number, or the major or minor number doesn't fit in a
+/// version code.
+pub fn parse_kernel_release(release: &[u8]) -> Option<u32> {
+    let mut parts = [0_u32; 3];
+    let mut index = 0;
+    let mut digits = 0;
+    for &byte in release {
+        match byte {
+            b'0'..=b'9' => {
+                parts[index] = parts[index]
+                    .saturating_mul(10)
+                    .saturating_add(u32::from(byte - b'0'));
+                digits += 1;
+            }
+            b'.' if digits != 0 && index < 2 => {
+                index += 1;
+                digits = 0;
+            }
+            _ => break,
+        }
+    }
+    if index == 0 || digits == 0 || parts[0] > 0xffff || parts[1] > 0xff {
+        return None;
+    }
+    Some(KERNEL_VERSION(parts[0], parts[1], parts[2]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            parse_kernel_release(b"5.11.0"),
+            Some(KERNEL_VERSION(5, 11, 0))
+        );
+        assert_eq!(
+            parse_kernel_release(b"4.19.128"),
+            Some(KERNEL_VERSION(4, 19, 128))
+        );
+        assert_eq!(parse_kernel_release(b"6.1"), Some(KERNEL_VERSION(6, 1, 0)));
+        assert_eq!(
+            decode_kernel_version(KERNEL_VERSION(4, 19, 128)),
+            (4, 19, 128)
+        );
+    }
+
+    #[test]
+    fn parse_suffix() {
+        assert_eq!(
+            parse_kernel_release(b"5.11.0-rc1"),
+            Some(KERNEL_VERSION(5, 11, 0))
+        );
+        assert_eq!(
+            parse_kernel_release(b"5.11-rc1"),
+            Some(KERNEL_VERSION(5, 11, 0))
+        );
+        assert_eq!(
+            parse_kernel_release(b"4.19.0-17-amd64"),
+            Some(KERNEL_VERSION(4, 19, 0))
+        );
+        assert_eq!(
+            parse_kernel_release(b"3.10.0.el7\0"),
+            Some(KERNEL_VERSION(3, 10, 0))
+        );
+    }
+
+    #[test]
+    fn parse_too_few_parts() {
+        assert_eq!(parse_kernel_release(b""), None);
+        assert_eq!(parse_kernel_release(b"5"), None);
+        assert_eq!(parse_kernel_release(b"5."), None);
+        assert_eq!(parse_kernel_release(b"5-rc1"), None);
+        assert_eq!(parse_kernel_release(b".11.0"), None);
+    }
+
+    #[test]
+    fn parse_overflow() {
+        assert_eq!(parse_kernel_release(b"4294967295.4294967295.0"), None);
+        assert_eq!(parse_kernel_release(b"99999999999.1.0"), None);
+        assert_eq!(parse_kernel_release(b"65536.0.0"), None);
+        assert_eq!(parse_kernel_release(b"5.256.0"), None);
+        assert_eq!(parse_kernel_release(b"65535.255.0"), Some(0xffff_ff00));
+
+        // The patch number saturates, as it does in the kernel since 4.9.256.
+        assert_eq!(
+            parse_kernel_release(b"4.9.256"),
+            Some(KERNEL_VERSION(4, 9, 255))
+        );
+        assert_eq!(
+            parse_kernel_release(b"4.9.99999999999"),
+            Some(KERNEL_VERSION(4, 9, 255))
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! Equivalents of the `W*` macros for decoding the status values returned by
+//! `wait4` and `waitid`, which bindgen doesn't translate.
+
+use crate::ctypes::c_int;
+
+/// Is `status` from a child which exited normally?
+#[inline]
+pub const fn WIFEXITED(status: c_int) -> bool {
+    WTERMSIG(status) == 0
+}
+
+/// The exit code of a child for which `WIFEXITED` is true.
+#[inline]
+pub const fn WEXITSTATUS(status: c_int) -> c_int {
+    (status & 0xff00) >> 8
+}
+
+/// Is `status` from a child which was terminated by a signal?
+#[inline]
+pub const fn WIFSIGNALED(status: c_int) -> bool {
+    (((status & 0x7f) + 1) as i8 >> 1) > 0
+}
+
+/// The signal which terminated a child for which `WIFSIGNALED` is true.
+#[inline]
+pub const fn WTERMSIG(status: c_int) -> c_int {
+    status & 0x7f
+}
+
+/// Is `status` from a child which is currently stopped?
+#[inline]
+pub const fn WIFSTOPPED(status: c_int) -> bool {
+    (status & 0xff) == 0x7f
+}
+
+/// The signal which stopped a child for which `WIFSTOPPED` is true.
+#[inline]
+pub const fn WSTOPSIG(status: c_int) -> c_int {
+    WEXITSTATUS(status)
+}
+
+/// Did a child for which `WIFSIGNALED` is true produce a core dump?
+#[inline]
+pub const fn WCOREDUMP(status: c_int) -> bool {
+    (status & 0x80) != 0
+}
+
+/// Is `status` from a child which was resumed by `SIGCONT`?
+#[inline]
+pub const fn WIFCONTINUED(status: c_int) -> bool {
+    status == 0xffff
+}
@@ -0,0 +1,5024 @@
+//! Check the constants in the `v5_11` bindings whose values differ between
+//! architectures, so that an architecture picking up another's values, such
+//! as the asm-generic ones, is caught.
+//!
+//! This file is auto-generated!
+
+#![cfg(feature = "v5_11")]
+
+#[cfg(feature = "errno")]
+#[test]
+fn errno() {
+    use linux_raw_sys::v5_11::errno::*;
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        assert_eq!(EADDRINUSE, 98);
+        assert_eq!(EADDRNOTAVAIL, 99);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 97);
+        assert_eq!(EALREADY, 114);
+        assert_eq!(EBADE, 52);
+        assert_eq!(EBADFD, 77);
+        assert_eq!(EBADMSG, 74);
+        assert_eq!(EBADR, 53);
+        assert_eq!(EBADRQC, 56);
+        assert_eq!(EBADSLT, 57);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 125);
+        assert_eq!(ECHRNG, 44);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 103);
+        assert_eq!(ECONNREFUSED, 111);
+        assert_eq!(ECONNRESET, 104);
+        assert_eq!(EDEADLK, 35);
+        assert_eq!(EDEADLOCK, 35);
+        assert_eq!(EDESTADDRREQ, 89);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 122);
+        assert_eq!(EHOSTDOWN, 112);
+        assert_eq!(EHOSTUNREACH, 113);
+        assert_eq!(EHWPOISON, 133);
+        assert_eq!(EIDRM, 43);
+        assert_eq!(EILSEQ, 84);
+        assert_eq!(EINPROGRESS, 115);
+        assert_eq!(EISCONN, 106);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 127);
+        assert_eq!(EKEYREJECTED, 129);
+        assert_eq!(EKEYREVOKED, 128);
+        assert_eq!(EL2HLT, 51);
+        assert_eq!(EL2NSYNC, 45);
+        assert_eq!(EL3HLT, 46);
+        assert_eq!(EL3RST, 47);
+        assert_eq!(ELIBACC, 79);
+        assert_eq!(ELIBBAD, 80);
+        assert_eq!(ELIBEXEC, 83);
+        assert_eq!(ELIBMAX, 82);
+        assert_eq!(ELIBSCN, 81);
+        assert_eq!(ELNRNG, 48);
+        assert_eq!(ELOOP, 40);
+        assert_eq!(EMEDIUMTYPE, 124);
+        assert_eq!(EMSGSIZE, 90);
+        assert_eq!(EMULTIHOP, 72);
+        assert_eq!(ENAMETOOLONG, 36);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 100);
+        assert_eq!(ENETRESET, 102);
+        assert_eq!(ENETUNREACH, 101);
+        assert_eq!(ENOANO, 55);
+        assert_eq!(ENOBUFS, 105);
+        assert_eq!(ENOCSI, 50);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 126);
+        assert_eq!(ENOLCK, 37);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 123);
+        assert_eq!(ENOMSG, 42);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 92);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 38);
+        assert_eq!(ENOTCONN, 107);
+        assert_eq!(ENOTEMPTY, 39);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 131);
+        assert_eq!(ENOTSOCK, 88);
+        assert_eq!(ENOTUNIQ, 76);
+        assert_eq!(EOPNOTSUPP, 95);
+        assert_eq!(EOVERFLOW, 75);
+        assert_eq!(EOWNERDEAD, 130);
+        assert_eq!(EPFNOSUPPORT, 96);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 93);
+        assert_eq!(EPROTOTYPE, 91);
+        assert_eq!(EREMCHG, 78);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 85);
+        assert_eq!(ERFKILL, 132);
+        assert_eq!(ESHUTDOWN, 108);
+        assert_eq!(ESOCKTNOSUPPORT, 94);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 116);
+        assert_eq!(ESTRPIPE, 86);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 110);
+        assert_eq!(ETOOMANYREFS, 109);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 49);
+        assert_eq!(EUSERS, 87);
+        assert_eq!(EXFULL, 54);
+    }
+
+    #[cfg(target_arch = "arm")]
+    {
+        assert_eq!(EADDRINUSE, 98);
+        assert_eq!(EADDRNOTAVAIL, 99);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 97);
+        assert_eq!(EALREADY, 114);
+        assert_eq!(EBADE, 52);
+        assert_eq!(EBADFD, 77);
+        assert_eq!(EBADMSG, 74);
+        assert_eq!(EBADR, 53);
+        assert_eq!(EBADRQC, 56);
+        assert_eq!(EBADSLT, 57);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 125);
+        assert_eq!(ECHRNG, 44);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 103);
+        assert_eq!(ECONNREFUSED, 111);
+        assert_eq!(ECONNRESET, 104);
+        assert_eq!(EDEADLK, 35);
+        assert_eq!(EDEADLOCK, 35);
+        assert_eq!(EDESTADDRREQ, 89);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 122);
+        assert_eq!(EHOSTDOWN, 112);
+        assert_eq!(EHOSTUNREACH, 113);
+        assert_eq!(EHWPOISON, 133);
+        assert_eq!(EIDRM, 43);
+        assert_eq!(EILSEQ, 84);
+        assert_eq!(EINPROGRESS, 115);
+        assert_eq!(EISCONN, 106);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 127);
+        assert_eq!(EKEYREJECTED, 129);
+        assert_eq!(EKEYREVOKED, 128);
+        assert_eq!(EL2HLT, 51);
+        assert_eq!(EL2NSYNC, 45);
+        assert_eq!(EL3HLT, 46);
+        assert_eq!(EL3RST, 47);
+        assert_eq!(ELIBACC, 79);
+        assert_eq!(ELIBBAD, 80);
+        assert_eq!(ELIBEXEC, 83);
+        assert_eq!(ELIBMAX, 82);
+        assert_eq!(ELIBSCN, 81);
+        assert_eq!(ELNRNG, 48);
+        assert_eq!(ELOOP, 40);
+        assert_eq!(EMEDIUMTYPE, 124);
+        assert_eq!(EMSGSIZE, 90);
+        assert_eq!(EMULTIHOP, 72);
+        assert_eq!(ENAMETOOLONG, 36);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 100);
+        assert_eq!(ENETRESET, 102);
+        assert_eq!(ENETUNREACH, 101);
+        assert_eq!(ENOANO, 55);
+        assert_eq!(ENOBUFS, 105);
+        assert_eq!(ENOCSI, 50);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 126);
+        assert_eq!(ENOLCK, 37);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 123);
+        assert_eq!(ENOMSG, 42);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 92);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 38);
+        assert_eq!(ENOTCONN, 107);
+        assert_eq!(ENOTEMPTY, 39);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 131);
+        assert_eq!(ENOTSOCK, 88);
+        assert_eq!(ENOTUNIQ, 76);
+        assert_eq!(EOPNOTSUPP, 95);
+        assert_eq!(EOVERFLOW, 75);
+        assert_eq!(EOWNERDEAD, 130);
+        assert_eq!(EPFNOSUPPORT, 96);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 93);
+        assert_eq!(EPROTOTYPE, 91);
+        assert_eq!(EREMCHG, 78);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 85);
+        assert_eq!(ERFKILL, 132);
+        assert_eq!(ESHUTDOWN, 108);
+        assert_eq!(ESOCKTNOSUPPORT, 94);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 116);
+        assert_eq!(ESTRPIPE, 86);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 110);
+        assert_eq!(ETOOMANYREFS, 109);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 49);
+        assert_eq!(EUSERS, 87);
+        assert_eq!(EXFULL, 54);
+    }
+
+    #[cfg(target_arch = "mips")]
+    {
+        assert_eq!(EADDRINUSE, 125);
+        assert_eq!(EADDRNOTAVAIL, 126);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 124);
+        assert_eq!(EALREADY, 149);
+        assert_eq!(EBADE, 50);
+        assert_eq!(EBADFD, 81);
+        assert_eq!(EBADMSG, 77);
+        assert_eq!(EBADR, 51);
+        assert_eq!(EBADRQC, 54);
+        assert_eq!(EBADSLT, 55);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 158);
+        assert_eq!(ECHRNG, 37);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 130);
+        assert_eq!(ECONNREFUSED, 146);
+        assert_eq!(ECONNRESET, 131);
+        assert_eq!(EDEADLK, 45);
+        assert_eq!(EDEADLOCK, 56);
+        assert_eq!(EDESTADDRREQ, 96);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 1133);
+        assert_eq!(EHOSTDOWN, 147);
+        assert_eq!(EHOSTUNREACH, 148);
+        assert_eq!(EHWPOISON, 168);
+        assert_eq!(EIDRM, 36);
+        assert_eq!(EILSEQ, 88);
+        assert_eq!(EINPROGRESS, 150);
+        assert_eq!(EISCONN, 133);
+        assert_eq!(EISNAM, 139);
+        assert_eq!(EKEYEXPIRED, 162);
+        assert_eq!(EKEYREJECTED, 164);
+        assert_eq!(EKEYREVOKED, 163);
+        assert_eq!(EL2HLT, 44);
+        assert_eq!(EL2NSYNC, 38);
+        assert_eq!(EL3HLT, 39);
+        assert_eq!(EL3RST, 40);
+        assert_eq!(ELIBACC, 83);
+        assert_eq!(ELIBBAD, 84);
+        assert_eq!(ELIBEXEC, 87);
+        assert_eq!(ELIBMAX, 86);
+        assert_eq!(ELIBSCN, 85);
+        assert_eq!(ELNRNG, 41);
+        assert_eq!(ELOOP, 90);
+        assert_eq!(EMEDIUMTYPE, 160);
+        assert_eq!(EMSGSIZE, 97);
+        assert_eq!(EMULTIHOP, 74);
+        assert_eq!(ENAMETOOLONG, 78);
+        assert_eq!(ENAVAIL, 138);
+        assert_eq!(ENETDOWN, 127);
+        assert_eq!(ENETRESET, 129);
+        assert_eq!(ENETUNREACH, 128);
+        assert_eq!(ENOANO, 53);
+        assert_eq!(ENOBUFS, 132);
+        assert_eq!(ENOCSI, 43);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 161);
+        assert_eq!(ENOLCK, 46);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 159);
+        assert_eq!(ENOMSG, 35);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 99);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 89);
+        assert_eq!(ENOTCONN, 134);
+        assert_eq!(ENOTEMPTY, 93);
+        assert_eq!(ENOTNAM, 137);
+        assert_eq!(ENOTRECOVERABLE, 166);
+        assert_eq!(ENOTSOCK, 95);
+        assert_eq!(ENOTUNIQ, 80);
+        assert_eq!(EOPNOTSUPP, 122);
+        assert_eq!(EOVERFLOW, 79);
+        assert_eq!(EOWNERDEAD, 165);
+        assert_eq!(EPFNOSUPPORT, 123);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 120);
+        assert_eq!(EPROTOTYPE, 98);
+        assert_eq!(EREMCHG, 82);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 140);
+        assert_eq!(ERESTART, 91);
+        assert_eq!(ERFKILL, 167);
+        assert_eq!(ESHUTDOWN, 143);
+        assert_eq!(ESOCKTNOSUPPORT, 121);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 151);
+        assert_eq!(ESTRPIPE, 92);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 145);
+        assert_eq!(ETOOMANYREFS, 144);
+        assert_eq!(EUCLEAN, 135);
+        assert_eq!(EUNATCH, 42);
+        assert_eq!(EUSERS, 94);
+        assert_eq!(EXFULL, 52);
+    }
+
+    #[cfg(target_arch = "mips64")]
+    {
+        assert_eq!(EADDRINUSE, 125);
+        assert_eq!(EADDRNOTAVAIL, 126);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 124);
+        assert_eq!(EALREADY, 149);
+        assert_eq!(EBADE, 50);
+        assert_eq!(EBADFD, 81);
+        assert_eq!(EBADMSG, 77);
+        assert_eq!(EBADR, 51);
+        assert_eq!(EBADRQC, 54);
+        assert_eq!(EBADSLT, 55);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 158);
+        assert_eq!(ECHRNG, 37);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 130);
+        assert_eq!(ECONNREFUSED, 146);
+        assert_eq!(ECONNRESET, 131);
+        assert_eq!(EDEADLK, 45);
+        assert_eq!(EDEADLOCK, 56);
+        assert_eq!(EDESTADDRREQ, 96);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 1133);
+        assert_eq!(EHOSTDOWN, 147);
+        assert_eq!(EHOSTUNREACH, 148);
+        assert_eq!(EHWPOISON, 168);
+        assert_eq!(EIDRM, 36);
+        assert_eq!(EILSEQ, 88);
+        assert_eq!(EINPROGRESS, 150);
+        assert_eq!(EISCONN, 133);
+        assert_eq!(EISNAM, 139);
+        assert_eq!(EKEYEXPIRED, 162);
+        assert_eq!(EKEYREJECTED, 164);
+        assert_eq!(EKEYREVOKED, 163);
+        assert_eq!(EL2HLT, 44);
+        assert_eq!(EL2NSYNC, 38);
+        assert_eq!(EL3HLT, 39);
+        assert_eq!(EL3RST, 40);
+        assert_eq!(ELIBACC, 83);
+        assert_eq!(ELIBBAD, 84);
+        assert_eq!(ELIBEXEC, 87);
+        assert_eq!(ELIBMAX, 86);
+        assert_eq!(ELIBSCN, 85);
+        assert_eq!(ELNRNG, 41);
+        assert_eq!(ELOOP, 90);
+        assert_eq!(EMEDIUMTYPE, 160);
+        assert_eq!(EMSGSIZE, 97);
+        assert_eq!(EMULTIHOP, 74);
+        assert_eq!(ENAMETOOLONG, 78);
+        assert_eq!(ENAVAIL, 138);
+        assert_eq!(ENETDOWN, 127);
+        assert_eq!(ENETRESET, 129);
+        assert_eq!(ENETUNREACH, 128);
+        assert_eq!(ENOANO, 53);
+        assert_eq!(ENOBUFS, 132);
+        assert_eq!(ENOCSI, 43);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 161);
+        assert_eq!(ENOLCK, 46);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 159);
+        assert_eq!(ENOMSG, 35);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 99);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 89);
+        assert_eq!(ENOTCONN, 134);
+        assert_eq!(ENOTEMPTY, 93);
+        assert_eq!(ENOTNAM, 137);
+        assert_eq!(ENOTRECOVERABLE, 166);
+        assert_eq!(ENOTSOCK, 95);
+        assert_eq!(ENOTUNIQ, 80);
+        assert_eq!(EOPNOTSUPP, 122);
+        assert_eq!(EOVERFLOW, 79);
+        assert_eq!(EOWNERDEAD, 165);
+        assert_eq!(EPFNOSUPPORT, 123);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 120);
+        assert_eq!(EPROTOTYPE, 98);
+        assert_eq!(EREMCHG, 82);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 140);
+        assert_eq!(ERESTART, 91);
+        assert_eq!(ERFKILL, 167);
+        assert_eq!(ESHUTDOWN, 143);
+        assert_eq!(ESOCKTNOSUPPORT, 121);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 151);
+        assert_eq!(ESTRPIPE, 92);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 145);
+        assert_eq!(ETOOMANYREFS, 144);
+        assert_eq!(EUCLEAN, 135);
+        assert_eq!(EUNATCH, 42);
+        assert_eq!(EUSERS, 94);
+        assert_eq!(EXFULL, 52);
+    }
+
+    #[cfg(target_arch = "powerpc")]
+    {
+        assert_eq!(EADDRINUSE, 98);
+        assert_eq!(EADDRNOTAVAIL, 99);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 97);
+        assert_eq!(EALREADY, 114);
+        assert_eq!(EBADE, 52);
+        assert_eq!(EBADFD, 77);
+        assert_eq!(EBADMSG, 74);
+        assert_eq!(EBADR, 53);
+        assert_eq!(EBADRQC, 56);
+        assert_eq!(EBADSLT, 57);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 125);
+        assert_eq!(ECHRNG, 44);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 103);
+        assert_eq!(ECONNREFUSED, 111);
+        assert_eq!(ECONNRESET, 104);
+        assert_eq!(EDEADLK, 35);
+        assert_eq!(EDEADLOCK, 35);
+        assert_eq!(EDESTADDRREQ, 89);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 122);
+        assert_eq!(EHOSTDOWN, 112);
+        assert_eq!(EHOSTUNREACH, 113);
+        assert_eq!(EHWPOISON, 133);
+        assert_eq!(EIDRM, 43);
+        assert_eq!(EILSEQ, 84);
+        assert_eq!(EINPROGRESS, 115);
+        assert_eq!(EISCONN, 106);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 127);
+        assert_eq!(EKEYREJECTED, 129);
+        assert_eq!(EKEYREVOKED, 128);
+        assert_eq!(EL2HLT, 51);
+        assert_eq!(EL2NSYNC, 45);
+        assert_eq!(EL3HLT, 46);
+        assert_eq!(EL3RST, 47);
+        assert_eq!(ELIBACC, 79);
+        assert_eq!(ELIBBAD, 80);
+        assert_eq!(ELIBEXEC, 83);
+        assert_eq!(ELIBMAX, 82);
+        assert_eq!(ELIBSCN, 81);
+        assert_eq!(ELNRNG, 48);
+        assert_eq!(ELOOP, 40);
+        assert_eq!(EMEDIUMTYPE, 124);
+        assert_eq!(EMSGSIZE, 90);
+        assert_eq!(EMULTIHOP, 72);
+        assert_eq!(ENAMETOOLONG, 36);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 100);
+        assert_eq!(ENETRESET, 102);
+        assert_eq!(ENETUNREACH, 101);
+        assert_eq!(ENOANO, 55);
+        assert_eq!(ENOBUFS, 105);
+        assert_eq!(ENOCSI, 50);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 126);
+        assert_eq!(ENOLCK, 37);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 123);
+        assert_eq!(ENOMSG, 42);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 92);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 38);
+        assert_eq!(ENOTCONN, 107);
+        assert_eq!(ENOTEMPTY, 39);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 131);
+        assert_eq!(ENOTSOCK, 88);
+        assert_eq!(ENOTUNIQ, 76);
+        assert_eq!(EOPNOTSUPP, 95);
+        assert_eq!(EOVERFLOW, 75);
+        assert_eq!(EOWNERDEAD, 130);
+        assert_eq!(EPFNOSUPPORT, 96);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 93);
+        assert_eq!(EPROTOTYPE, 91);
+        assert_eq!(EREMCHG, 78);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 85);
+        assert_eq!(ERFKILL, 132);
+        assert_eq!(ESHUTDOWN, 108);
+        assert_eq!(ESOCKTNOSUPPORT, 94);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 116);
+        assert_eq!(ESTRPIPE, 86);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 110);
+        assert_eq!(ETOOMANYREFS, 109);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 49);
+        assert_eq!(EUSERS, 87);
+        assert_eq!(EXFULL, 54);
+    }
+
+    #[cfg(target_arch = "powerpc64")]
+    {
+        assert_eq!(EADDRINUSE, 98);
+        assert_eq!(EADDRNOTAVAIL, 99);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 97);
+        assert_eq!(EALREADY, 114);
+        assert_eq!(EBADE, 52);
+        assert_eq!(EBADFD, 77);
+        assert_eq!(EBADMSG, 74);
+        assert_eq!(EBADR, 53);
+        assert_eq!(EBADRQC, 56);
+        assert_eq!(EBADSLT, 57);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 125);
+        assert_eq!(ECHRNG, 44);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 103);
+        assert_eq!(ECONNREFUSED, 111);
+        assert_eq!(ECONNRESET, 104);
+        assert_eq!(EDEADLK, 35);
+        assert_eq!(EDEADLOCK, 35);
+        assert_eq!(EDESTADDRREQ, 89);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 122);
+        assert_eq!(EHOSTDOWN, 112);
+        assert_eq!(EHOSTUNREACH, 113);
+        assert_eq!(EHWPOISON, 133);
+        assert_eq!(EIDRM, 43);
+        assert_eq!(EILSEQ, 84);
+        assert_eq!(EINPROGRESS, 115);
+        assert_eq!(EISCONN, 106);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 127);
+        assert_eq!(EKEYREJECTED, 129);
+        assert_eq!(EKEYREVOKED, 128);
+        assert_eq!(EL2HLT, 51);
+        assert_eq!(EL2NSYNC, 45);
+        assert_eq!(EL3HLT, 46);
+        assert_eq!(EL3RST, 47);
+        assert_eq!(ELIBACC, 79);
+        assert_eq!(ELIBBAD, 80);
+        assert_eq!(ELIBEXEC, 83);
+        assert_eq!(ELIBMAX, 82);
+        assert_eq!(ELIBSCN, 81);
+        assert_eq!(ELNRNG, 48);
+        assert_eq!(ELOOP, 40);
+        assert_eq!(EMEDIUMTYPE, 124);
+        assert_eq!(EMSGSIZE, 90);
+        assert_eq!(EMULTIHOP, 72);
+        assert_eq!(ENAMETOOLONG, 36);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 100);
+        assert_eq!(ENETRESET, 102);
+        assert_eq!(ENETUNREACH, 101);
+        assert_eq!(ENOANO, 55);
+        assert_eq!(ENOBUFS, 105);
+        assert_eq!(ENOCSI, 50);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 126);
+        assert_eq!(ENOLCK, 37);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 123);
+        assert_eq!(ENOMSG, 42);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 92);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 38);
+        assert_eq!(ENOTCONN, 107);
+        assert_eq!(ENOTEMPTY, 39);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 131);
+        assert_eq!(ENOTSOCK, 88);
+        assert_eq!(ENOTUNIQ, 76);
+        assert_eq!(EOPNOTSUPP, 95);
+        assert_eq!(EOVERFLOW, 75);
+        assert_eq!(EOWNERDEAD, 130);
+        assert_eq!(EPFNOSUPPORT, 96);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 93);
+        assert_eq!(EPROTOTYPE, 91);
+        assert_eq!(EREMCHG, 78);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 85);
+        assert_eq!(ERFKILL, 132);
+        assert_eq!(ESHUTDOWN, 108);
+        assert_eq!(ESOCKTNOSUPPORT, 94);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 116);
+        assert_eq!(ESTRPIPE, 86);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 110);
+        assert_eq!(ETOOMANYREFS, 109);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 49);
+        assert_eq!(EUSERS, 87);
+        assert_eq!(EXFULL, 54);
+    }
+
+    #[cfg(target_arch = "riscv32")]
+    {
+        assert_eq!(EADDRINUSE, 98);
+        assert_eq!(EADDRNOTAVAIL, 99);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 97);
+        assert_eq!(EALREADY, 114);
+        assert_eq!(EBADE, 52);
+        assert_eq!(EBADFD, 77);
+        assert_eq!(EBADMSG, 74);
+        assert_eq!(EBADR, 53);
+        assert_eq!(EBADRQC, 56);
+        assert_eq!(EBADSLT, 57);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 125);
+        assert_eq!(ECHRNG, 44);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 103);
+        assert_eq!(ECONNREFUSED, 111);
+        assert_eq!(ECONNRESET, 104);
+        assert_eq!(EDEADLK, 35);
+        assert_eq!(EDEADLOCK, 35);
+        assert_eq!(EDESTADDRREQ, 89);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 122);
+        assert_eq!(EHOSTDOWN, 112);
+        assert_eq!(EHOSTUNREACH, 113);
+        assert_eq!(EHWPOISON, 133);
+        assert_eq!(EIDRM, 43);
+        assert_eq!(EILSEQ, 84);
+        assert_eq!(EINPROGRESS, 115);
+        assert_eq!(EISCONN, 106);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 127);
+        assert_eq!(EKEYREJECTED, 129);
+        assert_eq!(EKEYREVOKED, 128);
+        assert_eq!(EL2HLT, 51);
+        assert_eq!(EL2NSYNC, 45);
+        assert_eq!(EL3HLT, 46);
+        assert_eq!(EL3RST, 47);
+        assert_eq!(ELIBACC, 79);
+        assert_eq!(ELIBBAD, 80);
+        assert_eq!(ELIBEXEC, 83);
+        assert_eq!(ELIBMAX, 82);
+        assert_eq!(ELIBSCN, 81);
+        assert_eq!(ELNRNG, 48);
+        assert_eq!(ELOOP, 40);
+        assert_eq!(EMEDIUMTYPE, 124);
+        assert_eq!(EMSGSIZE, 90);
+        assert_eq!(EMULTIHOP, 72);
+        assert_eq!(ENAMETOOLONG, 36);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 100);
+        assert_eq!(ENETRESET, 102);
+        assert_eq!(ENETUNREACH, 101);
+        assert_eq!(ENOANO, 55);
+        assert_eq!(ENOBUFS, 105);
+        assert_eq!(ENOCSI, 50);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 126);
+        assert_eq!(ENOLCK, 37);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 123);
+        assert_eq!(ENOMSG, 42);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 92);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 38);
+        assert_eq!(ENOTCONN, 107);
+        assert_eq!(ENOTEMPTY, 39);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 131);
+        assert_eq!(ENOTSOCK, 88);
+        assert_eq!(ENOTUNIQ, 76);
+        assert_eq!(EOPNOTSUPP, 95);
+        assert_eq!(EOVERFLOW, 75);
+        assert_eq!(EOWNERDEAD, 130);
+        assert_eq!(EPFNOSUPPORT, 96);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 93);
+        assert_eq!(EPROTOTYPE, 91);
+        assert_eq!(EREMCHG, 78);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 85);
+        assert_eq!(ERFKILL, 132);
+        assert_eq!(ESHUTDOWN, 108);
+        assert_eq!(ESOCKTNOSUPPORT, 94);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 116);
+        assert_eq!(ESTRPIPE, 86);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 110);
+        assert_eq!(ETOOMANYREFS, 109);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 49);
+        assert_eq!(EUSERS, 87);
+        assert_eq!(EXFULL, 54);
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        assert_eq!(EADDRINUSE, 98);
+        assert_eq!(EADDRNOTAVAIL, 99);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 97);
+        assert_eq!(EALREADY, 114);
+        assert_eq!(EBADE, 52);
+        assert_eq!(EBADFD, 77);
+        assert_eq!(EBADMSG, 74);
+        assert_eq!(EBADR, 53);
+        assert_eq!(EBADRQC, 56);
+        assert_eq!(EBADSLT, 57);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 125);
+        assert_eq!(ECHRNG, 44);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 103);
+        assert_eq!(ECONNREFUSED, 111);
+        assert_eq!(ECONNRESET, 104);
+        assert_eq!(EDEADLK, 35);
+        assert_eq!(EDEADLOCK, 35);
+        assert_eq!(EDESTADDRREQ, 89);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 122);
+        assert_eq!(EHOSTDOWN, 112);
+        assert_eq!(EHOSTUNREACH, 113);
+        assert_eq!(EHWPOISON, 133);
+        assert_eq!(EIDRM, 43);
+        assert_eq!(EILSEQ, 84);
+        assert_eq!(EINPROGRESS, 115);
+        assert_eq!(EISCONN, 106);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 127);
+        assert_eq!(EKEYREJECTED, 129);
+        assert_eq!(EKEYREVOKED, 128);
+        assert_eq!(EL2HLT, 51);
+        assert_eq!(EL2NSYNC, 45);
+        assert_eq!(EL3HLT, 46);
+        assert_eq!(EL3RST, 47);
+        assert_eq!(ELIBACC, 79);
+        assert_eq!(ELIBBAD, 80);
+        assert_eq!(ELIBEXEC, 83);
+        assert_eq!(ELIBMAX, 82);
+        assert_eq!(ELIBSCN, 81);
+        assert_eq!(ELNRNG, 48);
+        assert_eq!(ELOOP, 40);
+        assert_eq!(EMEDIUMTYPE, 124);
+        assert_eq!(EMSGSIZE, 90);
+        assert_eq!(EMULTIHOP, 72);
+        assert_eq!(ENAMETOOLONG, 36);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 100);
+        assert_eq!(ENETRESET, 102);
+        assert_eq!(ENETUNREACH, 101);
+        assert_eq!(ENOANO, 55);
+        assert_eq!(ENOBUFS, 105);
+        assert_eq!(ENOCSI, 50);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 126);
+        assert_eq!(ENOLCK, 37);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 123);
+        assert_eq!(ENOMSG, 42);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 92);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 38);
+        assert_eq!(ENOTCONN, 107);
+        assert_eq!(ENOTEMPTY, 39);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 131);
+        assert_eq!(ENOTSOCK, 88);
+        assert_eq!(ENOTUNIQ, 76);
+        assert_eq!(EOPNOTSUPP, 95);
+        assert_eq!(EOVERFLOW, 75);
+        assert_eq!(EOWNERDEAD, 130);
+        assert_eq!(EPFNOSUPPORT, 96);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 93);
+        assert_eq!(EPROTOTYPE, 91);
+        assert_eq!(EREMCHG, 78);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 85);
+        assert_eq!(ERFKILL, 132);
+        assert_eq!(ESHUTDOWN, 108);
+        assert_eq!(ESOCKTNOSUPPORT, 94);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 116);
+        assert_eq!(ESTRPIPE, 86);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 110);
+        assert_eq!(ETOOMANYREFS, 109);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 49);
+        assert_eq!(EUSERS, 87);
+        assert_eq!(EXFULL, 54);
+    }
+
+    #[cfg(target_arch = "s390x")]
+    {
+        assert_eq!(EADDRINUSE, 98);
+        assert_eq!(EADDRNOTAVAIL, 99);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 97);
+        assert_eq!(EALREADY, 114);
+        assert_eq!(EBADE, 52);
+        assert_eq!(EBADFD, 77);
+        assert_eq!(EBADMSG, 74);
+        assert_eq!(EBADR, 53);
+        assert_eq!(EBADRQC, 56);
+        assert_eq!(EBADSLT, 57);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 125);
+        assert_eq!(ECHRNG, 44);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 103);
+        assert_eq!(ECONNREFUSED, 111);
+        assert_eq!(ECONNRESET, 104);
+        assert_eq!(EDEADLK, 35);
+        assert_eq!(EDEADLOCK, 35);
+        assert_eq!(EDESTADDRREQ, 89);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 122);
+        assert_eq!(EHOSTDOWN, 112);
+        assert_eq!(EHOSTUNREACH, 113);
+        assert_eq!(EHWPOISON, 133);
+        assert_eq!(EIDRM, 43);
+        assert_eq!(EILSEQ, 84);
+        assert_eq!(EINPROGRESS, 115);
+        assert_eq!(EISCONN, 106);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 127);
+        assert_eq!(EKEYREJECTED, 129);
+        assert_eq!(EKEYREVOKED, 128);
+        assert_eq!(EL2HLT, 51);
+        assert_eq!(EL2NSYNC, 45);
+        assert_eq!(EL3HLT, 46);
+        assert_eq!(EL3RST, 47);
+        assert_eq!(ELIBACC, 79);
+        assert_eq!(ELIBBAD, 80);
+        assert_eq!(ELIBEXEC, 83);
+        assert_eq!(ELIBMAX, 82);
+        assert_eq!(ELIBSCN, 81);
+        assert_eq!(ELNRNG, 48);
+        assert_eq!(ELOOP, 40);
+        assert_eq!(EMEDIUMTYPE, 124);
+        assert_eq!(EMSGSIZE, 90);
+        assert_eq!(EMULTIHOP, 72);
+        assert_eq!(ENAMETOOLONG, 36);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 100);
+        assert_eq!(ENETRESET, 102);
+        assert_eq!(ENETUNREACH, 101);
+        assert_eq!(ENOANO, 55);
+        assert_eq!(ENOBUFS, 105);
+        assert_eq!(ENOCSI, 50);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 126);
+        assert_eq!(ENOLCK, 37);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 123);
+        assert_eq!(ENOMSG, 42);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 92);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 38);
+        assert_eq!(ENOTCONN, 107);
+        assert_eq!(ENOTEMPTY, 39);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 131);
+        assert_eq!(ENOTSOCK, 88);
+        assert_eq!(ENOTUNIQ, 76);
+        assert_eq!(EOPNOTSUPP, 95);
+        assert_eq!(EOVERFLOW, 75);
+        assert_eq!(EOWNERDEAD, 130);
+        assert_eq!(EPFNOSUPPORT, 96);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 93);
+        assert_eq!(EPROTOTYPE, 91);
+        assert_eq!(EREMCHG, 78);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 85);
+        assert_eq!(ERFKILL, 132);
+        assert_eq!(ESHUTDOWN, 108);
+        assert_eq!(ESOCKTNOSUPPORT, 94);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 116);
+        assert_eq!(ESTRPIPE, 86);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 110);
+        assert_eq!(ETOOMANYREFS, 109);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 49);
+        assert_eq!(EUSERS, 87);
+        assert_eq!(EXFULL, 54);
+    }
+
+    #[cfg(target_arch = "sparc")]
+    {
+        assert_eq!(EADDRINUSE, 48);
+        assert_eq!(EADDRNOTAVAIL, 49);
+        assert_eq!(EADV, 83);
+        assert_eq!(EAFNOSUPPORT, 47);
+        assert_eq!(EALREADY, 37);
+        assert_eq!(EBADE, 102);
+        assert_eq!(EBADFD, 93);
+        assert_eq!(EBADMSG, 76);
+        assert_eq!(EBADR, 103);
+        assert_eq!(EBADRQC, 106);
+        assert_eq!(EBADSLT, 107);
+        assert_eq!(EBFONT, 109);
+        assert_eq!(ECANCELED, 127);
+        assert_eq!(ECHRNG, 94);
+        assert_eq!(ECOMM, 85);
+        assert_eq!(ECONNABORTED, 53);
+        assert_eq!(ECONNREFUSED, 61);
+        assert_eq!(ECONNRESET, 54);
+        assert_eq!(EDEADLK, 78);
+        assert_eq!(EDEADLOCK, 108);
+        assert_eq!(EDESTADDRREQ, 39);
+        assert_eq!(EDOTDOT, 88);
+        assert_eq!(EDQUOT, 69);
+        assert_eq!(EHOSTDOWN, 64);
+        assert_eq!(EHOSTUNREACH, 65);
+        assert_eq!(EHWPOISON, 135);
+        assert_eq!(EIDRM, 77);
+        assert_eq!(EILSEQ, 122);
+        assert_eq!(EINPROGRESS, 36);
+        assert_eq!(EISCONN, 56);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 129);
+        assert_eq!(EKEYREJECTED, 131);
+        assert_eq!(EKEYREVOKED, 130);
+        assert_eq!(EL2HLT, 101);
+        assert_eq!(EL2NSYNC, 95);
+        assert_eq!(EL3HLT, 96);
+        assert_eq!(EL3RST, 97);
+        assert_eq!(ELIBACC, 114);
+        assert_eq!(ELIBBAD, 112);
+        assert_eq!(ELIBEXEC, 110);
+        assert_eq!(ELIBMAX, 123);
+        assert_eq!(ELIBSCN, 124);
+        assert_eq!(ELNRNG, 98);
+        assert_eq!(ELOOP, 62);
+        assert_eq!(EMEDIUMTYPE, 126);
+        assert_eq!(EMSGSIZE, 40);
+        assert_eq!(EMULTIHOP, 87);
+        assert_eq!(ENAMETOOLONG, 63);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 50);
+        assert_eq!(ENETRESET, 52);
+        assert_eq!(ENETUNREACH, 51);
+        assert_eq!(ENOANO, 105);
+        assert_eq!(ENOBUFS, 55);
+        assert_eq!(ENOCSI, 100);
+        assert_eq!(ENODATA, 111);
+        assert_eq!(ENOKEY, 128);
+        assert_eq!(ENOLCK, 79);
+        assert_eq!(ENOLINK, 82);
+        assert_eq!(ENOMEDIUM, 125);
+        assert_eq!(ENOMSG, 75);
+        assert_eq!(ENONET, 80);
+        assert_eq!(ENOPKG, 113);
+        assert_eq!(ENOPROTOOPT, 42);
+        assert_eq!(ENOSR, 74);
+        assert_eq!(ENOSTR, 72);
+        assert_eq!(ENOSYS, 90);
+        assert_eq!(ENOTCONN, 57);
+        assert_eq!(ENOTEMPTY, 66);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 133);
+        assert_eq!(ENOTSOCK, 38);
+        assert_eq!(ENOTUNIQ, 115);
+        assert_eq!(EOPNOTSUPP, 45);
+        assert_eq!(EOVERFLOW, 92);
+        assert_eq!(EOWNERDEAD, 132);
+        assert_eq!(EPFNOSUPPORT, 46);
+        assert_eq!(EPROTO, 86);
+        assert_eq!(EPROTONOSUPPORT, 43);
+        assert_eq!(EPROTOTYPE, 41);
+        assert_eq!(EREMCHG, 89);
+        assert_eq!(EREMOTE, 71);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 116);
+        assert_eq!(ERFKILL, 134);
+        assert_eq!(ESHUTDOWN, 58);
+        assert_eq!(ESOCKTNOSUPPORT, 44);
+        assert_eq!(ESRMNT, 84);
+        assert_eq!(ESTALE, 70);
+        assert_eq!(ESTRPIPE, 91);
+        assert_eq!(ETIME, 73);
+        assert_eq!(ETIMEDOUT, 60);
+        assert_eq!(ETOOMANYREFS, 59);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 99);
+        assert_eq!(EUSERS, 68);
+        assert_eq!(EXFULL, 104);
+    }
+
+    #[cfg(target_arch = "sparc64")]
+    {
+        assert_eq!(EADDRINUSE, 48);
+        assert_eq!(EADDRNOTAVAIL, 49);
+        assert_eq!(EADV, 83);
+        assert_eq!(EAFNOSUPPORT, 47);
+        assert_eq!(EALREADY, 37);
+        assert_eq!(EBADE, 102);
+        assert_eq!(EBADFD, 93);
+        assert_eq!(EBADMSG, 76);
+        assert_eq!(EBADR, 103);
+        assert_eq!(EBADRQC, 106);
+        assert_eq!(EBADSLT, 107);
+        assert_eq!(EBFONT, 109);
+        assert_eq!(ECANCELED, 127);
+        assert_eq!(ECHRNG, 94);
+        assert_eq!(ECOMM, 85);
+        assert_eq!(ECONNABORTED, 53);
+        assert_eq!(ECONNREFUSED, 61);
+        assert_eq!(ECONNRESET, 54);
+        assert_eq!(EDEADLK, 78);
+        assert_eq!(EDEADLOCK, 108);
+        assert_eq!(EDESTADDRREQ, 39);
+        assert_eq!(EDOTDOT, 88);
+        assert_eq!(EDQUOT, 69);
+        assert_eq!(EHOSTDOWN, 64);
+        assert_eq!(EHOSTUNREACH, 65);
+        assert_eq!(EHWPOISON, 135);
+        assert_eq!(EIDRM, 77);
+        assert_eq!(EILSEQ, 122);
+        assert_eq!(EINPROGRESS, 36);
+        assert_eq!(EISCONN, 56);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 129);
+        assert_eq!(EKEYREJECTED, 131);
+        assert_eq!(EKEYREVOKED, 130);
+        assert_eq!(EL2HLT, 101);
+        assert_eq!(EL2NSYNC, 95);
+        assert_eq!(EL3HLT, 96);
+        assert_eq!(EL3RST, 97);
+        assert_eq!(ELIBACC, 114);
+        assert_eq!(ELIBBAD, 112);
+        assert_eq!(ELIBEXEC, 110);
+        assert_eq!(ELIBMAX, 123);
+        assert_eq!(ELIBSCN, 124);
+        assert_eq!(ELNRNG, 98);
+        assert_eq!(ELOOP, 62);
+        assert_eq!(EMEDIUMTYPE, 126);
+        assert_eq!(EMSGSIZE, 40);
+        assert_eq!(EMULTIHOP, 87);
+        assert_eq!(ENAMETOOLONG, 63);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 50);
+        assert_eq!(ENETRESET, 52);
+        assert_eq!(ENETUNREACH, 51);
+        assert_eq!(ENOANO, 105);
+        assert_eq!(ENOBUFS, 55);
+        assert_eq!(ENOCSI, 100);
+        assert_eq!(ENODATA, 111);
+        assert_eq!(ENOKEY, 128);
+        assert_eq!(ENOLCK, 79);
+        assert_eq!(ENOLINK, 82);
+        assert_eq!(ENOMEDIUM, 125);
+        assert_eq!(ENOMSG, 75);
+        assert_eq!(ENONET, 80);
+        assert_eq!(ENOPKG, 113);
+        assert_eq!(ENOPROTOOPT, 42);
+        assert_eq!(ENOSR, 74);
+        assert_eq!(ENOSTR, 72);
+        assert_eq!(ENOSYS, 90);
+        assert_eq!(ENOTCONN, 57);
+        assert_eq!(ENOTEMPTY, 66);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 133);
+        assert_eq!(ENOTSOCK, 38);
+        assert_eq!(ENOTUNIQ, 115);
+        assert_eq!(EOPNOTSUPP, 45);
+        assert_eq!(EOVERFLOW, 92);
+        assert_eq!(EOWNERDEAD, 132);
+        assert_eq!(EPFNOSUPPORT, 46);
+        assert_eq!(EPROTO, 86);
+        assert_eq!(EPROTONOSUPPORT, 43);
+        assert_eq!(EPROTOTYPE, 41);
+        assert_eq!(EREMCHG, 89);
+        assert_eq!(EREMOTE, 71);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 116);
+        assert_eq!(ERFKILL, 134);
+        assert_eq!(ESHUTDOWN, 58);
+        assert_eq!(ESOCKTNOSUPPORT, 44);
+        assert_eq!(ESRMNT, 84);
+        assert_eq!(ESTALE, 70);
+        assert_eq!(ESTRPIPE, 91);
+        assert_eq!(ETIME, 73);
+        assert_eq!(ETIMEDOUT, 60);
+        assert_eq!(ETOOMANYREFS, 59);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 99);
+        assert_eq!(EUSERS, 68);
+        assert_eq!(EXFULL, 104);
+    }
+
+    #[cfg(target_arch = "x86")]
+    {
+        assert_eq!(EADDRINUSE, 98);
+        assert_eq!(EADDRNOTAVAIL, 99);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 97);
+        assert_eq!(EALREADY, 114);
+        assert_eq!(EBADE, 52);
+        assert_eq!(EBADFD, 77);
+        assert_eq!(EBADMSG, 74);
+        assert_eq!(EBADR, 53);
+        assert_eq!(EBADRQC, 56);
+        assert_eq!(EBADSLT, 57);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 125);
+        assert_eq!(ECHRNG, 44);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 103);
+        assert_eq!(ECONNREFUSED, 111);
+        assert_eq!(ECONNRESET, 104);
+        assert_eq!(EDEADLK, 35);
+        assert_eq!(EDEADLOCK, 35);
+        assert_eq!(EDESTADDRREQ, 89);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 122);
+        assert_eq!(EHOSTDOWN, 112);
+        assert_eq!(EHOSTUNREACH, 113);
+        assert_eq!(EHWPOISON, 133);
+        assert_eq!(EIDRM, 43);
+        assert_eq!(EILSEQ, 84);
+        assert_eq!(EINPROGRESS, 115);
+        assert_eq!(EISCONN, 106);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 127);
+        assert_eq!(EKEYREJECTED, 129);
+        assert_eq!(EKEYREVOKED, 128);
+        assert_eq!(EL2HLT, 51);
+        assert_eq!(EL2NSYNC, 45);
+        assert_eq!(EL3HLT, 46);
+        assert_eq!(EL3RST, 47);
+        assert_eq!(ELIBACC, 79);
+        assert_eq!(ELIBBAD, 80);
+        assert_eq!(ELIBEXEC, 83);
+        assert_eq!(ELIBMAX, 82);
+        assert_eq!(ELIBSCN, 81);
+        assert_eq!(ELNRNG, 48);
+        assert_eq!(ELOOP, 40);
+        assert_eq!(EMEDIUMTYPE, 124);
+        assert_eq!(EMSGSIZE, 90);
+        assert_eq!(EMULTIHOP, 72);
+        assert_eq!(ENAMETOOLONG, 36);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 100);
+        assert_eq!(ENETRESET, 102);
+        assert_eq!(ENETUNREACH, 101);
+        assert_eq!(ENOANO, 55);
+        assert_eq!(ENOBUFS, 105);
+        assert_eq!(ENOCSI, 50);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 126);
+        assert_eq!(ENOLCK, 37);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 123);
+        assert_eq!(ENOMSG, 42);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 92);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 38);
+        assert_eq!(ENOTCONN, 107);
+        assert_eq!(ENOTEMPTY, 39);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 131);
+        assert_eq!(ENOTSOCK, 88);
+        assert_eq!(ENOTUNIQ, 76);
+        assert_eq!(EOPNOTSUPP, 95);
+        assert_eq!(EOVERFLOW, 75);
+        assert_eq!(EOWNERDEAD, 130);
+        assert_eq!(EPFNOSUPPORT, 96);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 93);
+        assert_eq!(EPROTOTYPE, 91);
+        assert_eq!(EREMCHG, 78);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 85);
+        assert_eq!(ERFKILL, 132);
+        assert_eq!(ESHUTDOWN, 108);
+        assert_eq!(ESOCKTNOSUPPORT, 94);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 116);
+        assert_eq!(ESTRPIPE, 86);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 110);
+        assert_eq!(ETOOMANYREFS, 109);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 49);
+        assert_eq!(EUSERS, 87);
+        assert_eq!(EXFULL, 54);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        assert_eq!(EADDRINUSE, 98);
+        assert_eq!(EADDRNOTAVAIL, 99);
+        assert_eq!(EADV, 68);
+        assert_eq!(EAFNOSUPPORT, 97);
+        assert_eq!(EALREADY, 114);
+        assert_eq!(EBADE, 52);
+        assert_eq!(EBADFD, 77);
+        assert_eq!(EBADMSG, 74);
+        assert_eq!(EBADR, 53);
+        assert_eq!(EBADRQC, 56);
+        assert_eq!(EBADSLT, 57);
+        assert_eq!(EBFONT, 59);
+        assert_eq!(ECANCELED, 125);
+        assert_eq!(ECHRNG, 44);
+        assert_eq!(ECOMM, 70);
+        assert_eq!(ECONNABORTED, 103);
+        assert_eq!(ECONNREFUSED, 111);
+        assert_eq!(ECONNRESET, 104);
+        assert_eq!(EDEADLK, 35);
+        assert_eq!(EDEADLOCK, 35);
+        assert_eq!(EDESTADDRREQ, 89);
+        assert_eq!(EDOTDOT, 73);
+        assert_eq!(EDQUOT, 122);
+        assert_eq!(EHOSTDOWN, 112);
+        assert_eq!(EHOSTUNREACH, 113);
+        assert_eq!(EHWPOISON, 133);
+        assert_eq!(EIDRM, 43);
+        assert_eq!(EILSEQ, 84);
+        assert_eq!(EINPROGRESS, 115);
+        assert_eq!(EISCONN, 106);
+        assert_eq!(EISNAM, 120);
+        assert_eq!(EKEYEXPIRED, 127);
+        assert_eq!(EKEYREJECTED, 129);
+        assert_eq!(EKEYREVOKED, 128);
+        assert_eq!(EL2HLT, 51);
+        assert_eq!(EL2NSYNC, 45);
+        assert_eq!(EL3HLT, 46);
+        assert_eq!(EL3RST, 47);
+        assert_eq!(ELIBACC, 79);
+        assert_eq!(ELIBBAD, 80);
+        assert_eq!(ELIBEXEC, 83);
+        assert_eq!(ELIBMAX, 82);
+        assert_eq!(ELIBSCN, 81);
+        assert_eq!(ELNRNG, 48);
+        assert_eq!(ELOOP, 40);
+        assert_eq!(EMEDIUMTYPE, 124);
+        assert_eq!(EMSGSIZE, 90);
+        assert_eq!(EMULTIHOP, 72);
+        assert_eq!(ENAMETOOLONG, 36);
+        assert_eq!(ENAVAIL, 119);
+        assert_eq!(ENETDOWN, 100);
+        assert_eq!(ENETRESET, 102);
+        assert_eq!(ENETUNREACH, 101);
+        assert_eq!(ENOANO, 55);
+        assert_eq!(ENOBUFS, 105);
+        assert_eq!(ENOCSI, 50);
+        assert_eq!(ENODATA, 61);
+        assert_eq!(ENOKEY, 126);
+        assert_eq!(ENOLCK, 37);
+        assert_eq!(ENOLINK, 67);
+        assert_eq!(ENOMEDIUM, 123);
+        assert_eq!(ENOMSG, 42);
+        assert_eq!(ENONET, 64);
+        assert_eq!(ENOPKG, 65);
+        assert_eq!(ENOPROTOOPT, 92);
+        assert_eq!(ENOSR, 63);
+        assert_eq!(ENOSTR, 60);
+        assert_eq!(ENOSYS, 38);
+        assert_eq!(ENOTCONN, 107);
+        assert_eq!(ENOTEMPTY, 39);
+        assert_eq!(ENOTNAM, 118);
+        assert_eq!(ENOTRECOVERABLE, 131);
+        assert_eq!(ENOTSOCK, 88);
+        assert_eq!(ENOTUNIQ, 76);
+        assert_eq!(EOPNOTSUPP, 95);
+        assert_eq!(EOVERFLOW, 75);
+        assert_eq!(EOWNERDEAD, 130);
+        assert_eq!(EPFNOSUPPORT, 96);
+        assert_eq!(EPROTO, 71);
+        assert_eq!(EPROTONOSUPPORT, 93);
+        assert_eq!(EPROTOTYPE, 91);
+        assert_eq!(EREMCHG, 78);
+        assert_eq!(EREMOTE, 66);
+        assert_eq!(EREMOTEIO, 121);
+        assert_eq!(ERESTART, 85);
+        assert_eq!(ERFKILL, 132);
+        assert_eq!(ESHUTDOWN, 108);
+        assert_eq!(ESOCKTNOSUPPORT, 94);
+        assert_eq!(ESRMNT, 69);
+        assert_eq!(ESTALE, 116);
+        assert_eq!(ESTRPIPE, 86);
+        assert_eq!(ETIME, 62);
+        assert_eq!(ETIMEDOUT, 110);
+        assert_eq!(ETOOMANYREFS, 109);
+        assert_eq!(EUCLEAN, 117);
+        assert_eq!(EUNATCH, 49);
+        assert_eq!(EUSERS, 87);
+        assert_eq!(EXFULL, 54);
+    }
+}
+
+#[cfg(feature = "general")]
+#[test]
+fn general() {
+    use linux_raw_sys::v5_11::general::*;
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        assert_eq!(B1000000, 4104);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4105);
+        assert_eq!(B1500000, 4106);
+        assert_eq!(B2000000, 4107);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4101);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4102);
+        assert_eq!(B921600, 4103);
+        assert_eq!(BLKPBSZGET, 4731);
+        assert_eq!(BLKSSZGET, 4712);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 2048);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 8192);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 4096);
+        assert_eq!(F_GETLK, 5);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 9);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 8);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 32768);
+        assert_eq!(IOCSIZE_MASK, 1073676288);
+        assert_eq!(IOC_IN, 1073741824);
+        assert_eq!(IOC_OUT, 2147483648);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 256);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 8192);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 16384);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 1);
+        assert_eq!(MCL_FUTURE, 2);
+        assert_eq!(MCL_ONFAULT, 4);
+        assert_eq!(MINSIGSTKSZ, 5120);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 19);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 1024);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 64);
+        assert_eq!(O_DIRECT, 65536);
+        assert_eq!(O_DIRECTORY, 16384);
+        assert_eq!(O_DSYNC, 4096);
+        assert_eq!(O_EXCL, 128);
+        assert_eq!(O_LARGEFILE, 131072);
+        assert_eq!(O_NDELAY, 2048);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 256);
+        assert_eq!(O_NOFOLLOW, 32768);
+        assert_eq!(O_NONBLOCK, 2048);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 1052672);
+        assert_eq!(O_TMPFILE, 4210688);
+        assert_eq!(O_TMPFILE_MASK, 4210752);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 512);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 7);
+        assert_eq!(RLIMIT_NPROC, 6);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 2);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 4);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 7);
+        assert_eq!(SIGCHLD, 17);
+        assert_eq!(SIGCONT, 18);
+        assert_eq!(SIGIO, 29);
+        assert_eq!(SIGPOLL, 29);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 30);
+        assert_eq!(SIGSTKSZ, 16384);
+        assert_eq!(SIGSTOP, 19);
+        assert_eq!(SIGSYS, 31);
+        assert_eq!(SIGTSTP, 20);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 23);
+        assert_eq!(SIGUSR1, 10);
+        assert_eq!(SIGUSR2, 12);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 0);
+        assert_eq!(SIG_SETMASK, 2);
+        assert_eq!(SIG_UNBLOCK, 1);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 1);
+        assert_eq!(SO_ACCEPTCONN, 30);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 6);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 39);
+        assert_eq!(SO_DONTROUTE, 5);
+        assert_eq!(SO_ERROR, 4);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 9);
+        assert_eq!(SO_LINGER, 13);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 10);
+        assert_eq!(SO_PASSCRED, 16);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 17);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 31);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 38);
+        assert_eq!(SO_RCVBUF, 8);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 18);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 20);
+        assert_eq!(SO_REUSEADDR, 2);
+        assert_eq!(SO_REUSEPORT, 15);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 7);
+        assert_eq!(SO_SNDBUFFORCE, 32);
+        assert_eq!(SO_SNDLOWAT, 19);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 21);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 3);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 0);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 526336);
+        assert_eq!(TFD_NONBLOCK, 2048);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 526336);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 256);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 11);
+        assert_eq!(VEOL2, 16);
+        assert_eq!(VMIN, 6);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 2);
+        assert_eq!(_IOC_DIRMASK, 3);
+        assert_eq!(_IOC_DIRSHIFT, 30);
+        assert_eq!(_IOC_NONE, 0);
+        assert_eq!(_IOC_SIZEBITS, 14);
+        assert_eq!(_IOC_SIZEMASK, 16383);
+        assert_eq!(_IOC_WRITE, 1);
+        assert_eq!(_NSIG, 64);
+        assert_eq!(__O_SYNC, 1048576);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+
+    #[cfg(target_arch = "arm")]
+    {
+        assert_eq!(B1000000, 4104);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4105);
+        assert_eq!(B1500000, 4106);
+        assert_eq!(B2000000, 4107);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4101);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4102);
+        assert_eq!(B921600, 4103);
+        assert_eq!(BLKPBSZGET, 4731);
+        assert_eq!(BLKSSZGET, 4712);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 2048);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 8192);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 4096);
+        assert_eq!(F_GETLK, 5);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 9);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 8);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 32768);
+        assert_eq!(IOCSIZE_MASK, 1073676288);
+        assert_eq!(IOC_IN, 1073741824);
+        assert_eq!(IOC_OUT, 2147483648);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 256);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 8192);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 16384);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 1);
+        assert_eq!(MCL_FUTURE, 2);
+        assert_eq!(MCL_ONFAULT, 4);
+        assert_eq!(MINSIGSTKSZ, 2048);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 19);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 1024);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 64);
+        assert_eq!(O_DIRECT, 65536);
+        assert_eq!(O_DIRECTORY, 16384);
+        assert_eq!(O_DSYNC, 4096);
+        assert_eq!(O_EXCL, 128);
+        assert_eq!(O_LARGEFILE, 131072);
+        assert_eq!(O_NDELAY, 2048);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 256);
+        assert_eq!(O_NOFOLLOW, 32768);
+        assert_eq!(O_NONBLOCK, 2048);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 1052672);
+        assert_eq!(O_TMPFILE, 4210688);
+        assert_eq!(O_TMPFILE_MASK, 4210752);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 512);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 7);
+        assert_eq!(RLIMIT_NPROC, 6);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 2);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 4);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 7);
+        assert_eq!(SIGCHLD, 17);
+        assert_eq!(SIGCONT, 18);
+        assert_eq!(SIGIO, 29);
+        assert_eq!(SIGPOLL, 29);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 30);
+        assert_eq!(SIGSTKSZ, 8192);
+        assert_eq!(SIGSTOP, 19);
+        assert_eq!(SIGSYS, 31);
+        assert_eq!(SIGTSTP, 20);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 23);
+        assert_eq!(SIGUSR1, 10);
+        assert_eq!(SIGUSR2, 12);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 0);
+        assert_eq!(SIG_SETMASK, 2);
+        assert_eq!(SIG_UNBLOCK, 1);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 1);
+        assert_eq!(SO_ACCEPTCONN, 30);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 6);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 39);
+        assert_eq!(SO_DONTROUTE, 5);
+        assert_eq!(SO_ERROR, 4);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 9);
+        assert_eq!(SO_LINGER, 13);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 10);
+        assert_eq!(SO_PASSCRED, 16);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 17);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 31);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 38);
+        assert_eq!(SO_RCVBUF, 8);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 18);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 20);
+        assert_eq!(SO_REUSEADDR, 2);
+        assert_eq!(SO_REUSEPORT, 15);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 7);
+        assert_eq!(SO_SNDBUFFORCE, 32);
+        assert_eq!(SO_SNDLOWAT, 19);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 21);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 3);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 0);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 526336);
+        assert_eq!(TFD_NONBLOCK, 2048);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 526336);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 256);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 11);
+        assert_eq!(VEOL2, 16);
+        assert_eq!(VMIN, 6);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 2);
+        assert_eq!(_IOC_DIRMASK, 3);
+        assert_eq!(_IOC_DIRSHIFT, 30);
+        assert_eq!(_IOC_NONE, 0);
+        assert_eq!(_IOC_SIZEBITS, 14);
+        assert_eq!(_IOC_SIZEMASK, 16383);
+        assert_eq!(_IOC_WRITE, 1);
+        assert_eq!(_NSIG, 64);
+        assert_eq!(__O_SYNC, 1048576);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+
+    #[cfg(target_arch = "mips")]
+    {
+        assert_eq!(B1000000, 4104);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4105);
+        assert_eq!(B1500000, 4106);
+        assert_eq!(B2000000, 4107);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4101);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4102);
+        assert_eq!(B921600, 4103);
+        assert_eq!(BLKPBSZGET, 536875643);
+        assert_eq!(BLKSSZGET, 536875624);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 128);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 4096);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 8192);
+        assert_eq!(F_GETLK, 14);
+        assert_eq!(F_GETLK64, 33);
+        assert_eq!(F_GETOWN, 23);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 34);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 35);
+        assert_eq!(F_SETOWN, 24);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 256);
+        assert_eq!(IOCSIZE_MASK, 536805376);
+        assert_eq!(IOC_IN, 2147483648);
+        assert_eq!(IOC_OUT, 1073741824);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 2048);
+        assert_eq!(MAP_DENYWRITE, 8192);
+        assert_eq!(MAP_EXECUTABLE, 16384);
+        assert_eq!(MAP_GROWSDOWN, 4096);
+        assert_eq!(MAP_HUGETLB, 524288);
+        assert_eq!(MAP_LOCKED, 32768);
+        assert_eq!(MAP_NONBLOCK, 131072);
+        assert_eq!(MAP_NORESERVE, 1024);
+        assert_eq!(MAP_POPULATE, 65536);
+        assert_eq!(MAP_STACK, 262144);
+        assert_eq!(MCL_CURRENT, 1);
+        assert_eq!(MCL_FUTURE, 2);
+        assert_eq!(MCL_ONFAULT, 4);
+        assert_eq!(MINSIGSTKSZ, 2048);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 23);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 8);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 256);
+        assert_eq!(O_DIRECT, 32768);
+        assert_eq!(O_DIRECTORY, 65536);
+        assert_eq!(O_DSYNC, 16);
+        assert_eq!(O_EXCL, 1024);
+        assert_eq!(O_LARGEFILE, 8192);
+        assert_eq!(O_NDELAY, 128);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 2048);
+        assert_eq!(O_NOFOLLOW, 131072);
+        assert_eq!(O_NONBLOCK, 128);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 16400);
+        assert_eq!(O_TMPFILE, 4259840);
+        assert_eq!(O_TMPFILE_MASK, 4260096);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 256);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 16);
+        assert_eq!(RLIMIT_AS, 6);
+        assert_eq!(RLIMIT_MEMLOCK, 9);
+        assert_eq!(RLIMIT_NOFILE, 5);
+        assert_eq!(RLIMIT_NPROC, 8);
+        assert_eq!(RLIMIT_RSS, 7);
+        assert_eq!(RLIM_INFINITY, 2147483647);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 65536);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 8);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 10);
+        assert_eq!(SIGCHLD, 18);
+        assert_eq!(SIGCONT, 25);
+        assert_eq!(SIGIO, 22);
+        assert_eq!(SIGPOLL, 22);
+        assert_eq!(SIGPROF, 29);
+        assert_eq!(SIGPWR, 19);
+        assert_eq!(SIGSTKSZ, 8192);
+        assert_eq!(SIGSTOP, 23);
+        assert_eq!(SIGSYS, 12);
+        assert_eq!(SIGTSTP, 24);
+        assert_eq!(SIGTTIN, 26);
+        assert_eq!(SIGTTOU, 27);
+        assert_eq!(SIGURG, 21);
+        assert_eq!(SIGUSR1, 16);
+        assert_eq!(SIGUSR2, 17);
+        assert_eq!(SIGVTALRM, 28);
+        assert_eq!(SIGWINCH, 20);
+        assert_eq!(SIGXCPU, 30);
+        assert_eq!(SIGXFSZ, 31);
+        assert_eq!(SIG_BLOCK, 1);
+        assert_eq!(SIG_SETMASK, 3);
+        assert_eq!(SIG_UNBLOCK, 2);
+        assert_eq!(SOCK_DGRAM, 1);
+        assert_eq!(SOCK_STREAM, 2);
+        assert_eq!(SOL_SOCKET, 65535);
+        assert_eq!(SO_ACCEPTCONN, 4105);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 32);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 4137);
+        assert_eq!(SO_DONTROUTE, 16);
+        assert_eq!(SO_ERROR, 4103);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 8);
+        assert_eq!(SO_LINGER, 128);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 256);
+        assert_eq!(SO_PASSCRED, 17);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 18);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 30);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 4136);
+        assert_eq!(SO_RCVBUF, 4098);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 4100);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 4102);
+        assert_eq!(SO_REUSEADDR, 4);
+        assert_eq!(SO_REUSEPORT, 512);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 4097);
+        assert_eq!(SO_SNDBUFFORCE, 31);
+        assert_eq!(SO_SNDLOWAT, 4099);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 4101);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 4104);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 4000);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21638);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 524416);
+        assert_eq!(TFD_NONBLOCK, 128);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 524416);
+        assert_eq!(TIOCGICOUNT, 21650);
+        assert_eq!(TIOCGLCKTRMIOS, 21643);
+        assert_eq!(TIOCGSERIAL, 21636);
+        assert_eq!(TIOCLINUX, 21635);
+        assert_eq!(TIOCMIWAIT, 21649);
+        assert_eq!(TIOCM_CAR, 256);
+        assert_eq!(TIOCM_CD, 256);
+        assert_eq!(TIOCM_CTS, 64);
+        assert_eq!(TIOCM_DSR, 1024);
+        assert_eq!(TIOCM_RI, 512);
+        assert_eq!(TIOCM_RNG, 512);
+        assert_eq!(TIOCM_SR, 32);
+        assert_eq!(TIOCM_ST, 16);
+        assert_eq!(TIOCSERCONFIG, 21640);
+        assert_eq!(TIOCSERGETLSR, 21646);
+        assert_eq!(TIOCSERGETMULTI, 21647);
+        assert_eq!(TIOCSERGSTRUCT, 21645);
+        assert_eq!(TIOCSERGWILD, 21641);
+        assert_eq!(TIOCSERSETMULTI, 21648);
+        assert_eq!(TIOCSERSWILD, 21642);
+        assert_eq!(TIOCSLCKTRMIOS, 21644);
+        assert_eq!(TIOCSSERIAL, 21637);
+        assert_eq!(TOSTOP, 32768);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 16);
+        assert_eq!(VEOL, 17);
+        assert_eq!(VEOL2, 6);
+        assert_eq!(VMIN, 4);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 3);
+        assert_eq!(_IOC_DIRMASK, 7);
+        assert_eq!(_IOC_DIRSHIFT, 29);
+        assert_eq!(_IOC_NONE, 1);
+        assert_eq!(_IOC_SIZEBITS, 13);
+        assert_eq!(_IOC_SIZEMASK, 8191);
+        assert_eq!(_IOC_WRITE, 4);
+        assert_eq!(_NSIG, 128);
+        assert_eq!(__O_SYNC, 16384);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+
+    #[cfg(target_arch = "mips64")]
+    {
+        assert_eq!(B1000000, 4104);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4105);
+        assert_eq!(B1500000, 4106);
+        assert_eq!(B2000000, 4107);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4101);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4102);
+        assert_eq!(B921600, 4103);
+        assert_eq!(BLKPBSZGET, 536875643);
+        assert_eq!(BLKSSZGET, 536875624);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 128);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 4096);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 8192);
+        assert_eq!(F_GETLK, 14);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 23);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 24);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 256);
+        assert_eq!(IOCSIZE_MASK, 536805376);
+        assert_eq!(IOC_IN, 2147483648);
+        assert_eq!(IOC_OUT, 1073741824);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 2048);
+        assert_eq!(MAP_DENYWRITE, 8192);
+        assert_eq!(MAP_EXECUTABLE, 16384);
+        assert_eq!(MAP_GROWSDOWN, 4096);
+        assert_eq!(MAP_HUGETLB, 524288);
+        assert_eq!(MAP_LOCKED, 32768);
+        assert_eq!(MAP_NONBLOCK, 131072);
+        assert_eq!(MAP_NORESERVE, 1024);
+        assert_eq!(MAP_POPULATE, 65536);
+        assert_eq!(MAP_STACK, 262144);
+        assert_eq!(MCL_CURRENT, 1);
+        assert_eq!(MCL_FUTURE, 2);
+        assert_eq!(MCL_ONFAULT, 4);
+        assert_eq!(MINSIGSTKSZ, 2048);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 23);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 8);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 256);
+        assert_eq!(O_DIRECT, 32768);
+        assert_eq!(O_DIRECTORY, 65536);
+        assert_eq!(O_DSYNC, 16);
+        assert_eq!(O_EXCL, 1024);
+        assert_eq!(O_LARGEFILE, 8192);
+        assert_eq!(O_NDELAY, 128);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 2048);
+        assert_eq!(O_NOFOLLOW, 131072);
+        assert_eq!(O_NONBLOCK, 128);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 16400);
+        assert_eq!(O_TMPFILE, 4259840);
+        assert_eq!(O_TMPFILE_MASK, 4260096);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 256);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 16);
+        assert_eq!(RLIMIT_AS, 6);
+        assert_eq!(RLIMIT_MEMLOCK, 9);
+        assert_eq!(RLIMIT_NOFILE, 5);
+        assert_eq!(RLIMIT_NPROC, 8);
+        assert_eq!(RLIMIT_RSS, 7);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 65536);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 8);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 10);
+        assert_eq!(SIGCHLD, 18);
+        assert_eq!(SIGCONT, 25);
+        assert_eq!(SIGIO, 22);
+        assert_eq!(SIGPOLL, 22);
+        assert_eq!(SIGPROF, 29);
+        assert_eq!(SIGPWR, 19);
+        assert_eq!(SIGSTKSZ, 8192);
+        assert_eq!(SIGSTOP, 23);
+        assert_eq!(SIGSYS, 12);
+        assert_eq!(SIGTSTP, 24);
+        assert_eq!(SIGTTIN, 26);
+        assert_eq!(SIGTTOU, 27);
+        assert_eq!(SIGURG, 21);
+        assert_eq!(SIGUSR1, 16);
+        assert_eq!(SIGUSR2, 17);
+        assert_eq!(SIGVTALRM, 28);
+        assert_eq!(SIGWINCH, 20);
+        assert_eq!(SIGXCPU, 30);
+        assert_eq!(SIGXFSZ, 31);
+        assert_eq!(SIG_BLOCK, 1);
+        assert_eq!(SIG_SETMASK, 3);
+        assert_eq!(SIG_UNBLOCK, 2);
+        assert_eq!(SOCK_DGRAM, 1);
+        assert_eq!(SOCK_STREAM, 2);
+        assert_eq!(SOL_SOCKET, 65535);
+        assert_eq!(SO_ACCEPTCONN, 4105);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 32);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 4137);
+        assert_eq!(SO_DONTROUTE, 16);
+        assert_eq!(SO_ERROR, 4103);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 8);
+        assert_eq!(SO_LINGER, 128);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 256);
+        assert_eq!(SO_PASSCRED, 17);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 18);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 30);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 4136);
+        assert_eq!(SO_RCVBUF, 4098);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 4100);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 4102);
+        assert_eq!(SO_REUSEADDR, 4);
+        assert_eq!(SO_REUSEPORT, 512);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 4097);
+        assert_eq!(SO_SNDBUFFORCE, 31);
+        assert_eq!(SO_SNDLOWAT, 4099);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 4101);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 4104);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 5000);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21638);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 524416);
+        assert_eq!(TFD_NONBLOCK, 128);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 524416);
+        assert_eq!(TIOCGICOUNT, 21650);
+        assert_eq!(TIOCGLCKTRMIOS, 21643);
+        assert_eq!(TIOCGSERIAL, 21636);
+        assert_eq!(TIOCLINUX, 21635);
+        assert_eq!(TIOCMIWAIT, 21649);
+        assert_eq!(TIOCM_CAR, 256);
+        assert_eq!(TIOCM_CD, 256);
+        assert_eq!(TIOCM_CTS, 64);
+        assert_eq!(TIOCM_DSR, 1024);
+        assert_eq!(TIOCM_RI, 512);
+        assert_eq!(TIOCM_RNG, 512);
+        assert_eq!(TIOCM_SR, 32);
+        assert_eq!(TIOCM_ST, 16);
+        assert_eq!(TIOCSERCONFIG, 21640);
+        assert_eq!(TIOCSERGETLSR, 21646);
+        assert_eq!(TIOCSERGETMULTI, 21647);
+        assert_eq!(TIOCSERGSTRUCT, 21645);
+        assert_eq!(TIOCSERGWILD, 21641);
+        assert_eq!(TIOCSERSETMULTI, 21648);
+        assert_eq!(TIOCSERSWILD, 21642);
+        assert_eq!(TIOCSLCKTRMIOS, 21644);
+        assert_eq!(TIOCSSERIAL, 21637);
+        assert_eq!(TOSTOP, 32768);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 16);
+        assert_eq!(VEOL, 17);
+        assert_eq!(VEOL2, 6);
+        assert_eq!(VMIN, 4);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 3);
+        assert_eq!(_IOC_DIRMASK, 7);
+        assert_eq!(_IOC_DIRSHIFT, 29);
+        assert_eq!(_IOC_NONE, 1);
+        assert_eq!(_IOC_SIZEBITS, 13);
+        assert_eq!(_IOC_SIZEMASK, 8191);
+        assert_eq!(_IOC_WRITE, 4);
+        assert_eq!(_NSIG, 128);
+        assert_eq!(__O_SYNC, 16384);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+
+    #[cfg(target_arch = "powerpc")]
+    {
+        assert_eq!(B1000000, 23);
+        assert_eq!(B115200, 17);
+        assert_eq!(B1152000, 24);
+        assert_eq!(B1500000, 25);
+        assert_eq!(B2000000, 26);
+        assert_eq!(B230400, 18);
+        assert_eq!(B460800, 19);
+        assert_eq!(B500000, 20);
+        assert_eq!(B57600, 16);
+        assert_eq!(B576000, 21);
+        assert_eq!(B921600, 22);
+        assert_eq!(BLKPBSZGET, 536875643);
+        assert_eq!(BLKSSZGET, 536875624);
+        assert_eq!(BOTHER, 31);
+        assert_eq!(BS1, 32768);
+        assert_eq!(BSDLY, 32768);
+        assert_eq!(CBAUD, 255);
+        assert_eq!(CBAUDEX, 0);
+        assert_eq!(CIBAUD, 16711680);
+        assert_eq!(CLOCAL, 32768);
+        assert_eq!(CR1, 4096);
+        assert_eq!(CR2, 8192);
+        assert_eq!(CR3, 12288);
+        assert_eq!(CRDLY, 12288);
+        assert_eq!(CREAD, 2048);
+        assert_eq!(CS6, 256);
+        assert_eq!(CS7, 512);
+        assert_eq!(CS8, 768);
+        assert_eq!(CSIZE, 768);
+        assert_eq!(CSTOPB, 1024);
+        assert_eq!(ECHOCTL, 64);
+        assert_eq!(ECHOE, 2);
+        assert_eq!(ECHOK, 4);
+        assert_eq!(ECHOKE, 1);
+        assert_eq!(ECHONL, 16);
+        assert_eq!(ECHOPRT, 32);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 2048);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 268435456);
+        assert_eq!(FASYNC, 8192);
+        assert_eq!(FF1, 16384);
+        assert_eq!(FFDLY, 16384);
+        assert_eq!(FLUSHO, 8388608);
+        assert_eq!(F_GETLK, 5);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 9);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 8);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 16384);
+        assert_eq!(ICANON, 256);
+        assert_eq!(IEXTEN, 1024);
+        assert_eq!(IOCSIZE_MASK, 536805376);
+        assert_eq!(IOC_IN, 2147483648);
+        assert_eq!(IOC_OUT, 1073741824);
+        assert_eq!(ISIG, 128);
+        assert_eq!(IUCLC, 4096);
+        assert_eq!(IXOFF, 1024);
+        assert_eq!(IXON, 512);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 256);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 128);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 64);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 8192);
+        assert_eq!(MCL_FUTURE, 16384);
+        assert_eq!(MCL_ONFAULT, 32768);
+        assert_eq!(MINSIGSTKSZ, 2048);
+        assert_eq!(NCC, 10);
+        assert_eq!(NCCS, 19);
+        assert_eq!(NLDLY, 768);
+        assert_eq!(NOFLSH, 2147483648);
+        assert_eq!(OLCUC, 4);
+        assert_eq!(ONLCR, 2);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 1024);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 64);
+        assert_eq!(O_DIRECT, 131072);
+        assert_eq!(O_DIRECTORY, 16384);
+        assert_eq!(O_DSYNC, 4096);
+        assert_eq!(O_EXCL, 128);
+        assert_eq!(O_LARGEFILE, 65536);
+        assert_eq!(O_NDELAY, 2048);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 256);
+        assert_eq!(O_NOFOLLOW, 32768);
+        assert_eq!(O_NONBLOCK, 2048);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 1052672);
+        assert_eq!(O_TMPFILE, 4210688);
+        assert_eq!(O_TMPFILE_MASK, 4210752);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 4096);
+        assert_eq!(PARODD, 8192);
+        assert_eq!(PENDIN, 536870912);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 512);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 7);
+        assert_eq!(RLIMIT_NPROC, 6);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 2);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 4);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 7);
+        assert_eq!(SIGCHLD, 17);
+        assert_eq!(SIGCONT, 18);
+        assert_eq!(SIGIO, 29);
+        assert_eq!(SIGPOLL, 29);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 30);
+        assert_eq!(SIGSTKSZ, 8192);
+        assert_eq!(SIGSTOP, 19);
+        assert_eq!(SIGSYS, 31);
+        assert_eq!(SIGTSTP, 20);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 23);
+        assert_eq!(SIGUSR1, 10);
+        assert_eq!(SIGUSR2, 12);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 0);
+        assert_eq!(SIG_SETMASK, 2);
+        assert_eq!(SIG_UNBLOCK, 1);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 1);
+        assert_eq!(SO_ACCEPTCONN, 30);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 6);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 39);
+        assert_eq!(SO_DONTROUTE, 5);
+        assert_eq!(SO_ERROR, 4);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 9);
+        assert_eq!(SO_LINGER, 13);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 10);
+        assert_eq!(SO_PASSCRED, 20);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 21);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 31);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 38);
+        assert_eq!(SO_RCVBUF, 8);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 16);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 18);
+        assert_eq!(SO_REUSEADDR, 2);
+        assert_eq!(SO_REUSEPORT, 15);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 7);
+        assert_eq!(SO_SNDBUFFORCE, 32);
+        assert_eq!(SO_SNDLOWAT, 17);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 19);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 3);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 0);
+        assert_eq!(TAB1, 1024);
+        assert_eq!(TAB2, 2048);
+        assert_eq!(TAB3, 3072);
+        assert_eq!(TABDLY, 3072);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 526336);
+        assert_eq!(TFD_NONBLOCK, 2048);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 526336);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 4194304);
+        assert_eq!(VDISCARD, 16);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 6);
+        assert_eq!(VEOL2, 8);
+        assert_eq!(VMIN, 5);
+        assert_eq!(VREPRINT, 11);
+        assert_eq!(VSTART, 13);
+        assert_eq!(VSTOP, 14);
+        assert_eq!(VSUSP, 12);
+        assert_eq!(VSWTC, 9);
+        assert_eq!(VT1, 65536);
+        assert_eq!(VTDLY, 65536);
+        assert_eq!(VTIME, 7);
+        assert_eq!(VWERASE, 10);
+        assert_eq!(XCASE, 16384);
+        assert_eq!(XTABS, 3072);
+        assert_eq!(_IOC_DIRBITS, 3);
+        assert_eq!(_IOC_DIRMASK, 7);
+        assert_eq!(_IOC_DIRSHIFT, 29);
+        assert_eq!(_IOC_NONE, 1);
+        assert_eq!(_IOC_SIZEBITS, 13);
+        assert_eq!(_IOC_SIZEMASK, 8191);
+        assert_eq!(_IOC_WRITE, 4);
+        assert_eq!(_NSIG, 64);
+        assert_eq!(__O_SYNC, 1048576);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+
+    #[cfg(target_arch = "powerpc64")]
+    {
+        assert_eq!(B1000000, 23);
+        assert_eq!(B115200, 17);
+        assert_eq!(B1152000, 24);
+        assert_eq!(B1500000, 25);
+        assert_eq!(B2000000, 26);
+        assert_eq!(B230400, 18);
+        assert_eq!(B460800, 19);
+        assert_eq!(B500000, 20);
+        assert_eq!(B57600, 16);
+        assert_eq!(B576000, 21);
+        assert_eq!(B921600, 22);
+        assert_eq!(BLKPBSZGET, 536875643);
+        assert_eq!(BLKSSZGET, 536875624);
+        assert_eq!(BOTHER, 31);
+        assert_eq!(BS1, 32768);
+        assert_eq!(BSDLY, 32768);
+        assert_eq!(CBAUD, 255);
+        assert_eq!(CBAUDEX, 0);
+        assert_eq!(CIBAUD, 16711680);
+        assert_eq!(CLOCAL, 32768);
+        assert_eq!(CR1, 4096);
+        assert_eq!(CR2, 8192);
+        assert_eq!(CR3, 12288);
+        assert_eq!(CRDLY, 12288);
+        assert_eq!(CREAD, 2048);
+        assert_eq!(CS6, 256);
+        assert_eq!(CS7, 512);
+        assert_eq!(CS8, 768);
+        assert_eq!(CSIZE, 768);
+        assert_eq!(CSTOPB, 1024);
+        assert_eq!(ECHOCTL, 64);
+        assert_eq!(ECHOE, 2);
+        assert_eq!(ECHOK, 4);
+        assert_eq!(ECHOKE, 1);
+        assert_eq!(ECHONL, 16);
+        assert_eq!(ECHOPRT, 32);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 2048);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 268435456);
+        assert_eq!(FASYNC, 8192);
+        assert_eq!(FF1, 16384);
+        assert_eq!(FFDLY, 16384);
+        assert_eq!(FLUSHO, 8388608);
+        assert_eq!(F_GETLK, 5);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 9);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 8);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 16384);
+        assert_eq!(ICANON, 256);
+        assert_eq!(IEXTEN, 1024);
+        assert_eq!(IOCSIZE_MASK, 536805376);
+        assert_eq!(IOC_IN, 2147483648);
+        assert_eq!(IOC_OUT, 1073741824);
+        assert_eq!(ISIG, 128);
+        assert_eq!(IUCLC, 4096);
+        assert_eq!(IXOFF, 1024);
+        assert_eq!(IXON, 512);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 256);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 128);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 64);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 8192);
+        assert_eq!(MCL_FUTURE, 16384);
+        assert_eq!(MCL_ONFAULT, 32768);
+        assert_eq!(MINSIGSTKSZ, 2048);
+        assert_eq!(NCC, 10);
+        assert_eq!(NCCS, 19);
+        assert_eq!(NLDLY, 768);
+        assert_eq!(NOFLSH, 2147483648);
+        assert_eq!(OLCUC, 4);
+        assert_eq!(ONLCR, 2);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 1024);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 64);
+        assert_eq!(O_DIRECT, 131072);
+        assert_eq!(O_DIRECTORY, 16384);
+        assert_eq!(O_DSYNC, 4096);
+        assert_eq!(O_EXCL, 128);
+        assert_eq!(O_LARGEFILE, 65536);
+        assert_eq!(O_NDELAY, 2048);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 256);
+        assert_eq!(O_NOFOLLOW, 32768);
+        assert_eq!(O_NONBLOCK, 2048);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 1052672);
+        assert_eq!(O_TMPFILE, 4210688);
+        assert_eq!(O_TMPFILE_MASK, 4210752);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 4096);
+        assert_eq!(PARODD, 8192);
+        assert_eq!(PENDIN, 536870912);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 512);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 7);
+        assert_eq!(RLIMIT_NPROC, 6);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 2);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 4);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 7);
+        assert_eq!(SIGCHLD, 17);
+        assert_eq!(SIGCONT, 18);
+        assert_eq!(SIGIO, 29);
+        assert_eq!(SIGPOLL, 29);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 30);
+        assert_eq!(SIGSTKSZ, 8192);
+        assert_eq!(SIGSTOP, 19);
+        assert_eq!(SIGSYS, 31);
+        assert_eq!(SIGTSTP, 20);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 23);
+        assert_eq!(SIGUSR1, 10);
+        assert_eq!(SIGUSR2, 12);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 0);
+        assert_eq!(SIG_SETMASK, 2);
+        assert_eq!(SIG_UNBLOCK, 1);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 1);
+        assert_eq!(SO_ACCEPTCONN, 30);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 6);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 39);
+        assert_eq!(SO_DONTROUTE, 5);
+        assert_eq!(SO_ERROR, 4);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 9);
+        assert_eq!(SO_LINGER, 13);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 10);
+        assert_eq!(SO_PASSCRED, 20);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 21);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 31);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 38);
+        assert_eq!(SO_RCVBUF, 8);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 16);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 18);
+        assert_eq!(SO_REUSEADDR, 2);
+        assert_eq!(SO_REUSEPORT, 15);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 7);
+        assert_eq!(SO_SNDBUFFORCE, 32);
+        assert_eq!(SO_SNDLOWAT, 17);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 19);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 3);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 0);
+        assert_eq!(TAB1, 1024);
+        assert_eq!(TAB2, 2048);
+        assert_eq!(TAB3, 3072);
+        assert_eq!(TABDLY, 3072);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 526336);
+        assert_eq!(TFD_NONBLOCK, 2048);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 526336);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 4194304);
+        assert_eq!(VDISCARD, 16);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 6);
+        assert_eq!(VEOL2, 8);
+        assert_eq!(VMIN, 5);
+        assert_eq!(VREPRINT, 11);
+        assert_eq!(VSTART, 13);
+        assert_eq!(VSTOP, 14);
+        assert_eq!(VSUSP, 12);
+        assert_eq!(VSWTC, 9);
+        assert_eq!(VT1, 65536);
+        assert_eq!(VTDLY, 65536);
+        assert_eq!(VTIME, 7);
+        assert_eq!(VWERASE, 10);
+        assert_eq!(XCASE, 16384);
+        assert_eq!(XTABS, 3072);
+        assert_eq!(_IOC_DIRBITS, 3);
+        assert_eq!(_IOC_DIRMASK, 7);
+        assert_eq!(_IOC_DIRSHIFT, 29);
+        assert_eq!(_IOC_NONE, 1);
+        assert_eq!(_IOC_SIZEBITS, 13);
+        assert_eq!(_IOC_SIZEMASK, 8191);
+        assert_eq!(_IOC_WRITE, 4);
+        assert_eq!(_NSIG, 64);
+        assert_eq!(__O_SYNC, 1048576);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+
+    #[cfg(target_arch = "riscv32")]
+    {
+        assert_eq!(B1000000, 4104);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4105);
+        assert_eq!(B1500000, 4106);
+        assert_eq!(B2000000, 4107);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4101);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4102);
+        assert_eq!(B921600, 4103);
+        assert_eq!(BLKPBSZGET, 4731);
+        assert_eq!(BLKSSZGET, 4712);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 2048);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 8192);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 4096);
+        assert_eq!(F_GETLK, 5);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 9);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 8);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 32768);
+        assert_eq!(IOCSIZE_MASK, 1073676288);
+        assert_eq!(IOC_IN, 1073741824);
+        assert_eq!(IOC_OUT, 2147483648);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 256);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 8192);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 16384);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 1);
+        assert_eq!(MCL_FUTURE, 2);
+        assert_eq!(MCL_ONFAULT, 4);
+        assert_eq!(MINSIGSTKSZ, 2048);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 19);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 1024);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 64);
+        assert_eq!(O_DIRECT, 16384);
+        assert_eq!(O_DIRECTORY, 65536);
+        assert_eq!(O_DSYNC, 4096);
+        assert_eq!(O_EXCL, 128);
+        assert_eq!(O_LARGEFILE, 32768);
+        assert_eq!(O_NDELAY, 2048);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 256);
+        assert_eq!(O_NOFOLLOW, 131072);
+        assert_eq!(O_NONBLOCK, 2048);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 1052672);
+        assert_eq!(O_TMPFILE, 4259840);
+        assert_eq!(O_TMPFILE_MASK, 4259904);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 512);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 7);
+        assert_eq!(RLIMIT_NPROC, 6);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 2);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 4);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 7);
+        assert_eq!(SIGCHLD, 17);
+        assert_eq!(SIGCONT, 18);
+        assert_eq!(SIGIO, 29);
+        assert_eq!(SIGPOLL, 29);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 30);
+        assert_eq!(SIGSTKSZ, 8192);
+        assert_eq!(SIGSTOP, 19);
+        assert_eq!(SIGSYS, 31);
+        assert_eq!(SIGTSTP, 20);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 23);
+        assert_eq!(SIGUSR1, 10);
+        assert_eq!(SIGUSR2, 12);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 0);
+        assert_eq!(SIG_SETMASK, 2);
+        assert_eq!(SIG_UNBLOCK, 1);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 1);
+        assert_eq!(SO_ACCEPTCONN, 30);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 6);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 39);
+        assert_eq!(SO_DONTROUTE, 5);
+        assert_eq!(SO_ERROR, 4);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 9);
+        assert_eq!(SO_LINGER, 13);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 10);
+        assert_eq!(SO_PASSCRED, 16);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 17);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 31);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 38);
+        assert_eq!(SO_RCVBUF, 8);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 18);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 20);
+        assert_eq!(SO_REUSEADDR, 2);
+        assert_eq!(SO_REUSEPORT, 15);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 7);
+        assert_eq!(SO_SNDBUFFORCE, 32);
+        assert_eq!(SO_SNDLOWAT, 19);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 21);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 3);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 0);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 526336);
+        assert_eq!(TFD_NONBLOCK, 2048);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 526336);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 256);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 11);
+        assert_eq!(VEOL2, 16);
+        assert_eq!(VMIN, 6);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 2);
+        assert_eq!(_IOC_DIRMASK, 3);
+        assert_eq!(_IOC_DIRSHIFT, 30);
+        assert_eq!(_IOC_NONE, 0);
+        assert_eq!(_IOC_SIZEBITS, 14);
+        assert_eq!(_IOC_SIZEMASK, 16383);
+        assert_eq!(_IOC_WRITE, 1);
+        assert_eq!(_NSIG, 64);
+        assert_eq!(__O_SYNC, 1048576);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+
+    #[cfg(target_arch = "riscv64")]
+    {
+        assert_eq!(B1000000, 4104);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4105);
+        assert_eq!(B1500000, 4106);
+        assert_eq!(B2000000, 4107);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4101);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4102);
+        assert_eq!(B921600, 4103);
+        assert_eq!(BLKPBSZGET, 4731);
+        assert_eq!(BLKSSZGET, 4712);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 2048);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 8192);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 4096);
+        assert_eq!(F_GETLK, 5);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 9);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 8);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 32768);
+        assert_eq!(IOCSIZE_MASK, 1073676288);
+        assert_eq!(IOC_IN, 1073741824);
+        assert_eq!(IOC_OUT, 2147483648);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 256);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 8192);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 16384);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 1);
+        assert_eq!(MCL_FUTURE, 2);
+        assert_eq!(MCL_ONFAULT, 4);
+        assert_eq!(MINSIGSTKSZ, 2048);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 19);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 1024);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 64);
+        assert_eq!(O_DIRECT, 16384);
+        assert_eq!(O_DIRECTORY, 65536);
+        assert_eq!(O_DSYNC, 4096);
+        assert_eq!(O_EXCL, 128);
+        assert_eq!(O_LARGEFILE, 32768);
+        assert_eq!(O_NDELAY, 2048);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 256);
+        assert_eq!(O_NOFOLLOW, 131072);
+        assert_eq!(O_NONBLOCK, 2048);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 1052672);
+        assert_eq!(O_TMPFILE, 4259840);
+        assert_eq!(O_TMPFILE_MASK, 4259904);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 512);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 7);
+        assert_eq!(RLIMIT_NPROC, 6);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 2);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 4);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 7);
+        assert_eq!(SIGCHLD, 17);
+        assert_eq!(SIGCONT, 18);
+        assert_eq!(SIGIO, 29);
+        assert_eq!(SIGPOLL, 29);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 30);
+        assert_eq!(SIGSTKSZ, 8192);
+        assert_eq!(SIGSTOP, 19);
+        assert_eq!(SIGSYS, 31);
+        assert_eq!(SIGTSTP, 20);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 23);
+        assert_eq!(SIGUSR1, 10);
+        assert_eq!(SIGUSR2, 12);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 0);
+        assert_eq!(SIG_SETMASK, 2);
+        assert_eq!(SIG_UNBLOCK, 1);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 1);
+        assert_eq!(SO_ACCEPTCONN, 30);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 6);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 39);
+        assert_eq!(SO_DONTROUTE, 5);
+        assert_eq!(SO_ERROR, 4);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 9);
+        assert_eq!(SO_LINGER, 13);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 10);
+        assert_eq!(SO_PASSCRED, 16);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 17);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 31);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 38);
+        assert_eq!(SO_RCVBUF, 8);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 18);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 20);
+        assert_eq!(SO_REUSEADDR, 2);
+        assert_eq!(SO_REUSEPORT, 15);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 7);
+        assert_eq!(SO_SNDBUFFORCE, 32);
+        assert_eq!(SO_SNDLOWAT, 19);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 21);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 3);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 0);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 526336);
+        assert_eq!(TFD_NONBLOCK, 2048);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 526336);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 256);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 11);
+        assert_eq!(VEOL2, 16);
+        assert_eq!(VMIN, 6);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 2);
+        assert_eq!(_IOC_DIRMASK, 3);
+        assert_eq!(_IOC_DIRSHIFT, 30);
+        assert_eq!(_IOC_NONE, 0);
+        assert_eq!(_IOC_SIZEBITS, 14);
+        assert_eq!(_IOC_SIZEMASK, 16383);
+        assert_eq!(_IOC_WRITE, 1);
+        assert_eq!(_NSIG, 64);
+        assert_eq!(__O_SYNC, 1048576);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+
+    #[cfg(target_arch = "s390x")]
+    {
+        assert_eq!(B1000000, 4104);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4105);
+        assert_eq!(B1500000, 4106);
+        assert_eq!(B2000000, 4107);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4101);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4102);
+        assert_eq!(B921600, 4103);
+        assert_eq!(BLKPBSZGET, 4731);
+        assert_eq!(BLKSSZGET, 4712);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 2048);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 8192);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 4096);
+        assert_eq!(F_GETLK, 5);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 9);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 8);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 32768);
+        assert_eq!(IOCSIZE_MASK, 1073676288);
+        assert_eq!(IOC_IN, 1073741824);
+        assert_eq!(IOC_OUT, 2147483648);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 256);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 8192);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 16384);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 1);
+        assert_eq!(MCL_FUTURE, 2);
+        assert_eq!(MCL_ONFAULT, 4);
+        assert_eq!(MINSIGSTKSZ, 2048);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 19);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 1024);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 64);
+        assert_eq!(O_DIRECT, 16384);
+        assert_eq!(O_DIRECTORY, 65536);
+        assert_eq!(O_DSYNC, 4096);
+        assert_eq!(O_EXCL, 128);
+        assert_eq!(O_LARGEFILE, 32768);
+        assert_eq!(O_NDELAY, 2048);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 256);
+        assert_eq!(O_NOFOLLOW, 131072);
+        assert_eq!(O_NONBLOCK, 2048);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 1052672);
+        assert_eq!(O_TMPFILE, 4259840);
+        assert_eq!(O_TMPFILE_MASK, 4259904);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 512);
+        assert_eq!(POSIX_FADV_DONTNEED, 6);
+        assert_eq!(POSIX_FADV_NOREUSE, 7);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 7);
+        assert_eq!(RLIMIT_NPROC, 6);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 2);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 4);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 7);
+        assert_eq!(SIGCHLD, 17);
+        assert_eq!(SIGCONT, 18);
+        assert_eq!(SIGIO, 29);
+        assert_eq!(SIGPOLL, 29);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 30);
+        assert_eq!(SIGSTKSZ, 8192);
+        assert_eq!(SIGSTOP, 19);
+        assert_eq!(SIGSYS, 31);
+        assert_eq!(SIGTSTP, 20);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 23);
+        assert_eq!(SIGUSR1, 10);
+        assert_eq!(SIGUSR2, 12);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 0);
+        assert_eq!(SIG_SETMASK, 2);
+        assert_eq!(SIG_UNBLOCK, 1);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 1);
+        assert_eq!(SO_ACCEPTCONN, 30);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 6);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 39);
+        assert_eq!(SO_DONTROUTE, 5);
+        assert_eq!(SO_ERROR, 4);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 9);
+        assert_eq!(SO_LINGER, 13);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 10);
+        assert_eq!(SO_PASSCRED, 16);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 17);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 31);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 38);
+        assert_eq!(SO_RCVBUF, 8);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 18);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 20);
+        assert_eq!(SO_REUSEADDR, 2);
+        assert_eq!(SO_REUSEPORT, 15);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 7);
+        assert_eq!(SO_SNDBUFFORCE, 32);
+        assert_eq!(SO_SNDLOWAT, 19);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 21);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 3);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 1);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 526336);
+        assert_eq!(TFD_NONBLOCK, 2048);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 526336);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 256);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 11);
+        assert_eq!(VEOL2, 16);
+        assert_eq!(VMIN, 6);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 2);
+        assert_eq!(_IOC_DIRMASK, 3);
+        assert_eq!(_IOC_DIRSHIFT, 30);
+        assert_eq!(_IOC_NONE, 0);
+        assert_eq!(_IOC_SIZEBITS, 14);
+        assert_eq!(_IOC_SIZEMASK, 16383);
+        assert_eq!(_IOC_WRITE, 1);
+        assert_eq!(_NSIG, 64);
+        assert_eq!(__O_SYNC, 1048576);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+
+    #[cfg(target_arch = "sparc")]
+    {
+        assert_eq!(B1000000, 4108);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4109);
+        assert_eq!(B1500000, 4110);
+        assert_eq!(B2000000, 4111);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4106);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4107);
+        assert_eq!(B921600, 4105);
+        assert_eq!(BLKPBSZGET, 536875643);
+        assert_eq!(BLKSSZGET, 536875624);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 4194304);
+        assert_eq!(EFD_NONBLOCK, 16384);
+        assert_eq!(EPOLL_CLOEXEC, 4194304);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 64);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 8192);
+        assert_eq!(F_GETLK, 7);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 5);
+        assert_eq!(F_RDLCK, 1);
+        assert_eq!(F_SETLK, 8);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 9);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 6);
+        assert_eq!(F_UNLCK, 3);
+        assert_eq!(F_WRLCK, 2);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 32768);
+        assert_eq!(IOCSIZE_MASK, 1073676288);
+        assert_eq!(IOC_IN, 2147483648);
+        assert_eq!(IOC_OUT, 1073741824);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 512);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 256);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 64);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 8192);
+        assert_eq!(MCL_FUTURE, 16384);
+        assert_eq!(MCL_ONFAULT, 32768);
+        assert_eq!(MINSIGSTKSZ, 4096);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 17);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 4194304);
+        assert_eq!(O_APPEND, 8);
+        assert_eq!(O_CLOEXEC, 4194304);
+        assert_eq!(O_CREAT, 512);
+        assert_eq!(O_DIRECT, 1048576);
+        assert_eq!(O_DIRECTORY, 65536);
+        assert_eq!(O_DSYNC, 8192);
+        assert_eq!(O_EXCL, 2048);
+        assert_eq!(O_LARGEFILE, 262144);
+        assert_eq!(O_NDELAY, 16388);
+        assert_eq!(O_NOATIME, 2097152);
+        assert_eq!(O_NOCTTY, 32768);
+        assert_eq!(O_NOFOLLOW, 131072);
+        assert_eq!(O_NONBLOCK, 16384);
+        assert_eq!(O_PATH, 16777216);
+        assert_eq!(O_SYNC, 8396800);
+        assert_eq!(O_TMPFILE, 33619968);
+        assert_eq!(O_TMPFILE_MASK, 33620480);
+        assert_eq!(O_TRUNC, 1024);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 512);
+        assert_eq!(POLLRDHUP, 2048);
+        assert_eq!(POLLREMOVE, 1024);
+        assert_eq!(POLLWRBAND, 256);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 6);
+        assert_eq!(RLIMIT_NPROC, 7);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, 2147483647);
+        assert_eq!(SA_NOCLDSTOP, 8);
+        assert_eq!(SA_NOCLDWAIT, 256);
+        assert_eq!(SA_NODEFER, 32);
+        assert_eq!(SA_NOMASK, 32);
+        assert_eq!(SA_ONESHOT, 4);
+        assert_eq!(SA_ONSTACK, 1);
+        assert_eq!(SA_RESETHAND, 4);
+        assert_eq!(SA_RESTART, 2);
+        assert_eq!(SA_SIGINFO, 512);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 56);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 60);
+        assert_eq!(SCM_TXTIME, 63);
+        assert_eq!(SCM_WIFI_STATUS, 37);
+        assert_eq!(SIGBUS, 10);
+        assert_eq!(SIGCHLD, 20);
+        assert_eq!(SIGCONT, 19);
+        assert_eq!(SIGIO, 23);
+        assert_eq!(SIGPOLL, 23);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 29);
+        assert_eq!(SIGSTKSZ, 16384);
+        assert_eq!(SIGSTOP, 17);
+        assert_eq!(SIGSYS, 12);
+        assert_eq!(SIGTSTP, 18);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 16);
+        assert_eq!(SIGUSR1, 30);
+        assert_eq!(SIGUSR2, 31);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 1);
+        assert_eq!(SIG_SETMASK, 4);
+        assert_eq!(SIG_UNBLOCK, 2);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 65535);
+        assert_eq!(SO_ACCEPTCONN, 32768);
+        assert_eq!(SO_ATTACH_BPF, 52);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 53);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 54);
+        assert_eq!(SO_BINDTODEVICE, 13);
+        assert_eq!(SO_BINDTOIFINDEX, 65);
+        assert_eq!(SO_BPF_EXTENSIONS, 50);
+        assert_eq!(SO_BROADCAST, 32);
+        assert_eq!(SO_BSDCOMPAT, 1024);
+        assert_eq!(SO_BUSY_POLL, 48);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 73);
+        assert_eq!(SO_CNX_ADVICE, 55);
+        assert_eq!(SO_COOKIE, 59);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 71);
+        assert_eq!(SO_DOMAIN, 4137);
+        assert_eq!(SO_DONTROUTE, 16);
+        assert_eq!(SO_ERROR, 4103);
+        assert_eq!(SO_INCOMING_CPU, 51);
+        assert_eq!(SO_INCOMING_NAPI_ID, 58);
+        assert_eq!(SO_KEEPALIVE, 8);
+        assert_eq!(SO_LINGER, 128);
+        assert_eq!(SO_LOCK_FILTER, 40);
+        assert_eq!(SO_MARK, 34);
+        assert_eq!(SO_MAX_PACING_RATE, 49);
+        assert_eq!(SO_MEMINFO, 57);
+        assert_eq!(SO_NOFCS, 39);
+        assert_eq!(SO_OOBINLINE, 256);
+        assert_eq!(SO_PASSCRED, 2);
+        assert_eq!(SO_PASSSEC, 31);
+        assert_eq!(SO_PEEK_OFF, 38);
+        assert_eq!(SO_PEERCRED, 64);
+        assert_eq!(SO_PEERGROUPS, 61);
+        assert_eq!(SO_PEERSEC, 30);
+        assert_eq!(SO_PREFER_BUSY_POLL, 72);
+        assert_eq!(SO_PROTOCOL, 4136);
+        assert_eq!(SO_RCVBUF, 4098);
+        assert_eq!(SO_RCVBUFFORCE, 4107);
+        assert_eq!(SO_RCVLOWAT, 2048);
+        assert_eq!(SO_RCVTIMEO_NEW, 68);
+        assert_eq!(SO_RCVTIMEO_OLD, 8192);
+        assert_eq!(SO_REUSEADDR, 4);
+        assert_eq!(SO_REUSEPORT, 512);
+        assert_eq!(SO_RXQ_OVFL, 36);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 20481);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 20484);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 20482);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 41);
+        assert_eq!(SO_SNDBUF, 4097);
+        assert_eq!(SO_SNDBUFFORCE, 4106);
+        assert_eq!(SO_SNDLOWAT, 4096);
+        assert_eq!(SO_SNDTIMEO_NEW, 69);
+        assert_eq!(SO_SNDTIMEO_OLD, 16384);
+        assert_eq!(SO_TIMESTAMPING_NEW, 67);
+        assert_eq!(SO_TIMESTAMPING_OLD, 35);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 66);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 33);
+        assert_eq!(SO_TIMESTAMP_NEW, 70);
+        assert_eq!(SO_TXTIME, 63);
+        assert_eq!(SO_TYPE, 4104);
+        assert_eq!(SO_WIFI_STATUS, 37);
+        assert_eq!(SO_ZEROCOPY, 62);
+        assert_eq!(SYSCALL_NAMES_BASE, 0);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 4194304);
+        assert_eq!(TFD_CREATE_FLAGS, 4210688);
+        assert_eq!(TFD_NONBLOCK, 16384);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 4210688);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 256);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 5);
+        assert_eq!(VEOL2, 6);
+        assert_eq!(VMIN, 4);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 3);
+        assert_eq!(_IOC_DIRMASK, 7);
+        assert_eq!(_IOC_DIRSHIFT, 29);
+        assert_eq!(_IOC_NONE, 1);
+        assert_eq!(_IOC_SIZEBITS, 13);
+        assert_eq!(_IOC_SIZEMASK, 8191);
+        assert_eq!(_IOC_WRITE, 4);
+        assert_eq!(_NSIG, 32);
+        assert_eq!(__O_SYNC, 8388608);
+        assert_eq!(__O_TMPFILE, 33554432);
+    }
+
+    #[cfg(target_arch = "sparc64")]
+    {
+        assert_eq!(B1000000, 4108);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4109);
+        assert_eq!(B1500000, 4110);
+        assert_eq!(B2000000, 4111);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4106);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4107);
+        assert_eq!(B921600, 4105);
+        assert_eq!(BLKPBSZGET, 536875643);
+        assert_eq!(BLKSSZGET, 536875624);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 4194304);
+        assert_eq!(EFD_NONBLOCK, 16384);
+        assert_eq!(EPOLL_CLOEXEC, 4194304);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 64);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 8192);
+        assert_eq!(F_GETLK, 7);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 5);
+        assert_eq!(F_RDLCK, 1);
+        assert_eq!(F_SETLK, 8);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 9);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 6);
+        assert_eq!(F_UNLCK, 3);
+        assert_eq!(F_WRLCK, 2);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 32768);
+        assert_eq!(IOCSIZE_MASK, 1073676288);
+        assert_eq!(IOC_IN, 2147483648);
+        assert_eq!(IOC_OUT, 1073741824);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 512);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 256);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 64);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 8192);
+        assert_eq!(MCL_FUTURE, 16384);
+        assert_eq!(MCL_ONFAULT, 32768);
+        assert_eq!(MINSIGSTKSZ, 4096);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 17);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 4194304);
+        assert_eq!(O_APPEND, 8);
+        assert_eq!(O_CLOEXEC, 4194304);
+        assert_eq!(O_CREAT, 512);
+        assert_eq!(O_DIRECT, 1048576);
+        assert_eq!(O_DIRECTORY, 65536);
+        assert_eq!(O_DSYNC, 8192);
+        assert_eq!(O_EXCL, 2048);
+        assert_eq!(O_LARGEFILE, 262144);
+        assert_eq!(O_NDELAY, 4);
+        assert_eq!(O_NOATIME, 2097152);
+        assert_eq!(O_NOCTTY, 32768);
+        assert_eq!(O_NOFOLLOW, 131072);
+        assert_eq!(O_NONBLOCK, 16384);
+        assert_eq!(O_PATH, 16777216);
+        assert_eq!(O_SYNC, 8396800);
+        assert_eq!(O_TMPFILE, 33619968);
+        assert_eq!(O_TMPFILE_MASK, 33620480);
+        assert_eq!(O_TRUNC, 1024);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 512);
+        assert_eq!(POLLRDHUP, 2048);
+        assert_eq!(POLLREMOVE, 1024);
+        assert_eq!(POLLWRBAND, 256);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 6);
+        assert_eq!(RLIMIT_NPROC, 7);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 8);
+        assert_eq!(SA_NOCLDWAIT, 256);
+        assert_eq!(SA_NODEFER, 32);
+        assert_eq!(SA_NOMASK, 32);
+        assert_eq!(SA_ONESHOT, 4);
+        assert_eq!(SA_ONSTACK, 1);
+        assert_eq!(SA_RESETHAND, 4);
+        assert_eq!(SA_RESTART, 2);
+        assert_eq!(SA_SIGINFO, 512);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 56);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 60);
+        assert_eq!(SCM_TXTIME, 63);
+        assert_eq!(SCM_WIFI_STATUS, 37);
+        assert_eq!(SIGBUS, 10);
+        assert_eq!(SIGCHLD, 20);
+        assert_eq!(SIGCONT, 19);
+        assert_eq!(SIGIO, 23);
+        assert_eq!(SIGPOLL, 23);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 29);
+        assert_eq!(SIGSTKSZ, 16384);
+        assert_eq!(SIGSTOP, 17);
+        assert_eq!(SIGSYS, 12);
+        assert_eq!(SIGTSTP, 18);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 16);
+        assert_eq!(SIGUSR1, 30);
+        assert_eq!(SIGUSR2, 31);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 1);
+        assert_eq!(SIG_SETMASK, 4);
+        assert_eq!(SIG_UNBLOCK, 2);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 65535);
+        assert_eq!(SO_ACCEPTCONN, 32768);
+        assert_eq!(SO_ATTACH_BPF, 52);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 53);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 54);
+        assert_eq!(SO_BINDTODEVICE, 13);
+        assert_eq!(SO_BINDTOIFINDEX, 65);
+        assert_eq!(SO_BPF_EXTENSIONS, 50);
+        assert_eq!(SO_BROADCAST, 32);
+        assert_eq!(SO_BSDCOMPAT, 1024);
+        assert_eq!(SO_BUSY_POLL, 48);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 73);
+        assert_eq!(SO_CNX_ADVICE, 55);
+        assert_eq!(SO_COOKIE, 59);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 71);
+        assert_eq!(SO_DOMAIN, 4137);
+        assert_eq!(SO_DONTROUTE, 16);
+        assert_eq!(SO_ERROR, 4103);
+        assert_eq!(SO_INCOMING_CPU, 51);
+        assert_eq!(SO_INCOMING_NAPI_ID, 58);
+        assert_eq!(SO_KEEPALIVE, 8);
+        assert_eq!(SO_LINGER, 128);
+        assert_eq!(SO_LOCK_FILTER, 40);
+        assert_eq!(SO_MARK, 34);
+        assert_eq!(SO_MAX_PACING_RATE, 49);
+        assert_eq!(SO_MEMINFO, 57);
+        assert_eq!(SO_NOFCS, 39);
+        assert_eq!(SO_OOBINLINE, 256);
+        assert_eq!(SO_PASSCRED, 2);
+        assert_eq!(SO_PASSSEC, 31);
+        assert_eq!(SO_PEEK_OFF, 38);
+        assert_eq!(SO_PEERCRED, 64);
+        assert_eq!(SO_PEERGROUPS, 61);
+        assert_eq!(SO_PEERSEC, 30);
+        assert_eq!(SO_PREFER_BUSY_POLL, 72);
+        assert_eq!(SO_PROTOCOL, 4136);
+        assert_eq!(SO_RCVBUF, 4098);
+        assert_eq!(SO_RCVBUFFORCE, 4107);
+        assert_eq!(SO_RCVLOWAT, 2048);
+        assert_eq!(SO_RCVTIMEO_NEW, 68);
+        assert_eq!(SO_RCVTIMEO_OLD, 8192);
+        assert_eq!(SO_REUSEADDR, 4);
+        assert_eq!(SO_REUSEPORT, 512);
+        assert_eq!(SO_RXQ_OVFL, 36);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 20481);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 20484);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 20482);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 41);
+        assert_eq!(SO_SNDBUF, 4097);
+        assert_eq!(SO_SNDBUFFORCE, 4106);
+        assert_eq!(SO_SNDLOWAT, 4096);
+        assert_eq!(SO_SNDTIMEO_NEW, 69);
+        assert_eq!(SO_SNDTIMEO_OLD, 16384);
+        assert_eq!(SO_TIMESTAMPING_NEW, 67);
+        assert_eq!(SO_TIMESTAMPING_OLD, 35);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 66);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 33);
+        assert_eq!(SO_TIMESTAMP_NEW, 70);
+        assert_eq!(SO_TXTIME, 63);
+        assert_eq!(SO_TYPE, 4104);
+        assert_eq!(SO_WIFI_STATUS, 37);
+        assert_eq!(SO_ZEROCOPY, 62);
+        assert_eq!(SYSCALL_NAMES_BASE, 0);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 4194304);
+        assert_eq!(TFD_CREATE_FLAGS, 4210688);
+        assert_eq!(TFD_NONBLOCK, 16384);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 4210688);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 256);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 5);
+        assert_eq!(VEOL2, 6);
+        assert_eq!(VMIN, 4);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 3);
+        assert_eq!(_IOC_DIRMASK, 7);
+        assert_eq!(_IOC_DIRSHIFT, 29);
+        assert_eq!(_IOC_NONE, 1);
+        assert_eq!(_IOC_SIZEBITS, 13);
+        assert_eq!(_IOC_SIZEMASK, 8191);
+        assert_eq!(_IOC_WRITE, 4);
+        assert_eq!(_NSIG, 32);
+        assert_eq!(__O_SYNC, 8388608);
+        assert_eq!(__O_TMPFILE, 33554432);
+    }
+
+    #[cfg(target_arch = "x86")]
+    {
+        assert_eq!(B1000000, 4104);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4105);
+        assert_eq!(B1500000, 4106);
+        assert_eq!(B2000000, 4107);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4101);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4102);
+        assert_eq!(B921600, 4103);
+        assert_eq!(BLKPBSZGET, 4731);
+        assert_eq!(BLKSSZGET, 4712);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 2048);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 8192);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 4096);
+        assert_eq!(F_GETLK, 5);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 9);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 8);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 32768);
+        assert_eq!(IOCSIZE_MASK, 1073676288);
+        assert_eq!(IOC_IN, 1073741824);
+        assert_eq!(IOC_OUT, 2147483648);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 256);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 8192);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 16384);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 1);
+        assert_eq!(MCL_FUTURE, 2);
+        assert_eq!(MCL_ONFAULT, 4);
+        assert_eq!(MINSIGSTKSZ, 2048);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 19);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 1024);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 64);
+        assert_eq!(O_DIRECT, 16384);
+        assert_eq!(O_DIRECTORY, 65536);
+        assert_eq!(O_DSYNC, 4096);
+        assert_eq!(O_EXCL, 128);
+        assert_eq!(O_LARGEFILE, 32768);
+        assert_eq!(O_NDELAY, 2048);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 256);
+        assert_eq!(O_NOFOLLOW, 131072);
+        assert_eq!(O_NONBLOCK, 2048);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 1052672);
+        assert_eq!(O_TMPFILE, 4259840);
+        assert_eq!(O_TMPFILE_MASK, 4259904);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 512);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 7);
+        assert_eq!(RLIMIT_NPROC, 6);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 2);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 4);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 7);
+        assert_eq!(SIGCHLD, 17);
+        assert_eq!(SIGCONT, 18);
+        assert_eq!(SIGIO, 29);
+        assert_eq!(SIGPOLL, 29);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 30);
+        assert_eq!(SIGSTKSZ, 8192);
+        assert_eq!(SIGSTOP, 19);
+        assert_eq!(SIGSYS, 31);
+        assert_eq!(SIGTSTP, 20);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 23);
+        assert_eq!(SIGUSR1, 10);
+        assert_eq!(SIGUSR2, 12);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 0);
+        assert_eq!(SIG_SETMASK, 2);
+        assert_eq!(SIG_UNBLOCK, 1);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 1);
+        assert_eq!(SO_ACCEPTCONN, 30);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 6);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 39);
+        assert_eq!(SO_DONTROUTE, 5);
+        assert_eq!(SO_ERROR, 4);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 9);
+        assert_eq!(SO_LINGER, 13);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 10);
+        assert_eq!(SO_PASSCRED, 16);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 17);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 31);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 38);
+        assert_eq!(SO_RCVBUF, 8);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 18);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 20);
+        assert_eq!(SO_REUSEADDR, 2);
+        assert_eq!(SO_REUSEPORT, 15);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 7);
+        assert_eq!(SO_SNDBUFFORCE, 32);
+        assert_eq!(SO_SNDLOWAT, 19);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 21);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 3);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 0);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 526336);
+        assert_eq!(TFD_NONBLOCK, 2048);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 526336);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 256);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 11);
+        assert_eq!(VEOL2, 16);
+        assert_eq!(VMIN, 6);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 2);
+        assert_eq!(_IOC_DIRMASK, 3);
+        assert_eq!(_IOC_DIRSHIFT, 30);
+        assert_eq!(_IOC_NONE, 0);
+        assert_eq!(_IOC_SIZEBITS, 14);
+        assert_eq!(_IOC_SIZEMASK, 16383);
+        assert_eq!(_IOC_WRITE, 1);
+        assert_eq!(_NSIG, 64);
+        assert_eq!(__O_SYNC, 1048576);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        assert_eq!(B1000000, 4104);
+        assert_eq!(B115200, 4098);
+        assert_eq!(B1152000, 4105);
+        assert_eq!(B1500000, 4106);
+        assert_eq!(B2000000, 4107);
+        assert_eq!(B230400, 4099);
+        assert_eq!(B460800, 4100);
+        assert_eq!(B500000, 4101);
+        assert_eq!(B57600, 4097);
+        assert_eq!(B576000, 4102);
+        assert_eq!(B921600, 4103);
+        assert_eq!(BLKPBSZGET, 4731);
+        assert_eq!(BLKSSZGET, 4712);
+        assert_eq!(BOTHER, 4096);
+        assert_eq!(BS1, 8192);
+        assert_eq!(BSDLY, 8192);
+        assert_eq!(CBAUD, 4111);
+        assert_eq!(CBAUDEX, 4096);
+        assert_eq!(CIBAUD, 269418496);
+        assert_eq!(CLOCAL, 2048);
+        assert_eq!(CR1, 512);
+        assert_eq!(CR2, 1024);
+        assert_eq!(CR3, 1536);
+        assert_eq!(CRDLY, 1536);
+        assert_eq!(CREAD, 128);
+        assert_eq!(CS6, 16);
+        assert_eq!(CS7, 32);
+        assert_eq!(CS8, 48);
+        assert_eq!(CSIZE, 48);
+        assert_eq!(CSTOPB, 64);
+        assert_eq!(ECHOCTL, 512);
+        assert_eq!(ECHOE, 16);
+        assert_eq!(ECHOK, 32);
+        assert_eq!(ECHOKE, 2048);
+        assert_eq!(ECHONL, 64);
+        assert_eq!(ECHOPRT, 1024);
+        assert_eq!(EFD_CLOEXEC, 524288);
+        assert_eq!(EFD_NONBLOCK, 2048);
+        assert_eq!(EPOLL_CLOEXEC, 524288);
+        assert_eq!(EXTPROC, 65536);
+        assert_eq!(FASYNC, 8192);
+        assert_eq!(FF1, 32768);
+        assert_eq!(FFDLY, 32768);
+        assert_eq!(FLUSHO, 4096);
+        assert_eq!(F_GETLK, 5);
+        assert_eq!(F_GETLK64, 12);
+        assert_eq!(F_GETOWN, 9);
+        assert_eq!(F_RDLCK, 0);
+        assert_eq!(F_SETLK, 6);
+        assert_eq!(F_SETLK64, 13);
+        assert_eq!(F_SETLKW, 7);
+        assert_eq!(F_SETLKW64, 14);
+        assert_eq!(F_SETOWN, 8);
+        assert_eq!(F_UNLCK, 2);
+        assert_eq!(F_WRLCK, 1);
+        assert_eq!(HUPCL, 1024);
+        assert_eq!(ICANON, 2);
+        assert_eq!(IEXTEN, 32768);
+        assert_eq!(IOCSIZE_MASK, 1073676288);
+        assert_eq!(IOC_IN, 1073741824);
+        assert_eq!(IOC_OUT, 2147483648);
+        assert_eq!(ISIG, 1);
+        assert_eq!(IUCLC, 512);
+        assert_eq!(IXOFF, 4096);
+        assert_eq!(IXON, 1024);
+        assert_eq!(MAP_ANONYMOUS, 32);
+        assert_eq!(MAP_DENYWRITE, 2048);
+        assert_eq!(MAP_EXECUTABLE, 4096);
+        assert_eq!(MAP_GROWSDOWN, 256);
+        assert_eq!(MAP_HUGETLB, 262144);
+        assert_eq!(MAP_LOCKED, 8192);
+        assert_eq!(MAP_NONBLOCK, 65536);
+        assert_eq!(MAP_NORESERVE, 16384);
+        assert_eq!(MAP_POPULATE, 32768);
+        assert_eq!(MAP_STACK, 131072);
+        assert_eq!(MCL_CURRENT, 1);
+        assert_eq!(MCL_FUTURE, 2);
+        assert_eq!(MCL_ONFAULT, 4);
+        assert_eq!(MINSIGSTKSZ, 2048);
+        assert_eq!(NCC, 8);
+        assert_eq!(NCCS, 19);
+        assert_eq!(NLDLY, 256);
+        assert_eq!(NOFLSH, 128);
+        assert_eq!(OLCUC, 2);
+        assert_eq!(ONLCR, 4);
+        assert_eq!(OPEN_TREE_CLOEXEC, 524288);
+        assert_eq!(O_APPEND, 1024);
+        assert_eq!(O_CLOEXEC, 524288);
+        assert_eq!(O_CREAT, 64);
+        assert_eq!(O_DIRECT, 16384);
+        assert_eq!(O_DIRECTORY, 65536);
+        assert_eq!(O_DSYNC, 4096);
+        assert_eq!(O_EXCL, 128);
+        assert_eq!(O_LARGEFILE, 32768);
+        assert_eq!(O_NDELAY, 2048);
+        assert_eq!(O_NOATIME, 262144);
+        assert_eq!(O_NOCTTY, 256);
+        assert_eq!(O_NOFOLLOW, 131072);
+        assert_eq!(O_NONBLOCK, 2048);
+        assert_eq!(O_PATH, 2097152);
+        assert_eq!(O_SYNC, 1052672);
+        assert_eq!(O_TMPFILE, 4259840);
+        assert_eq!(O_TMPFILE_MASK, 4259904);
+        assert_eq!(O_TRUNC, 512);
+        assert_eq!(PARENB, 256);
+        assert_eq!(PARODD, 512);
+        assert_eq!(PENDIN, 16384);
+        assert_eq!(POLLMSG, 1024);
+        assert_eq!(POLLRDHUP, 8192);
+        assert_eq!(POLLREMOVE, 4096);
+        assert_eq!(POLLWRBAND, 512);
+        assert_eq!(POSIX_FADV_DONTNEED, 4);
+        assert_eq!(POSIX_FADV_NOREUSE, 5);
+        assert_eq!(PROT_SEM, 8);
+        assert_eq!(RLIMIT_AS, 9);
+        assert_eq!(RLIMIT_MEMLOCK, 8);
+        assert_eq!(RLIMIT_NOFILE, 7);
+        assert_eq!(RLIMIT_NPROC, 6);
+        assert_eq!(RLIMIT_RSS, 5);
+        assert_eq!(RLIM_INFINITY, -1);
+        assert_eq!(SA_NOCLDSTOP, 1);
+        assert_eq!(SA_NOCLDWAIT, 2);
+        assert_eq!(SA_NODEFER, 1073741824);
+        assert_eq!(SA_NOMASK, 1073741824);
+        assert_eq!(SA_ONESHOT, 2147483648);
+        assert_eq!(SA_ONSTACK, 134217728);
+        assert_eq!(SA_RESETHAND, 2147483648);
+        assert_eq!(SA_RESTART, 268435456);
+        assert_eq!(SA_SIGINFO, 4);
+        assert_eq!(SCM_TIMESTAMPING_OPT_STATS, 54);
+        assert_eq!(SCM_TIMESTAMPING_PKTINFO, 58);
+        assert_eq!(SCM_TXTIME, 61);
+        assert_eq!(SCM_WIFI_STATUS, 41);
+        assert_eq!(SIGBUS, 7);
+        assert_eq!(SIGCHLD, 17);
+        assert_eq!(SIGCONT, 18);
+        assert_eq!(SIGIO, 29);
+        assert_eq!(SIGPOLL, 29);
+        assert_eq!(SIGPROF, 27);
+        assert_eq!(SIGPWR, 30);
+        assert_eq!(SIGSTKSZ, 8192);
+        assert_eq!(SIGSTOP, 19);
+        assert_eq!(SIGSYS, 31);
+        assert_eq!(SIGTSTP, 20);
+        assert_eq!(SIGTTIN, 21);
+        assert_eq!(SIGTTOU, 22);
+        assert_eq!(SIGURG, 23);
+        assert_eq!(SIGUSR1, 10);
+        assert_eq!(SIGUSR2, 12);
+        assert_eq!(SIGVTALRM, 26);
+        assert_eq!(SIGWINCH, 28);
+        assert_eq!(SIGXCPU, 24);
+        assert_eq!(SIGXFSZ, 25);
+        assert_eq!(SIG_BLOCK, 0);
+        assert_eq!(SIG_SETMASK, 2);
+        assert_eq!(SIG_UNBLOCK, 1);
+        assert_eq!(SOCK_DGRAM, 2);
+        assert_eq!(SOCK_STREAM, 1);
+        assert_eq!(SOL_SOCKET, 1);
+        assert_eq!(SO_ACCEPTCONN, 30);
+        assert_eq!(SO_ATTACH_BPF, 50);
+        assert_eq!(SO_ATTACH_REUSEPORT_CBPF, 51);
+        assert_eq!(SO_ATTACH_REUSEPORT_EBPF, 52);
+        assert_eq!(SO_BINDTODEVICE, 25);
+        assert_eq!(SO_BINDTOIFINDEX, 62);
+        assert_eq!(SO_BPF_EXTENSIONS, 48);
+        assert_eq!(SO_BROADCAST, 6);
+        assert_eq!(SO_BSDCOMPAT, 14);
+        assert_eq!(SO_BUSY_POLL, 46);
+        assert_eq!(SO_BUSY_POLL_BUDGET, 70);
+        assert_eq!(SO_CNX_ADVICE, 53);
+        assert_eq!(SO_COOKIE, 57);
+        assert_eq!(SO_DETACH_REUSEPORT_BPF, 68);
+        assert_eq!(SO_DOMAIN, 39);
+        assert_eq!(SO_DONTROUTE, 5);
+        assert_eq!(SO_ERROR, 4);
+        assert_eq!(SO_INCOMING_CPU, 49);
+        assert_eq!(SO_INCOMING_NAPI_ID, 56);
+        assert_eq!(SO_KEEPALIVE, 9);
+        assert_eq!(SO_LINGER, 13);
+        assert_eq!(SO_LOCK_FILTER, 44);
+        assert_eq!(SO_MARK, 36);
+        assert_eq!(SO_MAX_PACING_RATE, 47);
+        assert_eq!(SO_MEMINFO, 55);
+        assert_eq!(SO_NOFCS, 43);
+        assert_eq!(SO_OOBINLINE, 10);
+        assert_eq!(SO_PASSCRED, 16);
+        assert_eq!(SO_PASSSEC, 34);
+        assert_eq!(SO_PEEK_OFF, 42);
+        assert_eq!(SO_PEERCRED, 17);
+        assert_eq!(SO_PEERGROUPS, 59);
+        assert_eq!(SO_PEERSEC, 31);
+        assert_eq!(SO_PREFER_BUSY_POLL, 69);
+        assert_eq!(SO_PROTOCOL, 38);
+        assert_eq!(SO_RCVBUF, 8);
+        assert_eq!(SO_RCVBUFFORCE, 33);
+        assert_eq!(SO_RCVLOWAT, 18);
+        assert_eq!(SO_RCVTIMEO_NEW, 66);
+        assert_eq!(SO_RCVTIMEO_OLD, 20);
+        assert_eq!(SO_REUSEADDR, 2);
+        assert_eq!(SO_REUSEPORT, 15);
+        assert_eq!(SO_RXQ_OVFL, 40);
+        assert_eq!(SO_SECURITY_AUTHENTICATION, 22);
+        assert_eq!(SO_SECURITY_ENCRYPTION_NETWORK, 24);
+        assert_eq!(SO_SECURITY_ENCRYPTION_TRANSPORT, 23);
+        assert_eq!(SO_SELECT_ERR_QUEUE, 45);
+        assert_eq!(SO_SNDBUF, 7);
+        assert_eq!(SO_SNDBUFFORCE, 32);
+        assert_eq!(SO_SNDLOWAT, 19);
+        assert_eq!(SO_SNDTIMEO_NEW, 67);
+        assert_eq!(SO_SNDTIMEO_OLD, 21);
+        assert_eq!(SO_TIMESTAMPING_NEW, 65);
+        assert_eq!(SO_TIMESTAMPING_OLD, 37);
+        assert_eq!(SO_TIMESTAMPNS_NEW, 64);
+        assert_eq!(SO_TIMESTAMPNS_OLD, 35);
+        assert_eq!(SO_TIMESTAMP_NEW, 63);
+        assert_eq!(SO_TXTIME, 61);
+        assert_eq!(SO_TYPE, 3);
+        assert_eq!(SO_WIFI_STATUS, 41);
+        assert_eq!(SO_ZEROCOPY, 60);
+        assert_eq!(SYSCALL_NAMES_BASE, 0);
+        assert_eq!(TAB1, 2048);
+        assert_eq!(TAB2, 4096);
+        assert_eq!(TAB3, 6144);
+        assert_eq!(TABDLY, 6144);
+        assert_eq!(TCSBRKP, 21541);
+        assert_eq!(TFD_CLOEXEC, 524288);
+        assert_eq!(TFD_CREATE_FLAGS, 526336);
+        assert_eq!(TFD_NONBLOCK, 2048);
+        assert_eq!(TFD_SHARED_FCNTL_FLAGS, 526336);
+        assert_eq!(TIOCGICOUNT, 21597);
+        assert_eq!(TIOCGLCKTRMIOS, 21590);
+        assert_eq!(TIOCGSERIAL, 21534);
+        assert_eq!(TIOCLINUX, 21532);
+        assert_eq!(TIOCMIWAIT, 21596);
+        assert_eq!(TIOCM_CAR, 64);
+        assert_eq!(TIOCM_CD, 64);
+        assert_eq!(TIOCM_CTS, 32);
+        assert_eq!(TIOCM_DSR, 256);
+        assert_eq!(TIOCM_RI, 128);
+        assert_eq!(TIOCM_RNG, 128);
+        assert_eq!(TIOCM_SR, 16);
+        assert_eq!(TIOCM_ST, 8);
+        assert_eq!(TIOCSERCONFIG, 21587);
+        assert_eq!(TIOCSERGETLSR, 21593);
+        assert_eq!(TIOCSERGETMULTI, 21594);
+        assert_eq!(TIOCSERGSTRUCT, 21592);
+        assert_eq!(TIOCSERGWILD, 21588);
+        assert_eq!(TIOCSERSETMULTI, 21595);
+        assert_eq!(TIOCSERSWILD, 21589);
+        assert_eq!(TIOCSLCKTRMIOS, 21591);
+        assert_eq!(TIOCSSERIAL, 21535);
+        assert_eq!(TOSTOP, 256);
+        assert_eq!(VDISCARD, 13);
+        assert_eq!(VEOF, 4);
+        assert_eq!(VEOL, 11);
+        assert_eq!(VEOL2, 16);
+        assert_eq!(VMIN, 6);
+        assert_eq!(VREPRINT, 12);
+        assert_eq!(VSTART, 8);
+        assert_eq!(VSTOP, 9);
+        assert_eq!(VSUSP, 10);
+        assert_eq!(VSWTC, 7);
+        assert_eq!(VT1, 16384);
+        assert_eq!(VTDLY, 16384);
+        assert_eq!(VTIME, 5);
+        assert_eq!(VWERASE, 14);
+        assert_eq!(XCASE, 4);
+        assert_eq!(XTABS, 6144);
+        assert_eq!(_IOC_DIRBITS, 2);
+        assert_eq!(_IOC_DIRMASK, 3);
+        assert_eq!(_IOC_DIRSHIFT, 30);
+        assert_eq!(_IOC_NONE, 0);
+        assert_eq!(_IOC_SIZEBITS, 14);
+        assert_eq!(_IOC_SIZEMASK, 16383);
+        assert_eq!(_IOC_WRITE, 1);
+        assert_eq!(_NSIG, 64);
+        assert_eq!(__O_SYNC, 1048576);
+        assert_eq!(__O_TMPFILE, 4194304);
+    }
+}
@@ -0,0 +1,74 @@
+//! Check that the `AT_*` flags are generated for every version which
+//! defines them. They were added in these versions:
+//!
+//! - `AT_FDCWD`, `AT_SYMLINK_NOFOLLOW`, `AT_REMOVEDIR`, `AT_SYMLINK_FOLLOW`:
+//!   before 2.6.32
+//! - `AT_NO_AUTOMOUNT`, `AT_EMPTY_PATH`: 2.6.39
+//! - `AT_STATX_SYNC_TYPE`, `AT_STATX_SYNC_AS_STAT`, `AT_STATX_FORCE_SYNC`,
+//!   `AT_STATX_DONT_SYNC`: 4.11
+//! - `AT_RECURSIVE`: 5.2
+//! - `AT_EACCESS`: 5.8
+
+#![cfg(feature = "general")]
+
+#[test]
+fn at_flags_default() {
+    use linux_raw_sys::general::*;
+
+    assert_eq!(AT_FDCWD, -100);
+    assert_eq!(AT_SYMLINK_NOFOLLOW, 0x100);
+    assert_eq!(AT_REMOVEDIR, 0x200);
+    assert_eq!(AT_SYMLINK_FOLLOW, 0x400);
+
+    // The default version is 2.6.32 on these, which predates the rest.
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "powerpc")))]
+    {
+        assert_eq!(AT_NO_AUTOMOUNT, 0x800);
+        assert_eq!(AT_EMPTY_PATH, 0x1000);
+    }
+
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    {
+        assert_eq!(AT_STATX_SYNC_TYPE, 0x6000);
+        assert_eq!(AT_STATX_SYNC_AS_STAT, 0);
+        assert_eq!(AT_STATX_FORCE_SYNC, 0x2000);
+        assert_eq!(AT_STATX_DONT_SYNC, 0x4000);
+    }
+}
+
+#[cfg(feature = "v5_4")]
+#[test]
+fn at_flags_v5_4() {
+    use linux_raw_sys::v5_4::general::*;
+
+    assert_eq!(AT_FDCWD, -100);
+    assert_eq!(AT_SYMLINK_NOFOLLOW, 0x100);
+    assert_eq!(AT_REMOVEDIR, 0x200);
+    assert_eq!(AT_SYMLINK_FOLLOW, 0x400);
+    assert_eq!(AT_NO_AUTOMOUNT, 0x800);
+    assert_eq!(AT_EMPTY_PATH, 0x1000);
+    assert_eq!(AT_STATX_SYNC_TYPE, 0x6000);
+    assert_eq!(AT_STATX_SYNC_AS_STAT, 0);
+    assert_eq!(AT_STATX_FORCE_SYNC, 0x2000);
+    assert_eq!(AT_STATX_DONT_SYNC, 0x4000);
+    assert_eq!(AT_RECURSIVE, 0x8000);
+}
+
+#[cfg(feature = "v5_11")]
+#[test]
+fn at_flags_v5_11() {
+    use linux_raw_sys::v5_11::general::*;
+
+    assert_eq!(AT_FDCWD, -100);
+    assert_eq!(AT_SYMLINK_NOFOLLOW, 0x100);
+    assert_eq!(AT_REMOVEDIR, 0x200);
+    assert_eq!(AT_EACCESS, 0x200);
+    assert_eq!(AT_SYMLINK_FOLLOW, 0x400);
+    assert_eq!(AT_NO_AUTOMOUNT, 0x800);
+    assert_eq!(AT_EMPTY_PATH, 0x1000);
+    assert_eq!(AT_STATX_SYNC_TYPE, 0x6000);
+    assert_eq!(AT_STATX_SYNC_AS_STAT, 0);
+    assert_eq!(AT_STATX_FORCE_SYNC, 0x2000);
+    assert_eq!(AT_STATX_DONT_SYNC, 0x4000);
+    assert_eq!(AT_RECURSIVE, 0x8000);
+}
@@ -0,0 +1,176 @@
+//! Check the generated bitfield accessors against the bytes C produces when
+//! each field is assigned on its own, so that a wrong bit order or offset in
+//! the generated code is caught. CI runs this under QEMU for the non-x86
+//! architectures, which covers both endiannesses.
+//!
+//! The expected bytes were captured from C on x86_64. C allocates bitfields
+//! from the least significant bit on little-endian architectures and from the
+//! most significant bit on big-endian ones, so the big-endian bytes follow
+//! from them.
+
+#![cfg(feature = "general")]
+
+use core::mem::{size_of, zeroed};
+use core::slice;
+
+use linux_raw_sys::general::*;
+
+/// View `value` as its bytes.
+fn bytes<T>(value: &T) -> &[u8] {
+    unsafe { slice::from_raw_parts((value as *const T).cast::<u8>(), size_of::<T>()) }
+}
+
+/// Set one bitfield of a zeroed `$ty` to `$value` with `$set`, and check that
+/// the only nonzero byte is `$byte` at offset `$offset`, and that `$get`
+/// reads `$value` back.
+macro_rules! check_bitfield {
+    ($ty:ty, $get:ident, $set:ident, $value:expr, [$offset:expr] = $byte:expr) => {{
+        let mut x: $ty = unsafe { zeroed() };
+        x.$set($value);
+        let mut expected = vec![0_u8; size_of::<$ty>()];
+        expected[$offset] = $byte;
+        assert_eq!(
+            bytes(&x),
+            &expected[..],
+            "bytes after {}",
+            stringify!($ty::$set)
+        );
+        assert_eq!(x.$get(), $value, "{}", stringify!($ty::$get));
+    }};
+}
+
+/// `tcphdr` declares its bitfields in a different order for each
+/// endianness, so that its layout matches the TCP header on the wire
+/// everywhere.
+#[test]
+fn tcphdr_bitfields() {
+    check_bitfield!(tcphdr, doff, set_doff, 5, [12] = 0x50);
+    check_bitfield!(tcphdr, res1, set_res1, 0xf, [12] = 0x0f);
+    check_bitfield!(tcphdr, cwr, set_cwr, 1, [13] = 0x80);
+    check_bitfield!(tcphdr, ece, set_ece, 1, [13] = 0x40);
+    check_bitfield!(tcphdr, urg, set_urg, 1, [13] = 0x20);
+    check_bitfield!(tcphdr, ack, set_ack, 1, [13] = 0x10);
+    check_bitfield!(tcphdr, psh, set_psh, 1, [13] = 0x08);
+    check_bitfield!(tcphdr, rst, set_rst, 1, [13] = 0x04);
+    check_bitfield!(tcphdr, syn, set_syn, 1, [13] = 0x02);
+    check_bitfield!(tcphdr, fin, set_fin, 1, [13] = 0x01);
+}
+
+#[cfg(target_endian = "little")]
+#[test]
+fn tcp_info_bitfields() {
+    check_bitfield!(
+        tcp_info,
+        tcpi_snd_wscale,
+        set_tcpi_snd_wscale,
+        0xa,
+        [6] = 0x0a
+    );
+    check_bitfield!(
+        tcp_info,
+        tcpi_rcv_wscale,
+        set_tcpi_rcv_wscale,
+        0x3,
+        [6] = 0x30
+    );
+}
+
+#[cfg(target_endian = "big")]
+#[test]
+fn tcp_info_bitfields() {
+    check_bitfield!(
+        tcp_info,
+        tcpi_snd_wscale,
+        set_tcpi_snd_wscale,
+        0xa,
+        [6] = 0xa0
+    );
+    check_bitfield!(
+        tcp_info,
+        tcpi_rcv_wscale,
+        set_tcpi_rcv_wscale,
+        0x3,
+        [6] = 0x03
+    );
+}
+
+#[cfg(target_endian = "little")]
+#[test]
+fn user_desc_bitfields() {
+    check_bitfield!(user_desc, seg_32bit, set_seg_32bit, 1, [12] = 0x01);
+    check_bitfield!(user_desc, contents, set_contents, 2, [12] = 0x04);
+    check_bitfield!(
+        user_desc,
+        read_exec_only,
+        set_read_exec_only,
+        1,
+        [12] = 0x08
+    );
+    check_bitfield!(
+        user_desc,
+        limit_in_pages,
+        set_limit_in_pages,
+        1,
+        [12] = 0x10
+    );
+    check_bitfield!(
+        user_desc,
+        seg_not_present,
+        set_seg_not_present,
+        1,
+        [12] = 0x20
+    );
+    check_bitfield!(user_desc, useable, set_useable, 1, [12] = 0x40);
+    #[cfg(target_arch = "x86_64")]
+    check_bitfield!(user_desc, lm, set_lm, 1, [12] = 0x80);
+}
+
+#[cfg(target_endian = "big")]
+#[test]
+fn user_desc_bitfields() {
+    check_bitfield!(user_desc, seg_32bit, set_seg_32bit, 1, [12] = 0x80);
+    check_bitfield!(user_desc, contents, set_contents, 2, [12] = 0x40);
+    check_bitfield!(
+        user_desc,
+        read_exec_only,
+        set_read_exec_only,
+        1,
+        [12] = 0x10
+    );
+    check_bitfield!(
+        user_desc,
+        limit_in_pages,
+        set_limit_in_pages,
+        1,
+        [12] = 0x08
+    );
+    check_bitfield!(
+        user_desc,
+        seg_not_present,
+        set_seg_not_present,
+        1,
+        [12] = 0x04
+    );
+    check_bitfield!(user_desc, useable, set_useable, 1, [12] = 0x02);
+}
+
+/// Setting several fields of `tcphdr` gives the bytes of an ECN-setup SYN-ACK
+/// with no options, and reading them back isn't disturbed by the neighbouring
+/// fields.
+#[test]
+fn tcphdr_all_bitfields() {
+    let mut x: tcphdr = unsafe { zeroed() };
+    x.set_doff(5);
+    x.set_syn(1);
+    x.set_ack(1);
+    x.set_ece(1);
+    assert_eq!(&bytes(&x)[12..14], &[0x50, 0x52]);
+    assert_eq!((x.doff(), x.res1()), (5, 0));
+    assert_eq!((x.cwr(), x.ece(), x.urg(), x.ack()), (0, 1, 0, 1));
+    assert_eq!((x.psh(), x.rst(), x.syn(), x.fin()), (0, 0, 1, 0));
+
+    x.set_doff(0xf);
+    x.set_ack(0);
+    assert_eq!(&bytes(&x)[12..14], &[0xf0, 0x42]);
+    assert_eq!((x.doff(), x.ack(), x.syn()), (0xf, 0, 1));
+}
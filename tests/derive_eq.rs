@@ -0,0 +1,62 @@
+//! Check which structs the `derive-eq` feature derives `PartialEq` and `Eq`
+//! for: those whose fields all support them, including nested structs, but
+//! not those containing unions.
+
+#![cfg(all(feature = "general", feature = "derive-eq"))]
+
+use core::marker::PhantomData;
+use core::mem::zeroed;
+use linux_raw_sys::general::{itimerspec, sigevent, sockaddr_in, timespec, AF_INET};
+
+/// `Probe::<T>::IS_EQ` is the inherent const if `T: Eq`, and otherwise falls
+/// back to the trait's.
+struct Probe<T>(PhantomData<T>);
+
+trait NotEq {
+    const IS_EQ: bool = false;
+}
+
+impl<T> NotEq for Probe<T> {}
+
+impl<T: Eq> Probe<T> {
+    const IS_EQ: bool = true;
+}
+
+const _: () = {
+    assert!(Probe::<timespec>::IS_EQ);
+    assert!(Probe::<sockaddr_in>::IS_EQ);
+    // `sigevent` contains `sigval`, a union.
+    assert!(!Probe::<sigevent>::IS_EQ);
+};
+
+#[test]
+fn derive_eq() {
+    let a = timespec {
+        tv_sec: 1,
+        tv_nsec: 2,
+    };
+    let b = timespec {
+        tv_sec: 1,
+        tv_nsec: 3,
+    };
+    assert!(a == a);
+    assert!(a != b);
+
+    let x = itimerspec {
+        it_interval: a,
+        it_value: a,
+    };
+    let y = itimerspec {
+        it_interval: a,
+        it_value: b,
+    };
+    assert!(x == x);
+    assert!(x != y);
+
+    let mut addr: sockaddr_in = unsafe { zeroed() };
+    addr.sin_family = AF_INET as _;
+    let mut other = addr;
+    assert!(addr == other);
+    other.sin_port = 80_u16.to_be();
+    assert!(addr != other);
+}
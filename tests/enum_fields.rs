@@ -0,0 +1,194 @@
+//! The generated enums are Rust enums, so holding a value which isn't one of
+//! their variants is undefined behavior, even though they're
+//! `#[non_exhaustive]`. That's fine for values passed to the kernel, but not
+//! for values the kernel writes, since newer kernels add values that older
+//! headers don't have.
+//!
+//! So check that no generated struct or union has a field of an enum type,
+//! and that the structs the kernel fills in are valid with any bytes in them.
+//! Miri checks the validity of every value it copies, so CI runs this under
+//! Miri too. If the first check fails, generate the enum as an integer
+//! newtype instead, with bindgen's `newtype_enum`.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Get the type name at the end of the field type `ty`, such as `foo` in
+/// `[foo; 4usize]` or `crate::ctypes::foo`.
+fn base_type(ty: &str) -> &str {
+    let ty = ty.trim_start_matches('[');
+    let ty = ty.split(';').next().unwrap();
+    ty.rsplit("::").next().unwrap().trim()
+}
+
+/// Find the fields of enum type in the structs and unions of `files`, which
+/// are all the files of one version, since arch files include shared ones.
+fn enum_fields(files: &[String]) -> Vec<String> {
+    let mut enums = HashSet::new();
+    for file in files {
+        for line in file.lines() {
+            if let Some(name) = line.strip_prefix("pub enum ") {
+                enums.insert(name.trim_end_matches(" {").to_owned());
+            }
+        }
+    }
+    // Include aliases of enums, which bindgen generates for typedefs.
+    for file in files {
+        for line in file.lines() {
+            if let Some(alias) = line.strip_prefix("pub type ") {
+                let (name, ty) = alias.trim_end_matches(';').split_once(" = ").unwrap();
+                if enums.contains(base_type(ty)) {
+                    enums.insert(name.to_owned());
+                }
+            }
+        }
+    }
+
+    let mut found = Vec::new();
+    for file in files {
+        let mut aggregate = None;
+        for line in file.lines() {
+            if let Some(name) = line
+                .strip_prefix("pub struct ")
+                .or_else(|| line.strip_prefix("pub union "))
+                .and_then(|rest| rest.strip_suffix(" {"))
+            {
+                aggregate = Some(name);
+            } else if line == "}" {
+                aggregate = None;
+            } else if let (Some(aggregate), Some(field)) = (aggregate, line.strip_prefix("pub ")) {
+                if let Some((name, ty)) = field.trim_end_matches(',').split_once(": ") {
+                    if enums.contains(base_type(ty)) {
+                        found.push(format!("{}.{}: {}", aggregate, name, ty));
+                    }
+                }
+            }
+        }
+    }
+    found
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn no_enum_typed_fields() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    for version in fs::read_dir(&src).unwrap() {
+        let version = version.unwrap().path();
+        if !version.is_dir() {
+            continue;
+        }
+        let mut files = Vec::new();
+        for dir in fs::read_dir(&version).unwrap() {
+            let dir = dir.unwrap().path();
+            if !dir.is_dir() {
+                continue;
+            }
+            for file in fs::read_dir(&dir).unwrap() {
+                files.push(fs::read_to_string(file.unwrap().path()).unwrap());
+            }
+        }
+        let found = enum_fields(&files);
+        assert!(
+            found.is_empty(),
+            "fields of enum type in {}: {:?}",
+            version.display(),
+            found
+        );
+    }
+}
+
+/// Read a `$ty` from buffers filled with a few byte patterns, and copy it,
+/// which Miri checks is a valid value.
+macro_rules! any_bytes_valid {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            for byte in [0x00, 0xff, 0xa5, 0x5a] {
+                let bytes = [byte; core::mem::size_of::<$ty>()];
+                let value = unsafe { core::ptr::read_unaligned(bytes.as_ptr().cast::<$ty>()) };
+                let copy = value;
+                core::hint::black_box(copy);
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "general")]
+#[test]
+fn general_any_bytes_valid() {
+    use linux_raw_sys::general::*;
+
+    any_bytes_valid!(
+        stat,
+        statfs,
+        statfs64,
+        rusage,
+        timespec,
+        timeval,
+        pollfd,
+        epoll_event,
+        sockaddr_in,
+        sockaddr_in6,
+        sockaddr_un,
+        termios,
+        tcp_info,
+    );
+    #[cfg(feature = "v5_11")]
+    any_bytes_valid!(linux_raw_sys::v5_11::general::statx);
+}
+
+#[cfg(feature = "netlink")]
+#[test]
+fn netlink_any_bytes_valid() {
+    use linux_raw_sys::netlink::*;
+
+    any_bytes_valid!(
+        sockaddr_nl,
+        nlmsghdr,
+        nlmsgerr,
+        nlattr,
+        rtattr,
+        ifinfomsg,
+        ifaddrmsg
+    );
+}
+
+/// Values the kernel writes are integers, which can be compared with the
+/// enums' discriminants without making an enum out of them.
+#[cfg(all(target_os = "linux", feature = "general", not(miri)))]
+#[test]
+fn kernel_tcp_info() {
+    use std::io;
+    use std::mem::{size_of, MaybeUninit};
+    use std::net::{TcpListener, TcpStream};
+    use std::os::unix::io::AsRawFd;
+
+    use linux_raw_sys::general::{tcp_ca_state, tcp_info, IPPROTO_TCP, TCP_INFO};
+
+    let listener = match TcpListener::bind("127.0.0.1:0") {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("skipping: can't listen on loopback: {}", err);
+            return;
+        }
+    };
+    let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+
+    let mut info = MaybeUninit::<tcp_info>::zeroed();
+    let mut len = size_of::<tcp_info>() as libc::socklen_t;
+    let ret = unsafe {
+        libc::getsockopt(
+            stream.as_raw_fd(),
+            IPPROTO_TCP as _,
+            TCP_INFO as _,
+            info.as_mut_ptr().cast(),
+            &mut len,
+        )
+    };
+    assert_eq!(ret, 0, "getsockopt failed: {}", io::Error::last_os_error());
+    let info = unsafe { info.assume_init() };
+
+    // `TCP_ESTABLISHED` is in the kernel's internal <net/tcp_states.h>.
+    assert_eq!(info.tcpi_state, 1);
+    assert_eq!(info.tcpi_ca_state, tcp_ca_state::TCP_CA_Open as u8);
+}
@@ -0,0 +1,35 @@
+//! Check that `epoll_event` has the kernel's layout: packed on x86_64 and
+//! naturally aligned everywhere else.
+
+#![cfg(feature = "general")]
+
+use core::mem::{align_of, offset_of, size_of};
+use linux_raw_sys::general::epoll_event;
+
+#[test]
+fn epoll_event_layout() {
+    assert_eq!(offset_of!(epoll_event, events), 0);
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        assert_eq!(offset_of!(epoll_event, data), 4);
+        assert_eq!(size_of::<epoll_event>(), 12);
+        assert_eq!(align_of::<epoll_event>(), 1);
+    }
+
+    // On i386, `u64` is only 4-byte aligned, so the natural layout happens to
+    // match the packed one.
+    #[cfg(target_arch = "x86")]
+    {
+        assert_eq!(offset_of!(epoll_event, data), 4);
+        assert_eq!(size_of::<epoll_event>(), 12);
+        assert_eq!(align_of::<epoll_event>(), 4);
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        assert_eq!(offset_of!(epoll_event, data), 8);
+        assert_eq!(size_of::<epoll_event>(), 16);
+        assert_eq!(align_of::<epoll_event>(), 8);
+    }
+}
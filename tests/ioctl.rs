@@ -0,0 +1,113 @@
+//! Check that the ioctl request codes have the type `Ioctl`, so that they can
+//! be passed to `ioctl` without casts, and the values the kernel expects on
+//! each architecture. CI runs this under QEMU for the non-x86 architectures.
+//!
+//! The codes made with `_IOC`, such as `TIOCGWINSZ` on mips and powerpc, are
+//! only checked where they're plain numbers.
+
+#![cfg(feature = "general")]
+
+use linux_raw_sys::general::*;
+use linux_raw_sys::Ioctl;
+
+/// Check each code against its value, which also checks that it's an `Ioctl`.
+fn check(codes: &[(&str, Ioctl, Ioctl)]) {
+    for (name, code, value) in codes {
+        assert_eq!(code, value, "{}", name);
+    }
+}
+
+macro_rules! codes {
+    ($($name:ident = $value:expr),* $(,)?) => {
+        &[$((stringify!($name), $name, $value)),*]
+    };
+}
+
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64",
+)))]
+#[test]
+fn generic_codes() {
+    check(codes!(
+        TCGETS = 0x5401,
+        TCSETS = 0x5402,
+        TIOCEXCL = 0x540c,
+        TIOCSCTTY = 0x540e,
+        TIOCGWINSZ = 0x5413,
+        FIONREAD = 0x541b,
+        FIONBIO = 0x5421,
+        FIOCLEX = 0x5451,
+        SIOCATMARK = 0x8905,
+    ));
+}
+
+#[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+#[test]
+fn mips_codes() {
+    check(codes!(
+        TCGETS = 0x540d,
+        TCSETS = 0x540e,
+        TIOCEXCL = 0x740d,
+        TIOCSCTTY = 0x5480,
+        FIONREAD = 0x467f,
+        FIONBIO = 0x667e,
+        FIOCLEX = 0x6601,
+    ));
+}
+
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+#[test]
+fn powerpc_codes() {
+    check(codes!(
+        TIOCEXCL = 0x540c,
+        TIOCSCTTY = 0x540e,
+        SIOCATMARK = 0x8905,
+    ));
+}
+
+/// libc's request codes have different types on different C libraries, but
+/// the same values.
+#[cfg(all(
+    target_os = "linux",
+    not(any(
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc",
+        target_arch = "sparc64",
+    ))
+))]
+#[test]
+fn libc_codes() {
+    check(codes!(
+        TCGETS = libc::TCGETS as Ioctl,
+        TCSETS = libc::TCSETS as Ioctl,
+        TIOCEXCL = libc::TIOCEXCL as Ioctl,
+        TIOCSCTTY = libc::TIOCSCTTY as Ioctl,
+        FIONREAD = libc::FIONREAD as Ioctl,
+        FIONBIO = libc::FIONBIO as Ioctl,
+        FIOCLEX = libc::FIOCLEX as Ioctl,
+    ));
+}
+
+/// The codes can be passed to `ioctl` as they are.
+#[cfg(all(target_os = "linux", not(miri)))]
+#[test]
+fn ioctl_syscall() {
+    use std::os::unix::io::AsRawFd;
+
+    let file = std::fs::File::open("/dev/null").unwrap();
+    let ret = unsafe {
+        libc::syscall(
+            __NR_ioctl as libc::c_long,
+            file.as_raw_fd(),
+            FIOCLEX,
+            0 as libc::c_ulong,
+        )
+    };
+    assert_eq!(ret, 0);
+}
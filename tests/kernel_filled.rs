@@ -0,0 +1,36 @@
+//! Check the helpers for reading the structs which the kernel fills in.
+
+#![cfg(feature = "general")]
+
+use core::mem::{size_of, MaybeUninit};
+use core::ptr::copy_nonoverlapping;
+
+use linux_raw_sys::general::{__NR_getrusage, rusage, RUSAGE_SELF};
+
+#[test]
+fn getrusage() {
+    let mut uninit = MaybeUninit::<rusage>::uninit();
+    let ret = unsafe {
+        libc::syscall(
+            __NR_getrusage as libc::c_long,
+            RUSAGE_SELF,
+            uninit.as_mut_ptr(),
+        )
+    };
+    assert_eq!(ret, 0);
+    let usage = unsafe { rusage::assume_init_ref(&uninit) };
+    assert!(usage.ru_maxrss > 0);
+
+    // Read a copy back out of a misaligned buffer.
+    let mut buf = vec![0_u8; size_of::<rusage>() + 1];
+    unsafe {
+        copy_nonoverlapping(
+            (usage as *const rusage).cast::<u8>(),
+            buf[1..].as_mut_ptr(),
+            size_of::<rusage>(),
+        )
+    };
+    let copy = unsafe { rusage::from_bytes_unchecked(&buf[1..]) };
+    assert_eq!(copy.ru_maxrss, usage.ru_maxrss);
+    assert_eq!(copy.ru_utime.tv_sec, usage.ru_utime.tv_sec);
+}
@@ -0,0 +1,207 @@
+//! Check a sample of the generated structs and constants against the Linux
+//! headers installed on the test host, by compiling C which asserts that
+//! they have the sizes, alignments, and values the bindings give them.
+//!
+//! The host's headers may be from a newer version than the bindings, so the
+//! samples are limited to items whose layout and values haven't changed.
+//! The test is skipped if there's no C compiler or no Linux headers.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// The C source being built: the headers to include, and the assertions.
+#[derive(Default)]
+struct Checks {
+    headers: Vec<&'static str>,
+    asserts: String,
+}
+
+impl Checks {
+    fn include(&mut self, headers: &[&'static str]) {
+        self.headers.extend(headers);
+    }
+
+    /// Assert that the C type `c_type` has the size and alignment of `T`.
+    fn layout<T>(&mut self, c_type: &str) {
+        let size = core::mem::size_of::<T>();
+        let align = core::mem::align_of::<T>();
+        writeln!(
+            self.asserts,
+            "_Static_assert(sizeof({0}) == {1}, \"size of {0} is not {1}\");",
+            c_type, size
+        )
+        .unwrap();
+        writeln!(
+            self.asserts,
+            "_Static_assert(_Alignof({0}) == {1}, \"alignment of {0} is not {1}\");",
+            c_type, align
+        )
+        .unwrap();
+    }
+
+    /// Assert that the C constant `name` has the value `value`.
+    fn value(&mut self, name: &str, value: impl Into<i128>) {
+        let value = value.into();
+        writeln!(
+            self.asserts,
+            "_Static_assert({0} == {1}, \"{0} is not {1}\");",
+            name, value
+        )
+        .unwrap();
+    }
+
+    fn source(&self) -> String {
+        let mut source = String::new();
+        for header in &self.headers {
+            writeln!(source, "#include <{}>", header).unwrap();
+        }
+        source.push_str(&self.asserts);
+        source
+    }
+}
+
+#[cfg(feature = "general")]
+fn general(checks: &mut Checks) {
+    use linux_raw_sys::general::*;
+
+    checks.include(&[
+        "linux/eventpoll.h",
+        "linux/fcntl.h",
+        "linux/in.h",
+        "linux/in6.h",
+        "linux/poll.h",
+        "linux/resource.h",
+        "linux/time.h",
+        "linux/uio.h",
+        "linux/un.h",
+        "asm/stat.h",
+        "asm/statfs.h",
+        "asm/termbits.h",
+    ]);
+
+    checks.layout::<timespec>("struct timespec");
+    checks.layout::<timeval>("struct timeval");
+    checks.layout::<itimerspec>("struct itimerspec");
+    checks.layout::<rusage>("struct rusage");
+    checks.layout::<rlimit>("struct rlimit");
+    checks.layout::<pollfd>("struct pollfd");
+    checks.layout::<iovec>("struct iovec");
+    checks.layout::<epoll_event>("struct epoll_event");
+    checks.layout::<sockaddr_in>("struct sockaddr_in");
+    checks.layout::<sockaddr_in6>("struct sockaddr_in6");
+    checks.layout::<sockaddr_un>("struct sockaddr_un");
+    checks.layout::<stat>("struct stat");
+    checks.layout::<statfs>("struct statfs");
+    checks.layout::<termios>("struct termios");
+
+    checks.value("O_CLOEXEC", O_CLOEXEC);
+    checks.value("O_DIRECTORY", O_DIRECTORY);
+    checks.value("O_NONBLOCK", O_NONBLOCK);
+    checks.value("AT_FDCWD", AT_FDCWD);
+    checks.value("EPOLLIN", EPOLLIN);
+    checks.value("POLLOUT", POLLOUT);
+    checks.value("RLIMIT_NOFILE", RLIMIT_NOFILE);
+}
+
+#[cfg(feature = "errno")]
+fn errno(checks: &mut Checks) {
+    use linux_raw_sys::errno::*;
+
+    checks.include(&["linux/errno.h"]);
+
+    checks.value("ENOENT", ENOENT);
+    checks.value("EAGAIN", EAGAIN);
+    checks.value("EINVAL", EINVAL);
+    checks.value("ENOSYS", ENOSYS);
+    checks.value("EDEADLK", EDEADLK);
+    checks.value("EOPNOTSUPP", EOPNOTSUPP);
+}
+
+#[cfg(feature = "netlink")]
+fn netlink(checks: &mut Checks) {
+    use linux_raw_sys::netlink::*;
+
+    checks.include(&["linux/netlink.h", "linux/rtnetlink.h"]);
+
+    checks.layout::<sockaddr_nl>("struct sockaddr_nl");
+    checks.layout::<nlmsghdr>("struct nlmsghdr");
+    checks.layout::<nlmsgerr>("struct nlmsgerr");
+    checks.layout::<nlattr>("struct nlattr");
+    checks.layout::<rtattr>("struct rtattr");
+    checks.layout::<ifinfomsg>("struct ifinfomsg");
+    checks.layout::<ifaddrmsg>("struct ifaddrmsg");
+
+    checks.value("NETLINK_ROUTE", NETLINK_ROUTE);
+    checks.value("NLM_F_REQUEST", NLM_F_REQUEST);
+    checks.value("RTM_NEWLINK", RTM_NEWLINK as u32);
+}
+
+/// Compile `source` with `cc`, without linking. Return `None` if there's no C
+/// compiler.
+fn compile(source: &str, name: &str) -> Option<Result<(), String>> {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let path = dir.join(name);
+    fs::write(&path, source).unwrap();
+
+    // `cc` normally gets these from cargo in a build script. When testing a
+    // cross target, they must be set in the environment; otherwise assume
+    // that the target is the host.
+    let target =
+        env::var("TARGET").unwrap_or_else(|_| format!("{}-unknown-linux-gnu", env::consts::ARCH));
+    let host = env::var("HOST").unwrap_or_else(|_| target.clone());
+    let compiler = cc::Build::new()
+        .cargo_metadata(false)
+        .cargo_warnings(false)
+        .opt_level(0)
+        .target(&target)
+        .host(&host)
+        .try_get_compiler()
+        .ok()?;
+    let output = match compiler
+        .to_command()
+        .arg("-fsyntax-only")
+        .arg(&path)
+        .output()
+    {
+        Ok(output) => output,
+        Err(err) if err.kind() == ErrorKind::NotFound => return None,
+        Err(err) => panic!("running {:?}: {}", compiler.path(), err),
+    };
+    if output.status.success() {
+        Some(Ok(()))
+    } else {
+        Some(Err(String::from_utf8_lossy(&output.stderr).into_owned()))
+    }
+}
+
+#[test]
+fn layout_matches_headers() {
+    match compile("#include <linux/types.h>\n", "probe.c") {
+        None => {
+            eprintln!("skipping: no C compiler found");
+            return;
+        }
+        Some(Err(_)) => {
+            eprintln!("skipping: no Linux headers found");
+            return;
+        }
+        Some(Ok(())) => {}
+    }
+
+    #[allow(unused_mut)]
+    let mut checks = Checks::default();
+    #[cfg(feature = "general")]
+    general(&mut checks);
+    #[cfg(feature = "errno")]
+    errno(&mut checks);
+    #[cfg(feature = "netlink")]
+    netlink(&mut checks);
+
+    let source = checks.source();
+    if let Some(Err(errors)) = compile(&source, "layout.c") {
+        panic!("{}\n{}", source, errors);
+    }
+}
@@ -0,0 +1,162 @@
+//! Check that structs which both these bindings and the `libc` crate define
+//! have the same layout, on the architectures where glibc uses the kernel's
+//! layout for them. CI runs this under QEMU for the non-x86 architectures.
+
+#![cfg(all(
+    feature = "general",
+    target_env = "gnu",
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "riscv64",
+        target_arch = "s390x",
+    )
+))]
+
+use core::mem::{align_of, offset_of, size_of, size_of_val, zeroed};
+use linux_raw_sys::general;
+
+/// Check that each pair of fields of `$ours` and `$theirs` has the same
+/// offset and size.
+macro_rules! same_fields {
+    ($ours:ty, $theirs:ty, { $($a:ident => $b:ident),* $(,)? }) => {{
+        let ours: $ours = unsafe { zeroed() };
+        let theirs: $theirs = unsafe { zeroed() };
+        $(
+            assert_eq!(
+                offset_of!($ours, $a),
+                offset_of!($theirs, $b),
+                "offset of {}",
+                stringify!($ours.$a)
+            );
+            // Copy the fields out, in case the struct is packed.
+            assert_eq!(
+                size_of_val(&{ ours.$a }),
+                size_of_val(&{ theirs.$b }),
+                "size of {}",
+                stringify!($ours.$a)
+            );
+        )*
+    }};
+}
+
+/// Check that `$ours` and `$theirs` have the same size and alignment, and
+/// that each pair of fields has the same offset and size.
+macro_rules! same_layout {
+    ($ours:ty, $theirs:ty, { $($fields:tt)* }) => {{
+        assert_eq!(size_of::<$ours>(), size_of::<$theirs>(), "size of {}", stringify!($ours));
+        assert_eq!(align_of::<$ours>(), align_of::<$theirs>(), "align of {}", stringify!($ours));
+        same_fields!($ours, $theirs, { $($fields)* });
+    }};
+}
+
+#[test]
+fn timespec_layout() {
+    same_layout!(general::timespec, libc::timespec, {
+        tv_sec => tv_sec,
+        tv_nsec => tv_nsec,
+    });
+}
+
+#[test]
+fn sockaddr_in_layout() {
+    same_layout!(general::sockaddr_in, libc::sockaddr_in, {
+        sin_family => sin_family,
+        sin_port => sin_port,
+        sin_addr => sin_addr,
+    });
+}
+
+#[test]
+fn epoll_event_layout() {
+    same_fields!(general::epoll_event, libc::epoll_event, {
+        events => events,
+        data => u64,
+    });
+    assert_eq!(
+        size_of::<general::epoll_event>(),
+        size_of::<libc::epoll_event>()
+    );
+
+    // libc packs it on x86 too, where that doesn't change its layout, but
+    // does lower its alignment.
+    #[cfg(not(target_arch = "x86"))]
+    assert_eq!(
+        align_of::<general::epoll_event>(),
+        align_of::<libc::epoll_event>()
+    );
+}
+
+/// glibc's `termios` has a larger `c_cc` and adds `c_ispeed` and `c_ospeed`,
+/// so only the fields before `c_cc` have the same layout.
+#[test]
+fn termios_layout() {
+    same_fields!(general::termios, libc::termios, {
+        c_iflag => c_iflag,
+        c_oflag => c_oflag,
+        c_cflag => c_cflag,
+        c_lflag => c_lflag,
+    });
+    assert_eq!(
+        offset_of!(general::termios, c_cc),
+        offset_of!(libc::termios, c_cc)
+    );
+
+    // On powerpc, `c_line` comes after `c_cc`.
+    #[cfg(not(any(target_arch = "powerpc", target_arch = "powerpc64")))]
+    same_fields!(general::termios, libc::termios, {
+        c_line => c_line,
+    });
+}
+
+/// glibc's `stat` only has the kernel's layout on 64-bit architectures; the
+/// 32-bit ones use `stat64`.
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn stat_layout() {
+    same_layout!(general::stat, libc::stat, {
+        st_dev => st_dev,
+        st_ino => st_ino,
+        st_mode => st_mode,
+        st_nlink => st_nlink,
+        st_uid => st_uid,
+        st_gid => st_gid,
+        st_rdev => st_rdev,
+        st_size => st_size,
+        st_blksize => st_blksize,
+        st_blocks => st_blocks,
+        st_atime => st_atime,
+        st_atime_nsec => st_atime_nsec,
+        st_mtime => st_mtime,
+        st_mtime_nsec => st_mtime_nsec,
+        st_ctime => st_ctime,
+        st_ctime_nsec => st_ctime_nsec,
+    });
+}
+
+#[cfg(target_pointer_width = "32")]
+#[test]
+fn stat64_layout() {
+    same_layout!(general::stat64, libc::stat64, {
+        st_dev => st_dev,
+        st_ino => st_ino,
+        st_mode => st_mode,
+        st_nlink => st_nlink,
+        st_uid => st_uid,
+        st_gid => st_gid,
+        st_rdev => st_rdev,
+        st_size => st_size,
+        st_blksize => st_blksize,
+        st_blocks => st_blocks,
+        st_atime => st_atime,
+        st_atime_nsec => st_atime_nsec,
+        st_mtime => st_mtime,
+        st_mtime_nsec => st_mtime_nsec,
+        st_ctime => st_ctime,
+        st_ctime_nsec => st_ctime_nsec,
+    });
+}
@@ -0,0 +1,65 @@
+//! Check that the generated files match the content hashes recorded in
+//! `src/MANIFEST`, so that edits which regenerating would lose are caught.
+//! Run `cd gen && cargo run --release -- --check-manifest` to also find files
+//! whose inputs have changed since they were generated.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Continue the 64-bit FNV-1a hash `hash` with `bytes`.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// List the files under `dir`, relative to `base`.
+fn files_in(base: &Path, dir: &Path, files: &mut HashSet<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files_in(base, &path, files);
+        } else {
+            let rel = path.strip_prefix(base).unwrap();
+            files.insert(rel.to_str().unwrap().to_owned());
+        }
+    }
+}
+
+/// List the files in the version directories under `src`, relative to it.
+fn generated_files(src: &Path) -> HashSet<String> {
+    let mut files = HashSet::new();
+    for entry in fs::read_dir(src).unwrap() {
+        let entry = entry.unwrap();
+        let name = entry.file_name().into_string().unwrap();
+        if entry.file_type().unwrap().is_dir() && name.starts_with('v') {
+            files_in(src, &entry.path(), &mut files);
+        }
+    }
+    files
+}
+
+#[test]
+fn manifest_matches() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let manifest = fs::read_to_string(src.join("MANIFEST")).unwrap();
+
+    let mut unlisted = generated_files(&src);
+    for line in manifest.lines().filter(|line| !line.starts_with('#')) {
+        let mut fields = line.splitn(3, ' ');
+        let output_hash = fields.next().unwrap();
+        let _input_hash = fields.next().unwrap();
+        let path = fields.next().unwrap();
+
+        let contents = fs::read(src.join(path)).unwrap();
+        assert_eq!(
+            format!("{:016x}", fnv1a(0xcbf2_9ce4_8422_2325, &contents)),
+            output_hash,
+            "{} has been edited since it was generated",
+            path
+        );
+        assert!(unlisted.remove(path), "{} is listed twice", path);
+    }
+    assert!(unlisted.is_empty(), "not in the manifest: {:?}", unlisted);
+}
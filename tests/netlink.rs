@@ -0,0 +1,36 @@
+//! Check that the core netlink types have the kernel's layout, which is the
+//! same on every architecture.
+
+#![cfg(feature = "netlink")]
+
+use core::mem::{offset_of, size_of};
+use linux_raw_sys::netlink::{
+    nlattr, nlmsghdr, sockaddr_nl, NETLINK_GENERIC, NETLINK_ROUTE, NLM_F_ACK, NLM_F_DUMP,
+    NLM_F_MULTI, NLM_F_REQUEST,
+};
+
+#[test]
+fn netlink_layout() {
+    assert_eq!(size_of::<sockaddr_nl>(), 12);
+    assert_eq!(offset_of!(sockaddr_nl, nl_pid), 4);
+    assert_eq!(offset_of!(sockaddr_nl, nl_groups), 8);
+
+    assert_eq!(size_of::<nlmsghdr>(), 16);
+    assert_eq!(offset_of!(nlmsghdr, nlmsg_type), 4);
+    assert_eq!(offset_of!(nlmsghdr, nlmsg_flags), 6);
+    assert_eq!(offset_of!(nlmsghdr, nlmsg_seq), 8);
+    assert_eq!(offset_of!(nlmsghdr, nlmsg_pid), 12);
+
+    assert_eq!(size_of::<nlattr>(), 4);
+    assert_eq!(offset_of!(nlattr, nla_type), 2);
+}
+
+#[test]
+fn netlink_constants() {
+    assert_eq!(NETLINK_ROUTE, 0);
+    assert_eq!(NETLINK_GENERIC, 16);
+    assert_eq!(NLM_F_REQUEST, 1);
+    assert_eq!(NLM_F_MULTI, 2);
+    assert_eq!(NLM_F_ACK, 4);
+    assert_eq!(NLM_F_DUMP, 0x300);
+}
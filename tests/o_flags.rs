@@ -0,0 +1,113 @@
+//! Check the open flags whose values differ between architectures, so that
+//! an architecture picking up the asm-generic values by mistake is caught.
+
+#![cfg(feature = "general")]
+
+use linux_raw_sys::general::{
+    O_APPEND, O_CLOEXEC, O_CREAT, O_DIRECT, O_DIRECTORY, O_LARGEFILE, O_NOATIME, O_NOFOLLOW,
+    O_NONBLOCK, O_RDONLY, O_RDWR, O_WRONLY,
+};
+
+#[test]
+fn o_flags_common() {
+    assert_eq!(O_RDONLY, 0);
+    assert_eq!(O_WRONLY, 1);
+    assert_eq!(O_RDWR, 2);
+}
+
+#[test]
+fn o_flags_arch() {
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv32",
+        target_arch = "riscv64",
+        target_arch = "s390x",
+    ))]
+    {
+        assert_eq!(O_CREAT, 0o100);
+        assert_eq!(O_APPEND, 0o2000);
+        assert_eq!(O_NONBLOCK, 0o4000);
+        assert_eq!(O_DIRECT, 0o40000);
+        assert_eq!(O_LARGEFILE, 0o100000);
+        assert_eq!(O_DIRECTORY, 0o200000);
+        assert_eq!(O_NOFOLLOW, 0o400000);
+        assert_eq!(O_NOATIME, 0o1000000);
+        assert_eq!(O_CLOEXEC, 0o2000000);
+    }
+
+    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+    {
+        assert_eq!(O_CREAT, 0o100);
+        assert_eq!(O_APPEND, 0o2000);
+        assert_eq!(O_NONBLOCK, 0o4000);
+        assert_eq!(O_DIRECTORY, 0o40000);
+        assert_eq!(O_NOFOLLOW, 0o100000);
+        assert_eq!(O_DIRECT, 0o200000);
+        assert_eq!(O_LARGEFILE, 0o400000);
+        assert_eq!(O_NOATIME, 0o1000000);
+        assert_eq!(O_CLOEXEC, 0o2000000);
+    }
+
+    #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+    {
+        assert_eq!(O_CREAT, 0o100);
+        assert_eq!(O_APPEND, 0o2000);
+        assert_eq!(O_NONBLOCK, 0o4000);
+        assert_eq!(O_DIRECTORY, 0o40000);
+        assert_eq!(O_NOFOLLOW, 0o100000);
+        assert_eq!(O_LARGEFILE, 0o200000);
+        assert_eq!(O_DIRECT, 0o400000);
+        assert_eq!(O_NOATIME, 0o1000000);
+        assert_eq!(O_CLOEXEC, 0o2000000);
+    }
+
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    {
+        assert_eq!(O_APPEND, 0o10);
+        assert_eq!(O_NONBLOCK, 0o200);
+        assert_eq!(O_CREAT, 0o400);
+        assert_eq!(O_LARGEFILE, 0o20000);
+        assert_eq!(O_DIRECT, 0o100000);
+        assert_eq!(O_DIRECTORY, 0o200000);
+        assert_eq!(O_NOFOLLOW, 0o400000);
+        assert_eq!(O_NOATIME, 0o1000000);
+        assert_eq!(O_CLOEXEC, 0o2000000);
+    }
+
+    #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+    {
+        assert_eq!(O_APPEND, 0o10);
+        assert_eq!(O_CREAT, 0o1000);
+        assert_eq!(O_NONBLOCK, 0o40000);
+        assert_eq!(O_DIRECTORY, 0o200000);
+        assert_eq!(O_NOFOLLOW, 0o400000);
+        assert_eq!(O_LARGEFILE, 0o1000000);
+        assert_eq!(O_DIRECT, 0o4000000);
+        assert_eq!(O_NOATIME, 0o10000000);
+        assert_eq!(O_CLOEXEC, 0o20000000);
+    }
+}
+
+/// Compare with libc's values, which come from the C library's headers.
+/// `O_LARGEFILE` is left out, since C libraries define it as 0 on 64-bit
+/// architectures, where the kernel sets it implicitly.
+#[test]
+fn o_flags_libc() {
+    let flags = [
+        (O_RDONLY, libc::O_RDONLY),
+        (O_WRONLY, libc::O_WRONLY),
+        (O_RDWR, libc::O_RDWR),
+        (O_CREAT, libc::O_CREAT),
+        (O_APPEND, libc::O_APPEND),
+        (O_NONBLOCK, libc::O_NONBLOCK),
+        (O_DIRECT, libc::O_DIRECT),
+        (O_DIRECTORY, libc::O_DIRECTORY),
+        (O_NOFOLLOW, libc::O_NOFOLLOW),
+        (O_NOATIME, libc::O_NOATIME),
+        (O_CLOEXEC, libc::O_CLOEXEC),
+    ];
+    for (ours, theirs) in flags {
+        assert_eq!(ours as libc::c_int, theirs);
+    }
+}
@@ -0,0 +1,75 @@
+//! Check the getters and setters of the packed structs on values placed so
+//! that their fields are misaligned, which Miri reports any aligned access
+//! to. CI runs this under Miri too.
+
+use core::mem::{align_of, size_of};
+
+/// Run `f` on a zeroed `T` placed `offset` bytes into a buffer aligned to 8
+/// bytes.
+fn misaligned<T, R>(offset: usize, f: impl FnOnce(&mut T) -> R) -> R {
+    #[repr(C, align(8))]
+    struct Buf([u8; 128]);
+
+    assert!(offset % align_of::<T>() == 0 && offset + size_of::<T>() <= 128);
+    let mut buf = Buf([0; 128]);
+    let ptr = buf.0[offset..].as_mut_ptr().cast::<T>();
+    f(unsafe { &mut *ptr })
+}
+
+#[cfg(all(feature = "general", target_arch = "x86_64"))]
+#[test]
+fn epoll_event_accessors() {
+    use linux_raw_sys::general::{epoll_event, EPOLLIN, EPOLLOUT};
+
+    // Put `data` at an odd address.
+    misaligned(1, |event: &mut epoll_event| {
+        event.set_events(EPOLLIN);
+        event.set_data(0x0123_4567_89ab_cdef);
+        assert_eq!(event.events(), EPOLLIN);
+        assert_eq!(event.data(), 0x0123_4567_89ab_cdef);
+
+        event.set_events(EPOLLOUT);
+        assert_eq!(event.events(), EPOLLOUT);
+        assert_eq!(event.data(), 0x0123_4567_89ab_cdef);
+        assert_eq!({ event.data }, 0x0123_4567_89ab_cdef);
+    });
+}
+
+/// `compat_statfs64` is packed to 4 bytes, so placing it at an address which
+/// is 4 but not 8 aligned misaligns its 64-bit fields.
+#[cfg(all(
+    feature = "general",
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+#[test]
+fn compat_statfs64_accessors() {
+    use linux_raw_sys::general::compat_statfs64;
+
+    misaligned(4, |st: &mut compat_statfs64| {
+        st.set_f_type(0xef53);
+        st.set_f_blocks(u64::MAX - 1);
+        st.set_f_bfree(1 << 40);
+        st.set_f_namelen(255);
+        assert_eq!(st.f_type(), 0xef53);
+        assert_eq!(st.f_blocks(), u64::MAX - 1);
+        assert_eq!(st.f_bfree(), 1 << 40);
+        assert_eq!(st.f_bavail(), 0);
+        assert_eq!(st.f_namelen(), 255);
+        assert_eq!({ st.f_blocks }, u64::MAX - 1);
+    });
+}
+
+/// `statfs64` is only packed on arm.
+#[cfg(all(feature = "general", target_arch = "arm"))]
+#[test]
+fn statfs64_accessors() {
+    use linux_raw_sys::general::statfs64;
+
+    misaligned(4, |st: &mut statfs64| {
+        st.set_f_blocks(u64::MAX - 1);
+        st.set_f_files(1 << 40);
+        assert_eq!(st.f_blocks(), u64::MAX - 1);
+        assert_eq!(st.f_files(), 1 << 40);
+        assert_eq!(st.f_ffree(), 0);
+    });
+}
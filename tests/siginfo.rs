@@ -0,0 +1,32 @@
+//! Check the `si_*` accessors on `siginfo` against libc's.
+
+#![cfg(feature = "general")]
+
+use core::mem::{size_of, transmute};
+
+use linux_raw_sys::general::siginfo;
+
+/// Compare the accessors with libc's, which follow the C macros, on a
+/// `siginfo` whose bytes are all distinct, so that reading from the wrong
+/// offset gives a different value.
+#[test]
+fn si_fields() {
+    let mut bytes = [0_u8; size_of::<siginfo>()];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    let ours: siginfo = unsafe { transmute(bytes) };
+    let theirs: libc::siginfo_t = unsafe { transmute(bytes) };
+
+    assert_eq!(ours.si_signo(), theirs.si_signo);
+    assert_eq!(ours.si_errno(), theirs.si_errno);
+    assert_eq!(ours.si_code(), theirs.si_code);
+    unsafe {
+        assert_eq!(ours.si_pid(), theirs.si_pid());
+        assert_eq!(ours.si_uid(), theirs.si_uid());
+        assert_eq!(ours.si_status(), theirs.si_status());
+        assert_eq!(ours.si_addr(), theirs.si_addr());
+        assert_eq!(ours.si_fd(), theirs.si_fd());
+        assert_eq!(ours.si_value().sival_ptr, theirs.si_value().sival_ptr);
+    }
+}
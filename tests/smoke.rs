@@ -0,0 +1,178 @@
+//! Make some harmless syscalls with the generated syscall numbers and structs,
+//! and check that the kernel's results land in the expected fields, to catch
+//! layout mistakes which the type system can't.
+//!
+//! The syscalls may be filtered in sandboxes, so tests are skipped if they
+//! fail with `ENOSYS` or `EPERM`.
+
+#![cfg(all(target_os = "linux", feature = "general"))]
+// The field types differ between architectures, so the casts are only
+// unnecessary on some of them.
+#![allow(clippy::unnecessary_cast)]
+
+use std::fs::File;
+use std::io;
+use std::mem::MaybeUninit;
+use std::os::unix::fs::MetadataExt;
+use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use linux_raw_sys::general::*;
+
+/// Make a raw syscall, returning `None`, after saying so, if the syscall
+/// isn't permitted or isn't available.
+macro_rules! syscall {
+    ($nr:expr $(, $arg:expr)* $(,)?) => {{
+        match unsafe { libc::syscall($nr as libc::c_long $(, $arg)*) } {
+            -1 => {
+                let err = io::Error::last_os_error();
+                match err.raw_os_error() {
+                    Some(libc::ENOSYS) | Some(libc::EPERM) => {
+                        eprintln!("skipping: {} failed: {}", stringify!($nr), err);
+                        None
+                    }
+                    _ => panic!("{} failed: {}", stringify!($nr), err),
+                }
+            }
+            ret => Some(ret),
+        }
+    }};
+}
+
+#[cfg(target_pointer_width = "64")]
+use {__NR_fstat as NR_FSTAT, stat as Stat};
+#[cfg(target_pointer_width = "32")]
+use {__NR_fstat64 as NR_FSTAT, stat64 as Stat};
+
+#[test]
+fn fstat_fields() {
+    let file = File::open(std::env::current_exe().unwrap()).unwrap();
+    let mut buf = MaybeUninit::<Stat>::uninit();
+    if syscall!(NR_FSTAT, file.as_raw_fd(), buf.as_mut_ptr()).is_none() {
+        return;
+    }
+    let st = unsafe { buf.assume_init() };
+
+    let metadata = file.metadata().unwrap();
+    assert_eq!(st.st_dev as u64, metadata.dev());
+    assert_eq!(st.st_ino as u64, metadata.ino());
+    assert_eq!(st.st_mode as u32, metadata.mode());
+    assert_eq!(st.st_nlink as u64, metadata.nlink());
+    assert_eq!(st.st_uid as u32, metadata.uid());
+    assert_eq!(st.st_gid as u32, metadata.gid());
+    assert_eq!(st.st_size as u64, metadata.size());
+    assert_eq!(st.st_blocks as u64, metadata.blocks());
+    assert_eq!(st.st_mtime as i64, metadata.mtime());
+    assert_eq!(st.st_mtime_nsec as i64, metadata.mtime_nsec());
+}
+
+#[cfg(feature = "v5_11")]
+#[test]
+fn statx_fields() {
+    use linux_raw_sys::v5_11::general::{__NR_statx, statx, AT_FDCWD, STATX_BASIC_STATS};
+
+    let mut buf = MaybeUninit::<statx>::uninit();
+    let path = b"/proc/self\0";
+    if syscall!(
+        __NR_statx,
+        AT_FDCWD,
+        path.as_ptr(),
+        0,
+        STATX_BASIC_STATS,
+        buf.as_mut_ptr()
+    )
+    .is_none()
+    {
+        return;
+    }
+    let stx = unsafe { buf.assume_init() };
+
+    let metadata = std::fs::metadata("/proc/self").unwrap();
+    assert_eq!(stx.stx_mask & STATX_BASIC_STATS, STATX_BASIC_STATS);
+    assert_eq!(u32::from(stx.stx_mode), metadata.mode());
+    assert_eq!(stx.stx_ino, metadata.ino());
+    assert_eq!(u64::from(stx.stx_nlink), metadata.nlink());
+    assert_eq!(stx.stx_uid, metadata.uid());
+    assert_eq!(stx.stx_gid, metadata.gid());
+    assert_eq!(stx.stx_mtime.tv_sec, metadata.mtime());
+    assert_eq!(i64::from(stx.stx_mtime.tv_nsec), metadata.mtime_nsec());
+    assert_eq!(
+        libc::makedev(stx.stx_dev_major, stx.stx_dev_minor),
+        metadata.dev()
+    );
+}
+
+/// There's no 32-bit `clock_gettime` on riscv32.
+#[cfg(not(target_arch = "riscv32"))]
+#[test]
+fn clock_gettime_fields() {
+    let read = || {
+        let mut buf = MaybeUninit::<timespec>::uninit();
+        syscall!(__NR_clock_gettime, CLOCK_REALTIME, buf.as_mut_ptr())?;
+        Some(unsafe { buf.assume_init() })
+    };
+
+    let before = match read() {
+        Some(ts) => ts,
+        None => return,
+    };
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let after = read().unwrap();
+
+    for ts in &[before, after] {
+        assert!((0..1_000_000_000).contains(&(ts.tv_nsec as i64)));
+    }
+    let nanos = |ts: &timespec| ts.tv_sec as i128 * 1_000_000_000 + ts.tv_nsec as i128;
+    assert!(nanos(&before) <= now.as_nanos() as i128);
+    assert!(now.as_nanos() as i128 <= nanos(&after));
+}
+
+#[test]
+fn epoll_event_fields() {
+    let epfd = match syscall!(__NR_epoll_create1, EPOLL_CLOEXEC) {
+        Some(fd) => unsafe { File::from_raw_fd(fd as _) },
+        None => return,
+    };
+    let efd = match syscall!(__NR_eventfd2, 1, EFD_CLOEXEC) {
+        Some(fd) => unsafe { File::from_raw_fd(fd as _) },
+        None => return,
+    };
+
+    // Use a `data` value whose halves differ, to catch it being misplaced.
+    let data = 0x0123_4567_89ab_cdef;
+    let mut event = epoll_event {
+        events: EPOLLIN,
+        data,
+    };
+    let ctl = syscall!(
+        __NR_epoll_ctl,
+        epfd.as_raw_fd(),
+        EPOLL_CTL_ADD,
+        efd.as_raw_fd(),
+        &mut event as *mut epoll_event
+    );
+    if ctl.is_none() {
+        return;
+    }
+
+    let mut events = [MaybeUninit::<epoll_event>::uninit(); 2];
+    let null = core::ptr::null::<u8>();
+    let n = match syscall!(
+        __NR_epoll_pwait,
+        epfd.as_raw_fd(),
+        events.as_mut_ptr(),
+        events.len(),
+        0,
+        null,
+        0
+    ) {
+        Some(n) => n,
+        None => return,
+    };
+    assert_eq!(n, 1);
+
+    let ready = unsafe { events[0].assume_init() };
+    let (ready_events, ready_data) = (ready.events, ready.data);
+    assert_eq!(ready_events, EPOLLIN);
+    assert_eq!(ready_data, data);
+}
@@ -0,0 +1,13 @@
+//! Check that the `union-debug` impls don't read the bytes of a union, which
+//! may be partly uninitialized, such as a `sigval` with only its `sival_int`
+//! set on a 64-bit architecture. CI runs this under Miri too.
+
+#![cfg(all(feature = "general", feature = "union-debug"))]
+
+use linux_raw_sys::general::sigval;
+
+#[test]
+fn partially_initialized() {
+    let value = sigval { sival_int: 1 };
+    assert_eq!(format!("{:?}", value), "sigval { .. }");
+}
@@ -0,0 +1,157 @@
+//! Check the `zeroed()` constructors of the request structs which the kernel
+//! requires unused bytes of to be zero, and that the kernel does reject
+//! nonzero bytes it doesn't know of.
+
+#![cfg(all(feature = "v5_11", feature = "general"))]
+
+use core::mem::{size_of, size_of_val, MaybeUninit};
+use core::slice;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use linux_raw_sys::v5_11::general::{clone_args, open_how};
+
+/// View `value` as its bytes.
+fn bytes<T>(value: &T) -> &[u8] {
+    unsafe { slice::from_raw_parts((value as *const T).cast::<u8>(), size_of::<T>()) }
+}
+
+#[test]
+fn zeroed_is_all_zeros() {
+    assert!(bytes(&clone_args::zeroed()).iter().all(|b| *b == 0));
+    assert!(bytes(&open_how::zeroed()).iter().all(|b| *b == 0));
+    assert_eq!(bytes(&clone_args::default()), bytes(&clone_args::zeroed()));
+    assert_eq!(bytes(&open_how::default()), bytes(&open_how::zeroed()));
+}
+
+#[test]
+fn zero_in_place_is_all_zeros() {
+    let mut uninit = MaybeUninit::<open_how>::uninit();
+    let how = open_how::zero_in_place(&mut uninit);
+    assert!(bytes(how).iter().all(|b| *b == 0));
+    how.resolve = 1;
+    assert_eq!(
+        bytes(how),
+        bytes(&open_how {
+            resolve: 1,
+            ..open_how::zeroed()
+        })
+    );
+}
+
+/// Find the structs with `zeroed()` constructors in the `v5_11` general
+/// bindings for this architecture, from the generated source, and whether
+/// their docs list any padding.
+fn zeroed_structs() -> Vec<(String, bool)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/v5_11");
+    let mut structs = Vec::new();
+    for arch in ["shared", env::consts::ARCH] {
+        let contents = fs::read_to_string(dir.join(arch).join("general.rs")).unwrap();
+        let mut current = None;
+        let mut padded = false;
+        for line in contents.lines() {
+            if let Some(name) = line
+                .strip_prefix("impl ")
+                .and_then(|l| l.strip_suffix(" {"))
+            {
+                current = Some(name);
+                padded = false;
+            } else if line.starts_with("/// Its padding is at bytes ") {
+                padded = true;
+            } else if line == "pub fn zeroed() -> Self {" {
+                structs.push((current.unwrap().to_owned(), padded));
+            }
+        }
+    }
+    structs.sort();
+    structs
+}
+
+/// Check that the structs with `zeroed()` whose docs list no padding have
+/// fields which cover all of their bytes. The structs are found in the
+/// generated source, so one added there without being added here fails.
+#[test]
+fn no_padding() {
+    assert_eq!(
+        zeroed_structs(),
+        [
+            ("clone_args".to_owned(), false),
+            ("open_how".to_owned(), false)
+        ]
+    );
+
+    let args = clone_args::zeroed();
+    let fields = [
+        size_of_val(&args.flags),
+        size_of_val(&args.pidfd),
+        size_of_val(&args.child_tid),
+        size_of_val(&args.parent_tid),
+        size_of_val(&args.exit_signal),
+        size_of_val(&args.stack),
+        size_of_val(&args.stack_size),
+        size_of_val(&args.tls),
+        size_of_val(&args.set_tid),
+        size_of_val(&args.set_tid_size),
+        size_of_val(&args.cgroup),
+    ];
+    assert_eq!(fields.iter().sum::<usize>(), size_of::<clone_args>());
+
+    let how = open_how::zeroed();
+    let fields = [
+        size_of_val(&how.flags),
+        size_of_val(&how.mode),
+        size_of_val(&how.resolve),
+    ];
+    assert_eq!(fields.iter().sum::<usize>(), size_of::<open_how>());
+}
+
+/// `openat2` accepts a larger `open_how` than it knows of if the extra bytes
+/// are zero, and fails with `E2BIG` if they aren't.
+#[cfg(target_os = "linux")]
+#[test]
+fn openat2_rejects_nonzero_unknown_bytes() {
+    use linux_raw_sys::v5_11::general::{__NR_openat2, AT_FDCWD, O_CLOEXEC, O_RDONLY};
+    use std::io;
+
+    #[repr(C)]
+    struct Extended {
+        how: open_how,
+        extra: u64,
+    }
+
+    let openat2 = |ext: &Extended| {
+        let path = b"/\0";
+        let ret = unsafe {
+            libc::syscall(
+                __NR_openat2 as libc::c_long,
+                AT_FDCWD,
+                path.as_ptr(),
+                ext as *const Extended,
+                size_of::<Extended>(),
+            )
+        };
+        if ret < 0 {
+            Err(io::Error::last_os_error().raw_os_error().unwrap())
+        } else {
+            unsafe { libc::close(ret as _) };
+            Ok(())
+        }
+    };
+
+    let mut ext = Extended {
+        how: open_how::zeroed(),
+        extra: 0,
+    };
+    ext.how.flags = u64::from(O_RDONLY | O_CLOEXEC);
+    match openat2(&ext) {
+        Err(libc::ENOSYS) | Err(libc::EPERM) => {
+            eprintln!("skipping: openat2 isn't available");
+            return;
+        }
+        result => assert_eq!(result, Ok(())),
+    }
+
+    ext.extra = 1;
+    assert_eq!(openat2(&ext), Err(libc::E2BIG));
+}